@@ -0,0 +1,357 @@
+use crate::{
+    books::{Level, mid_price},
+    event::MarketEvent,
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// Best [`Level`] on one side of a [`ConsolidatedL1`], and the exchange it is available on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct VenueLevel {
+    pub exchange: ExchangeId,
+    pub level: Level,
+}
+
+/// Synthetic best bid and offer of an instrument across multiple exchanges.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize)]
+pub struct ConsolidatedL1 {
+    pub last_update_time: DateTime<Utc>,
+    pub best_bid: Option<VenueLevel>,
+    pub best_ask: Option<VenueLevel>,
+}
+
+impl ConsolidatedL1 {
+    /// Calculate the mid-price by taking the average of the consolidated best bid and ask prices.
+    pub fn mid_price(&self) -> Option<Decimal> {
+        match (self.best_bid, self.best_ask) {
+            (Some(best_bid), Some(best_ask)) => {
+                Some(mid_price(best_bid.level.price, best_ask.level.price))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if the best bid of one exchange is priced at or above the best ask of another
+    /// exchange (ie/ a cross-exchange arbitrage opportunity, before fees).
+    pub fn is_crossed(&self) -> bool {
+        match (self.best_bid, self.best_ask) {
+            (Some(best_bid), Some(best_ask)) => {
+                best_bid.exchange != best_ask.exchange
+                    && best_bid.level.price >= best_ask.level.price
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<&ConsolidatedL1> for OrderBookL1 {
+    fn from(value: &ConsolidatedL1) -> Self {
+        Self {
+            last_update_time: value.last_update_time,
+            best_bid: value.best_bid.map(|bid| bid.level),
+            best_ask: value.best_ask.map(|ask| ask.level),
+        }
+    }
+}
+
+/// Consolidates [`OrderBookL1`] market events from multiple exchanges into a synthetic
+/// [`ConsolidatedL1`] best bid and offer per instrument.
+///
+/// Instruments are merged by `InstrumentKey`, so the key must be exchange agnostic
+/// (eg/ [`MarketDataInstrument`](crate::instrument::MarketDataInstrument)).
+#[derive(Debug, Clone)]
+pub struct L1Consolidator<InstrumentKey> {
+    /// Latest [`OrderBookL1`] of each exchange, for every instrument.
+    pub books: FnvHashMap<InstrumentKey, Vec<(ExchangeId, OrderBookL1)>>,
+
+    /// Optional maximum age of an exchange [`OrderBookL1`], relative to the latest update, before
+    /// it is excluded from the [`ConsolidatedL1`] as stale.
+    pub max_age: Option<TimeDelta>,
+}
+
+impl<InstrumentKey> Default for L1Consolidator<InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            books: FnvHashMap::default(),
+            max_age: None,
+        }
+    }
+}
+
+impl<InstrumentKey> L1Consolidator<InstrumentKey>
+where
+    InstrumentKey: Eq + Hash + Clone,
+{
+    /// Construct a new `L1Consolidator` with no stale [`OrderBookL1`] exclusion.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exclude exchange [`OrderBookL1`]s older than the provided `max_age` from the
+    /// [`ConsolidatedL1`].
+    pub fn with_max_age(self, max_age: TimeDelta) -> Self {
+        Self {
+            max_age: Some(max_age),
+            ..self
+        }
+    }
+
+    /// Update the `L1Consolidator` from an exchange [`OrderBookL1`] market event, returning the
+    /// synthetic [`ConsolidatedL1`] market event of the instrument.
+    ///
+    /// The returned `MarketEvent` exchange is the exchange that triggered the update. See
+    /// [`VenueLevel::exchange`] for the exchanges the best levels are available on.
+    pub fn update(
+        &mut self,
+        event: MarketEvent<InstrumentKey, OrderBookL1>,
+    ) -> MarketEvent<InstrumentKey, ConsolidatedL1> {
+        let MarketEvent {
+            time_exchange,
+            time_received,
+            exchange,
+            instrument,
+            kind: l1,
+        } = event;
+
+        let books = self.books.entry(instrument.clone()).or_default();
+        match books.iter_mut().find(|(venue, _)| *venue == exchange) {
+            Some((_, book)) => *book = l1,
+            None => books.push((exchange, l1)),
+        }
+
+        MarketEvent {
+            time_exchange,
+            time_received,
+            exchange,
+            kind: self.consolidated(&instrument, time_exchange),
+            instrument,
+        }
+    }
+
+    /// Remove the [`OrderBookL1`]s of the provided exchange (eg/ when its market data stream is
+    /// reconnecting and the data is no longer live).
+    pub fn remove_exchange(&mut self, exchange: ExchangeId) {
+        self.books
+            .values_mut()
+            .for_each(|books| books.retain(|(venue, _)| *venue != exchange));
+    }
+
+    /// Generate the [`ConsolidatedL1`] of the provided instrument at the provided time.
+    ///
+    /// Ties between exchanges are broken by the largest amount available at the best price.
+    pub fn consolidated(&self, instrument: &InstrumentKey, time: DateTime<Utc>) -> ConsolidatedL1 {
+        let Some(books) = self.books.get(instrument) else {
+            return ConsolidatedL1 {
+                last_update_time: time,
+                ..ConsolidatedL1::default()
+            };
+        };
+
+        let live = books.iter().filter(|(_, book)| {
+            self.max_age
+                .is_none_or(|max_age| time - book.last_update_time <= max_age)
+        });
+
+        let venue_levels = |side: fn(&OrderBookL1) -> Option<Level>| {
+            live.clone().filter_map(move |(exchange, book)| {
+                side(book).map(|level| VenueLevel {
+                    exchange: *exchange,
+                    level,
+                })
+            })
+        };
+
+        let best_bid = venue_levels(|book| book.best_bid).reduce(|best, next| {
+            if (next.level.price, next.level.amount) > (best.level.price, best.level.amount) {
+                next
+            } else {
+                best
+            }
+        });
+
+        let best_ask = venue_levels(|book| book.best_ask).reduce(|best, next| {
+            if next.level.price < best.level.price
+                || (next.level.price == best.level.price && next.level.amount > best.level.amount)
+            {
+                next
+            } else {
+                best
+            }
+        });
+
+        ConsolidatedL1 {
+            last_update_time: time,
+            best_bid,
+            best_ask,
+        }
+    }
+}
+
+/// Consolidate a `Stream` of exchange [`OrderBookL1`] market events into a `Stream` of
+/// synthetic [`ConsolidatedL1`] market events, using the provided [`L1Consolidator`].
+pub fn consolidate_l1_stream<St, InstrumentKey>(
+    stream: St,
+    consolidator: L1Consolidator<InstrumentKey>,
+) -> impl Stream<Item = MarketEvent<InstrumentKey, ConsolidatedL1>>
+where
+    St: Stream<Item = MarketEvent<InstrumentKey, OrderBookL1>>,
+    InstrumentKey: Eq + Hash + Clone,
+{
+    stream.scan(consolidator, |consolidator, event| {
+        std::future::ready(Some(consolidator.update(event)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn l1_event(
+        exchange: ExchangeId,
+        time_secs: i64,
+        bid: Option<(Decimal, Decimal)>,
+        ask: Option<(Decimal, Decimal)>,
+    ) -> MarketEvent<&'static str, OrderBookL1> {
+        let time = DateTime::<Utc>::MIN_UTC + TimeDelta::seconds(time_secs);
+        MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange,
+            instrument: "btc_usdt",
+            kind: OrderBookL1 {
+                last_update_time: time,
+                best_bid: bid.map(Level::from),
+                best_ask: ask.map(Level::from),
+            },
+        }
+    }
+
+    fn venue(exchange: ExchangeId, price: Decimal, amount: Decimal) -> Option<VenueLevel> {
+        Some(VenueLevel {
+            exchange,
+            level: Level::new(price, amount),
+        })
+    }
+
+    #[test]
+    fn test_l1_consolidator_update() {
+        struct TestCase {
+            input: Vec<MarketEvent<&'static str, OrderBookL1>>,
+            max_age: Option<TimeDelta>,
+            expected_bid: Option<VenueLevel>,
+            expected_ask: Option<VenueLevel>,
+        }
+
+        let cases = vec![
+            // TC0: single exchange
+            TestCase {
+                input: vec![l1_event(
+                    ExchangeId::BinanceSpot,
+                    0,
+                    Some((dec!(100), dec!(1))),
+                    Some((dec!(101), dec!(1))),
+                )],
+                max_age: None,
+                expected_bid: venue(ExchangeId::BinanceSpot, dec!(100), dec!(1)),
+                expected_ask: venue(ExchangeId::BinanceSpot, dec!(101), dec!(1)),
+            },
+            // TC1: best bid & best ask from different exchanges
+            TestCase {
+                input: vec![
+                    l1_event(
+                        ExchangeId::BinanceSpot,
+                        0,
+                        Some((dec!(100), dec!(1))),
+                        Some((dec!(102), dec!(1))),
+                    ),
+                    l1_event(
+                        ExchangeId::Okx,
+                        1,
+                        Some((dec!(99), dec!(1))),
+                        Some((dec!(101), dec!(1))),
+                    ),
+                ],
+                max_age: None,
+                expected_bid: venue(ExchangeId::BinanceSpot, dec!(100), dec!(1)),
+                expected_ask: venue(ExchangeId::Okx, dec!(101), dec!(1)),
+            },
+            // TC2: latest update of an exchange replaces its previous OrderBookL1
+            TestCase {
+                input: vec![
+                    l1_event(ExchangeId::BinanceSpot, 0, Some((dec!(100), dec!(1))), None),
+                    l1_event(ExchangeId::Okx, 1, Some((dec!(99), dec!(1))), None),
+                    l1_event(ExchangeId::BinanceSpot, 2, Some((dec!(98), dec!(1))), None),
+                ],
+                max_age: None,
+                expected_bid: venue(ExchangeId::Okx, dec!(99), dec!(1)),
+                expected_ask: None,
+            },
+            // TC3: equal best bid prices, so largest amount wins
+            TestCase {
+                input: vec![
+                    l1_event(ExchangeId::BinanceSpot, 0, Some((dec!(100), dec!(1))), None),
+                    l1_event(ExchangeId::Okx, 1, Some((dec!(100), dec!(5))), None),
+                ],
+                max_age: None,
+                expected_bid: venue(ExchangeId::Okx, dec!(100), dec!(5)),
+                expected_ask: None,
+            },
+            // TC4: stale exchange OrderBookL1 excluded
+            TestCase {
+                input: vec![
+                    l1_event(ExchangeId::BinanceSpot, 0, Some((dec!(100), dec!(1))), None),
+                    l1_event(ExchangeId::Okx, 10, Some((dec!(99), dec!(1))), None),
+                ],
+                max_age: Some(TimeDelta::seconds(5)),
+                expected_bid: venue(ExchangeId::Okx, dec!(99), dec!(1)),
+                expected_ask: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut consolidator = L1Consolidator::new();
+            consolidator.max_age = test.max_age;
+
+            let actual = test
+                .input
+                .into_iter()
+                .map(|event| consolidator.update(event))
+                .last()
+                .unwrap();
+
+            assert_eq!(actual.kind.best_bid, test.expected_bid, "TC{index} failed");
+            assert_eq!(actual.kind.best_ask, test.expected_ask, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_l1_consolidator_remove_exchange() {
+        let mut consolidator = L1Consolidator::new();
+        consolidator.update(l1_event(
+            ExchangeId::BinanceSpot,
+            0,
+            Some((dec!(100), dec!(1))),
+            None,
+        ));
+        let crossed = consolidator.update(l1_event(
+            ExchangeId::Okx,
+            0,
+            None,
+            Some((dec!(99), dec!(1))),
+        ));
+        assert!(crossed.kind.is_crossed());
+        assert_eq!(crossed.kind.mid_price(), Some(dec!(99.5)));
+
+        consolidator.remove_exchange(ExchangeId::BinanceSpot);
+        let actual = consolidator.consolidated(&"btc_usdt", crossed.time_exchange);
+        assert_eq!(actual.best_bid, None);
+        assert_eq!(actual.best_ask, venue(ExchangeId::Okx, dec!(99), dec!(1)));
+    }
+}
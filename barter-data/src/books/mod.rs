@@ -6,6 +6,11 @@ use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
 use tracing::debug;
 
+/// Provides an [`L1Consolidator`](consolidated::L1Consolidator) that merges
+/// [`OrderBookL1`](crate::subscription::book::OrderBookL1)s from multiple exchanges into a
+/// synthetic best bid and offer per instrument.
+pub mod consolidated;
+
 /// Provides a [`OrderBookL2Manager`](manager::OrderBookL2Manager) for maintaining a set of local
 /// L2 [`OrderBook`]s.
 pub mod manager;
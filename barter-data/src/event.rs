@@ -4,7 +4,9 @@ use crate::{
     subscription::{
         book::{OrderBookEvent, OrderBookL1},
         candle::Candle,
+        carry::{BorrowRate, FundingRate},
         liquidation::Liquidation,
+        mark_price::MarkPrice,
         trade::PublicTrade,
     },
 };
@@ -98,6 +100,27 @@ impl<InstrumentKey> MarketEvent<InstrumentKey, DataKind> {
         }
     }
 
+    pub fn as_mark_price(&self) -> Option<MarketEvent<&InstrumentKey, &MarkPrice>> {
+        match &self.kind {
+            DataKind::MarkPrice(mark_price) => Some(self.as_event(mark_price)),
+            _ => None,
+        }
+    }
+
+    pub fn as_funding_rate(&self) -> Option<MarketEvent<&InstrumentKey, &FundingRate>> {
+        match &self.kind {
+            DataKind::FundingRate(funding_rate) => Some(self.as_event(funding_rate)),
+            _ => None,
+        }
+    }
+
+    pub fn as_borrow_rate(&self) -> Option<MarketEvent<&InstrumentKey, &BorrowRate>> {
+        match &self.kind {
+            DataKind::BorrowRate(borrow_rate) => Some(self.as_event(borrow_rate)),
+            _ => None,
+        }
+    }
+
     fn as_event<'a, K>(&'a self, kind: &'a K) -> MarketEvent<&'a InstrumentKey, &'a K> {
         MarketEvent {
             time_exchange: self.time_exchange,
@@ -127,6 +150,9 @@ pub enum DataKind {
     OrderBook(OrderBookEvent),
     Candle(Candle),
     Liquidation(Liquidation),
+    MarkPrice(MarkPrice),
+    FundingRate(FundingRate),
+    BorrowRate(BorrowRate),
 }
 
 impl DataKind {
@@ -137,6 +163,9 @@ impl DataKind {
             DataKind::OrderBook(_) => "l2",
             DataKind::Candle(_) => "candle",
             DataKind::Liquidation(_) => "liquidation",
+            DataKind::MarkPrice(_) => "mark_price",
+            DataKind::FundingRate(_) => "funding_rate",
+            DataKind::BorrowRate(_) => "borrow_rate",
         }
     }
 }
@@ -220,3 +249,35 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, Liquidation>>
         value.map_kind(Liquidation::into)
     }
 }
+
+impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, MarkPrice>>
+    for MarketStreamResult<InstrumentKey, DataKind>
+{
+    fn from(value: MarketStreamResult<InstrumentKey, MarkPrice>) -> Self {
+        value.map_ok(MarketEvent::from)
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, MarkPrice>>
+    for MarketEvent<InstrumentKey, DataKind>
+{
+    fn from(value: MarketEvent<InstrumentKey, MarkPrice>) -> Self {
+        value.map_kind(MarkPrice::into)
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, FundingRate>>
+    for MarketEvent<InstrumentKey, DataKind>
+{
+    fn from(value: MarketEvent<InstrumentKey, FundingRate>) -> Self {
+        value.map_kind(FundingRate::into)
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, BorrowRate>>
+    for MarketEvent<InstrumentKey, DataKind>
+{
+    fn from(value: MarketEvent<InstrumentKey, BorrowRate>) -> Self {
+        value.map_kind(BorrowRate::into)
+    }
+}
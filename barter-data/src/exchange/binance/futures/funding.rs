@@ -0,0 +1,161 @@
+use crate::{event::MarketEvent, subscription::carry::FundingRate};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP historical funding rate url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#get-funding-rate-history>
+pub const HTTP_FUNDING_RATE_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/fundingRate";
+
+/// Maximum number of [`BinanceFundingRate`]s returned per funding rate history request.
+const HTTP_FUNDING_RATE_LIMIT: usize = 1000;
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) historical funding rate.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#get-funding-rate-history>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "fundingRate": "-0.03750000",
+///     "fundingTime": 1570608000000,
+///     "markPrice": "34287.54619963"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFundingRate {
+    pub symbol: String,
+    #[serde(
+        alias = "fundingRate",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub rate: f64,
+    #[serde(
+        alias = "fundingTime",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(InstrumentKey, BinanceFundingRate)>
+    for MarketEvent<InstrumentKey, FundingRate>
+{
+    fn from((instrument, funding): (InstrumentKey, BinanceFundingRate)) -> Self {
+        Self {
+            time_exchange: funding.time,
+            time_received: funding.time,
+            exchange: ExchangeId::BinanceFuturesUsd,
+            instrument,
+            kind: FundingRate {
+                rate: funding.rate,
+                time: funding.time,
+            },
+        }
+    }
+}
+
+/// Backfill the historical [`FundingRate`]s of a [`BinanceFuturesUsd`](super::BinanceFuturesUsd)
+/// market (eg/ "BTCUSDT") between the provided start and end times (inclusive).
+///
+/// Requests are paginated, so arbitrarily long time ranges can be backfilled.
+pub async fn fetch_funding_rates<InstrumentKey>(
+    market: &str,
+    instrument: InstrumentKey,
+    time_start: DateTime<Utc>,
+    time_end: DateTime<Utc>,
+) -> Result<Vec<MarketEvent<InstrumentKey, FundingRate>>, SocketError>
+where
+    InstrumentKey: Clone,
+{
+    let mut funding_rates = Vec::new();
+    let mut time_next = time_start;
+
+    while time_next <= time_end {
+        let url = format!(
+            "{}?symbol={}&startTime={}&endTime={}&limit={}",
+            HTTP_FUNDING_RATE_URL_BINANCE_FUTURES_USD,
+            market,
+            time_next.timestamp_millis(),
+            time_end.timestamp_millis(),
+            HTTP_FUNDING_RATE_LIMIT,
+        );
+
+        let page = reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<Vec<BinanceFundingRate>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        let Some(last) = page.last() else {
+            break;
+        };
+
+        let is_last_page = page.len() < HTTP_FUNDING_RATE_LIMIT;
+        time_next = last.time + TimeDelta::milliseconds(1);
+
+        funding_rates.extend(
+            page.into_iter()
+                .map(|funding| MarketEvent::from((instrument.clone(), funding))),
+        );
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(funding_rates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_funding_rates() {
+            let input = r#"
+            [
+                {
+                    "symbol": "BTCUSDT",
+                    "fundingRate": "-0.03750000",
+                    "fundingTime": 1570608000000,
+                    "markPrice": "34287.54619963"
+                },
+                {
+                    "symbol": "BTCUSDT",
+                    "fundingRate": "0.00010000",
+                    "fundingTime": 1570636800000
+                }
+            ]
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<BinanceFundingRate>>(input).unwrap(),
+                vec![
+                    BinanceFundingRate {
+                        symbol: "BTCUSDT".to_string(),
+                        rate: -0.0375,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1570608000000
+                        )),
+                    },
+                    BinanceFundingRate {
+                        symbol: "BTCUSDT".to_string(),
+                        rate: 0.0001,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1570636800000
+                        )),
+                    },
+                ]
+            );
+        }
+    }
+}
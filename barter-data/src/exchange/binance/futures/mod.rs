@@ -15,6 +15,9 @@ use crate::{
 use barter_instrument::exchange::ExchangeId;
 use std::fmt::{Display, Formatter};
 
+/// Historical funding rate types.
+pub mod funding;
+
 /// Level 2 OrderBook types.
 pub mod l2;
 
@@ -0,0 +1,257 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    streams::consumer::MarketStreamEvent,
+    subscription::candle::Candle,
+};
+use barter_instrument::exchange::ExchangeId;
+use fnv::FnvHashMap;
+use futures::Stream;
+use futures_util::StreamExt;
+use std::hash::Hash;
+
+/// Suppresses intra-candle updates from live feeds, only releasing a [`Candle`] once it is
+/// confirmed closed.
+///
+/// A [`Candle`] is confirmed closed when either:
+/// - The `MarketEvent` `time_exchange` has reached the [`Candle`] `close_time`.
+/// - An update for a later [`Candle`] of the same instrument is received, in which case the
+///   final update of the previous [`Candle`] is released.
+///
+/// Most indicator-based strategies misbehave when fed partial candles, so this is useful to
+/// apply to a live market `Stream` before it reaches the `Engine`.
+#[derive(Debug, Clone)]
+pub struct ClosedCandleFilter<InstrumentKey> {
+    pub pending: FnvHashMap<(ExchangeId, InstrumentKey), MarketEvent<InstrumentKey, Candle>>,
+}
+
+impl<InstrumentKey> Default for ClosedCandleFilter<InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            pending: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<InstrumentKey> ClosedCandleFilter<InstrumentKey>
+where
+    InstrumentKey: Eq + Hash + Clone,
+{
+    /// Process the next [`Candle`] update, returning the [`Candle`] `MarketEvent`s that were
+    /// confirmed closed as a result.
+    ///
+    /// Late updates for a [`Candle`] older than the pending [`Candle`] are discarded.
+    pub fn update(
+        &mut self,
+        event: MarketEvent<InstrumentKey, Candle>,
+    ) -> impl Iterator<Item = MarketEvent<InstrumentKey, Candle>> + use<InstrumentKey> {
+        let key = (event.exchange, event.instrument.clone());
+        let close_time = event.kind.close_time;
+
+        if self
+            .pending
+            .get(&key)
+            .is_some_and(|pending| pending.kind.close_time > close_time)
+        {
+            return None.into_iter().chain(None);
+        }
+
+        let (previous, closed) = if event.time_exchange >= close_time {
+            (self.pending.remove(&key), Some(event))
+        } else {
+            (self.pending.insert(key, event), None)
+        };
+
+        // Pending updates of the same Candle are superseded, whereas a pending earlier Candle
+        // is now confirmed closed
+        let previous = previous.filter(|previous| previous.kind.close_time < close_time);
+
+        previous.into_iter().chain(closed)
+    }
+
+    /// Filter the provided [`DataKind`] `MarketEvent`, forwarding all non-[`Candle`] events, and
+    /// only forwarding [`Candle`]s once confirmed closed (see [`Self::update`]).
+    pub fn filter(
+        &mut self,
+        event: MarketEvent<InstrumentKey, DataKind>,
+    ) -> impl Iterator<Item = MarketEvent<InstrumentKey, DataKind>> + use<InstrumentKey> {
+        let MarketEvent {
+            time_exchange,
+            time_received,
+            exchange,
+            instrument,
+            kind,
+        } = event;
+
+        let (other, closed) = match kind {
+            DataKind::Candle(candle) => {
+                let closed = self.update(MarketEvent {
+                    time_exchange,
+                    time_received,
+                    exchange,
+                    instrument,
+                    kind: candle,
+                });
+                (None, Some(closed.map(MarketEvent::from)))
+            }
+            kind => {
+                let other = MarketEvent {
+                    time_exchange,
+                    time_received,
+                    exchange,
+                    instrument,
+                    kind,
+                };
+                (Some(other), None)
+            }
+        };
+
+        other.into_iter().chain(closed.into_iter().flatten())
+    }
+}
+
+/// Extension for a `Stream` of [`DataKind`] [`MarketStreamEvent`]s that applies a
+/// [`ClosedCandleFilter`].
+pub trait ClosedCandleStream<InstrumentKey>
+where
+    Self: Stream<Item = MarketStreamEvent<InstrumentKey, DataKind>> + Sized,
+    InstrumentKey: Eq + Hash + Clone,
+{
+    /// Only forward [`Candle`] `MarketEvent`s once they are confirmed closed, suppressing
+    /// intra-candle updates (see [`ClosedCandleFilter`]).
+    ///
+    /// All other `MarketStreamEvent`s are forwarded unmodified.
+    fn with_closed_candles(self) -> impl Stream<Item = MarketStreamEvent<InstrumentKey, DataKind>> {
+        let mut filter = ClosedCandleFilter::default();
+
+        self.flat_map(move |event| {
+            let events = match event {
+                MarketStreamEvent::Item(event) => filter
+                    .filter(event)
+                    .map(MarketStreamEvent::Item)
+                    .collect::<Vec<_>>(),
+                reconnecting => vec![reconnecting],
+            };
+
+            futures::stream::iter(events)
+        })
+    }
+}
+
+impl<St, InstrumentKey> ClosedCandleStream<InstrumentKey> for St
+where
+    St: Stream<Item = MarketStreamEvent<InstrumentKey, DataKind>>,
+    InstrumentKey: Eq + Hash + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_instrument::Side;
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    fn candle(
+        time_exchange_secs: i64,
+        close_time_secs: i64,
+        close: f64,
+    ) -> MarketEvent<u64, Candle> {
+        let base = DateTime::<Utc>::MIN_UTC;
+        MarketEvent {
+            time_exchange: base + TimeDelta::seconds(time_exchange_secs),
+            time_received: base + TimeDelta::seconds(time_exchange_secs),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: 0,
+            kind: Candle {
+                close_time: base + TimeDelta::seconds(close_time_secs),
+                open: 1.0,
+                high: close,
+                low: 1.0,
+                close,
+                volume: 1.0,
+                trade_count: 1,
+            },
+        }
+    }
+
+    #[test]
+    fn test_closed_candle_filter_update() {
+        struct TestCase {
+            updates: Vec<MarketEvent<u64, Candle>>,
+            expected: Vec<MarketEvent<u64, Candle>>,
+        }
+
+        let cases = vec![
+            // TC0: intra-candle updates are suppressed until the Candle close_time is reached
+            TestCase {
+                updates: vec![
+                    candle(10, 60, 2.0),
+                    candle(30, 60, 3.0),
+                    candle(60, 60, 4.0),
+                ],
+                expected: vec![candle(60, 60, 4.0)],
+            },
+            // TC1: final update of previous Candle released when the next Candle starts
+            TestCase {
+                updates: vec![
+                    candle(10, 60, 2.0),
+                    candle(50, 60, 3.0),
+                    candle(70, 120, 5.0),
+                ],
+                expected: vec![candle(50, 60, 3.0)],
+            },
+            // TC2: pending previous Candle and final next Candle are both released
+            TestCase {
+                updates: vec![candle(50, 60, 3.0), candle(120, 120, 5.0)],
+                expected: vec![candle(50, 60, 3.0), candle(120, 120, 5.0)],
+            },
+            // TC3: late update for an older Candle is discarded
+            TestCase {
+                updates: vec![candle(70, 120, 5.0), candle(59, 60, 3.0)],
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut filter = ClosedCandleFilter::default();
+
+            let actual = test
+                .updates
+                .into_iter()
+                .flat_map(|update| filter.update(update).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_closed_candle_filter_filter() {
+        let mut filter = ClosedCandleFilter::default();
+
+        // Intra-candle update is suppressed
+        let partial = candle(0, 60, 1.0).map_kind(DataKind::Candle);
+        assert_eq!(filter.filter(partial).count(), 0);
+
+        // Non-candle event is forwarded unmodified
+        let trade = candle(10, 60, 1.0).map_kind(|_| {
+            DataKind::Trade(PublicTrade {
+                id: "id".to_string(),
+                price: 1.0,
+                amount: 1.0,
+                side: Side::Buy,
+            })
+        });
+        assert_eq!(
+            filter.filter(trade.clone()).collect::<Vec<_>>(),
+            vec![trade]
+        );
+
+        // Closed candle is forwarded
+        let closed = candle(60, 60, 2.0).map_kind(DataKind::Candle);
+        assert_eq!(
+            filter.filter(closed.clone()).collect::<Vec<_>>(),
+            vec![closed]
+        );
+    }
+}
@@ -9,6 +9,11 @@ use futures::Stream;
 /// [`MarketStream`](super::MarketStream) [`Streams`].
 pub mod builder;
 
+/// Defines a [`ClosedCandleFilter`](candle::ClosedCandleFilter) and associated `Stream`
+/// extension for only forwarding [`Candle`](crate::subscription::candle::Candle)s once they are
+/// confirmed closed.
+pub mod candle;
+
 /// Central consumer loop functionality used by the [`StreamBuilder`] to
 /// drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Normalised Barter perpetual [`FundingRate`] model.
+///
+/// The funding rate is exchanged between LONG and SHORT positions at the funding `time`. A
+/// positive rate means LONG positions pay SHORT positions.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: f64,
+    pub time: DateTime<Utc>,
+}
+
+/// Normalised Barter margin [`BorrowRate`] model.
+///
+/// Annualised rate (eg/ 0.05 for 5%) charged for borrowing an asset, applicable from `time`
+/// until the next `BorrowRate`.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BorrowRate {
+    pub rate_annual: f64,
+    pub time: DateTime<Utc>,
+}
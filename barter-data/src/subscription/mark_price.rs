@@ -0,0 +1,34 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`MarkPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct MarkPrices;
+
+impl SubscriptionKind for MarkPrices {
+    type Event = MarkPrice;
+
+    fn as_str(&self) -> &'static str {
+        "mark_prices"
+    }
+}
+
+impl std::fmt::Display for MarkPrices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Normalised Barter derivative [`MarkPrice`] model.
+///
+/// The mark price is the fair price used by derivative exchanges to value positions, and is
+/// less susceptible to manipulation than the last traded price.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarkPrice {
+    pub price: f64,
+    pub time: DateTime<Utc>,
+}
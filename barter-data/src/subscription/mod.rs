@@ -20,9 +20,16 @@ pub mod book;
 /// Candle [`SubscriptionKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Historical funding rate and borrow rate Barter data models, used to model the carry costs of
+/// perpetual and margin positions (eg/ in backtests).
+pub mod carry;
+
 /// Liquidation [`SubscriptionKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Derivative mark price [`SubscriptionKind`] and the associated Barter output data model.
+pub mod mark_price;
+
 /// Public trade [`SubscriptionKind`] and the associated Barter output data model.
 pub mod trade;
 
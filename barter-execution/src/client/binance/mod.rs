@@ -1 +1,197 @@
+use crate::{
+    UnindexedAccountEvent,
+    client::{
+        binance::user_data::BinanceUserDataEvent,
+        keepalive::{KeepaliveConfig, ListenKeyProvider, init_keepalive_account_stream},
+    },
+    error::{ConnectivityError, UnindexedClientError},
+    sub_account::SubAccountId,
+};
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WsMessage, connect},
+};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tracing::{debug, warn};
 
+/// Binance Spot user-data stream event models, and their mapping into normalised
+/// [`UnindexedAccountEvent`]s.
+pub mod user_data;
+
+/// Default Binance Spot REST API base url.
+pub const BINANCE_SPOT_BASE_URL_REST: &str = "https://api.binance.com";
+
+/// Default Binance Spot user-data WebSocket base url.
+pub const BINANCE_SPOT_BASE_URL_WS: &str = "wss://stream.binance.com:9443";
+
+/// Configuration of a Binance Spot user-data `AccountStream`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct BinanceUserDataConfig {
+    pub api_key: String,
+    pub base_url_rest: String,
+    pub base_url_ws: String,
+    pub keepalive: KeepaliveConfig,
+
+    /// Binance sub-account the `api_key` belongs to, used to attribute the generated
+    /// [`UnindexedAccountEvent`]s. `None` for the master account.
+    #[serde(default)]
+    pub sub_account: Option<SubAccountId>,
+}
+
+impl BinanceUserDataConfig {
+    /// Construct a new `BinanceUserDataConfig` using the default Binance Spot urls & listen key
+    /// [`KeepaliveConfig`].
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            base_url_rest: BINANCE_SPOT_BASE_URL_REST.to_string(),
+            base_url_ws: BINANCE_SPOT_BASE_URL_WS.to_string(),
+            keepalive: KeepaliveConfig::default(),
+            sub_account: None,
+        }
+    }
+
+    /// Attribute the generated [`UnindexedAccountEvent`]s to the provided Binance sub-account.
+    pub fn with_sub_account(self, sub_account: SubAccountId) -> Self {
+        Self {
+            sub_account: Some(sub_account),
+            ..self
+        }
+    }
+}
+
+/// Binance Spot [`ListenKeyProvider`] that creates & keeps alive user-data stream listen keys
+/// via the REST API.
+#[derive(Debug, Clone)]
+pub struct BinanceListenKeyProvider {
+    http: reqwest::Client,
+    url: String,
+    api_key: String,
+}
+
+impl BinanceListenKeyProvider {
+    pub fn new(api_key: String, base_url_rest: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: format!("{base_url_rest}/api/v3/userDataStream"),
+            api_key,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BinanceListenKey {
+    #[serde(rename = "listenKey")]
+    listen_key: String,
+}
+
+impl ListenKeyProvider for BinanceListenKeyProvider {
+    type Key = String;
+
+    fn create_listen_key(
+        &self,
+    ) -> impl Future<Output = Result<Self::Key, UnindexedClientError>> + Send {
+        let request = self
+            .http
+            .post(&self.url)
+            .header("X-MBX-APIKEY", &self.api_key);
+
+        async move {
+            let response = request
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(connectivity_error)?;
+
+            response
+                .json::<BinanceListenKey>()
+                .await
+                .map(|key| key.listen_key)
+                .map_err(connectivity_error)
+        }
+    }
+
+    fn keepalive_listen_key(
+        &self,
+        key: &Self::Key,
+    ) -> impl Future<Output = Result<(), UnindexedClientError>> + Send {
+        let request = self
+            .http
+            .put(&self.url)
+            .header("X-MBX-APIKEY", &self.api_key)
+            .query(&[("listenKey", key)]);
+
+        async move {
+            request
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map(|_| ())
+                .map_err(connectivity_error)
+        }
+    }
+}
+
+fn connectivity_error(error: reqwest::Error) -> UnindexedClientError {
+    ConnectivityError::from(SocketError::from(error)).into()
+}
+
+/// Initialise a Binance Spot user-data `AccountStream` of normalised
+/// [`UnindexedAccountEvent`]s.
+///
+/// The listen key lifecycle is managed by [`init_keepalive_account_stream`], transparently
+/// reconnecting the WebSocket with a new listen key if the current one expires.
+///
+/// The returned `Stream` ends if the WebSocket disconnects, leaving reconnection to the consumer
+/// (eg/ a `ReconnectingStream`).
+pub fn init_user_data_stream(
+    config: BinanceUserDataConfig,
+) -> impl Stream<Item = UnindexedAccountEvent> {
+    let provider = BinanceListenKeyProvider::new(config.api_key, &config.base_url_rest);
+    let base_url_ws = config.base_url_ws;
+    let sub_account = config.sub_account;
+
+    init_keepalive_account_stream(provider, config.keepalive, move |listen_key: String| {
+        let url = format!("{base_url_ws}/ws/{listen_key}");
+        let sub_account = sub_account.clone();
+
+        async move {
+            let websocket = connect(url)
+                .await
+                .map_err(|error| UnindexedClientError::from(ConnectivityError::from(error)))?;
+
+            let events = websocket
+                .take_while(|message| {
+                    if let Err(error) = message {
+                        warn!(?error, "Binance user-data WebSocket disconnected");
+                    }
+                    std::future::ready(message.is_ok())
+                })
+                .filter_map(|message| {
+                    std::future::ready(message.ok().and_then(parse_user_data_message))
+                })
+                .flat_map(|event| futures::stream::iter(event.into_account_events()))
+                .map(move |event| event.with_sub_account(sub_account.clone()));
+
+            Ok(Box::pin(events))
+        }
+    })
+}
+
+fn parse_user_data_message(message: WsMessage) -> Option<BinanceUserDataEvent> {
+    let WsMessage::Text(text) = message else {
+        debug!(
+            ?message,
+            "Binance user-data WebSocket received non-text message"
+        );
+        return None;
+    };
+
+    serde_json::from_str(text.as_str())
+        .inspect_err(|error| {
+            debug!(?error, payload = %text.as_str(), "failed to deserialise Binance user-data event")
+        })
+        .ok()
+}
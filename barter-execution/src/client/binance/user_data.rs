@@ -0,0 +1,502 @@
+use crate::{
+    AccountEventKind, UnindexedAccountEvent,
+    balance::{AssetBalance, Balance},
+    error::{ApiError, OrderError},
+    order::{
+        Order, OrderKey, OrderKind, StopTrigger, TimeInForce, UnindexedOrderSnapshot,
+        id::{ClientOrderId, OrderId, StrategyId},
+        state::{
+            ActiveOrderState, CancelInFlight, Cancelled, InactiveOrderState, Open, OrderState,
+        },
+    },
+    trade::{AssetFees, Liquidity, Trade, TradeId},
+};
+use barter_instrument::{
+    Side,
+    asset::{QuoteAsset, name::AssetNameExchange},
+    exchange::ExchangeId,
+    instrument::name::InstrumentNameExchange,
+};
+use barter_integration::{
+    de::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    snapshot::Snapshot,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Binance Spot user-data stream event.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/user-data-stream>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceUserDataEvent {
+    #[serde(rename = "executionReport")]
+    ExecutionReport(Box<BinanceExecutionReport>),
+
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(BinanceAccountPosition),
+
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BinanceBalanceUpdate),
+}
+
+/// Binance Spot order update, including any fill (ie/ `execution_type` is `TRADE`).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BinanceExecutionReport {
+    #[serde(rename = "s")]
+    pub symbol: InstrumentNameExchange,
+
+    #[serde(rename = "c")]
+    pub cid: ClientOrderId,
+
+    /// [`ClientOrderId`] of the original order if this report is for a cancellation.
+    #[serde(rename = "C", default)]
+    pub cid_original: Option<ClientOrderId>,
+
+    #[serde(rename = "S")]
+    pub side: Side,
+
+    #[serde(rename = "o")]
+    pub kind: BinanceOrderType,
+
+    #[serde(rename = "f")]
+    pub time_in_force: BinanceTimeInForce,
+
+    #[serde(rename = "q", deserialize_with = "de_str")]
+    pub quantity: Decimal,
+
+    #[serde(rename = "p", deserialize_with = "de_str")]
+    pub price: Decimal,
+
+    #[serde(rename = "P", deserialize_with = "de_str")]
+    pub price_stop: Decimal,
+
+    #[serde(rename = "F", deserialize_with = "de_str")]
+    pub quantity_iceberg: Decimal,
+
+    #[serde(rename = "x")]
+    pub execution_type: BinanceExecutionType,
+
+    #[serde(rename = "X")]
+    pub status: BinanceOrderStatus,
+
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub last_quantity: Decimal,
+
+    #[serde(rename = "z", deserialize_with = "de_str")]
+    pub filled_quantity: Decimal,
+
+    #[serde(rename = "L", deserialize_with = "de_str")]
+    pub last_price: Decimal,
+
+    #[serde(rename = "n", deserialize_with = "de_str")]
+    pub commission: Decimal,
+
+    /// Asset the `commission` is charged in (eg/ base asset for buys, or BNB if the BNB fee
+    /// discount is enabled), `None` if no commission was charged.
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<AssetNameExchange>,
+
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time_exchange: DateTime<Utc>,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceOrderType {
+    Limit,
+    Market,
+    LimitMaker,
+    StopLoss,
+    StopLossLimit,
+    TakeProfit,
+    TakeProfitLimit,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum BinanceTimeInForce {
+    #[serde(rename = "GTC")]
+    GoodUntilCancelled,
+    #[serde(rename = "IOC")]
+    ImmediateOrCancel,
+    #[serde(rename = "FOK")]
+    FillOrKill,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceExecutionType {
+    New,
+    Canceled,
+    Replaced,
+    Rejected,
+    Trade,
+    Expired,
+    TradePrevention,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceOrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+}
+
+/// Binance Spot account balances that changed, sent after any balance change.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BinanceAccountPosition {
+    #[serde(rename = "u", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time_exchange: DateTime<Utc>,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceBalance>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BinanceBalance {
+    #[serde(rename = "a")]
+    pub asset: AssetNameExchange,
+
+    #[serde(rename = "f", deserialize_with = "de_str")]
+    pub free: Decimal,
+
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub locked: Decimal,
+}
+
+/// Binance Spot balance delta from a deposit, withdrawal, or transfer.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BinanceBalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: AssetNameExchange,
+
+    #[serde(rename = "d", deserialize_with = "de_str")]
+    pub delta: Decimal,
+
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time_exchange: DateTime<Utc>,
+}
+
+impl BinanceUserDataEvent {
+    /// Map the `BinanceUserDataEvent` into normalised [`UnindexedAccountEvent`]s.
+    ///
+    /// A [`BinanceBalanceUpdate`] delta generates no events, since Binance follows every balance
+    /// change with a [`BinanceAccountPosition`] containing the updated balance snapshots.
+    pub fn into_account_events(self) -> Vec<UnindexedAccountEvent> {
+        let account_event =
+            |kind: AccountEventKind<ExchangeId, AssetNameExchange, InstrumentNameExchange>| {
+                UnindexedAccountEvent::new(ExchangeId::BinanceSpot, kind)
+            };
+
+        match self {
+            Self::ExecutionReport(report) => {
+                let trade = report.trade();
+                std::iter::once(account_event(Snapshot(report.into_order_snapshot()).into()))
+                    .chain(trade.map(|trade| account_event(trade.into())))
+                    .collect()
+            }
+            Self::AccountPosition(position) => position
+                .balances
+                .into_iter()
+                .map(|balance| {
+                    account_event(
+                        Snapshot(AssetBalance::new(
+                            balance.asset,
+                            Balance::new(balance.free + balance.locked, balance.free),
+                            position.time_exchange,
+                        ))
+                        .into(),
+                    )
+                })
+                .collect(),
+            Self::BalanceUpdate(update) => {
+                debug!(
+                    ?update,
+                    "Binance balanceUpdate awaiting outboundAccountPosition"
+                );
+                vec![]
+            }
+        }
+    }
+}
+
+impl BinanceExecutionReport {
+    /// Generate the [`Trade`] associated with this report, if it represents a fill.
+    ///
+    /// See [`Self::fees_quote`] for how the commission is denominated in the quote asset.
+    pub fn trade(&self) -> Option<Trade<QuoteAsset, InstrumentNameExchange>> {
+        (self.execution_type == BinanceExecutionType::Trade).then(|| Trade {
+            id: TradeId::new(self.trade_id.to_string()),
+            order_id: OrderId::new(self.order_id.to_string()),
+            cid: Some(self.cid.clone()),
+            instrument: self.symbol.clone(),
+            strategy: StrategyId::unknown(),
+            time_exchange: self.time_exchange,
+            side: self.side,
+            price: self.last_price,
+            quantity: self.last_quantity,
+            liquidity: Some(if self.is_maker {
+                Liquidity::Maker
+            } else {
+                Liquidity::Taker
+            }),
+            fees: AssetFees {
+                asset: QuoteAsset,
+                fees: self.fees_quote(),
+            },
+        })
+    }
+
+    /// Commission denominated in the quote asset of the Binance Spot `symbol` (ie/ base asset
+    /// followed by quote asset).
+    ///
+    /// Commission charged in the base asset is converted at the fill price. Commission charged
+    /// in any other asset (eg/ BNB) cannot be converted, so it is logged and excluded from the
+    /// quote asset fees (the balance itself is corrected by the following
+    /// [`BinanceAccountPosition`]).
+    pub fn fees_quote(&self) -> Decimal {
+        let symbol = self.symbol.name().as_str();
+        match &self.commission_asset {
+            _ if self.commission.is_zero() => Decimal::ZERO,
+            Some(asset) if symbol.ends_with(asset.name().as_str()) => self.commission,
+            Some(asset) if symbol.starts_with(asset.name().as_str()) => {
+                self.commission * self.last_price
+            }
+            asset => {
+                warn!(
+                    symbol,
+                    ?asset,
+                    commission = %self.commission,
+                    "Binance fill commission charged in neither base nor quote asset"
+                );
+                Decimal::ZERO
+            }
+        }
+    }
+
+    /// Map the `BinanceExecutionReport` into a normalised [`UnindexedOrderSnapshot`].
+    pub fn into_order_snapshot(self) -> UnindexedOrderSnapshot {
+        let id = OrderId::new(self.order_id.to_string());
+        let open = || Open::new(id.clone(), self.time_exchange, self.filled_quantity);
+
+        let state = match self.status {
+            BinanceOrderStatus::New | BinanceOrderStatus::PartiallyFilled => {
+                OrderState::Active(ActiveOrderState::Open(open()))
+            }
+            BinanceOrderStatus::PendingCancel => OrderState::Active(
+                ActiveOrderState::CancelInFlight(CancelInFlight::new(Some(open()))),
+            ),
+            BinanceOrderStatus::Filled => OrderState::Inactive(InactiveOrderState::FullyFilled),
+            BinanceOrderStatus::Canceled => OrderState::Inactive(InactiveOrderState::Cancelled(
+                Cancelled::new(id.clone(), self.time_exchange),
+            )),
+            BinanceOrderStatus::Rejected => OrderState::Inactive(InactiveOrderState::OpenFailed(
+                OrderError::Rejected(ApiError::OrderRejected(self.reject_reason.clone())),
+            )),
+            BinanceOrderStatus::Expired | BinanceOrderStatus::ExpiredInMatch => {
+                OrderState::Inactive(InactiveOrderState::Expired)
+            }
+        };
+
+        let kind = match self.kind {
+            BinanceOrderType::Limit | BinanceOrderType::LimitMaker
+                if self.quantity_iceberg > Decimal::ZERO =>
+            {
+                OrderKind::Iceberg {
+                    display_quantity: self.quantity_iceberg,
+                }
+            }
+            BinanceOrderType::Limit | BinanceOrderType::LimitMaker => OrderKind::Limit,
+            BinanceOrderType::Market => OrderKind::Market,
+            BinanceOrderType::StopLoss | BinanceOrderType::TakeProfit => OrderKind::StopMarket {
+                trigger_price: self.price_stop,
+                trigger: StopTrigger::LastPrice,
+            },
+            BinanceOrderType::StopLossLimit | BinanceOrderType::TakeProfitLimit => {
+                OrderKind::StopLimit {
+                    trigger_price: self.price_stop,
+                    trigger: StopTrigger::LastPrice,
+                }
+            }
+        };
+
+        let time_in_force = match self.time_in_force {
+            BinanceTimeInForce::GoodUntilCancelled => TimeInForce::GoodUntilCancelled {
+                post_only: self.kind == BinanceOrderType::LimitMaker,
+            },
+            BinanceTimeInForce::ImmediateOrCancel => TimeInForce::ImmediateOrCancel,
+            BinanceTimeInForce::FillOrKill => TimeInForce::FillOrKill,
+        };
+
+        // Cancellation reports identify the cancelled order via the original ClientOrderId
+        let cid = match (self.execution_type, self.cid_original) {
+            (BinanceExecutionType::Canceled, Some(cid)) if !cid.0.is_empty() => cid,
+            _ => self.cid,
+        };
+
+        Order {
+            key: OrderKey::new(
+                ExchangeId::BinanceSpot,
+                self.symbol,
+                StrategyId::unknown(),
+                cid,
+            ),
+            side: self.side,
+            price: self.price,
+            quantity: self.quantity,
+            kind,
+            time_in_force,
+            reduce_only: false,
+            state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_de_binance_user_data_event() {
+        let input = r#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","c":"mUvoqJxFIILMdfAW5iGSOW","S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000","p":"0.10264410","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"TRADE","X":"PARTIALLY_FILLED","r":"NONE","i":4293153,"l":"0.40000000","z":"0.40000000","L":"0.10264400","n":"0.00004105","N":"BTC","T":1499405658657,"t":7,"I":8641984,"w":false,"m":true,"M":true,"O":1499405658657,"Z":"0.04105760","Y":"0.04105760","Q":"0.00000000"}"#;
+
+        let time_exchange = datetime_utc_from_epoch_duration(Duration::from_millis(1499405658657));
+        let actual = serde_json::from_str::<BinanceUserDataEvent>(input)
+            .unwrap()
+            .into_account_events();
+
+        let expected_order = Order {
+            key: OrderKey::new(
+                ExchangeId::BinanceSpot,
+                InstrumentNameExchange::new("ETHBTC"),
+                StrategyId::unknown(),
+                ClientOrderId::new("mUvoqJxFIILMdfAW5iGSOW"),
+            ),
+            side: Side::Buy,
+            price: Decimal::new(10264410, 8),
+            quantity: Decimal::new(100000000, 8),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
+            state: OrderState::Active(ActiveOrderState::Open(Open::new(
+                OrderId::new("4293153"),
+                time_exchange,
+                Decimal::new(40000000, 8),
+            ))),
+        };
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(
+            actual[0].kind,
+            AccountEventKind::OrderSnapshot(Snapshot(expected_order))
+        );
+
+        let AccountEventKind::Trade(trade) = &actual[1].kind else {
+            panic!("expected Trade, got: {:?}", actual[1].kind);
+        };
+        assert_eq!(trade.price, Decimal::new(10264400, 8));
+        assert_eq!(trade.quantity, Decimal::new(40000000, 8));
+        assert_eq!(trade.fees.fees, Decimal::new(4105, 8));
+        assert_eq!(trade.liquidity, Some(Liquidity::Maker));
+    }
+
+    #[test]
+    fn test_binance_execution_report_fees_quote() {
+        struct TestCase {
+            side: &'static str,
+            commission: &'static str,
+            commission_asset: &'static str,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: sell fill commission charged in quote asset
+            TestCase {
+                side: "SELL",
+                commission: "\"0.1\"",
+                commission_asset: "\"USDT\"",
+                expected: Decimal::new(1, 1),
+            },
+            // TC1: buy fill commission charged in base asset is converted at the fill price
+            TestCase {
+                side: "BUY",
+                commission: "\"0.001\"",
+                commission_asset: "\"ETH\"",
+                expected: Decimal::new(2, 0),
+            },
+            // TC2: BNB discounted commission cannot be converted
+            TestCase {
+                side: "BUY",
+                commission: "\"0.01\"",
+                commission_asset: "\"BNB\"",
+                expected: Decimal::ZERO,
+            },
+            // TC3: no commission charged
+            TestCase {
+                side: "BUY",
+                commission: "\"0\"",
+                commission_asset: "null",
+                expected: Decimal::ZERO,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = format!(
+                r#"{{"e":"executionReport","E":1499405658658,"s":"ETHUSDT","c":"cid","S":"{}","o":"MARKET","f":"GTC","q":"1.00000000","p":"0.00000000","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"TRADE","X":"FILLED","r":"NONE","i":1,"l":"1.00000000","z":"1.00000000","L":"2000.00000000","n":{},"N":{},"T":1499405658657,"t":7,"I":8641984,"w":false,"m":false,"M":true,"O":1499405658657,"Z":"2000.00000000","Y":"2000.00000000","Q":"0.00000000"}}"#,
+                test.side, test.commission, test.commission_asset
+            );
+
+            let BinanceUserDataEvent::ExecutionReport(report) =
+                serde_json::from_str::<BinanceUserDataEvent>(&input).unwrap()
+            else {
+                panic!("TC{index} failed: expected ExecutionReport");
+            };
+
+            let trade = report.trade().unwrap();
+            assert_eq!(trade.fees.fees, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_de_binance_account_position() {
+        let input = r#"{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"ETH","f":"10000.000000","l":"5.000000"}]}"#;
+
+        let actual = serde_json::from_str::<BinanceUserDataEvent>(input)
+            .unwrap()
+            .into_account_events();
+
+        assert_eq!(
+            actual,
+            vec![UnindexedAccountEvent::new(
+                ExchangeId::BinanceSpot,
+                Snapshot(AssetBalance::new(
+                    AssetNameExchange::new("ETH"),
+                    Balance::new(Decimal::new(10005, 0), Decimal::new(10000, 0)),
+                    datetime_utc_from_epoch_duration(Duration::from_millis(1564034571073)),
+                )),
+            )]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::error::UnindexedClientError;
+use derive_more::Constructor;
+use futures::{Stream, StreamExt, future::Either};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, future::Future, time::Duration};
+use tokio::time::{Instant, Interval, MissedTickBehavior, interval_at};
+use tracing::{error, info, warn};
+
+/// Default interval between listen key keepalives (eg/ Binance expires listen keys after 60
+/// minutes without a keepalive, and recommends a keepalive every 30 minutes).
+pub const DEFAULT_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Exchange user-data stream session that must be created, and periodically kept alive, in order
+/// to keep receiving `AccountStream` updates.
+///
+/// eg/ Binance listen keys, which require a `PUT` keepalive every 30 minutes.
+pub trait ListenKeyProvider {
+    type Key: Debug + Clone + Send;
+
+    /// Create a new listen key (or session) for a user-data stream.
+    fn create_listen_key(
+        &self,
+    ) -> impl Future<Output = Result<Self::Key, UnindexedClientError>> + Send;
+
+    /// Extend the validity of the provided listen key.
+    ///
+    /// An `Err` indicates the listen key has expired and must be re-created.
+    fn keepalive_listen_key(
+        &self,
+        key: &Self::Key,
+    ) -> impl Future<Output = Result<(), UnindexedClientError>> + Send;
+}
+
+/// Configuration of a listen key keepalive `AccountStream`.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct KeepaliveConfig {
+    /// Interval between listen key keepalives.
+    pub interval: Duration,
+}
+
+impl Default for KeepaliveConfig {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_KEEPALIVE_INTERVAL,
+        }
+    }
+}
+
+struct KeepaliveState<Provider, FnInit, Key, St> {
+    provider: Provider,
+    init: FnInit,
+    config: KeepaliveConfig,
+    active: Option<(Key, St, Interval)>,
+}
+
+/// Initialise an `AccountStream` that keeps its listen key alive using the provided
+/// [`ListenKeyProvider`].
+///
+/// A listen key is created and passed to `init` to construct the inner `AccountStream`. The
+/// listen key is then kept alive every [`KeepaliveConfig::interval`]. If a keepalive fails (ie/
+/// the listen key has expired), a new listen key is created and the inner `AccountStream` is
+/// transparently rebuilt.
+///
+/// The returned `Stream` ends if the inner `AccountStream` ends, or a listen key or inner
+/// `AccountStream` cannot be created, leaving reconnection to the consumer (eg/ a
+/// `ReconnectingStream`).
+pub fn init_keepalive_account_stream<Provider, FnInit, Fut, St>(
+    provider: Provider,
+    config: KeepaliveConfig,
+    init: FnInit,
+) -> impl Stream<Item = St::Item>
+where
+    Provider: ListenKeyProvider,
+    FnInit: FnMut(Provider::Key) -> Fut,
+    Fut: Future<Output = Result<St, UnindexedClientError>>,
+    St: Stream + Unpin,
+{
+    let state = KeepaliveState {
+        provider,
+        init,
+        config,
+        active: None,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.active.is_none() {
+                let key = match state.provider.create_listen_key().await {
+                    Ok(key) => key,
+                    Err(error) => {
+                        error!(?error, "AccountStream failed to create listen key");
+                        return None;
+                    }
+                };
+
+                let stream = match (state.init)(key.clone()).await {
+                    Ok(stream) => stream,
+                    Err(error) => {
+                        error!(?key, ?error, "AccountStream failed to initialise");
+                        return None;
+                    }
+                };
+
+                info!(?key, "AccountStream initialised with listen key");
+
+                let period = state.config.interval;
+                let mut keepalive = interval_at(Instant::now() + period, period);
+                keepalive.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+                state.active = Some((key, stream, keepalive));
+            }
+
+            let (key, stream, keepalive) = state
+                .active
+                .as_mut()
+                .expect("AccountStream listen key session initialised above");
+
+            let next = tokio::select! {
+                event = stream.next() => Either::Left(event),
+                _ = keepalive.tick() => Either::Right(()),
+            };
+
+            match next {
+                Either::Left(Some(event)) => return Some((event, state)),
+                Either::Left(None) => return None,
+                Either::Right(()) => {
+                    if let Err(error) = state.provider.keepalive_listen_key(key).await {
+                        warn!(
+                            ?key,
+                            ?error,
+                            "AccountStream listen key keepalive failed - rebuilding AccountStream"
+                        );
+                        state.active = None;
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ConnectivityError;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[derive(Debug)]
+    struct TestProvider {
+        created: AtomicU64,
+        keepalive_ok: bool,
+    }
+
+    impl ListenKeyProvider for TestProvider {
+        type Key = u64;
+
+        fn create_listen_key(
+            &self,
+        ) -> impl Future<Output = Result<Self::Key, UnindexedClientError>> + Send {
+            std::future::ready(Ok(self.created.fetch_add(1, Ordering::Relaxed) + 1))
+        }
+
+        fn keepalive_listen_key(
+            &self,
+            _: &Self::Key,
+        ) -> impl Future<Output = Result<(), UnindexedClientError>> + Send {
+            std::future::ready(if self.keepalive_ok {
+                Ok(())
+            } else {
+                Err(ConnectivityError::Timeout.into())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_account_stream_rebuilds_on_expiry() {
+        let provider = TestProvider {
+            created: AtomicU64::new(0),
+            keepalive_ok: false,
+        };
+
+        // Each inner AccountStream yields its listen key, then remains pending
+        let actual = init_keepalive_account_stream(
+            provider,
+            KeepaliveConfig::new(Duration::from_millis(5)),
+            |key| async move { Ok(futures::stream::iter([key]).chain(futures::stream::pending())) },
+        )
+        .take(3)
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(actual, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_account_stream_ends_with_inner_stream() {
+        let provider = TestProvider {
+            created: AtomicU64::new(0),
+            keepalive_ok: true,
+        };
+
+        let actual =
+            init_keepalive_account_stream(provider, KeepaliveConfig::default(), |key| async move {
+                Ok(futures::stream::iter([key, key]))
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(actual, vec![1, 1]);
+    }
+}
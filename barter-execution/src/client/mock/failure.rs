@@ -0,0 +1,156 @@
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Schedule of failures injected by the [`MockExecution`](super::MockExecution) client.
+///
+/// Enables integration tests for error-handling and reconciliation logic. Each `*_every`
+/// schedule applies to every `n`th occurrence (eg/ `Some(3)` applies to the 3rd, 6th, 9th, etc.),
+/// and `None` (the default) disables it.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+#[serde(default)]
+pub struct MockFailureConfig {
+    /// Reject every `n`th open order request.
+    pub reject_open_every: Option<u64>,
+
+    /// Delay the acknowledgement of every `n`th open or cancel order request by `ack_delay_ms`.
+    pub delay_ack_every: Option<u64>,
+
+    /// Milliseconds that scheduled delayed acknowledgements are delayed by.
+    pub ack_delay_ms: u64,
+
+    /// Duplicate every `n`th account stream event.
+    pub duplicate_event_every: Option<u64>,
+
+    /// Drop every `n`th account stream event, simulating dropped websocket messages.
+    pub drop_event_every: Option<u64>,
+}
+
+/// Tracks progress through a [`MockFailureConfig`] schedule, shared between all clones of a
+/// [`MockExecution`](super::MockExecution) client.
+#[derive(Debug, Default)]
+pub struct MockFailureSchedule {
+    pub config: MockFailureConfig,
+    pub opens: AtomicU64,
+    pub acks: AtomicU64,
+}
+
+impl MockFailureSchedule {
+    /// Construct a new `MockFailureSchedule` from the provided [`MockFailureConfig`].
+    pub fn new(config: MockFailureConfig) -> Self {
+        Self {
+            config,
+            opens: AtomicU64::new(0),
+            acks: AtomicU64::new(0),
+        }
+    }
+
+    /// Record an open order request, returning true if it should be rejected.
+    pub fn next_open_rejected(&self) -> bool {
+        let count = self.opens.fetch_add(1, Ordering::Relaxed) + 1;
+        is_scheduled(self.config.reject_open_every, count)
+    }
+
+    /// Record an order request acknowledgement, returning the delay to apply (if any).
+    pub fn next_ack_delay(&self) -> Option<Duration> {
+        let count = self.acks.fetch_add(1, Ordering::Relaxed) + 1;
+        is_scheduled(self.config.delay_ack_every, count)
+            .then(|| Duration::from_millis(self.config.ack_delay_ms))
+    }
+}
+
+/// Returns true if the `count`th (1-based) occurrence is scheduled by the `every` schedule.
+pub fn is_scheduled(every: Option<u64>, count: u64) -> bool {
+    every.is_some_and(|every| every > 0 && count.is_multiple_of(every))
+}
+
+/// Apply the [`MockFailureConfig`] account stream event duplication and drop schedules to the
+/// provided `Stream`.
+///
+/// If an event is scheduled to be both dropped and duplicated, it is dropped.
+pub fn inject_stream_failures<St>(
+    config: MockFailureConfig,
+    stream: St,
+) -> impl Stream<Item = St::Item>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    let mut count = 0;
+    stream.flat_map(move |event| {
+        count += 1;
+
+        let events = if is_scheduled(config.drop_event_every, count) {
+            vec![]
+        } else if is_scheduled(config.duplicate_event_every, count) {
+            vec![event.clone(), event]
+        } else {
+            vec![event]
+        };
+
+        futures::stream::iter(events)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_scheduled() {
+        struct TestCase {
+            every: Option<u64>,
+            expected: Vec<bool>,
+        }
+
+        let cases = vec![
+            // TC0: disabled schedule
+            TestCase {
+                every: None,
+                expected: vec![false, false, false],
+            },
+            // TC1: zero schedule is disabled
+            TestCase {
+                every: Some(0),
+                expected: vec![false, false, false],
+            },
+            // TC2: every occurrence
+            TestCase {
+                every: Some(1),
+                expected: vec![true, true, true],
+            },
+            // TC3: every third occurrence
+            TestCase {
+                every: Some(3),
+                expected: vec![false, false, true, false, false, true],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = (1..=test.expected.len() as u64)
+                .map(|count| is_scheduled(test.every, count))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inject_stream_failures() {
+        let config = MockFailureConfig {
+            duplicate_event_every: Some(2),
+            drop_event_every: Some(3),
+            ..Default::default()
+        };
+
+        let actual = inject_stream_failures(config, futures::stream::iter(1..=6))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(actual, vec![1, 2, 2, 4, 4, 5]);
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
     UnindexedAccountEvent, UnindexedAccountSnapshot,
     balance::AssetBalance,
-    client::ExecutionClient,
-    error::{ConnectivityError, UnindexedClientError, UnindexedOrderError},
-    exchange::mock::request::MockExchangeRequest,
+    client::{
+        ExecutionClient,
+        mock::failure::{MockFailureConfig, MockFailureSchedule, inject_stream_failures},
+    },
+    error::{ApiError, ConnectivityError, UnindexedClientError, UnindexedOrderError},
+    exchange::mock::{request::MockExchangeRequest, slippage::SlippageConfig},
+    margin::MarginMode,
     order::{
         Order, OrderEvent, OrderKey,
         request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
@@ -12,18 +16,24 @@ use crate::{
     trade::Trade,
 };
 use barter_instrument::{
+    Keyed,
     asset::{QuoteAsset, name::AssetNameExchange},
     exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    instrument::{name::InstrumentNameExchange, spec::InstrumentSpec},
 };
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use futures::stream::BoxStream;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
-use tracing::error;
+use tracing::{error, warn};
+
+/// Configurable failure injection (eg/ rejections, delayed acks, dropped events) for the
+/// [`MockExecution`] client.
+pub mod failure;
 
 #[derive(
     Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
@@ -33,6 +43,10 @@ pub struct MockExecutionConfig {
     pub initial_state: UnindexedAccountSnapshot,
     pub latency_ms: u64,
     pub fees_percent: Decimal,
+    #[serde(default)]
+    pub slippage: SlippageConfig,
+    #[serde(default)]
+    pub failures: MockFailureConfig,
 }
 
 #[derive(Debug, Constructor)]
@@ -41,6 +55,7 @@ pub struct MockExecutionClientConfig<FnTime> {
     pub clock: FnTime,
     pub request_tx: mpsc::UnboundedSender<MockExchangeRequest>,
     pub event_rx: broadcast::Receiver<UnindexedAccountEvent>,
+    pub failures: MockFailureConfig,
 }
 
 impl<FnTime> Clone for MockExecutionClientConfig<FnTime>
@@ -53,6 +68,7 @@ where
             clock: self.clock.clone(),
             request_tx: self.request_tx.clone(),
             event_rx: self.event_rx.resubscribe(),
+            failures: self.failures,
         }
     }
 }
@@ -63,6 +79,7 @@ pub struct MockExecution<FnTime> {
     pub clock: FnTime,
     pub request_tx: mpsc::UnboundedSender<MockExchangeRequest>,
     pub event_rx: broadcast::Receiver<UnindexedAccountEvent>,
+    pub failures: Arc<MockFailureSchedule>,
 }
 
 impl<FnTime> Clone for MockExecution<FnTime>
@@ -75,6 +92,7 @@ where
             clock: self.clock.clone(),
             request_tx: self.request_tx.clone(),
             event_rx: self.event_rx.resubscribe(),
+            failures: Arc::clone(&self.failures),
         }
     }
 }
@@ -86,6 +104,18 @@ where
     pub fn time_request(&self) -> DateTime<Utc> {
         (self.clock)()
     }
+
+    /// Delay the current order request acknowledgement if scheduled by the
+    /// [`MockFailureConfig`].
+    async fn maybe_delay_ack(&self) {
+        if let Some(delay) = self.failures.next_ack_delay() {
+            warn!(
+                ?delay,
+                "MockExecution injecting delayed order acknowledgement"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
 }
 
 impl<FnTime> ExecutionClient for MockExecution<FnTime>
@@ -93,6 +123,7 @@ where
     FnTime: Fn() -> DateTime<Utc> + Clone + Sync,
 {
     const EXCHANGE: ExchangeId = ExchangeId::Mock;
+    const STOP_NATIVE: bool = true;
     type Config = MockExecutionClientConfig<FnTime>;
     type AccountStream = BoxStream<'static, UnindexedAccountEvent>;
 
@@ -102,6 +133,7 @@ where
             clock: config.clock,
             request_tx: config.request_tx,
             event_rx: config.event_rx,
+            failures: Arc::new(MockFailureSchedule::new(config.failures)),
         }
     }
 
@@ -135,7 +167,7 @@ where
         _: &[AssetNameExchange],
         _: &[InstrumentNameExchange],
     ) -> Result<Self::AccountStream, UnindexedClientError> {
-        Ok(futures::StreamExt::boxed(
+        let stream =
             BroadcastStream::new(self.event_rx.resubscribe()).map_while(|result| match result {
                 Ok(event) => Some(event),
                 Err(error) => {
@@ -145,8 +177,12 @@ where
                     );
                     None
                 }
-            }),
-        ))
+            });
+
+        Ok(futures::StreamExt::boxed(inject_stream_failures(
+            self.failures.config,
+            stream,
+        )))
     }
 
     async fn cancel_order(
@@ -155,6 +191,8 @@ where
     ) -> UnindexedOrderResponseCancel {
         let (response_tx, response_rx) = oneshot::channel();
 
+        self.maybe_delay_ack().await;
+
         self.request_tx
             .send(MockExchangeRequest::cancel_order(
                 self.time_request(),
@@ -172,8 +210,27 @@ where
         &self,
         request: OrderRequestOpen<ExchangeId, &InstrumentNameExchange>,
     ) -> Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>> {
+        if self.failures.next_open_rejected() {
+            warn!(cid = %request.key.cid, "MockExecution injecting open order rejection");
+            let request = into_owned_request(request);
+            return Order {
+                key: request.key,
+                side: request.state.side,
+                price: request.state.price,
+                quantity: request.state.quantity,
+                kind: request.state.kind,
+                time_in_force: request.state.time_in_force,
+                reduce_only: request.state.reduce_only,
+                state: Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
+                    "MockExecution injected rejection".to_string(),
+                ))),
+            };
+        }
+
         let (response_tx, response_rx) = oneshot::channel();
 
+        self.maybe_delay_ack().await;
+
         self.request_tx
             .send(MockExchangeRequest::open_order(
                 self.time_request(),
@@ -187,6 +244,88 @@ where
             .expect("MockExchange if offline - failed to receive response")
     }
 
+    async fn fetch_instrument_specs(
+        &self,
+        instruments: &[InstrumentNameExchange],
+    ) -> Result<
+        Vec<Keyed<InstrumentNameExchange, InstrumentSpec<AssetNameExchange>>>,
+        UnindexedClientError,
+    > {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.request_tx
+            .send(MockExchangeRequest::fetch_instrument_specs(
+                self.time_request(),
+                response_tx,
+                instruments.to_vec(),
+            ))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })?;
+
+        response_rx.await.map_err(|_| {
+            UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                self.mocked_exchange,
+            ))
+        })
+    }
+
+    async fn set_leverage(
+        &self,
+        instrument: &InstrumentNameExchange,
+        leverage: Decimal,
+    ) -> Result<Decimal, UnindexedClientError> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.request_tx
+            .send(MockExchangeRequest::set_leverage(
+                self.time_request(),
+                response_tx,
+                instrument.clone(),
+                leverage,
+            ))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })?;
+
+        response_rx.await.map_err(|_| {
+            UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                self.mocked_exchange,
+            ))
+        })?
+    }
+
+    async fn set_margin_mode(
+        &self,
+        instrument: &InstrumentNameExchange,
+        mode: MarginMode,
+    ) -> Result<MarginMode, UnindexedClientError> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.request_tx
+            .send(MockExchangeRequest::set_margin_mode(
+                self.time_request(),
+                response_tx,
+                instrument.clone(),
+                mode,
+            ))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })?;
+
+        response_rx.await.map_err(|_| {
+            UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                self.mocked_exchange,
+            ))
+        })?
+    }
+
     async fn fetch_balances(
         &self,
     ) -> Result<Vec<AssetBalance<AssetNameExchange>>, UnindexedClientError> {
@@ -2,6 +2,8 @@ use crate::{
     UnindexedAccountEvent, UnindexedAccountSnapshot,
     balance::AssetBalance,
     error::{UnindexedClientError, UnindexedOrderError},
+    funding::FundingPayment,
+    margin::MarginMode,
     order::{
         Order,
         request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
@@ -10,15 +12,24 @@ use crate::{
     trade::Trade,
 };
 use barter_instrument::{
+    Keyed,
     asset::{QuoteAsset, name::AssetNameExchange},
     exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    instrument::{name::InstrumentNameExchange, spec::InstrumentSpec},
 };
 use chrono::{DateTime, Utc};
 use futures::Stream;
+use rust_decimal::Decimal;
 use std::future::Future;
 
-mod binance;
+/// Binance Spot user-data `AccountStream` integration, including listen key lifecycle
+/// management.
+pub mod binance;
+
+/// Generic user-data stream listen key keepalive manager that transparently rebuilds the
+/// `AccountStream` when the listen key expires.
+pub mod keepalive;
+
 pub mod mock;
 
 pub trait ExecutionClient
@@ -27,6 +38,27 @@ where
 {
     const EXCHANGE: ExchangeId;
 
+    /// Whether the exchange supports [`OrderKind::Iceberg`](crate::order::OrderKind::Iceberg)
+    /// orders natively.
+    ///
+    /// If not, iceberg orders should be sliced into sequential `Limit` child orders before being
+    /// sent to the `ExecutionClient`.
+    const ICEBERG_NATIVE: bool = false;
+
+    /// Whether the exchange supports
+    /// [`OrderRequestOco`](crate::order::request::OrderRequestOco) groups natively.
+    ///
+    /// If not, OCO groups should be emulated by sending each leg as an independent order, and
+    /// cancelling the remaining legs once any leg fills.
+    const OCO_NATIVE: bool = false;
+
+    /// Whether the exchange supports [`OrderKind::StopMarket`](crate::order::OrderKind::StopMarket)
+    /// & [`OrderKind::StopLimit`](crate::order::OrderKind::StopLimit) orders natively.
+    ///
+    /// If not, stop orders should be held locally and only sent to the `ExecutionClient` once
+    /// triggered by the monitored market price.
+    const STOP_NATIVE: bool = false;
+
     type Config: Clone;
     type AccountStream: Stream<Item = UnindexedAccountEvent>;
 
@@ -77,18 +109,83 @@ where
         )
     }
 
+    /// Set the leverage of the provided (eg/ futures) instrument, returning the leverage
+    /// confirmed by the exchange.
+    ///
+    /// Defaults to a [`ClientError::Leverage`](crate::error::ClientError::Leverage) for exchanges
+    /// that do not support configurable leverage.
+    fn set_leverage(
+        &self,
+        instrument: &InstrumentNameExchange,
+        leverage: Decimal,
+    ) -> impl Future<Output = Result<Decimal, UnindexedClientError>> + Send {
+        std::future::ready(Err(UnindexedClientError::Leverage(format!(
+            "{} does not support setting {instrument} leverage to {leverage}",
+            Self::EXCHANGE
+        ))))
+    }
+
+    /// Set the [`MarginMode`] of the provided (eg/ futures) instrument, returning the
+    /// `MarginMode` confirmed by the exchange.
+    ///
+    /// Defaults to a [`ClientError::MarginMode`](crate::error::ClientError::MarginMode) for
+    /// exchanges that do not support configurable margin modes.
+    fn set_margin_mode(
+        &self,
+        instrument: &InstrumentNameExchange,
+        mode: MarginMode,
+    ) -> impl Future<Output = Result<MarginMode, UnindexedClientError>> + Send {
+        std::future::ready(Err(UnindexedClientError::MarginMode(format!(
+            "{} does not support setting {instrument} margin mode to {mode}",
+            Self::EXCHANGE
+        ))))
+    }
+
+    /// Fetch the exchange [`InstrumentSpec`] (eg/ tick size, quantity increment, min notional) of
+    /// each of the provided instruments.
+    ///
+    /// Instruments without a known `InstrumentSpec` are omitted. Defaults to no
+    /// `InstrumentSpec`s for exchanges that do not provide instrument metadata.
+    fn fetch_instrument_specs(
+        &self,
+        _instruments: &[InstrumentNameExchange],
+    ) -> impl Future<
+        Output = Result<
+            Vec<Keyed<InstrumentNameExchange, InstrumentSpec<AssetNameExchange>>>,
+            UnindexedClientError,
+        >,
+    > + Send {
+        std::future::ready(Ok(Vec::new()))
+    }
+
     fn fetch_balances(
         &self,
-    ) -> impl Future<Output = Result<Vec<AssetBalance<AssetNameExchange>>, UnindexedClientError>>;
+    ) -> impl Future<Output = Result<Vec<AssetBalance<AssetNameExchange>>, UnindexedClientError>> + Send;
 
     fn fetch_open_orders(
         &self,
     ) -> impl Future<
         Output = Result<Vec<Order<ExchangeId, InstrumentNameExchange, Open>>, UnindexedClientError>,
-    >;
+    > + Send;
 
     fn fetch_trades(
         &self,
         time_since: DateTime<Utc>,
     ) -> impl Future<Output = Result<Vec<Trade<QuoteAsset, InstrumentNameExchange>>, UnindexedClientError>>;
+
+    /// Fetch the perpetual instrument [`FundingPayment`]s received or paid since the provided
+    /// time (eg/ from exchange income history).
+    ///
+    /// Defaults to no `FundingPayment`s for exchanges without perpetual instruments.
+    fn fetch_funding_payments(
+        &self,
+        _time_since: DateTime<Utc>,
+    ) -> impl Future<
+        Output = Result<
+            Vec<FundingPayment<AssetNameExchange, InstrumentNameExchange>>,
+            UnindexedClientError,
+        >,
+    > + Send {
+        std::future::ready(Ok(Vec::new()))
+    }
 }
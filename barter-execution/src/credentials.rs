@@ -0,0 +1,327 @@
+use barter_instrument::exchange::ExchangeId;
+use fnv::FnvHashMap;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Formatter, path::PathBuf, sync::Arc};
+use thiserror::Error;
+
+/// Exchange API key and secret used to authenticate private requests.
+///
+/// Note that the `Debug` implementation redacts the `secret`, and [`ApiCredentials`] are
+/// intentionally not `Serialize` so they never leak into audit or log output.
+#[derive(Clone, Eq, PartialEq, Hash, Deserialize)]
+pub struct ApiCredentials {
+    pub key: String,
+    pub secret: String,
+}
+
+impl ApiCredentials {
+    /// Construct new [`ApiCredentials`] from the provided key and secret.
+    pub fn new<K, S>(key: K, secret: S) -> Self
+    where
+        K: Into<String>,
+        S: Into<String>,
+    {
+        Self {
+            key: key.into(),
+            secret: secret.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ApiCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiCredentials")
+            .field("key", &self.key)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Source that [`ApiCredentials`] can be loaded from.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum CredentialsSource {
+    /// Load the key and secret from the named environment variables.
+    ///
+    /// eg/ `Env { key: "BINANCE_API_KEY", secret: "BINANCE_API_SECRET" }`
+    Env { key: String, secret: String },
+
+    /// Load the key and secret from a JSON file.
+    ///
+    /// eg/ `{"key": "...", "secret": "..."}`
+    File(PathBuf),
+}
+
+impl CredentialsSource {
+    /// Load [`ApiCredentials`] from this [`CredentialsSource`].
+    pub fn load(&self) -> Result<ApiCredentials, CredentialsError> {
+        match self {
+            CredentialsSource::Env { key, secret } => {
+                let var = |name: &String| {
+                    std::env::var(name).map_err(|error| CredentialsError::Env {
+                        var: name.clone(),
+                        error: error.to_string(),
+                    })
+                };
+                Ok(ApiCredentials::new(var(key)?, var(secret)?))
+            }
+            CredentialsSource::File(path) => {
+                let file_error = |error: String| CredentialsError::File {
+                    path: path.display().to_string(),
+                    error,
+                };
+
+                let contents =
+                    std::fs::read_to_string(path).map_err(|error| file_error(error.to_string()))?;
+
+                serde_json::from_str(&contents).map_err(|error| file_error(error.to_string()))
+            }
+        }
+    }
+}
+
+/// All errors generated when loading, or rotating, [`ApiCredentials`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum CredentialsError {
+    #[error("failed to load credentials from environment variable {var}: {error}")]
+    Env { var: String, error: String },
+
+    #[error("failed to load credentials from file {path}: {error}")]
+    File { path: String, error: String },
+
+    #[error("no credentials configured for exchange: {0}")]
+    NotConfigured(ExchangeId),
+}
+
+/// Runtime update of the [`Credentials`] used for an exchange, eg/ to rotate compromised or
+/// expiring keys without restarting.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum CredentialsUpdate {
+    /// Rotate to the next configured [`ApiCredentials`] of the exchange, wrapping around to the
+    /// first.
+    Rotate(ExchangeId),
+
+    /// Load new [`ApiCredentials`] for the exchange from the [`CredentialsSource`], and make them
+    /// active.
+    Load {
+        exchange: ExchangeId,
+        source: CredentialsSource,
+    },
+}
+
+impl CredentialsUpdate {
+    /// Return the [`ExchangeId`] whose [`Credentials`] are being updated.
+    pub fn exchange(&self) -> ExchangeId {
+        match self {
+            CredentialsUpdate::Rotate(exchange) => *exchange,
+            CredentialsUpdate::Load { exchange, .. } => *exchange,
+        }
+    }
+}
+
+/// Configured [`ApiCredentials`] of an exchange, one of which is active.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct ExchangeCredentials {
+    pub keys: Vec<ApiCredentials>,
+    pub active: usize,
+}
+
+impl ExchangeCredentials {
+    /// Return the active [`ApiCredentials`], if any are configured.
+    pub fn active(&self) -> Option<&ApiCredentials> {
+        self.keys.get(self.active)
+    }
+
+    /// Rotate to the next configured [`ApiCredentials`], wrapping around to the first.
+    pub fn rotate(&mut self) {
+        if !self.keys.is_empty() {
+            self.active = (self.active + 1) % self.keys.len();
+        }
+    }
+}
+
+/// Shared, rotatable store of per-exchange [`ApiCredentials`], supporting multiple keys per
+/// exchange.
+///
+/// Cloning [`Credentials`] shares the same underlying store, so an `ExecutionClient` that looks
+/// up the [`Self::active`] [`ApiCredentials`] per request observes any rotation (eg/ actioned
+/// via the `Engine` command channel) without downtime.
+#[derive(Debug, Clone, Default)]
+pub struct Credentials(Arc<RwLock<FnvHashMap<ExchangeId, ExchangeCredentials>>>);
+
+impl PartialEq for Credentials {
+    /// [`Credentials`] are equal if they share the same underlying store.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Credentials {}
+
+impl Credentials {
+    /// Add [`ApiCredentials`] for the exchange.
+    ///
+    /// The first [`ApiCredentials`] added for an exchange become active.
+    pub fn with(self, exchange: ExchangeId, credentials: ApiCredentials) -> Self {
+        self.0
+            .write()
+            .entry(exchange)
+            .or_default()
+            .keys
+            .push(credentials);
+        self
+    }
+
+    /// Load and add [`ApiCredentials`] for the exchange from the [`CredentialsSource`].
+    pub fn with_source(
+        self,
+        exchange: ExchangeId,
+        source: &CredentialsSource,
+    ) -> Result<Self, CredentialsError> {
+        source
+            .load()
+            .map(|credentials| self.with(exchange, credentials))
+    }
+
+    /// Return a clone of the active [`ApiCredentials`] of the exchange, if any are configured.
+    pub fn active(&self, exchange: ExchangeId) -> Option<ApiCredentials> {
+        self.0
+            .read()
+            .get(&exchange)
+            .and_then(ExchangeCredentials::active)
+            .cloned()
+    }
+
+    /// Apply a runtime [`CredentialsUpdate`].
+    ///
+    /// Loading new [`ApiCredentials`] replaces nothing, so the previous keys remain available to
+    /// rotate back to.
+    pub fn update(&self, update: &CredentialsUpdate) -> Result<(), CredentialsError> {
+        match update {
+            CredentialsUpdate::Rotate(exchange) => {
+                let mut store = self.0.write();
+                let credentials = store
+                    .get_mut(exchange)
+                    .filter(|credentials| !credentials.keys.is_empty())
+                    .ok_or(CredentialsError::NotConfigured(*exchange))?;
+                credentials.rotate();
+            }
+            CredentialsUpdate::Load { exchange, source } => {
+                let loaded = source.load()?;
+                let mut store = self.0.write();
+                let credentials = store.entry(*exchange).or_default();
+                credentials.keys.push(loaded);
+                credentials.active = credentials.keys.len() - 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credentials_update() {
+        struct TestCase {
+            updates: Vec<CredentialsUpdate>,
+            expected_ok: Vec<bool>,
+            expected_active: Option<&'static str>,
+        }
+
+        let missing_file = CredentialsSource::File(PathBuf::from("/non/existent/credentials"));
+
+        let cases = vec![
+            // TC0: no updates leaves first configured key active
+            TestCase {
+                updates: vec![],
+                expected_ok: vec![],
+                expected_active: Some("key_a"),
+            },
+            // TC1: rotate to the next configured key
+            TestCase {
+                updates: vec![CredentialsUpdate::Rotate(ExchangeId::BinanceSpot)],
+                expected_ok: vec![true],
+                expected_active: Some("key_b"),
+            },
+            // TC2: rotation wraps around to the first configured key
+            TestCase {
+                updates: vec![
+                    CredentialsUpdate::Rotate(ExchangeId::BinanceSpot),
+                    CredentialsUpdate::Rotate(ExchangeId::BinanceSpot),
+                ],
+                expected_ok: vec![true, true],
+                expected_active: Some("key_a"),
+            },
+            // TC3: rotating an unconfigured exchange fails
+            TestCase {
+                updates: vec![CredentialsUpdate::Rotate(ExchangeId::Kraken)],
+                expected_ok: vec![false],
+                expected_active: Some("key_a"),
+            },
+            // TC4: failing to load new credentials leaves the active key unchanged
+            TestCase {
+                updates: vec![CredentialsUpdate::Load {
+                    exchange: ExchangeId::BinanceSpot,
+                    source: missing_file,
+                }],
+                expected_ok: vec![false],
+                expected_active: Some("key_a"),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let credentials = Credentials::default()
+                .with(
+                    ExchangeId::BinanceSpot,
+                    ApiCredentials::new("key_a", "secret_a"),
+                )
+                .with(
+                    ExchangeId::BinanceSpot,
+                    ApiCredentials::new("key_b", "secret_b"),
+                );
+
+            let actual_ok = test
+                .updates
+                .iter()
+                .map(|update| credentials.update(update).is_ok())
+                .collect::<Vec<_>>();
+            assert_eq!(actual_ok, test.expected_ok, "TC{index} failed");
+
+            let actual_active = credentials.active(ExchangeId::BinanceSpot);
+            assert_eq!(
+                actual_active.as_ref().map(|active| active.key.as_str()),
+                test.expected_active,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_credentials_source_load_file() {
+        let path = std::env::temp_dir().join("barter_execution_test_credentials.json");
+        std::fs::write(&path, r#"{"key": "key_file", "secret": "secret_file"}"#).unwrap();
+
+        let credentials = Credentials::default();
+        let update = CredentialsUpdate::Load {
+            exchange: ExchangeId::Okx,
+            source: CredentialsSource::File(path.clone()),
+        };
+        assert!(credentials.update(&update).is_ok());
+        assert_eq!(
+            credentials.active(ExchangeId::Okx),
+            Some(ApiCredentials::new("key_file", "secret_file"))
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_api_credentials_debug_redacts_secret() {
+        let debug = format!("{:?}", ApiCredentials::new("key", "secret"));
+        assert!(debug.contains("key"));
+        assert!(!debug.contains("\"secret\""));
+    }
+}
@@ -1,9 +1,10 @@
+use crate::order::validation::OrderValidationError;
 use barter_instrument::{
     asset::{AssetIndex, name::AssetNameExchange},
     exchange::ExchangeId,
     instrument::{InstrumentIndex, name::InstrumentNameExchange},
 };
-use barter_integration::error::SocketError;
+use barter_integration::{Unrecoverable, error::SocketError};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -19,6 +20,19 @@ pub type UnindexedApiError = ApiError<AssetNameExchange, InstrumentNameExchange>
 /// [`InstrumentNameExchange`] (yet to be indexed).
 pub type UnindexedOrderError = OrderError<AssetNameExchange, InstrumentNameExchange>;
 
+/// Action a caller should take in response to an execution error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum ErrorAction {
+    /// Transient error (eg/ Timeout, RateLimit), so the request may succeed if retried.
+    Retry,
+
+    /// Error specific to the request (eg/ OrderRejected), so it should be skipped.
+    Skip,
+
+    /// Fatal error (eg/ invalid instrument), so trading should halt until resolved.
+    Halt,
+}
+
 /// Represents all errors produced by an [`ExecutionClient`](super::client::ExecutionClient).
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
 pub enum ClientError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
@@ -41,6 +55,32 @@ pub enum ClientError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     /// Failed to initialise an AccountStream.
     #[error("failed to init AccountStream: {0}")]
     AccountStream(String),
+
+    /// Failed to set instrument leverage.
+    #[error("failed to set leverage: {0}")]
+    Leverage(String),
+
+    /// Failed to set instrument margin mode.
+    #[error("failed to set margin mode: {0}")]
+    MarginMode(String),
+}
+
+impl<AssetKey, InstrumentKey> ClientError<AssetKey, InstrumentKey> {
+    /// Determine the [`ErrorAction`] a caller should take in response to this error.
+    pub fn action(&self) -> ErrorAction {
+        match self {
+            Self::Connectivity(error) => error.action(),
+            Self::Api(error) => error.action(),
+            Self::AccountSnapshot(_) | Self::AccountStream(_) => ErrorAction::Retry,
+            Self::Leverage(_) | Self::MarginMode(_) => ErrorAction::Halt,
+        }
+    }
+}
+
+impl<AssetKey, InstrumentKey> Unrecoverable for ClientError<AssetKey, InstrumentKey> {
+    fn is_unrecoverable(&self) -> bool {
+        self.action() == ErrorAction::Halt
+    }
 }
 
 /// Represents all connectivity-centric errors.
@@ -61,6 +101,15 @@ pub enum ConnectivityError {
     Socket(String),
 }
 
+impl ConnectivityError {
+    /// Determine the [`ErrorAction`] a caller should take in response to this error.
+    ///
+    /// Connectivity errors are intermittent, so are always worth retrying.
+    pub fn action(&self) -> ErrorAction {
+        ErrorAction::Retry
+    }
+}
+
 impl From<SocketError> for ConnectivityError {
     fn from(value: SocketError) -> Self {
         Self::Socket(value.to_string())
@@ -97,6 +146,26 @@ pub enum ApiError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     OrderAlreadyCancelled,
     #[error("order already fully filled")]
     OrderAlreadyFullyFilled,
+
+    /// Order could not be made valid for the exchange instrument specification, so was never
+    /// sent to the exchange.
+    #[error("order invalid: {0}")]
+    OrderInvalid(OrderValidationError),
+}
+
+impl<AssetKey, InstrumentKey> ApiError<AssetKey, InstrumentKey> {
+    /// Determine the [`ErrorAction`] a caller should take in response to this error.
+    pub fn action(&self) -> ErrorAction {
+        match self {
+            Self::AssetInvalid(_, _) | Self::InstrumentInvalid(_, _) => ErrorAction::Halt,
+            Self::RateLimit => ErrorAction::Retry,
+            Self::BalanceInsufficient(_, _)
+            | Self::OrderRejected(_)
+            | Self::OrderAlreadyCancelled
+            | Self::OrderAlreadyFullyFilled
+            | Self::OrderInvalid(_) => ErrorAction::Skip,
+        }
+    }
 }
 
 /// Represents all errors that can be generated when cancelling or opening orders.
@@ -115,6 +184,22 @@ pub enum OrderError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     Rejected(#[from] ApiError<AssetKey, InstrumentKey>),
 }
 
+impl<AssetKey, InstrumentKey> OrderError<AssetKey, InstrumentKey> {
+    /// Determine the [`ErrorAction`] a caller should take in response to this error.
+    pub fn action(&self) -> ErrorAction {
+        match self {
+            Self::Connectivity(error) => error.action(),
+            Self::Rejected(error) => error.action(),
+        }
+    }
+}
+
+impl<AssetKey, InstrumentKey> Unrecoverable for OrderError<AssetKey, InstrumentKey> {
+    fn is_unrecoverable(&self) -> bool {
+        self.action() == ErrorAction::Halt
+    }
+}
+
 /// Represents errors related to exchange, asset and instrument identifier key lookups.
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
 pub enum KeyError {
@@ -133,3 +218,55 @@ pub enum KeyError {
     #[error("InstrumentKey: {0}")]
     InstrumentKey(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_error_action() {
+        struct TestCase {
+            input: OrderError,
+            expected: ErrorAction,
+        }
+
+        let cases = vec![
+            // TC0: Timeout is retried
+            TestCase {
+                input: OrderError::Connectivity(ConnectivityError::Timeout),
+                expected: ErrorAction::Retry,
+            },
+            // TC1: RateLimit is retried
+            TestCase {
+                input: OrderError::Rejected(ApiError::RateLimit),
+                expected: ErrorAction::Retry,
+            },
+            // TC2: BalanceInsufficient is skipped
+            TestCase {
+                input: OrderError::Rejected(ApiError::BalanceInsufficient(
+                    AssetIndex(0),
+                    "insufficient".to_string(),
+                )),
+                expected: ErrorAction::Skip,
+            },
+            // TC3: InstrumentInvalid halts
+            TestCase {
+                input: OrderError::Rejected(ApiError::InstrumentInvalid(
+                    InstrumentIndex(0),
+                    "delisted".to_string(),
+                )),
+                expected: ErrorAction::Halt,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.action();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(
+                test.input.is_unrecoverable(),
+                test.expected == ErrorAction::Halt,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -69,6 +69,37 @@ impl AccountState {
         self.balances.get_mut(asset)
     }
 
+    /// Returns a mutable reference to the open order with the provided [`ClientOrderId`], if any.
+    pub fn order_open_mut(
+        &mut self,
+        cid: &ClientOrderId,
+    ) -> Option<&mut Order<ExchangeId, InstrumentNameExchange, Open>> {
+        self.orders_open.get_mut(cid)
+    }
+
+    /// Returns true if an order with the provided [`ClientOrderId`] has been cancelled.
+    pub fn is_order_cancelled(&self, cid: &ClientOrderId) -> bool {
+        self.orders_cancelled.contains_key(cid)
+    }
+
+    pub fn insert_order_open(&mut self, order: Order<ExchangeId, InstrumentNameExchange, Open>) {
+        self.orders_open.insert(order.key.cid.clone(), order);
+    }
+
+    pub fn remove_order_open(
+        &mut self,
+        cid: &ClientOrderId,
+    ) -> Option<Order<ExchangeId, InstrumentNameExchange, Open>> {
+        self.orders_open.remove(cid)
+    }
+
+    pub fn insert_order_cancelled(
+        &mut self,
+        order: Order<ExchangeId, InstrumentNameExchange, Cancelled>,
+    ) {
+        self.orders_cancelled.insert(order.key.cid.clone(), order);
+    }
+
     pub fn ack_trade(&mut self, trade: Trade<QuoteAsset, InstrumentNameExchange>) {
         self.trades.push(trade);
     }
@@ -102,6 +133,7 @@ impl From<UnindexedAccountSnapshot> for AccountState {
                                     quantity: order.quantity,
                                     kind: order.kind,
                                     time_in_force: order.time_in_force,
+                                    reduce_only: order.reduce_only,
                                     state: open,
                                 },
                             );
@@ -116,6 +148,7 @@ impl From<UnindexedAccountSnapshot> for AccountState {
                                     quantity: order.quantity,
                                     kind: order.kind,
                                     time_in_force: order.time_in_force,
+                                    reduce_only: order.reduce_only,
                                     state: cancelled,
                                 },
                             );
@@ -2,24 +2,31 @@ use crate::{
     AccountEventKind, InstrumentAccountSnapshot, UnindexedAccountEvent, UnindexedAccountSnapshot,
     balance::AssetBalance,
     client::mock::MockExecutionConfig,
-    error::{ApiError, UnindexedApiError, UnindexedOrderError},
+    error::{ApiError, UnindexedApiError, UnindexedClientError, UnindexedOrderError},
     exchange::mock::{
         account::AccountState,
+        price::MockPriceFeed,
         request::{MockExchangeRequest, MockExchangeRequestKind},
+        slippage::SlippageModel,
     },
+    margin::MarginMode,
     order::{
-        Order, OrderKind, UnindexedOrder,
+        Order, OrderEvent, OrderKind, StopTrigger, UnindexedOrder,
         id::OrderId,
-        request::{OrderRequestCancel, OrderRequestOpen},
-        state::{Cancelled, Open},
+        request::{
+            OrderRequestCancel, OrderRequestOpen, RequestOpen, UnindexedOrderResponseCancel,
+        },
+        state::{Cancelled, Open, OrderState},
     },
-    trade::{AssetFees, Trade, TradeId},
+    trade::{AssetFees, Liquidity, Trade, TradeId},
 };
 use barter_instrument::{
-    Side,
+    Keyed, Side,
     asset::{QuoteAsset, name::AssetNameExchange},
     exchange::ExchangeId,
-    instrument::{Instrument, name::InstrumentNameExchange},
+    instrument::{
+        Instrument, kind::InstrumentKind, name::InstrumentNameExchange, spec::InstrumentSpec,
+    },
 };
 use barter_integration::snapshot::Snapshot;
 use chrono::{DateTime, TimeDelta, Utc};
@@ -29,22 +36,28 @@ use itertools::Itertools;
 use rust_decimal::Decimal;
 use smol_str::ToSmolStr;
 use std::fmt::Debug;
-use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tracing::{error, info};
 
 pub mod account;
+pub mod price;
 pub mod request;
+pub mod slippage;
 
 #[derive(Debug)]
 pub struct MockExchange {
     pub exchange: ExchangeId,
     pub latency_ms: u64,
     pub fees_percent: Decimal,
+    pub slippage: Box<dyn SlippageModel + Send + Sync>,
+    pub price_feed: Option<MockPriceFeed>,
     pub request_rx: mpsc::UnboundedReceiver<MockExchangeRequest>,
     pub event_tx: broadcast::Sender<UnindexedAccountEvent>,
     pub instruments: FnvHashMap<InstrumentNameExchange, Instrument<ExchangeId, AssetNameExchange>>,
     pub account: AccountState,
+    /// Notified on each [`MockPriceFeed`] update, triggering the matching of resting orders.
+    pub price_updates: Option<watch::Receiver<()>>,
     pub order_sequence: u64,
     pub time_exchange_latest: DateTime<Utc>,
 }
@@ -56,64 +69,64 @@ impl MockExchange {
         event_tx: broadcast::Sender<UnindexedAccountEvent>,
         instruments: FnvHashMap<InstrumentNameExchange, Instrument<ExchangeId, AssetNameExchange>>,
     ) -> Self {
+        let account = AccountState::from(config.initial_state);
+
+        // Continue the OrderId sequence after any open orders in the initial account snapshot
+        // (eg/ when resuming a backtest), so new orders never reuse an open OrderId
+        let order_sequence = account
+            .orders_open()
+            .filter_map(|order| order.state.id.0.parse::<u64>().ok())
+            .map(|sequence| sequence + 1)
+            .max()
+            .unwrap_or_default();
+
         Self {
             exchange: config.mocked_exchange,
             latency_ms: config.latency_ms,
             fees_percent: config.fees_percent,
+            slippage: Box::new(config.slippage),
+            price_feed: None,
             request_rx,
             event_tx,
             instruments,
-            account: AccountState::from(config.initial_state),
-            order_sequence: 0,
+            account,
+            price_updates: None,
+            order_sequence,
             time_exchange_latest: Default::default(),
         }
     }
 
-    pub async fn run(mut self) {
-        while let Some(request) = self.request_rx.recv().await {
-            self.update_time_exchange(request.time_request);
+    /// Fill market orders at the latest [`MockPriceFeed`] quote prices, rather than at the
+    /// requested order price.
+    pub fn with_price_feed(self, price_feed: MockPriceFeed) -> Self {
+        Self {
+            price_updates: Some(price_feed.subscribe()),
+            price_feed: Some(price_feed),
+            ..self
+        }
+    }
 
-            match request.kind {
-                MockExchangeRequestKind::FetchAccountSnapshot { response_tx } => {
-                    let snapshot = self.account_snapshot();
-                    self.respond_with_latency(response_tx, snapshot);
-                }
-                MockExchangeRequestKind::FetchBalances { response_tx } => {
-                    let balances = self.account.balances().cloned().collect();
-                    self.respond_with_latency(response_tx, balances);
-                }
-                MockExchangeRequestKind::FetchOrdersOpen { response_tx } => {
-                    let orders_open = self.account.orders_open().cloned().collect();
-                    self.respond_with_latency(response_tx, orders_open);
-                }
-                MockExchangeRequestKind::FetchTrades {
-                    response_tx,
-                    time_since,
-                } => {
-                    let trades = self.account.trades(time_since).cloned().collect();
-                    self.respond_with_latency(response_tx, trades);
-                }
-                MockExchangeRequestKind::CancelOrder {
-                    response_tx: _,
-                    request,
-                } => {
-                    error!(
-                        exchange = %self.exchange,
-                        ?request,
-                        "MockExchange received cancel request but only Market orders are supported"
-                    );
-                }
-                MockExchangeRequestKind::OpenOrder {
-                    response_tx,
-                    request,
-                } => {
-                    let (response, notifications) = self.open_order(request);
-                    self.respond_with_latency(response_tx, response);
-
-                    if let Some(notifications) = notifications {
-                        self.account.ack_trade(notifications.trade.clone());
-                        self.send_notifications_with_latency(notifications);
-                    }
+    /// Price fills using the provided custom [`SlippageModel`], rather than the configured
+    /// built-in model.
+    pub fn with_slippage_model<Model>(self, slippage: Model) -> Self
+    where
+        Model: SlippageModel + Send + Sync + 'static,
+    {
+        Self {
+            slippage: Box::new(slippage),
+            ..self
+        }
+    }
+
+    pub async fn run(mut self) {
+        loop {
+            tokio::select! {
+                request = self.request_rx.recv() => match request {
+                    Some(request) => self.process_request(request),
+                    None => break,
+                },
+                Some(()) = price_updated(&mut self.price_updates) => {
+                    self.fill_orders_open();
                 }
             }
         }
@@ -121,6 +134,75 @@ impl MockExchange {
         info!(exchange = %self.exchange, "MockExchange shutting down");
     }
 
+    fn process_request(&mut self, request: MockExchangeRequest) {
+        self.update_time_exchange(request.time_request);
+        self.fill_orders_open();
+
+        match request.kind {
+            MockExchangeRequestKind::FetchAccountSnapshot { response_tx } => {
+                let snapshot = self.account_snapshot();
+                self.respond_with_latency(response_tx, snapshot);
+            }
+            MockExchangeRequestKind::FetchInstrumentSpecs {
+                response_tx,
+                instruments,
+            } => {
+                let specs = self.instrument_specs(&instruments);
+                self.respond_with_latency(response_tx, specs);
+            }
+            MockExchangeRequestKind::FetchBalances { response_tx } => {
+                let balances = self.account.balances().cloned().collect();
+                self.respond_with_latency(response_tx, balances);
+            }
+            MockExchangeRequestKind::FetchOrdersOpen { response_tx } => {
+                let orders_open = self.account.orders_open().cloned().collect();
+                self.respond_with_latency(response_tx, orders_open);
+            }
+            MockExchangeRequestKind::FetchTrades {
+                response_tx,
+                time_since,
+            } => {
+                let trades = self.account.trades(time_since).cloned().collect();
+                self.respond_with_latency(response_tx, trades);
+            }
+            MockExchangeRequestKind::SetLeverage {
+                response_tx,
+                instrument,
+                leverage,
+            } => {
+                let confirmed = self.set_leverage(&instrument, leverage);
+                self.respond_with_latency(response_tx, confirmed);
+            }
+            MockExchangeRequestKind::SetMarginMode {
+                response_tx,
+                instrument,
+                mode,
+            } => {
+                let confirmed = self.set_margin_mode(&instrument, mode);
+                self.respond_with_latency(response_tx, confirmed);
+            }
+            MockExchangeRequestKind::CancelOrder {
+                response_tx,
+                request,
+            } => {
+                let response = self.cancel_order(request);
+                self.respond_with_latency(response_tx, response);
+            }
+            MockExchangeRequestKind::OpenOrder {
+                response_tx,
+                request,
+            } => {
+                let (response, notifications) = self.open_order(request);
+                self.respond_with_latency(response_tx, response);
+
+                if let Some(notifications) = notifications {
+                    self.account.ack_trade(notifications.trade.clone());
+                    self.send_notifications_with_latency(notifications);
+                }
+            }
+        }
+    }
+
     fn update_time_exchange(&mut self, time_request: DateTime<Utc>) {
         let client_to_exchange_latency = self.latency_ms / 2;
 
@@ -169,6 +251,63 @@ impl MockExchange {
         }
     }
 
+    /// Returns the [`InstrumentSpec`] of each provided instrument the `MockExchange` manages,
+    /// omitting instruments without a spec.
+    pub fn instrument_specs(
+        &self,
+        instruments: &[InstrumentNameExchange],
+    ) -> Vec<Keyed<InstrumentNameExchange, InstrumentSpec<AssetNameExchange>>> {
+        instruments
+            .iter()
+            .filter_map(|name| {
+                let spec = self.instruments.get(name)?.spec.clone()?;
+                Some(Keyed::new(name.clone(), spec))
+            })
+            .collect()
+    }
+
+    /// Set the leverage of the provided instrument, returning the leverage confirmed by the
+    /// `MockExchange`.
+    ///
+    /// Spot instruments cannot be leveraged, so always confirm a leverage of one.
+    pub fn set_leverage(
+        &self,
+        instrument: &InstrumentNameExchange,
+        leverage: Decimal,
+    ) -> Result<Decimal, UnindexedClientError> {
+        let Some(instrument_data) = self.instruments.get(instrument) else {
+            return Err(UnindexedClientError::Leverage(format!(
+                "MockExchange is not set-up for managing: {instrument}"
+            )));
+        };
+
+        match instrument_data.kind {
+            InstrumentKind::Spot => Ok(Decimal::ONE),
+            _ => Ok(leverage),
+        }
+    }
+
+    /// Set the [`MarginMode`] of the provided instrument, returning the `MarginMode` confirmed by
+    /// the `MockExchange`.
+    ///
+    /// Spot instruments are not margined, so always confirm [`MarginMode::Cross`].
+    pub fn set_margin_mode(
+        &self,
+        instrument: &InstrumentNameExchange,
+        mode: MarginMode,
+    ) -> Result<MarginMode, UnindexedClientError> {
+        let Some(instrument_data) = self.instruments.get(instrument) else {
+            return Err(UnindexedClientError::MarginMode(format!(
+                "MockExchange is not set-up for managing: {instrument}"
+            )));
+        };
+
+        match instrument_data.kind {
+            InstrumentKind::Spot => Ok(MarginMode::Cross),
+            _ => Ok(mode),
+        }
+    }
+
     /// Sends the provided `Response` via the [`oneshot::Sender`] after waiting for the latency
     /// [`Duration`].
     ///
@@ -243,11 +382,50 @@ impl MockExchange {
         ))
     }
 
+    /// Cancel the open order (eg/ a resting stop) with the [`ClientOrderId`] of the provided
+    /// request.
+    ///
+    /// [`ClientOrderId`]: crate::order::id::ClientOrderId
     pub fn cancel_order(
         &mut self,
-        _: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
-    ) -> Order<ExchangeId, InstrumentNameExchange, Result<Cancelled, UnindexedOrderError>> {
-        unimplemented!()
+        request: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
+    ) -> UnindexedOrderResponseCancel {
+        let Some(order) = self.account.remove_order_open(&request.key.cid) else {
+            let error = if self.account.is_order_cancelled(&request.key.cid) {
+                ApiError::OrderAlreadyCancelled
+            } else {
+                ApiError::OrderRejected(format!(
+                    "MockExchange has no open order with cid: {}",
+                    request.key.cid
+                ))
+            };
+
+            return OrderEvent {
+                key: request.key,
+                state: Err(UnindexedOrderError::Rejected(error)),
+            };
+        };
+
+        let cancelled = Cancelled {
+            id: order.state.id,
+            time_exchange: self.time_exchange(),
+        };
+
+        self.account.insert_order_cancelled(Order {
+            key: order.key,
+            side: order.side,
+            price: order.price,
+            quantity: order.quantity,
+            kind: order.kind,
+            time_in_force: order.time_in_force,
+            reduce_only: order.reduce_only,
+            state: cancelled.clone(),
+        });
+
+        OrderEvent {
+            key: request.key,
+            state: Ok(cancelled),
+        }
     }
 
     pub fn open_order(
@@ -261,12 +439,71 @@ impl MockExchange {
             return (build_open_order_err_response(request, error), None);
         }
 
+        if request.state.kind.stop().is_some()
+            && self.stop_reference_price(&request.key.instrument).is_none()
+        {
+            let error = ApiError::OrderRejected(
+                "MockExchange requires a MockPriceFeed to evaluate stop orders".to_string(),
+            );
+            return (build_open_order_err_response(request, error), None);
+        }
+
+        if let Some(kind) = self.resting_kind(
+            &request.key.instrument,
+            request.state.side,
+            request.state.price,
+            request.state.kind,
+        ) {
+            if let Err(error) = self.validate_reduce_only(&request) {
+                return (build_open_order_err_response(request, error), None);
+            }
+
+            return (self.rest_order(request, kind), None);
+        }
+
+        self.fill_order(request, None)
+    }
+
+    /// Fill the provided order request at the latest [`MockPriceFeed`] quote (or the requested
+    /// price if there is no quote), updating the account balance.
+    ///
+    /// The provided [`OrderId`] is used if the order was previously resting on the
+    /// `MockExchange`, otherwise a new one is generated.
+    fn fill_order(
+        &mut self,
+        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+        order_id: Option<OrderId>,
+    ) -> (
+        Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        Option<OpenOrderNotifications>,
+    ) {
         let underlying = match self.find_instrument_data(&request.key.instrument) {
             Ok(instrument) => instrument.underlying.clone(),
             Err(error) => return (build_open_order_err_response(request, error), None),
         };
 
+        if let Err(error) = self.validate_reduce_only(&request) {
+            return (build_open_order_err_response(request, error), None);
+        }
+
         let time_exchange = self.time_exchange();
+        let price_fill = self.price_fill(
+            &request.key.instrument,
+            request.state.side,
+            request.state.price,
+            request.state.quantity,
+        );
+
+        // Limit orders never fill at a worse price than the limit price
+        let price_fill = match (request.state.kind, request.state.side) {
+            (OrderKind::Limit | OrderKind::StopLimit { .. }, Side::Buy) => {
+                price_fill.min(request.state.price)
+            }
+            (OrderKind::Limit | OrderKind::StopLimit { .. }, Side::Sell) => {
+                price_fill.max(request.state.price)
+            }
+            _ => price_fill,
+        };
 
         let balance_change_result = match request.state.side {
             Side::Buy => {
@@ -279,7 +516,7 @@ impl MockExchange {
                 // Currently we only supported MarketKind orders, so they should be identical
                 assert_eq!(current.balance.total, current.balance.free);
 
-                let order_value_quote = request.state.price * request.state.quantity.abs();
+                let order_value_quote = price_fill * request.state.quantity.abs();
                 let order_fees_quote = order_value_quote * self.fees_percent;
                 let quote_required = order_value_quote + order_fees_quote;
 
@@ -322,7 +559,7 @@ impl MockExchange {
                     current.balance.total = maybe_new_balance;
                     current.time_exchange = time_exchange;
 
-                    let fees_quote = order_fees_base * request.state.price;
+                    let fees_quote = order_fees_base * price_fill;
 
                     Ok((current.clone(), AssetFees::quote_fees(fees_quote)))
                 } else {
@@ -342,7 +579,7 @@ impl MockExchange {
             Err(error) => return (build_open_order_err_response(request, error), None),
         };
 
-        let order_id = self.order_id_sequence_fetch_add();
+        let order_id = order_id.unwrap_or_else(|| self.order_id_sequence_fetch_add());
         let trade_id = TradeId(order_id.0.clone());
 
         let order_response = Order {
@@ -352,6 +589,7 @@ impl MockExchange {
             quantity: request.state.quantity,
             kind: request.state.kind,
             time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
             state: Ok(Open {
                 id: order_id.clone(),
                 time_exchange: self.time_exchange(),
@@ -364,12 +602,14 @@ impl MockExchange {
             trade: Trade {
                 id: trade_id,
                 order_id: order_id.clone(),
+                cid: Some(request.key.cid.clone()),
                 instrument: request.key.instrument,
                 strategy: request.key.strategy,
                 time_exchange: self.time_exchange(),
                 side: request.state.side,
-                price: request.state.price,
+                price: price_fill,
                 quantity: request.state.quantity,
+                liquidity: Some(Liquidity::Taker),
                 fees,
             },
         };
@@ -377,11 +617,276 @@ impl MockExchange {
         (order_response, Some(notifications))
     }
 
+    /// Validate a `reduce_only` order request only reduces the net position of the instrument
+    /// trades executed by the `MockExchange`, and never increases or flips it.
+    fn validate_reduce_only(
+        &self,
+        request: &OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+    ) -> Result<(), UnindexedApiError> {
+        if !request.state.reduce_only {
+            return Ok(());
+        }
+
+        let position = self
+            .account
+            .trades(DateTime::<Utc>::MIN_UTC)
+            .filter(|trade| trade.instrument == request.key.instrument)
+            .map(|trade| match trade.side {
+                Side::Buy => trade.quantity.abs(),
+                Side::Sell => -trade.quantity.abs(),
+            })
+            .sum::<Decimal>();
+
+        let quantity = request.state.quantity.abs();
+        let is_reducing = match request.state.side {
+            Side::Buy => position < Decimal::ZERO && quantity <= position.abs(),
+            Side::Sell => position > Decimal::ZERO && quantity <= position,
+        };
+
+        if is_reducing {
+            Ok(())
+        } else {
+            Err(ApiError::OrderRejected(format!(
+                "reduce only order would increase or flip position: {position}"
+            )))
+        }
+    }
+
+    /// Rest an order with the provided [`OrderKind`] on the `MockExchange` open orders, returning
+    /// the open order response.
+    fn rest_order(
+        &mut self,
+        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+        kind: OrderKind,
+    ) -> Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>> {
+        let open = Open {
+            id: self.order_id_sequence_fetch_add(),
+            time_exchange: self.time_exchange(),
+            filled_quantity: Decimal::ZERO,
+        };
+
+        self.account.insert_order_open(Order {
+            key: request.key.clone(),
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state: open.clone(),
+        });
+
+        Order {
+            key: request.key,
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state: Ok(open),
+        }
+    }
+
+    /// Returns the [`OrderKind`] an order should rest on the `MockExchange` as, or `None` if it
+    /// should be filled immediately, given the latest [`MockPriceFeed`] quotes.
+    ///
+    /// A triggered `StopLimit` order that is not yet marketable rests as a `Limit` order.
+    pub fn resting_kind(
+        &self,
+        instrument: &InstrumentNameExchange,
+        side: Side,
+        price: Decimal,
+        kind: OrderKind,
+    ) -> Option<OrderKind> {
+        let is_triggered = |trigger_price| {
+            self.stop_reference_price(instrument)
+                .is_some_and(|reference| StopTrigger::is_triggered(side, trigger_price, reference))
+        };
+
+        match kind {
+            OrderKind::StopMarket { trigger_price, .. } => {
+                (!is_triggered(trigger_price)).then_some(kind)
+            }
+            OrderKind::StopLimit { trigger_price, .. } if !is_triggered(trigger_price) => {
+                Some(kind)
+            }
+            OrderKind::StopLimit { .. } | OrderKind::Limit => {
+                (!self.is_marketable(instrument, side, price)).then_some(OrderKind::Limit)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if a limit order of the provided [`Side`] and price would cross the latest
+    /// [`MockPriceFeed`] quote.
+    pub fn is_marketable(
+        &self,
+        instrument: &InstrumentNameExchange,
+        side: Side,
+        price: Decimal,
+    ) -> bool {
+        self.price_feed
+            .as_ref()
+            .and_then(|feed| feed.quote(self.exchange, instrument))
+            .is_some_and(|quote| match side {
+                Side::Buy => quote.ask <= price,
+                Side::Sell => quote.bid >= price,
+            })
+    }
+
+    /// Fill any open orders (eg/ resting stops) made fillable by the latest [`MockPriceFeed`]
+    /// quotes, sending the associated [`OpenOrderNotifications`].
+    pub fn fill_orders_open(&mut self) {
+        let matches = self
+            .account
+            .orders_open()
+            .filter(|order| {
+                matches!(
+                    order.kind,
+                    OrderKind::Limit | OrderKind::StopMarket { .. } | OrderKind::StopLimit { .. }
+                )
+            })
+            .map(|order| {
+                let resting =
+                    self.resting_kind(&order.key.instrument, order.side, order.price, order.kind);
+                (order.key.cid.clone(), resting)
+            })
+            .collect::<Vec<_>>();
+
+        for (cid, resting) in matches {
+            if let Some(kind) = resting {
+                // eg/ Triggered StopLimit resting as a Limit order
+                if let Some(order) = self.account.order_open_mut(&cid) {
+                    order.kind = kind;
+                }
+                continue;
+            }
+
+            let Some(order) = self.account.remove_order_open(&cid) else {
+                continue;
+            };
+
+            let request = OrderEvent {
+                key: order.key,
+                state: RequestOpen {
+                    side: order.side,
+                    price: order.price,
+                    quantity: order.quantity,
+                    kind: order.kind,
+                    time_in_force: order.time_in_force,
+                    reduce_only: order.reduce_only,
+                },
+            };
+
+            match self.fill_order(request, Some(order.state.id.clone())) {
+                (_, Some(notifications)) => {
+                    self.account.ack_trade(notifications.trade.clone());
+                    self.send_notifications_with_latency(notifications);
+                }
+                (response, None) => {
+                    error!(
+                        exchange = %self.exchange,
+                        ?response,
+                        "MockExchange failed to fill resting order - cancelling"
+                    );
+                    self.cancel_order_unfillable(response, order.state.id);
+                }
+            }
+        }
+    }
+
+    /// Cancel a resting order that could not be filled once marketable (eg/ a `reduce_only` stop
+    /// that would flip the position), notifying the client of the cancellation.
+    fn cancel_order_unfillable(
+        &mut self,
+        order: Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        id: OrderId,
+    ) {
+        let cancelled = Cancelled {
+            id,
+            time_exchange: self.time_exchange(),
+        };
+
+        self.account.insert_order_cancelled(Order {
+            key: order.key.clone(),
+            side: order.side,
+            price: order.price,
+            quantity: order.quantity,
+            kind: order.kind,
+            time_in_force: order.time_in_force,
+            reduce_only: order.reduce_only,
+            state: cancelled.clone(),
+        });
+
+        let event = self.build_account_event(Snapshot(Order {
+            key: order.key,
+            side: order.side,
+            price: order.price,
+            quantity: order.quantity,
+            kind: order.kind,
+            time_in_force: order.time_in_force,
+            reduce_only: order.reduce_only,
+            state: OrderState::inactive(cancelled),
+        }));
+
+        let exchange = self.exchange;
+        let latency = std::time::Duration::from_millis(self.latency_ms);
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(latency).await;
+
+            if tx.send(event).is_err() {
+                error!(
+                    %exchange,
+                    kind = "Snapshot<Order<ExchangeId, InstrumentNameExchange, OrderState>>",
+                    "MockExchange failed to send AccountEvent notification to client"
+                );
+            }
+        });
+    }
+
+    /// Determine the price used to trigger stop orders of the provided instrument.
+    ///
+    /// The [`MockPriceFeed`] only provides top of book quotes, so the mid price is used for both
+    /// [`StopTrigger::LastPrice`] and [`StopTrigger::MarkPrice`].
+    pub fn stop_reference_price(&self, instrument: &InstrumentNameExchange) -> Option<Decimal> {
+        self.price_feed
+            .as_ref()
+            .and_then(|feed| feed.quote(self.exchange, instrument))
+            .map(|quote| (quote.bid + quote.ask) / Decimal::TWO)
+    }
+
+    /// Determine the price of a fill, applying the adverse [`SlippageModel`] slippage.
+    ///
+    /// If a [`MockPriceFeed`] is configured and contains a quote for the instrument, the fill is
+    /// priced from the quote, otherwise the requested price is used.
+    pub fn price_fill(
+        &self,
+        instrument: &InstrumentNameExchange,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Decimal {
+        let quote = self
+            .price_feed
+            .as_ref()
+            .and_then(|feed| feed.quote(self.exchange, instrument));
+
+        let price = quote.map_or(price, |quote| quote.price(side));
+
+        self.slippage
+            .fill_price(side, price, quantity, quote.as_ref())
+    }
+
     pub fn validate_order_kind_supported(
         &self,
         order_kind: OrderKind,
     ) -> Result<(), UnindexedOrderError> {
-        if order_kind == OrderKind::Market {
+        if matches!(
+            order_kind,
+            OrderKind::Market | OrderKind::StopMarket { .. } | OrderKind::StopLimit { .. }
+        ) {
             Ok(())
         } else {
             Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
@@ -412,10 +917,16 @@ impl MockExchange {
     where
         Kind: Into<AccountEventKind<ExchangeId, AssetNameExchange, InstrumentNameExchange>>,
     {
-        UnindexedAccountEvent {
-            exchange: self.exchange,
-            kind: kind.into(),
-        }
+        UnindexedAccountEvent::new(self.exchange, kind)
+    }
+}
+
+/// Resolves once the provided [`MockPriceFeed`] update receiver is notified, or never if there is
+/// no [`MockPriceFeed`].
+async fn price_updated(price_updates: &mut Option<watch::Receiver<()>>) -> Option<()> {
+    match price_updates {
+        Some(price_updates) => price_updates.changed().await.ok(),
+        None => std::future::pending().await,
     }
 }
 
@@ -433,6 +944,7 @@ where
         quantity: request.state.quantity,
         kind: request.state.kind,
         time_in_force: request.state.time_in_force,
+        reduce_only: request.state.reduce_only,
         state: Err(error.into()),
     }
 }
@@ -442,3 +954,454 @@ pub struct OpenOrderNotifications {
     pub balance: Snapshot<AssetBalance<AssetNameExchange>>,
     pub trade: Trade<QuoteAsset, InstrumentNameExchange>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        InstrumentAccountSnapshot, UnindexedAccountSnapshot,
+        balance::Balance,
+        client::mock::failure::MockFailureConfig,
+        exchange::mock::{price::MockQuote, slippage::SlippageConfig},
+        order::{
+            OrderKey, OrderSnapshot, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            state::OrderState,
+        },
+    };
+    use barter_instrument::Underlying;
+
+    fn instrument() -> InstrumentNameExchange {
+        InstrumentNameExchange::new("BTCUSDT")
+    }
+
+    fn quote(feed: &MockPriceFeed, bid: i64, ask: i64) {
+        feed.update(
+            ExchangeId::BinanceSpot,
+            instrument(),
+            MockQuote::new(Decimal::from(bid), Decimal::from(ask)),
+        );
+    }
+
+    fn exchange(
+        feed: &MockPriceFeed,
+    ) -> (
+        MockExchange,
+        mpsc::UnboundedSender<MockExchangeRequest>,
+        broadcast::Receiver<UnindexedAccountEvent>,
+    ) {
+        exchange_with_orders(feed, vec![])
+    }
+
+    fn exchange_with_orders(
+        feed: &MockPriceFeed,
+        orders: Vec<OrderSnapshot<ExchangeId, AssetNameExchange, InstrumentNameExchange>>,
+    ) -> (
+        MockExchange,
+        mpsc::UnboundedSender<MockExchangeRequest>,
+        broadcast::Receiver<UnindexedAccountEvent>,
+    ) {
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = broadcast::channel(16);
+
+        let config = MockExecutionConfig::new(
+            ExchangeId::BinanceSpot,
+            UnindexedAccountSnapshot {
+                exchange: ExchangeId::BinanceSpot,
+                balances: vec![AssetBalance {
+                    asset: AssetNameExchange::from("usdt"),
+                    balance: Balance::new(Decimal::from(10_000), Decimal::from(10_000)),
+                    time_exchange: DateTime::<Utc>::MIN_UTC,
+                }],
+                instruments: vec![InstrumentAccountSnapshot {
+                    instrument: instrument(),
+                    orders,
+                }],
+            },
+            0,
+            Decimal::ZERO,
+            SlippageConfig::default(),
+            MockFailureConfig::default(),
+        );
+
+        let instruments = FnvHashMap::from_iter([(
+            instrument(),
+            Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ),
+        )]);
+
+        let exchange = MockExchange::new(config, request_rx, event_tx, instruments)
+            .with_price_feed(feed.clone());
+
+        (exchange, request_tx, event_rx)
+    }
+
+    fn request_open(
+        cid: &str,
+        side: Side,
+        price: i64,
+        kind: OrderKind,
+    ) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+        OrderEvent {
+            key: OrderKey {
+                exchange: ExchangeId::BinanceSpot,
+                instrument: instrument(),
+                strategy: StrategyId::new("test"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price: Decimal::from(price),
+                quantity: Decimal::ONE,
+                kind,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn request_cancel(cid: &str) -> OrderRequestCancel<ExchangeId, InstrumentNameExchange> {
+        OrderEvent {
+            key: OrderKey {
+                exchange: ExchangeId::BinanceSpot,
+                instrument: instrument(),
+                strategy: StrategyId::new("test"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: Default::default(),
+        }
+    }
+
+    fn stop_market(trigger_price: i64) -> OrderKind {
+        OrderKind::StopMarket {
+            trigger_price: Decimal::from(trigger_price),
+            trigger: StopTrigger::LastPrice,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_stop_market_rests_as_open_order_until_triggered() {
+        let feed = MockPriceFeed::default();
+        let (mut exchange, _request_tx, _event_rx) = exchange(&feed);
+        quote(&feed, 99, 101);
+
+        let (response, notifications) =
+            exchange.open_order(request_open("stop", Side::Buy, 100, stop_market(105)));
+        let order_id = response.state.unwrap().id;
+        assert!(notifications.is_none());
+
+        // Resting stop is reported as an open order
+        let orders_open = exchange.account.orders_open().collect::<Vec<_>>();
+        assert_eq!(orders_open.len(), 1);
+        assert_eq!(orders_open[0].state.id, order_id);
+        assert_eq!(orders_open[0].kind, stop_market(105));
+
+        // Mid price below trigger price
+        quote(&feed, 103, 105);
+        exchange.fill_orders_open();
+        assert_eq!(exchange.account.orders_open().count(), 1);
+
+        // Mid price reaches trigger price, so the stop fills at the ask
+        quote(&feed, 104, 106);
+        exchange.fill_orders_open();
+        assert_eq!(exchange.account.orders_open().count(), 0);
+
+        let trades = exchange
+            .account
+            .trades(DateTime::<Utc>::MIN_UTC)
+            .collect::<Vec<_>>();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].order_id, order_id);
+        assert_eq!(trades[0].price, Decimal::from(106));
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_stop_limit_fills_once_triggered_and_marketable() {
+        let feed = MockPriceFeed::default();
+        let (mut exchange, _request_tx, _event_rx) = exchange(&feed);
+        quote(&feed, 99, 101);
+
+        let stop_limit = OrderKind::StopLimit {
+            trigger_price: Decimal::from(105),
+            trigger: StopTrigger::LastPrice,
+        };
+
+        let (response, notifications) =
+            exchange.open_order(request_open("stop", Side::Buy, 107, stop_limit));
+        assert!(response.state.is_ok());
+        assert!(notifications.is_none());
+
+        // Triggered, but the ask is above the limit price, so rests as a Limit order
+        quote(&feed, 107, 109);
+        exchange.fill_orders_open();
+        let orders_open = exchange.account.orders_open().collect::<Vec<_>>();
+        assert_eq!(orders_open.len(), 1);
+        assert_eq!(orders_open[0].kind, OrderKind::Limit);
+
+        // Mid price falls back below the trigger price, but the triggered Limit is marketable
+        quote(&feed, 101, 103);
+        exchange.fill_orders_open();
+        assert_eq!(exchange.account.orders_open().count(), 0);
+
+        let trades = exchange
+            .account
+            .trades(DateTime::<Utc>::MIN_UTC)
+            .collect::<Vec<_>>();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].price, Decimal::from(103));
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_cancel_order() {
+        let feed = MockPriceFeed::default();
+        let (mut exchange, _request_tx, _event_rx) = exchange(&feed);
+        quote(&feed, 99, 101);
+
+        let (response, _) =
+            exchange.open_order(request_open("stop", Side::Sell, 100, stop_market(95)));
+        let order_id = response.state.unwrap().id;
+
+        // Cancel resting stop
+        let cancelled = exchange.cancel_order(request_cancel("stop"));
+        assert_eq!(cancelled.state.unwrap().id, order_id);
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert_eq!(exchange.account.orders_cancelled().count(), 1);
+
+        // Cancelled stop is never filled
+        quote(&feed, 89, 91);
+        exchange.fill_orders_open();
+        assert_eq!(exchange.account.trades(DateTime::<Utc>::MIN_UTC).count(), 0);
+
+        // Cancel already cancelled order
+        let cancelled = exchange.cancel_order(request_cancel("stop"));
+        assert_eq!(
+            cancelled.state.unwrap_err(),
+            UnindexedOrderError::Rejected(ApiError::OrderAlreadyCancelled)
+        );
+
+        // Cancel unknown order
+        let cancelled = exchange.cancel_order(request_cancel("unknown"));
+        assert!(matches!(
+            cancelled.state,
+            Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_price_update_fills_resting_stop() {
+        let feed = MockPriceFeed::default();
+        let (exchange, request_tx, mut event_rx) = exchange(&feed);
+        quote(&feed, 99, 101);
+        tokio::spawn(exchange.run());
+
+        let (response_tx, response_rx) = oneshot::channel();
+        request_tx
+            .send(MockExchangeRequest::open_order(
+                DateTime::<Utc>::MIN_UTC,
+                response_tx,
+                request_open("stop", Side::Sell, 100, stop_market(95)),
+            ))
+            .unwrap();
+        let order_id = response_rx.await.unwrap().state.unwrap().id;
+
+        // Price update alone (ie/ no further requests) fills the triggered stop
+        quote(&feed, 89, 91);
+
+        let trade = loop {
+            let event = event_rx.recv().await.unwrap();
+            if let AccountEventKind::Trade(trade) = event.kind {
+                break trade;
+            }
+        };
+
+        assert_eq!(trade.order_id, order_id);
+        assert_eq!(trade.price, Decimal::from(89));
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_continues_order_sequence_after_initial_open_orders() {
+        let feed = MockPriceFeed::default();
+        let request = request_open("resting", Side::Buy, 90, OrderKind::Limit);
+        let resting = Order {
+            key: request.key,
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state: OrderState::active(Open::new(
+                OrderId::new("7"),
+                DateTime::<Utc>::MIN_UTC,
+                Decimal::ZERO,
+            )),
+        };
+        let (mut exchange, _request_tx, _event_rx) = exchange_with_orders(&feed, vec![resting]);
+        quote(&feed, 99, 101);
+
+        let (response, _) =
+            exchange.open_order(request_open("stop", Side::Buy, 100, stop_market(105)));
+        assert_eq!(response.state.unwrap().id, OrderId::new("8"));
+        assert_eq!(exchange.account.orders_open().count(), 2);
+    }
+
+    fn request_reduce_only(
+        cid: &str,
+        side: Side,
+        price: i64,
+        quantity: i64,
+        kind: OrderKind,
+    ) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+        let mut request = request_open(cid, side, price, kind);
+        request.state.quantity = Decimal::from(quantity);
+        request.state.reduce_only = true;
+        request
+    }
+
+    fn open_and_ack(
+        exchange: &mut MockExchange,
+        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+    ) -> Result<Open, UnindexedOrderError> {
+        let (response, notifications) = exchange.open_order(request);
+        if let Some(notifications) = notifications {
+            exchange.account.ack_trade(notifications.trade);
+        }
+        response.state
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_reduce_only() {
+        let feed = MockPriceFeed::default();
+        let (mut exchange, _request_tx, _event_rx) = exchange(&feed);
+        quote(&feed, 99, 101);
+
+        // No position to reduce
+        let response = open_and_ack(
+            &mut exchange,
+            request_reduce_only("flat", Side::Sell, 100, 1, OrderKind::Market),
+        );
+        assert!(matches!(
+            response,
+            Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(_)))
+        ));
+
+        // Open LONG position
+        let response = open_and_ack(
+            &mut exchange,
+            request_open("entry", Side::Buy, 100, OrderKind::Market),
+        );
+        assert!(response.is_ok());
+
+        // Would increase the LONG position
+        let response = open_and_ack(
+            &mut exchange,
+            request_reduce_only("increase", Side::Buy, 100, 1, OrderKind::Market),
+        );
+        assert!(response.is_err());
+
+        // Would flip the LONG position to SHORT
+        let response = open_and_ack(
+            &mut exchange,
+            request_reduce_only("flip", Side::Sell, 100, 2, OrderKind::Market),
+        );
+        assert!(response.is_err());
+
+        // Reduces the LONG position
+        let response = open_and_ack(
+            &mut exchange,
+            request_reduce_only("exit", Side::Sell, 100, 1, OrderKind::Market),
+        );
+        assert!(response.is_ok());
+        assert_eq!(exchange.account.trades(DateTime::<Utc>::MIN_UTC).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_cancels_triggered_reduce_only_stop_that_would_flip_position() {
+        let feed = MockPriceFeed::default();
+        let (mut exchange, _request_tx, mut event_rx) = exchange(&feed);
+        quote(&feed, 99, 101);
+
+        // Open LONG position, protected by a resting reduce only stop
+        let response = open_and_ack(
+            &mut exchange,
+            request_open("entry", Side::Buy, 100, OrderKind::Market),
+        );
+        assert!(response.is_ok());
+
+        let stop = open_and_ack(
+            &mut exchange,
+            request_reduce_only("stop", Side::Sell, 100, 1, stop_market(95)),
+        )
+        .unwrap();
+        assert_eq!(exchange.account.orders_open().count(), 1);
+
+        // Position is exited before the stop triggers
+        let response = open_and_ack(
+            &mut exchange,
+            request_open("exit", Side::Sell, 100, OrderKind::Market),
+        );
+        assert!(response.is_ok());
+
+        // Triggered stop would flip the position, so is cancelled rather than filled
+        quote(&feed, 89, 91);
+        exchange.fill_orders_open();
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert_eq!(exchange.account.orders_cancelled().count(), 1);
+        assert_eq!(exchange.account.trades(DateTime::<Utc>::MIN_UTC).count(), 2);
+
+        let event = event_rx.recv().await.unwrap();
+        let AccountEventKind::OrderSnapshot(Snapshot(order)) = event.kind else {
+            panic!("expected OrderSnapshot, found: {event:?}");
+        };
+        assert!(order.reduce_only);
+        assert_eq!(
+            order.state,
+            OrderState::inactive(Cancelled {
+                id: stop.id,
+                time_exchange: exchange.time_exchange(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_reduce_only_defaults_to_false_when_missing() {
+        let mut request = request_open("cid", Side::Buy, 100, OrderKind::Market);
+        request.state.reduce_only = true;
+
+        let mut json = serde_json::to_value(&request.state).unwrap();
+        json.as_object_mut().unwrap().remove("reduce_only");
+        let request_open = serde_json::from_value::<RequestOpen>(json).unwrap();
+        assert!(!request_open.reduce_only);
+
+        let order = Order::from(&request);
+        let order = Order::new(
+            order.key,
+            order.side,
+            order.price,
+            order.quantity,
+            order.kind,
+            order.time_in_force,
+            order.reduce_only,
+            OrderState::<AssetNameExchange, InstrumentNameExchange>::inactive(Cancelled {
+                id: OrderId::new("1"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+            }),
+        );
+        let mut json = serde_json::to_value(order).unwrap();
+        json.as_object_mut().unwrap().remove("reduce_only");
+        let order = serde_json::from_value::<
+            Order<
+                ExchangeId,
+                InstrumentNameExchange,
+                OrderState<AssetNameExchange, InstrumentNameExchange>,
+            >,
+        >(json)
+        .unwrap();
+        assert!(!order.reduce_only);
+    }
+}
@@ -0,0 +1,123 @@
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, PoisonError, RwLock};
+use tokio::sync::watch;
+
+/// Latest top of book quote for an instrument.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct MockQuote {
+    pub bid: Decimal,
+    pub ask: Decimal,
+}
+
+impl MockQuote {
+    /// Price a market order of the provided [`Side`] would be filled at (ie/ crossing the
+    /// spread).
+    pub fn price(&self, side: Side) -> Decimal {
+        match side {
+            Side::Buy => self.ask,
+            Side::Sell => self.bid,
+        }
+    }
+}
+
+/// Shared feed of the latest [`MockQuote`] for each exchange instrument.
+///
+/// Enables the [`MockExchange`](super::MockExchange) to fill market orders at realistic live
+/// market prices (eg/ when paper trading), rather than at the requested order price.
+///
+/// Each update notifies [`MockPriceFeed::subscribe`] receivers, so resting orders (eg/ stops) can
+/// be matched as soon as the market moves.
+#[derive(Debug, Clone)]
+pub struct MockPriceFeed {
+    quotes: Arc<RwLock<FnvHashMap<(ExchangeId, InstrumentNameExchange), MockQuote>>>,
+    updates: Arc<watch::Sender<()>>,
+}
+
+impl Default for MockPriceFeed {
+    fn default() -> Self {
+        Self {
+            quotes: Arc::default(),
+            updates: Arc::new(watch::Sender::new(())),
+        }
+    }
+}
+
+impl MockPriceFeed {
+    /// Update the latest [`MockQuote`] of the provided exchange instrument.
+    pub fn update(
+        &self,
+        exchange: ExchangeId,
+        instrument: InstrumentNameExchange,
+        quote: MockQuote,
+    ) {
+        self.quotes
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert((exchange, instrument), quote);
+
+        self.updates.send_modify(|_| {});
+    }
+
+    /// Subscribe to notifications of [`MockQuote`] updates.
+    pub fn subscribe(&self) -> watch::Receiver<()> {
+        self.updates.subscribe()
+    }
+
+    /// Return the latest [`MockQuote`] of the provided exchange instrument, if any.
+    pub fn quote(
+        &self,
+        exchange: ExchangeId,
+        instrument: &InstrumentNameExchange,
+    ) -> Option<MockQuote> {
+        self.quotes
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(exchange, instrument.clone()))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_price_feed() {
+        let feed = MockPriceFeed::default();
+        let updates = feed.subscribe();
+        let instrument = InstrumentNameExchange::new("BTC-USDT");
+
+        assert_eq!(feed.quote(ExchangeId::BinanceSpot, &instrument), None);
+
+        // Clones share the same feed
+        feed.clone().update(
+            ExchangeId::BinanceSpot,
+            instrument.clone(),
+            MockQuote::new(Decimal::from(99), Decimal::from(101)),
+        );
+
+        assert!(updates.has_changed().unwrap());
+
+        let quote = feed.quote(ExchangeId::BinanceSpot, &instrument).unwrap();
+        assert_eq!(quote.price(Side::Buy), Decimal::from(101));
+        assert_eq!(quote.price(Side::Sell), Decimal::from(99));
+        assert_eq!(feed.quote(ExchangeId::Okx, &instrument), None);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     UnindexedAccountSnapshot,
     balance::AssetBalance,
-    error::UnindexedOrderError,
+    error::{UnindexedClientError, UnindexedOrderError},
+    margin::MarginMode,
     order::{
         Order,
         request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
@@ -10,11 +11,13 @@ use crate::{
     trade::Trade,
 };
 use barter_instrument::{
+    Keyed,
     asset::{QuoteAsset, name::AssetNameExchange},
     exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    instrument::{name::InstrumentNameExchange, spec::InstrumentSpec},
 };
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use tokio::sync::oneshot;
 
 #[derive(Debug)]
@@ -38,6 +41,22 @@ impl MockExchangeRequest {
         )
     }
 
+    pub fn fetch_instrument_specs(
+        time_request: DateTime<Utc>,
+        response_tx: oneshot::Sender<
+            Vec<Keyed<InstrumentNameExchange, InstrumentSpec<AssetNameExchange>>>,
+        >,
+        instruments: Vec<InstrumentNameExchange>,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::FetchInstrumentSpecs {
+                response_tx,
+                instruments,
+            },
+        )
+    }
+
     pub fn fetch_balances(
         time_request: DateTime<Utc>,
         response_tx: oneshot::Sender<Vec<AssetBalance<AssetNameExchange>>>,
@@ -72,6 +91,38 @@ impl MockExchangeRequest {
         )
     }
 
+    pub fn set_leverage(
+        time_request: DateTime<Utc>,
+        response_tx: oneshot::Sender<Result<Decimal, UnindexedClientError>>,
+        instrument: InstrumentNameExchange,
+        leverage: Decimal,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::SetLeverage {
+                response_tx,
+                instrument,
+                leverage,
+            },
+        )
+    }
+
+    pub fn set_margin_mode(
+        time_request: DateTime<Utc>,
+        response_tx: oneshot::Sender<Result<MarginMode, UnindexedClientError>>,
+        instrument: InstrumentNameExchange,
+        mode: MarginMode,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::SetMarginMode {
+                response_tx,
+                instrument,
+                mode,
+            },
+        )
+    }
+
     pub fn cancel_order(
         time_request: DateTime<Utc>,
         response_tx: oneshot::Sender<UnindexedOrderResponseCancel>,
@@ -108,6 +159,11 @@ pub enum MockExchangeRequestKind {
     FetchAccountSnapshot {
         response_tx: oneshot::Sender<UnindexedAccountSnapshot>,
     },
+    FetchInstrumentSpecs {
+        response_tx:
+            oneshot::Sender<Vec<Keyed<InstrumentNameExchange, InstrumentSpec<AssetNameExchange>>>>,
+        instruments: Vec<InstrumentNameExchange>,
+    },
     FetchBalances {
         response_tx: oneshot::Sender<Vec<AssetBalance<AssetNameExchange>>>,
     },
@@ -118,6 +174,16 @@ pub enum MockExchangeRequestKind {
         response_tx: oneshot::Sender<Vec<Trade<QuoteAsset, InstrumentNameExchange>>>,
         time_since: DateTime<Utc>,
     },
+    SetLeverage {
+        response_tx: oneshot::Sender<Result<Decimal, UnindexedClientError>>,
+        instrument: InstrumentNameExchange,
+        leverage: Decimal,
+    },
+    SetMarginMode {
+        response_tx: oneshot::Sender<Result<MarginMode, UnindexedClientError>>,
+        instrument: InstrumentNameExchange,
+        mode: MarginMode,
+    },
     CancelOrder {
         response_tx: oneshot::Sender<UnindexedOrderResponseCancel>,
         request: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
@@ -0,0 +1,277 @@
+use crate::exchange::mock::price::MockQuote;
+use barter_instrument::Side;
+use derive_more::{Constructor, From};
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Number of basis points in one (ie/ 100%).
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Model of the adverse slippage incurred when filling an order in the simulated
+/// [`MockExchange`](super::MockExchange) execution path.
+///
+/// Injecting different `SlippageModel`s enables the same strategy to be stressed under
+/// different execution-quality assumptions.
+pub trait SlippageModel: Debug {
+    /// Calculate the non-negative adverse price slippage of filling an order of the provided
+    /// [`Side`] and quantity at the reference price.
+    ///
+    /// The latest [`MockQuote`] of the instrument is provided, if available.
+    fn slippage(
+        &self,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        quote: Option<&MockQuote>,
+    ) -> Decimal;
+
+    /// Determine the fill price of an order after applying the adverse [`Self::slippage`].
+    fn fill_price(
+        &self,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        quote: Option<&MockQuote>,
+    ) -> Decimal {
+        let slippage = self.slippage(side, price, quantity, quote).abs();
+        match side {
+            Side::Buy => price + slippage,
+            Side::Sell => price - slippage,
+        }
+    }
+}
+
+/// Fixed slippage of a number of basis points of the fill price.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct FixedBps {
+    /// Adverse slippage in basis points (eg/ 5 for 0.05%).
+    pub bps: Decimal,
+}
+
+impl FixedBps {
+    /// Construct a [`FixedBps`] from a slippage fraction of the fill price (eg/ 0.0005 for 5
+    /// bps).
+    pub fn from_percent(percent: Decimal) -> Self {
+        Self::new(percent * BPS_PER_UNIT)
+    }
+}
+
+impl SlippageModel for FixedBps {
+    fn slippage(&self, _: Side, price: Decimal, _: Decimal, _: Option<&MockQuote>) -> Decimal {
+        price.abs() * self.bps / BPS_PER_UNIT
+    }
+}
+
+/// Slippage proportional to the latest quoted bid-ask spread of the instrument.
+///
+/// eg/ a `fraction` of 0.5 fills orders half a spread beyond the touch. No slippage is applied
+/// if no [`MockQuote`] is available.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct SpreadProportional {
+    pub fraction: Decimal,
+}
+
+impl SlippageModel for SpreadProportional {
+    fn slippage(&self, _: Side, _: Decimal, _: Decimal, quote: Option<&MockQuote>) -> Decimal {
+        quote.map_or(Decimal::ZERO, |quote| {
+            (quote.ask - quote.bid).max(Decimal::ZERO) * self.fraction
+        })
+    }
+}
+
+/// Square-root market impact model, where slippage grows with the square root of the order
+/// quantity as a proportion of the traded volume.
+///
+/// ie/ `slippage = price * coefficient * sqrt(quantity / volume)`
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct SquareRootImpact {
+    /// Impact coefficient, typically of the order of the instrument's daily volatility.
+    pub coefficient: Decimal,
+
+    /// Reference traded volume (eg/ average daily volume) in instrument quantity units.
+    pub volume: Decimal,
+}
+
+impl SlippageModel for SquareRootImpact {
+    fn slippage(
+        &self,
+        _: Side,
+        price: Decimal,
+        quantity: Decimal,
+        _: Option<&MockQuote>,
+    ) -> Decimal {
+        if self.volume <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        (quantity.abs() / self.volume)
+            .sqrt()
+            .map_or(Decimal::ZERO, |participation| {
+                price.abs() * self.coefficient * participation
+            })
+    }
+}
+
+/// Serialisable configuration of the built-in [`SlippageModel`]s.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, From,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SlippageConfig {
+    FixedBps(FixedBps),
+    SpreadProportional(SpreadProportional),
+    SquareRootImpact(SquareRootImpact),
+}
+
+impl Default for SlippageConfig {
+    fn default() -> Self {
+        Self::FixedBps(FixedBps::default())
+    }
+}
+
+impl SlippageModel for SlippageConfig {
+    fn slippage(
+        &self,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        quote: Option<&MockQuote>,
+    ) -> Decimal {
+        match self {
+            SlippageConfig::FixedBps(model) => model.slippage(side, price, quantity, quote),
+            SlippageConfig::SpreadProportional(model) => {
+                model.slippage(side, price, quantity, quote)
+            }
+            SlippageConfig::SquareRootImpact(model) => model.slippage(side, price, quantity, quote),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slippage_model_fill_price() {
+        struct TestCase {
+            model: SlippageConfig,
+            side: Side,
+            quantity: Decimal,
+            quote: Option<MockQuote>,
+            expected: Decimal,
+        }
+
+        let price = Decimal::new(100, 0);
+        let quote = MockQuote::new(Decimal::new(99, 0), Decimal::new(100, 0));
+
+        let cases = vec![
+            // TC0: zero slippage by default
+            TestCase {
+                model: SlippageConfig::default(),
+                side: Side::Buy,
+                quantity: Decimal::ONE,
+                quote: None,
+                expected: Decimal::new(100, 0),
+            },
+            // TC1: fixed 50 bps slippage on a Buy
+            TestCase {
+                model: FixedBps::new(Decimal::new(50, 0)).into(),
+                side: Side::Buy,
+                quantity: Decimal::ONE,
+                quote: None,
+                expected: Decimal::new(1005, 1),
+            },
+            // TC2: fixed 50 bps slippage on a Sell
+            TestCase {
+                model: FixedBps::from_percent(Decimal::new(5, 3)).into(),
+                side: Side::Sell,
+                quantity: Decimal::ONE,
+                quote: None,
+                expected: Decimal::new(995, 1),
+            },
+            // TC3: half spread slippage on a Sell
+            TestCase {
+                model: SpreadProportional::new(Decimal::new(5, 1)).into(),
+                side: Side::Sell,
+                quantity: Decimal::ONE,
+                quote: Some(quote),
+                expected: Decimal::new(995, 1),
+            },
+            // TC4: spread proportional slippage without a quote is zero
+            TestCase {
+                model: SpreadProportional::new(Decimal::new(5, 1)).into(),
+                side: Side::Buy,
+                quantity: Decimal::ONE,
+                quote: None,
+                expected: Decimal::new(100, 0),
+            },
+            // TC5: square-root impact of 4% of volume with 0.1 coefficient is 2%
+            TestCase {
+                model: SquareRootImpact::new(Decimal::new(1, 1), Decimal::new(100, 0)).into(),
+                side: Side::Buy,
+                quantity: Decimal::new(4, 0),
+                quote: None,
+                expected: Decimal::new(102, 0),
+            },
+            // TC6: square-root impact quadruple quantity doubles slippage
+            TestCase {
+                model: SquareRootImpact::new(Decimal::new(1, 1), Decimal::new(100, 0)).into(),
+                side: Side::Sell,
+                quantity: Decimal::new(16, 0),
+                quote: None,
+                expected: Decimal::new(96, 0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                test.model
+                    .fill_price(test.side, price, test.quantity, test.quote.as_ref());
+            assert_eq!(
+                actual.normalize(),
+                test.expected.normalize(),
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Periodic funding payment of a perpetual instrument position, either received from or paid
+/// to the exchange.
+///
+/// Sourced from exchange income history or account websocket streams.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct FundingPayment<AssetKey, InstrumentKey> {
+    pub instrument: InstrumentKey,
+    /// Asset the funding payment is settled in.
+    pub asset: AssetKey,
+    /// Signed funding payment amount, positive if received and negative if paid.
+    pub amount: Decimal,
+    /// Funding rate applied, if reported by the exchange.
+    pub rate: Option<Decimal>,
+    pub time_exchange: DateTime<Utc>,
+}
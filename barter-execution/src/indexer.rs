@@ -6,6 +6,7 @@ use crate::{
         ApiError, ClientError, KeyError, OrderError, UnindexedApiError, UnindexedClientError,
         UnindexedOrderError,
     },
+    funding::FundingPayment,
     map::ExecutionInstrumentMap,
     order::{
         Order, OrderEvent, OrderKey, OrderSnapshot, UnindexedOrderKey, UnindexedOrderSnapshot,
@@ -45,7 +46,11 @@ impl Indexer for AccountEventIndexer {
 
 impl AccountEventIndexer {
     pub fn account_event(&self, event: UnindexedAccountEvent) -> Result<AccountEvent, IndexError> {
-        let UnindexedAccountEvent { exchange, kind } = event;
+        let UnindexedAccountEvent {
+            exchange,
+            sub_account,
+            kind,
+        } = event;
 
         let exchange = self.map.find_exchange_index(exchange)?;
 
@@ -56,6 +61,12 @@ impl AccountEventIndexer {
             AccountEventKind::BalanceSnapshot(snapshot) => {
                 AccountEventKind::BalanceSnapshot(self.asset_balance(snapshot.0).map(Snapshot)?)
             }
+            AccountEventKind::BalanceCheck(balances) => AccountEventKind::BalanceCheck(
+                balances
+                    .into_iter()
+                    .map(|balance| self.asset_balance(balance))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
             AccountEventKind::OrderSnapshot(snapshot) => {
                 AccountEventKind::OrderSnapshot(self.order_snapshot(snapshot.0).map(Snapshot)?)
             }
@@ -63,9 +74,16 @@ impl AccountEventIndexer {
                 AccountEventKind::OrderCancelled(self.order_response_cancel(response)?)
             }
             AccountEventKind::Trade(trade) => AccountEventKind::Trade(self.trade(trade)?),
+            AccountEventKind::FundingPayment(payment) => {
+                AccountEventKind::FundingPayment(self.funding_payment(payment)?)
+            }
         };
 
-        Ok(AccountEvent { exchange, kind })
+        Ok(AccountEvent {
+            exchange,
+            sub_account,
+            kind,
+        })
     }
 
     pub fn snapshot(
@@ -126,6 +144,27 @@ impl AccountEventIndexer {
         })
     }
 
+    pub fn funding_payment(
+        &self,
+        payment: FundingPayment<AssetNameExchange, InstrumentNameExchange>,
+    ) -> Result<FundingPayment<AssetIndex, InstrumentIndex>, IndexError> {
+        let FundingPayment {
+            instrument,
+            asset,
+            amount,
+            rate,
+            time_exchange,
+        } = payment;
+
+        Ok(FundingPayment {
+            instrument: self.map.find_instrument_index(&instrument)?,
+            asset: self.map.find_asset_index(&asset)?,
+            amount,
+            rate,
+            time_exchange,
+        })
+    }
+
     pub fn order_snapshot(
         &self,
         order: UnindexedOrderSnapshot,
@@ -137,6 +176,7 @@ impl AccountEventIndexer {
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = order;
 
@@ -166,6 +206,7 @@ impl AccountEventIndexer {
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         })
     }
@@ -216,6 +257,7 @@ impl AccountEventIndexer {
             UnindexedApiError::OrderRejected(reason) => ApiError::OrderRejected(reason),
             UnindexedApiError::OrderAlreadyCancelled => ApiError::OrderAlreadyCancelled,
             UnindexedApiError::OrderAlreadyFullyFilled => ApiError::OrderAlreadyFullyFilled,
+            UnindexedApiError::OrderInvalid(error) => ApiError::OrderInvalid(error),
         })
     }
 
@@ -264,6 +306,8 @@ impl AccountEventIndexer {
             UnindexedClientError::Api(error) => ClientError::Api(self.api_error(error)?),
             UnindexedClientError::AccountSnapshot(value) => ClientError::AccountSnapshot(value),
             UnindexedClientError::AccountStream(value) => ClientError::AccountStream(value),
+            UnindexedClientError::Leverage(value) => ClientError::Leverage(value),
+            UnindexedClientError::MarginMode(value) => ClientError::MarginMode(value),
         })
     }
 
@@ -274,12 +318,14 @@ impl AccountEventIndexer {
         let Trade {
             id,
             order_id,
+            cid,
             instrument,
             strategy,
             time_exchange,
             side,
             price,
             quantity,
+            liquidity,
             fees,
         } = trade;
 
@@ -288,12 +334,14 @@ impl AccountEventIndexer {
         Ok(Trade {
             id,
             order_id,
+            cid,
             instrument: instrument_index,
             strategy,
             time_exchange,
             side,
             price,
             quantity,
+            liquidity,
             fees,
         })
     }
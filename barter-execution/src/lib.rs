@@ -25,7 +25,9 @@
 
 use crate::{
     balance::AssetBalance,
+    funding::FundingPayment,
     order::{Order, OrderSnapshot, request::OrderResponseCancel},
+    sub_account::SubAccountId,
     trade::Trade,
 };
 use barter_instrument::{
@@ -41,11 +43,18 @@ use serde::{Deserialize, Serialize};
 
 pub mod balance;
 pub mod client;
+/// Shared, rotatable per-exchange API [`ApiCredentials`](credentials::ApiCredentials), loaded
+/// from environment variables or files.
+pub mod credentials;
 pub mod error;
 pub mod exchange;
+pub mod funding;
 pub mod indexer;
 pub mod map;
+pub mod margin;
 pub mod order;
+/// Exchange sub-account identifiers, and aggregation of balances across sub-accounts.
+pub mod sub_account;
 pub mod trade;
 
 /// Convenient type alias for an [`AccountEvent`] keyed with [`ExchangeId`],
@@ -65,6 +74,11 @@ pub struct AccountEvent<
     InstrumentKey = InstrumentIndex,
 > {
     pub exchange: ExchangeKey,
+
+    /// Exchange sub-account the `AccountEvent` relates to, or `None` for the master account.
+    #[serde(default)]
+    pub sub_account: Option<SubAccountId>,
+
     pub kind: AccountEventKind<ExchangeKey, AssetKey, InstrumentKey>,
 }
 
@@ -75,9 +89,18 @@ impl<ExchangeKey, AssetKey, InstrumentKey> AccountEvent<ExchangeKey, AssetKey, I
     {
         Self {
             exchange,
+            sub_account: None,
             kind: kind.into(),
         }
     }
+
+    /// Attribute the `AccountEvent` to the provided exchange sub-account.
+    pub fn with_sub_account(self, sub_account: Option<SubAccountId>) -> Self {
+        Self {
+            sub_account,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, From)]
@@ -88,6 +111,10 @@ pub enum AccountEventKind<ExchangeKey, AssetKey, InstrumentKey> {
     /// Single [`AssetBalance`] snapshot - replaces existing balance state.
     BalanceSnapshot(Snapshot<AssetBalance<AssetKey>>),
 
+    /// Periodically fetched [`AssetBalance`]s - used to detect drift from the internally tracked
+    /// balances, so does not replace existing balance state.
+    BalanceCheck(Vec<AssetBalance<AssetKey>>),
+
     /// Single [`Order`] snapshot - used to upsert existing order state if it's more recent.
     ///
     /// This variant covers general order updates, and open order responses.
@@ -98,6 +125,9 @@ pub enum AccountEventKind<ExchangeKey, AssetKey, InstrumentKey> {
 
     /// [`Order<ExchangeKey, InstrumentKey, Open>`] partial or full-fill.
     Trade(Trade<QuoteAsset, InstrumentKey>),
+
+    /// Perpetual instrument position [`FundingPayment`] received or paid.
+    FundingPayment(FundingPayment<AssetKey, InstrumentKey>),
 }
 
 impl<ExchangeKey, AssetKey, InstrumentKey> AccountEvent<ExchangeKey, AssetKey, InstrumentKey>
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Margin mode of a (eg/ futures) instrument position.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MarginMode {
+    /// Margin is shared across all positions using the account balance.
+    #[default]
+    Cross,
+
+    /// Margin is isolated to each position, limiting a liquidation loss to the position margin.
+    Isolated,
+}
+
+impl Display for MarginMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MarginMode::Cross => "cross",
+                MarginMode::Isolated => "isolated",
+            }
+        )
+    }
+}
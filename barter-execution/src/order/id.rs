@@ -1,7 +1,7 @@
 use derive_more::{Display, From};
 use rand::prelude::IndexedRandom;
 use serde::{Deserialize, Serialize};
-use smol_str::SmolStr;
+use smol_str::{SmolStr, format_smolstr};
 
 #[derive(
     Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display, From,
@@ -16,6 +16,15 @@ impl ClientOrderId<SmolStr> {
         Self(id.into())
     }
 
+    /// Construct a deterministic `ClientOrderId` from a [`StrategyId`] and a monotonically
+    /// increasing sequence number (eg/ "strategy_a-42").
+    ///
+    /// Unlike [`Self::random`], re-generating a `ClientOrderId` from the same inputs (eg/ when
+    /// replaying events) produces the same `ClientOrderId`.
+    pub fn from_sequence(strategy: &StrategyId, sequence: u64) -> Self {
+        Self(format_smolstr!("{}-{}", strategy.0, sequence))
+    }
+
     /// Construct a stack-allocated `ClientOrderId` backed by a 23 byte [`SmolStr`].
     pub fn random() -> Self {
         const LEN_URL_SAFE_SYMBOLS: usize = 64;
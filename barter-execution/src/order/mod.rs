@@ -28,6 +28,12 @@ pub mod state;
 /// ie/ `OrderRequestOpen` & `OrderRequestCancel`.
 pub mod request;
 
+/// Normalisation & validation of order requests against exchange
+/// [`InstrumentSpec`](barter_instrument::instrument::spec::InstrumentSpec)s.
+///
+/// eg/ rounding prices to the tick size & rejecting quantities below the minimum.
+pub mod validation;
+
 /// Convenient type alias for an [`Order`] keyed with [`ExchangeId`] and [`InstrumentNameExchange`].
 pub type UnindexedOrder = Order<ExchangeId, InstrumentNameExchange, UnindexedOrderState>;
 
@@ -79,6 +85,9 @@ pub struct Order<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex, S
     pub quantity: Decimal,
     pub kind: OrderKind,
     pub time_in_force: TimeInForce,
+    /// If true, the order may only reduce an existing position (see [`RequestOpen`]).
+    #[serde(default)]
+    pub reduce_only: bool,
     pub state: State,
 }
 
@@ -101,6 +110,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey>
             quantity: self.quantity,
             kind: self.kind,
             time_in_force: self.time_in_force,
+            reduce_only: self.reduce_only,
             state: state.clone(),
         })
     }
@@ -124,6 +134,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey>
             quantity: self.quantity,
             kind: self.kind,
             time_in_force: self.time_in_force,
+            reduce_only: self.reduce_only,
             state: state.clone(),
         })
     }
@@ -158,6 +169,80 @@ where
 pub enum OrderKind {
     Market,
     Limit,
+    /// Limit order that only displays the `display_quantity` in the order book at any one time,
+    /// hiding the remaining quantity.
+    ///
+    /// Exchanges that do not support iceberg orders natively (see
+    /// [`ExecutionClient::ICEBERG_NATIVE`](crate::client::ExecutionClient::ICEBERG_NATIVE)) can
+    /// emulate them by slicing the parent order into sequential `Limit` child orders.
+    #[display("Iceberg")]
+    Iceberg {
+        display_quantity: Decimal,
+    },
+    /// Market order that rests on the exchange until the [`StopTrigger`] price reaches the
+    /// `trigger_price`.
+    #[display("StopMarket")]
+    StopMarket {
+        trigger_price: Decimal,
+        trigger: StopTrigger,
+    },
+    /// Limit order at the requested price that is placed once the [`StopTrigger`] price reaches
+    /// the `trigger_price`.
+    #[display("StopLimit")]
+    StopLimit {
+        trigger_price: Decimal,
+        trigger: StopTrigger,
+    },
+}
+
+impl OrderKind {
+    /// Returns the `trigger_price` and [`StopTrigger`] if the `OrderKind` is a stop order.
+    pub fn stop(&self) -> Option<(Decimal, StopTrigger)> {
+        match self {
+            Self::StopMarket {
+                trigger_price,
+                trigger,
+            }
+            | Self::StopLimit {
+                trigger_price,
+                trigger,
+            } => Some((*trigger_price, *trigger)),
+            _ => None,
+        }
+    }
+}
+
+/// Price used to determine if a stop order has been triggered.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Display,
+)]
+pub enum StopTrigger {
+    #[default]
+    LastPrice,
+    MarkPrice,
+}
+
+impl StopTrigger {
+    /// Returns true if a stop order of the provided [`Side`] is triggered by the `price`.
+    ///
+    /// ie/ Buy stops trigger at or above the `trigger_price`, and sell stops trigger at or below.
+    pub fn is_triggered(side: Side, trigger_price: Decimal, price: Decimal) -> bool {
+        match side {
+            Side::Buy => price >= trigger_price,
+            Side::Sell => price <= trigger_price,
+        }
+    }
 }
 
 #[derive(
@@ -186,6 +271,7 @@ where
                     quantity,
                     kind,
                     time_in_force,
+                    reduce_only,
                 },
         } = value;
 
@@ -196,6 +282,7 @@ where
             quantity: *quantity,
             kind: *kind,
             time_in_force: *time_in_force,
+            reduce_only: *reduce_only,
             state: ActiveOrderState::OpenInFlight(OpenInFlight),
         }
     }
@@ -212,6 +299,7 @@ impl<ExchangeKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey, Open>>
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = value;
 
@@ -222,6 +310,7 @@ impl<ExchangeKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey, Open>>
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state: ActiveOrderState::Open(state),
         }
     }
@@ -238,6 +327,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = value;
 
@@ -248,6 +338,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state: OrderState::Active(ActiveOrderState::Open(state)),
         }
     }
@@ -264,6 +355,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = value;
 
@@ -274,6 +366,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state: OrderState::Inactive(InactiveOrderState::Cancelled(state)),
         }
     }
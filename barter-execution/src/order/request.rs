@@ -27,6 +27,17 @@ pub type OrderResponseCancel<
 pub type UnindexedOrderResponseCancel =
     OrderResponseCancel<ExchangeId, AssetNameExchange, InstrumentNameExchange>;
 
+/// One-cancels-other (OCO) group of open order requests, where a fill of any leg cancels the
+/// remaining legs.
+///
+/// eg/ A take-profit and a stop-loss exit for the same position, which must never both fill.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct OrderRequestOco<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    pub legs: Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+}
+
 #[derive(
     Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
 )]
@@ -36,6 +47,12 @@ pub struct RequestOpen {
     pub quantity: Decimal,
     pub kind: OrderKind,
     pub time_in_force: TimeInForce,
+    /// If true, the order may only reduce an existing position, and must never increase or
+    /// flip it (eg/ due to a delayed fill of an exit order).
+    ///
+    /// Honoured by execution clients for exchanges that support it (eg/ futures markets).
+    #[serde(default)]
+    pub reduce_only: bool,
 }
 
 #[derive(
@@ -4,7 +4,7 @@ use barter_instrument::{
     instrument::{InstrumentIndex, name::InstrumentNameExchange},
 };
 use chrono::{DateTime, Utc};
-use derive_more::{Constructor, From};
+use derive_more::{Constructor, Display, From};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -41,6 +41,20 @@ impl<AssetKey, InstrumentKey> OrderState<AssetKey, InstrumentKey> {
         Self::Inactive(InactiveOrderState::Expired)
     }
 
+    /// Determine the flattened [`OrderLifecycle`] stage of an order with the provided initial
+    /// quantity.
+    pub fn lifecycle(&self, quantity: Decimal) -> OrderLifecycle {
+        match self {
+            Self::Active(active) => active.lifecycle(quantity),
+            Self::Inactive(inactive) => match inactive {
+                InactiveOrderState::Cancelled(_) => OrderLifecycle::Cancelled,
+                InactiveOrderState::FullyFilled => OrderLifecycle::FullyFilled,
+                InactiveOrderState::OpenFailed(_) => OrderLifecycle::Rejected,
+                InactiveOrderState::Expired => OrderLifecycle::Expired,
+            },
+        }
+    }
+
     pub fn time_exchange(&self) -> Option<DateTime<Utc>> {
         match self {
             Self::Active(active) => match active {
@@ -73,6 +87,54 @@ impl ActiveOrderState {
             Self::CancelInFlight(cancel) => cancel.order.as_ref(),
         }
     }
+
+    /// Determine the flattened [`OrderLifecycle`] stage of an active order with the provided
+    /// initial quantity.
+    pub fn lifecycle(&self, quantity: Decimal) -> OrderLifecycle {
+        match self {
+            Self::OpenInFlight(_) => OrderLifecycle::OpenInFlight,
+            Self::Open(open) if open.quantity_remaining(quantity).is_zero() => {
+                OrderLifecycle::FullyFilled
+            }
+            Self::Open(open) if !open.filled_quantity.is_zero() => OrderLifecycle::PartiallyFilled,
+            Self::Open(_) => OrderLifecycle::Open,
+            Self::CancelInFlight(_) => OrderLifecycle::CancelInFlight,
+        }
+    }
+}
+
+/// Flattened lifecycle stage of an order, derived from its [`OrderState`].
+///
+/// Enables strategies and risk managers to reason about where an order is in its lifecycle
+/// without assuming a sent order is immediately live on the exchange.
+///
+/// Orders tend to progress through the following stages:
+/// 1. `OpenInFlight` - open request sent, but not yet confirmed by the exchange.
+/// 2. `Open` / `PartiallyFilled` - order confirmed as open on the exchange.
+/// 3. `CancelInFlight` - cancel request sent, but not yet confirmed by the exchange.
+/// 4. `FullyFilled` / `Cancelled` / `Rejected` / `Expired` - terminal stages.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+)]
+pub enum OrderLifecycle {
+    OpenInFlight,
+    Open,
+    PartiallyFilled,
+    FullyFilled,
+    CancelInFlight,
+    Cancelled,
+    Rejected,
+    Expired,
+}
+
+impl OrderLifecycle {
+    /// Returns true if the `OrderLifecycle` stage is terminal (ie/ the order is finished).
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::FullyFilled | Self::Cancelled | Self::Rejected | Self::Expired
+        )
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
@@ -0,0 +1,302 @@
+use crate::order::{OrderKind, request::RequestOpen};
+use barter_instrument::{
+    Side,
+    instrument::spec::{InstrumentSpec, OrderQuantityUnits},
+};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Represents all reasons an order cannot be made valid for an [`InstrumentSpec`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum OrderValidationError {
+    #[error("price {price} below instrument minimum {min}")]
+    PriceBelowMin { price: Decimal, min: Decimal },
+
+    #[error("quantity {quantity} below instrument minimum {min}")]
+    QuantityBelowMin { quantity: Decimal, min: Decimal },
+
+    #[error("quantity {quantity} above instrument maximum {max}")]
+    QuantityAboveMax { quantity: Decimal, max: Decimal },
+
+    #[error("notional {notional} below instrument minimum {min}")]
+    NotionalBelowMin { notional: Decimal, min: Decimal },
+}
+
+/// Normalise a [`RequestOpen`] against the provided [`InstrumentSpec`], returning an
+/// [`OrderValidationError`] if the order cannot be made valid.
+///
+/// Normalisation:
+/// - Limit prices are rounded to the tick size passively (ie/ Buy down, Sell up), so the order
+///   never crosses further than requested.
+/// - Stop trigger prices are rounded to the nearest tick size.
+/// - Quantities are rounded down to the quantity increment, so the order is never larger than
+///   requested.
+///
+/// A zero tick size or quantity increment is interpreted as unconstrained.
+pub fn normalise_open<AssetKey>(
+    request: RequestOpen,
+    spec: &InstrumentSpec<AssetKey>,
+) -> Result<RequestOpen, OrderValidationError> {
+    let price = match request.kind {
+        OrderKind::Market | OrderKind::StopMarket { .. } => request.price,
+        OrderKind::Limit | OrderKind::Iceberg { .. } | OrderKind::StopLimit { .. } => {
+            let strategy = match request.side {
+                Side::Buy => RoundingStrategy::ToNegativeInfinity,
+                Side::Sell => RoundingStrategy::ToPositiveInfinity,
+            };
+            let price = round_to_increment(request.price, spec.price.tick_size, strategy);
+
+            if price < spec.price.min {
+                return Err(OrderValidationError::PriceBelowMin {
+                    price,
+                    min: spec.price.min,
+                });
+            }
+
+            price
+        }
+    };
+
+    let kind = match request.kind {
+        OrderKind::Market | OrderKind::Limit => request.kind,
+        OrderKind::Iceberg { display_quantity } => OrderKind::Iceberg {
+            display_quantity: round_to_increment(
+                display_quantity,
+                spec.quantity.increment,
+                RoundingStrategy::ToZero,
+            ),
+        },
+        OrderKind::StopMarket {
+            trigger_price,
+            trigger,
+        } => OrderKind::StopMarket {
+            trigger_price: round_to_nearest_tick(trigger_price, spec.price.tick_size),
+            trigger,
+        },
+        OrderKind::StopLimit {
+            trigger_price,
+            trigger,
+        } => OrderKind::StopLimit {
+            trigger_price: round_to_nearest_tick(trigger_price, spec.price.tick_size),
+            trigger,
+        },
+    };
+
+    let quantity = round_to_increment(
+        request.quantity,
+        spec.quantity.increment,
+        RoundingStrategy::ToZero,
+    );
+
+    if quantity < spec.quantity.min {
+        return Err(OrderValidationError::QuantityBelowMin {
+            quantity,
+            min: spec.quantity.min,
+        });
+    }
+
+    if let Some(max) = spec.quantity.max.filter(|max| quantity > *max) {
+        return Err(OrderValidationError::QuantityAboveMax { quantity, max });
+    }
+
+    let notional = match spec.quantity.unit {
+        OrderQuantityUnits::Quote => quantity,
+        OrderQuantityUnits::Asset(_) | OrderQuantityUnits::Contract => quantity * price,
+    };
+
+    if notional < spec.notional.min {
+        return Err(OrderValidationError::NotionalBelowMin {
+            notional,
+            min: spec.notional.min,
+        });
+    }
+
+    Ok(RequestOpen {
+        price,
+        quantity,
+        kind,
+        ..request
+    })
+}
+
+fn round_to_increment(value: Decimal, increment: Decimal, strategy: RoundingStrategy) -> Decimal {
+    if increment.is_zero() {
+        return value;
+    }
+
+    (value / increment).round_dp_with_strategy(0, strategy) * increment
+}
+
+fn round_to_nearest_tick(value: Decimal, tick_size: Decimal) -> Decimal {
+    round_to_increment(value, tick_size, RoundingStrategy::MidpointAwayFromZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::{StopTrigger, TimeInForce};
+    use barter_instrument::instrument::spec::{
+        InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
+    };
+
+    fn spec() -> InstrumentSpec<()> {
+        InstrumentSpec::new(
+            InstrumentSpecPrice::new(Decimal::new(1, 1), Decimal::new(1, 1)),
+            InstrumentSpecQuantity::new(
+                OrderQuantityUnits::Asset(()),
+                Decimal::new(1, 3),
+                Decimal::new(1, 3),
+            )
+            .with_max(Decimal::from(100)),
+            InstrumentSpecNotional::new(Decimal::from(10)),
+        )
+    }
+
+    fn request(side: Side, kind: OrderKind, price: Decimal, quantity: Decimal) -> RequestOpen {
+        RequestOpen {
+            side,
+            price,
+            quantity,
+            kind,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
+        }
+    }
+
+    #[test]
+    fn test_normalise_open() {
+        struct TestCase {
+            input: RequestOpen,
+            expected: Result<RequestOpen, OrderValidationError>,
+        }
+
+        let cases = vec![
+            // TC0: valid Buy Limit is unchanged
+            TestCase {
+                input: request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::from(100),
+                    Decimal::new(5, 1),
+                ),
+                expected: Ok(request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::from(100),
+                    Decimal::new(5, 1),
+                )),
+            },
+            // TC1: Buy Limit price rounded down & quantity rounded down
+            TestCase {
+                input: request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::new(10019, 2),
+                    Decimal::new(5009, 4),
+                ),
+                expected: Ok(request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::new(1001, 1),
+                    Decimal::new(500, 3),
+                )),
+            },
+            // TC2: Sell Limit price rounded up
+            TestCase {
+                input: request(
+                    Side::Sell,
+                    OrderKind::Limit,
+                    Decimal::new(10011, 2),
+                    Decimal::new(5, 1),
+                ),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::Limit,
+                    Decimal::new(1002, 1),
+                    Decimal::new(5, 1),
+                )),
+            },
+            // TC3: StopMarket trigger price rounded to nearest tick, price untouched
+            TestCase {
+                input: request(
+                    Side::Sell,
+                    OrderKind::StopMarket {
+                        trigger_price: Decimal::new(9006, 2),
+                        trigger: StopTrigger::LastPrice,
+                    },
+                    Decimal::new(9006, 2),
+                    Decimal::new(5, 1),
+                ),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::StopMarket {
+                        trigger_price: Decimal::new(901, 1),
+                        trigger: StopTrigger::LastPrice,
+                    },
+                    Decimal::new(9006, 2),
+                    Decimal::new(5, 1),
+                )),
+            },
+            // TC4: price below minimum after rounding
+            TestCase {
+                input: request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::new(5, 2),
+                    Decimal::from(1),
+                ),
+                expected: Err(OrderValidationError::PriceBelowMin {
+                    price: Decimal::ZERO,
+                    min: Decimal::new(1, 1),
+                }),
+            },
+            // TC5: quantity below minimum after rounding
+            TestCase {
+                input: request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::from(100),
+                    Decimal::new(5, 4),
+                ),
+                expected: Err(OrderValidationError::QuantityBelowMin {
+                    quantity: Decimal::ZERO,
+                    min: Decimal::new(1, 3),
+                }),
+            },
+            // TC6: quantity above maximum
+            TestCase {
+                input: request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::from(100),
+                    Decimal::from(101),
+                ),
+                expected: Err(OrderValidationError::QuantityAboveMax {
+                    quantity: Decimal::from(101),
+                    max: Decimal::from(100),
+                }),
+            },
+            // TC7: notional below minimum
+            TestCase {
+                input: request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    Decimal::from(100),
+                    Decimal::new(5, 2),
+                ),
+                expected: Err(OrderValidationError::NotionalBelowMin {
+                    notional: Decimal::from(5),
+                    min: Decimal::from(10),
+                }),
+            },
+        ];
+
+        let spec = spec();
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = normalise_open(test.input, &spec);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    AccountEvent, AccountEventKind,
+    balance::{AssetBalance, Balance},
+};
+use derive_more::{Display, From};
+use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::hash::Hash;
+
+/// Exchange sub-account identifier (eg/ Binance sub-account email, Bybit sub-UID).
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display, From,
+)]
+pub struct SubAccountId(pub SmolStr);
+
+impl SubAccountId {
+    pub fn new<S: AsRef<str>>(id: S) -> Self {
+        Self(SmolStr::new(id))
+    }
+}
+
+/// Latest [`AssetBalance`]s of every sub-account, used to aggregate balances across them.
+///
+/// Balances of [`AccountEvent`]s without a [`SubAccountId`] are attributed to the master
+/// account (ie/ `None`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SubAccountBalances<AssetKey>
+where
+    AssetKey: Eq + Hash,
+{
+    pub balances: FnvHashMap<(Option<SubAccountId>, AssetKey), AssetBalance<AssetKey>>,
+}
+
+impl<AssetKey> Default for SubAccountBalances<AssetKey>
+where
+    AssetKey: Eq + Hash,
+{
+    fn default() -> Self {
+        Self {
+            balances: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<AssetKey> SubAccountBalances<AssetKey>
+where
+    AssetKey: Eq + Hash + Clone,
+{
+    /// Update the sub-account balances from the next [`AccountEvent`].
+    ///
+    /// Balance snapshots older than the current sub-account balance are ignored.
+    pub fn update_from_account_event<ExchangeKey, InstrumentKey>(
+        &mut self,
+        event: &AccountEvent<ExchangeKey, AssetKey, InstrumentKey>,
+    ) {
+        match &event.kind {
+            AccountEventKind::Snapshot(snapshot) => snapshot
+                .balances
+                .iter()
+                .for_each(|balance| self.update(event.sub_account.as_ref(), balance)),
+            AccountEventKind::BalanceSnapshot(balance) => {
+                self.update(event.sub_account.as_ref(), &balance.0)
+            }
+            _ => {}
+        }
+    }
+
+    /// Upsert the [`AssetBalance`] of the provided sub-account, if it is more recent.
+    pub fn update(&mut self, sub_account: Option<&SubAccountId>, balance: &AssetBalance<AssetKey>) {
+        self.balances
+            .entry((sub_account.cloned(), balance.asset.clone()))
+            .and_modify(|current| {
+                if current.time_exchange <= balance.time_exchange {
+                    *current = balance.clone();
+                }
+            })
+            .or_insert_with(|| balance.clone());
+    }
+
+    /// Return the [`Balance`] of an asset in the provided sub-account, if any.
+    pub fn sub_account(
+        &self,
+        sub_account: Option<&SubAccountId>,
+        asset: &AssetKey,
+    ) -> Option<Balance> {
+        self.balances
+            .get(&(sub_account.cloned(), asset.clone()))
+            .map(|balance| balance.balance)
+    }
+
+    /// Aggregate the [`Balance`] of an asset across every sub-account (including the master
+    /// account).
+    pub fn aggregate(&self, asset: &AssetKey) -> Balance {
+        self.balances
+            .iter()
+            .filter(|((_, key), _)| key == asset)
+            .fold(Balance::default(), |aggregate, (_, balance)| Balance {
+                total: aggregate.total + balance.balance.total,
+                free: aggregate.free + balance.balance.free,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::snapshot::Snapshot;
+    use chrono::{DateTime, TimeDelta, Utc};
+    use rust_decimal::Decimal;
+
+    fn balance_event(
+        sub_account: Option<&str>,
+        asset: &'static str,
+        secs: i64,
+        total: i64,
+    ) -> AccountEvent<u64, &'static str, u64> {
+        let balance = AssetBalance::new(
+            asset,
+            Balance::new(Decimal::new(total, 0), Decimal::new(total, 0)),
+            DateTime::<Utc>::MIN_UTC + TimeDelta::seconds(secs),
+        );
+
+        AccountEvent::new(0, AccountEventKind::BalanceSnapshot(Snapshot(balance)))
+            .with_sub_account(sub_account.map(SubAccountId::new))
+    }
+
+    #[test]
+    fn test_sub_account_balances_aggregate() {
+        struct TestCase {
+            events: Vec<AccountEvent<u64, &'static str, u64>>,
+            expected_total: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: master account balance only
+            TestCase {
+                events: vec![balance_event(None, "usdt", 0, 100)],
+                expected_total: Decimal::new(100, 0),
+            },
+            // TC1: balances aggregated across master & sub-accounts
+            TestCase {
+                events: vec![
+                    balance_event(None, "usdt", 0, 100),
+                    balance_event(Some("a"), "usdt", 0, 50),
+                    balance_event(Some("b"), "usdt", 0, 25),
+                ],
+                expected_total: Decimal::new(175, 0),
+            },
+            // TC2: latest sub-account balance replaces the previous balance
+            TestCase {
+                events: vec![
+                    balance_event(Some("a"), "usdt", 0, 50),
+                    balance_event(Some("a"), "usdt", 1, 10),
+                ],
+                expected_total: Decimal::new(10, 0),
+            },
+            // TC3: stale sub-account balance is ignored
+            TestCase {
+                events: vec![
+                    balance_event(Some("a"), "usdt", 1, 10),
+                    balance_event(Some("a"), "usdt", 0, 50),
+                ],
+                expected_total: Decimal::new(10, 0),
+            },
+            // TC4: other asset balances are excluded
+            TestCase {
+                events: vec![
+                    balance_event(Some("a"), "usdt", 0, 10),
+                    balance_event(Some("a"), "btc", 0, 1),
+                ],
+                expected_total: Decimal::new(10, 0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut balances = SubAccountBalances::default();
+            test.events
+                .iter()
+                .for_each(|event| balances.update_from_account_event(event));
+
+            assert_eq!(
+                balances.aggregate(&"usdt").total,
+                test.expected_total,
+                "TC{index} failed"
+            );
+        }
+    }
+}
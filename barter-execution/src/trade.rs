@@ -1,4 +1,4 @@
-use crate::order::id::{OrderId, StrategyId};
+use crate::order::id::{ClientOrderId, OrderId, StrategyId};
 use barter_instrument::{Side, asset::QuoteAsset};
 use chrono::{DateTime, Utc};
 use derive_more::{Constructor, From};
@@ -22,12 +22,22 @@ impl TradeId {
 pub struct Trade<AssetKey, InstrumentKey> {
     pub id: TradeId,
     pub order_id: OrderId,
+    /// [`ClientOrderId`] of the order that generated the `Trade`, correlating the fill back
+    /// to the originating order request.
+    ///
+    /// `None` if the exchange does not report it (eg/ the order was opened externally).
+    pub cid: Option<ClientOrderId>,
     pub instrument: InstrumentKey,
     pub strategy: StrategyId,
     pub time_exchange: DateTime<Utc>,
     pub side: Side,
     pub price: Decimal,
     pub quantity: Decimal,
+    /// Whether the `Trade` provided ([`Liquidity::Maker`]) or took ([`Liquidity::Taker`])
+    /// liquidity.
+    ///
+    /// `None` if the exchange does not report it.
+    pub liquidity: Option<Liquidity>,
     pub fees: AssetFees<AssetKey>,
 }
 
@@ -35,6 +45,31 @@ impl<AssetKey, InstrumentKey> Trade<AssetKey, InstrumentKey> {
     pub fn value_quote(&self) -> Decimal {
         self.price * self.quantity.abs()
     }
+
+    /// Fees paid as a fraction of the `Trade` quote value (eg/ 0.001 for 10 bps).
+    ///
+    /// Useful for verifying exchange fee schedules independently of `Trade` size.
+    pub fn fee_rate(&self) -> Decimal {
+        let value_quote = self.value_quote();
+        if value_quote.is_zero() {
+            Decimal::ZERO
+        } else {
+            self.fees.fees / value_quote
+        }
+    }
+}
+
+/// Whether a [`Trade`] provided or took liquidity from the order book.
+///
+/// Exchanges report this with different conventions (eg/ `isMaker`, `execType`, `liquidity`),
+/// so each `ExecutionClient` must normalise it into this type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum Liquidity {
+    /// Resting order that provided liquidity.
+    Maker,
+
+    /// Aggressing order that took liquidity.
+    Taker,
 }
 
 impl<AssetKey, InstrumentKey> Display for Trade<AssetKey, InstrumentKey>
@@ -85,3 +120,88 @@ impl<AssetKey> Default for AssetFees<Option<AssetKey>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trade_fee_rate() {
+        struct TestCase {
+            liquidity: Option<Liquidity>,
+            price: Decimal,
+            quantity: Decimal,
+            fees: Decimal,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: Maker Trade charged 2 bps
+            TestCase {
+                liquidity: Some(Liquidity::Maker),
+                price: Decimal::from(100),
+                quantity: Decimal::from(1),
+                fees: Decimal::new(2, 2),
+                expected: Decimal::new(2, 4),
+            },
+            // TC1: Taker Trade charged 10 bps
+            TestCase {
+                liquidity: Some(Liquidity::Taker),
+                price: Decimal::from(100),
+                quantity: Decimal::from(2),
+                fees: Decimal::new(2, 1),
+                expected: Decimal::new(1, 3),
+            },
+            // TC2: fee rate is measured against the absolute quantity
+            TestCase {
+                liquidity: Some(Liquidity::Taker),
+                price: Decimal::from(100),
+                quantity: Decimal::from(-2),
+                fees: Decimal::new(2, 1),
+                expected: Decimal::new(1, 3),
+            },
+            // TC3: Trade without reported Liquidity
+            TestCase {
+                liquidity: None,
+                price: Decimal::from(50),
+                quantity: Decimal::from(4),
+                fees: Decimal::from(1),
+                expected: Decimal::new(5, 3),
+            },
+            // TC4: zero quantity Trade has a zero fee rate
+            TestCase {
+                liquidity: Some(Liquidity::Taker),
+                price: Decimal::from(100),
+                quantity: Decimal::ZERO,
+                fees: Decimal::from(1),
+                expected: Decimal::ZERO,
+            },
+            // TC5: zero price Trade has a zero fee rate
+            TestCase {
+                liquidity: Some(Liquidity::Maker),
+                price: Decimal::ZERO,
+                quantity: Decimal::from(1),
+                fees: Decimal::from(1),
+                expected: Decimal::ZERO,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let trade = Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                cid: None,
+                instrument: "instrument",
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: Side::Buy,
+                price: test.price,
+                quantity: test.quantity,
+                liquidity: test.liquidity,
+                fees: AssetFees::quote_fees(test.fees),
+            };
+
+            assert_eq!(trade.fee_rate(), test.expected, "TC{index} failed");
+        }
+    }
+}
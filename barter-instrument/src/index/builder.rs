@@ -228,6 +228,7 @@ mod tests {
                     unit: OrderQuantityUnits::Asset(base_asset.clone()),
                     min: dec!(0.001),
                     increment: dec!(0.001),
+                    max: None,
                 },
                 notional: InstrumentSpecNotional { min: dec!(10) },
             }),
@@ -207,6 +207,7 @@ impl<ExchangeKey, AssetKey> Instrument<ExchangeKey, AssetKey> {
                             unit,
                             min,
                             increment,
+                            max,
                         },
                     notional,
                 } = spec;
@@ -225,6 +226,7 @@ impl<ExchangeKey, AssetKey> Instrument<ExchangeKey, AssetKey> {
                         unit,
                         min,
                         increment,
+                        max,
                     },
                     notional,
                 })
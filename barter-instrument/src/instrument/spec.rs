@@ -19,13 +19,34 @@ pub struct InstrumentSpecPrice {
     pub tick_size: Decimal,
 }
 
-#[derive(
-    Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
-)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub struct InstrumentSpecQuantity<AssetKey> {
     pub unit: OrderQuantityUnits<AssetKey>,
     pub min: Decimal,
     pub increment: Decimal,
+    /// Maximum order quantity, if the exchange imposes one.
+    #[serde(default)]
+    pub max: Option<Decimal>,
+}
+
+impl<AssetKey> InstrumentSpecQuantity<AssetKey> {
+    /// Construct a new `InstrumentSpecQuantity` with no maximum order quantity.
+    pub fn new(unit: OrderQuantityUnits<AssetKey>, min: Decimal, increment: Decimal) -> Self {
+        Self {
+            unit,
+            min,
+            increment,
+            max: None,
+        }
+    }
+
+    /// Set the maximum order quantity.
+    pub fn with_max(self, max: Decimal) -> Self {
+        Self {
+            max: Some(max),
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
@@ -3,7 +3,7 @@ use barter_integration::{
     error::SocketError,
     protocol::http::{
         HttpParser,
-        private::{RequestSigner, Signer, encoder::HexEncoder},
+        private::{RequestSigner, SignatureInput, Signer, encoder::HexEncoder},
         rest::{RestRequest, client::RestClient},
     },
 };
@@ -50,7 +50,7 @@ impl Signer for FtxSigner {
 
     fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
     where
-        M: Mac,
+        M: SignatureInput,
     {
         mac.update(config.time.to_string().as_bytes());
         mac.update(config.method.as_str().as_bytes());
@@ -3,10 +3,13 @@ use derive_more::{Constructor, Display};
 use futures::{Sink, Stream};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     pin::Pin,
-    task::{Context, Poll},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
 };
+use tokio::sync::mpsc::error::{SendError, TryRecvError};
 use tracing::warn;
 
 pub trait Tx
@@ -55,21 +58,21 @@ where
     T: Debug + Clone + Send,
 {
     type Item = T;
-    type Error = tokio::sync::mpsc::error::SendError<T>;
+    type Error = SendError<T>;
 
     fn send<Item: Into<Self::Item>>(&self, item: Item) -> Result<(), Self::Error> {
         self.tx.send(item.into())
     }
 }
 
-impl<T> Unrecoverable for tokio::sync::mpsc::error::SendError<T> {
+impl<T> Unrecoverable for SendError<T> {
     fn is_unrecoverable(&self) -> bool {
         true
     }
 }
 
 impl<T> Sink<T> for UnboundedTx<T> {
-    type Error = tokio::sync::mpsc::error::SendError<T>;
+    type Error = SendError<T>;
 
     fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         // UnboundedTx is always ready
@@ -103,8 +106,8 @@ impl<T> Iterator for UnboundedRx<T> {
         loop {
             match self.rx.try_recv() {
                 Ok(event) => break Some(event),
-                Err(tokio::sync::mpsc::error::TryRecvError::Empty) => continue,
-                Err(tokio::sync::mpsc::error::TryRecvError::Disconnected) => break None,
+                Err(TryRecvError::Empty) => continue,
+                Err(TryRecvError::Disconnected) => break None,
             }
         }
     }
@@ -176,3 +179,517 @@ pub fn mpsc_unbounded<T>() -> (UnboundedTx<T>, UnboundedRx<T>) {
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
     (UnboundedTx::new(tx), UnboundedRx::new(rx))
 }
+
+/// Default capacity of a bounded [`BoundedTx`] & [`BoundedRx`] channel.
+pub const DEFAULT_BOUNDED_CAPACITY: usize = 65_536;
+
+/// Policy applied when sending an item to a full bounded channel.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum OverflowPolicy {
+    /// Wait until the receiver has consumed items and capacity is available (default).
+    #[default]
+    Block,
+
+    /// Discard the oldest buffered [`Droppable`] item (eg/ market data) to make room.
+    ///
+    /// Items that are not droppable (eg/ account events) are never discarded. If no droppable
+    /// item is buffered, the sender waits for capacity as with [`OverflowPolicy::Block`].
+    DropOldest,
+}
+
+/// Classifies the channel items that may be discarded by [`OverflowPolicy::DropOldest`].
+pub trait Droppable {
+    fn is_droppable(&self) -> bool;
+}
+
+/// Configuration of a bounded [`BoundedTx`] & [`BoundedRx`] channel.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct BoundedConfig {
+    /// Maximum number of buffered items.
+    pub capacity: usize,
+
+    /// [`OverflowPolicy`] applied when the channel is full.
+    pub policy: OverflowPolicy,
+}
+
+impl Default for BoundedConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_BOUNDED_CAPACITY,
+            policy: OverflowPolicy::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BoundedShared<T> {
+    config: BoundedConfig,
+    state: Mutex<BoundedState<T>>,
+    /// Notifies a blocked synchronous [`BoundedRx`] that an item was pushed, or that all senders
+    /// were dropped.
+    rx_notify: Condvar,
+}
+
+impl<T> BoundedShared<T> {
+    fn lock(&self) -> MutexGuard<'_, BoundedState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn wait<'a>(&self, state: MutexGuard<'a, BoundedState<T>>) -> MutexGuard<'a, BoundedState<T>> {
+        self.rx_notify
+            .wait(state)
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Debug)]
+struct BoundedState<T> {
+    queue: VecDeque<T>,
+    /// Capacity reserved by `Sink` senders between `poll_ready` and `start_send`.
+    reserved: usize,
+    senders: usize,
+    rx_dropped: bool,
+    dropped: u64,
+    rx_waker: Option<Waker>,
+    tx_wakers: Vec<Waker>,
+}
+
+impl<T> BoundedState<T> {
+    fn push(&mut self, item: T) {
+        self.queue.push_back(item);
+        if let Some(waker) = self.rx_waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let item = self.queue.pop_front()?;
+        self.tx_wakers.drain(..).for_each(Waker::wake);
+        Some(item)
+    }
+}
+
+impl<T> BoundedState<T>
+where
+    T: Droppable,
+{
+    /// Returns true if an item can be pushed, first applying the [`OverflowPolicy`] if the
+    /// channel is full (including any capacity reserved by `Sink` senders).
+    fn reserve(&mut self, config: &BoundedConfig) -> bool {
+        if self.queue.len() + self.reserved < config.capacity {
+            return true;
+        }
+
+        match config.policy {
+            OverflowPolicy::Block => false,
+            OverflowPolicy::DropOldest => {
+                let Some(oldest) = self.queue.iter().position(Droppable::is_droppable) else {
+                    return false;
+                };
+                self.queue.remove(oldest);
+                self.dropped += 1;
+                true
+            }
+        }
+    }
+}
+
+/// Bounded channel transmitter applying an [`OverflowPolicy`] when the channel is full.
+///
+/// Backpressure is applied via the `Sink` implementation, which waits for capacity and reserves
+/// it in `poll_ready`, so concurrent senders never exceed the capacity. Note the
+/// synchronous [`Tx::send`] cannot wait, so it exceeds the capacity rather than blocking once the
+/// `OverflowPolicy` has been applied - it should only be used for low volume events
+/// (eg/ `Shutdown` commands).
+#[derive(Debug)]
+pub struct BoundedTx<T> {
+    shared: Arc<BoundedShared<T>>,
+    /// True if this sender reserved capacity in `poll_ready` that `start_send` has not used.
+    reserved: bool,
+}
+
+impl<T> BoundedTx<T> {
+    /// Number of items discarded by [`OverflowPolicy::DropOldest`].
+    pub fn dropped(&self) -> u64 {
+        self.shared.lock().dropped
+    }
+}
+
+impl<T> Clone for BoundedTx<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+            reserved: false,
+        }
+    }
+}
+
+impl<T> Drop for BoundedTx<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        if self.reserved {
+            state.reserved -= 1;
+            state.tx_wakers.drain(..).for_each(Waker::wake);
+        }
+
+        state.senders -= 1;
+        if state.senders != 0 {
+            return;
+        }
+
+        if let Some(waker) = state.rx_waker.take() {
+            waker.wake();
+        }
+        self.shared.rx_notify.notify_one();
+    }
+}
+
+impl<T> Tx for BoundedTx<T>
+where
+    T: Droppable + Debug + Clone + Send,
+{
+    type Item = T;
+    type Error = SendError<T>;
+
+    fn send<Item: Into<Self::Item>>(&self, item: Item) -> Result<(), Self::Error> {
+        let item = item.into();
+        let mut state = self.shared.lock();
+        if state.rx_dropped {
+            return Err(SendError(item));
+        }
+
+        state.reserve(&self.shared.config);
+        state.push(item);
+        self.shared.rx_notify.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Sink<T> for BoundedTx<T>
+where
+    T: Droppable,
+{
+    type Error = SendError<T>;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        let mut state = this.shared.lock();
+
+        // Dropped receiver is reported by start_send, since SendError requires the item
+        if this.reserved || state.rx_dropped {
+            return Poll::Ready(Ok(()));
+        }
+
+        // Reserve capacity so concurrent senders cannot use it before start_send
+        if state.reserve(&this.shared.config) {
+            state.reserved += 1;
+            this.reserved = true;
+            Poll::Ready(Ok(()))
+        } else {
+            state.tx_wakers.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        let mut state = this.shared.lock();
+
+        // Use the capacity reserved by poll_ready, otherwise re-check capacity and apply the
+        // OverflowPolicy
+        if std::mem::take(&mut this.reserved) {
+            state.reserved -= 1;
+        } else if !state.rx_dropped {
+            state.reserve(&this.shared.config);
+        }
+
+        if state.rx_dropped {
+            return Err(SendError(item));
+        }
+
+        state.push(item);
+        this.shared.rx_notify.notify_one();
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // BoundedTx pushes directly to the shared buffer, so no flushing is required
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // BoundedTx requires no closing logic
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Bounded channel receiver, see [`BoundedTx`].
+#[derive(Debug)]
+pub struct BoundedRx<T> {
+    shared: Arc<BoundedShared<T>>,
+}
+
+impl<T> BoundedRx<T> {
+    /// Attempt to receive the next buffered item without waiting.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.pop() {
+            Some(item) => Ok(item),
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Number of currently buffered items.
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// Returns true if no items are currently buffered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of items discarded by [`OverflowPolicy::DropOldest`].
+    pub fn dropped(&self) -> u64 {
+        self.shared.lock().dropped
+    }
+}
+
+impl<T> Drop for BoundedRx<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.rx_dropped = true;
+        state.queue.clear();
+        state.tx_wakers.drain(..).for_each(Waker::wake);
+    }
+}
+
+impl<T> Iterator for BoundedRx<T> {
+    type Item = T;
+
+    /// Blocks the current thread until an item is available, or all senders are dropped.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(item) = state.pop() {
+                return Some(item);
+            }
+            if state.senders == 0 {
+                return None;
+            }
+            state = self.shared.wait(state);
+        }
+    }
+}
+
+impl<T> Stream for BoundedRx<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.shared.lock();
+        if let Some(item) = state.pop() {
+            Poll::Ready(Some(item))
+        } else if state.senders == 0 {
+            Poll::Ready(None)
+        } else {
+            state.rx_waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Construct a bounded [`BoundedTx`] & [`BoundedRx`] channel using the provided
+/// [`BoundedConfig`].
+///
+/// # Panics
+/// Panics if the configured capacity is zero.
+pub fn mpsc_bounded<T>(config: BoundedConfig) -> (BoundedTx<T>, BoundedRx<T>) {
+    assert!(
+        config.capacity > 0,
+        "bounded channel capacity must be non-zero"
+    );
+
+    let shared = Arc::new(BoundedShared {
+        config,
+        state: Mutex::new(BoundedState {
+            queue: VecDeque::with_capacity(config.capacity.min(DEFAULT_BOUNDED_CAPACITY)),
+            reserved: 0,
+            senders: 1,
+            rx_dropped: false,
+            dropped: 0,
+            rx_waker: None,
+            tx_wakers: Vec::new(),
+        }),
+        rx_notify: Condvar::new(),
+    });
+
+    (
+        BoundedTx {
+            shared: Arc::clone(&shared),
+            reserved: false,
+        },
+        BoundedRx { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{SinkExt, StreamExt};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    enum Event {
+        Market(u64),
+        Account(u64),
+    }
+
+    impl Droppable for Event {
+        fn is_droppable(&self) -> bool {
+            matches!(self, Event::Market(_))
+        }
+    }
+
+    #[test]
+    fn test_bounded_drop_oldest_never_drops_non_droppable() {
+        struct TestCase {
+            input: Vec<Event>,
+            expected: Vec<Event>,
+            expected_dropped: u64,
+        }
+
+        let cases = vec![
+            // TC0: within capacity
+            TestCase {
+                input: vec![Event::Market(0), Event::Account(1)],
+                expected: vec![Event::Market(0), Event::Account(1)],
+                expected_dropped: 0,
+            },
+            // TC1: oldest market event dropped
+            TestCase {
+                input: vec![
+                    Event::Market(0),
+                    Event::Market(1),
+                    Event::Market(2),
+                    Event::Market(3),
+                ],
+                expected: vec![Event::Market(1), Event::Market(2), Event::Market(3)],
+                expected_dropped: 1,
+            },
+            // TC2: oldest market event dropped, skipping older account event
+            TestCase {
+                input: vec![
+                    Event::Account(0),
+                    Event::Market(1),
+                    Event::Market(2),
+                    Event::Account(3),
+                ],
+                expected: vec![Event::Account(0), Event::Market(2), Event::Account(3)],
+                expected_dropped: 1,
+            },
+            // TC3: no market event to drop, so account events exceed capacity
+            TestCase {
+                input: vec![
+                    Event::Account(0),
+                    Event::Account(1),
+                    Event::Account(2),
+                    Event::Account(3),
+                ],
+                expected: vec![
+                    Event::Account(0),
+                    Event::Account(1),
+                    Event::Account(2),
+                    Event::Account(3),
+                ],
+                expected_dropped: 0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (tx, rx) = mpsc_bounded::<Event>(BoundedConfig::new(3, OverflowPolicy::DropOldest));
+
+            for event in test.input {
+                Tx::send(&tx, event).unwrap();
+            }
+            drop(tx);
+
+            assert_eq!(rx.dropped(), test.expected_dropped, "TC{index} failed");
+            let actual = Iterator::collect::<Vec<_>>(rx);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bounded_sink_block_waits_for_capacity() {
+        let (mut tx, mut rx) = mpsc_bounded(BoundedConfig::new(1, OverflowPolicy::Block));
+
+        SinkExt::send(&mut tx, Event::Market(0)).await.unwrap();
+
+        // Channel is full, so the next send waits until the receiver consumes an item
+        let mut pending = tx.clone();
+        let send = tokio::spawn(async move { SinkExt::send(&mut pending, Event::Market(1)).await });
+        tokio::task::yield_now().await;
+        assert!(!send.is_finished());
+        assert_eq!(rx.len(), 1);
+
+        assert_eq!(StreamExt::next(&mut rx).await, Some(Event::Market(0)));
+        send.await.unwrap().unwrap();
+        assert_eq!(StreamExt::next(&mut rx).await, Some(Event::Market(1)));
+
+        drop(tx);
+        assert_eq!(StreamExt::next(&mut rx).await, None);
+        assert_eq!(rx.dropped(), 0);
+    }
+
+    #[test]
+    fn test_bounded_sink_concurrent_senders_never_exceed_capacity() {
+        let (mut tx_a, rx) = mpsc_bounded(BoundedConfig::new(1, OverflowPolicy::Block));
+        let mut tx_b = tx_a.clone();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        // Both senders poll_ready before either sends, but only one can reserve the capacity
+        assert!(Pin::new(&mut tx_a).poll_ready(&mut cx).is_ready());
+        assert!(Pin::new(&mut tx_b).poll_ready(&mut cx).is_pending());
+
+        Pin::new(&mut tx_a).start_send(Event::Market(0)).unwrap();
+        assert_eq!(rx.len(), 1);
+        assert!(Pin::new(&mut tx_b).poll_ready(&mut cx).is_pending());
+
+        // Dropping a sender releases its unused reservation
+        drop(rx);
+        let (mut tx_a, rx) = mpsc_bounded(BoundedConfig::new(1, OverflowPolicy::Block));
+        let mut tx_b = tx_a.clone();
+        assert!(Pin::new(&mut tx_a).poll_ready(&mut cx).is_ready());
+        drop(tx_a);
+        assert!(Pin::new(&mut tx_b).poll_ready(&mut cx).is_ready());
+        Pin::new(&mut tx_b).start_send(Event::Market(1)).unwrap();
+        assert_eq!(rx.len(), 1);
+    }
+
+    #[test]
+    fn test_bounded_iterator_blocks_until_item_or_disconnect() {
+        let (tx, mut rx) = mpsc_bounded(BoundedConfig::new(1, OverflowPolicy::Block));
+
+        let producer = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            Tx::send(&tx, Event::Account(0)).unwrap();
+        });
+
+        assert_eq!(Iterator::next(&mut rx), Some(Event::Account(0)));
+        producer.join().unwrap();
+        assert_eq!(Iterator::next(&mut rx), None);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_sink_errors_after_rx_dropped() {
+        let (mut tx, rx) = mpsc_bounded(BoundedConfig::new(1, OverflowPolicy::Block));
+        drop(rx);
+        let actual = SinkExt::send(&mut tx, Event::Account(0)).await;
+        assert_eq!(actual, Err(SendError(Event::Account(0))));
+    }
+}
@@ -59,6 +59,9 @@ pub enum SocketError {
 
     #[error("consumed error message from execution: {0}")]
     Exchange(String),
+
+    #[error("request signing error: {0}")]
+    Sign(String),
 }
 
 impl From<reqwest::Error> for SocketError {
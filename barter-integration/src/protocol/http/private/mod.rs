@@ -6,6 +6,10 @@ use hmac::Mac;
 /// Implementations for encoding signatures generated by a [`RequestSigner`].
 pub mod encoder;
 
+/// Exchange [`ServerClock`](time_sync::ServerClock) synchronisation used to align signed request
+/// timestamps with exchange server time, and detect clock skew.
+pub mod time_sync;
+
 /// API specific signing logic used by a [`RequestSigner`].
 #[allow(clippy::needless_lifetimes)]
 pub trait Signer {
@@ -43,6 +47,8 @@ pub trait Signer {
 
     /// Generate the bytes to sign from the provided [`Self::Config`].
     ///
+    /// The bytes are signed by the [`RequestSigner`]'s [`KeySigner`].
+    ///
     /// # Examples
     ///
     /// ## Private REST Request: FTX
@@ -55,7 +61,7 @@ pub trait Signer {
     /// ```
     fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
     where
-        M: Mac;
+        M: SignatureInput;
 
     /// Build a signed [`reqwest::Request`] from the provided [`Self::Config`],
     /// [`RequestBuilder`](reqwest::RequestBuilder), and generated cryptographic signature `String`.
@@ -81,19 +87,54 @@ pub trait Signer {
     ) -> Result<reqwest::Request, SocketError>;
 }
 
-/// Generically signs Http [`RestRequest`]s utilising API specific [`Signer`] logic, a hashable
-/// [`Mac`], and a signature [`Encoder`].
+/// Accumulates the bytes to sign generated by a [`Signer`].
+pub trait SignatureInput {
+    /// Append the provided bytes to the input that will be signed.
+    fn update(&mut self, data: &[u8]);
+}
+
+impl SignatureInput for Vec<u8> {
+    fn update(&mut self, data: &[u8]) {
+        self.extend_from_slice(data)
+    }
+}
+
+/// Cryptographic signing scheme and key used to sign the bytes generated by a [`Signer`].
+///
+/// Implemented for all HMAC [`Mac`]s (eg/ `Hmac<Sha256>`). Other schemes (eg/ Ed25519, RSA), or
+/// keys held in external key stores and HSMs, can be supported by implementing `KeySigner`.
+pub trait KeySigner {
+    /// Sign the provided message bytes, returning the raw signature bytes.
+    ///
+    /// The [`SocketError`] is boxed since signing failures are rare, and it keeps the happy path
+    /// `Result` small.
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Box<SocketError>>;
+}
+
+impl<M> KeySigner for M
+where
+    M: Mac + Clone,
+{
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Box<SocketError>> {
+        let mut mac = self.clone();
+        mac.update(message);
+        Ok(mac.finalize().into_bytes().to_vec())
+    }
+}
+
+/// Generically signs Http [`RestRequest`]s utilising API specific [`Signer`] logic, a
+/// [`KeySigner`] (eg/ HMAC-SHA256), and a signature [`Encoder`].
 #[derive(Debug, Copy, Clone)]
-pub struct RequestSigner<Sig, Hmac, SigEncoder> {
+pub struct RequestSigner<Sig, Key, SigEncoder> {
     signer: Sig,
-    mac: Hmac,
+    key: Key,
     encoder: SigEncoder,
 }
 
-impl<Sig, Hmac, SigEncoder> BuildStrategy for RequestSigner<Sig, Hmac, SigEncoder>
+impl<Sig, Key, SigEncoder> BuildStrategy for RequestSigner<Sig, Key, SigEncoder>
 where
     Sig: Signer,
-    Hmac: Mac + Clone,
+    Key: KeySigner,
     SigEncoder: Encoder,
 {
     fn build<Request>(
@@ -107,25 +148,45 @@ where
         // Build configuration required for generating signed requests
         let config = self.signer.config(request, &builder)?;
 
-        // Update Mac state & finalise bytes
-        let mut mac = self.mac.clone();
-        Sig::add_bytes_to_sign(&mut mac, &config);
-        let bytes_to_encode = mac.finalize().into_bytes();
+        // Generate bytes to sign & sign them with the KeySigner
+        let mut message = Vec::new();
+        Sig::add_bytes_to_sign(&mut message, &config);
+        let bytes_to_encode = self.key.sign(&message).map_err(|error| *error)?;
 
-        // Encode signature from Mac bytes
+        // Encode signature from signed bytes
         let signature = self.encoder.encode(bytes_to_encode);
 
         Sig::build_signed_request(config, builder, signature)
     }
 }
 
-impl<Sig, Hmac, SigEncoder> RequestSigner<Sig, Hmac, SigEncoder> {
+impl<Sig, Key, SigEncoder> RequestSigner<Sig, Key, SigEncoder> {
     /// Construct a new [`Self`] using the provided API specific configuration.
-    pub fn new(signer: Sig, mac: Hmac, encoder: SigEncoder) -> Self {
+    pub fn new(signer: Sig, key: Key, encoder: SigEncoder) -> Self {
         Self {
             signer,
-            mac,
+            key,
             encoder,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hmac::Hmac;
+
+    #[test]
+    fn test_hmac_key_signer() {
+        let mac = Hmac::<sha2::Sha256>::new_from_slice(b"key").unwrap();
+
+        let mut message = Vec::new();
+        SignatureInput::update(&mut message, b"The quick brown fox ");
+        SignatureInput::update(&mut message, b"jumps over the lazy dog");
+
+        let actual = hex::encode(KeySigner::sign(&mac, &message).unwrap());
+        let expected = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8";
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::error::SocketError;
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicI64, Ordering},
+    },
+    time::Duration,
+};
+use tracing::{debug, warn};
+
+/// Default interval between exchange server time measurements.
+pub const DEFAULT_TIME_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Default `recvWindow` an exchange will accept signed request timestamps within (eg/ Binance).
+pub const DEFAULT_RECV_WINDOW: Duration = Duration::from_millis(5000);
+
+/// Shared estimate of the offset between an exchange's server time and local time.
+///
+/// Each exchange should have its own `ServerClock`, used by its [`Signer`](super::Signer) to
+/// generate signed request timestamps that are aligned with the exchange server time.
+#[derive(Debug, Clone, Default)]
+pub struct ServerClock {
+    offset_ms: Arc<AtomicI64>,
+}
+
+impl ServerClock {
+    /// Latest estimated offset of the exchange server time from local time.
+    pub fn offset(&self) -> TimeDelta {
+        TimeDelta::milliseconds(self.offset_ms.load(Ordering::Relaxed))
+    }
+
+    /// Update the estimated offset of the exchange server time from local time.
+    pub fn set_offset(&self, offset: TimeDelta) {
+        self.offset_ms
+            .store(offset.num_milliseconds(), Ordering::Relaxed)
+    }
+
+    /// Current time, adjusted by the offset to align with the exchange server time.
+    pub fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset()
+    }
+
+    /// Current time in milliseconds since the epoch, adjusted by the offset to align with the
+    /// exchange server time (eg/ for a signed request `timestamp` parameter).
+    pub fn timestamp_millis(&self) -> i64 {
+        self.now().timestamp_millis()
+    }
+}
+
+/// Configuration of a [`run_time_sync`] task.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct TimeSyncConfig {
+    /// Interval between exchange server time measurements.
+    pub interval: Duration,
+
+    /// Maximum clock skew the exchange tolerates for signed request timestamps.
+    pub recv_window: Duration,
+}
+
+impl Default for TimeSyncConfig {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_TIME_SYNC_INTERVAL,
+            recv_window: DEFAULT_RECV_WINDOW,
+        }
+    }
+}
+
+/// Estimate the offset of the exchange server time from local time, given a server time
+/// measured between the local `time_sent` and `time_received` of the request.
+///
+/// Assumes symmetric network latency, so the server time is compared to the round trip
+/// midpoint.
+pub fn estimate_offset(
+    time_sent: DateTime<Utc>,
+    time_server: DateTime<Utc>,
+    time_received: DateTime<Utc>,
+) -> TimeDelta {
+    let midpoint = time_sent + (time_received - time_sent) / 2;
+    time_server - midpoint
+}
+
+/// Returns true if the absolute clock skew exceeds the `recv_window`, in which case signed
+/// requests are likely to be rejected by the exchange (eg/ with signature or timestamp errors).
+pub fn is_skew_excessive(offset: TimeDelta, recv_window: Duration) -> bool {
+    TimeDelta::from_std(recv_window).is_ok_and(|recv_window| offset.abs() > recv_window)
+}
+
+/// Measure the exchange server time once using the provided `fetch_server_time` request,
+/// updating the [`ServerClock`] offset.
+///
+/// Warns if the measured clock skew exceeds the [`TimeSyncConfig::recv_window`].
+pub async fn sync_server_clock<FnFetch, Fut>(
+    clock: &ServerClock,
+    config: &TimeSyncConfig,
+    fetch_server_time: &mut FnFetch,
+) -> Result<TimeDelta, SocketError>
+where
+    FnFetch: FnMut() -> Fut,
+    Fut: Future<Output = Result<DateTime<Utc>, SocketError>>,
+{
+    let time_sent = Utc::now();
+    let time_server = fetch_server_time().await?;
+    let time_received = Utc::now();
+
+    let offset = estimate_offset(time_sent, time_server, time_received);
+
+    if is_skew_excessive(offset, config.recv_window) {
+        warn!(
+            offset_ms = offset.num_milliseconds(),
+            recv_window_ms = config.recv_window.as_millis(),
+            "ServerClock skew exceeds recvWindow - applying offset to signed request timestamps"
+        );
+    } else {
+        debug!(
+            offset_ms = offset.num_milliseconds(),
+            "ServerClock synchronised"
+        );
+    }
+
+    clock.set_offset(offset);
+    Ok(offset)
+}
+
+/// Run a task that periodically measures the exchange server time using the provided
+/// `fetch_server_time` request, keeping the [`ServerClock`] offset up to date.
+///
+/// Failed measurements are logged, and the previous offset is retained.
+pub async fn run_time_sync<FnFetch, Fut>(
+    clock: ServerClock,
+    config: TimeSyncConfig,
+    mut fetch_server_time: FnFetch,
+) where
+    FnFetch: FnMut() -> Fut,
+    Fut: Future<Output = Result<DateTime<Utc>, SocketError>>,
+{
+    loop {
+        if let Err(error) = sync_server_clock(&clock, &config, &mut fetch_server_time).await {
+            warn!(?error, "ServerClock failed to fetch exchange server time");
+        }
+
+        tokio::time::sleep(config.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_offset() {
+        struct TestCase {
+            sent_ms: i64,
+            server_ms: i64,
+            received_ms: i64,
+            expected_ms: i64,
+        }
+
+        let cases = vec![
+            // TC0: clocks aligned
+            TestCase {
+                sent_ms: 1000,
+                server_ms: 1050,
+                received_ms: 1100,
+                expected_ms: 0,
+            },
+            // TC1: server clock ahead
+            TestCase {
+                sent_ms: 1000,
+                server_ms: 3050,
+                received_ms: 1100,
+                expected_ms: 2000,
+            },
+            // TC2: server clock behind
+            TestCase {
+                sent_ms: 1000,
+                server_ms: 550,
+                received_ms: 1100,
+                expected_ms: -500,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let time = |ms| DateTime::<Utc>::from_timestamp_millis(ms).unwrap();
+            let actual = estimate_offset(
+                time(test.sent_ms),
+                time(test.server_ms),
+                time(test.received_ms),
+            );
+            assert_eq!(
+                actual.num_milliseconds(),
+                test.expected_ms,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_skew_excessive() {
+        let recv_window = Duration::from_millis(5000);
+        assert!(!is_skew_excessive(
+            TimeDelta::milliseconds(4999),
+            recv_window
+        ));
+        assert!(!is_skew_excessive(
+            TimeDelta::milliseconds(-5000),
+            recv_window
+        ));
+        assert!(is_skew_excessive(
+            TimeDelta::milliseconds(5001),
+            recv_window
+        ));
+        assert!(is_skew_excessive(
+            TimeDelta::milliseconds(-5001),
+            recv_window
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sync_server_clock() {
+        let clock = ServerClock::default();
+        let config = TimeSyncConfig::default();
+        let mut fetch = || async { Ok(Utc::now() + TimeDelta::hours(1)) };
+
+        let offset = sync_server_clock(&clock, &config, &mut fetch)
+            .await
+            .unwrap();
+
+        assert!(offset > TimeDelta::minutes(59) && offset < TimeDelta::minutes(61));
+        assert_eq!(
+            clock.offset(),
+            TimeDelta::milliseconds(offset.num_milliseconds())
+        );
+        assert!(clock.now() > Utc::now() + TimeDelta::minutes(59));
+    }
+}
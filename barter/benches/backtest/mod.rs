@@ -18,7 +18,7 @@ use barter::{
         },
     },
     risk::DefaultRiskManager,
-    statistic::time::Daily,
+    statistic::{summary::pnl::ReturnsInterval, time::Daily},
     strategy::{
         algo::AlgoStrategy,
         close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
@@ -323,6 +323,7 @@ impl AlgoStrategy for LoseMoneyStrategy {
                         quantity: Decimal::from_f64(trade_not_sent_as_order_open.amount).unwrap(),
                         kind: OrderKind::Market,
                         time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
                     },
                 })
             });
@@ -478,6 +479,7 @@ fn args_constant(
         executions,
         market_data,
         summary_interval: Daily,
+        returns_interval: ReturnsInterval::PerTrade,
         engine_state,
     })
 }
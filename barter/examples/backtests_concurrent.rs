@@ -9,7 +9,7 @@ use barter::{
         instrument::data::DefaultInstrumentMarketData, trading::TradingState,
     },
     risk::DefaultRiskManager,
-    statistic::time::Daily,
+    statistic::{summary::pnl::ReturnsInterval, time::Daily},
     strategy::DefaultStrategy,
     system::config::SystemConfig,
 };
@@ -72,6 +72,7 @@ async fn main() {
         executions,
         market_data,
         summary_interval: Daily,
+        returns_interval: ReturnsInterval::Daily,
         engine_state,
     });
 
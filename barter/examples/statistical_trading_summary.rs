@@ -139,6 +139,8 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
             price_entry_average: dec!(1.0),
             quantity_abs_max: dec!(1000.0),
             pnl_realised: dec!(2000.0), // 2000 usdt profit
+            excursion_adverse_max: dec!(0.0),
+            excursion_favourable_max: dec!(2.0),
             fees_enter: AssetFees {
                 asset: QuoteAsset,
                 fees: dec!(0.0),
@@ -150,6 +152,7 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
             time_enter: base_time.checked_add_days(Days::new(1)).unwrap(),
             time_exit: base_time.checked_add_days(Days::new(2)).unwrap(),
             trades: vec![TradeId(SmolStr::new("1")), TradeId(SmolStr::new("2"))],
+            signal: None,
         }),
         // Update 4: minus 2000 usdt (ie/ executed a Side::Buy MARKET order with no fees)
         ContrivedEvents::Balance(Snapshot::new(AssetBalance {
@@ -170,11 +173,14 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
             price_entry_average: dec!(1.0),
             quantity_abs_max: dec!(2000.0),
             pnl_realised: dec!(1000.0), // 1000 usdt profit
+            excursion_adverse_max: dec!(0.1),
+            excursion_favourable_max: dec!(0.5),
             fees_enter: AssetFees::default(),
             fees_exit: AssetFees::default(),
             time_enter: base_time.checked_add_days(Days::new(2)).unwrap(),
             time_exit: base_time.checked_add_days(Days::new(3)).unwrap(),
             trades: vec![TradeId(SmolStr::new("3")), TradeId(SmolStr::new("4"))],
+            signal: None,
         }),
         // Update 7: minus 5000 usdt (ie/ executed a Side::Buy MARKET order with no fees)
         ContrivedEvents::Balance(Snapshot::new(AssetBalance {
@@ -195,11 +201,14 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
             price_entry_average: dec!(1.0),
             quantity_abs_max: dec!(2000.0),
             pnl_realised: dec!(-2000.0), // 2000 usdt loss
+            excursion_adverse_max: dec!(1.0),
+            excursion_favourable_max: dec!(0.2),
             fees_enter: AssetFees::default(),
             fees_exit: AssetFees::default(),
             time_enter: base_time.checked_add_days(Days::new(4)).unwrap(),
             time_exit: base_time.checked_add_days(Days::new(5)).unwrap(),
             trades: vec![TradeId(SmolStr::new("5")), TradeId(SmolStr::new("6"))],
+            signal: None,
         }),
         // Update 10: minus 5000 usdt (ie/ executed a Side::Buy MARKET order with no fees)
         ContrivedEvents::Balance(Snapshot::new(AssetBalance {
@@ -226,6 +235,8 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
             price_entry_average: dec!(1.0),
             quantity_abs_max: dec!(6000.0),
             pnl_realised: dec!(-1000.0), // 1000 usdt loss
+            excursion_adverse_max: dec!(0.25),
+            excursion_favourable_max: dec!(0.1),
             fees_enter: AssetFees::default(),
             fees_exit: AssetFees::default(),
             time_enter: base_time.checked_add_days(Days::new(6)).unwrap(),
@@ -235,6 +246,7 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
                 TradeId(SmolStr::new("8")),
                 TradeId(SmolStr::new("9")),
             ],
+            signal: None,
         }),
         // Update 14: minus 3000 usdt (ie/ executed a Side::Buy MARKET order with no fees)
         ContrivedEvents::Balance(Snapshot::new(AssetBalance {
@@ -255,11 +267,14 @@ fn generate_synthetic_updates(base_time: DateTime<Utc>) -> Vec<ContrivedEvents>
             price_entry_average: dec!(1.0),
             quantity_abs_max: dec!(6000.0),
             pnl_realised: dec!(500.0), // 500 usdt profit
+            excursion_adverse_max: dec!(0.1),
+            excursion_favourable_max: dec!(0.1),
             fees_enter: AssetFees::default(),
             fees_exit: AssetFees::default(),
             time_enter: base_time.checked_add_days(Days::new(10)).unwrap(),
             time_exit: base_time.checked_add_days(Days::new(11)).unwrap(),
             trades: vec![TradeId(SmolStr::new("10")), TradeId(SmolStr::new("11"))],
+            signal: None,
         }),
     ]
 }
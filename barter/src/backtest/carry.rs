@@ -0,0 +1,226 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    streams::{consumer::MarketStreamEvent, reconnect::Event},
+    subscription::carry::{BorrowRate, FundingRate},
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::BufRead;
+use thiserror::Error;
+
+/// Kind of historical carry rate contained in a rates CSV.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum CarryRateKind {
+    /// Perpetual funding rate applied at each funding time (eg/ 0.0001 for 1 bps).
+    Funding,
+
+    /// Annual margin borrow rate (eg/ 0.05 for 5%).
+    Borrow,
+}
+
+/// All errors generated when loading historical carry rates.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum CarryDataError {
+    #[error("failed to read rates: {0}")]
+    Io(String),
+
+    #[error("failed to parse rates line {line}: {error}")]
+    Parse { line: usize, error: String },
+}
+
+/// Load historical carry rates of an instrument from CSV, as market events that can be
+/// interleaved with backtest market data (see [`interleave`]).
+///
+/// The CSV must have a header row, followed by `time,rate` rows where `time` is RFC3339
+/// (eg/ "2024-01-01T08:00:00Z").
+pub fn load_rates_csv<Reader, InstrumentKey>(
+    reader: Reader,
+    kind: CarryRateKind,
+    exchange: ExchangeId,
+    instrument: InstrumentKey,
+) -> Result<Vec<MarketEvent<InstrumentKey, DataKind>>, CarryDataError>
+where
+    Reader: BufRead,
+    InstrumentKey: Clone,
+{
+    reader
+        .lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            let line = line.map_err(|error| CarryDataError::Io(error.to_string()))?;
+            let parse_error = |error: String| CarryDataError::Parse {
+                line: line_number,
+                error,
+            };
+
+            let (time, rate) = line
+                .split_once(',')
+                .ok_or_else(|| parse_error("expected time,rate columns".to_string()))?;
+
+            let time = DateTime::parse_from_rfc3339(time.trim())
+                .map_err(|error| parse_error(error.to_string()))?
+                .with_timezone(&Utc);
+
+            let rate = rate
+                .trim()
+                .parse::<f64>()
+                .map_err(|error| parse_error(error.to_string()))?;
+
+            let kind = match kind {
+                CarryRateKind::Funding => DataKind::FundingRate(FundingRate { rate, time }),
+                CarryRateKind::Borrow => DataKind::BorrowRate(BorrowRate {
+                    rate_annual: rate,
+                    time,
+                }),
+            };
+
+            Ok(MarketEvent {
+                time_exchange: time,
+                time_received: time,
+                exchange,
+                instrument: instrument.clone(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Interleave time ordered carry rate market events with time ordered backtest market data, so
+/// rates are processed at the correct point in the market data stream.
+///
+/// Rates with the same `time_exchange` as a market event are ordered before it.
+pub fn interleave<InstrumentKey, Kind>(
+    market: impl IntoIterator<Item = MarketStreamEvent<InstrumentKey, Kind>>,
+    rates: impl IntoIterator<Item = MarketEvent<InstrumentKey, Kind>>,
+) -> Vec<MarketStreamEvent<InstrumentKey, Kind>> {
+    let mut rates = rates.into_iter().peekable();
+    let mut interleaved = Vec::new();
+
+    for event in market {
+        if let Event::Item(market_event) = &event {
+            while let Some(rate) =
+                rates.next_if(|rate| rate.time_exchange <= market_event.time_exchange)
+            {
+                interleaved.push(Event::Item(rate));
+            }
+        }
+        interleaved.push(event);
+    }
+
+    interleaved.extend(rates.map(Event::Item));
+    interleaved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+
+    fn event(time: DateTime<Utc>, kind: &'static str) -> MarketEvent<&'static str, &'static str> {
+        MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: ExchangeId::BinanceFuturesUsd,
+            instrument: "btc_usdt",
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_load_rates_csv() {
+        let csv = "time,rate\n2024-01-01T00:00:00Z,0.0001\n\n2024-01-01T08:00:00Z,-0.0002\n";
+
+        let rates = load_rates_csv(
+            csv.as_bytes(),
+            CarryRateKind::Funding,
+            ExchangeId::BinanceFuturesUsd,
+            "btc_usdt",
+        )
+        .unwrap();
+
+        let rates = rates
+            .iter()
+            .map(|event| match &event.kind {
+                DataKind::FundingRate(funding) => (event.time_exchange.to_rfc3339(), funding.rate),
+                _ => panic!("expected FundingRate"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rates,
+            vec![
+                ("2024-01-01T00:00:00+00:00".to_string(), 0.0001),
+                ("2024-01-01T08:00:00+00:00".to_string(), -0.0002),
+            ]
+        );
+
+        let invalid = "time,rate\n2024-01-01T00:00:00Z,0.0001\nnot_a_time,0.1\n";
+        assert!(matches!(
+            load_rates_csv(
+                invalid.as_bytes(),
+                CarryRateKind::Borrow,
+                ExchangeId::BinanceSpot,
+                "btc_usdt",
+            ),
+            Err(CarryDataError::Parse { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_interleave() {
+        struct TestCase {
+            market: Vec<MarketStreamEvent<&'static str, &'static str>>,
+            rates: Vec<MarketEvent<&'static str, &'static str>>,
+            expected: Vec<&'static str>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+
+        let cases = vec![
+            // TC0: no rates
+            TestCase {
+                market: vec![Event::Item(event(base, "candle_0"))],
+                rates: vec![],
+                expected: vec!["candle_0"],
+            },
+            // TC1: rates ordered by time, before market events with the same time
+            TestCase {
+                market: vec![
+                    Event::Item(event(base, "candle_0")),
+                    Event::Item(event(time_plus_secs(base, 10), "candle_10")),
+                    Event::Item(event(time_plus_secs(base, 20), "candle_20")),
+                ],
+                rates: vec![
+                    event(time_plus_secs(base, 5), "rate_5"),
+                    event(time_plus_secs(base, 10), "rate_10"),
+                ],
+                expected: vec!["candle_0", "rate_5", "rate_10", "candle_10", "candle_20"],
+            },
+            // TC2: rates after the last market event are appended
+            TestCase {
+                market: vec![
+                    Event::Item(event(base, "candle_0")),
+                    Event::Reconnecting(ExchangeId::BinanceFuturesUsd),
+                ],
+                rates: vec![event(time_plus_secs(base, 30), "rate_30")],
+                expected: vec!["candle_0", "reconnecting", "rate_30"],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = interleave(test.market, test.rates)
+                .into_iter()
+                .map(|event| match event {
+                    Event::Item(event) => event.kind,
+                    Event::Reconnecting(_) => "reconnecting",
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,868 @@
+use crate::{
+    backtest::{
+        BacktestArgsConstant, BacktestArgsDynamic, backtest_engine,
+        market_data::{BacktestMarketData, MarketDataInMemory},
+        summary::BacktestSummary,
+    },
+    engine::{
+        Processor,
+        clock::HistoricalClock,
+        execution_tx::MultiExchangeTxMap,
+        schema::{SchemaError, SchemaMigrations, Versioned},
+        state::{
+            EngineState, asset::migrate_asset_states_v1, instrument::data::InstrumentDataState,
+        },
+    },
+    error::BarterError,
+    risk::RiskManager,
+    statistic::{
+        metric::{exposure::ExposureGenerator, shortfall::ShortfallTracker},
+        summary::TradingSummaryGenerator,
+        time::TimeInterval,
+    },
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
+    system::config::ExecutionConfig,
+};
+use barter_data::event::MarketEvent;
+use barter_execution::{
+    AccountEvent, InstrumentAccountSnapshot,
+    balance::AssetBalance,
+    order::{
+        Order, OrderKey,
+        state::{ActiveOrderState, OrderState},
+    },
+};
+use barter_instrument::{index::IndexedInstruments, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::{
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+use tracing::info;
+
+/// All errors generated when writing or reading a [`BacktestCheckpoint`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum CheckpointError {
+    #[error("checkpoint interval must be positive: {0}")]
+    Interval(TimeDelta),
+
+    #[error("checkpoint I/O: {0}")]
+    Io(String),
+
+    #[error("checkpoint SerDe: {0}")]
+    SerDe(String),
+
+    #[error("checkpoint {0}")]
+    Schema(#[from] SchemaError),
+}
+
+impl From<std::io::Error> for CheckpointError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CheckpointError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::SerDe(value.to_string())
+    }
+}
+
+/// Configuration of a checkpointed backtest (see [`backtest_with_checkpoints`]).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Constructor)]
+pub struct CheckpointConfig {
+    /// File path the latest [`BacktestCheckpoint`] is written to, and resumed from.
+    pub path: PathBuf,
+
+    /// Interval of market data exchange time between checkpoints.
+    pub interval: TimeDelta,
+}
+
+/// [`SchemaMigrations`] that upgrade a [`BacktestCheckpoint`] written by a previous schema
+/// version.
+pub fn checkpoint_migrations() -> SchemaMigrations {
+    SchemaMigrations::default().register(1, migrate_checkpoint_v1)
+}
+
+/// Migrate a [`BacktestCheckpoint`] from schema version 1 by upgrading its `EngineState`
+/// [`AssetStates`](crate::engine::state::asset::AssetStates).
+fn migrate_checkpoint_v1(mut value: Value) -> Result<Value, String> {
+    let assets = value
+        .pointer_mut("/engine_state/assets")
+        .ok_or("missing engine_state assets")?;
+
+    *assets = migrate_asset_states_v1(assets.take())?;
+
+    Ok(value)
+}
+
+/// Checkpoint of a partially completed backtest, containing the `EngineState` (ie/ portfolio,
+/// open orders & statistics), the `Strategy` & `RiskManager`, and the exchange time from which
+/// the backtest resumes.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BacktestCheckpoint<State, Strategy, Risk> {
+    /// Exchange time of the first market event of the backtest.
+    pub time_start: DateTime<Utc>,
+
+    /// Exchange time of the first market event that has not yet been processed.
+    pub time_resume: DateTime<Utc>,
+
+    pub engine_state: State,
+    pub exposure: ExposureGenerator,
+    pub shortfall: ShortfallTracker,
+    pub strategy: Strategy,
+    pub risk: Risk,
+}
+
+impl<State, Strategy, Risk> BacktestCheckpoint<State, Strategy, Risk> {
+    /// Construct a new `BacktestCheckpoint` for a backtest that has not yet processed any market
+    /// events.
+    pub fn new(
+        time_start: DateTime<Utc>,
+        engine_state: State,
+        strategy: Strategy,
+        risk: Risk,
+    ) -> Self {
+        Self {
+            time_start,
+            time_resume: time_start,
+            engine_state,
+            exposure: ExposureGenerator::default(),
+            shortfall: ShortfallTracker::default(),
+            strategy,
+            risk,
+        }
+    }
+
+    /// Write the `BacktestCheckpoint` to the provided path as [`Versioned`] JSON.
+    ///
+    /// The checkpoint is written to a temporary file that then replaces the previous checkpoint,
+    /// so an interruption never leaves a partially written checkpoint.
+    pub fn write(&self, path: &Path) -> Result<(), CheckpointError>
+    where
+        State: Serialize,
+        Strategy: Serialize,
+        Risk: Serialize,
+    {
+        let path_tmp = path.with_extension("tmp");
+        std::fs::write(&path_tmp, serde_json::to_vec(&Versioned::new(self))?)?;
+        std::fs::rename(&path_tmp, path)?;
+        Ok(())
+    }
+
+    /// Read the `BacktestCheckpoint` at the provided path, upgrading it using the provided
+    /// [`SchemaMigrations`] (eg/ [`checkpoint_migrations`]) if it was written by a previous
+    /// schema version.
+    ///
+    /// Returns `None` if no checkpoint exists at the path.
+    pub fn read(path: &Path, migrations: &SchemaMigrations) -> Result<Option<Self>, CheckpointError>
+    where
+        State: DeserializeOwned,
+        Strategy: DeserializeOwned,
+        Risk: DeserializeOwned,
+    {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Ok(Some(migrations.deserialise(&json)?)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(CheckpointError::from(error)),
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData, Strategy, Risk>
+    BacktestCheckpoint<EngineState<GlobalData, InstrumentData>, Strategy, Risk>
+{
+    /// Update the `BacktestCheckpoint` from the `Engine` at the end of a checkpoint interval.
+    ///
+    /// In flight requests are never answered once the interval
+    /// [`MockExchange`](barter_execution::exchange::mock::MockExchange) shuts down, so orders
+    /// awaiting an open response are discarded, and confirmed open orders awaiting a cancel
+    /// response revert to open so they can be cancelled again.
+    pub fn update(
+        &mut self,
+        time_resume: DateTime<Utc>,
+        mut engine_state: EngineState<GlobalData, InstrumentData>,
+        exposure: ExposureGenerator,
+        shortfall: ShortfallTracker,
+        strategy: Strategy,
+        risk: Risk,
+    ) {
+        for instrument in engine_state.instruments.0.values_mut() {
+            instrument.orders.0.retain(|_, order| {
+                let open = match &order.state {
+                    ActiveOrderState::OpenInFlight(_) => None,
+                    ActiveOrderState::Open(open) => Some(open.clone()),
+                    ActiveOrderState::CancelInFlight(cancel) => cancel.order.clone(),
+                };
+
+                match open {
+                    Some(open) => {
+                        order.state = ActiveOrderState::Open(open);
+                        true
+                    }
+                    None => false,
+                }
+            });
+        }
+
+        self.time_resume = time_resume;
+        self.engine_state = engine_state;
+        self.exposure = exposure;
+        self.shortfall = shortfall;
+        self.strategy = strategy;
+        self.risk = risk;
+    }
+
+    /// Generate the [`ExecutionConfig`] used to resume the backtest, initialising each mocked
+    /// exchange account with the checkpointed asset balances and open orders.
+    pub fn execution_config(
+        &self,
+        instruments: &IndexedInstruments,
+        config: &ExecutionConfig,
+    ) -> ExecutionConfig {
+        let ExecutionConfig::Mock(config) = config;
+        let mut config = config.clone();
+        let exchange_index = instruments.find_exchange_index(config.mocked_exchange).ok();
+
+        // Checkpointed balances replace the configured balances, which are retained for any
+        // asset the Engine has not yet received a balance for (eg/ before the first interval)
+        let balances = self
+            .engine_state
+            .assets
+            .0
+            .iter()
+            .filter(|(key, _)| key.exchange == config.mocked_exchange)
+            .filter_map(|(_, state)| {
+                state.balance.map(|balance| {
+                    AssetBalance::new(
+                        state.asset.name_exchange.clone(),
+                        balance.value,
+                        balance.time,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        config.initial_state.balances.retain(|configured| {
+            balances
+                .iter()
+                .all(|balance| balance.asset != configured.asset)
+        });
+        config.initial_state.balances.extend(balances);
+
+        config.initial_state.instruments = config
+            .initial_state
+            .instruments
+            .into_iter()
+            .map(|snapshot| {
+                let orders = self
+                    .engine_state
+                    .instruments
+                    .0
+                    .values()
+                    .filter(|state| Some(state.instrument.exchange) == exchange_index)
+                    .filter(|state| state.instrument.name_exchange == snapshot.instrument)
+                    .flat_map(|state| state.orders.0.values())
+                    .filter_map(|order| match &order.state {
+                        ActiveOrderState::Open(open) => Some(Order {
+                            key: OrderKey {
+                                exchange: config.mocked_exchange,
+                                instrument: snapshot.instrument.clone(),
+                                strategy: order.key.strategy.clone(),
+                                cid: order.key.cid.clone(),
+                            },
+                            side: order.side,
+                            price: order.price,
+                            quantity: order.quantity,
+                            kind: order.kind,
+                            time_in_force: order.time_in_force,
+                            reduce_only: order.reduce_only,
+                            state: OrderState::active(open.clone()),
+                        }),
+                        _ => None,
+                    })
+                    .collect();
+
+                InstrumentAccountSnapshot {
+                    instrument: snapshot.instrument,
+                    orders,
+                }
+            })
+            .collect();
+
+        ExecutionConfig::Mock(config)
+    }
+}
+
+/// Run a single backtest over in-memory market data, writing a [`BacktestCheckpoint`] to disk
+/// after every [`CheckpointConfig::interval`] of market data.
+///
+/// If a checkpoint already exists at the [`CheckpointConfig::path`], the backtest resumes from
+/// it rather than starting from the beginning (eg/ after the process was interrupted).
+///
+/// Open orders, and the `Strategy` & `RiskManager` (including any internal state), are carried
+/// across checkpoints. Note that orders with in flight requests at the end of a checkpoint
+/// interval are not (see [`BacktestCheckpoint::update`]).
+pub async fn backtest_with_checkpoints<
+    Kind,
+    SummaryInterval,
+    Strategy,
+    Risk,
+    GlobalData,
+    InstrumentData,
+>(
+    args_constant: Arc<
+        BacktestArgsConstant<
+            MarketDataInMemory<Kind>,
+            SummaryInterval,
+            EngineState<GlobalData, InstrumentData>,
+        >,
+    >,
+    args_dynamic: BacktestArgsDynamic<Strategy, Risk>,
+    config: &CheckpointConfig,
+) -> Result<BacktestSummary<SummaryInterval>, BarterError>
+where
+    Kind: Clone + Sync + Send + 'static,
+    SummaryInterval: TimeInterval,
+    Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + OnTradingDisabled<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + OnDisconnectStrategy<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + Clone
+        + Serialize
+        + DeserializeOwned
+        + Send
+        + 'static,
+    <Strategy as OnTradingDisabled<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnTradingDisabled: Debug + Clone + Send,
+    <Strategy as OnDisconnectStrategy<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnDisconnect: Debug + Clone + Send,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>
+        + Clone
+        + Serialize
+        + DeserializeOwned
+        + Send
+        + 'static,
+    GlobalData: for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>
+        + for<'a> Processor<&'a AccountEvent>
+        + Debug
+        + Clone
+        + Default
+        + Send
+        + Serialize
+        + DeserializeOwned
+        + 'static,
+    InstrumentData:
+        InstrumentDataState<MarketEventKind = Kind> + Serialize + DeserializeOwned + Send + 'static,
+{
+    if config.interval <= TimeDelta::zero() {
+        return Err(BarterError::from(CheckpointError::Interval(
+            config.interval,
+        )));
+    }
+
+    let market_data = &args_constant.market_data;
+    let time_end = market_data.time_last_event();
+
+    let mut checkpoint = match BacktestCheckpoint::read(&config.path, &checkpoint_migrations())? {
+        Some(checkpoint) => {
+            info!(
+                id = %args_dynamic.id,
+                time_resume = %checkpoint.time_resume,
+                "backtest resuming from checkpoint"
+            );
+            checkpoint
+        }
+        None => BacktestCheckpoint::new(
+            market_data.time_first_event().await?,
+            args_constant.engine_state.clone(),
+            args_dynamic.strategy.clone(),
+            args_dynamic.risk.clone(),
+        ),
+    };
+
+    while checkpoint.time_resume <= time_end {
+        let time_next = checkpoint
+            .time_resume
+            .checked_add_signed(config.interval)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+
+        let Some(market_data) = market_data.window(&(checkpoint.time_resume..time_next)) else {
+            checkpoint.time_resume = time_next;
+            continue;
+        };
+
+        let executions = args_constant
+            .executions
+            .iter()
+            .map(|execution| checkpoint.execution_config(&args_constant.instruments, execution))
+            .collect();
+
+        let engine = backtest_engine(
+            &args_constant.instruments,
+            executions,
+            &market_data,
+            checkpoint.engine_state.clone(),
+            checkpoint.exposure.clone(),
+            checkpoint.shortfall.clone(),
+            checkpoint.strategy.clone(),
+            checkpoint.risk.clone(),
+        )
+        .await?;
+
+        checkpoint.update(
+            time_next,
+            engine.state,
+            engine.extensions.exposure.unwrap_or_default(),
+            engine.extensions.shortfall.unwrap_or_default(),
+            engine.strategy,
+            engine.risk,
+        );
+        checkpoint.write(&config.path)?;
+
+        info!(
+            id = %args_dynamic.id,
+            time_resume = %checkpoint.time_resume,
+            "backtest wrote checkpoint"
+        );
+    }
+
+    let BacktestCheckpoint {
+        time_start,
+        time_resume: _,
+        engine_state,
+        exposure,
+        shortfall,
+        strategy: _,
+        risk: _,
+    } = checkpoint;
+
+    let trading_summary = TradingSummaryGenerator::init(
+        args_dynamic.risk_free_return,
+        time_start,
+        engine_state.time_engine_now,
+        &engine_state.instruments,
+        &engine_state.assets,
+    )
+    .with_exposure(Some(exposure))
+    .with_shortfall(Some(shortfall.report(|instrument| {
+        engine_state
+            .instruments
+            .instrument_index(instrument)
+            .instrument
+            .name_internal
+            .clone()
+    })))
+    .with_returns_interval(args_constant.returns_interval)
+    .generate(args_constant.summary_interval);
+
+    Ok(BacktestSummary {
+        id: args_dynamic.id,
+        risk_free_return: args_dynamic.risk_free_return,
+        trading_summary,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            trading::TradingState,
+        },
+        risk::DefaultRiskManager,
+        statistic::{summary::pnl::ReturnsInterval, time::Daily},
+        strategy::dca::{DcaConfig, DcaStrategy},
+        test_utils::{time_plus_days, time_plus_secs},
+    };
+    use barter_data::{
+        event::DataKind, streams::consumer::MarketStreamEvent, subscription::trade::PublicTrade,
+    };
+    use barter_execution::{
+        UnindexedAccountSnapshot,
+        balance::Balance,
+        client::mock::{MockExecutionConfig, failure::MockFailureConfig},
+        exchange::mock::slippage::SlippageConfig,
+        order::{
+            OrderKind, TimeInForce,
+            id::{ClientOrderId, OrderId, StrategyId},
+            state::{CancelInFlight, Open, OpenInFlight},
+        },
+    };
+    use barter_instrument::{
+        Side, Underlying,
+        asset::name::AssetNameExchange,
+        exchange::{ExchangeId, ExchangeIndex},
+        instrument::{Instrument, name::InstrumentNameExchange},
+    };
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+    type TestStrategy = DcaStrategy<TestState>;
+    type TestRisk = DefaultRiskManager<TestState>;
+
+    fn instruments() -> IndexedInstruments {
+        IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build()
+    }
+
+    fn execution_config() -> ExecutionConfig {
+        ExecutionConfig::Mock(MockExecutionConfig::new(
+            ExchangeId::BinanceSpot,
+            UnindexedAccountSnapshot {
+                exchange: ExchangeId::BinanceSpot,
+                balances: vec![
+                    AssetBalance::new(
+                        AssetNameExchange::new("usdt"),
+                        Balance::new(dec!(10_000), dec!(10_000)),
+                        DateTime::<Utc>::MIN_UTC,
+                    ),
+                    AssetBalance::new(
+                        AssetNameExchange::new("btc"),
+                        Balance::new(dec!(0), dec!(0)),
+                        DateTime::<Utc>::MIN_UTC,
+                    ),
+                ],
+                instruments: vec![InstrumentAccountSnapshot {
+                    instrument: InstrumentNameExchange::new("BTCUSDT"),
+                    orders: vec![],
+                }],
+            },
+            0,
+            dec!(0),
+            SlippageConfig::default(),
+            MockFailureConfig::default(),
+        ))
+    }
+
+    fn order(
+        cid: &str,
+        state: ActiveOrderState,
+    ) -> Order<ExchangeIndex, InstrumentIndex, ActiveOrderState> {
+        Order {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("test"),
+                cid: ClientOrderId::new(cid),
+            },
+            side: Side::Buy,
+            price: dec!(90),
+            quantity: dec!(1),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
+            state,
+        }
+    }
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "barter_backtest_checkpoint_{name}_{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_backtest_checkpoint_write_read() {
+        let path = checkpoint_path("write_read");
+        let migrations = checkpoint_migrations();
+
+        // No checkpoint has been written yet
+        assert_eq!(
+            BacktestCheckpoint::<u64, u64, u64>::read(&path, &migrations).unwrap(),
+            None
+        );
+
+        // Latest checkpoint replaces the previous checkpoint
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let mut checkpoint = BacktestCheckpoint::new(time_start, 1u64, 1u64, 1u64);
+        checkpoint.write(&path).unwrap();
+
+        checkpoint.time_resume = time_plus_days(time_start, 1);
+        checkpoint.engine_state = 2;
+        checkpoint.strategy = 2;
+        checkpoint.risk = 2;
+        checkpoint.write(&path).unwrap();
+
+        let actual = BacktestCheckpoint::<u64, u64, u64>::read(&path, &migrations).unwrap();
+        assert_eq!(actual, Some(checkpoint));
+        assert!(!path.with_extension("tmp").exists());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_backtest_checkpoint_read_migrates_asset_states_v1() {
+        let path = checkpoint_path("migrate");
+        let instruments = instruments();
+        let state = TestState::builder(&instruments, DefaultGlobalData, Default::default)
+            .time_engine_start(DateTime::<Utc>::MIN_UTC)
+            .build();
+        let checkpoint = BacktestCheckpoint::new(
+            DateTime::<Utc>::MIN_UTC,
+            state,
+            TestStrategy::new(
+                StrategyId::new("dca"),
+                DcaConfig::new(InstrumentIndex(0), dec!(100), TimeDelta::hours(2), None),
+            ),
+            TestRisk::default(),
+        );
+
+        // Version 1 checkpoint with AssetStates serialised as a map
+        let mut legacy = serde_json::to_value(&checkpoint).unwrap();
+        let assets = legacy.pointer_mut("/engine_state/assets").unwrap();
+        *assets = Value::Object(
+            assets
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|entry| (entry[0].to_string(), entry[1].clone()))
+                .collect(),
+        );
+        let legacy = serde_json::json!({"schema_version": 1, "payload": legacy});
+        std::fs::write(&path, legacy.to_string()).unwrap();
+
+        let actual = BacktestCheckpoint::<TestState, TestStrategy, TestRisk>::read(
+            &path,
+            &checkpoint_migrations(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(actual).unwrap(),
+            serde_json::to_value(checkpoint).unwrap()
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_backtest_checkpoint_update_persists_open_orders() {
+        let instruments = instruments();
+        let time = DateTime::<Utc>::MIN_UTC;
+        let open = Open::new(OrderId::new("7"), time, dec!(0));
+
+        let mut state = TestState::builder(&instruments, DefaultGlobalData, Default::default)
+            .time_engine_start(time)
+            .build();
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .orders
+            .0 = [
+            order("open", ActiveOrderState::Open(open.clone())),
+            order(
+                "open_in_flight",
+                ActiveOrderState::OpenInFlight(OpenInFlight),
+            ),
+            order(
+                "cancel_in_flight",
+                ActiveOrderState::CancelInFlight(CancelInFlight {
+                    order: Some(open.clone()),
+                }),
+            ),
+            order(
+                "cancel_in_flight_unopened",
+                ActiveOrderState::CancelInFlight(CancelInFlight { order: None }),
+            ),
+        ]
+        .into_iter()
+        .map(|order| (order.key.cid.clone(), order))
+        .collect();
+
+        let mut checkpoint = BacktestCheckpoint::new(time, state.clone(), (), ());
+        checkpoint.update(
+            time_plus_days(time, 1),
+            state,
+            ExposureGenerator::default(),
+            ShortfallTracker::default(),
+            (),
+            (),
+        );
+
+        // Orders awaiting an open response are discarded, orders awaiting a cancel revert to open
+        let orders = &checkpoint
+            .engine_state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .orders
+            .0;
+        let mut cids = orders.keys().map(|cid| cid.0.as_str()).collect::<Vec<_>>();
+        cids.sort();
+        assert_eq!(cids, vec!["cancel_in_flight", "open"]);
+        assert!(
+            orders
+                .values()
+                .all(|order| matches!(order.state, ActiveOrderState::Open(_)))
+        );
+
+        // Open orders rest on the resumed MockExchange
+        let ExecutionConfig::Mock(config) =
+            checkpoint.execution_config(&instruments, &execution_config());
+        let [snapshot] = config.initial_state.instruments.as_slice() else {
+            panic!("expected one InstrumentAccountSnapshot");
+        };
+        assert_eq!(snapshot.instrument, InstrumentNameExchange::new("BTCUSDT"));
+        assert_eq!(snapshot.orders.len(), 2);
+        assert!(snapshot.orders.iter().all(|order| {
+            order.key.exchange == ExchangeId::BinanceSpot
+                && order.key.instrument == snapshot.instrument
+                && order.state == OrderState::active(open.clone())
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_backtest_with_checkpoints_resumed_matches_straight_run() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let instruments = instruments();
+
+        // Trade every hour for 12 hours
+        let events = (0..12)
+            .map(|hour| {
+                let time = time_plus_secs(time_start, hour * 3600);
+                MarketStreamEvent::Item(MarketEvent {
+                    time_exchange: time,
+                    time_received: time,
+                    exchange: ExchangeId::BinanceSpot,
+                    instrument: InstrumentIndex(0),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: hour.to_string(),
+                        price: 100.0 + hour as f64,
+                        amount: 1.0,
+                        side: Side::Buy,
+                    }),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let args_constant = |events: Vec<_>| {
+            Arc::new(BacktestArgsConstant {
+                instruments: instruments.clone(),
+                executions: vec![execution_config()],
+                market_data: MarketDataInMemory::new(Arc::new(events)),
+                summary_interval: Daily,
+                returns_interval: ReturnsInterval::Daily,
+                engine_state: TestState::builder(&instruments, DefaultGlobalData, Default::default)
+                    .time_engine_start(time_start)
+                    .trading_state(TradingState::Enabled)
+                    .build(),
+            })
+        };
+
+        // Purchase interval is not a multiple of the checkpoint interval, so the purchase schedule
+        // is only preserved if the DcaStrategy state is carried across checkpoints
+        let args_dynamic = BacktestArgsDynamic {
+            id: "dca".into(),
+            risk_free_return: dec!(0),
+            strategy: TestStrategy::new(
+                StrategyId::new("dca"),
+                DcaConfig::new(InstrumentIndex(0), dec!(100), TimeDelta::minutes(210), None),
+            ),
+            risk: TestRisk::default(),
+        };
+        let config = |name| CheckpointConfig::new(checkpoint_path(name), TimeDelta::hours(3));
+        let read = |path: &Path| {
+            BacktestCheckpoint::<TestState, TestStrategy, TestRisk>::read(
+                path,
+                &checkpoint_migrations(),
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        // Engine time includes the live time elapsed since the last event, so compare whole hours
+        let outcome = |checkpoint: &BacktestCheckpoint<TestState, TestStrategy, TestRisk>| {
+            let previous = checkpoint.strategy.previous().unwrap();
+            let balances = checkpoint
+                .engine_state
+                .assets
+                .0
+                .values()
+                .map(|state| state.balance.unwrap().value)
+                .collect::<Vec<_>>();
+
+            (
+                checkpoint.time_resume,
+                previous.value,
+                (previous.time - time_start).num_hours(),
+                balances,
+            )
+        };
+
+        // Straight run
+        let straight = config("straight");
+        backtest_with_checkpoints(
+            args_constant(events.clone()),
+            args_dynamic.clone(),
+            &straight,
+        )
+        .await
+        .unwrap();
+        let straight_checkpoint = read(&straight.path);
+
+        // Final purchase is 4 hours after the purchase in the previous checkpoint interval
+        assert_eq!(
+            (straight_checkpoint.strategy.previous().unwrap().time - time_start).num_hours(),
+            10
+        );
+
+        // Run interrupted after the first two checkpoint intervals, then resumed
+        let resumed = config("resumed");
+        backtest_with_checkpoints(
+            args_constant(events[..6].to_vec()),
+            args_dynamic.clone(),
+            &resumed,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            read(&resumed.path).time_resume,
+            time_plus_secs(time_start, 6 * 3600)
+        );
+
+        backtest_with_checkpoints(args_constant(events), args_dynamic, &resumed)
+            .await
+            .unwrap();
+        let resumed_checkpoint = read(&resumed.path);
+
+        assert_eq!(outcome(&resumed_checkpoint), outcome(&straight_checkpoint));
+
+        std::fs::remove_file(&straight.path).unwrap();
+        std::fs::remove_file(&resumed.path).unwrap();
+    }
+}
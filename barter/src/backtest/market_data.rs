@@ -3,7 +3,7 @@ use barter_data::streams::consumer::MarketStreamEvent;
 use barter_instrument::instrument::InstrumentIndex;
 use chrono::{DateTime, Utc};
 use futures::Stream;
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 /// Interface that provides the backtest MarketStream and associated [`HistoricalClock`].
 pub trait BacktestMarketData {
@@ -73,4 +73,37 @@ impl<Kind> MarketDataInMemory<Kind> {
             events,
         }
     }
+
+    /// Return the `DateTime<Utc>` of the last event in the market data.
+    pub fn time_last_event(&self) -> DateTime<Utc> {
+        self.events
+            .iter()
+            .rev()
+            .find_map(|event| match event {
+                MarketStreamEvent::Item(event) => Some(event.time_exchange),
+                _ => None,
+            })
+            .unwrap_or(self.time_first_event)
+    }
+
+    /// Construct a new in-memory market data source containing only the market events with a
+    /// `time_exchange` within the provided time window.
+    ///
+    /// Returns `None` if there are no market events within the time window.
+    pub fn window(&self, window: &Range<DateTime<Utc>>) -> Option<Self>
+    where
+        Kind: Clone,
+    {
+        let events = self
+            .events
+            .iter()
+            .filter(|event| match event {
+                MarketStreamEvent::Item(event) => window.contains(&event.time_exchange),
+                _ => false,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        (!events.is_empty()).then(|| Self::new(Arc::new(events)))
+    }
 }
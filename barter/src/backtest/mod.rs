@@ -15,7 +15,11 @@ use crate::{
     },
     error::BarterError,
     risk::RiskManager,
-    statistic::time::TimeInterval,
+    statistic::{
+        metric::{exposure::ExposureGenerator, shortfall::ShortfallTracker},
+        summary::pnl::ReturnsInterval,
+        time::TimeInterval,
+    },
     strategy::{
         algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
         on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
@@ -30,18 +34,42 @@ use crate::{
 use barter_data::event::MarketEvent;
 use barter_execution::AccountEvent;
 use barter_instrument::{index::IndexedInstruments, instrument::InstrumentIndex};
+use barter_integration::channel::BoundedConfig;
 use futures::future::try_join_all;
 use rust_decimal::Decimal;
 use smol_str::SmolStr;
 use std::{fmt::Debug, sync::Arc};
 
+/// Periodic checkpointing of long running backtests to disk, so they can resume from the latest
+/// checkpoint after an interruption.
+pub mod checkpoint;
+
+/// Historical funding rate & borrow rate loaders that interleave with backtest market data, so
+/// carry costs are modelled from real rates.
+pub mod carry;
+
 /// Defines the interface and implementations for different types of market data sources
 /// that can be used in backtests.
 pub mod market_data;
 
+/// Commission & slippage sensitivity analysis that re-runs a backtest across a range of trading
+/// cost assumptions.
+pub mod sensitivity;
+
 /// Contains data structures for representing backtest results and metrics.
 pub mod summary;
 
+/// Parallel parameter sweep that backtests every parameter set in a grid and collects the
+/// results into a sortable table.
+pub mod sweep;
+
+/// Walk-forward optimisation of strategy parameters over rolling train & test windows of
+/// historical market data.
+pub mod walkforward;
+
+/// Warm-up of a common [`EngineState`] that every backtest in a batch branches from.
+pub mod warm_up;
+
 /// Configuration for constants used across all backtests in a batch.
 ///
 /// Contains shared inputs like instruments, execution configurations,
@@ -56,6 +84,8 @@ pub struct BacktestArgsConstant<MarketData, SummaryInterval, State> {
     pub market_data: MarketData,
     /// Time interval for aggregating and reporting summary statistics.
     pub summary_interval: SummaryInterval,
+    /// Interval over which PnL returns are measured for risk-adjusted summary statistics.
+    pub returns_interval: ReturnsInterval,
     /// EngineState.
     pub engine_state: State,
 }
@@ -193,42 +223,124 @@ where
         + 'static,
     InstrumentData: InstrumentDataState + Send + 'static,
 {
-    let clock = args_constant
-        .market_data
+    let engine = backtest_engine(
+        &args_constant.instruments,
+        args_constant.executions.clone(),
+        &args_constant.market_data,
+        args_constant.engine_state.clone(),
+        ExposureGenerator::default(),
+        ShortfallTracker::default(),
+        args_dynamic.strategy,
+        args_dynamic.risk,
+    )
+    .await?;
+
+    let trading_summary = engine
+        .trading_summary_generator(args_dynamic.risk_free_return)
+        .with_returns_interval(args_constant.returns_interval)
+        .generate(args_constant.summary_interval);
+
+    Ok(BacktestSummary {
+        id: args_dynamic.id,
+        risk_free_return: args_dynamic.risk_free_return,
+        trading_summary,
+    })
+}
+
+/// Run the `Engine` of a single backtest over the provided market data, returning the `Engine`
+/// once the market data has ended.
+///
+/// The provided [`ExposureGenerator`] and [`ShortfallTracker`] seed the `Engine` exposure &
+/// implementation shortfall tracking (eg/ when resuming from a
+/// [`BacktestCheckpoint`](checkpoint::BacktestCheckpoint)).
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn backtest_engine<MarketData, Strategy, Risk, GlobalData, InstrumentData>(
+    instruments: &IndexedInstruments,
+    executions: Vec<ExecutionConfig>,
+    market_data: &MarketData,
+    engine_state: EngineState<GlobalData, InstrumentData>,
+    exposure: ExposureGenerator,
+    shortfall: ShortfallTracker,
+    strategy: Strategy,
+    risk: Risk,
+) -> Result<
+    Engine<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Strategy,
+        Risk,
+    >,
+    BarterError,
+>
+where
+    MarketData: BacktestMarketData<Kind = InstrumentData::MarketEventKind>,
+    Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + OnTradingDisabled<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + OnDisconnectStrategy<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + Send
+        + 'static,
+    <Strategy as OnTradingDisabled<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnTradingDisabled: Debug + Clone + Send,
+    <Strategy as OnDisconnectStrategy<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnDisconnect: Debug + Clone + Send,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>> + Send + 'static,
+    GlobalData: for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>
+        + for<'a> Processor<&'a AccountEvent>
+        + Debug
+        + Clone
+        + Default
+        + Send
+        + 'static,
+    InstrumentData: InstrumentDataState + Send + 'static,
+{
+    let clock = market_data
         .time_first_event()
         .await
         .map(HistoricalClock::new)?;
-    let market_stream = args_constant.market_data.stream().await?;
+    let market_stream = market_data.stream().await?;
 
     // Build Execution infrastructure
     let ExecutionBuild {
         execution_tx_map,
         account_channel,
         futures,
-    } = args_constant
-        .executions
-        .clone()
+    } = executions
         .into_iter()
         .try_fold(
-            ExecutionBuilder::new(&args_constant.instruments),
+            ExecutionBuilder::new(instruments),
             |builder, config| match config {
                 ExecutionConfig::Mock(mock_config) => builder.add_mock(mock_config, clock.clone()),
             },
         )?
         .build();
 
-    let engine = Engine::new(
-        clock,
-        args_constant.engine_state.clone(),
-        execution_tx_map,
-        args_dynamic.strategy,
-        args_dynamic.risk,
-    );
+    let mut engine = Engine::new(clock, engine_state, execution_tx_map, strategy, risk);
+    engine.extensions.exposure = Some(exposure);
+    engine.extensions.shortfall = Some(shortfall);
 
     let system = SystemBuild::new(
         engine,
         EngineFeedMode::Stream,
         AuditMode::Disabled,
+        BoundedConfig::default(),
         market_stream,
         account_channel,
         futures,
@@ -238,13 +350,5 @@ where
 
     let (engine, _shutdown_audit) = system.shutdown_after_backtest().await?;
 
-    let trading_summary = engine
-        .trading_summary_generator(args_dynamic.risk_free_return)
-        .generate(args_constant.summary_interval);
-
-    Ok(BacktestSummary {
-        id: args_dynamic.id,
-        risk_free_return: args_dynamic.risk_free_return,
-        trading_summary,
-    })
+    Ok(engine)
 }
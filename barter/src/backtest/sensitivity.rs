@@ -0,0 +1,256 @@
+use crate::{
+    backtest::{
+        BacktestArgsConstant, BacktestArgsDynamic, backtest, market_data::BacktestMarketData,
+        summary::BacktestSummary,
+    },
+    engine::{
+        Processor,
+        clock::HistoricalClock,
+        execution_tx::MultiExchangeTxMap,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    error::BarterError,
+    risk::RiskManager,
+    statistic::time::TimeInterval,
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
+    system::config::ExecutionConfig,
+};
+use barter_data::event::MarketEvent;
+use barter_execution::AccountEvent;
+use barter_execution::exchange::mock::slippage::{FixedBps, SlippageConfig};
+use barter_instrument::instrument::InstrumentIndex;
+use derive_more::Constructor;
+use futures::future::try_join_all;
+use prettytable::{Cell, Row, Table};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, sync::Arc};
+
+/// Trading cost assumption applied to every mock execution of a backtest.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct CostAssumption {
+    /// Fees charged as a fraction of each fill value (eg/ 0.001 for 10 bps).
+    pub fees_percent: Decimal,
+
+    /// Adverse slippage applied as a fraction of each fill price (eg/ 0.0005 for 5 bps).
+    pub slippage_percent: Decimal,
+}
+
+impl CostAssumption {
+    /// Generate every combination of the provided fee and slippage assumptions, ordered by fees
+    /// then slippage.
+    pub fn grid(fees: &[Decimal], slippages: &[Decimal]) -> Vec<Self> {
+        fees.iter()
+            .flat_map(|fees_percent| {
+                slippages
+                    .iter()
+                    .map(|slippage_percent| Self::new(*fees_percent, *slippage_percent))
+            })
+            .collect()
+    }
+
+    /// Apply the `CostAssumption` to the provided [`ExecutionConfig`].
+    pub fn apply(&self, config: ExecutionConfig) -> ExecutionConfig {
+        match config {
+            ExecutionConfig::Mock(mut mock_config) => {
+                mock_config.fees_percent = self.fees_percent;
+                mock_config.slippage =
+                    SlippageConfig::FixedBps(FixedBps::from_percent(self.slippage_percent));
+                ExecutionConfig::Mock(mock_config)
+            }
+        }
+    }
+}
+
+/// Backtest [`BacktestSummary`] of a strategy under a single [`CostAssumption`].
+#[derive(Debug, PartialEq)]
+pub struct CostSensitivityRow<Interval> {
+    pub costs: CostAssumption,
+    pub summary: BacktestSummary<Interval>,
+}
+
+impl<Interval> CostSensitivityRow<Interval> {
+    /// Total PnL across all instruments of the backtest.
+    pub fn pnl(&self) -> Decimal {
+        self.summary
+            .trading_summary
+            .instruments
+            .values()
+            .map(|tear_sheet| tear_sheet.pnl)
+            .sum()
+    }
+}
+
+/// Sensitivity of a strategy's performance to a range of [`CostAssumption`]s.
+#[derive(Debug, PartialEq)]
+pub struct CostSensitivity<Interval> {
+    /// [`CostSensitivityRow`] for each [`CostAssumption`], in the order provided.
+    pub rows: Vec<CostSensitivityRow<Interval>>,
+}
+
+impl<Interval> CostSensitivity<Interval> {
+    /// Return the [`CostAssumption`]s under which the strategy's edge survives (ie/ total PnL
+    /// is positive).
+    pub fn profitable(&self) -> impl Iterator<Item = CostAssumption> + '_ {
+        self.rows
+            .iter()
+            .filter(|row| row.pnl() > Decimal::ZERO)
+            .map(|row| row.costs)
+    }
+
+    /// Print the [`CostSensitivity`] table to stdout.
+    pub fn print_table(&self) {
+        self.table().printstd();
+    }
+
+    /// Generate a [`CostSensitivity`] table, with a row for each [`CostAssumption`].
+    pub fn table(&self) -> Table {
+        let mut table = Table::new();
+
+        table.set_titles(Row::new(vec![
+            Cell::new("Fees %").style_spec("bc"),
+            Cell::new("Slippage %").style_spec("bc"),
+            Cell::new("PnL").style_spec("bc"),
+        ]));
+
+        for row in &self.rows {
+            let pnl = row.pnl();
+            let pnl_cell = Cell::new(&pnl.round_dp(4).to_string());
+
+            table.add_row(Row::new(vec![
+                Cell::new(&row.costs.fees_percent.to_string()),
+                Cell::new(&row.costs.slippage_percent.to_string()),
+                if pnl > Decimal::ZERO {
+                    pnl_cell.style_spec("Fg")
+                } else {
+                    pnl_cell.style_spec("Fr")
+                },
+            ]));
+        }
+
+        table
+    }
+}
+
+/// Re-run a backtest concurrently under each provided [`CostAssumption`], generating a
+/// [`CostSensitivity`] table that indicates whether a strategy's edge survives realistic costs.
+///
+/// Each [`CostAssumption`] overrides the fees & slippage of every mock [`ExecutionConfig`].
+pub async fn cost_sensitivity<
+    MarketData,
+    SummaryInterval,
+    Strategy,
+    Risk,
+    GlobalData,
+    InstrumentData,
+>(
+    args_constant: Arc<
+        BacktestArgsConstant<MarketData, SummaryInterval, EngineState<GlobalData, InstrumentData>>,
+    >,
+    args_dynamic: BacktestArgsDynamic<Strategy, Risk>,
+    costs: impl IntoIterator<Item = CostAssumption>,
+) -> Result<CostSensitivity<SummaryInterval>, BarterError>
+where
+    MarketData: BacktestMarketData<Kind = InstrumentData::MarketEventKind> + Clone,
+    SummaryInterval: TimeInterval,
+    Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + OnTradingDisabled<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + OnDisconnectStrategy<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + Clone
+        + Send
+        + 'static,
+    <Strategy as OnTradingDisabled<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnTradingDisabled: Debug + Clone + Send,
+    <Strategy as OnDisconnectStrategy<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnDisconnect: Debug + Clone + Send,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>> + Clone + Send + 'static,
+    GlobalData: for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>
+        + for<'a> Processor<&'a AccountEvent>
+        + Debug
+        + Clone
+        + Default
+        + Send
+        + 'static,
+    InstrumentData: InstrumentDataState + Send + 'static,
+{
+    let backtest_futures = costs.into_iter().map(|costs| {
+        let args_constant = Arc::new(BacktestArgsConstant {
+            instruments: args_constant.instruments.clone(),
+            executions: args_constant
+                .executions
+                .iter()
+                .cloned()
+                .map(|config| costs.apply(config))
+                .collect(),
+            market_data: args_constant.market_data.clone(),
+            summary_interval: args_constant.summary_interval,
+            returns_interval: args_constant.returns_interval,
+            engine_state: args_constant.engine_state.clone(),
+        });
+
+        let args_dynamic = args_dynamic.clone();
+
+        async move {
+            backtest(args_constant, args_dynamic)
+                .await
+                .map(|summary| CostSensitivityRow { costs, summary })
+        }
+    });
+
+    Ok(CostSensitivity {
+        rows: try_join_all(backtest_futures).await?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_cost_assumption_grid() {
+        let actual = CostAssumption::grid(&[dec!(0), dec!(0.001)], &[dec!(0), dec!(0.0005)]);
+
+        let expected = vec![
+            CostAssumption::new(dec!(0), dec!(0)),
+            CostAssumption::new(dec!(0), dec!(0.0005)),
+            CostAssumption::new(dec!(0.001), dec!(0)),
+            CostAssumption::new(dec!(0.001), dec!(0.0005)),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
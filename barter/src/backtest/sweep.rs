@@ -0,0 +1,256 @@
+use crate::{
+    backtest::{
+        BacktestArgsConstant, BacktestArgsDynamic, backtest, market_data::BacktestMarketData,
+        summary::BacktestSummary,
+    },
+    engine::{
+        Processor,
+        clock::HistoricalClock,
+        execution_tx::MultiExchangeTxMap,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    error::BarterError,
+    risk::RiskManager,
+    statistic::time::TimeInterval,
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_data::event::MarketEvent;
+use barter_execution::AccountEvent;
+use barter_instrument::instrument::InstrumentIndex;
+use futures::{StreamExt, TryStreamExt};
+use prettytable::{Cell, Row, Table};
+use rust_decimal::Decimal;
+use std::{cmp::Reverse, fmt::Debug, sync::Arc, time::Duration};
+
+/// Backtest [`BacktestSummary`] of a single parameter set in a [`sweep`].
+#[derive(Debug, PartialEq)]
+pub struct SweepResult<Params, Interval> {
+    /// Parameter set the backtest was run with.
+    pub params: Params,
+
+    /// Results of the backtest.
+    pub summary: BacktestSummary<Interval>,
+}
+
+impl<Params, Interval> SweepResult<Params, Interval> {
+    /// Total PnL across all instruments of the backtest.
+    pub fn pnl(&self) -> Decimal {
+        self.summary
+            .trading_summary
+            .instruments
+            .values()
+            .map(|tear_sheet| tear_sheet.pnl)
+            .sum()
+    }
+}
+
+/// Sortable table of [`SweepResult`]s generated by a [`sweep`] across a parameter grid.
+#[derive(Debug, PartialEq)]
+pub struct SweepResults<Params, Interval> {
+    /// Total execution time for all backtests in the sweep.
+    pub duration: Duration,
+
+    /// Collection of `SweepResult`s, initially in parameter grid order.
+    pub results: Vec<SweepResult<Params, Interval>>,
+}
+
+impl<Params, Interval> SweepResults<Params, Interval> {
+    /// Sort the [`SweepResult`]s in descending order of the provided metric (ie/ best first).
+    pub fn sort_by_desc<FnMetric>(&mut self, metric: FnMetric)
+    where
+        FnMetric: Fn(&BacktestSummary<Interval>) -> Decimal,
+    {
+        self.results
+            .sort_by_cached_key(|result| Reverse(metric(&result.summary)));
+    }
+
+    /// Return the [`SweepResult`] with the greatest value of the provided metric.
+    pub fn best<FnMetric>(&self, metric: FnMetric) -> Option<&SweepResult<Params, Interval>>
+    where
+        FnMetric: Fn(&BacktestSummary<Interval>) -> Decimal,
+    {
+        self.results
+            .iter()
+            .max_by_key(|result| metric(&result.summary))
+    }
+
+    /// Print the [`SweepResults`] table to stdout.
+    pub fn print_table(&self)
+    where
+        Params: Debug,
+    {
+        self.table().printstd();
+    }
+
+    /// Generate a [`SweepResults`] table, with a row for each parameter set.
+    pub fn table(&self) -> Table
+    where
+        Params: Debug,
+    {
+        let mut table = Table::new();
+
+        table.set_titles(Row::new(vec![
+            Cell::new("Id").style_spec("bc"),
+            Cell::new("Params").style_spec("bc"),
+            Cell::new("PnL").style_spec("bc"),
+        ]));
+
+        for result in &self.results {
+            table.add_row(Row::new(vec![
+                Cell::new(&result.summary.id),
+                Cell::new(&format!("{:?}", result.params)),
+                Cell::new(&result.pnl().round_dp(4).to_string()),
+            ]));
+        }
+
+        table
+    }
+}
+
+/// Run a backtest for every parameter set in the provided grid, in parallel.
+///
+/// Each backtest is spawned as a blocking task driving its own `Engine`, with an isolated clone
+/// of the `args_constant` `EngineState`. At most `parallelism` backtests are run at once.
+///
+/// The returned [`SweepResults`] are in parameter grid order, and can be sorted by any metric
+/// (see [`SweepResults::sort_by_desc`]).
+pub async fn sweep<
+    MarketData,
+    SummaryInterval,
+    Params,
+    Strategy,
+    Risk,
+    GlobalData,
+    InstrumentData,
+    BuildFn,
+>(
+    args_constant: Arc<
+        BacktestArgsConstant<MarketData, SummaryInterval, EngineState<GlobalData, InstrumentData>>,
+    >,
+    grid: impl IntoIterator<Item = Params>,
+    build_args_dynamic: BuildFn,
+    parallelism: usize,
+) -> Result<SweepResults<Params, SummaryInterval>, BarterError>
+where
+    MarketData: BacktestMarketData<Kind = InstrumentData::MarketEventKind> + Send + Sync + 'static,
+    SummaryInterval: TimeInterval + Send + Sync + 'static,
+    Params: Send + 'static,
+    BuildFn: Fn(&Params) -> BacktestArgsDynamic<Strategy, Risk>,
+    Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + OnTradingDisabled<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + OnDisconnectStrategy<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + Send
+        + 'static,
+    <Strategy as OnTradingDisabled<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnTradingDisabled: Debug + Clone + Send,
+    <Strategy as OnDisconnectStrategy<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnDisconnect: Debug + Clone + Send,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>> + Send + 'static,
+    GlobalData: for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>
+        + for<'a> Processor<&'a AccountEvent>
+        + Debug
+        + Clone
+        + Default
+        + Send
+        + Sync
+        + 'static,
+    InstrumentData: InstrumentDataState + Send + Sync + 'static,
+{
+    let time_start = std::time::Instant::now();
+    let runtime = tokio::runtime::Handle::current();
+
+    let results = futures::stream::iter(grid)
+        .map(|params| {
+            let args_constant = Arc::clone(&args_constant);
+            let args_dynamic = build_args_dynamic(&params);
+            let runtime = runtime.clone();
+
+            // Drive each backtest on a dedicated blocking thread so they run in parallel
+            tokio::task::spawn_blocking(move || {
+                runtime
+                    .block_on(backtest(args_constant, args_dynamic))
+                    .map(|summary| SweepResult { params, summary })
+            })
+        })
+        .buffered(parallelism.max(1))
+        .map(|result| result?)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    Ok(SweepResults {
+        duration: std::time::Instant::now().duration_since(time_start),
+        results,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistic::summary::TradingSummary;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn sweep_result(params: u64, risk_free_return: Decimal) -> SweepResult<u64, ()> {
+        SweepResult {
+            params,
+            summary: BacktestSummary {
+                id: params.to_string().into(),
+                risk_free_return,
+                trading_summary: TradingSummary::new(
+                    DateTime::<Utc>::MIN_UTC,
+                    DateTime::<Utc>::MIN_UTC,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    None,
+                    None,
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn test_sweep_results_sort_by_desc() {
+        let mut results = SweepResults {
+            duration: Duration::ZERO,
+            results: vec![
+                sweep_result(0, dec!(0.02)),
+                sweep_result(1, dec!(0.05)),
+                sweep_result(2, dec!(0.01)),
+            ],
+        };
+
+        let metric = |summary: &BacktestSummary<()>| summary.risk_free_return;
+
+        assert_eq!(results.best(metric).map(|result| result.params), Some(1));
+
+        results.sort_by_desc(metric);
+        let actual = results
+            .results
+            .iter()
+            .map(|result| result.params)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, vec![1, 0, 2]);
+    }
+}
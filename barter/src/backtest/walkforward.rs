@@ -0,0 +1,331 @@
+use crate::{
+    backtest::{
+        BacktestArgsConstant, BacktestArgsDynamic, backtest,
+        market_data::{BacktestMarketData, MarketDataInMemory},
+        run_backtests,
+        summary::BacktestSummary,
+    },
+    engine::{
+        Processor,
+        clock::HistoricalClock,
+        execution_tx::MultiExchangeTxMap,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    error::BarterError,
+    risk::RiskManager,
+    statistic::time::TimeInterval,
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_data::event::MarketEvent;
+use barter_execution::AccountEvent;
+use barter_instrument::instrument::{InstrumentIndex, name::InstrumentNameInternal};
+use barter_integration::collection::FnvIndexMap;
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, ops::Range, sync::Arc};
+use tracing::warn;
+
+/// Configuration of the rolling train & test windows used by a [`walk_forward`] optimisation.
+///
+/// Consecutive windows are stepped forward by the `test` duration, such that the out-of-sample
+/// test windows are contiguous and can be stitched together.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct WalkForwardConfig {
+    /// Duration of each in-sample train window that strategy parameters are optimised over.
+    pub train: TimeDelta,
+
+    /// Duration of each out-of-sample test window that the optimised parameters are evaluated
+    /// over.
+    pub test: TimeDelta,
+}
+
+/// Single in-sample train window, and the subsequent out-of-sample test window.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct WalkForwardWindow {
+    pub train: Range<DateTime<Utc>>,
+    pub test: Range<DateTime<Utc>>,
+}
+
+/// Generate the rolling [`WalkForwardWindow`]s that fit between `time_start` and `time_end`.
+///
+/// Trailing data that is insufficient for a complete test window is not used. Returns no windows
+/// if either the `train` or `test` duration is not positive.
+pub fn walk_forward_windows(
+    time_start: DateTime<Utc>,
+    time_end: DateTime<Utc>,
+    config: &WalkForwardConfig,
+) -> Vec<WalkForwardWindow> {
+    if config.train <= TimeDelta::zero() || config.test <= TimeDelta::zero() {
+        return vec![];
+    }
+
+    std::iter::successors(Some(time_start), |train_start| {
+        train_start.checked_add_signed(config.test)
+    })
+    .map_while(|train_start| {
+        let train_end = train_start.checked_add_signed(config.train)?;
+        let test_end = train_end.checked_add_signed(config.test)?;
+
+        (test_end <= time_end).then_some(WalkForwardWindow {
+            train: train_start..train_end,
+            test: train_end..test_end,
+        })
+    })
+    .collect()
+}
+
+/// Results of a single [`WalkForwardWindow`] of a [`walk_forward`] optimisation.
+#[derive(Debug, PartialEq)]
+pub struct WalkForwardWindowSummary<Params, Interval> {
+    /// Train & test time windows.
+    pub window: WalkForwardWindow,
+
+    /// Strategy parameters that maximised the objective over the train window.
+    pub params: Params,
+
+    /// Objective value of the selected parameters over the train window.
+    pub objective_in_sample: Decimal,
+
+    /// Objective value of the selected parameters over the test window.
+    pub objective_out_of_sample: Decimal,
+
+    /// [`BacktestSummary`] of the selected parameters over the test window.
+    pub out_of_sample: BacktestSummary<Interval>,
+}
+
+/// Aggregated results of a [`walk_forward`] optimisation.
+#[derive(Debug, PartialEq)]
+pub struct WalkForwardSummary<Params, Interval> {
+    /// Results of each [`WalkForwardWindow`], in chronological order.
+    pub windows: Vec<WalkForwardWindowSummary<Params, Interval>>,
+
+    /// Stitched out-of-sample PnL of each instrument across all test windows.
+    pub pnl_out_of_sample: FnvIndexMap<InstrumentNameInternal, Decimal>,
+
+    /// Mean out-of-sample objective value across all test windows.
+    pub objective_out_of_sample_mean: Option<Decimal>,
+}
+
+impl<Params, Interval> WalkForwardSummary<Params, Interval> {
+    /// Construct a new `WalkForwardSummary` by stitching together the out-of-sample results of
+    /// the provided [`WalkForwardWindowSummary`]s.
+    pub fn new(windows: Vec<WalkForwardWindowSummary<Params, Interval>>) -> Self {
+        let pnl_out_of_sample = windows.iter().fold(
+            FnvIndexMap::default(),
+            |mut pnl: FnvIndexMap<InstrumentNameInternal, Decimal>, window| {
+                for (instrument, tear_sheet) in &window.out_of_sample.trading_summary.instruments {
+                    *pnl.entry(instrument.clone()).or_default() += tear_sheet.pnl;
+                }
+                pnl
+            },
+        );
+
+        let objective_out_of_sample_mean = (!windows.is_empty()).then(|| {
+            windows
+                .iter()
+                .map(|window| window.objective_out_of_sample)
+                .sum::<Decimal>()
+                / Decimal::from(windows.len())
+        });
+
+        Self {
+            windows,
+            pnl_out_of_sample,
+            objective_out_of_sample_mean,
+        }
+    }
+}
+
+/// Run a walk-forward optimisation over the provided in-memory market data.
+///
+/// For each rolling [`WalkForwardWindow`]:
+/// 1. Backtests every candidate `Params` over the train window.
+/// 2. Selects the `Params` with the greatest in-sample `objective` value.
+/// 3. Backtests the selected `Params` over the subsequent test window.
+///
+/// Each backtest starts from the `args_constant` `EngineState`, so the out-of-sample results are
+/// stitched together by aggregating the test window statistics (see [`WalkForwardSummary`]).
+/// Windows without market data are skipped.
+pub async fn walk_forward<
+    Kind,
+    SummaryInterval,
+    Params,
+    Strategy,
+    Risk,
+    GlobalData,
+    InstrumentData,
+    BuildFn,
+    ObjectiveFn,
+>(
+    args_constant: BacktestArgsConstant<
+        MarketDataInMemory<Kind>,
+        SummaryInterval,
+        EngineState<GlobalData, InstrumentData>,
+    >,
+    config: WalkForwardConfig,
+    params: Vec<Params>,
+    build_args_dynamic: BuildFn,
+    objective: ObjectiveFn,
+) -> Result<WalkForwardSummary<Params, SummaryInterval>, BarterError>
+where
+    Kind: Clone + Sync + Send + 'static,
+    SummaryInterval: TimeInterval,
+    Params: Clone,
+    BuildFn: Fn(&Params) -> BacktestArgsDynamic<Strategy, Risk>,
+    ObjectiveFn: Fn(&BacktestSummary<SummaryInterval>) -> Decimal,
+    Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + OnTradingDisabled<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + OnDisconnectStrategy<
+            HistoricalClock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Risk,
+        > + Send
+        + 'static,
+    <Strategy as OnTradingDisabled<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnTradingDisabled: Debug + Clone + Send,
+    <Strategy as OnDisconnectStrategy<
+        HistoricalClock,
+        EngineState<GlobalData, InstrumentData>,
+        MultiExchangeTxMap,
+        Risk,
+    >>::OnDisconnect: Debug + Clone + Send,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>> + Send + 'static,
+    GlobalData: for<'a> Processor<&'a MarketEvent<InstrumentIndex, Kind>>
+        + for<'a> Processor<&'a AccountEvent>
+        + Debug
+        + Clone
+        + Default
+        + Send
+        + 'static,
+    InstrumentData: InstrumentDataState<MarketEventKind = Kind> + Default + Send + 'static,
+{
+    let windows = walk_forward_windows(
+        args_constant.market_data.time_first_event().await?,
+        args_constant.market_data.time_last_event(),
+        &config,
+    );
+
+    let args_constant_window = |market_data: MarketDataInMemory<Kind>| {
+        Arc::new(BacktestArgsConstant {
+            instruments: args_constant.instruments.clone(),
+            executions: args_constant.executions.clone(),
+            market_data,
+            summary_interval: args_constant.summary_interval,
+            returns_interval: args_constant.returns_interval,
+            engine_state: args_constant.engine_state.clone(),
+        })
+    };
+
+    let mut summaries = Vec::with_capacity(windows.len());
+
+    for window in windows {
+        let (Some(market_data_train), Some(market_data_test)) = (
+            args_constant.market_data.window(&window.train),
+            args_constant.market_data.window(&window.test),
+        ) else {
+            warn!(?window, "WalkForward skipping window without market data");
+            continue;
+        };
+
+        // Optimise Params over the in-sample train window
+        let in_sample = run_backtests(
+            args_constant_window(market_data_train),
+            params.iter().map(&build_args_dynamic),
+        )
+        .await?;
+
+        let Some((params_best, objective_in_sample)) = params
+            .iter()
+            .zip(&in_sample.summaries)
+            .map(|(params, summary)| (params, objective(summary)))
+            .max_by_key(|(_, objective)| *objective)
+        else {
+            continue;
+        };
+
+        // Evaluate optimised Params over the out-of-sample test window
+        let out_of_sample = backtest(
+            args_constant_window(market_data_test),
+            build_args_dynamic(params_best),
+        )
+        .await?;
+
+        summaries.push(WalkForwardWindowSummary {
+            window,
+            params: params_best.clone(),
+            objective_in_sample,
+            objective_out_of_sample: objective(&out_of_sample),
+            out_of_sample,
+        });
+    }
+
+    Ok(WalkForwardSummary::new(summaries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_days;
+
+    #[test]
+    fn test_walk_forward_windows() {
+        struct TestCase {
+            time_end: DateTime<Utc>,
+            config: WalkForwardConfig,
+            expected: Vec<WalkForwardWindow>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let window = |train_start: u64, test_start: u64, test_end: u64| WalkForwardWindow {
+            train: time_plus_days(base, train_start)..time_plus_days(base, test_start),
+            test: time_plus_days(base, test_start)..time_plus_days(base, test_end),
+        };
+
+        let cases = vec![
+            // TC0: rolling windows stepped by the test duration
+            TestCase {
+                time_end: time_plus_days(base, 10),
+                config: WalkForwardConfig::new(TimeDelta::days(4), TimeDelta::days(2)),
+                expected: vec![window(0, 4, 6), window(2, 6, 8), window(4, 8, 10)],
+            },
+            // TC1: trailing data insufficient for a complete test window is not used
+            TestCase {
+                time_end: time_plus_days(base, 9),
+                config: WalkForwardConfig::new(TimeDelta::days(4), TimeDelta::days(2)),
+                expected: vec![window(0, 4, 6), window(2, 6, 8)],
+            },
+            // TC2: insufficient data for a single window
+            TestCase {
+                time_end: time_plus_days(base, 5),
+                config: WalkForwardConfig::new(TimeDelta::days(4), TimeDelta::days(2)),
+                expected: vec![],
+            },
+            // TC3: non-positive duration generates no windows
+            TestCase {
+                time_end: time_plus_days(base, 10),
+                config: WalkForwardConfig::new(TimeDelta::days(4), TimeDelta::zero()),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = walk_forward_windows(base, test.time_end, &test.config);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
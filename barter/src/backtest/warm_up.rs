@@ -0,0 +1,138 @@
+use crate::{
+    backtest::market_data::BacktestMarketData,
+    engine::{
+        Processor,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    error::BarterError,
+};
+use barter_data::{event::MarketEvent, streams::consumer::MarketStreamEvent};
+use barter_instrument::instrument::InstrumentIndex;
+use futures::StreamExt;
+use std::pin::pin;
+
+/// Warm up an [`EngineState`] by processing every market event provided by the
+/// [`BacktestMarketData`] (eg/ to initialise indicators in the `InstrumentData` & `GlobalData`).
+///
+/// The warmed-up `EngineState` can be used as the
+/// [`BacktestArgsConstant`](super::BacktestArgsConstant) `engine_state`. Every backtest forks the
+/// shared state by cloning it when it starts, so parameter sweeps & Monte Carlo runs all branch
+/// from the common warmed-up state without re-running the warm-up each time.
+///
+/// Note that each fork is a deep clone of the `EngineState`. A copy-on-write repository that
+/// shares unmodified instrument & asset state between forks is not yet implemented.
+///
+/// Note that the warm-up market data should precede the backtest market data (see
+/// [`MarketDataInMemory::window`](super::market_data::MarketDataInMemory::window)), and since
+/// no trading occurs during the warm-up, the `EngineState` `time_engine_start` should be the
+/// start of the backtest.
+pub async fn warm_up<MarketData, GlobalData, InstrumentData>(
+    mut state: EngineState<GlobalData, InstrumentData>,
+    market_data: &MarketData,
+) -> Result<EngineState<GlobalData, InstrumentData>, BarterError>
+where
+    MarketData: BacktestMarketData<Kind = InstrumentData::MarketEventKind>,
+    GlobalData:
+        for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>,
+    InstrumentData: InstrumentDataState,
+{
+    let mut stream = pin!(market_data.stream().await?);
+
+    while let Some(event) = stream.next().await {
+        if let MarketStreamEvent::Item(event) = event {
+            state.update_from_market(&event);
+        }
+    }
+
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backtest::market_data::MarketDataInMemory,
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        test_utils::time_plus_secs,
+    };
+    use barter_data::{
+        event::DataKind, streams::reconnect::Event, subscription::trade::PublicTrade,
+    };
+    use barter_instrument::{
+        Side, Underlying, exchange::ExchangeId, index::IndexedInstruments, instrument::Instrument,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+    use std::sync::Arc;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn trade(time: DateTime<Utc>, price: f64) -> MarketStreamEvent<InstrumentIndex, DataKind> {
+        Event::Item(MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: price.to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_warm_up() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let state = TestState::builder(&instruments, DefaultGlobalData, Default::default)
+            .time_engine_start(time_start)
+            .build();
+
+        let market_data = MarketDataInMemory::new(Arc::new(vec![
+            trade(time_start, 100.0),
+            Event::Reconnecting(ExchangeId::BinanceSpot),
+            trade(time_plus_secs(time_start, 1), 101.0),
+        ]));
+
+        let warmed_up = warm_up(state.clone(), &market_data).await.unwrap();
+        let price = |state: &TestState| {
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(0))
+                .data
+                .price()
+        };
+
+        // Market data state reflects the final warm-up event
+        assert_eq!(price(&warmed_up), Some(dec!(101)));
+
+        // Forks of the warmed-up state evolve independently of the shared state
+        let mut fork = warmed_up.clone();
+        fork.update_from_market(&MarketEvent {
+            time_exchange: time_plus_secs(time_start, 2),
+            time_received: time_plus_secs(time_start, 2),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: "fork".to_string(),
+                price: 90.0,
+                amount: 1.0,
+                side: Side::Sell,
+            }),
+        });
+        assert_eq!(price(&fork), Some(dec!(90)));
+        assert_eq!(price(&warmed_up), Some(dec!(101)));
+        assert_eq!(price(&state), None);
+    }
+}
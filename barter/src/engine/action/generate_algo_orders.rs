@@ -4,7 +4,10 @@ use crate::{
         action::send_requests::{SendCancelsAndOpensOutput, SendRequests, SendRequestsOutput},
         error::UnrecoverableEngineError,
         execution_tx::ExecutionTxMap,
-        state::order::in_flight_recorder::InFlightRequestRecorder,
+        outage::ExchangeDegradation,
+        state::{
+            instrument::InstrumentWindDown, order::in_flight_recorder::InFlightRequestRecorder,
+        },
     },
     risk::{RiskApproved, RiskManager, RiskRefused},
     strategy::algo::AlgoStrategy,
@@ -36,7 +39,9 @@ impl<Clock, State, ExecutionTxs, Strategy, Risk, ExchangeKey, InstrumentKey>
     GenerateAlgoOrders<ExchangeKey, InstrumentKey>
     for Engine<Clock, State, ExecutionTxs, Strategy, Risk>
 where
-    State: InFlightRequestRecorder<ExchangeKey, InstrumentKey>,
+    State: InFlightRequestRecorder<ExchangeKey, InstrumentKey>
+        + InstrumentWindDown<InstrumentKey>
+        + ExchangeDegradation<ExchangeKey, InstrumentKey>,
     ExecutionTxs: ExecutionTxMap<ExchangeKey, InstrumentKey>,
     Strategy: AlgoStrategy<ExchangeKey, InstrumentKey, State = State>,
     Risk: RiskManager<ExchangeKey, InstrumentKey, State = State>,
@@ -47,6 +52,19 @@ where
         // Generate orders
         let (cancels, opens) = self.strategy.generate_algo_orders(&self.state);
 
+        // Refuse open requests for instruments winding down after removal
+        let (opens, opens_winding_down): (Vec<_>, Vec<_>) = opens
+            .into_iter()
+            .partition(|open| !self.state.is_winding_down(&open.key.instrument));
+
+        // Apply the DegradedMode of any degraded exchanges (eg/ exit-only, halt, reroute)
+        let router = self
+            .extensions
+            .outage
+            .as_ref()
+            .and_then(|outage| outage.router.as_ref());
+        let (opens, opens_degraded) = self.state.apply_degraded_modes(opens, router);
+
         // RiskApprove & RiskRefuse order requests
         let (cancels, opens, refused_cancels, refused_opens) =
             self.risk.check(&self.state, cancels, opens);
@@ -57,7 +75,12 @@ where
 
         // Collect remaining Iterators (so we can access &mut self)
         let cancels_refused = refused_cancels.into_iter().collect();
-        let opens_refused = refused_opens.into_iter().collect();
+        let opens_refused = opens_winding_down
+            .into_iter()
+            .map(|open| RiskRefused::new(open, "instrument is winding down"))
+            .chain(opens_degraded)
+            .chain(refused_opens)
+            .collect();
 
         // Record in flight order requests
         self.state.record_in_flight_cancels(cancels.sent.iter());
@@ -1,13 +1,20 @@
-use crate::engine::{
-    action::{
-        generate_algo_orders::GenerateAlgoOrdersOutput,
-        send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
+use crate::{
+    engine::{
+        action::{
+            generate_algo_orders::GenerateAlgoOrdersOutput,
+            send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
+        },
+        error::UnrecoverableEngineError,
+        state::instrument::InstrumentUpdateError,
     },
-    error::UnrecoverableEngineError,
+    strategy::algo::StrategyUpdateError,
+};
+use barter_execution::{
+    credentials::CredentialsError,
+    order::request::{OrderRequestOpen, RequestCancel, RequestOpen},
 };
-use barter_execution::order::request::{RequestCancel, RequestOpen};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
-use barter_integration::collection::one_or_many::OneOrMany;
+use barter_integration::collection::{none_one_or_many::NoneOneOrMany, one_or_many::OneOrMany};
 use derive_more::From;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -32,6 +39,12 @@ pub enum ActionOutput<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentInd
     CancelOrders(SendRequestsOutput<RequestCancel, ExchangeKey, InstrumentKey>),
     OpenOrders(SendRequestsOutput<RequestOpen, ExchangeKey, InstrumentKey>),
     ClosePositions(SendCancelsAndOpensOutput<ExchangeKey, InstrumentKey>),
+    UpdateStrategy(Result<(), StrategyUpdateError>),
+    UpdateCredentials(Result<(), CredentialsError>),
+    AddInstrument(Result<InstrumentKey, InstrumentUpdateError>),
+    RemoveInstrument(
+        Result<SendCancelsAndOpensOutput<ExchangeKey, InstrumentKey>, InstrumentUpdateError>,
+    ),
 }
 
 impl<ExchangeKey, InstrumentKey> ActionOutput<ExchangeKey, InstrumentKey> {
@@ -42,7 +55,31 @@ impl<ExchangeKey, InstrumentKey> ActionOutput<ExchangeKey, InstrumentKey> {
             ActionOutput::CancelOrders(cancels) => cancels.unrecoverable_errors(),
             ActionOutput::OpenOrders(opens) => opens.unrecoverable_errors(),
             ActionOutput::ClosePositions(requests) => requests.unrecoverable_errors(),
+            ActionOutput::RemoveInstrument(Ok(requests)) => requests.unrecoverable_errors(),
+            ActionOutput::UpdateStrategy(_)
+            | ActionOutput::UpdateCredentials(_)
+            | ActionOutput::AddInstrument(_)
+            | ActionOutput::RemoveInstrument(Err(_)) => NoneOneOrMany::None,
         }
         .into_option()
     }
+
+    /// Returns the open order requests that were sent for execution during an `Engine` action.
+    pub fn opens_sent(
+        &self,
+    ) -> impl Iterator<Item = &OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        match self {
+            ActionOutput::GenerateAlgoOrders(algo) => Some(&algo.cancels_and_opens.opens.sent),
+            ActionOutput::OpenOrders(opens) => Some(&opens.sent),
+            ActionOutput::ClosePositions(requests) => Some(&requests.opens.sent),
+            ActionOutput::RemoveInstrument(Ok(requests)) => Some(&requests.opens.sent),
+            ActionOutput::CancelOrders(_)
+            | ActionOutput::UpdateStrategy(_)
+            | ActionOutput::UpdateCredentials(_)
+            | ActionOutput::AddInstrument(_)
+            | ActionOutput::RemoveInstrument(Err(_)) => None,
+        }
+        .into_iter()
+        .flatten()
+    }
 }
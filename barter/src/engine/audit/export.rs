@@ -0,0 +1,532 @@
+use crate::{
+    EngineEvent,
+    engine::{
+        EngineOutput,
+        action::ActionOutput,
+        audit::{
+            AuditTick, EngineAudit, ProcessAudit, context::EngineContext, shutdown::ShutdownAudit,
+        },
+        state::position::PositionExited,
+    },
+    execution::AccountStreamEvent,
+};
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    order::{
+        Order,
+        request::{OrderRequestOpen, OrderResponseCancel},
+        state::{ActiveOrderState, InactiveOrderState, OrderState},
+    },
+    trade::Trade,
+};
+use barter_instrument::{
+    asset::QuoteAsset,
+    exchange::ExchangeIndex,
+    index::IndexedInstruments,
+    instrument::{InstrumentIndex, name::InstrumentNameInternal},
+};
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+use tracing::info;
+
+const HEADER_ORDERS: &str = "time_engine,event,exchange,instrument,strategy,cid,order_id,side,\
+price,quantity,kind,time_in_force,filled_quantity,state";
+
+const HEADER_FILLS: &str = "time_engine,time_exchange,exchange,instrument,strategy,trade_id,\
+order_id,cid,side,price,quantity,value_quote,fees,liquidity";
+
+const HEADER_POSITIONS: &str = "exchange,instrument,side,time_enter,time_exit,\
+price_entry_average,quantity_abs_max,pnl_realised,fees_enter,fees_exit,excursion_adverse_max,\
+excursion_favourable_max,trades,\
+signal_tag,signal_stop,signal_target,signal_reward_risk";
+
+/// Exports every order, fill and exited position from the `Engine` AuditStream to CSV, with
+/// full metadata, suitable for importing into external analytics tooling (eg/ notebooks).
+///
+/// Orders are exported when an open order request is sent, and on every subsequent order
+/// snapshot and cancel response. Indexes are resolved to the exchange and instrument names of
+/// the provided [`IndexedInstruments`].
+#[derive(Debug)]
+pub struct HistoryExporter<Writer> {
+    pub orders: Writer,
+    pub fills: Writer,
+    pub positions: Writer,
+    exchanges: Vec<String>,
+    instruments: Vec<(ExchangeIndex, InstrumentNameInternal)>,
+}
+
+impl HistoryExporter<BufWriter<File>> {
+    /// Create `orders.csv`, `fills.csv` and `positions.csv` files in the provided directory,
+    /// and construct a [`HistoryExporter`] that writes to them.
+    pub fn create<P>(directory: P, instruments: &IndexedInstruments) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        let create = |name: &str| File::create(directory.join(name)).map(BufWriter::new);
+        Self::new(
+            instruments,
+            create("orders.csv")?,
+            create("fills.csv")?,
+            create("positions.csv")?,
+        )
+    }
+}
+
+impl<Writer> HistoryExporter<Writer>
+where
+    Writer: Write,
+{
+    /// Construct a new [`HistoryExporter`], writing the CSV header row to each `Writer`.
+    pub fn new(
+        instruments: &IndexedInstruments,
+        mut orders: Writer,
+        mut fills: Writer,
+        mut positions: Writer,
+    ) -> std::io::Result<Self> {
+        writeln!(orders, "{HEADER_ORDERS}")?;
+        writeln!(fills, "{HEADER_FILLS}")?;
+        writeln!(positions, "{HEADER_POSITIONS}")?;
+
+        Ok(Self {
+            orders,
+            fills,
+            positions,
+            exchanges: instruments
+                .exchanges()
+                .iter()
+                .map(|exchange| exchange.value.as_str().to_string())
+                .collect(),
+            instruments: instruments
+                .instruments()
+                .iter()
+                .map(|instrument| {
+                    (
+                        instrument.value.exchange.key,
+                        instrument.value.name_internal.clone(),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    /// Run the `HistoryExporter`, exporting every `Engine` [`AuditTick`] until the `Engine`
+    /// shuts down or the feed ends.
+    ///
+    /// Returns the number of CSV rows written.
+    pub fn run<AuditIter, State, MarketKind, OnDisable, OnDisconnect>(
+        &mut self,
+        feed: AuditIter,
+    ) -> std::io::Result<u64>
+    where
+        AuditIter: IntoIterator<
+            Item = AuditTick<
+                EngineAudit<State, EngineEvent<MarketKind>, EngineOutput<OnDisable, OnDisconnect>>,
+                EngineContext,
+            >,
+        >,
+    {
+        info!("HistoryExporter running");
+
+        let mut rows = 0;
+        for audit in feed {
+            rows += self.update_from_audit(&audit)?;
+
+            if matches!(audit.event, EngineAudit::Shutdown(_)) {
+                break;
+            }
+        }
+
+        self.flush()?;
+
+        info!(rows, "HistoryExporter stopped");
+        Ok(rows)
+    }
+
+    /// Export the orders, fills and exited positions contained in an `Engine` [`AuditTick`].
+    ///
+    /// Returns the number of CSV rows written.
+    pub fn update_from_audit<State, MarketKind, OnDisable, OnDisconnect>(
+        &mut self,
+        audit: &AuditTick<
+            EngineAudit<State, EngineEvent<MarketKind>, EngineOutput<OnDisable, OnDisconnect>>,
+            EngineContext,
+        >,
+    ) -> std::io::Result<u64> {
+        let time = audit.context.time;
+        match &audit.event {
+            EngineAudit::Snapshot(_) => Ok(0),
+            EngineAudit::Process(process) => self.update_from_process(process, time),
+            EngineAudit::Shutdown(ShutdownAudit::ErrorWithProcess(process, _)) => {
+                self.update_from_process(process, time)
+            }
+            EngineAudit::Shutdown(
+                ShutdownAudit::Error(event, _) | ShutdownAudit::Commanded(event),
+            ) => self.update_from_event(event, time),
+            EngineAudit::Shutdown(ShutdownAudit::FeedEnded) => Ok(0),
+        }
+    }
+
+    fn update_from_process<MarketKind, OnDisable, OnDisconnect>(
+        &mut self,
+        process: &ProcessAudit<EngineEvent<MarketKind>, EngineOutput<OnDisable, OnDisconnect>>,
+        time: DateTime<Utc>,
+    ) -> std::io::Result<u64> {
+        let mut rows = self.update_from_event(process.event(), time)?;
+
+        if let ProcessAudit::ProcessWithOutput(_, outputs) = process {
+            for output in outputs.iter() {
+                rows += match output {
+                    EngineOutput::Commanded(action) => {
+                        self.write_opens_sent(ActionOutput::opens_sent(action), time)?
+                    }
+                    EngineOutput::AlgoOrders(algo) => {
+                        self.write_opens_sent(&algo.cancels_and_opens.opens.sent, time)?
+                    }
+                    EngineOutput::PositionExit(position) => {
+                        self.write_position(position)?;
+                        1
+                    }
+                    _ => 0,
+                };
+            }
+        }
+
+        Ok(rows)
+    }
+
+    fn update_from_event<MarketKind>(
+        &mut self,
+        event: &EngineEvent<MarketKind>,
+        time: DateTime<Utc>,
+    ) -> std::io::Result<u64> {
+        let EngineEvent::Account(AccountStreamEvent::Item(AccountEvent { kind, .. })) = event
+        else {
+            return Ok(0);
+        };
+
+        match kind {
+            AccountEventKind::OrderSnapshot(snapshot) => {
+                self.write_order(time, "snapshot", &snapshot.0)?;
+            }
+            AccountEventKind::OrderCancelled(response) => {
+                self.write_cancel_response(time, response)?;
+            }
+            AccountEventKind::Trade(trade) => {
+                self.write_fill(time, trade)?;
+            }
+            _ => return Ok(0),
+        }
+
+        Ok(1)
+    }
+
+    fn write_opens_sent<'a, Iter>(
+        &mut self,
+        requests: Iter,
+        time: DateTime<Utc>,
+    ) -> std::io::Result<u64>
+    where
+        Iter: IntoIterator<Item = &'a OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    {
+        let mut rows = 0;
+        for request in requests {
+            writeln!(
+                self.orders,
+                "{},request_open,{},{},{},{},,{},{},{},{},{},,open_in_flight",
+                time.to_rfc3339(),
+                self.exchange_name(request.key.exchange),
+                self.instrument_name(request.key.instrument),
+                request.key.strategy,
+                request.key.cid,
+                request.state.side,
+                request.state.price,
+                request.state.quantity,
+                request.state.kind,
+                request.state.time_in_force,
+            )?;
+            rows += 1;
+        }
+        Ok(rows)
+    }
+
+    fn write_order(
+        &mut self,
+        time: DateTime<Utc>,
+        event: &str,
+        order: &Order<ExchangeIndex, InstrumentIndex, OrderState>,
+    ) -> std::io::Result<()> {
+        let (order_id, filled_quantity, state) = match &order.state {
+            OrderState::Active(ActiveOrderState::OpenInFlight(_)) => (None, None, "open_in_flight"),
+            OrderState::Active(ActiveOrderState::Open(open)) => {
+                (Some(&open.id), Some(open.filled_quantity), "open")
+            }
+            OrderState::Active(ActiveOrderState::CancelInFlight(cancel)) => (
+                cancel.order.as_ref().map(|open| &open.id),
+                cancel.order.as_ref().map(|open| open.filled_quantity),
+                "cancel_in_flight",
+            ),
+            OrderState::Inactive(InactiveOrderState::Cancelled(cancelled)) => {
+                (Some(&cancelled.id), None, "cancelled")
+            }
+            OrderState::Inactive(InactiveOrderState::FullyFilled) => {
+                (None, Some(order.quantity), "fully_filled")
+            }
+            OrderState::Inactive(InactiveOrderState::OpenFailed(_)) => (None, None, "open_failed"),
+            OrderState::Inactive(InactiveOrderState::Expired) => (None, None, "expired"),
+        };
+
+        writeln!(
+            self.orders,
+            "{},{event},{},{},{},{},{},{},{},{},{},{},{},{state}",
+            time.to_rfc3339(),
+            self.exchange_name(order.key.exchange),
+            self.instrument_name(order.key.instrument),
+            order.key.strategy,
+            order.key.cid,
+            order_id.map(ToString::to_string).unwrap_or_default(),
+            order.side,
+            order.price,
+            order.quantity,
+            order.kind,
+            order.time_in_force,
+            filled_quantity
+                .map(|quantity| quantity.to_string())
+                .unwrap_or_default(),
+        )
+    }
+
+    fn write_cancel_response(
+        &mut self,
+        time: DateTime<Utc>,
+        response: &OrderResponseCancel,
+    ) -> std::io::Result<()> {
+        let (order_id, state) = match &response.state {
+            Ok(cancelled) => (cancelled.id.to_string(), "cancelled"),
+            Err(_) => (String::new(), "cancel_failed"),
+        };
+
+        writeln!(
+            self.orders,
+            "{},cancel_response,{},{},{},{},{order_id},,,,,,,{state}",
+            time.to_rfc3339(),
+            self.exchange_name(response.key.exchange),
+            self.instrument_name(response.key.instrument),
+            response.key.strategy,
+            response.key.cid,
+        )
+    }
+
+    fn write_fill(
+        &mut self,
+        time: DateTime<Utc>,
+        trade: &Trade<QuoteAsset, InstrumentIndex>,
+    ) -> std::io::Result<()> {
+        let exchange = self.instrument_exchange_name(trade.instrument);
+        writeln!(
+            self.fills,
+            "{},{},{exchange},{},{},{},{},{},{},{},{},{},{},{}",
+            time.to_rfc3339(),
+            trade.time_exchange.to_rfc3339(),
+            self.instrument_name(trade.instrument),
+            trade.strategy,
+            trade.id.0,
+            trade.order_id,
+            trade
+                .cid
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            trade.side,
+            trade.price,
+            trade.quantity,
+            trade.value_quote(),
+            trade.fees.fees,
+            trade
+                .liquidity
+                .map(|liquidity| format!("{liquidity:?}").to_lowercase())
+                .unwrap_or_default(),
+        )
+    }
+
+    fn write_position(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> std::io::Result<()> {
+        let signal = position.signal.as_ref();
+        writeln!(
+            self.positions,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.instrument_exchange_name(position.instrument),
+            self.instrument_name(position.instrument),
+            position.side,
+            position.time_enter.to_rfc3339(),
+            position.time_exit.to_rfc3339(),
+            position.price_entry_average,
+            position.quantity_abs_max,
+            position.pnl_realised,
+            position.fees_enter.fees,
+            position.fees_exit.fees,
+            position.excursion_adverse_max,
+            position.excursion_favourable_max,
+            position.trades.iter().map(|trade| &trade.0).join(";"),
+            signal
+                .and_then(|signal| signal.tag.as_ref())
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            signal
+                .and_then(|signal| signal.stop)
+                .map(|stop| stop.to_string())
+                .unwrap_or_default(),
+            signal
+                .and_then(|signal| signal.target)
+                .map(|target| target.to_string())
+                .unwrap_or_default(),
+            signal
+                .and_then(|signal| signal.reward_risk(position.price_entry_average))
+                .map(|reward_risk| reward_risk.to_string())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Flush all `Writer`s.
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.orders.flush()?;
+        self.fills.flush()?;
+        self.positions.flush()
+    }
+
+    fn exchange_name(&self, exchange: ExchangeIndex) -> String {
+        self.exchanges
+            .get(exchange.index())
+            .cloned()
+            .unwrap_or_else(|| exchange.to_string())
+    }
+
+    fn instrument_exchange_name(&self, instrument: InstrumentIndex) -> String {
+        self.instruments
+            .get(instrument.index())
+            .map(|(exchange, _)| self.exchange_name(*exchange))
+            .unwrap_or_default()
+    }
+
+    fn instrument_name(&self, instrument: InstrumentIndex) -> String {
+        self.instruments
+            .get(instrument.index())
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| instrument.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Sequence, engine::state::position::SignalMeta, test_utils::trade};
+    use barter_data::event::DataKind;
+    use barter_execution::trade::AssetFees;
+    use barter_instrument::{Side, Underlying, exchange::ExchangeId, instrument::Instrument};
+    use barter_integration::collection::one_or_many::OneOrMany;
+    use rust_decimal_macros::dec;
+
+    type TestAudit =
+        AuditTick<EngineAudit<(), EngineEvent<DataKind>, EngineOutput<(), ()>>, EngineContext>;
+
+    #[test]
+    fn test_history_exporter_run() {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let time = DateTime::<Utc>::from_timestamp(0, 0).unwrap();
+        let context = EngineContext::new(Sequence(0), time);
+
+        let fill = trade(time, Side::Buy, 100.0, 2.0, 0.1);
+        let fill = Trade {
+            id: fill.id,
+            order_id: fill.order_id,
+            cid: fill.cid,
+            instrument: InstrumentIndex(0),
+            strategy: fill.strategy,
+            time_exchange: fill.time_exchange,
+            side: fill.side,
+            price: fill.price,
+            quantity: fill.quantity,
+            liquidity: fill.liquidity,
+            fees: fill.fees,
+        };
+
+        let position = PositionExited {
+            instrument: InstrumentIndex(0),
+            side: Side::Buy,
+            price_entry_average: dec!(100),
+            quantity_abs_max: dec!(2),
+            pnl_realised: dec!(19.8),
+            excursion_adverse_max: dec!(1),
+            excursion_favourable_max: dec!(12),
+            fees_enter: AssetFees::new(QuoteAsset, dec!(0.1)),
+            fees_exit: AssetFees::new(QuoteAsset, dec!(0.1)),
+            time_enter: time,
+            time_exit: time,
+            trades: vec![fill.id.clone(), fill.id.clone()],
+            signal: Some(SignalMeta::new(
+                Some(dec!(95)),
+                Some(dec!(110)),
+                Some("breakout".into()),
+            )),
+        };
+
+        let feed: Vec<TestAudit> = vec![
+            AuditTick::new(EngineAudit::Snapshot(()), context),
+            AuditTick::new(
+                EngineAudit::Process(ProcessAudit::ProcessWithOutput(
+                    EngineEvent::Account(AccountStreamEvent::Item(AccountEvent::new(
+                        ExchangeIndex(0),
+                        AccountEventKind::Trade(fill),
+                    ))),
+                    OneOrMany::One(EngineOutput::PositionExit(position)),
+                )),
+                context,
+            ),
+            AuditTick::new(EngineAudit::Shutdown(ShutdownAudit::FeedEnded), context),
+            AuditTick::new(EngineAudit::Snapshot(()), context),
+        ];
+
+        let mut exporter =
+            HistoryExporter::new(&instruments, Vec::new(), Vec::new(), Vec::new()).unwrap();
+
+        assert_eq!(exporter.run(feed).unwrap(), 2);
+
+        let orders = String::from_utf8(exporter.orders).unwrap();
+        assert_eq!(orders, format!("{HEADER_ORDERS}\n"));
+
+        let fills = String::from_utf8(exporter.fills).unwrap();
+        let expected = format!(
+            "{HEADER_FILLS}\n\
+            1970-01-01T00:00:00+00:00,1970-01-01T00:00:00+00:00,binance_spot,\
+            binance_spot_btc_usdt,strategy,trade_id,order_id,,buy,100,2,200,0.1,\n"
+        );
+        assert_eq!(fills, expected);
+
+        let positions = String::from_utf8(exporter.positions).unwrap();
+        let expected = format!(
+            "{HEADER_POSITIONS}\n\
+            binance_spot,binance_spot_btc_usdt,buy,1970-01-01T00:00:00+00:00,\
+            1970-01-01T00:00:00+00:00,100,2,19.8,0.1,0.1,1,12,trade_id;trade_id,\
+            breakout,95,110,2\n"
+        );
+        assert_eq!(positions, expected);
+    }
+}
@@ -17,9 +17,17 @@ use serde::{Deserialize, Serialize};
 /// Defines data structures that represent the context an `Engine` [`AuditTick`] was generated.
 pub mod context;
 
+/// Defines a `HistoryExporter` that exports every order, fill and exited position from the
+/// `Engine` AuditStream to CSV files for external analytics.
+pub mod export;
+
 /// Defines an `Engine` shutdown audit.
 pub mod shutdown;
 
+/// Defines an `AuditSink` that publishes the `Engine` AuditStream to a downstream message broker
+/// (eg/ Kafka or NATS).
+pub mod sink;
+
 /// Defines a `StateReplicaManager` that can be used to maintain an `EngineState` replica.
 ///
 /// Useful for supporting non-hot path trading system components such as UIs, web apps, etc.
@@ -253,11 +261,27 @@ impl<Event, OnTradingDisabled, OnDisconnect>
                 event.into(),
                 OneOrMany::One(EngineOutput::MarketDisconnect(disconnect)),
             ),
+            UpdateFromMarketOutput::PositionExit(position) => Self::ProcessWithOutput(
+                event.into(),
+                OneOrMany::One(EngineOutput::PositionExit(position)),
+            ),
+            UpdateFromMarketOutput::PositionUpdate(update) => Self::ProcessWithOutput(
+                event.into(),
+                OneOrMany::One(EngineOutput::PositionUpdate(update)),
+            ),
         }
     }
 }
 
 impl<Event, Output> ProcessAudit<Event, Output> {
+    /// Returns a reference to the processed `Event`.
+    pub fn event(&self) -> &Event {
+        match self {
+            ProcessAudit::Process(event) => event,
+            ProcessAudit::ProcessWithOutput(event, _) => event,
+        }
+    }
+
     pub fn add_additional<O>(self, output: O) -> Self
     where
         O: Into<Output>,
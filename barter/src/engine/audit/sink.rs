@@ -0,0 +1,165 @@
+use crate::engine::{
+    audit::{AuditTick, EngineAudit},
+    schema::Versioned,
+};
+use barter_integration::channel::{Tx, UnboundedTx};
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// All errors generated by an [`AuditSink`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum SinkError {
+    #[error("AuditSink encode: {0}")]
+    Encode(String),
+
+    #[error("AuditSink publish: {0}")]
+    Publish(String),
+}
+
+/// Encoded event payload and the topic it should be published to.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct SinkMessage {
+    pub topic: String,
+    pub payload: Vec<u8>,
+}
+
+/// Defines how an event is serialised before it is published by an [`AuditSink`].
+///
+/// For example, [`JsonEncoder`] or a protobuf encoder implemented for the generated message
+/// types.
+pub trait EventEncoder<Event> {
+    fn encode(&self, event: &Event) -> Result<Vec<u8>, SinkError>;
+}
+
+/// [`EventEncoder`] that serialises events as [`Versioned`] JSON.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct JsonEncoder;
+
+impl<Event> EventEncoder<Event> for JsonEncoder
+where
+    Event: Serialize,
+{
+    fn encode(&self, event: &Event) -> Result<Vec<u8>, SinkError> {
+        serde_json::to_vec(&Versioned::new(event))
+            .map_err(|error| SinkError::Encode(error.to_string()))
+    }
+}
+
+/// Defines how an encoded [`SinkMessage`] is published to a downstream message broker
+/// (eg/ a Kafka topic or NATS subject).
+pub trait EventPublisher {
+    fn publish(&mut self, message: SinkMessage) -> Result<(), SinkError>;
+}
+
+/// Forwards each [`SinkMessage`] to a channel, enabling an async Kafka or NATS producer task to
+/// publish messages without blocking the [`AuditSink`].
+impl EventPublisher for UnboundedTx<SinkMessage> {
+    fn publish(&mut self, message: SinkMessage) -> Result<(), SinkError> {
+        self.send(message)
+            .map_err(|error| SinkError::Publish(error.to_string()))
+    }
+}
+
+/// Publishes the `Engine` AuditStream to a downstream message broker (eg/ Kafka or NATS),
+/// enabling risk systems and data warehouses to consume the `Engine` output in real time.
+///
+/// Runs off the hot path (eg/ consuming the [`System`](crate::system::System) audit channel), so
+/// events that cannot be encoded or published are logged and skipped.
+#[derive(Debug, Clone, Constructor)]
+pub struct AuditSink<Encoder, Publisher> {
+    pub topic: String,
+    pub encoder: Encoder,
+    pub publisher: Publisher,
+}
+
+impl<Encoder, Publisher> AuditSink<Encoder, Publisher>
+where
+    Publisher: EventPublisher,
+{
+    /// Run the `AuditSink`, publishing every `Engine` [`AuditTick`] until the `Engine` shuts down
+    /// or the feed ends.
+    ///
+    /// Returns the number of [`AuditTick`]s published.
+    pub fn run<AuditIter, State, Event, Output, Context>(&mut self, feed: AuditIter) -> u64
+    where
+        AuditIter: IntoIterator<Item = AuditTick<EngineAudit<State, Event, Output>, Context>>,
+        Encoder: EventEncoder<AuditTick<EngineAudit<State, Event, Output>, Context>>,
+        Context: Debug,
+    {
+        info!(topic = %self.topic, "AuditSink running");
+
+        let mut published = 0;
+        for audit in feed {
+            let shutdown = matches!(audit.event, EngineAudit::Shutdown(_));
+
+            match self.publish(&audit) {
+                Ok(()) => published += 1,
+                Err(error) => {
+                    warn!(?error, context = ?audit.context, "AuditSink skipped AuditTick")
+                }
+            }
+
+            if shutdown {
+                break;
+            }
+        }
+
+        info!(topic = %self.topic, published, "AuditSink stopped");
+        published
+    }
+
+    /// Encode and publish a single event to the `AuditSink` topic.
+    pub fn publish<Event>(&mut self, event: &Event) -> Result<(), SinkError>
+    where
+        Encoder: EventEncoder<Event>,
+    {
+        let payload = self.encoder.encode(event)?;
+        self.publisher
+            .publish(SinkMessage::new(self.topic.clone(), payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{audit::shutdown::ShutdownAudit, schema::SchemaMigrations};
+
+    impl EventPublisher for Vec<SinkMessage> {
+        fn publish(&mut self, message: SinkMessage) -> Result<(), SinkError> {
+            self.push(message);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_audit_sink_run() {
+        type Audit = AuditTick<EngineAudit<u64, u64, u64>, u64>;
+
+        let feed: Vec<Audit> = vec![
+            AuditTick::new(EngineAudit::Snapshot(1), 0),
+            AuditTick::new(EngineAudit::Shutdown(ShutdownAudit::FeedEnded), 1),
+            AuditTick::new(EngineAudit::Snapshot(2), 2),
+        ];
+
+        let mut sink = AuditSink::new("engine.audit".to_string(), JsonEncoder, Vec::new());
+
+        // Feed is published until the Engine shuts down
+        assert_eq!(sink.run(feed.clone()), 2);
+        assert_eq!(sink.publisher.len(), 2);
+
+        for (message, expected) in sink.publisher.iter().zip(&feed) {
+            assert_eq!(message.topic, "engine.audit");
+
+            let json = std::str::from_utf8(&message.payload).unwrap();
+            let actual = SchemaMigrations::default()
+                .deserialise::<Audit>(json)
+                .unwrap();
+            assert_eq!(&actual, expected);
+        }
+    }
+}
@@ -75,6 +75,7 @@ where
                 continue;
             } else {
                 self.validate_and_update_context(audit.context)?;
+                self.replica_engine_state_mut().time_engine_now = audit.context.time;
             }
 
             let shutdown_audit = match audit.event {
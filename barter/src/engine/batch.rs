@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines how frequently the [`Engine`](super::Engine) generates algorithmic orders while
+/// processing a burst of market events.
+///
+/// By default, the `Engine` generates algo orders after every processed event. When
+/// back-testing over tick data this means the `AlgoStrategy` and `RiskManager` are invoked
+/// millions of times, often against near-identical state. Batching market events amortises
+/// that cost by only generating algo orders once per batch.
+///
+/// Note that any non-market event (eg/ `AccountEvent`, `Command`, `TradingState` update) flushes
+/// the current batch, so the `Engine` always reacts immediately to account changes.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum MarketBatchPolicy {
+    /// Generate algo orders after every processed event (default).
+    #[default]
+    Disabled,
+
+    /// Generate algo orders once every `n` market events have been processed.
+    ///
+    /// `MaxEvents(0)` and `MaxEvents(1)` are equivalent to `Disabled`.
+    MaxEvents(usize),
+}
+
+/// Tracks the [`MarketBatchPolicy`] progress of the current batch of market events.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct MarketBatcher {
+    pub policy: MarketBatchPolicy,
+    pub pending: usize,
+}
+
+impl MarketBatcher {
+    /// Construct a new `MarketBatcher` with the provided [`MarketBatchPolicy`].
+    pub fn new(policy: MarketBatchPolicy) -> Self {
+        Self { policy, pending: 0 }
+    }
+
+    /// Record a processed market event, returning true if the batch is complete and algo orders
+    /// should be generated.
+    pub fn record_market_event(&mut self) -> bool {
+        match self.policy {
+            MarketBatchPolicy::Disabled => true,
+            MarketBatchPolicy::MaxEvents(max) => {
+                self.pending += 1;
+                if self.pending >= max {
+                    self.pending = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Flush the current batch, returning true to indicate algo orders should be generated.
+    pub fn flush(&mut self) -> bool {
+        self.pending = 0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_market_batcher_record_market_event() {
+        struct TestCase {
+            policy: MarketBatchPolicy,
+            expected: Vec<bool>,
+        }
+
+        let cases = vec![
+            // TC0: Disabled generates after every event
+            TestCase {
+                policy: MarketBatchPolicy::Disabled,
+                expected: vec![true, true, true],
+            },
+            // TC1: MaxEvents(0) generates after every event
+            TestCase {
+                policy: MarketBatchPolicy::MaxEvents(0),
+                expected: vec![true, true, true],
+            },
+            // TC2: MaxEvents(3) generates after every third event
+            TestCase {
+                policy: MarketBatchPolicy::MaxEvents(3),
+                expected: vec![false, false, true, false, false, true],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut batcher = MarketBatcher::new(test.policy);
+            let actual = (0..test.expected.len())
+                .map(|_| batcher.record_market_event())
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_market_batcher_flush_resets_pending() {
+        let mut batcher = MarketBatcher::new(MarketBatchPolicy::MaxEvents(2));
+
+        assert!(!batcher.record_market_event());
+        assert!(batcher.flush());
+        assert!(!batcher.record_market_event());
+        assert!(batcher.record_market_event());
+    }
+}
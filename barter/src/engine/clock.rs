@@ -11,6 +11,7 @@ use tracing::{debug, error, warn};
 /// Generally an `Engine` will use a:
 /// * [`LiveClock`] for live-trading.
 /// * [`HistoricalClock`] for back-testing.
+/// * [`ReplayClock`] for deterministically replaying a persisted event log.
 pub trait EngineClock {
     fn time(&self) -> DateTime<Utc>;
 }
@@ -144,6 +145,45 @@ where
     }
 }
 
+/// Deterministic historical `Clock` that uses only processed event timestamps.
+///
+/// Unlike the [`HistoricalClock`], the time does not advance with the live time elapsed between
+/// events, so replaying the same event sequence always produces the same `Engine` times.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct ReplayClock {
+    time_exchange_last: DateTime<Utc>,
+}
+
+impl ReplayClock {
+    /// Construct a new `ReplayClock` using the provided `last_exchange_time` as a seed.
+    pub fn new(last_exchange_time: DateTime<Utc>) -> Self {
+        Self {
+            time_exchange_last: last_exchange_time,
+        }
+    }
+}
+
+impl EngineClock for ReplayClock {
+    fn time(&self) -> DateTime<Utc> {
+        self.time_exchange_last
+    }
+}
+
+impl<Event> Processor<&Event> for ReplayClock
+where
+    Event: TimeExchange,
+{
+    type Audit = ();
+
+    fn process(&mut self, event: &Event) -> Self::Audit {
+        if let Some(time_event_exchange) = event.time_exchange() {
+            self.time_exchange_last = self.time_exchange_last.max(time_event_exchange);
+        }
+    }
+}
+
 impl<MarketEventKind: Debug> TimeExchange for EngineEvent<MarketEventKind> {
     fn time_exchange(&self) -> Option<DateTime<Utc>> {
         match self {
@@ -151,6 +191,9 @@ impl<MarketEventKind: Debug> TimeExchange for EngineEvent<MarketEventKind> {
             Self::Account(AccountStreamEvent::Item(event)) => match &event.kind {
                 AccountEventKind::Snapshot(snapshot) => snapshot.time_most_recent(),
                 AccountEventKind::BalanceSnapshot(balance) => Some(balance.0.time_exchange),
+                AccountEventKind::BalanceCheck(balances) => {
+                    balances.iter().map(|balance| balance.time_exchange).max()
+                }
                 AccountEventKind::OrderSnapshot(order) => order.0.state.time_exchange(),
                 AccountEventKind::OrderCancelled(response) => response
                     .state
@@ -158,6 +201,7 @@ impl<MarketEventKind: Debug> TimeExchange for EngineEvent<MarketEventKind> {
                     .map(|cancelled| cancelled.time_exchange)
                     .ok(),
                 AccountEventKind::Trade(trade) => Some(trade.time_exchange),
+                AccountEventKind::FundingPayment(payment) => Some(payment.time_exchange),
             },
             _ => None,
         }
@@ -181,6 +225,28 @@ mod tests {
         }))
     }
 
+    #[test]
+    fn test_replay_clock_process() {
+        let time_base = DateTime::<Utc>::MIN_UTC;
+        let plus_ms = |ms: i64| time_base + TimeDelta::milliseconds(ms);
+
+        let mut clock = ReplayClock::new(time_base);
+
+        clock.process(&market_event(plus_ms(1000)));
+        assert_eq!(clock.time(), plus_ms(1000));
+
+        // Out of order event does not move time backwards
+        clock.process(&market_event(plus_ms(500)));
+        assert_eq!(clock.time(), plus_ms(1000));
+
+        // Event without an exchange timestamp does not affect time
+        clock.process(&EngineEvent::<()>::shutdown());
+        assert_eq!(clock.time(), plus_ms(1000));
+
+        clock.process(&market_event(plus_ms(2000)));
+        assert_eq!(clock.time(), plus_ms(2000));
+    }
+
     #[test]
     fn test_historical_clock_process() {
         #[derive(Debug)]
@@ -1,7 +1,16 @@
 use crate::engine::state::instrument::filter::InstrumentFilter;
-use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
-use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_execution::{
+    credentials::CredentialsUpdate,
+    order::request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{
+    Keyed,
+    asset::AssetIndex,
+    exchange::ExchangeIndex,
+    instrument::{Instrument, InstrumentIndex},
+};
 use barter_integration::collection::one_or_many::OneOrMany;
+use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
 
 /// Trading related commands for the [`Engine`](super::Engine) to action, sent from an
@@ -16,4 +25,22 @@ pub enum Command<
     SendOpenRequests(OneOrMany<OrderRequestOpen<ExchangeKey, InstrumentKey>>),
     ClosePositions(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
     CancelOrders(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
+    UpdateStrategy(StrategyUpdate),
+    UpdateCredentials(CredentialsUpdate),
+    AddInstrument(Box<Keyed<InstrumentKey, Instrument<ExchangeKey, AssetKey>>>),
+    RemoveInstrument(InstrumentKey),
+}
+
+/// Runtime update of the [`Engine`](super::Engine) strategy parameters, applied without
+/// restarting the `Engine`.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct StrategyUpdate {
+    /// Serialised (JSON) strategy parameters, deserialised by the
+    /// [`AlgoStrategy`](crate::strategy::algo::AlgoStrategy).
+    pub params: String,
+
+    /// Carry over warm instrument indicator state (eg/ price history), rather than resetting it.
+    pub warm_start: bool,
 }
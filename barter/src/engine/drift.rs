@@ -0,0 +1,184 @@
+use crate::Timed;
+use barter_execution::balance::{AssetBalance, Balance};
+use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Tolerated divergence of internally tracked asset balances from periodically fetched exchange
+/// balances, before the [`Engine`](super::Engine) emits a [`BalanceDrift`].
+///
+/// An asset balance has drifted if the absolute difference of its total balance exceeds the
+/// larger of the `absolute` tolerance and the `relative` tolerance of the tracked total.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct BalanceDriftTolerance {
+    /// Tolerated absolute difference in asset units (eg/ to ignore rounding dust).
+    pub absolute: Decimal,
+
+    /// Tolerated difference relative to the tracked total balance (eg/ `0.001` for 0.1%).
+    pub relative: Decimal,
+}
+
+impl Default for BalanceDriftTolerance {
+    fn default() -> Self {
+        Self {
+            absolute: Decimal::ZERO,
+            relative: Decimal::new(1, 3),
+        }
+    }
+}
+
+impl BalanceDriftTolerance {
+    /// Returns true if the difference between the tracked and fetched total balance exceeds the
+    /// tolerance.
+    pub fn is_exceeded(&self, tracked: Decimal, fetched: Decimal) -> bool {
+        (fetched - tracked).abs() > self.absolute.max(self.relative * tracked.abs())
+    }
+
+    /// Compare the provided exchange fetched [`AssetBalance`]s against the internally tracked
+    /// balances, returning a [`BalanceDrift`] if any asset has drifted beyond the tolerance.
+    ///
+    /// The provided closure is used to determine the tracked balance of an asset, where a
+    /// missing balance is treated as empty. Tracked balances that are more recent than the
+    /// fetched balance are skipped, since the fetched balance is already stale.
+    pub fn check<FnTracked>(
+        &self,
+        exchange: ExchangeIndex,
+        fetched: &[AssetBalance<AssetIndex>],
+        tracked: FnTracked,
+    ) -> Option<BalanceDrift>
+    where
+        FnTracked: Fn(&AssetIndex) -> Option<Timed<Balance>>,
+    {
+        let assets = fetched
+            .iter()
+            .filter_map(|fetched| {
+                let tracked = tracked(&fetched.asset);
+
+                if tracked.is_some_and(|tracked| tracked.time > fetched.time_exchange) {
+                    return None;
+                }
+
+                let tracked = tracked.map(|tracked| tracked.value).unwrap_or_default();
+
+                self.is_exceeded(tracked.total, fetched.balance.total)
+                    .then_some(AssetBalanceDrift {
+                        asset: fetched.asset,
+                        tracked,
+                        fetched: fetched.balance,
+                        time_exchange: fetched.time_exchange,
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        (!assets.is_empty()).then_some(BalanceDrift { exchange, assets })
+    }
+}
+
+/// Divergence of internally tracked asset balances from the exchange fetched balances (eg/ due to
+/// missed fills, external withdrawals or accounting bugs).
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct BalanceDrift {
+    pub exchange: ExchangeIndex,
+    pub assets: Vec<AssetBalanceDrift>,
+}
+
+/// Internally tracked and exchange fetched [`Balance`] of an asset that has drifted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct AssetBalanceDrift {
+    pub asset: AssetIndex,
+    pub tracked: Balance,
+    pub fetched: Balance,
+
+    /// Exchange time of the fetched `Balance`.
+    pub time_exchange: DateTime<Utc>,
+}
+
+impl AssetBalanceDrift {
+    /// Difference of the fetched total balance from the tracked total balance.
+    pub fn delta_total(&self) -> Decimal {
+        self.fetched.total - self.tracked.total
+    }
+
+    /// Difference of the fetched free balance from the tracked free balance.
+    pub fn delta_free(&self) -> Decimal {
+        self.fetched.free - self.tracked.free
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_balance_drift_tolerance_check() {
+        struct TestCase {
+            tracked: Option<Timed<Balance>>,
+            fetched: Balance,
+            expected: Option<Decimal>,
+        }
+
+        let time_base = DateTime::<Utc>::MIN_UTC;
+        let tolerance = BalanceDriftTolerance {
+            absolute: dec!(0.5),
+            relative: dec!(0.01),
+        };
+
+        let cases = vec![
+            // TC0: no drift
+            TestCase {
+                tracked: Some(Timed::new(Balance::new(dec!(100), dec!(100)), time_base)),
+                fetched: Balance::new(dec!(100), dec!(100)),
+                expected: None,
+            },
+            // TC1: drift within the relative tolerance
+            TestCase {
+                tracked: Some(Timed::new(Balance::new(dec!(100), dec!(100)), time_base)),
+                fetched: Balance::new(dec!(101), dec!(101)),
+                expected: None,
+            },
+            // TC2: drift beyond the relative tolerance
+            TestCase {
+                tracked: Some(Timed::new(Balance::new(dec!(100), dec!(100)), time_base)),
+                fetched: Balance::new(dec!(98), dec!(98)),
+                expected: Some(dec!(-2)),
+            },
+            // TC3: drift within the absolute tolerance of a small balance
+            TestCase {
+                tracked: Some(Timed::new(Balance::new(dec!(1), dec!(1)), time_base)),
+                fetched: Balance::new(dec!(1.4), dec!(1.4)),
+                expected: None,
+            },
+            // TC4: untracked balance is treated as empty
+            TestCase {
+                tracked: None,
+                fetched: Balance::new(dec!(10), dec!(10)),
+                expected: Some(dec!(10)),
+            },
+            // TC5: tracked balance more recent than the fetched balance is skipped
+            TestCase {
+                tracked: Some(Timed::new(
+                    Balance::new(dec!(100), dec!(100)),
+                    time_plus_secs(time_base, 1),
+                )),
+                fetched: Balance::new(dec!(50), dec!(50)),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let fetched = [AssetBalance::new(AssetIndex(0), test.fetched, time_base)];
+
+            let actual = tolerance
+                .check(ExchangeIndex(0), &fetched, |_| test.tracked)
+                .map(|drift| {
+                    assert_eq!(drift.assets.len(), 1, "TC{index} failed");
+                    drift.assets[0].delta_total()
+                });
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
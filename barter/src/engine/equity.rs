@@ -0,0 +1,123 @@
+use crate::engine::state::asset::equity::EquityCalculator;
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Defines how frequently the [`Engine`](super::Engine) emits an
+/// [`EquitySnapshot`](crate::engine::state::asset::equity::EquitySnapshot).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum EquitySnapshotPolicy {
+    /// Emit an `EquitySnapshot` once every interval of `Engine` time has elapsed.
+    ///
+    /// Note an `EquitySnapshot` is always emitted after the first processed event.
+    Interval(TimeDelta),
+
+    /// Emit an `EquitySnapshot` once every `n` market events have been processed (eg/ for
+    /// back-tests, where `Engine` time may be sparse).
+    ///
+    /// `MarketEvents(0)` is equivalent to `MarketEvents(1)`.
+    MarketEvents(usize),
+}
+
+/// Tracks the [`EquitySnapshotPolicy`] progress, and the [`EquityCalculator`] used to value each
+/// `EquitySnapshot`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EquitySnapshotter {
+    pub policy: EquitySnapshotPolicy,
+    pub calculator: EquityCalculator,
+    pub market_events: usize,
+    pub time_last: Option<DateTime<Utc>>,
+}
+
+impl EquitySnapshotter {
+    /// Construct a new `EquitySnapshotter` with the provided [`EquitySnapshotPolicy`] and
+    /// [`EquityCalculator`].
+    pub fn new(policy: EquitySnapshotPolicy, calculator: EquityCalculator) -> Self {
+        Self {
+            policy,
+            calculator,
+            market_events: 0,
+            time_last: None,
+        }
+    }
+
+    /// Record a processed event, returning true if an `EquitySnapshot` is due.
+    pub fn record_event(&mut self, time: DateTime<Utc>, is_market_event: bool) -> bool {
+        match self.policy {
+            EquitySnapshotPolicy::Interval(interval) => {
+                let due = self
+                    .time_last
+                    .is_none_or(|time_last| time - time_last >= interval);
+                if due {
+                    self.time_last = Some(time);
+                }
+                due
+            }
+            EquitySnapshotPolicy::MarketEvents(max) => {
+                if !is_market_event {
+                    return false;
+                }
+                self.market_events += 1;
+                if self.market_events >= max {
+                    self.market_events = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{engine::state::asset::equity::FxRateSource, test_utils::time_plus_secs};
+    use barter_instrument::asset::name::AssetNameInternal;
+
+    #[test]
+    fn test_equity_snapshotter_record_event() {
+        struct TestCase {
+            policy: EquitySnapshotPolicy,
+            events: Vec<(i64, bool)>,
+            expected: Vec<bool>,
+        }
+
+        let cases = vec![
+            // TC0: Interval emits after first event, then once per interval elapsed
+            TestCase {
+                policy: EquitySnapshotPolicy::Interval(TimeDelta::seconds(10)),
+                events: vec![(0, true), (5, false), (10, true), (19, true), (25, false)],
+                expected: vec![true, false, true, false, true],
+            },
+            // TC1: MarketEvents emits every nth market event, ignoring other events
+            TestCase {
+                policy: EquitySnapshotPolicy::MarketEvents(2),
+                events: vec![(0, true), (1, false), (2, true), (3, true), (4, true)],
+                expected: vec![false, false, true, false, true],
+            },
+            // TC2: MarketEvents(0) emits after every market event
+            TestCase {
+                policy: EquitySnapshotPolicy::MarketEvents(0),
+                events: vec![(0, true), (1, false), (2, true)],
+                expected: vec![true, false, true],
+            },
+        ];
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let calculator =
+            EquityCalculator::new(AssetNameInternal::new("usdt"), FxRateSource::LatestPrices);
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut snapshotter = EquitySnapshotter::new(test.policy, calculator.clone());
+
+            let actual = test
+                .events
+                .into_iter()
+                .map(|(secs, is_market_event)| {
+                    snapshotter.record_event(time_plus_secs(base, secs), is_market_event)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
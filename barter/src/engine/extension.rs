@@ -0,0 +1,186 @@
+use crate::{
+    engine::{
+        drift::BalanceDriftTolerance,
+        equity::EquitySnapshotter,
+        health::HealthMonitor,
+        outage::OutageMonitor,
+        shadow::ShadowExecution,
+        stale::StaleOrderPolicy,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    statistic::metric::{
+        exposure::ExposureGenerator, latency::LatencyTracker, shortfall::ShortfallTracker,
+    },
+};
+use barter_execution::{
+    AccountEvent,
+    credentials::Credentials,
+    order::{id::StrategyId, request::OrderRequestOpen},
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+
+/// Optional [`Engine`](super::Engine) subsystem that observes the `Engine` event processing
+/// pipeline (eg/ [`LatencyTracker`]).
+///
+/// Every hook defaults to doing nothing, so an extension only implements the hooks it requires.
+pub trait EngineExtension<GlobalData, InstrumentData> {
+    /// Observe an open order request sent by the `Engine`.
+    fn on_open_sent(
+        &mut self,
+        _state: &EngineState<GlobalData, InstrumentData>,
+        _request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) {
+    }
+
+    /// Observe an [`AccountEvent`] before it is applied to the [`EngineState`].
+    fn on_account_event(
+        &mut self,
+        _state: &EngineState<GlobalData, InstrumentData>,
+        _event: &AccountEvent,
+    ) {
+    }
+}
+
+/// Optional [`Engine`](super::Engine) subsystems, each enabled via the associated `Engine`
+/// `with_*` constructor (eg/ `Engine::with_latency_tracking`).
+///
+/// Subsystems that observe the `Engine` event processing pipeline implement
+/// [`EngineExtension`], and are notified in turn via [`EngineExtensions::on_opens_sent`] and
+/// [`EngineExtensions::on_account_event`].
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct EngineExtensions {
+    pub equity: Option<EquitySnapshotter>,
+    pub health: Option<HealthMonitor>,
+    pub exposure: Option<ExposureGenerator>,
+    pub latency: Option<LatencyTracker>,
+    pub shortfall: Option<ShortfallTracker>,
+    pub stale_orders: Option<StaleOrderPolicy>,
+    pub balance_drift: Option<BalanceDriftTolerance>,
+    pub outage: Option<OutageMonitor>,
+    pub shadow: Option<ShadowExecution>,
+    pub credentials: Option<Credentials>,
+    pub protective_stops: Option<StrategyId>,
+}
+
+impl EngineExtensions {
+    /// Notify every enabled [`EngineExtension`] of the open order requests sent by the `Engine`.
+    pub fn on_opens_sent<'a, GlobalData, InstrumentData, Iter>(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        requests: Iter,
+    ) where
+        InstrumentData: InstrumentDataState,
+        Iter: IntoIterator<Item = &'a OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    {
+        for request in requests {
+            self.for_each_enabled(|extension| extension.on_open_sent(state, request));
+        }
+    }
+
+    /// Notify every enabled [`EngineExtension`] of an [`AccountEvent`], before it is applied to
+    /// the [`EngineState`].
+    pub fn on_account_event<GlobalData, InstrumentData>(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        event: &AccountEvent,
+    ) where
+        InstrumentData: InstrumentDataState,
+    {
+        self.for_each_enabled(|extension| extension.on_account_event(state, event));
+    }
+
+    fn for_each_enabled<GlobalData, InstrumentData, FnHook>(&mut self, mut hook: FnHook)
+    where
+        InstrumentData: InstrumentDataState,
+        FnHook: FnMut(&mut dyn EngineExtension<GlobalData, InstrumentData>),
+    {
+        if let Some(latency) = &mut self.latency {
+            hook(latency);
+        }
+        if let Some(shortfall) = &mut self.shortfall {
+            hook(shortfall);
+        }
+        if let Some(outage) = &mut self.outage {
+            hook(outage);
+        }
+        if let Some(shadow) = &mut self.shadow {
+            hook(shadow);
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> EngineExtension<GlobalData, InstrumentData> for LatencyTracker {
+    fn on_open_sent(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) {
+        self.record_sent(std::iter::once(request), state.time_engine_now);
+    }
+
+    fn on_account_event(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        event: &AccountEvent,
+    ) {
+        self.update_from_account_event(event, state.time_engine_now);
+    }
+}
+
+impl<GlobalData, InstrumentData> EngineExtension<GlobalData, InstrumentData> for ShortfallTracker
+where
+    InstrumentData: InstrumentDataState,
+{
+    fn on_open_sent(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) {
+        self.record_sent(std::iter::once(request), |instrument| {
+            state.instruments.instrument_index(instrument).data.price()
+        });
+    }
+
+    fn on_account_event(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        event: &AccountEvent,
+    ) {
+        self.update_from_account_event(event, |instrument| {
+            state.instruments.instrument_index(instrument).data.price()
+        });
+    }
+}
+
+impl<GlobalData, InstrumentData> EngineExtension<GlobalData, InstrumentData> for OutageMonitor {
+    fn on_account_event(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        event: &AccountEvent,
+    ) {
+        self.record_account_event(event, state.time_engine_now);
+    }
+}
+
+impl<GlobalData, InstrumentData> EngineExtension<GlobalData, InstrumentData> for ShadowExecution
+where
+    InstrumentData: InstrumentDataState,
+{
+    fn on_open_sent(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) {
+        self.record_sent(std::iter::once(request), |instrument| {
+            state.instruments.instrument_index(instrument).data.l1()
+        });
+    }
+
+    fn on_account_event(
+        &mut self,
+        _: &EngineState<GlobalData, InstrumentData>,
+        event: &AccountEvent,
+    ) {
+        self.update_from_account_event(event);
+    }
+}
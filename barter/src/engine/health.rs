@@ -0,0 +1,283 @@
+use crate::engine::state::connectivity::{ConnectivityState, ConnectivityStates};
+use barter_instrument::exchange::{ExchangeId, ExchangeIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the [`Engine`](super::Engine) [`HealthMonitor`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct HealthConfig {
+    /// Interval of `Engine` time between emitted [`EngineHealth`] heartbeats.
+    pub interval: TimeDelta,
+
+    /// Maximum tolerated market data feed lag (ie/ `Engine` time minus the exchange time of the
+    /// latest market event).
+    pub max_feed_lag: TimeDelta,
+
+    /// Maximum tolerated age of the latest account event of each exchange.
+    ///
+    /// `None` disables the check, since account events may legitimately be sparse.
+    pub max_account_event_age: Option<TimeDelta>,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            interval: TimeDelta::seconds(10),
+            max_feed_lag: TimeDelta::seconds(5),
+            max_account_event_age: None,
+        }
+    }
+}
+
+/// Exchange time of the latest market event, and the `Engine` time it was processed.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct MarketEventTimes {
+    pub time_exchange: DateTime<Utc>,
+    pub time_processed: DateTime<Utc>,
+}
+
+/// Health of an exchange's market data and account connections.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ExchangeHealth {
+    pub exchange: ExchangeId,
+    pub connectivity: ConnectivityState,
+
+    /// `Engine` time the latest account event of this exchange was processed.
+    pub time_last_account_event: Option<DateTime<Utc>>,
+}
+
+/// Periodic `Engine` heartbeat summarising the health of the trading system.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct EngineHealth {
+    /// `Engine` time the `EngineHealth` was generated.
+    pub time: DateTime<Utc>,
+    pub market_last: Option<MarketEventTimes>,
+    pub orders_open: usize,
+    pub exchanges: Vec<ExchangeHealth>,
+}
+
+impl EngineHealth {
+    /// Market data feed lag of the latest market event.
+    pub fn feed_lag(&self) -> Option<TimeDelta> {
+        self.market_last
+            .map(|market| market.time_processed - market.time_exchange)
+    }
+
+    /// Age of the provided exchange's latest account event, relative to the `EngineHealth` time.
+    pub fn account_event_age(&self, exchange: &ExchangeHealth) -> Option<TimeDelta> {
+        exchange
+            .time_last_account_event
+            .map(|time_last| self.time - time_last)
+    }
+
+    /// Returns true if all exchange connections are healthy, and the feed lag and account event
+    /// ages are within the [`HealthConfig`] limits.
+    ///
+    /// Suitable for consumption by external supervisors (eg/ a Kubernetes liveness probe).
+    pub fn is_healthy(&self, config: &HealthConfig) -> bool {
+        let connections_healthy = self
+            .exchanges
+            .iter()
+            .all(|exchange| exchange.connectivity.all_healthy());
+
+        let feed_lag_healthy = self.feed_lag().is_none_or(|lag| lag <= config.max_feed_lag);
+
+        let accounts_healthy = config.max_account_event_age.is_none_or(|max_age| {
+            self.exchanges.iter().all(|exchange| {
+                self.account_event_age(exchange)
+                    .is_some_and(|age| age <= max_age)
+            })
+        });
+
+        connections_healthy && feed_lag_healthy && accounts_healthy
+    }
+}
+
+/// Tracks the latest market and account event times used to generate [`EngineHealth`]
+/// heartbeats every [`HealthConfig::interval`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HealthMonitor {
+    pub config: HealthConfig,
+    pub market_last: Option<MarketEventTimes>,
+    pub account_last: FnvHashMap<ExchangeIndex, DateTime<Utc>>,
+    pub time_last_emitted: Option<DateTime<Utc>>,
+}
+
+impl HealthMonitor {
+    /// Construct a new `HealthMonitor` using the provided [`HealthConfig`].
+    pub fn new(config: HealthConfig) -> Self {
+        Self {
+            config,
+            market_last: None,
+            account_last: FnvHashMap::default(),
+            time_last_emitted: None,
+        }
+    }
+
+    /// Record a market event with the provided exchange time, processed at the `Engine` time.
+    pub fn record_market_event(
+        &mut self,
+        time_engine: DateTime<Utc>,
+        time_exchange: DateTime<Utc>,
+    ) {
+        self.market_last = Some(MarketEventTimes {
+            time_exchange,
+            time_processed: time_engine,
+        });
+    }
+
+    /// Record an account event of the provided exchange, processed at the `Engine` time.
+    pub fn record_account_event(&mut self, time_engine: DateTime<Utc>, exchange: ExchangeIndex) {
+        self.account_last.insert(exchange, time_engine);
+    }
+
+    /// Returns true if an [`EngineHealth`] heartbeat is due at the provided `Engine` time.
+    ///
+    /// Note a heartbeat is always due after the first processed event.
+    pub fn record_heartbeat(&mut self, time_engine: DateTime<Utc>) -> bool {
+        let due = self
+            .time_last_emitted
+            .is_none_or(|time_last| time_engine - time_last >= self.config.interval);
+        if due {
+            self.time_last_emitted = Some(time_engine);
+        }
+        due
+    }
+
+    /// Generate an [`EngineHealth`] at the provided `Engine` time.
+    pub fn health(
+        &self,
+        time_engine: DateTime<Utc>,
+        connectivity: &ConnectivityStates,
+        orders_open: usize,
+    ) -> EngineHealth {
+        let exchanges = connectivity
+            .exchanges
+            .iter()
+            .enumerate()
+            .map(|(index, (exchange, connectivity))| ExchangeHealth {
+                exchange: *exchange,
+                connectivity: connectivity.clone(),
+                time_last_account_event: self.account_last.get(&ExchangeIndex(index)).copied(),
+            })
+            .collect();
+
+        EngineHealth {
+            time: time_engine,
+            market_last: self.market_last,
+            orders_open,
+            exchanges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::connectivity::Health,
+        test_utils::{time_plus_millis, time_plus_secs},
+    };
+
+    fn health(
+        connectivity: ConnectivityState,
+        market_lag_ms: Option<i64>,
+        account_age_secs: Option<i64>,
+    ) -> EngineHealth {
+        let time = DateTime::<Utc>::MIN_UTC + TimeDelta::days(1);
+        EngineHealth {
+            time,
+            market_last: market_lag_ms.map(|lag| MarketEventTimes {
+                time_exchange: time_plus_millis(time, -lag),
+                time_processed: time,
+            }),
+            orders_open: 0,
+            exchanges: vec![ExchangeHealth {
+                exchange: ExchangeId::BinanceSpot,
+                connectivity,
+                time_last_account_event: account_age_secs.map(|age| time_plus_secs(time, -age)),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_engine_health_is_healthy() {
+        struct TestCase {
+            health: EngineHealth,
+            config: HealthConfig,
+            expected: bool,
+        }
+
+        let healthy = ConnectivityState {
+            market_data: Health::Healthy,
+            account: Health::Healthy,
+            degraded: None,
+        };
+
+        let cases = vec![
+            // TC0: healthy connections & feed lag within limit
+            TestCase {
+                health: health(healthy.clone(), Some(100), None),
+                config: HealthConfig::default(),
+                expected: true,
+            },
+            // TC1: reconnecting account connection
+            TestCase {
+                health: health(
+                    ConnectivityState {
+                        market_data: Health::Healthy,
+                        account: Health::Reconnecting,
+                        degraded: None,
+                    },
+                    Some(100),
+                    None,
+                ),
+                config: HealthConfig::default(),
+                expected: false,
+            },
+            // TC2: feed lag exceeds limit
+            TestCase {
+                health: health(healthy.clone(), Some(10_000), None),
+                config: HealthConfig::default(),
+                expected: false,
+            },
+            // TC3: account event age exceeds limit
+            TestCase {
+                health: health(healthy.clone(), Some(100), Some(120)),
+                config: HealthConfig {
+                    max_account_event_age: Some(TimeDelta::seconds(60)),
+                    ..HealthConfig::default()
+                },
+                expected: false,
+            },
+            // TC4: no account event received, with account event age check enabled
+            TestCase {
+                health: health(healthy, None, None),
+                config: HealthConfig {
+                    max_account_event_age: Some(TimeDelta::seconds(60)),
+                    ..HealthConfig::default()
+                },
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.health.is_healthy(&test.config);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_health_monitor_record_heartbeat() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut monitor = HealthMonitor::new(HealthConfig::default());
+
+        assert!(monitor.record_heartbeat(base));
+        assert!(!monitor.record_heartbeat(time_plus_secs(base, 5)));
+        assert!(monitor.record_heartbeat(time_plus_secs(base, 10)));
+        assert!(!monitor.record_heartbeat(time_plus_secs(base, 19)));
+    }
+}
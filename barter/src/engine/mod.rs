@@ -6,36 +6,68 @@ use crate::{
             cancel_orders::CancelOrders,
             close_positions::ClosePositions,
             generate_algo_orders::{GenerateAlgoOrders, GenerateAlgoOrdersOutput},
-            send_requests::SendRequests,
+            send_requests::{SendCancelsAndOpensOutput, SendRequests, SendRequestsOutput},
         },
         audit::{AuditTick, Auditor, EngineAudit, ProcessAudit, context::EngineContext},
+        batch::{MarketBatchPolicy, MarketBatcher},
         clock::EngineClock,
-        command::Command,
+        command::{Command, StrategyUpdate},
+        drift::{BalanceDrift, BalanceDriftTolerance},
+        equity::{EquitySnapshotPolicy, EquitySnapshotter},
         execution_tx::ExecutionTxMap,
+        extension::EngineExtensions,
+        health::{EngineHealth, HealthConfig, HealthMonitor},
+        outage::{ExchangeStatusUpdate, OutageConfig, OutageMonitor},
+        shadow::{ShadowConfig, ShadowExecution, ShadowReport},
+        stale::StaleOrderPolicy,
         state::{
-            EngineState, instrument::data::InstrumentDataState,
-            order::in_flight_recorder::InFlightRequestRecorder, position::PositionExited,
+            EngineState,
+            asset::equity::{EquityCalculator, EquitySnapshot},
+            instrument::{
+                InstrumentUpdateError, data::InstrumentDataState, filter::InstrumentFilter,
+            },
+            order::in_flight_recorder::InFlightRequestRecorder,
+            position::{PositionExited, PositionMarketUpdate, PositionUpdate},
             trading::TradingState,
         },
     },
-    execution::{AccountStreamEvent, request::ExecutionRequest},
-    risk::RiskManager,
+    execution::{AccountStreamEvent, request::ExecutionRequest, router::SmartOrderRouter},
+    risk::{RiskManager, halt::RiskHalt},
     shutdown::SyncShutdown,
-    statistic::summary::TradingSummaryGenerator,
+    statistic::{
+        metric::{
+            exposure::{Exposure, ExposureGenerator},
+            latency::LatencyTracker,
+            shortfall::ShortfallTracker,
+        },
+        summary::TradingSummaryGenerator,
+    },
     strategy::{
-        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
-        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+        algo::{AlgoStrategy, StrategyUpdateError},
+        close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
     },
 };
 use barter_data::{event::MarketEvent, streams::consumer::MarketStreamEvent};
-use barter_execution::AccountEvent;
-use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
-use barter_integration::channel::Tx;
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    credentials::{Credentials, CredentialsError, CredentialsUpdate},
+    order::{
+        OrderKey, OrderKind, StopTrigger, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestCancel, RequestOpen},
+    },
+};
+use barter_instrument::{
+    Side, asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex,
+};
+use barter_integration::{channel::Tx, collection::one_or_many::OneOrMany};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use tracing::info;
+use tracing::{info, warn};
 
 /// Defines how the [`Engine`] actions a [`Command`], and the associated outputs.
 pub mod action;
@@ -45,6 +77,10 @@ pub mod action;
 /// eg/ `StateReplicaManager` component can be used to maintain an `EngineState` replica.
 pub mod audit;
 
+/// Defines a [`MarketBatchPolicy`] used to control how frequently the [`Engine`] generates algo
+/// orders while processing bursts of market events.
+pub mod batch;
+
 /// Defines the [`EngineClock`] interface used to determine the current `Engine` time.
 ///
 /// This flexibility enables back-testing runs to use approximately correct historical timestamps.
@@ -54,6 +90,34 @@ pub mod clock;
 /// external process (eg/ ClosePositions).
 pub mod command;
 
+/// Defines a [`BalanceDriftTolerance`] used to detect divergence of the internally tracked
+/// balances from periodically fetched exchange balances, emitting a [`BalanceDrift`].
+pub mod drift;
+
+/// Defines an [`EquitySnapshotPolicy`] used to control how frequently the [`Engine`] emits an
+/// [`EquitySnapshot`] of portfolio equity.
+pub mod equity;
+
+/// Defines a [`HealthMonitor`] used to emit periodic [`EngineHealth`] heartbeats containing feed
+/// lag, account event ages, open order count and exchange connection statuses.
+pub mod health;
+
+/// Defines an [`OutageMonitor`] used to detect degraded exchanges from connection statuses and
+/// order request error rates, switching their instruments into a configurable `DegradedMode`.
+pub mod outage;
+
+/// Defines a [`StaleOrderPolicy`] used to automatically cancel resting limit orders that remain
+/// unfilled beyond a configurable age or price distance from the market.
+pub mod stale;
+
+/// Defines a [`ShadowExecution`] mode that simulates the fills of live open order requests,
+/// periodically reporting the divergence of live fills from the simulated execution path.
+pub mod shadow;
+
+/// Defines the [`EngineExtensions`] grouping the optional [`Engine`] subsystems, and the
+/// [`EngineExtension`](extension::EngineExtension) hooks they use to observe event processing.
+pub mod extension;
+
 /// Defines all possible errors that can occur in the [`Engine`].
 pub mod error;
 
@@ -71,6 +135,18 @@ pub mod state;
 /// eg/ `fn sync_run`, `fn sync_run_with_audit`, `fn async_run`, `fn async_run_with_audit`,
 pub mod run;
 
+/// Defines a [`WriteAheadLog`](wal::WriteAheadLog) that durably records `Engine` input events
+/// before they are processed, enabling exact `EngineState` replay after a crash.
+pub mod wal;
+
+/// Deterministic replay of a persisted `Engine` event log, reconstructing the `EngineState` and
+/// `TradingSummary` of a recorded trading session.
+pub mod replay;
+
+/// Defines a [`Versioned`](schema::Versioned) envelope and migration path for serialised payloads,
+/// enabling event logs written by a previous crate version to be read after upgrades.
+pub mod schema;
+
 /// Defines how a component processing an input Event and generates an appropriate Audit.
 pub trait Processor<Event> {
     type Audit;
@@ -103,10 +179,12 @@ where
 /// * `ExecutionTxs` - [`ExecutionTxMap`] implementation for sending execution requests.
 /// * `Strategy` - Trading Strategy implementation (see [`super::strategy`]).
 /// * `Risk` - [`RiskManager`] implementation.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Engine<Clock, State, ExecutionTxs, Strategy, Risk> {
     pub clock: Clock,
     pub meta: EngineMeta,
+    pub batch: MarketBatcher,
+    pub extensions: EngineExtensions,
     pub state: State,
     pub execution_txs: ExecutionTxs,
     pub strategy: Strategy,
@@ -145,11 +223,19 @@ where
 
     fn process(&mut self, event: EngineEvent<InstrumentData::MarketEventKind>) -> Self::Audit {
         self.clock.process(&event);
+        self.state.time_engine_now = self.clock.time();
+
+        let batch_complete = match &event {
+            EngineEvent::Market(MarketStreamEvent::Item(_)) => self.batch.record_market_event(),
+            _ => self.batch.flush(),
+        };
 
         let process_audit = match &event {
             EngineEvent::Shutdown(_) => return EngineAudit::shutdown_commanded(event),
             EngineEvent::Command(command) => {
                 let output = self.action(command);
+                self.extensions
+                    .on_opens_sent(&self.state, output.opens_sent());
 
                 if let Some(unrecoverable) = output.unrecoverable_errors() {
                     return EngineAudit::shutdown_on_err(event, unrecoverable, output);
@@ -162,17 +248,115 @@ where
                 ProcessAudit::with_trading_state_update(event, output)
             }
             EngineEvent::Account(account) => {
+                let drift = match account {
+                    AccountStreamEvent::Item(account) => {
+                        self.extensions.on_account_event(&self.state, account);
+                        self.check_balance_drift(account)
+                    }
+                    _ => None,
+                };
+
                 let output = self.update_from_account_stream(account);
-                ProcessAudit::with_account_update(event, output)
+
+                let halts = match &output {
+                    UpdateFromAccountOutput::PositionExit(position) => {
+                        self.risk.update_from_position_exit(position)
+                    }
+                    _ => Vec::new(),
+                };
+
+                let process_audit = ProcessAudit::with_account_update(event, output);
+                let process_audit = match drift {
+                    Some(drift) => process_audit.add_additional(EngineOutput::BalanceDrift(drift)),
+                    None => process_audit,
+                };
+                halts.into_iter().fold(process_audit, |audit, halt| {
+                    audit.add_additional(EngineOutput::RiskHalt(halt))
+                })
             }
             EngineEvent::Market(market) => {
                 let output = self.update_from_market_stream(market);
-                ProcessAudit::with_market_update(event, output)
+
+                let halts = match &output {
+                    UpdateFromMarketOutput::PositionExit(position) => {
+                        self.risk.update_from_position_exit(position)
+                    }
+                    _ => Vec::new(),
+                };
+
+                let stop = match &output {
+                    UpdateFromMarketOutput::PositionUpdate(update) => {
+                        self.send_protective_stop(update)
+                    }
+                    _ => None,
+                };
+
+                let process_audit = ProcessAudit::with_market_update(event, output);
+                let process_audit = match stop {
+                    Some(stop) => match stop.unrecoverable_errors().into_option() {
+                        Some(unrecoverable) => {
+                            return EngineAudit::shutdown_on_err_with_process(
+                                process_audit.add_additional(EngineOutput::ProtectiveStop(stop)),
+                                unrecoverable,
+                            );
+                        }
+                        None => process_audit.add_additional(EngineOutput::ProtectiveStop(stop)),
+                    },
+                    None => process_audit,
+                };
+                halts.into_iter().fold(process_audit, |audit, halt| {
+                    audit.add_additional(EngineOutput::RiskHalt(halt))
+                })
             }
         };
 
-        if let TradingState::Enabled = self.state.trading {
+        let process_audit = match self.generate_equity_snapshot(process_audit.event()) {
+            Some(snapshot) => process_audit.add_additional(EngineOutput::EquitySnapshot(snapshot)),
+            None => process_audit,
+        };
+
+        let process_audit = match self.generate_health(process_audit.event()) {
+            Some(health) => process_audit.add_additional(EngineOutput::Health(health)),
+            None => process_audit,
+        };
+
+        let process_audit = self.update_exchange_statuses().into_iter().fold(
+            process_audit,
+            |process_audit, update| {
+                process_audit.add_additional(EngineOutput::ExchangeStatus(update))
+            },
+        );
+
+        let process_audit = match self.generate_shadow_report() {
+            Some(report) => process_audit.add_additional(EngineOutput::ShadowReport(report)),
+            None => process_audit,
+        };
+
+        self.update_exposure();
+
+        let process_audit = match self.cancel_stale_orders() {
+            Some(cancels) => match cancels.unrecoverable_errors().into_option() {
+                Some(unrecoverable) => {
+                    return EngineAudit::shutdown_on_err_with_process(
+                        process_audit.add_additional(EngineOutput::StaleOrders(cancels)),
+                        unrecoverable,
+                    );
+                }
+                None => process_audit.add_additional(EngineOutput::StaleOrders(cancels)),
+            },
+            None => process_audit,
+        };
+
+        // Only generate algo orders once every exchange account is synchronised, so the
+        // Strategy never trades on assumed empty balances, positions & orders
+        if batch_complete
+            && self.state.trading == TradingState::Enabled
+            && self.state.connectivity.all_accounts_synced()
+        {
             let output = self.generate_algo_orders();
+            self.extensions
+                .on_opens_sent(&self.state, &output.cancels_and_opens.opens.sent);
+            self.record_signals_sent(&output.cancels_and_opens.opens.sent);
 
             if output.is_empty() {
                 EngineAudit::from(process_audit)
@@ -207,9 +391,10 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
     /// Action an `Engine` [`Command`], producing an [`ActionOutput`] of work done.
     pub fn action(&mut self, command: &Command) -> ActionOutput
     where
-        InstrumentData: InFlightRequestRecorder,
+        InstrumentData: InstrumentDataState,
         ExecutionTxs: ExecutionTxMap,
-        Strategy: ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>,
+        Strategy:
+            AlgoStrategy + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>,
         Risk: RiskManager,
     {
         match &command {
@@ -236,9 +421,113 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
                 info!(?filter, "Engine actioning user Command::CancelOrders");
                 ActionOutput::CancelOrders(self.cancel_orders(filter))
             }
+            Command::UpdateStrategy(update) => {
+                info!(?update, "Engine actioning user Command::UpdateStrategy");
+                let output = self.update_strategy(update);
+                if let Err(error) = &output {
+                    warn!(%error, "Engine failed to action user Command::UpdateStrategy");
+                }
+                ActionOutput::UpdateStrategy(output)
+            }
+            Command::UpdateCredentials(update) => {
+                info!(?update, "Engine actioning user Command::UpdateCredentials");
+                let output = self.update_credentials(update);
+                if let Err(error) = &output {
+                    warn!(%error, "Engine failed to action user Command::UpdateCredentials");
+                }
+                ActionOutput::UpdateCredentials(output)
+            }
+            Command::AddInstrument(instrument) => {
+                info!(?instrument, "Engine actioning user Command::AddInstrument");
+                let output = self.state.add_instrument(instrument);
+                if let Err(error) = &output {
+                    warn!(%error, "Engine failed to action user Command::AddInstrument");
+                }
+                ActionOutput::AddInstrument(output)
+            }
+            Command::RemoveInstrument(instrument) => {
+                info!(
+                    ?instrument,
+                    "Engine actioning user Command::RemoveInstrument"
+                );
+                let output = self.remove_instrument(instrument);
+                if let Err(error) = &output {
+                    warn!(%error, "Engine failed to action user Command::RemoveInstrument");
+                }
+                ActionOutput::RemoveInstrument(output)
+            }
         }
     }
 
+    /// Gracefully wind down trading of an instrument removed from the running `Engine`.
+    ///
+    /// The instrument is marked as winding down so no new algorithmic open order requests are
+    /// sent for it, and any open orders are cancelled & any open position is closed. The
+    /// instrument state is retained so the closing orders continue to be tracked.
+    ///
+    /// Note that the instrument market data should be unsubscribed separately (see
+    /// [`System::remove_instrument`](crate::system::System::remove_instrument)).
+    pub fn remove_instrument(
+        &mut self,
+        instrument: &InstrumentIndex,
+    ) -> Result<SendCancelsAndOpensOutput, InstrumentUpdateError>
+    where
+        InstrumentData: InstrumentDataState,
+        ExecutionTxs: ExecutionTxMap,
+        Strategy: ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>,
+    {
+        self.state.wind_down_instrument(instrument)?;
+
+        let filter = InstrumentFilter::Instruments(OneOrMany::One(*instrument));
+        let cancels = self.cancel_orders(&filter);
+        let closes = self.close_positions(&filter);
+
+        Ok(SendCancelsAndOpensOutput::new(
+            SendRequestsOutput::new(
+                cancels.sent.extend(closes.cancels.sent),
+                cancels.errors.extend(closes.cancels.errors),
+            ),
+            closes.opens,
+        ))
+    }
+
+    /// Apply a runtime [`CredentialsUpdate`] to the `Engine` [`Credentials`], eg/ to rotate
+    /// compromised or expiring exchange keys without downtime.
+    ///
+    /// Execution clients sharing the [`Credentials`] (see [`Engine::with_credentials`]) use the
+    /// rotated keys from their next request.
+    pub fn update_credentials(&self, update: &CredentialsUpdate) -> Result<(), CredentialsError> {
+        self.extensions
+            .credentials
+            .as_ref()
+            .ok_or(CredentialsError::NotConfigured(update.exchange()))?
+            .update(update)
+    }
+
+    /// Update the `Engine` strategy parameters at runtime from a [`StrategyUpdate`].
+    ///
+    /// Commands are actioned between events, so any orders generated using the previous
+    /// parameters have already been sent and recorded as in-flight, and continue to be managed
+    /// as normal. The new parameters are used from the next algorithmic order generation.
+    ///
+    /// Unless the update is a `warm_start`, instrument indicator state is reset.
+    pub fn update_strategy(&mut self, update: &StrategyUpdate) -> Result<(), StrategyUpdateError>
+    where
+        InstrumentData: InstrumentDataState,
+        Strategy: AlgoStrategy,
+    {
+        self.strategy.update_params(&update.params)?;
+
+        if !update.warm_start {
+            self.state
+                .instruments
+                .instruments_mut(&InstrumentFilter::None)
+                .for_each(|state| state.data.reset_indicators());
+        }
+
+        Ok(())
+    }
+
     /// Update the `Engine` [`TradingState`].
     ///
     /// If the `TradingState` transitions to `TradingState::Disabled`, the `Engine` will call
@@ -291,6 +580,10 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
     ///
     /// If the input `MarketStreamEvent` indicates the exchange market data link has disconnected,
     /// the `Engine` will call the configured [`OnDisconnectStrategy`] strategy logic.
+    ///
+    /// If the `MarketEvent` triggers the liquidation of a leveraged `Position`, the resulting
+    /// [`PositionExited`] is returned, and if it moves a `Position` protective stop, the
+    /// resulting [`PositionUpdate`] is returned.
     pub fn update_from_market_stream(
         &mut self,
         event: &MarketStreamEvent<InstrumentIndex, InstrumentData::MarketEventKind>,
@@ -309,13 +602,294 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
 
                 UpdateFromMarketOutput::OnDisconnect(Strategy::on_disconnect(self, *exchange))
             }
-            MarketStreamEvent::Item(event) => {
-                self.state.update_from_market(event);
-                UpdateFromMarketOutput::None
+            MarketStreamEvent::Item(event) => match self.state.update_from_market(event) {
+                Some(PositionMarketUpdate::Update(update)) => {
+                    UpdateFromMarketOutput::PositionUpdate(update)
+                }
+                Some(PositionMarketUpdate::Liquidated { exited, .. }) => {
+                    UpdateFromMarketOutput::PositionExit(exited)
+                }
+                None => UpdateFromMarketOutput::None,
+            },
+        }
+    }
+
+    /// Generate an [`EquitySnapshot`] if one is due according to the configured
+    /// [`EquitySnapshotPolicy`].
+    ///
+    /// Returns `None` if no `EquitySnapshotPolicy` is configured, or no snapshot is due.
+    pub fn generate_equity_snapshot(
+        &mut self,
+        event: &EngineEvent<InstrumentData::MarketEventKind>,
+    ) -> Option<EquitySnapshot>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let snapshotter = self.extensions.equity.as_mut()?;
+        let time = self.state.time_engine_now;
+        let is_market_event = matches!(event, EngineEvent::Market(MarketStreamEvent::Item(_)));
+
+        snapshotter
+            .record_event(time, is_market_event)
+            .then(|| snapshotter.calculator.snapshot(&self.state, time))
+    }
+
+    /// Record the provided event with the configured [`HealthMonitor`], generating an
+    /// [`EngineHealth`] heartbeat if one is due.
+    ///
+    /// Returns `None` if no `HealthMonitor` is configured, or no heartbeat is due.
+    pub fn generate_health(
+        &mut self,
+        event: &EngineEvent<InstrumentData::MarketEventKind>,
+    ) -> Option<EngineHealth>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let time = self.state.time_engine_now;
+        let monitor = self.extensions.health.as_mut()?;
+
+        match event {
+            EngineEvent::Market(MarketStreamEvent::Item(market)) => {
+                monitor.record_market_event(time, market.time_exchange)
+            }
+            EngineEvent::Account(AccountStreamEvent::Item(account)) => {
+                monitor.record_account_event(time, account.exchange)
             }
+            _ => {}
+        }
+
+        if monitor.record_heartbeat(time) {
+            self.health()
+        } else {
+            None
+        }
+    }
+
+    /// Generate the current [`EngineHealth`] using the configured [`HealthMonitor`].
+    ///
+    /// Returns `None` if no `HealthMonitor` is configured.
+    pub fn health(&self) -> Option<EngineHealth> {
+        let orders_open = self
+            .state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .map(|state| state.orders.0.len())
+            .sum();
+
+        self.extensions.health.as_ref().map(|monitor| {
+            monitor.health(
+                self.state.time_engine_now,
+                &self.state.connectivity,
+                orders_open,
+            )
+        })
+    }
+
+    /// Returns true if the current [`EngineHealth`] is within the configured [`HealthConfig`]
+    /// limits (eg/ for an external supervisor liveness probe).
+    ///
+    /// Returns false if no [`HealthMonitor`] is configured.
+    pub fn is_healthy(&self) -> bool {
+        self.extensions
+            .health
+            .as_ref()
+            .zip(self.health())
+            .is_some_and(|(monitor, health)| health.is_healthy(&monitor.config))
+    }
+
+    /// Update the configured [`ExposureGenerator`] with the current portfolio [`Exposure`].
+    ///
+    /// Does nothing if exposure tracking is not enabled.
+    pub fn update_exposure(&mut self)
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        if let Some(exposure) = self.extensions.exposure.as_mut() {
+            exposure.update(
+                self.state.time_engine_now,
+                Exposure::from_instruments(&self.state.instruments),
+            );
         }
     }
 
+    /// Record the [`SignalMeta`](state::position::SignalMeta) attached by the `Strategy` to the
+    /// algorithmic open order requests sent by the `Engine`, so it can be persisted onto any
+    /// `Position` they open.
+    pub fn record_signals_sent<'a, Iter>(&mut self, requests: Iter)
+    where
+        Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>,
+        Iter: IntoIterator<Item = &'a OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    {
+        for request in requests {
+            let Some(signal) = self.strategy.signal_meta(&self.state, request) else {
+                continue;
+            };
+
+            self.state
+                .instruments
+                .instrument_index_mut(&request.key.instrument)
+                .signals
+                .insert(request.key.cid.clone(), signal);
+        }
+    }
+
+    /// Generate a [`ShadowReport`] with the configured [`ShadowExecution`] if one is due.
+    ///
+    /// Returns `None` if shadow mode is not enabled, or no report is due.
+    pub fn generate_shadow_report(&mut self) -> Option<ShadowReport> {
+        let time = self.state.time_engine_now;
+        self.extensions.shadow.as_mut()?.generate_report(time)
+    }
+
+    /// Check the exchange balances of an [`AccountEventKind::BalanceCheck`] for drift from the
+    /// internally tracked balances, using the configured [`BalanceDriftTolerance`].
+    ///
+    /// Returns `None` if balance drift checking is not enabled, or no asset has drifted.
+    pub fn check_balance_drift(&self, event: &AccountEvent) -> Option<BalanceDrift> {
+        let tolerance = self.extensions.balance_drift.as_ref()?;
+        let AccountEventKind::BalanceCheck(balances) = &event.kind else {
+            return None;
+        };
+
+        tolerance.check(event.exchange, balances, |asset| {
+            self.state.assets.asset_index(asset).balance
+        })
+    }
+
+    /// Update the degraded status of each exchange using the configured [`OutageMonitor`],
+    /// returning an [`ExchangeStatusUpdate`] for each exchange that transitioned.
+    ///
+    /// Returns an empty `Vec` if outage detection is not enabled.
+    pub fn update_exchange_statuses(&mut self) -> Vec<ExchangeStatusUpdate> {
+        match self.extensions.outage.as_mut() {
+            Some(outage) => outage.update(&mut self.state.connectivity, self.state.time_engine_now),
+            None => Vec::new(),
+        }
+    }
+
+    /// Encode the tracked order round-trip latencies in the Prometheus text exposition format,
+    /// labelled by [`ExchangeId`](barter_instrument::exchange::ExchangeId).
+    ///
+    /// Returns `None` if latency tracking is not enabled.
+    pub fn latency_prometheus(&self) -> Option<String> {
+        self.extensions.latency.as_ref().map(|latency| {
+            latency.encode_prometheus(|exchange| {
+                self.state
+                    .connectivity
+                    .exchanges
+                    .get_index(exchange.index())
+                    .map(|(exchange, _)| exchange.to_string())
+                    .unwrap_or_else(|| exchange.to_string())
+            })
+        })
+    }
+
+    /// Cancel any resting limit orders that are stale according to the configured
+    /// [`StaleOrderPolicy`].
+    ///
+    /// Returns `None` if no `StaleOrderPolicy` is configured, or no orders are stale.
+    pub fn cancel_stale_orders(&mut self) -> Option<SendRequestsOutput<RequestCancel>>
+    where
+        InstrumentData: InstrumentDataState,
+        ExecutionTxs: ExecutionTxMap,
+    {
+        let policy = self.extensions.stale_orders?;
+        let time_now = self.state.time_engine_now;
+
+        let requests = self
+            .state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .flat_map(|state| {
+                let price_market = state.data.price();
+                state
+                    .orders
+                    .0
+                    .values()
+                    .filter(move |order| policy.is_stale(order, price_market, time_now))
+            })
+            .filter_map(|order| order.to_request_cancel())
+            .collect::<Vec<_>>();
+
+        if requests.is_empty() {
+            return None;
+        }
+
+        info!(
+            num_orders = requests.len(),
+            "Engine cancelling stale orders"
+        );
+
+        let cancels = self.send_requests(requests);
+        self.state.record_in_flight_cancels(&cancels.sent);
+
+        Some(cancels)
+    }
+
+    /// Send a reduce-only `StopMarket` order protecting the `Position` of the provided
+    /// [`PositionUpdate`] at the new stop price, cancelling any existing stop orders on the exit
+    /// [`Side`] (ie/ cancel-and-replace).
+    ///
+    /// Returns `None` if protective stop orders are not enabled (see
+    /// [`Self::with_protective_stop_orders`]).
+    pub fn send_protective_stop(
+        &mut self,
+        update: &PositionUpdate,
+    ) -> Option<SendCancelsAndOpensOutput>
+    where
+        InstrumentData: InstrumentDataState,
+        ExecutionTxs: ExecutionTxMap,
+    {
+        let strategy = self.extensions.protective_stops.clone()?;
+        let side_exit = match update.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+
+        let state = self.state.instruments.instrument_index(&update.instrument);
+        let cancels = state
+            .orders
+            .0
+            .values()
+            .filter(|order| order.side == side_exit && order.kind.stop().is_some())
+            .filter_map(|order| order.to_request_cancel())
+            .collect::<Vec<_>>();
+
+        let open = OrderRequestOpen {
+            key: OrderKey {
+                exchange: state.instrument.exchange,
+                instrument: update.instrument,
+                strategy,
+                cid: ClientOrderId::random(),
+            },
+            state: RequestOpen {
+                side: side_exit,
+                price: update.price_stop,
+                quantity: update.quantity_abs,
+                kind: OrderKind::StopMarket {
+                    trigger_price: update.price_stop,
+                    trigger: StopTrigger::LastPrice,
+                },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: true,
+            },
+        };
+
+        info!(
+            instrument = %update.instrument,
+            price_stop = %update.price_stop,
+            num_cancels = cancels.len(),
+            "Engine replacing Position protective stop order"
+        );
+
+        let cancels = self.send_requests(cancels);
+        self.state.record_in_flight_cancels(&cancels.sent);
+        let opens = self.send_requests([open]);
+        self.state.record_in_flight_opens(&opens.sent);
+
+        Some(SendCancelsAndOpensOutput::new(cancels, opens))
+    }
+
     /// Returns a [`TradingSummaryGenerator`] for the current trading session.
     pub fn trading_summary_generator(&self, risk_free_return: Decimal) -> TradingSummaryGenerator
     where
@@ -328,6 +902,17 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
             &self.state.instruments,
             &self.state.assets,
         )
+        .with_exposure(self.extensions.exposure.clone())
+        .with_shortfall(self.extensions.shortfall.as_ref().map(|shortfall| {
+            shortfall.report(|instrument| {
+                self.state
+                    .instruments
+                    .instrument_index(instrument)
+                    .instrument
+                    .name_internal
+                    .clone()
+            })
+        }))
     }
 }
 
@@ -350,6 +935,8 @@ where
                 time_start: clock.time(),
                 sequence: Sequence(0),
             },
+            batch: MarketBatcher::default(),
+            extensions: EngineExtensions::default(),
             clock,
             state,
             execution_txs,
@@ -358,6 +945,108 @@ where
         }
     }
 
+    /// Configure the [`MarketBatchPolicy`] used to batch market events before generating algo
+    /// orders.
+    pub fn with_market_batch_policy(self, policy: MarketBatchPolicy) -> Self {
+        Self {
+            batch: MarketBatcher::new(policy),
+            ..self
+        }
+    }
+
+    /// Configure the [`EquitySnapshotPolicy`] used to emit [`EquitySnapshot`]s, valued by the
+    /// provided [`EquityCalculator`].
+    pub fn with_equity_snapshot_policy(
+        mut self,
+        policy: EquitySnapshotPolicy,
+        calculator: EquityCalculator,
+    ) -> Self {
+        self.extensions.equity = Some(EquitySnapshotter::new(policy, calculator));
+        self
+    }
+
+    /// Configure a [`HealthMonitor`] to emit [`EngineHealth`] heartbeats using the provided
+    /// [`HealthConfig`].
+    pub fn with_health_monitor(mut self, config: HealthConfig) -> Self {
+        self.extensions.health = Some(HealthMonitor::new(config));
+        self
+    }
+
+    /// Enable tracking of the portfolio gross & net notional [`Exposure`] time series, which is
+    /// summarised in the generated [`TradingSummary`](crate::statistic::summary::TradingSummary).
+    pub fn with_exposure_tracking(mut self) -> Self {
+        self.extensions.exposure = Some(ExposureGenerator::default());
+        self
+    }
+
+    /// Enable tracking of per-exchange order round-trip latencies (see [`LatencyTracker`]).
+    pub fn with_latency_tracking(mut self) -> Self {
+        self.extensions.latency = Some(LatencyTracker::default());
+        self
+    }
+
+    /// Enable tracking of the implementation shortfall of executed orders (see
+    /// [`ShortfallTracker`]), which is reported in the generated
+    /// [`TradingSummary`](crate::statistic::summary::TradingSummary).
+    pub fn with_shortfall_tracking(mut self) -> Self {
+        self.extensions.shortfall = Some(ShortfallTracker::default());
+        self
+    }
+
+    /// Configure a [`StaleOrderPolicy`] used to automatically cancel stale resting limit orders.
+    pub fn with_stale_order_policy(mut self, policy: StaleOrderPolicy) -> Self {
+        self.extensions.stale_orders = Some(policy);
+        self
+    }
+
+    /// Configure the [`BalanceDriftTolerance`] used to emit a [`BalanceDrift`] when periodically
+    /// fetched exchange balances diverge from the internally tracked balances.
+    ///
+    /// Requires the `ExecutionManager`s to be configured with a balance check interval (see
+    /// [`ExecutionBuilder::balance_check_interval`](crate::execution::builder::ExecutionBuilder::balance_check_interval)).
+    pub fn with_balance_drift_tolerance(mut self, tolerance: BalanceDriftTolerance) -> Self {
+        self.extensions.balance_drift = Some(tolerance);
+        self
+    }
+
+    /// Configure an [`OutageMonitor`] that switches the instruments of degraded exchanges into
+    /// the [`OutageConfig`] `DegradedMode`, emitting an [`ExchangeStatusUpdate`] on each
+    /// transition.
+    ///
+    /// The [`SmartOrderRouter`] is required to reroute open order requests when using
+    /// `DegradedMode::Reroute`.
+    pub fn with_outage_monitor(
+        mut self,
+        config: OutageConfig,
+        router: Option<SmartOrderRouter>,
+    ) -> Self {
+        self.extensions.outage = Some(OutageMonitor::new(config, router));
+        self
+    }
+
+    /// Enable shadow mode, simulating the fills of live open order requests using the provided
+    /// [`ShadowConfig`] and periodically emitting a [`ShadowReport`] of the divergence of live
+    /// fills from the simulated execution path.
+    pub fn with_shadow_execution(mut self, config: ShadowConfig) -> Self {
+        self.extensions.shadow = Some(ShadowExecution::new(config));
+        self
+    }
+
+    /// Configure the shared exchange [`Credentials`] that can be rotated at runtime via
+    /// `Command::UpdateCredentials`.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.extensions.credentials = Some(credentials);
+        self
+    }
+
+    /// Enable sending a reduce-only `StopMarket` order, tagged with the provided [`StrategyId`],
+    /// to protect an open `Position` whenever its protective stop is moved (eg/ by a
+    /// [`BreakEvenStop`](state::position::BreakEvenStop)).
+    pub fn with_protective_stop_orders(mut self, strategy: StrategyId) -> Self {
+        self.extensions.protective_stops = Some(strategy);
+        self
+    }
+
     /// Return `Engine` clock time.
     pub fn time(&self) -> DateTime<Utc> {
         self.clock.time()
@@ -382,8 +1071,17 @@ pub enum EngineOutput<
     OnTradingDisabled(OnTradingDisabled),
     AccountDisconnect(OnDisconnect),
     PositionExit(PositionExited<QuoteAsset, InstrumentKey>),
+    RiskHalt(RiskHalt),
+    EquitySnapshot(EquitySnapshot),
+    Health(EngineHealth),
+    StaleOrders(SendRequestsOutput<RequestCancel, ExchangeKey, InstrumentKey>),
+    BalanceDrift(BalanceDrift),
+    ExchangeStatus(ExchangeStatusUpdate),
+    ShadowReport(ShadowReport),
     MarketDisconnect(OnDisconnect),
     AlgoOrders(GenerateAlgoOrdersOutput<ExchangeKey, InstrumentKey>),
+    PositionUpdate(PositionUpdate<InstrumentKey>),
+    ProtectiveStop(SendCancelsAndOpensOutput<ExchangeKey, InstrumentKey>),
 }
 
 /// Output produced by the [`Engine`] updating from an [`TradingState`], used to construct
@@ -396,6 +1094,7 @@ pub enum UpdateTradingStateOutput<OnTradingDisabled> {
 
 /// Output produced by the [`Engine`] updating from an [`AccountStreamEvent`], used to construct
 /// an `Engine` [`EngineAudit`].
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub enum UpdateFromAccountOutput<OnDisconnect, InstrumentKey = InstrumentIndex> {
     None,
@@ -405,10 +1104,13 @@ pub enum UpdateFromAccountOutput<OnDisconnect, InstrumentKey = InstrumentIndex>
 
 /// Output produced by the [`Engine`] updating from an [`MarketStreamEvent`], used to construct
 /// an `Engine` [`EngineAudit`].
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
-pub enum UpdateFromMarketOutput<OnDisconnect> {
+pub enum UpdateFromMarketOutput<OnDisconnect, InstrumentKey = InstrumentIndex> {
     None,
     OnDisconnect(OnDisconnect),
+    PositionExit(PositionExited<QuoteAsset, InstrumentKey>),
+    PositionUpdate(PositionUpdate<InstrumentKey>),
 }
 
 impl<OnTradingDisabled, OnDisconnect> From<ActionOutput>
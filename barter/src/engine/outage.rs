@@ -0,0 +1,447 @@
+use crate::{
+    engine::state::connectivity::ConnectivityStates, execution::router::SmartOrderRouter,
+    risk::RiskRefused,
+};
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    error::{ErrorAction, OrderError},
+    order::{
+        request::OrderRequestOpen,
+        state::{InactiveOrderState, OrderState},
+    },
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tracing::{info, warn};
+
+/// Defines how algorithmic open order requests for the instruments of a degraded exchange are
+/// handled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum DegradedMode {
+    /// Only open order requests that reduce an existing `Position` are sent.
+    ExitOnly,
+
+    /// No open order requests are sent.
+    Halt,
+
+    /// Open order requests are rerouted to equivalent instruments on operational exchanges via
+    /// the configured [`SmartOrderRouter`].
+    Reroute,
+}
+
+/// Configuration of the [`OutageMonitor`] used to detect degraded exchanges.
+///
+/// An exchange is deemed degraded if either its market data or account connection is
+/// reconnecting, or the rate of transient order request errors (eg/ Timeout, RateLimit) within
+/// the rolling `window` reaches the `max_error_rate`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct OutageConfig {
+    /// Rolling window over which order request error rates are measured.
+    pub window: TimeDelta,
+
+    /// Minimum number of order responses within the `window` before the error rate is used.
+    pub min_responses: usize,
+
+    /// Error rate at which an exchange is deemed degraded (eg/ `0.5` for 50%).
+    pub max_error_rate: Decimal,
+
+    /// [`DegradedMode`] applied to the instruments of a degraded exchange.
+    pub mode: DegradedMode,
+}
+
+impl Default for OutageConfig {
+    fn default() -> Self {
+        Self {
+            window: TimeDelta::minutes(1),
+            min_responses: 5,
+            max_error_rate: Decimal::new(5, 1),
+            mode: DegradedMode::ExitOnly,
+        }
+    }
+}
+
+/// Reason an exchange was deemed degraded.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum DegradedReason {
+    /// Market data or account connection is reconnecting.
+    Disconnected,
+
+    /// Order request error rate within the configured window.
+    ErrorRate(Decimal),
+}
+
+/// Operational status of an exchange.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum ExchangeStatus {
+    Operational,
+    Degraded {
+        mode: DegradedMode,
+        reason: DegradedReason,
+    },
+}
+
+/// Transition of an exchange [`ExchangeStatus`] detected by the [`OutageMonitor`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ExchangeStatusUpdate {
+    pub exchange: ExchangeIndex,
+    pub status: ExchangeStatus,
+    pub time: DateTime<Utc>,
+}
+
+/// Monitors exchange connectivity and order request error rates, switching the instruments of
+/// degraded exchanges into the configured [`DegradedMode`].
+///
+/// The current `DegradedMode` of each exchange is maintained in the [`ConnectivityStates`] so it
+/// can be enforced when generating algo orders (see [`ExchangeDegradation`]).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OutageMonitor {
+    pub config: OutageConfig,
+
+    /// [`SmartOrderRouter`] used by [`DegradedMode::Reroute`].
+    pub router: Option<SmartOrderRouter>,
+
+    /// Order responses by exchange within the rolling window, where `true` indicates an error.
+    responses: FnvHashMap<ExchangeIndex, VecDeque<(DateTime<Utc>, bool)>>,
+}
+
+impl OutageMonitor {
+    /// Construct a new `OutageMonitor` using the provided [`OutageConfig`] and optional
+    /// [`SmartOrderRouter`] (required by [`DegradedMode::Reroute`]).
+    pub fn new(config: OutageConfig, router: Option<SmartOrderRouter>) -> Self {
+        Self {
+            config,
+            router,
+            responses: FnvHashMap::default(),
+        }
+    }
+
+    /// Record any order response contained in the provided [`AccountEvent`] received at the
+    /// provided time.
+    ///
+    /// Open failures and cancel failures due to transient errors (see [`ErrorAction::Retry`])
+    /// are recorded as errors, and all other order responses as successes.
+    pub fn record_account_event(&mut self, event: &AccountEvent, time: DateTime<Utc>) {
+        let is_error = match &event.kind {
+            AccountEventKind::OrderSnapshot(snapshot) => match &snapshot.0.state {
+                OrderState::Inactive(InactiveOrderState::OpenFailed(error)) => is_transient(error),
+                _ => false,
+            },
+            AccountEventKind::OrderCancelled(response) => {
+                response.state.as_ref().is_err_and(is_transient)
+            }
+            _ => return,
+        };
+
+        self.responses
+            .entry(event.exchange)
+            .or_default()
+            .push_back((time, is_error));
+    }
+
+    /// Returns the order request error rate of the provided exchange within the rolling window
+    /// ending at the provided time.
+    ///
+    /// Returns `None` if fewer than the configured minimum number of responses were recorded.
+    pub fn error_rate(&mut self, exchange: &ExchangeIndex, time: DateTime<Utc>) -> Option<Decimal> {
+        let responses = self.responses.get_mut(exchange)?;
+
+        if let Some(time_cutoff) = time.checked_sub_signed(self.config.window) {
+            while responses
+                .front()
+                .is_some_and(|(time_response, _)| *time_response < time_cutoff)
+            {
+                responses.pop_front();
+            }
+        }
+
+        if responses.is_empty() || responses.len() < self.config.min_responses {
+            return None;
+        }
+
+        let errors = responses.iter().filter(|(_, is_error)| *is_error).count();
+        Some(Decimal::from(errors) / Decimal::from(responses.len()))
+    }
+
+    /// Determine the current [`ExchangeStatus`] of every exchange, updating the
+    /// [`ConnectivityStates`] `DegradedMode`s and returning an [`ExchangeStatusUpdate`] for each
+    /// exchange that transitioned.
+    pub fn update(
+        &mut self,
+        connectivity: &mut ConnectivityStates,
+        time: DateTime<Utc>,
+    ) -> Vec<ExchangeStatusUpdate> {
+        (0..connectivity.exchanges.len())
+            .map(ExchangeIndex)
+            .filter_map(|exchange| {
+                let state = connectivity.connectivity_index(&exchange);
+
+                let reason = if !state.all_healthy() {
+                    Some(DegradedReason::Disconnected)
+                } else {
+                    self.error_rate(&exchange, time)
+                        .filter(|rate| *rate >= self.config.max_error_rate)
+                        .map(DegradedReason::ErrorRate)
+                };
+
+                let status = match reason {
+                    Some(reason) => ExchangeStatus::Degraded {
+                        mode: self.config.mode,
+                        reason,
+                    },
+                    None => ExchangeStatus::Operational,
+                };
+
+                let degraded = match status {
+                    ExchangeStatus::Operational => None,
+                    ExchangeStatus::Degraded { mode, .. } => Some(mode),
+                };
+
+                let state = connectivity.connectivity_index_mut(&exchange);
+                if state.degraded == degraded {
+                    return None;
+                }
+
+                match status {
+                    ExchangeStatus::Operational => {
+                        info!(%exchange, "OutageMonitor exchange is operational")
+                    }
+                    ExchangeStatus::Degraded { mode, reason } => {
+                        warn!(%exchange, ?mode, ?reason, "OutageMonitor exchange is degraded")
+                    }
+                }
+
+                state.degraded = degraded;
+                Some(ExchangeStatusUpdate {
+                    exchange,
+                    status,
+                    time,
+                })
+            })
+            .collect()
+    }
+}
+
+fn is_transient<AssetKey, InstrumentKey>(error: &OrderError<AssetKey, InstrumentKey>) -> bool {
+    error.action() == ErrorAction::Retry
+}
+
+/// Defines how algorithmic open order requests for exchanges in a [`DegradedMode`] are handled.
+///
+/// # Type Parameters
+/// * `ExchangeKey` - Type used to identify an exchange (defaults to [`ExchangeIndex`]).
+/// * `InstrumentKey` - Type used to identify an instrument (defaults to [`InstrumentIndex`]).
+pub trait ExchangeDegradation<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// Apply the current `DegradedMode` of each exchange to the provided open order requests,
+    /// returning the requests to send, and those refused.
+    ///
+    /// The provided [`SmartOrderRouter`] is used by [`DegradedMode::Reroute`].
+    #[allow(clippy::type_complexity)]
+    fn apply_degraded_modes(
+        &self,
+        opens: Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+        router: Option<&SmartOrderRouter>,
+    ) -> (
+        Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::connectivity::{ConnectivityState, Health},
+        test_utils::time_plus_secs,
+    };
+    use barter_execution::{
+        error::{ApiError, ConnectivityError},
+        order::{
+            Order, OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            request::OrderResponseCancel,
+        },
+    };
+    use barter_instrument::{Side, exchange::ExchangeId};
+    use barter_integration::snapshot::Snapshot;
+    use rust_decimal_macros::dec;
+
+    fn open_failed(error: OrderError) -> AccountEvent {
+        AccountEvent::new(
+            ExchangeIndex(0),
+            AccountEventKind::OrderSnapshot(Snapshot(Order {
+                key: OrderKey {
+                    exchange: ExchangeIndex(0),
+                    instrument: InstrumentIndex(0),
+                    strategy: StrategyId::unknown(),
+                    cid: ClientOrderId::default(),
+                },
+                side: Side::Buy,
+                price: dec!(1),
+                quantity: dec!(1),
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+                state: OrderState::inactive(InactiveOrderState::OpenFailed(error)),
+            })),
+        )
+    }
+
+    fn cancel_failed(error: OrderError) -> AccountEvent {
+        AccountEvent::new(
+            ExchangeIndex(0),
+            AccountEventKind::OrderCancelled(OrderResponseCancel {
+                key: OrderKey {
+                    exchange: ExchangeIndex(0),
+                    instrument: InstrumentIndex(0),
+                    strategy: StrategyId::unknown(),
+                    cid: ClientOrderId::default(),
+                },
+                state: Err(error),
+            }),
+        )
+    }
+
+    fn connectivity(market_data: Health) -> ConnectivityStates {
+        ConnectivityStates {
+            global: Health::Healthy,
+            exchanges: [(
+                ExchangeId::BinanceSpot,
+                ConnectivityState {
+                    market_data,
+                    account: Health::Healthy,
+                    degraded: None,
+                },
+            )]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_outage_monitor_update() {
+        struct TestCase {
+            market_data: Health,
+            events: Vec<AccountEvent>,
+            expected: Option<ExchangeStatus>,
+        }
+
+        let timeout = || OrderError::Connectivity(ConnectivityError::Timeout);
+        let rejected = || OrderError::Rejected(ApiError::OrderRejected("rejected".to_string()));
+        let config = OutageConfig {
+            window: TimeDelta::seconds(10),
+            min_responses: 2,
+            max_error_rate: dec!(0.5),
+            mode: DegradedMode::Halt,
+        };
+
+        let cases = vec![
+            // TC0: healthy exchange without responses is operational
+            TestCase {
+                market_data: Health::Healthy,
+                events: vec![],
+                expected: None,
+            },
+            // TC1: reconnecting connection is degraded
+            TestCase {
+                market_data: Health::Reconnecting,
+                events: vec![],
+                expected: Some(ExchangeStatus::Degraded {
+                    mode: DegradedMode::Halt,
+                    reason: DegradedReason::Disconnected,
+                }),
+            },
+            // TC2: transient error rate below the minimum number of responses is ignored
+            TestCase {
+                market_data: Health::Healthy,
+                events: vec![open_failed(timeout())],
+                expected: None,
+            },
+            // TC3: transient error rate reaching the maximum is degraded
+            TestCase {
+                market_data: Health::Healthy,
+                events: vec![open_failed(timeout()), cancel_failed(rejected())],
+                expected: Some(ExchangeStatus::Degraded {
+                    mode: DegradedMode::Halt,
+                    reason: DegradedReason::ErrorRate(dec!(0.5)),
+                }),
+            },
+            // TC4: request specific errors are not counted
+            TestCase {
+                market_data: Health::Healthy,
+                events: vec![
+                    open_failed(rejected()),
+                    cancel_failed(rejected()),
+                    cancel_failed(timeout()),
+                ],
+                expected: None,
+            },
+        ];
+
+        let time_base = DateTime::<Utc>::MIN_UTC;
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut monitor = OutageMonitor::new(config, None);
+            let mut connectivity = connectivity(test.market_data);
+
+            for event in &test.events {
+                monitor.record_account_event(event, time_base);
+            }
+
+            let actual = monitor
+                .update(&mut connectivity, time_base)
+                .into_iter()
+                .map(|update| update.status)
+                .next();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_outage_monitor_error_rate_window_expiry() {
+        let mut monitor = OutageMonitor::new(
+            OutageConfig {
+                window: TimeDelta::seconds(10),
+                min_responses: 1,
+                max_error_rate: dec!(0.5),
+                mode: DegradedMode::ExitOnly,
+            },
+            None,
+        );
+        let mut connectivity = connectivity(Health::Healthy);
+        let time_base = DateTime::<Utc>::MIN_UTC;
+
+        monitor.record_account_event(
+            &open_failed(OrderError::Connectivity(ConnectivityError::Timeout)),
+            time_base,
+        );
+
+        let updates = monitor.update(&mut connectivity, time_base);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(
+            connectivity.connectivity_index(&ExchangeIndex(0)).degraded,
+            Some(DegradedMode::ExitOnly)
+        );
+
+        // No transition while still degraded
+        assert!(monitor.update(&mut connectivity, time_base).is_empty());
+
+        // Error expires from the rolling window
+        let updates = monitor.update(&mut connectivity, time_plus_secs(time_base, 11));
+        assert_eq!(
+            updates
+                .into_iter()
+                .map(|update| update.status)
+                .collect::<Vec<_>>(),
+            vec![ExchangeStatus::Operational]
+        );
+        assert_eq!(
+            connectivity.connectivity_index(&ExchangeIndex(0)).degraded,
+            None
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{
+    EngineEvent,
+    engine::{
+        Engine, Processor,
+        audit::EngineAudit,
+        clock::ReplayClock,
+        execution_tx::ExecutionTxMap,
+        state::{EngineState, instrument::data::InstrumentDataState},
+        wal::WalError,
+    },
+    risk::RiskManager,
+    statistic::{summary::TradingSummary, time::TimeInterval},
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_data::event::MarketEvent;
+use barter_execution::AccountEvent;
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// Summary of an `Engine` event log replay.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ReplaySummary<Interval> {
+    /// Number of events replayed.
+    pub replayed: u64,
+
+    /// [`TradingSummary`] reconstructed from the replayed events.
+    pub trading_summary: TradingSummary<Interval>,
+}
+
+/// Deterministically replay a persisted `Engine` event log (eg/ read from a
+/// [`WriteAheadLog`](super::wal::WriteAheadLog) via
+/// [`replay_with_migrations`](super::wal::replay_with_migrations)) into the provided freshly
+/// initialised `Engine`, reconstructing the `EngineState` and [`TradingSummary`] of the recorded
+/// session.
+///
+/// The [`ReplayClock`] derives the `Engine` time purely from the recorded event timestamps, so
+/// the exact event sequence of a live incident can be re-run and debugged repeatedly with
+/// identical results. The `Engine` metadata is reset to the time of the first replayed event.
+///
+/// Positions and balances are reconstructed from the recorded `AccountEvent`s (eg/ fills), so
+/// any `ExecutionRequest`s generated during the replay should be sent to a disconnected
+/// `ExecutionTxs` implementation for inspection rather than a live exchange.
+///
+/// As with a live `Engine` run, the replay stops after a recorded `Shutdown` event or an
+/// unrecoverable error that shuts down the `Engine`, ignoring any subsequently recorded events.
+pub fn replay_event_log<
+    Events,
+    Interval,
+    GlobalData,
+    InstrumentData,
+    ExecutionTxs,
+    Strategy,
+    Risk,
+>(
+    engine: &mut Engine<
+        ReplayClock,
+        EngineState<GlobalData, InstrumentData>,
+        ExecutionTxs,
+        Strategy,
+        Risk,
+    >,
+    events: Events,
+    summary_interval: Interval,
+    risk_free_return: Decimal,
+) -> Result<ReplaySummary<Interval>, WalError>
+where
+    Events: IntoIterator<Item = Result<EngineEvent<InstrumentData::MarketEventKind>, WalError>>,
+    Interval: TimeInterval,
+    InstrumentData: InstrumentDataState,
+    GlobalData: for<'a> Processor<&'a AccountEvent>
+        + for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>,
+    ExecutionTxs: ExecutionTxMap<ExchangeIndex, InstrumentIndex>,
+    Strategy: OnTradingDisabled<ReplayClock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>
+        + OnDisconnectStrategy<
+            ReplayClock,
+            EngineState<GlobalData, InstrumentData>,
+            ExecutionTxs,
+            Risk,
+        > + AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    info!("Engine event log replay starting");
+
+    let mut replayed = 0;
+    for event in events {
+        let event = event?;
+
+        if replayed == 0 {
+            engine.clock.process(&event);
+            engine.reset_metadata();
+        }
+
+        let audit = engine.process(event);
+        replayed += 1;
+
+        if let EngineAudit::Shutdown(_) = audit {
+            info!(replayed, "Engine event log replay reached Engine shutdown");
+            break;
+        }
+    }
+
+    info!(replayed, "Engine event log replay complete");
+
+    Ok(ReplaySummary {
+        replayed,
+        trading_summary: engine
+            .trading_summary_generator(risk_free_return)
+            .generate(summary_interval),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::{
+            execution_tx::MultiExchangeTxMap,
+            state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        },
+        execution::AccountStreamEvent,
+        risk::DefaultRiskManager,
+        statistic::time::Daily,
+        strategy::DefaultStrategy,
+        test_utils::time_plus_secs,
+    };
+    use barter_data::{
+        event::DataKind, streams::consumer::MarketStreamEvent, subscription::trade::PublicTrade,
+    };
+    use barter_execution::{
+        AccountEventKind,
+        order::id::{OrderId, StrategyId},
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, Underlying,
+        asset::QuoteAsset,
+        exchange::ExchangeId,
+        index::IndexedInstruments,
+        instrument::{Instrument, name::InstrumentNameInternal},
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn engine() -> Engine<
+        ReplayClock,
+        TestState,
+        MultiExchangeTxMap,
+        DefaultStrategy<TestState>,
+        DefaultRiskManager<TestState>,
+    > {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        Engine::new(
+            ReplayClock::new(DateTime::<Utc>::MIN_UTC),
+            EngineState::builder(&instruments, DefaultGlobalData, Default::default)
+                .time_engine_start(DateTime::<Utc>::MIN_UTC)
+                .build(),
+            MultiExchangeTxMap::from_iter([(ExchangeId::BinanceSpot, None)]),
+            DefaultStrategy::default(),
+            DefaultRiskManager::default(),
+        )
+    }
+
+    fn market_trade(secs: i64, price: f64) -> EngineEvent {
+        let time = time_plus_secs(DateTime::<Utc>::MIN_UTC, secs);
+        EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: secs.to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        }))
+    }
+
+    fn fill(secs: i64, side: Side, price: Decimal) -> EngineEvent {
+        EngineEvent::Account(AccountStreamEvent::Item(AccountEvent::new(
+            ExchangeIndex(0),
+            AccountEventKind::Trade(Trade {
+                id: TradeId::new(secs.to_string()),
+                order_id: OrderId::new(secs.to_string()),
+                cid: None,
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: time_plus_secs(DateTime::<Utc>::MIN_UTC, secs),
+                side,
+                price,
+                quantity: dec!(1),
+                liquidity: None,
+                fees: AssetFees::new(QuoteAsset, dec!(0.1)),
+            }),
+        )))
+    }
+
+    #[test]
+    fn test_replay_event_log() {
+        let log = [
+            market_trade(1, 100.0),
+            fill(2, Side::Buy, dec!(100)),
+            market_trade(3, 110.0),
+            fill(4, Side::Sell, dec!(110)),
+            EngineEvent::shutdown(),
+            // Recorded after the Engine shutdown, so never replayed
+            fill(6, Side::Buy, dec!(110)),
+            market_trade(7, 120.0),
+        ];
+
+        let mut engine = engine();
+        let summary =
+            replay_event_log(&mut engine, log.into_iter().map(Ok), Daily, Decimal::ZERO).unwrap();
+
+        assert_eq!(summary.replayed, 5);
+        assert_eq!(
+            engine.state.time_engine_now,
+            time_plus_secs(DateTime::<Utc>::MIN_UTC, 4)
+        );
+
+        let instrument = engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0));
+        assert!(instrument.position.current.is_none());
+        assert_eq!(instrument.data.price(), Some(dec!(110)));
+
+        // Round trip PnL: (110 - 100) * 1 - 0.1 entry fee - 0.1 exit fee
+        let tear_sheet = summary
+            .trading_summary
+            .instruments
+            .get(&InstrumentNameInternal::new("binance_spot_btc_usdt"))
+            .unwrap();
+        assert_eq!(tear_sheet.pnl, dec!(9.8));
+        assert_eq!(
+            summary.trading_summary.time_engine_end,
+            time_plus_secs(DateTime::<Utc>::MIN_UTC, 4)
+        );
+    }
+}
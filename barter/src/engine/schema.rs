@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Current schema version of serialised Barter payloads (eg/ `EngineEvent`s, `AccountEvent`s,
+/// `Position`s).
+///
+/// Must be incremented whenever a breaking change is made to a serialised payload, registering a
+/// [`SchemaMigration`] from the previous version so persisted payloads remain readable.
+///
+/// Version history:
+/// - 1: Initial [`Versioned`] payloads.
+/// - 2: [`AssetStates`](crate::engine::state::asset::AssetStates) serialised as a sequence of
+///   entries (see [`migrate_asset_states_v1`](crate::engine::state::asset::migrate_asset_states_v1)).
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Schema version of legacy payloads that were serialised without a [`Versioned`] envelope.
+pub const SCHEMA_VERSION_UNVERSIONED: u32 = 0;
+
+/// All errors generated when reading a [`Versioned`] payload.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum SchemaError {
+    #[error("schema version {0} is newer than the supported version {SCHEMA_VERSION}")]
+    Unsupported(u32),
+
+    #[error("schema migration from version {version} failed: {error}")]
+    Migration { version: u32, error: String },
+
+    #[error("schema SerDe: {0}")]
+    SerDe(String),
+}
+
+impl From<serde_json::Error> for SchemaError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::SerDe(value.to_string())
+    }
+}
+
+/// Envelope that tags a serialised payload with the [`SCHEMA_VERSION`] it was written with.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct Versioned<T> {
+    pub schema_version: u32,
+    pub payload: T,
+}
+
+impl<T> Versioned<T> {
+    /// Tag the provided payload with the current [`SCHEMA_VERSION`].
+    pub fn new(payload: T) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            payload,
+        }
+    }
+}
+
+/// Migrates a JSON payload from one schema version to the next.
+pub type SchemaMigration = fn(Value) -> Result<Value, String>;
+
+/// Registry of [`SchemaMigration`]s that upgrade persisted payloads written by a previous crate
+/// version to the current [`SCHEMA_VERSION`].
+///
+/// A schema version without a registered migration is assumed to be compatible with the next
+/// version.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaMigrations {
+    migrations: BTreeMap<u32, SchemaMigration>,
+}
+
+impl SchemaMigrations {
+    /// Register a [`SchemaMigration`] that upgrades a payload from `version` to `version + 1`.
+    pub fn register(mut self, version: u32, migration: SchemaMigration) -> Self {
+        self.migrations.insert(version, migration);
+        self
+    }
+
+    /// Upgrade a (possibly [`Versioned`]) JSON payload to the current [`SCHEMA_VERSION`],
+    /// returning the bare payload.
+    ///
+    /// Payloads without a [`Versioned`] envelope are treated as
+    /// [`SCHEMA_VERSION_UNVERSIONED`].
+    pub fn upgrade(&self, value: Value) -> Result<Value, SchemaError> {
+        let (version, payload) = unwrap_versioned(value);
+
+        if version > SCHEMA_VERSION {
+            return Err(SchemaError::Unsupported(version));
+        }
+
+        (version..SCHEMA_VERSION).try_fold(payload, |payload, version| {
+            match self.migrations.get(&version) {
+                Some(migration) => {
+                    migration(payload).map_err(|error| SchemaError::Migration { version, error })
+                }
+                None => Ok(payload),
+            }
+        })
+    }
+
+    /// Deserialise a (possibly [`Versioned`]) JSON payload, upgrading it to the current
+    /// [`SCHEMA_VERSION`] first.
+    pub fn deserialise<T>(&self, json: &str) -> Result<T, SchemaError>
+    where
+        T: DeserializeOwned,
+    {
+        let value = serde_json::from_str(json)?;
+        let payload = self.upgrade(value)?;
+        serde_json::from_value(payload).map_err(SchemaError::from)
+    }
+}
+
+/// Split a JSON value into its schema version and payload, returning
+/// [`SCHEMA_VERSION_UNVERSIONED`] if it is not wrapped in a [`Versioned`] envelope.
+fn unwrap_versioned(value: Value) -> (u32, Value) {
+    match value {
+        Value::Object(mut object)
+            if object.len() == 2
+                && object.contains_key("payload")
+                && object.get("schema_version").is_some_and(Value::is_u64) =>
+        {
+            let version = object
+                .get("schema_version")
+                .and_then(Value::as_u64)
+                .and_then(|version| u32::try_from(version).ok())
+                .unwrap_or(u32::MAX);
+            let payload = object.remove("payload").unwrap_or_default();
+            (version, payload)
+        }
+        value => (SCHEMA_VERSION_UNVERSIONED, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rename_quantity(mut value: Value) -> Result<Value, String> {
+        let object = value.as_object_mut().ok_or("expected object")?;
+        let quantity = object.remove("qty").ok_or("missing qty")?;
+        object.insert("quantity".to_string(), quantity);
+        Ok(value)
+    }
+
+    #[test]
+    fn test_schema_migrations_upgrade() {
+        struct TestCase {
+            input: Value,
+            expected: Result<Value, SchemaError>,
+        }
+
+        let migrations = SchemaMigrations::default().register(0, rename_quantity);
+
+        let cases = vec![
+            // TC0: current version payload is unwrapped
+            TestCase {
+                input: serde_json::to_value(Versioned::new(json!({"quantity": 1}))).unwrap(),
+                expected: Ok(json!({"quantity": 1})),
+            },
+            // TC1: unversioned legacy payload is migrated
+            TestCase {
+                input: json!({"qty": 1}),
+                expected: Ok(json!({"quantity": 1})),
+            },
+            // TC2: explicitly versioned legacy payload is migrated
+            TestCase {
+                input: json!({"schema_version": 0, "payload": {"qty": 1}}),
+                expected: Ok(json!({"quantity": 1})),
+            },
+            // TC3: failed migration
+            TestCase {
+                input: json!({"schema_version": 0, "payload": {"quantity": 1}}),
+                expected: Err(SchemaError::Migration {
+                    version: 0,
+                    error: "missing qty".to_string(),
+                }),
+            },
+            // TC4: newer version than supported
+            TestCase {
+                input: json!({"schema_version": SCHEMA_VERSION + 1, "payload": {"quantity": 1}}),
+                expected: Err(SchemaError::Unsupported(SCHEMA_VERSION + 1)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = migrations.upgrade(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_schema_migrations_deserialise() {
+        let migrations = SchemaMigrations::default();
+        let json = serde_json::to_string(&Versioned::new(42_u64)).unwrap();
+
+        assert_eq!(migrations.deserialise::<u64>(&json), Ok(42));
+        assert_eq!(migrations.deserialise::<u64>("42"), Ok(42));
+        assert!(matches!(
+            migrations.deserialise::<u64>("not_json"),
+            Err(SchemaError::SerDe(_))
+        ));
+    }
+}
@@ -0,0 +1,481 @@
+use barter_data::subscription::book::OrderBookL1;
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    exchange::mock::{
+        price::MockQuote,
+        slippage::{SlippageConfig, SlippageModel},
+    },
+    order::{
+        id::{ClientOrderId, OrderId},
+        request::OrderRequestOpen,
+        state::{ActiveOrderState, InactiveOrderState, OrderState},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_integration::collection::FnvIndexMap;
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the simulated execution path used by [`ShadowExecution`].
+///
+/// The fee and slippage assumptions should match those used when backtesting (see
+/// `MockExecutionConfig`), such that the reported divergence quantifies how far the backtest
+/// assumptions deviate from live execution.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ShadowConfig {
+    /// Simulated fees as a fraction of the filled notional (eg/ `0.001` for 10 bps).
+    pub fees_percent: Decimal,
+
+    /// Simulated [`SlippageModel`].
+    pub slippage: SlippageConfig,
+
+    /// Interval of `Engine` time between emitted [`ShadowReport`]s.
+    pub report_interval: TimeDelta,
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        Self {
+            fees_percent: Decimal::ZERO,
+            slippage: SlippageConfig::default(),
+            report_interval: TimeDelta::minutes(1),
+        }
+    }
+}
+
+/// Divergence of live fills from the simulated fills of the same open order requests.
+///
+/// Only the live filled quantity is compared, so unfilled live orders do not contribute to the
+/// fill price or PnL divergence.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct ShadowDivergence {
+    /// Number of recorded live fills.
+    pub fills: u64,
+
+    /// Total absolute quantity simulated as filled when the orders were sent.
+    pub quantity_simulated: Decimal,
+
+    /// Total absolute quantity filled live.
+    pub quantity_live: Decimal,
+
+    /// Total notional of the live filled quantity valued at the simulated fill prices.
+    pub value_simulated: Decimal,
+
+    /// Total notional of the live filled quantity valued at the live fill prices.
+    pub value_live: Decimal,
+
+    /// Simulated fees of the live filled quantity.
+    pub fees_simulated: Decimal,
+
+    /// Live fees paid.
+    pub fees_live: Decimal,
+
+    /// PnL of the simulated execution path minus the PnL of live execution, where a positive
+    /// divergence means live execution under-performed the simulation.
+    pub pnl_divergence: Decimal,
+}
+
+impl ShadowDivergence {
+    /// Record a live fill of the provided [`Side`] and quantity against the simulated fill price.
+    pub fn record(
+        &mut self,
+        side: Side,
+        quantity: Decimal,
+        price_simulated: Decimal,
+        price_live: Decimal,
+        fees_simulated: Decimal,
+        fees_live: Decimal,
+    ) {
+        let quantity = quantity.abs();
+        let price_divergence = match side {
+            Side::Buy => price_live - price_simulated,
+            Side::Sell => price_simulated - price_live,
+        };
+
+        self.fills += 1;
+        self.quantity_live += quantity;
+        self.value_simulated += price_simulated.abs() * quantity;
+        self.value_live += price_live.abs() * quantity;
+        self.fees_simulated += fees_simulated;
+        self.fees_live += fees_live;
+        self.pnl_divergence += price_divergence * quantity + (fees_live - fees_simulated);
+    }
+
+    /// Average simulated fill price of the live filled quantity.
+    pub fn price_simulated_mean(&self) -> Option<Decimal> {
+        (!self.quantity_live.is_zero()).then(|| self.value_simulated / self.quantity_live)
+    }
+
+    /// Average live fill price.
+    pub fn price_live_mean(&self) -> Option<Decimal> {
+        (!self.quantity_live.is_zero()).then(|| self.value_live / self.quantity_live)
+    }
+
+    /// Ratio of the live filled quantity to the simulated filled quantity.
+    pub fn fill_ratio(&self) -> Option<Decimal> {
+        (!self.quantity_simulated.is_zero()).then(|| self.quantity_live / self.quantity_simulated)
+    }
+
+    /// Combine with another `ShadowDivergence` (eg/ to aggregate instruments).
+    pub fn combine(self, other: &Self) -> Self {
+        Self {
+            fills: self.fills + other.fills,
+            quantity_simulated: self.quantity_simulated + other.quantity_simulated,
+            quantity_live: self.quantity_live + other.quantity_live,
+            value_simulated: self.value_simulated + other.value_simulated,
+            value_live: self.value_live + other.value_live,
+            fees_simulated: self.fees_simulated + other.fees_simulated,
+            fees_live: self.fees_live + other.fees_live,
+            pnl_divergence: self.pnl_divergence + other.pnl_divergence,
+        }
+    }
+}
+
+/// Periodic report of the [`ShadowDivergence`] of live execution from the simulated execution
+/// path, for the portfolio and each instrument.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ShadowReport {
+    pub time: DateTime<Utc>,
+    pub total: ShadowDivergence,
+    pub instruments: Vec<(InstrumentIndex, ShadowDivergence)>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+struct ShadowOrder {
+    quantity_remaining: Decimal,
+    price_simulated: Decimal,
+}
+
+/// Shadow mode that runs the simulated execution path alongside live execution on the same
+/// open order requests, tracking the [`ShadowDivergence`] of each instrument.
+///
+/// Each open order request sent by the `Engine` is simulated as immediately filled using the same
+/// pricing as the `MockExchange` (ie/ crossing the latest [`OrderBookL1`] spread if available,
+/// otherwise at the request price, plus the configured [`SlippageModel`] and fees). Live fills
+/// are then compared against the simulated fill price of their order.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ShadowExecution {
+    pub config: ShadowConfig,
+    pub instruments: FnvIndexMap<InstrumentIndex, ShadowDivergence>,
+    pending: FnvHashMap<ClientOrderId, ShadowOrder>,
+    acked: FnvHashMap<OrderId, ClientOrderId>,
+    time_last_report: Option<DateTime<Utc>>,
+}
+
+impl ShadowExecution {
+    /// Construct a new `ShadowExecution` using the provided [`ShadowConfig`].
+    pub fn new(config: ShadowConfig) -> Self {
+        Self {
+            config,
+            instruments: FnvIndexMap::default(),
+            pending: FnvHashMap::default(),
+            acked: FnvHashMap::default(),
+            time_last_report: None,
+        }
+    }
+
+    /// Determine the simulated fill price of the provided open order request.
+    pub fn price_simulated(
+        &self,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+        l1: Option<&OrderBookL1>,
+    ) -> Decimal {
+        let quote = l1.and_then(|l1| match (&l1.best_bid, &l1.best_ask) {
+            (Some(bid), Some(ask)) => Some(MockQuote::new(bid.price, ask.price)),
+            _ => None,
+        });
+
+        let side = request.state.side;
+        let price = quote.map_or(request.state.price, |quote| quote.price(side));
+
+        self.config
+            .slippage
+            .fill_price(side, price, request.state.quantity, quote.as_ref())
+    }
+
+    /// Simulate the fills of the provided open order requests.
+    ///
+    /// The provided closure is used to determine the latest [`OrderBookL1`] of an instrument.
+    pub fn record_sent<'a, 'b, Iter, FnL1>(&mut self, requests: Iter, l1: FnL1)
+    where
+        Iter: IntoIterator<Item = &'a OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+        FnL1: Fn(&InstrumentIndex) -> Option<&'b OrderBookL1>,
+    {
+        for request in requests {
+            let quantity = request.state.quantity.abs();
+            let price_simulated = self.price_simulated(request, l1(&request.key.instrument));
+
+            self.instruments
+                .entry(request.key.instrument)
+                .or_default()
+                .quantity_simulated += quantity;
+
+            self.pending.insert(
+                request.key.cid.clone(),
+                ShadowOrder {
+                    quantity_remaining: quantity,
+                    price_simulated,
+                },
+            );
+        }
+    }
+
+    /// Update the `ShadowExecution` from an [`AccountEvent`], comparing any live fill against
+    /// the simulated fill price of its order.
+    pub fn update_from_account_event(&mut self, event: &AccountEvent) {
+        match &event.kind {
+            AccountEventKind::OrderSnapshot(snapshot) => {
+                let order = &snapshot.0;
+                match &order.state {
+                    OrderState::Active(ActiveOrderState::Open(open)) => {
+                        if self.pending.contains_key(&order.key.cid) {
+                            self.acked.insert(open.id.clone(), order.key.cid.clone());
+                        }
+                    }
+                    OrderState::Inactive(InactiveOrderState::FullyFilled) => {}
+                    OrderState::Inactive(_) => self.remove(&order.key.cid),
+                    OrderState::Active(_) => {}
+                }
+            }
+            AccountEventKind::Trade(trade) => {
+                let Some(cid) = trade
+                    .cid
+                    .clone()
+                    .or_else(|| self.acked.get(&trade.order_id).cloned())
+                else {
+                    return;
+                };
+
+                let Some(pending) = self.pending.get_mut(&cid) else {
+                    return;
+                };
+
+                let quantity = trade.quantity.abs();
+                let fees_simulated =
+                    pending.price_simulated.abs() * quantity * self.config.fees_percent;
+
+                self.instruments
+                    .entry(trade.instrument)
+                    .or_default()
+                    .record(
+                        trade.side,
+                        quantity,
+                        pending.price_simulated,
+                        trade.price,
+                        fees_simulated,
+                        trade.fees.fees,
+                    );
+
+                pending.quantity_remaining -= quantity;
+                if pending.quantity_remaining <= Decimal::ZERO {
+                    self.remove(&cid);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn remove(&mut self, cid: &ClientOrderId) {
+        if self.pending.remove(cid).is_some() {
+            self.acked.retain(|_, acked_cid| acked_cid != cid);
+        }
+    }
+
+    /// Generate a [`ShadowReport`] if one is due at the provided `Engine` time.
+    ///
+    /// Returns `None` if the report interval has not elapsed, or no orders have been simulated.
+    pub fn generate_report(&mut self, time: DateTime<Utc>) -> Option<ShadowReport> {
+        if self.instruments.is_empty() {
+            return None;
+        }
+
+        let due = self
+            .time_last_report
+            .is_none_or(|time_last| time - time_last >= self.config.report_interval);
+        if !due {
+            return None;
+        }
+
+        self.time_last_report = Some(time);
+        Some(self.report(time))
+    }
+
+    /// Generate a [`ShadowReport`] of the tracked [`ShadowDivergence`]s.
+    pub fn report(&self, time: DateTime<Utc>) -> ShadowReport {
+        ShadowReport {
+            time,
+            total: self
+                .instruments
+                .values()
+                .fold(ShadowDivergence::default(), |total, divergence| {
+                    total.combine(divergence)
+                }),
+            instruments: self
+                .instruments
+                .iter()
+                .map(|(instrument, divergence)| (*instrument, *divergence))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use barter_data::books::Level;
+    use barter_execution::{
+        exchange::mock::slippage::FixedBps,
+        order::{OrderKey, OrderKind, TimeInForce, id::StrategyId, request::RequestOpen},
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::asset::QuoteAsset;
+    use rust_decimal_macros::dec;
+
+    fn request(cid: &str, side: Side, quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(100),
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn trade(
+        cid: &str,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        fees: Decimal,
+    ) -> AccountEvent {
+        AccountEvent::new(
+            ExchangeIndex(0),
+            AccountEventKind::Trade(Trade {
+                id: TradeId::new(cid),
+                order_id: OrderId::new(cid),
+                cid: Some(ClientOrderId::new(cid)),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side,
+                price,
+                quantity,
+                liquidity: None,
+                fees: AssetFees::new(QuoteAsset, fees),
+            }),
+        )
+    }
+
+    #[test]
+    fn test_shadow_execution_price_simulated() {
+        struct TestCase {
+            side: Side,
+            l1: Option<OrderBookL1>,
+            expected: Decimal,
+        }
+
+        let shadow = ShadowExecution::new(ShadowConfig {
+            slippage: FixedBps::new(dec!(10)).into(),
+            ..ShadowConfig::default()
+        });
+
+        let l1 = OrderBookL1 {
+            last_update_time: DateTime::<Utc>::MIN_UTC,
+            best_bid: Some(Level::new(dec!(90), dec!(1))),
+            best_ask: Some(Level::new(dec!(110), dec!(1))),
+        };
+
+        let cases = vec![
+            // TC0: Buy without an OrderBookL1 fills at the request price plus slippage
+            TestCase {
+                side: Side::Buy,
+                l1: None,
+                expected: dec!(100.1),
+            },
+            // TC1: Buy crosses the spread to the best ask
+            TestCase {
+                side: Side::Buy,
+                l1: Some(l1.clone()),
+                expected: dec!(110.11),
+            },
+            // TC2: Sell crosses the spread to the best bid
+            TestCase {
+                side: Side::Sell,
+                l1: Some(l1),
+                expected: dec!(89.91),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                shadow.price_simulated(&request("cid", test.side, dec!(1)), test.l1.as_ref());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_shadow_execution_divergence() {
+        let mut shadow = ShadowExecution::new(ShadowConfig {
+            fees_percent: dec!(0.001),
+            report_interval: TimeDelta::seconds(10),
+            ..ShadowConfig::default()
+        });
+        let time_base = DateTime::<Utc>::MIN_UTC;
+
+        shadow.record_sent(
+            [
+                &request("buy", Side::Buy, dec!(2)),
+                &request("sell", Side::Sell, dec!(1)),
+            ],
+            |_| None,
+        );
+
+        // Buy partially filled live 1 above the simulated price, with higher fees
+        shadow.update_from_account_event(&trade("buy", Side::Buy, dec!(101), dec!(1), dec!(0.2)));
+
+        // Sell filled live 2 above the simulated price, with equal fees
+        shadow.update_from_account_event(&trade("sell", Side::Sell, dec!(102), dec!(1), dec!(0.1)));
+
+        // Unknown order is ignored
+        shadow.update_from_account_event(&trade("other", Side::Buy, dec!(200), dec!(1), dec!(1)));
+
+        let report = shadow.generate_report(time_base).unwrap();
+        assert_eq!(
+            report.total,
+            ShadowDivergence {
+                fills: 2,
+                quantity_simulated: dec!(3),
+                quantity_live: dec!(2),
+                value_simulated: dec!(200),
+                value_live: dec!(203),
+                fees_simulated: dec!(0.2),
+                fees_live: dec!(0.3),
+                pnl_divergence: dec!(-0.9),
+            }
+        );
+        assert_eq!(report.total.fill_ratio(), Some(dec!(2) / dec!(3)));
+        assert_eq!(report.total.price_live_mean(), Some(dec!(101.5)));
+
+        // Report is not due until the interval has elapsed
+        assert_eq!(shadow.generate_report(time_plus_secs(time_base, 5)), None);
+        assert!(
+            shadow
+                .generate_report(time_plus_secs(time_base, 10))
+                .is_some()
+        );
+    }
+}
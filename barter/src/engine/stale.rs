@@ -0,0 +1,200 @@
+use barter_execution::order::{
+    Order, OrderKind,
+    state::{ActiveOrderState, Open},
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
+
+/// Defines when the [`Engine`](super::Engine) considers a resting limit order stale, and
+/// automatically cancels it.
+///
+/// Once cancelled, the `AlgoStrategy` is free to re-quote the order at the current market price.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct StaleOrderPolicy {
+    /// Maximum age of a resting order (ie/ `Engine` time minus the exchange open time).
+    ///
+    /// `None` disables the check.
+    pub max_age: Option<TimeDelta>,
+
+    /// Maximum relative distance of a resting order price from the instrument market price
+    /// (eg/ `0.01` for 1%).
+    ///
+    /// `None` disables the check.
+    pub max_price_distance: Option<Decimal>,
+}
+
+impl StaleOrderPolicy {
+    /// Returns true if the provided order is a resting limit order that has exceeded the
+    /// `max_age` or `max_price_distance`.
+    ///
+    /// Orders that are not yet `Open` (eg/ `OpenInFlight`), or already being cancelled, are never
+    /// considered stale.
+    pub fn is_stale<ExchangeKey, InstrumentKey>(
+        &self,
+        order: &Order<ExchangeKey, InstrumentKey, ActiveOrderState>,
+        price_market: Option<Decimal>,
+        time_now: DateTime<Utc>,
+    ) -> bool {
+        if !matches!(order.kind, OrderKind::Limit | OrderKind::Iceberg { .. }) {
+            return false;
+        }
+
+        let ActiveOrderState::Open(Open { time_exchange, .. }) = &order.state else {
+            return false;
+        };
+
+        let expired = self
+            .max_age
+            .is_some_and(|max_age| time_now - *time_exchange > max_age);
+
+        let distant = self
+            .max_price_distance
+            .zip(price_market.filter(|price| !price.is_zero()))
+            .is_some_and(|(max_distance, price)| {
+                ((order.price - price) / price).abs() > max_distance
+            });
+
+        expired || distant
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use barter_execution::order::{
+        OrderKey, TimeInForce,
+        id::{ClientOrderId, OrderId, StrategyId},
+        state::{CancelInFlight, OpenInFlight},
+    };
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    fn order(
+        kind: OrderKind,
+        price: Decimal,
+        state: ActiveOrderState,
+    ) -> Order<u64, u64, ActiveOrderState> {
+        Order {
+            key: OrderKey {
+                exchange: 0,
+                instrument: 0,
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            side: Side::Buy,
+            price,
+            quantity: dec!(1),
+            kind,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
+            state,
+        }
+    }
+
+    fn open(time_exchange: DateTime<Utc>) -> ActiveOrderState {
+        ActiveOrderState::Open(Open {
+            id: OrderId::new("id"),
+            time_exchange,
+            filled_quantity: dec!(0),
+        })
+    }
+
+    #[test]
+    fn test_stale_order_policy_is_stale() {
+        struct TestCase {
+            policy: StaleOrderPolicy,
+            order: Order<u64, u64, ActiveOrderState>,
+            price_market: Option<Decimal>,
+            expected: bool,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let time_now = time_plus_secs(base, 60);
+        let policy = StaleOrderPolicy {
+            max_age: Some(TimeDelta::seconds(30)),
+            max_price_distance: Some(dec!(0.01)),
+        };
+
+        let cases = vec![
+            // TC0: recent order close to market is not stale
+            TestCase {
+                policy,
+                order: order(OrderKind::Limit, dec!(100), open(time_plus_secs(base, 40))),
+                price_market: Some(dec!(100.5)),
+                expected: false,
+            },
+            // TC1: order older than max_age is stale
+            TestCase {
+                policy,
+                order: order(OrderKind::Limit, dec!(100), open(time_plus_secs(base, 20))),
+                price_market: Some(dec!(100)),
+                expected: true,
+            },
+            // TC2: order further than max_price_distance from market is stale
+            TestCase {
+                policy,
+                order: order(OrderKind::Limit, dec!(100), open(time_plus_secs(base, 40))),
+                price_market: Some(dec!(102)),
+                expected: true,
+            },
+            // TC3: no market price available, so only age is checked
+            TestCase {
+                policy,
+                order: order(OrderKind::Limit, dec!(100), open(time_plus_secs(base, 40))),
+                price_market: None,
+                expected: false,
+            },
+            // TC4: disabled checks never consider orders stale
+            TestCase {
+                policy: StaleOrderPolicy::default(),
+                order: order(OrderKind::Limit, dec!(100), open(base)),
+                price_market: Some(dec!(200)),
+                expected: false,
+            },
+            // TC5: OpenInFlight order is not stale
+            TestCase {
+                policy,
+                order: order(
+                    OrderKind::Limit,
+                    dec!(100),
+                    ActiveOrderState::OpenInFlight(OpenInFlight),
+                ),
+                price_market: Some(dec!(200)),
+                expected: false,
+            },
+            // TC6: CancelInFlight order is not stale
+            TestCase {
+                policy,
+                order: order(
+                    OrderKind::Limit,
+                    dec!(100),
+                    ActiveOrderState::CancelInFlight(CancelInFlight { order: None }),
+                ),
+                price_market: Some(dec!(200)),
+                expected: false,
+            },
+            // TC7: resting StopMarket order is not stale
+            TestCase {
+                policy,
+                order: order(
+                    OrderKind::StopMarket {
+                        trigger_price: dec!(100),
+                        trigger: Default::default(),
+                    },
+                    dec!(100),
+                    open(base),
+                ),
+                price_market: Some(dec!(200)),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test
+                .policy
+                .is_stale(&test.order, test.price_market, time_now);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,479 @@
+use crate::engine::state::{
+    EngineState,
+    instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+};
+use barter_instrument::{
+    Side,
+    asset::{ExchangeAsset, name::AssetNameInternal},
+    instrument::name::InstrumentNameInternal,
+};
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Source of conversion rates used to value assets in a reporting currency.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FxRateSource {
+    /// Use the latest market price of an instrument that trades the asset against the reporting
+    /// currency (eg/ "btc_usdt" to value "btc" in "usdt"), or the inverse instrument.
+    LatestPrices,
+
+    /// Use fixed conversion rates, where each rate is the value of one unit of the asset in the
+    /// reporting currency.
+    Fixed(FnvHashMap<AssetNameInternal, Decimal>),
+}
+
+/// Total equity of every [`AssetState`](super::AssetState) balance, valued in a single
+/// reporting currency.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct Equity {
+    /// Total equity valued in the reporting currency.
+    pub total: Decimal,
+
+    /// Exchange assets with a non-zero balance that could not be valued due to a missing
+    /// conversion rate, and so are excluded from the `total`.
+    pub unconverted: Vec<ExchangeAsset<AssetNameInternal>>,
+}
+
+/// Timestamped snapshot of portfolio equity valued in a single reporting currency, used to
+/// build an equity curve.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct EquitySnapshot {
+    /// Time the `EquitySnapshot` was taken.
+    pub time: DateTime<Utc>,
+
+    /// Total value of all asset balances.
+    pub cash: Decimal,
+
+    /// Total signed notional value of all open positions (negative for SHORT positions).
+    pub positions_value: Decimal,
+
+    /// Total unrealised PnL of all open positions.
+    pub pnl_unrealised: Decimal,
+
+    /// Assets that could not be valued due to a missing conversion rate (or position price), and
+    /// so are excluded.
+    pub unconverted: Vec<AssetNameInternal>,
+}
+
+/// Realised and unrealised PnL denominated in a single currency.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct PnL {
+    /// PnL of exited positions, plus the realised PnL (eg/ fees) of any open position.
+    pub realised: Decimal,
+
+    /// PnL of any open position, valued at the latest market price.
+    pub unrealised: Decimal,
+}
+
+/// [`PnL`] of an instrument, in both the instrument quote currency and the reporting currency.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct InstrumentPnL {
+    pub instrument: InstrumentNameInternal,
+
+    /// Instrument quote asset the `quote` [`PnL`] is denominated in.
+    pub quote_asset: AssetNameInternal,
+
+    /// [`PnL`] denominated in the instrument quote currency.
+    pub quote: PnL,
+
+    /// [`PnL`] converted into the reporting currency, or `None` if the `quote_asset` could not
+    /// be converted due to a missing conversion rate.
+    pub reporting: Option<PnL>,
+}
+
+/// Realised and unrealised PnL of every instrument, aggregated in a single reporting currency.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize)]
+pub struct PnLReport {
+    /// Per-instrument [`InstrumentPnL`], in both quote and reporting currency.
+    pub instruments: Vec<InstrumentPnL>,
+
+    /// Total [`PnL`] of all instruments converted into the reporting currency.
+    pub total: PnL,
+
+    /// Quote assets that could not be converted due to a missing conversion rate, and so are
+    /// excluded from the `total`.
+    pub unconverted: Vec<AssetNameInternal>,
+}
+
+/// Calculates the total [`Equity`] of multi-currency asset balances in a reporting currency,
+/// using the configured [`FxRateSource`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Constructor)]
+pub struct EquityCalculator {
+    /// Currency that [`Equity`] is valued in (eg/ "usdt").
+    pub reporting: AssetNameInternal,
+
+    /// Source of conversion rates into the `reporting` currency.
+    pub source: FxRateSource,
+}
+
+impl EquityCalculator {
+    /// Determine the conversion rate of one unit of the provided asset into the `reporting`
+    /// currency.
+    pub fn rate<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        asset: &AssetNameInternal,
+    ) -> Option<Decimal>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        if *asset == self.reporting {
+            return Some(Decimal::ONE);
+        }
+
+        match &self.source {
+            FxRateSource::Fixed(rates) => rates.get(asset).copied(),
+            FxRateSource::LatestPrices => state
+                .instruments
+                .instruments(&InstrumentFilter::None)
+                .find_map(|instrument_state| {
+                    let underlying = &instrument_state.instrument.underlying;
+                    let base = &state.assets.asset_index(&underlying.base).asset;
+                    let quote = &state.assets.asset_index(&underlying.quote).asset;
+
+                    if base.name_internal == *asset && quote.name_internal == self.reporting {
+                        instrument_state.data.price()
+                    } else if base.name_internal == self.reporting && quote.name_internal == *asset
+                    {
+                        instrument_state
+                            .data
+                            .price()
+                            .filter(|price| !price.is_zero())
+                            .map(|price| Decimal::ONE / price)
+                    } else {
+                        None
+                    }
+                }),
+        }
+    }
+
+    /// Calculate the total [`Equity`] of all `EngineState` asset balances in the `reporting`
+    /// currency.
+    pub fn equity<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+    ) -> Equity
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        calculate_equity(
+            state.assets.0.iter().filter_map(|(key, asset_state)| {
+                asset_state
+                    .balance
+                    .as_ref()
+                    .map(|balance| (key, balance.value.total))
+            }),
+            |asset| self.rate(state, asset),
+        )
+    }
+
+    /// Generate an [`EquitySnapshot`] of all `EngineState` asset balances and open positions,
+    /// valued in the `reporting` currency.
+    ///
+    /// Open position values are converted using the rate of their instrument quote asset.
+    pub fn snapshot<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        time: DateTime<Utc>,
+    ) -> EquitySnapshot
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let Equity { total, unconverted } = self.equity(state);
+
+        let mut snapshot = EquitySnapshot {
+            time,
+            cash: total,
+            positions_value: Decimal::ZERO,
+            pnl_unrealised: Decimal::ZERO,
+            unconverted: unconverted.into_iter().map(|key| key.asset).collect(),
+        };
+
+        for instrument_state in state.instruments.instruments(&InstrumentFilter::None) {
+            let Some(position) = &instrument_state.position.current else {
+                continue;
+            };
+
+            let quote = &state
+                .assets
+                .asset_index(&instrument_state.instrument.underlying.quote)
+                .asset
+                .name_internal;
+
+            let (Some(rate), Some(price)) =
+                (self.rate(state, quote), instrument_state.data.price())
+            else {
+                if !snapshot.unconverted.contains(quote) {
+                    snapshot.unconverted.push(quote.clone());
+                }
+                continue;
+            };
+
+            let value = position.quantity_abs * price * rate;
+            snapshot.positions_value += match position.side {
+                Side::Buy => value,
+                Side::Sell => -value,
+            };
+            snapshot.pnl_unrealised += position.pnl_unrealised * rate;
+        }
+
+        snapshot
+    }
+
+    /// Generate a [`PnLReport`] of every `EngineState` instrument, reporting realised and
+    /// unrealised PnL in both the instrument quote currency and the `reporting` currency.
+    ///
+    /// Realised PnL is the sum of all exited positions (see `TearSheetGenerator`) plus the
+    /// realised PnL of any open position. Note that all PnL is converted using the current
+    /// rate of the quote asset, rather than the rate at the time it was realised.
+    pub fn pnl<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+    ) -> PnLReport
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        calculate_pnl(
+            state
+                .instruments
+                .instruments(&InstrumentFilter::None)
+                .map(|instrument_state| {
+                    let quote = state
+                        .assets
+                        .asset_index(&instrument_state.instrument.underlying.quote)
+                        .asset
+                        .name_internal
+                        .clone();
+
+                    let current = instrument_state.position.current.as_ref();
+                    let pnl = PnL {
+                        realised: instrument_state.tear_sheet.pnl_returns.pnl_raw
+                            + current.map_or(Decimal::ZERO, |position| position.pnl_realised),
+                        unrealised: current
+                            .map_or(Decimal::ZERO, |position| position.pnl_unrealised),
+                    };
+
+                    (
+                        instrument_state.instrument.name_internal.clone(),
+                        quote,
+                        pnl,
+                    )
+                }),
+            |asset| self.rate(state, asset),
+        )
+    }
+}
+
+/// Calculate the total [`Equity`] of the provided exchange asset balances, using the provided
+/// conversion rate lookup.
+pub fn calculate_equity<'a, BalanceIter, FnRate>(balances: BalanceIter, rate: FnRate) -> Equity
+where
+    BalanceIter: IntoIterator<Item = (&'a ExchangeAsset<AssetNameInternal>, Decimal)>,
+    FnRate: Fn(&AssetNameInternal) -> Option<Decimal>,
+{
+    balances
+        .into_iter()
+        .filter(|(_, balance)| !balance.is_zero())
+        .fold(Equity::default(), |mut equity, (key, balance)| {
+            match rate(&key.asset) {
+                Some(rate) => equity.total += balance * rate,
+                None => equity.unconverted.push(key.clone()),
+            }
+            equity
+        })
+}
+
+/// Calculate a [`PnLReport`] from the provided instrument quote currency [`PnL`]s, using the
+/// provided conversion rate lookup.
+pub fn calculate_pnl<PnLIter, FnRate>(instruments: PnLIter, rate: FnRate) -> PnLReport
+where
+    PnLIter: IntoIterator<Item = (InstrumentNameInternal, AssetNameInternal, PnL)>,
+    FnRate: Fn(&AssetNameInternal) -> Option<Decimal>,
+{
+    instruments.into_iter().fold(
+        PnLReport::default(),
+        |mut report, (instrument, quote_asset, quote)| {
+            let reporting = rate(&quote_asset).map(|rate| PnL {
+                realised: quote.realised * rate,
+                unrealised: quote.unrealised * rate,
+            });
+
+            match reporting {
+                Some(reporting) => {
+                    report.total.realised += reporting.realised;
+                    report.total.unrealised += reporting.unrealised;
+                }
+                None if quote == PnL::default() => {}
+                None => {
+                    if !report.unconverted.contains(&quote_asset) {
+                        report.unconverted.push(quote_asset.clone());
+                    }
+                }
+            }
+
+            report.instruments.push(InstrumentPnL {
+                instrument,
+                quote_asset,
+                quote,
+                reporting,
+            });
+
+            report
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+    use rust_decimal_macros::dec;
+
+    fn exchange_asset(asset: &str) -> ExchangeAsset<AssetNameInternal> {
+        ExchangeAsset::new(ExchangeId::Simulated, AssetNameInternal::new(asset))
+    }
+
+    #[test]
+    fn test_calculate_equity() {
+        struct TestCase {
+            balances: Vec<(ExchangeAsset<AssetNameInternal>, Decimal)>,
+            expected: Equity,
+        }
+
+        let rates = FnvHashMap::from_iter([
+            (AssetNameInternal::new("usdt"), dec!(1.0)),
+            (AssetNameInternal::new("btc"), dec!(50_000.0)),
+            (AssetNameInternal::new("eur"), dec!(1.1)),
+        ]);
+
+        let cases = vec![
+            // TC0: single reporting currency balance
+            TestCase {
+                balances: vec![(exchange_asset("usdt"), dec!(1_000.0))],
+                expected: Equity {
+                    total: dec!(1_000.0),
+                    unconverted: vec![],
+                },
+            },
+            // TC1: multiple currency balances converted
+            TestCase {
+                balances: vec![
+                    (exchange_asset("usdt"), dec!(1_000.0)),
+                    (exchange_asset("btc"), dec!(0.5)),
+                    (exchange_asset("eur"), dec!(100.0)),
+                ],
+                expected: Equity {
+                    total: dec!(26_110.0),
+                    unconverted: vec![],
+                },
+            },
+            // TC2: balance without a conversion rate is excluded
+            TestCase {
+                balances: vec![
+                    (exchange_asset("usdt"), dec!(1_000.0)),
+                    (exchange_asset("eth"), dec!(1.0)),
+                ],
+                expected: Equity {
+                    total: dec!(1_000.0),
+                    unconverted: vec![exchange_asset("eth")],
+                },
+            },
+            // TC3: zero balance without a conversion rate is ignored
+            TestCase {
+                balances: vec![(exchange_asset("eth"), dec!(0.0))],
+                expected: Equity::default(),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = calculate_equity(
+                test.balances.iter().map(|(key, balance)| (key, *balance)),
+                |asset| rates.get(asset).copied(),
+            );
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_calculate_pnl() {
+        struct TestCase {
+            instruments: Vec<(&'static str, &'static str, PnL)>,
+            expected_total: PnL,
+            expected_unconverted: Vec<AssetNameInternal>,
+        }
+
+        let rates = FnvHashMap::from_iter([
+            (AssetNameInternal::new("usdt"), dec!(1.0)),
+            (AssetNameInternal::new("btc"), dec!(50_000.0)),
+            (AssetNameInternal::new("eur"), dec!(1.1)),
+        ]);
+
+        let pnl = |realised, unrealised| PnL {
+            realised,
+            unrealised,
+        };
+
+        let cases = vec![
+            // TC0: reporting currency PnL is unchanged
+            TestCase {
+                instruments: vec![("btc_usdt", "usdt", pnl(dec!(100.0), dec!(-10.0)))],
+                expected_total: pnl(dec!(100.0), dec!(-10.0)),
+                expected_unconverted: vec![],
+            },
+            // TC1: mixed quote currency PnL aggregated in reporting currency
+            TestCase {
+                instruments: vec![
+                    ("btc_usdt", "usdt", pnl(dec!(100.0), dec!(-10.0))),
+                    ("eth_btc", "btc", pnl(dec!(0.01), dec!(0.002))),
+                    ("btc_eur", "eur", pnl(dec!(-50.0), dec!(20.0))),
+                ],
+                expected_total: pnl(dec!(545.0), dec!(112.0)),
+                expected_unconverted: vec![],
+            },
+            // TC2: PnL without a conversion rate is excluded from the total
+            TestCase {
+                instruments: vec![
+                    ("btc_usdt", "usdt", pnl(dec!(100.0), dec!(0.0))),
+                    ("sol_gbp", "gbp", pnl(dec!(5.0), dec!(1.0))),
+                ],
+                expected_total: pnl(dec!(100.0), dec!(0.0)),
+                expected_unconverted: vec![AssetNameInternal::new("gbp")],
+            },
+            // TC3: zero PnL without a conversion rate is ignored
+            TestCase {
+                instruments: vec![("sol_gbp", "gbp", PnL::default())],
+                expected_total: PnL::default(),
+                expected_unconverted: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = calculate_pnl(
+                test.instruments.iter().map(|(instrument, quote, pnl)| {
+                    (
+                        InstrumentNameInternal::new(*instrument),
+                        AssetNameInternal::new(*quote),
+                        *pnl,
+                    )
+                }),
+                |asset| rates.get(asset).copied(),
+            );
+
+            assert_eq!(actual.total, test.expected_total, "TC{index} failed");
+            assert_eq!(
+                actual.unconverted, test.expected_unconverted,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                actual.instruments.len(),
+                test.instruments.len(),
+                "TC{index} failed"
+            );
+        }
+    }
+}
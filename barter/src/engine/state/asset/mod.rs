@@ -2,7 +2,10 @@ use crate::{
     Timed, engine::state::asset::filter::AssetFilter,
     statistic::summary::asset::TearSheetAssetGenerator,
 };
-use barter_execution::balance::{AssetBalance, Balance};
+use barter_execution::{
+    balance::{AssetBalance, Balance},
+    funding::FundingPayment,
+};
 use barter_instrument::{
     asset::{
         Asset, AssetIndex, ExchangeAsset,
@@ -11,20 +14,53 @@ use barter_instrument::{
     index::IndexedInstruments,
 };
 use barter_integration::{collection::FnvIndexMap, snapshot::Snapshot};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use itertools::Either;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fmt::Debug;
 
 /// Defines an `AssetFilter`, used to filter asset-centric data structures.
 pub mod filter;
 
+/// Multi-currency [`Equity`](equity::Equity) valuation using a configurable
+/// [`FxRateSource`](equity::FxRateSource) (eg/ latest market prices or fixed rates).
+pub mod equity;
+
 /// Collection of exchange [`AssetState`]s indexed by [`AssetIndex`].
 ///
 /// Note that the same named assets on different exchanges will have their own [`AssetState`].
+///
+/// Serialised as a sequence of entries, since the [`ExchangeAsset`] keys cannot be used as keys
+/// by formats such as JSON.
 #[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
-pub struct AssetStates(pub FnvIndexMap<ExchangeAsset<AssetNameInternal>, AssetState>);
+pub struct AssetStates(
+    #[serde(with = "indexmap::map::serde_seq")]
+    pub  FnvIndexMap<ExchangeAsset<AssetNameInternal>, AssetState>,
+);
+
+/// [`SchemaMigration`](crate::engine::schema::SchemaMigration) of serialised [`AssetStates`]
+/// from schema version 1, when they were serialised as a map keyed by JSON encoded
+/// [`ExchangeAsset`]s, to the sequence of entries used since schema version 2.
+///
+/// [`AssetStates`] that are already serialised as a sequence are returned unchanged.
+pub fn migrate_asset_states_v1(value: Value) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, state)| {
+                serde_json::from_str::<Value>(&key)
+                    .map(|key| Value::Array(vec![key, state]))
+                    .map_err(|error| format!("invalid AssetStates key {key}: {error}"))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Array(entries) => Ok(Value::Array(entries)),
+        other => Err(format!("expected AssetStates map, found: {other}")),
+    }
+}
 
 impl AssetStates {
     /// Return a reference to the `AssetState` associated with an `AssetIndex`.
@@ -125,6 +161,31 @@ impl AssetState {
             self.statistics.update_from_balance(snapshot);
         }
     }
+
+    /// Books a [`FundingPayment`] against the `AssetState` balance.
+    ///
+    /// The payment `amount` is added to both the total and free balance.
+    pub fn update_from_funding_payment<AssetKey, InstrumentKey>(
+        &mut self,
+        payment: &FundingPayment<AssetKey, InstrumentKey>,
+    ) {
+        self.update_from_balance_change(payment.amount, payment.time_exchange);
+    }
+
+    /// Books a balance change (eg/ the realised PnL of a simulated liquidation) against the
+    /// `AssetState` balance.
+    ///
+    /// The `amount` is added to both the total and free balance.
+    pub fn update_from_balance_change(&mut self, amount: Decimal, time_exchange: DateTime<Utc>) {
+        let (balance, time) = match &self.balance {
+            Some(balance) => (balance.value, balance.time.max(time_exchange)),
+            None => (Balance::default(), time_exchange),
+        };
+
+        let balance = Balance::new(balance.total + amount, balance.free + amount);
+
+        self.update_from_balance(Snapshot(&AssetBalance::new((), balance, time)));
+    }
 }
 
 impl From<&AssetState> for AssetBalance<AssetNameExchange> {
@@ -176,8 +237,8 @@ pub fn generate_empty_indexed_asset_states(instruments: &IndexedInstruments) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::asset_state;
-    use barter_instrument::asset::name::AssetNameExchange;
+    use crate::test_utils::{asset_state, time_plus_secs};
+    use barter_instrument::{asset::name::AssetNameExchange, exchange::ExchangeId};
     use chrono::{DateTime, TimeZone, Utc};
     use rust_decimal_macros::dec;
 
@@ -282,4 +343,52 @@ mod tests {
 
         assert_eq!(state, expected)
     }
+
+    #[test]
+    fn test_update_from_funding_payment() {
+        let time = time_plus_secs(DateTime::<Utc>::MIN_UTC, 60);
+        let mut state = asset_state("usdt", 1000.0, 900.0, DateTime::<Utc>::MIN_UTC);
+
+        let payment = FundingPayment::new((), (), dec!(-5.0), Some(dec!(0.0001)), time);
+
+        state.update_from_funding_payment(&payment);
+
+        assert_eq!(state.balance.unwrap().value.total, dec!(995.0));
+        assert_eq!(state.balance.unwrap().value.free, dec!(895.0));
+        assert_eq!(state.balance.unwrap().time, time);
+    }
+
+    #[test]
+    fn test_migrate_asset_states_v1() {
+        let key = ExchangeAsset::new(ExchangeId::BinanceSpot, "btc");
+        let state = asset_state("btc", 1000.0, 900.0, DateTime::<Utc>::MIN_UTC);
+        let expected = AssetStates(FnvIndexMap::from_iter([(key.clone(), state.clone())]));
+
+        // TC0: version 1 map keyed by JSON encoded ExchangeAssets
+        let legacy = Value::Object(serde_json::Map::from_iter([(
+            serde_json::to_string(&key).unwrap(),
+            serde_json::to_value(&state).unwrap(),
+        )]));
+        let actual = migrate_asset_states_v1(legacy).unwrap();
+        assert_eq!(
+            serde_json::from_value::<AssetStates>(actual).unwrap(),
+            expected,
+            "TC0 failed"
+        );
+
+        // TC1: current sequence of entries is unchanged
+        let current = serde_json::to_value(&expected).unwrap();
+        assert_eq!(
+            migrate_asset_states_v1(current.clone()),
+            Ok(current),
+            "TC1 failed"
+        );
+
+        // TC2: invalid key
+        let invalid = Value::Object(serde_json::Map::from_iter([(
+            "btc".to_string(),
+            serde_json::to_value(&state).unwrap(),
+        )]));
+        assert!(migrate_asset_states_v1(invalid).is_err(), "TC2 failed");
+    }
 }
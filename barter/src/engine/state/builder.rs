@@ -1,7 +1,10 @@
 use crate::engine::state::{
-    EngineState, asset::generate_empty_indexed_asset_states,
+    EngineState,
+    asset::generate_empty_indexed_asset_states,
     connectivity::generate_empty_indexed_connectivity_states,
-    instrument::generate_indexed_instrument_states, order::Orders, position::PositionManager,
+    instrument::generate_indexed_instrument_states,
+    order::Orders,
+    position::{BreakEvenStop, MarginRequirement, PositionManager, TrailingStop},
     trading::TradingState,
 };
 use barter_execution::balance::{AssetBalance, Balance};
@@ -9,10 +12,12 @@ use barter_instrument::{
     Keyed,
     asset::{ExchangeAsset, name::AssetNameInternal},
     index::IndexedInstruments,
+    instrument::{kind::InstrumentKind, name::InstrumentNameInternal},
 };
 use barter_integration::snapshot::Snapshot;
 use chrono::{DateTime, Utc};
 use fnv::FnvHashMap;
+use rust_decimal::Decimal;
 use tracing::debug;
 
 /// Builder utility for an [`EngineState`] instance.
@@ -23,6 +28,10 @@ pub struct EngineStateBuilder<'a, GlobalData, FnInstrumentData> {
     time_engine_start: Option<DateTime<Utc>>,
     global: GlobalData,
     balances: FnvHashMap<ExchangeAsset<AssetNameInternal>, Balance>,
+    borrow_rates: FnvHashMap<ExchangeAsset<AssetNameInternal>, Decimal>,
+    margins: FnvHashMap<InstrumentNameInternal, MarginRequirement>,
+    trailing_stop: Option<TrailingStop>,
+    break_even_stop: Option<BreakEvenStop>,
     instrument_data_init: FnInstrumentData,
 }
 
@@ -45,6 +54,10 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
             trading_state: None,
             global,
             balances: FnvHashMap::default(),
+            borrow_rates: FnvHashMap::default(),
+            margins: FnvHashMap::default(),
+            trailing_stop: None,
+            break_even_stop: None,
             instrument_data_init,
         }
     }
@@ -92,6 +105,62 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         self
     }
 
+    /// Optionally provide annual borrow rates (eg/ 0.05 for 5%) for exchange assets.
+    ///
+    /// SHORT `Spot` positions accrue borrow cost using the rate of the instrument base asset.
+    ///
+    /// Note the internal implementation uses a `HashMap`, so duplicate
+    /// `ExchangeAsset<AssetNameInternal>` keys are overwritten.
+    pub fn borrow_rates<RateIter, KeyedRate>(mut self, rates: RateIter) -> Self
+    where
+        RateIter: IntoIterator<Item = KeyedRate>,
+        KeyedRate: Into<Keyed<ExchangeAsset<AssetNameInternal>, Decimal>>,
+    {
+        self.borrow_rates.extend(rates.into_iter().map(|keyed| {
+            let Keyed { key, value } = keyed.into();
+
+            (key, value)
+        }));
+        self
+    }
+
+    /// Optionally provide simulated [`MarginRequirement`]s for leveraged instruments.
+    ///
+    /// Open `Position`s that breach their maintenance margin are liquidated on market updates.
+    ///
+    /// Note the internal implementation uses a `HashMap`, so duplicate `InstrumentNameInternal`
+    /// keys are overwritten.
+    pub fn margins<MarginIter, KeyedMargin>(mut self, margins: MarginIter) -> Self
+    where
+        MarginIter: IntoIterator<Item = KeyedMargin>,
+        KeyedMargin: Into<Keyed<InstrumentNameInternal, MarginRequirement>>,
+    {
+        self.margins.extend(margins.into_iter().map(|keyed| {
+            let Keyed { key, value } = keyed.into();
+
+            (key, value)
+        }));
+        self
+    }
+
+    /// Optionally provide a [`TrailingStop`] configuration that is attached to every newly
+    /// opened `Position`.
+    pub fn trailing_stop(self, value: TrailingStop) -> Self {
+        Self {
+            trailing_stop: Some(value),
+            ..self
+        }
+    }
+
+    /// Optionally provide a [`BreakEvenStop`] configuration that is attached to every newly
+    /// opened `Position`.
+    pub fn break_even_stop(self, value: BreakEvenStop) -> Self {
+        Self {
+            break_even_stop: Some(value),
+            ..self
+        }
+    }
+
     /// Use the builder data to generate the associated [`EngineState`].
     ///
     /// If optional data is not provided (eg/ Balances), default values are used (eg/ zero Balance).
@@ -105,6 +174,10 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
             trading_state,
             global,
             balances,
+            borrow_rates,
+            margins,
+            trailing_stop,
+            break_even_stop,
             instrument_data_init,
         } = self;
 
@@ -131,16 +204,36 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         }
 
         // Generate empty InstrumentStates using provided FnInstrumentData etc.
-        let instruments = generate_indexed_instrument_states(
+        let mut instruments = generate_indexed_instrument_states(
             instruments,
             time_engine_start,
-            PositionManager::default,
+            || PositionManager::new(None, trailing_stop.clone(), break_even_stop.clone()),
             Orders::default,
             instrument_data_init,
         );
 
+        // Apply provided borrow rates to Spot instruments using their base asset
+        for state in instruments.0.values_mut() {
+            if !matches!(state.instrument.kind, InstrumentKind::Spot) {
+                continue;
+            }
+
+            let base = state.instrument.underlying.base;
+            state.borrow_rate = assets
+                .0
+                .get_index(base.index())
+                .and_then(|(key, _)| borrow_rates.get(key))
+                .copied();
+        }
+
+        // Apply provided MarginRequirements to leveraged instruments
+        for (name, state) in instruments.0.iter_mut() {
+            state.margin = margins.get(name).copied();
+        }
+
         EngineState {
             trading,
+            time_engine_now: time_engine_start,
             global,
             connectivity,
             assets,
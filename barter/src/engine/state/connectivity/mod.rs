@@ -1,3 +1,4 @@
+use crate::engine::outage::DegradedMode;
 use barter_instrument::{
     exchange::{ExchangeId, ExchangeIndex},
     index::IndexedInstruments,
@@ -95,6 +96,15 @@ impl ConnectivityStates {
         }
     }
 
+    /// Returns true if every exchange account connection is [`Health::Healthy`].
+    ///
+    /// Since each (re)connected `AccountStream` yields an `AccountSnapshot` first, this indicates
+    /// the `EngineState` balances, positions & orders are synchronised with every exchange.
+    pub fn all_accounts_synced(&self) -> bool {
+        self.exchange_states()
+            .all(|state| state.account == Health::Healthy)
+    }
+
     /// Returns a reference to the `ConnectivityState` associated with the
     /// provided `ExchangeIndex`.
     ///
@@ -174,6 +184,9 @@ pub struct ConnectivityState {
 
     /// Status of the account and execution connection.
     pub account: Health,
+
+    /// [`DegradedMode`] of the exchange if it has been deemed degraded by the `OutageMonitor`.
+    pub degraded: Option<DegradedMode>,
 }
 
 impl ConnectivityState {
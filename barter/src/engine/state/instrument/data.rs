@@ -11,10 +11,11 @@ use barter_execution::{
     order::request::{OrderRequestCancel, OrderRequestOpen},
 };
 use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_more::Constructor;
 use rust_decimal::{Decimal, prelude::FromPrimitive};
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use std::{collections::VecDeque, fmt::Debug, time::Duration};
 
 /// Defines a state object for tracking and managing custom instrument level data.
 ///
@@ -49,10 +50,66 @@ pub trait InstrumentDataState<
     /// - Volume-weighted mid-price from an `OrderBookL1`.
     /// - Volume-weighted mid-price from an `OrderBookL2`.
     fn price(&self) -> Option<Decimal>;
+
+    /// Latest derivative mark price for an instrument, if available.
+    ///
+    /// If available, the mark price is used to calculate the unrealised PnL of derivative
+    /// positions, since it is the price exchanges use to value positions (eg/ for liquidation).
+    fn price_mark(&self) -> Option<Decimal> {
+        None
+    }
+
+    /// Perpetual funding rate delivered by the provided [`MarketEvent`], if any.
+    ///
+    /// If available, the funding rate is applied to any open derivative `Position` (eg/ from
+    /// historical funding rates interleaved with backtest market data).
+    ///
+    /// This is called before the [`MarketEvent`] is processed, so implementations should ignore
+    /// funding rates at or before the last applied funding rate time (eg/ duplicates), since
+    /// each returned funding rate is charged.
+    fn funding_rate(
+        &self,
+        _: &MarketEvent<InstrumentKey, Self::MarketEventKind>,
+    ) -> Option<Decimal> {
+        None
+    }
+
+    /// Latest annual borrow rate (eg/ 0.05 for 5%) for an instrument, if available.
+    ///
+    /// If available, this is used in preference to any constant `InstrumentState` borrow rate.
+    fn borrow_rate(&self) -> Option<Decimal> {
+        None
+    }
+
+    /// Latest [`OrderBookL1`] (ie/ best bid & ask) for an instrument, if available.
+    ///
+    /// Used to determine the current bid-ask spread (eg/ by a `SpreadGuardRiskManager`).
+    fn l1(&self) -> Option<&OrderBookL1> {
+        None
+    }
+
+    /// Returns true if a cluster of large liquidations has recently been observed for the
+    /// instrument, warning strategies and risk managers of elevated volatility.
+    fn is_liquidation_cluster(&self) -> bool {
+        false
+    }
+
+    /// Reset any warm indicator state (eg/ price history), such as when the strategy parameters
+    /// are updated without a warm start.
+    fn reset_indicators(&mut self) {}
+
+    /// Initialise empty data state for an instrument added to a running `Engine`, using this
+    /// existing instrument data state as a configuration template (eg/ indicator periods).
+    ///
+    /// Returns `None` if adding instruments at runtime is not supported (default).
+    fn init_from_template(&self) -> Option<Self> {
+        None
+    }
 }
 
-/// Basic [`InstrumentDataState`] implementation that tracks the [`OrderBookL1`] and last traded
-/// price for an instrument.
+/// Basic [`InstrumentDataState`] implementation that tracks the [`OrderBookL1`], last traded
+/// price, last candle close price, mark price, funding rate, and borrow rate for an instrument, as well as
+/// recent liquidations if a [`LiquidationMonitor`] is configured.
 ///
 /// This is a simple example of instrument level data. Trading strategies typically maintain more
 /// comprehensive data, such as candles, technical indicators, market depth (L2 book), volatility metrics,
@@ -63,6 +120,11 @@ pub trait InstrumentDataState<
 pub struct DefaultInstrumentMarketData {
     pub l1: OrderBookL1,
     pub last_traded_price: Option<Timed<Decimal>>,
+    pub last_candle_close: Option<Timed<Decimal>>,
+    pub mark_price: Option<Timed<Decimal>>,
+    pub funding_rate: Option<Timed<Decimal>>,
+    pub borrow_rate: Option<Timed<Decimal>>,
+    pub liquidations: Option<LiquidationMonitor>,
 }
 
 impl InstrumentDataState for DefaultInstrumentMarketData {
@@ -72,6 +134,49 @@ impl InstrumentDataState for DefaultInstrumentMarketData {
         self.l1
             .volume_weighed_mid_price()
             .or(self.last_traded_price.as_ref().map(|timed| timed.value))
+            .or(self.last_candle_close.as_ref().map(|timed| timed.value))
+    }
+
+    fn price_mark(&self) -> Option<Decimal> {
+        self.mark_price.as_ref().map(|timed| timed.value)
+    }
+
+    fn funding_rate(&self, event: &MarketEvent<InstrumentIndex, DataKind>) -> Option<Decimal> {
+        match &event.kind {
+            DataKind::FundingRate(funding_rate)
+                if self
+                    .funding_rate
+                    .as_ref()
+                    .is_none_or(|rate| rate.time < funding_rate.time) =>
+            {
+                Decimal::from_f64(funding_rate.rate)
+            }
+            _ => None,
+        }
+    }
+
+    fn borrow_rate(&self) -> Option<Decimal> {
+        self.borrow_rate.as_ref().map(|timed| timed.value)
+    }
+
+    fn l1(&self) -> Option<&OrderBookL1> {
+        Some(&self.l1)
+    }
+
+    fn is_liquidation_cluster(&self) -> bool {
+        self.liquidations
+            .as_ref()
+            .is_some_and(LiquidationMonitor::is_cluster)
+    }
+
+    fn init_from_template(&self) -> Option<Self> {
+        Some(Self {
+            liquidations: self
+                .liquidations
+                .as_ref()
+                .map(|monitor| LiquidationMonitor::new(monitor.window, monitor.threshold)),
+            ..Self::default()
+        })
     }
 }
 
@@ -81,6 +186,10 @@ impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>>
     type Audit = ();
 
     fn process(&mut self, event: &MarketEvent<InstrumentKey, DataKind>) -> Self::Audit {
+        if let Some(liquidations) = &mut self.liquidations {
+            liquidations.expire(event.time_exchange);
+        }
+
         match &event.kind {
             DataKind::Trade(trade) => {
                 if self
@@ -94,16 +203,125 @@ impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>>
                     }
                 }
             }
+            DataKind::Candle(candle)
+                if self
+                    .last_candle_close
+                    .as_ref()
+                    .is_none_or(|close| close.time < candle.close_time) =>
+            {
+                if let Some(close) = Decimal::from_f64(candle.close) {
+                    self.last_candle_close
+                        .replace(Timed::new(close, candle.close_time));
+                }
+            }
             DataKind::OrderBookL1(l1) => {
                 if self.l1.last_update_time < event.time_exchange {
                     self.l1 = l1.clone()
                 }
             }
+            DataKind::MarkPrice(mark_price)
+                if self
+                    .mark_price
+                    .as_ref()
+                    .is_none_or(|price| price.time < event.time_exchange) =>
+            {
+                if let Some(price) = Decimal::from_f64(mark_price.price) {
+                    self.mark_price
+                        .replace(Timed::new(price, event.time_exchange));
+                }
+            }
+            DataKind::FundingRate(funding_rate)
+                if self
+                    .funding_rate
+                    .as_ref()
+                    .is_none_or(|rate| rate.time < funding_rate.time) =>
+            {
+                if let Some(rate) = Decimal::from_f64(funding_rate.rate) {
+                    self.funding_rate
+                        .replace(Timed::new(rate, funding_rate.time));
+                }
+            }
+            DataKind::BorrowRate(borrow_rate)
+                if self
+                    .borrow_rate
+                    .as_ref()
+                    .is_none_or(|rate| rate.time < borrow_rate.time) =>
+            {
+                if let Some(rate) = Decimal::from_f64(borrow_rate.rate_annual) {
+                    self.borrow_rate.replace(Timed::new(rate, borrow_rate.time));
+                }
+            }
+            DataKind::Liquidation(liquidation) => {
+                if let (Some(liquidations), Some(notional)) = (
+                    &mut self.liquidations,
+                    Decimal::from_f64(liquidation.price * liquidation.quantity),
+                ) {
+                    liquidations.update(Timed::new(notional.abs(), event.time_exchange));
+                }
+            }
             _ => {}
         }
     }
 }
 
+/// Monitors the total notional value of recent liquidations over a rolling window, identifying
+/// large liquidation clusters that typically accompany elevated volatility.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct LiquidationMonitor {
+    /// Rolling window that liquidations are aggregated over.
+    pub window: Duration,
+
+    /// Total liquidated notional value within the `window` that constitutes a cluster.
+    pub threshold: Decimal,
+
+    /// Liquidated notional values within the `window`, in time order.
+    pub recent: VecDeque<Timed<Decimal>>,
+}
+
+impl LiquidationMonitor {
+    /// Construct a new `LiquidationMonitor` with the provided rolling window and cluster
+    /// notional value threshold.
+    pub fn new(window: Duration, threshold: Decimal) -> Self {
+        Self {
+            window,
+            threshold,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Record a new liquidation notional value, expiring any that have fallen out of the window.
+    pub fn update(&mut self, notional: Timed<Decimal>) {
+        let time = notional.time;
+        self.recent.push_back(notional);
+        self.expire(time);
+    }
+
+    /// Expire liquidations that occurred outside the window ending at the provided time.
+    pub fn expire(&mut self, time: DateTime<Utc>) {
+        let window = TimeDelta::from_std(self.window).unwrap_or(TimeDelta::MAX);
+        while self
+            .recent
+            .front()
+            .is_some_and(|liquidation| time - liquidation.time > window)
+        {
+            self.recent.pop_front();
+        }
+    }
+
+    /// Total liquidated notional value within the window.
+    pub fn notional(&self) -> Decimal {
+        self.recent
+            .iter()
+            .map(|liquidation| liquidation.value)
+            .sum()
+    }
+
+    /// Returns true if the liquidated notional value within the window meets the threshold.
+    pub fn is_cluster(&self) -> bool {
+        !self.recent.is_empty() && self.notional() >= self.threshold
+    }
+}
+
 impl<ExchangeKey, AssetKey, InstrumentKey>
     Processor<&AccountEvent<ExchangeKey, AssetKey, InstrumentKey>> for DefaultInstrumentMarketData
 {
@@ -119,3 +337,93 @@ impl<ExchangeKey, InstrumentKey> InFlightRequestRecorder<ExchangeKey, Instrument
 
     fn record_in_flight_open(&mut self, _: &OrderRequestOpen<ExchangeKey, InstrumentKey>) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use barter_data::subscription::candle::Candle;
+    use barter_instrument::exchange::ExchangeId;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_default_instrument_market_data_price_from_candles() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let candle = |close_time, close| MarketEvent {
+            time_exchange: close_time,
+            time_received: close_time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Candle(Candle {
+                close_time,
+                open: 100.0,
+                high: 110.0,
+                low: 90.0,
+                close,
+                volume: 10.0,
+                trade_count: 5,
+            }),
+        };
+
+        let mut data = DefaultInstrumentMarketData::default();
+        assert_eq!(data.price(), None);
+
+        data.process(&candle(time_plus_secs(base, 60), 105.0));
+        assert_eq!(data.price(), Some(dec!(105.0)));
+
+        // Out of order candle is ignored
+        data.process(&candle(base, 95.0));
+        assert_eq!(data.price(), Some(dec!(105.0)));
+    }
+
+    #[test]
+    fn test_liquidation_monitor() {
+        struct TestCase {
+            liquidation: Timed<Decimal>,
+            expected_notional: Decimal,
+            expected_cluster: bool,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut monitor = LiquidationMonitor::new(Duration::from_secs(60), dec!(1_000_000));
+
+        let cases = vec![
+            // TC0: single liquidation below threshold
+            TestCase {
+                liquidation: Timed::new(dec!(400_000), base),
+                expected_notional: dec!(400_000),
+                expected_cluster: false,
+            },
+            // TC1: liquidations within window meet threshold
+            TestCase {
+                liquidation: Timed::new(dec!(600_000), time_plus_secs(base, 30)),
+                expected_notional: dec!(1_000_000),
+                expected_cluster: true,
+            },
+            // TC2: first liquidation expires from window
+            TestCase {
+                liquidation: Timed::new(dec!(100_000), time_plus_secs(base, 61)),
+                expected_notional: dec!(700_000),
+                expected_cluster: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            monitor.update(test.liquidation);
+            assert_eq!(
+                monitor.notional(),
+                test.expected_notional,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                monitor.is_cluster(),
+                test.expected_cluster,
+                "TC{index} failed"
+            );
+        }
+
+        // All liquidations expire once the window has elapsed
+        monitor.expire(time_plus_secs(base, 200));
+        assert!(!monitor.is_cluster());
+    }
+}
@@ -1,18 +1,25 @@
 use crate::{
     engine::state::{
-        instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        instrument::{
+            data::InstrumentDataState, filter::InstrumentFilter, strategy::StrategyBooks,
+        },
         order::{Orders, manager::OrderManager},
-        position::{PositionExited, PositionManager},
+        position::{
+            MarginRequirement, PositionExited, PositionManager, PositionMarketUpdate,
+            PositionUpdate, SignalMeta, calculate_pnl_realised,
+        },
     },
     statistic::summary::instrument::TearSheetGenerator,
 };
 use barter_data::event::MarketEvent;
 use barter_execution::{
     InstrumentAccountSnapshot,
+    funding::FundingPayment,
     order::{
         Order, OrderKey,
+        id::ClientOrderId,
         request::OrderResponseCancel,
-        state::{ActiveOrderState, OrderState},
+        state::{ActiveOrderState, InactiveOrderState, OrderState},
     },
     trade::Trade,
 };
@@ -22,15 +29,20 @@ use barter_instrument::{
     index::IndexedInstruments,
     instrument::{
         Instrument, InstrumentIndex,
+        kind::InstrumentKind,
         name::{InstrumentNameExchange, InstrumentNameInternal},
     },
 };
 use barter_integration::{collection::FnvIndexMap, snapshot::Snapshot};
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
+use fnv::FnvHashMap;
 use itertools::Either;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use thiserror::Error;
+use tracing::{info, warn};
 
 /// Defines the state interface [`InstrumentDataState`] that can be implemented for custom
 /// instrument level data state.
@@ -39,6 +51,10 @@ pub mod data;
 /// Defines an `InstrumentFilter`, used to filter instrument-centric data structures.
 pub mod filter;
 
+/// Defines per-strategy [`StrategyBooks`], used to track the `Position` and statistics of each
+/// strategy trading an instrument.
+pub mod strategy;
+
 /// Collection of [`InstrumentState`]s indexed by [`InstrumentIndex`].
 ///
 /// Note that the same instruments with the same [`InstrumentNameExchange`] (eg/ "btc_usdt") but
@@ -237,6 +253,38 @@ impl<InstrumentData> InstrumentStates<InstrumentData> {
     }
 }
 
+/// Error that can occur when adding or removing an instrument on a running `Engine`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum InstrumentUpdateError {
+    #[error("instrument is already tracked: {0}")]
+    AlreadyTracked(InstrumentNameInternal),
+
+    #[error("instrument {actual} must be keyed with the next InstrumentIndex: {expected}")]
+    IndexMismatch {
+        expected: InstrumentIndex,
+        actual: InstrumentIndex,
+    },
+
+    #[error("instrument references an untracked exchange: {0}")]
+    UnknownExchange(ExchangeIndex),
+
+    #[error("instrument references an untracked asset: {0}")]
+    UnknownAsset(AssetIndex),
+
+    #[error("InstrumentData does not support adding instruments at runtime")]
+    Unsupported,
+
+    #[error("instrument is not tracked: {0}")]
+    NotTracked(InstrumentIndex),
+}
+
+/// Determines if an instrument is winding down after being removed from a running `Engine`, and
+/// so should not be sent new algorithmic open order requests.
+pub trait InstrumentWindDown<InstrumentKey = InstrumentIndex> {
+    /// Returns true if the instrument associated with the `InstrumentKey` is winding down.
+    fn is_winding_down(&self, instrument: &InstrumentKey) -> bool;
+}
+
 /// Represents the current state of an instrument, including its [`Position`], [`Orders`], and
 /// user provided instrument data.
 ///
@@ -261,6 +309,35 @@ pub struct InstrumentState<
     /// Current `PositionManager`.
     pub position: PositionManager<InstrumentKey>,
 
+    /// Per-`StrategyId` position books and statistics, tracking the share of the `position`
+    /// attributable to each strategy trading this instrument.
+    pub strategies: StrategyBooks<InstrumentKey>,
+
+    /// Optional annual borrow rate (eg/ 0.05 for 5%) accrued by SHORT `Spot` [`Position`]s on
+    /// every market price update.
+    ///
+    /// Models the carrying cost of borrowing the base asset to short, so back-tests do not
+    /// assume free unlimited shorting. Superseded by the [`InstrumentDataState::borrow_rate`] if
+    /// available (eg/ from historical borrow rates).
+    pub borrow_rate: Option<Decimal>,
+
+    /// Optional simulated [`MarginRequirement`] of leveraged [`Position`]s.
+    ///
+    /// If configured, a [`Position`] whose maintenance margin is breached by a market price
+    /// update is forcibly liquidated, so leveraged back-tests cannot survive drawdowns that
+    /// would have been liquidated live.
+    pub margin: Option<MarginRequirement>,
+
+    /// Instrument is winding down after being removed from the running `Engine`, so algorithmic
+    /// open order requests are refused while any remaining orders & `Position` are closed out.
+    #[serde(default)]
+    pub winding_down: bool,
+
+    /// [`SignalMeta`] of open order requests that have not yet filled, attached to the
+    /// [`Position`] opened by their first fill.
+    #[serde(default)]
+    pub signals: FnvHashMap<ClientOrderId, SignalMeta>,
+
     /// Active orders and associated order management.
     pub orders: Orders<ExchangeKey, InstrumentKey>,
 
@@ -298,6 +375,14 @@ impl<InstrumentData, ExchangeKey, AssetKey, InstrumentKey>
         AssetKey: Debug + Clone,
         InstrumentKey: Debug + Clone,
     {
+        // Orders that will not fill no longer require their SignalMeta
+        match &order.0.state {
+            OrderState::Active(_) | OrderState::Inactive(InactiveOrderState::FullyFilled) => {}
+            OrderState::Inactive(_) => {
+                self.signals.remove(&order.0.key.cid);
+            }
+        }
+
         self.orders.update_from_order_snapshot(order);
     }
 
@@ -320,6 +405,7 @@ impl<InstrumentData, ExchangeKey, AssetKey, InstrumentKey>
     /// This method handles:
     /// - Opening/updating the current position state based on a new trade.
     /// - Updating the internal [`TearSheetGenerator`] if a position is exited.
+    /// - Updating the [`StrategyBooks`] position & statistics of the trade `StrategyId`.
     pub fn update_from_trade(
         &mut self,
         trade: &Trade<QuoteAsset, InstrumentKey>,
@@ -327,32 +413,204 @@ impl<InstrumentData, ExchangeKey, AssetKey, InstrumentKey>
     where
         InstrumentKey: Debug + Clone + PartialEq,
     {
-        self.position
+        self.strategies
+            .update_from_trade(trade, self.tear_sheet.time_engine_start);
+
+        let signal = trade.cid.as_ref().and_then(|cid| self.signals.remove(cid));
+        let had_position = self.position.current.is_some();
+
+        let exited = self
+            .position
             .update_from_trade(trade)
-            .inspect(|closed| self.tear_sheet.update_from_position(closed))
+            .inspect(|closed| self.tear_sheet.update_from_position(closed));
+
+        // Attach any SignalMeta to a newly opened Position (including flips)
+        let opened = !had_position || exited.is_some();
+        if let Some(position) = self.position.current.as_mut().filter(|_| opened) {
+            position.signal = signal;
+        }
+
+        exited
+    }
+
+    /// Books a [`FundingPayment`] against the current [`Position`](super::position::Position)
+    /// `pnl_realised`.
+    ///
+    /// A `FundingPayment` received without an open `Position` is logged and otherwise ignored.
+    pub fn update_from_funding_payment(&mut self, payment: &FundingPayment<AssetKey, InstrumentKey>)
+    where
+        InstrumentKey: Debug,
+    {
+        match &mut self.position.current {
+            Some(position) => position.apply_funding_payment(payment.amount),
+            None => warn!(
+                instrument = ?payment.instrument,
+                amount = %payment.amount,
+                "InstrumentState received FundingPayment without an open Position"
+            ),
+        }
+    }
+
+    /// Accrue the borrow cost of any open SHORT [`Position`] up to the provided time, using the
+    /// [`InstrumentDataState::borrow_rate`] if available, otherwise the configured `borrow_rate`.
+    ///
+    /// Returns the accrued borrow cost denominated in the borrowed base asset, if any.
+    pub fn accrue_borrow_cost(&mut self, time_exchange: DateTime<Utc>) -> Option<Decimal>
+    where
+        InstrumentData: InstrumentDataState<ExchangeKey, AssetKey, InstrumentKey>,
+    {
+        let position = self.position.current.as_mut()?;
+        let price = self.data.price()?;
+        let borrow_rate = self.data.borrow_rate().or(self.borrow_rate)?;
+
+        let cost = position.accrue_borrow_cost(borrow_rate, price, time_exchange);
+        (cost > Decimal::ZERO).then(|| cost / price)
+    }
+
+    /// Book the funding payment of any open perpetual [`Position`] for the
+    /// [`InstrumentDataState::funding_rate`] delivered by the provided market event, if any.
+    ///
+    /// This must be called before the market event is processed by the `InstrumentData`, so
+    /// that funding rates at or before the last applied funding rate time are ignored.
+    ///
+    /// Returns the perpetual settlement asset and the signed funding payment denominated in it,
+    /// positive if received and negative if paid.
+    pub fn apply_funding_rate(
+        &mut self,
+        event: &MarketEvent<InstrumentKey, InstrumentData::MarketEventKind>,
+    ) -> Option<(AssetKey, Decimal)>
+    where
+        InstrumentData: InstrumentDataState<ExchangeKey, AssetKey, InstrumentKey>,
+        AssetKey: Clone + PartialEq,
+    {
+        let InstrumentKind::Perpetual(contract) = &self.instrument.kind else {
+            return None;
+        };
+        let funding_rate = self.data.funding_rate(event)?;
+        let position = self.position.current.as_mut()?;
+        let price = self.data.price_mark().or_else(|| self.data.price())?;
+
+        let amount = position.apply_funding_rate(funding_rate, price);
+
+        // Funding payments are quote denominated, so convert if settled in the base asset
+        if contract.settlement_asset == self.instrument.underlying.base {
+            Some((contract.settlement_asset.clone(), amount / price))
+        } else {
+            Some((contract.settlement_asset.clone(), amount))
+        }
     }
 
     /// Updates the instrument state based on a new market event.
     ///
     /// If the market event has a price associated with it (eg/ `PublicTrade`, `OrderBookL1`), any
-    /// open [`Position`] `pnl_unrealised` is re-calculated.
+    /// open [`Position`] `pnl_unrealised`, `TrailingStop` & `BreakEvenStop` are re-calculated.
+    ///
+    /// Note that borrow cost is accrued separately (see [`Self::accrue_borrow_cost`]), so it
+    /// can be debited from the borrowed asset balance.
+    ///
+    /// Note that funding payments are booked separately (see [`Self::apply_funding_rate`]), so
+    /// they can be booked against the settlement asset balance.
+    ///
+    /// Derivative [`Position`] `pnl_unrealised` is calculated using the instrument mark price if
+    /// available (see [`InstrumentDataState::price_mark`]).
+    ///
+    /// If a [`MarginRequirement`] is configured and the valuation price breaches the
+    /// [`Position`] maintenance margin, the [`Position`] is liquidated at the liquidation price
+    /// and the resulting [`PositionExited`] is returned, along with the quote asset balance
+    /// change to book (see [`PositionMarketUpdate::Liquidated`]).
+    ///
+    /// Otherwise, if the `BreakEvenStop` moved the protective stop to break-even, the resulting
+    /// [`PositionUpdate`] is returned.
     pub fn update_from_market(
         &mut self,
         event: &MarketEvent<InstrumentKey, InstrumentData::MarketEventKind>,
-    ) where
+    ) -> Option<PositionMarketUpdate<InstrumentKey>>
+    where
         InstrumentData: InstrumentDataState<ExchangeKey, AssetKey, InstrumentKey>,
+        InstrumentKey: Debug + Clone,
     {
         self.data.process(event);
 
-        let Some(position) = &mut self.position.current else {
-            return;
+        let position = self.position.current.as_mut()?;
+        let price = self.data.price()?;
+
+        // Derivative positions are valued using the mark price, if available
+        let price_valuation = match self.instrument.kind {
+            InstrumentKind::Spot => price,
+            _ => self.data.price_mark().unwrap_or(price),
+        };
+
+        position.update_pnl_unrealised(price_valuation);
+        position.update_trailing_stop(price);
+        self.strategies.update_pnl_unrealised(price_valuation);
+
+        let update = position.update_break_even_stop(price).then(|| {
+            info!(
+                instrument = %self.instrument.name_internal,
+                price_stop = %position.price_entry_average,
+                pnl_unrealised = %position.pnl_unrealised,
+                "Position stop moved to break-even"
+            );
+
+            PositionMarketUpdate::Update(PositionUpdate {
+                instrument: position.instrument.clone(),
+                side: position.side,
+                quantity_abs: position.quantity_abs,
+                price_stop: position.price_entry_average,
+                time_exchange: event.time_exchange,
+            })
+        });
+
+        let Some(margin) = self.margin else {
+            return update;
         };
+        if !margin.is_breached(position.side, position.price_entry_average, price_valuation) {
+            return update;
+        }
 
-        let Some(price) = self.data.price() else {
-            return;
+        let Some(price_liquidation) =
+            margin.price_liquidation(position.side, position.price_entry_average)
+        else {
+            return update;
         };
 
-        position.update_pnl_unrealised(price);
+        // Quote balance change of closing the Position at the liquidation price, net of the
+        // liquidation fee (entry fees were already debited when the Position was opened)
+        let fee_liquidation =
+            position.quantity_abs * price_liquidation * margin.liquidation_fee_rate;
+        let balance_change = calculate_pnl_realised(
+            position.side,
+            position.price_entry_average,
+            position.quantity_abs,
+            price_liquidation,
+            fee_liquidation,
+        );
+
+        let liquidated = self.position.current.take()?.liquidate(
+            price_liquidation,
+            margin.liquidation_fee_rate,
+            event.time_exchange,
+        );
+
+        self.strategies.liquidate(
+            price_liquidation,
+            margin.liquidation_fee_rate,
+            event.time_exchange,
+        );
+
+        warn!(
+            instrument = %self.instrument.name_internal,
+            price = %price_valuation,
+            %price_liquidation,
+            pnl_realised = %liquidated.pnl_realised,
+            "Position liquidated after breaching maintenance margin"
+        );
+
+        self.tear_sheet.update_from_position(&liquidated);
+        Some(PositionMarketUpdate::Liquidated {
+            exited: liquidated,
+            balance_change,
+        })
     }
 }
 
@@ -374,6 +632,11 @@ where
         instrument,
         tear_sheet: _,
         position: _,
+        strategies: _,
+        borrow_rate: _,
+        margin: _,
+        winding_down: _,
+        signals: _,
         orders,
         data: _,
     } = state;
@@ -390,6 +653,7 @@ where
                     quantity,
                     kind,
                     time_in_force,
+                    reduce_only,
                     state: ActiveOrderState::Open(open),
                 } = order
                 else {
@@ -408,6 +672,7 @@ where
                     quantity: *quantity,
                     kind: *kind,
                     time_in_force: *time_in_force,
+                    reduce_only: *reduce_only,
                     state: OrderState::active(open.clone()),
                 })
             })
@@ -442,6 +707,11 @@ where
                         instrument.value.clone().map_exchange_key(exchange_index),
                         TearSheetGenerator::init(time_engine_start),
                         position_manager_init(),
+                        StrategyBooks::default(),
+                        None,
+                        None,
+                        false,
+                        FnvHashMap::default(),
                         orders_init(),
                         instrument_data_init(),
                     ),
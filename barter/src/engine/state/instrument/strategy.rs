@@ -0,0 +1,151 @@
+use crate::{
+    engine::state::position::{PositionExited, PositionManager},
+    statistic::summary::instrument::TearSheetGenerator,
+};
+use barter_execution::{order::id::StrategyId, trade::Trade};
+use barter_instrument::{asset::QuoteAsset, instrument::InstrumentIndex};
+use barter_integration::collection::FnvIndexMap;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// [`Position`](super::super::position::Position) book and [`TearSheetGenerator`] of a single
+/// [`StrategyId`] trading an instrument.
+///
+/// Only contains the [`Trade`]s attributed to the [`StrategyId`], whereas the
+/// [`InstrumentState`](super::InstrumentState) `position` nets the [`Trade`]s of all strategies.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StrategyBook<InstrumentKey = InstrumentIndex> {
+    pub position: PositionManager<InstrumentKey>,
+    pub tear_sheet: TearSheetGenerator,
+}
+
+impl<InstrumentKey> StrategyBook<InstrumentKey> {
+    /// Initialise an empty [`StrategyBook`] with an initial timestamp.
+    pub fn init(time_engine_start: DateTime<Utc>) -> Self {
+        Self {
+            position: PositionManager::default(),
+            tear_sheet: TearSheetGenerator::init(time_engine_start),
+        }
+    }
+}
+
+/// Collection of per-[`StrategyId`] [`StrategyBook`]s of an instrument, allowing multiple
+/// strategies to trade the same instrument within one `Engine`.
+///
+/// [`StrategyBook`]s are created lazily upon the first [`Trade`] of a [`StrategyId`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StrategyBooks<InstrumentKey = InstrumentIndex>(
+    pub FnvIndexMap<StrategyId, StrategyBook<InstrumentKey>>,
+);
+
+impl<InstrumentKey> Default for StrategyBooks<InstrumentKey> {
+    fn default() -> Self {
+        Self(FnvIndexMap::default())
+    }
+}
+
+impl<InstrumentKey> StrategyBooks<InstrumentKey> {
+    /// Return a reference to the [`StrategyBook`] associated with the [`StrategyId`], if it has
+    /// traded the instrument.
+    pub fn strategy(&self, strategy: &StrategyId) -> Option<&StrategyBook<InstrumentKey>> {
+        self.0.get(strategy)
+    }
+
+    /// Update the [`StrategyBook`] of the [`Trade`] `strategy` from the next [`Trade`].
+    ///
+    /// Returns the [`StrategyId`] and [`PositionExited`] if the [`StrategyBook`] position was
+    /// exited.
+    pub fn update_from_trade(
+        &mut self,
+        trade: &Trade<QuoteAsset, InstrumentKey>,
+        time_engine_start: DateTime<Utc>,
+    ) -> Option<(StrategyId, PositionExited<QuoteAsset, InstrumentKey>)>
+    where
+        InstrumentKey: Debug + Clone + PartialEq,
+    {
+        let book = self
+            .0
+            .entry(trade.strategy.clone())
+            .or_insert_with(|| StrategyBook::init(time_engine_start));
+
+        book.position
+            .update_from_trade(trade)
+            .inspect(|exited| book.tear_sheet.update_from_position(exited))
+            .map(|exited| (trade.strategy.clone(), exited))
+    }
+
+    /// Re-calculate the `pnl_unrealised` of every open [`StrategyBook`] position.
+    pub fn update_pnl_unrealised(&mut self, price: Decimal) {
+        self.0
+            .values_mut()
+            .filter_map(|book| book.position.current.as_mut())
+            .for_each(|position| position.update_pnl_unrealised(price));
+    }
+
+    /// Forcibly close every open [`StrategyBook`] position at the provided liquidation price,
+    /// after the netted instrument position has been liquidated.
+    pub fn liquidate(&mut self, price: Decimal, fee_rate: Decimal, time: DateTime<Utc>) {
+        for book in self.0.values_mut() {
+            if let Some(position) = book.position.current.take() {
+                let liquidated = position.liquidate(price, fee_rate, time);
+                book.tear_sheet.update_from_position(&liquidated);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{time_plus_days, trade};
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_strategy_books_update_from_trade() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let strategy_a = StrategyId::new("a");
+        let strategy_b = StrategyId::new("b");
+
+        let trade = |strategy: &StrategyId, day, side, price, quantity| Trade {
+            strategy: strategy.clone(),
+            ..trade(time_plus_days(base, day), side, price, quantity, 0.0)
+        };
+
+        let mut books = StrategyBooks::default();
+        let current = |books: &StrategyBooks<_>, strategy| {
+            books
+                .strategy(strategy)
+                .and_then(|book| book.position.current.clone())
+        };
+
+        // Strategy A opens LONG, Strategy B opens SHORT on the same instrument
+        assert_eq!(
+            books.update_from_trade(&trade(&strategy_a, 0, Side::Buy, 100.0, 1.0), base),
+            None
+        );
+        assert_eq!(
+            books.update_from_trade(&trade(&strategy_b, 0, Side::Sell, 100.0, 2.0), base),
+            None
+        );
+        assert_eq!(
+            current(&books, &strategy_a).map(|position| position.side),
+            Some(Side::Buy)
+        );
+        assert_eq!(
+            current(&books, &strategy_b).map(|position| position.quantity_abs),
+            Some(dec!(2))
+        );
+
+        // Strategy A exits LONG, leaving Strategy B book untouched
+        let (strategy, exited) = books
+            .update_from_trade(&trade(&strategy_a, 1, Side::Sell, 110.0, 1.0), base)
+            .unwrap();
+        assert_eq!(strategy, strategy_a);
+        assert_eq!(exited.pnl_realised, dec!(10));
+        assert!(current(&books, &strategy_a).is_none());
+        assert!(current(&books, &strategy_b).is_some());
+    }
+}
@@ -1,32 +1,45 @@
-use crate::engine::{
-    Processor,
-    state::{
-        asset::{AssetStates, filter::AssetFilter},
-        builder::EngineStateBuilder,
-        connectivity::ConnectivityStates,
-        instrument::{
-            InstrumentStates, data::InstrumentDataState, filter::InstrumentFilter,
-            generate_unindexed_instrument_account_snapshot,
+use crate::{
+    engine::{
+        Processor,
+        outage::{DegradedMode, ExchangeDegradation},
+        state::{
+            asset::{AssetStates, filter::AssetFilter},
+            builder::EngineStateBuilder,
+            connectivity::ConnectivityStates,
+            instrument::{
+                InstrumentState, InstrumentStates, InstrumentUpdateError, InstrumentWindDown,
+                data::InstrumentDataState, filter::InstrumentFilter,
+                generate_unindexed_instrument_account_snapshot, strategy::StrategyBooks,
+            },
+            order::Orders,
+            position::{PositionExited, PositionManager, PositionMarketUpdate},
+            trading::TradingState,
         },
-        position::PositionExited,
-        trading::TradingState,
     },
+    execution::router::SmartOrderRouter,
+    risk::{RiskRefused, check::util::is_position_reducing},
+    statistic::summary::instrument::TearSheetGenerator,
 };
 use barter_data::event::MarketEvent;
 use barter_execution::{
     AccountEvent, AccountEventKind, UnindexedAccountSnapshot, balance::AssetBalance,
+    order::request::OrderRequestOpen,
 };
 use barter_instrument::{
+    Keyed,
     asset::{AssetIndex, QuoteAsset},
     exchange::{ExchangeId, ExchangeIndex},
     index::IndexedInstruments,
-    instrument::InstrumentIndex,
+    instrument::{Instrument, InstrumentIndex},
 };
 use barter_integration::{collection::one_or_many::OneOrMany, snapshot::Snapshot};
+use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use fnv::FnvHashMap;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use tracing::info;
 
 /// Asset-centric state and associated state management logic.
 pub mod asset;
@@ -61,6 +74,12 @@ pub struct EngineState<GlobalData, InstrumentData> {
     /// Current `TradingState` of the `Engine`.
     pub trading: TradingState,
 
+    /// Most recent `Engine` clock time.
+    ///
+    /// Updated by the `Engine` before processing each event, so in back-tests this reflects the
+    /// historical clock time.
+    pub time_engine_now: DateTime<Utc>,
+
     /// Configurable `GlobalData` state.
     pub global: GlobalData,
 
@@ -98,6 +117,8 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
     ///   [`Health::Healthy`](connectivity::Health::Healthy) if it was not previously.
     /// - Updates the `GlobalData` with the `AccountEvent`.
     /// - Updates the associated `AssetStates` and `InstrumentStates` with the `AccountEvent`.
+    /// - Books any [`FundingPayment`](barter_execution::funding::FundingPayment) against the
+    ///   settlement asset balance and the open [`Position`](position::Position) `pnl_realised`.
     pub fn update_from_account(
         &mut self,
         event: &AccountEvent,
@@ -132,6 +153,7 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
                     .update_from_balance(balance.as_ref());
                 None
             }
+            AccountEventKind::BalanceCheck(_) => None,
             AccountEventKind::OrderSnapshot(order) => {
                 let instrument_state = self
                     .instruments
@@ -156,6 +178,17 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
                 instrument_state.data.process(event);
                 instrument_state.update_from_trade(trade)
             }
+            AccountEventKind::FundingPayment(payment) => {
+                self.assets
+                    .asset_index_mut(&payment.asset)
+                    .update_from_funding_payment(payment);
+
+                let instrument_state = self.instruments.instrument_index_mut(&payment.instrument);
+
+                instrument_state.data.process(event);
+                instrument_state.update_from_funding_payment(payment);
+                None
+            }
         };
 
         // Update any user provided GlobalData State
@@ -171,10 +204,21 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
     ///   [`Health::Healthy`](connectivity::Health::Healthy) if it was not previously.
     /// - Updates the `GlobalData` with the `MarketEvent`.
     /// - Updates the associated [`InstrumentDataState`] with the `MarketEvent`.
+    /// - Books any perpetual [`Position`](position::Position) funding payment against the
+    ///   settlement asset balance.
+    /// - Updates any open [`Position`](position::Position) unrealised PnL & price excursions.
+    /// - Accrues any SHORT [`Position`](position::Position) borrow cost, debiting it from the
+    ///   borrowed base asset balance.
+    /// - Liquidates any open [`Position`](position::Position) that breaches its configured
+    ///   [`MarginRequirement`](position::MarginRequirement), booking the liquidation PnL & fee
+    ///   against the quote asset balance, and returning the [`PositionExited`].
+    /// - Returns the [`PositionUpdate`](position::PositionUpdate) of any
+    ///   [`BreakEvenStop`](position::BreakEvenStop) moved to break-even.
     pub fn update_from_market(
         &mut self,
         event: &MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>,
-    ) where
+    ) -> Option<PositionMarketUpdate>
+    where
         GlobalData:
             for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>,
         InstrumentData: InstrumentDataState,
@@ -185,7 +229,234 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
         let instrument_state = self.instruments.instrument_index_mut(&event.instrument);
 
         self.global.process(event);
-        instrument_state.data.process(event);
+
+        // Book any perpetual Position funding payment against the settlement asset balance
+        if let Some((settlement, amount)) = instrument_state.apply_funding_rate(event) {
+            self.assets
+                .asset_index_mut(&settlement)
+                .update_from_balance_change(amount, event.time_exchange);
+        }
+
+        let update = instrument_state.update_from_market(event);
+
+        // Debit accrued SHORT Position borrow cost from the borrowed base asset balance
+        if let Some(cost) = instrument_state.accrue_borrow_cost(event.time_exchange) {
+            let base = instrument_state.instrument.underlying.base;
+            self.assets
+                .asset_index_mut(&base)
+                .update_from_balance_change(-cost, event.time_exchange);
+        }
+
+        // Book simulated liquidation PnL & fees against the quote asset balance
+        if let Some(PositionMarketUpdate::Liquidated { balance_change, .. }) = &update {
+            let quote = instrument_state.instrument.underlying.quote;
+            self.assets
+                .asset_index_mut(&quote)
+                .update_from_balance_change(*balance_change, event.time_exchange);
+        }
+
+        update
+    }
+
+    /// Add a new [`InstrumentState`](instrument::InstrumentState) for an instrument traded on an
+    /// already tracked exchange, returning its [`InstrumentIndex`].
+    ///
+    /// The instrument must be keyed with the next `InstrumentIndex` (ie/ the number of tracked
+    /// instruments), and its `InstrumentData` is initialised using the first tracked instrument
+    /// as a template (see [`InstrumentDataState::init_from_template`]).
+    ///
+    /// Re-adding an instrument that is winding down after removal resumes trading it.
+    pub fn add_instrument(
+        &mut self,
+        instrument: &Keyed<InstrumentIndex, Instrument<ExchangeIndex, AssetIndex>>,
+    ) -> Result<InstrumentIndex, InstrumentUpdateError>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let Keyed {
+            key,
+            value: instrument,
+        } = instrument;
+
+        if let Some(state) = self.instruments.0.get_mut(&instrument.name_internal) {
+            return if state.winding_down && state.key == *key {
+                info!(%key, name = %instrument.name_internal, "EngineState resumed instrument");
+                state.winding_down = false;
+                Ok(*key)
+            } else {
+                Err(InstrumentUpdateError::AlreadyTracked(
+                    instrument.name_internal.clone(),
+                ))
+            };
+        }
+
+        let expected = InstrumentIndex(self.instruments.0.len());
+        if *key != expected {
+            return Err(InstrumentUpdateError::IndexMismatch {
+                expected,
+                actual: *key,
+            });
+        }
+
+        if instrument.exchange.index() >= self.connectivity.exchanges.len() {
+            return Err(InstrumentUpdateError::UnknownExchange(instrument.exchange));
+        }
+
+        if let Some(asset) = [instrument.underlying.base, instrument.underlying.quote]
+            .into_iter()
+            .find(|asset| asset.index() >= self.assets.0.len())
+        {
+            return Err(InstrumentUpdateError::UnknownAsset(asset));
+        }
+
+        let data = self
+            .instruments
+            .0
+            .first()
+            .and_then(|(_, template)| template.data.init_from_template())
+            .ok_or(InstrumentUpdateError::Unsupported)?;
+
+        info!(%key, name = %instrument.name_internal, "EngineState added instrument");
+        self.instruments.0.insert(
+            instrument.name_internal.clone(),
+            InstrumentState::new(
+                *key,
+                instrument.clone(),
+                TearSheetGenerator::init(self.time_engine_now),
+                PositionManager::default(),
+                StrategyBooks::default(),
+                None,
+                None,
+                false,
+                FnvHashMap::default(),
+                Orders::default(),
+                data,
+            ),
+        );
+
+        Ok(*key)
+    }
+
+    /// Mark the instrument associated with the [`InstrumentIndex`] as winding down, so no new
+    /// algorithmic open order requests are sent for it.
+    ///
+    /// The instrument state is retained so in-flight orders & any closing trades continue to
+    /// be tracked.
+    pub fn wind_down_instrument(
+        &mut self,
+        instrument: &InstrumentIndex,
+    ) -> Result<(), InstrumentUpdateError> {
+        let (_, state) = self
+            .instruments
+            .0
+            .get_index_mut(instrument.index())
+            .ok_or(InstrumentUpdateError::NotTracked(*instrument))?;
+
+        info!(key = %instrument, name = %state.instrument.name_internal, "EngineState winding down instrument");
+        state.winding_down = true;
+        Ok(())
+    }
+}
+
+impl<GlobalData, InstrumentData> InstrumentWindDown for EngineState<GlobalData, InstrumentData> {
+    fn is_winding_down(&self, instrument: &InstrumentIndex) -> bool {
+        self.instruments
+            .0
+            .get_index(instrument.index())
+            .is_some_and(|(_, state)| state.winding_down)
+    }
+}
+
+impl<GlobalData, InstrumentData> ExchangeDegradation for EngineState<GlobalData, InstrumentData>
+where
+    InstrumentData: InstrumentDataState,
+{
+    fn apply_degraded_modes(
+        &self,
+        opens: Vec<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+        router: Option<&SmartOrderRouter>,
+    ) -> (
+        Vec<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let mut approved = Vec::with_capacity(opens.len());
+        let mut refused = Vec::new();
+
+        for open in opens {
+            let mode = self
+                .connectivity
+                .connectivity_index(&open.key.exchange)
+                .degraded;
+
+            match mode {
+                None => approved.push(open),
+                Some(DegradedMode::Halt) => {
+                    refused.push(RiskRefused::new(open, "exchange is degraded: halted"))
+                }
+                Some(DegradedMode::ExitOnly) => {
+                    let position = &self
+                        .instruments
+                        .instrument_index(&open.key.instrument)
+                        .position;
+                    if is_position_reducing(
+                        position.current.as_ref(),
+                        open.state.side,
+                        open.state.quantity,
+                    ) {
+                        approved.push(open)
+                    } else {
+                        refused.push(RiskRefused::new(open, "exchange is degraded: exit only"))
+                    }
+                }
+                Some(DegradedMode::Reroute) => {
+                    let Some(router) = router else {
+                        refused.push(RiskRefused::new(
+                            open,
+                            "exchange is degraded: no SmartOrderRouter to reroute",
+                        ));
+                        continue;
+                    };
+
+                    let instruments = self.equivalent_instruments(&open.key.instrument);
+                    let venues = router.venues(self, &instruments, open.state.side);
+                    let routed = router.route(open.clone(), &venues);
+                    approved.extend(routed.requests);
+
+                    if routed.quantity_unrouted > Decimal::ZERO {
+                        let mut unrouted = open;
+                        unrouted.state.quantity = routed.quantity_unrouted;
+                        refused.push(RiskRefused::new(
+                            unrouted,
+                            "exchange is degraded: insufficient alternative venues",
+                        ));
+                    }
+                }
+            }
+        }
+
+        (approved, refused)
+    }
+}
+
+impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
+    /// Returns the instruments with the same kind & underlying base and quote assets as the
+    /// provided instrument (including itself), across all exchanges.
+    fn equivalent_instruments(&self, instrument: &InstrumentIndex) -> Vec<InstrumentIndex> {
+        let asset_name = |asset: &AssetIndex| &self.assets.asset_index(asset).asset.name_internal;
+        let target = &self.instruments.instrument_index(instrument).instrument;
+
+        self.instruments
+            .0
+            .values()
+            .filter(|state| {
+                let candidate = &state.instrument;
+                std::mem::discriminant(&candidate.kind) == std::mem::discriminant(&target.kind)
+                    && asset_name(&candidate.underlying.base) == asset_name(&target.underlying.base)
+                    && asset_name(&candidate.underlying.quote)
+                        == asset_name(&target.underlying.quote)
+            })
+            .map(|state| state.key)
+            .collect()
     }
 }
 
@@ -195,6 +466,7 @@ impl<GlobalData, InstrumentData> From<&EngineState<GlobalData, InstrumentData>>
     fn from(value: &EngineState<GlobalData, InstrumentData>) -> Self {
         let EngineState {
             trading: _,
+            time_engine_now: _,
             global: _,
             connectivity,
             assets,
@@ -230,3 +502,500 @@ impl<GlobalData, InstrumentData> From<&EngineState<GlobalData, InstrumentData>>
         snapshots
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::state::{
+        global::DefaultGlobalData,
+        instrument::data::DefaultInstrumentMarketData,
+        position::{MarginRequirement, SignalMeta},
+    };
+    use crate::test_utils::time_plus_days;
+    use barter_data::{
+        event::DataKind,
+        subscription::{carry::FundingRate, trade::PublicTrade},
+    };
+    use barter_execution::{
+        balance::Balance,
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, OrderId, StrategyId},
+            request::RequestOpen,
+        },
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, Underlying,
+        asset::ExchangeAsset,
+        instrument::kind::{InstrumentKind, perpetual::PerpetualContract},
+    };
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state(
+        balances: &[(&str, Balance)],
+        margin: Option<MarginRequirement>,
+        borrow_rates: &[(&str, Decimal)],
+    ) -> TestState {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        EngineState::builder(&instruments, DefaultGlobalData, Default::default)
+            .time_engine_start(DateTime::<Utc>::MIN_UTC)
+            .balances(
+                balances
+                    .iter()
+                    .map(|(asset, balance)| (ExchangeId::BinanceSpot, *asset, *balance)),
+            )
+            .margins(margin.map(|margin| Keyed::new("binance_spot_btc_usdt".into(), margin)))
+            .borrow_rates(borrow_rates.iter().map(|(asset, rate)| {
+                Keyed::new(ExchangeAsset::new(ExchangeId::BinanceSpot, *asset), *rate)
+            }))
+            .build()
+    }
+
+    fn entry(side: Side) -> Trade<QuoteAsset, InstrumentIndex> {
+        Trade {
+            id: TradeId::new("entry"),
+            order_id: OrderId::new("entry"),
+            cid: None,
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            side,
+            price: dec!(100),
+            quantity: dec!(1),
+            liquidity: None,
+            fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+        }
+    }
+
+    fn instrument(
+        key: usize,
+        exchange: usize,
+        name: &str,
+        (base, quote): (usize, usize),
+    ) -> Keyed<InstrumentIndex, Instrument<ExchangeIndex, AssetIndex>> {
+        Keyed::new(
+            InstrumentIndex(key),
+            Instrument::spot(
+                ExchangeIndex(exchange),
+                name,
+                name,
+                Underlying::new(AssetIndex(base), AssetIndex(quote)),
+                None,
+            ),
+        )
+    }
+
+    #[test]
+    fn test_engine_state_add_instrument() {
+        struct TestCase {
+            input: Keyed<InstrumentIndex, Instrument<ExchangeIndex, AssetIndex>>,
+            expected: Result<InstrumentIndex, InstrumentUpdateError>,
+        }
+
+        let cases = vec![
+            // TC0: instrument with tracked exchange & assets is added with the next index
+            TestCase {
+                input: instrument(1, 0, "binance_spot_usdt_btc", (1, 0)),
+                expected: Ok(InstrumentIndex(1)),
+            },
+            // TC1: instrument that is already tracked is refused
+            TestCase {
+                input: instrument(1, 0, "binance_spot_btc_usdt", (0, 1)),
+                expected: Err(InstrumentUpdateError::AlreadyTracked(
+                    "binance_spot_btc_usdt".into(),
+                )),
+            },
+            // TC2: instrument not keyed with the next index is refused
+            TestCase {
+                input: instrument(2, 0, "binance_spot_usdt_btc", (1, 0)),
+                expected: Err(InstrumentUpdateError::IndexMismatch {
+                    expected: InstrumentIndex(1),
+                    actual: InstrumentIndex(2),
+                }),
+            },
+            // TC3: instrument on an untracked exchange is refused
+            TestCase {
+                input: instrument(1, 1, "okx_spot_btc_usdt", (0, 1)),
+                expected: Err(InstrumentUpdateError::UnknownExchange(ExchangeIndex(1))),
+            },
+            // TC4: instrument with an untracked asset is refused
+            TestCase {
+                input: instrument(1, 0, "binance_spot_eth_usdt", (2, 1)),
+                expected: Err(InstrumentUpdateError::UnknownAsset(AssetIndex(2))),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut state = state(&[], None, &[]);
+            let actual = state.add_instrument(&test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+
+            let expected_len = if test.expected.is_ok() { 2 } else { 1 };
+            assert_eq!(state.instruments.0.len(), expected_len, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_engine_state_wind_down_instrument() {
+        let mut state = state(&[], None, &[]);
+        let btc_usdt = InstrumentIndex(0);
+
+        assert!(!state.is_winding_down(&btc_usdt));
+        assert_eq!(
+            state.wind_down_instrument(&InstrumentIndex(1)),
+            Err(InstrumentUpdateError::NotTracked(InstrumentIndex(1)))
+        );
+
+        state.wind_down_instrument(&btc_usdt).unwrap();
+        assert!(state.is_winding_down(&btc_usdt));
+
+        // Re-adding the instrument resumes trading it
+        let instrument = Keyed::new(
+            btc_usdt,
+            state
+                .instruments
+                .instrument_index(&btc_usdt)
+                .instrument
+                .clone(),
+        );
+        assert_eq!(state.add_instrument(&instrument), Ok(btc_usdt));
+        assert!(!state.is_winding_down(&btc_usdt));
+    }
+
+    #[test]
+    fn test_engine_state_update_from_market_liquidation_books_quote_balance() {
+        let mut state = state(
+            &[("usdt", Balance::new(dec!(1_000), dec!(1_000)))],
+            Some(MarginRequirement::new(dec!(10), dec!(0.005), dec!(0.01))),
+            &[],
+        );
+
+        // LONG 1 @ 100, liquidation price 100 * (1 - 0.1 + 0.005) = 90.5
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .update_from_trade(&entry(Side::Buy));
+
+        let market = |days: u64, price: f64| MarketEvent {
+            time_exchange: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+            time_received: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: "trade".to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Sell,
+            }),
+        };
+        let usdt = |state: &TestState| state.assets.asset_index(&AssetIndex(1)).balance.unwrap();
+
+        // Price above the liquidation price leaves the balance untouched
+        assert_eq!(state.update_from_market(&market(1, 95.0)), None);
+        assert_eq!(usdt(&state).value, Balance::new(dec!(1_000), dec!(1_000)));
+
+        // Breach liquidates @ 90.5, booking (90.5 - 100) * 1 - 0.905 fee against usdt
+        let Some(PositionMarketUpdate::Liquidated {
+            exited,
+            balance_change,
+        }) = state.update_from_market(&market(2, 90.0))
+        else {
+            panic!("expected PositionMarketUpdate::Liquidated");
+        };
+        assert_eq!(balance_change, dec!(-10.405));
+        assert_eq!(exited.fees_exit.fees, dec!(0.905));
+        assert_eq!(
+            usdt(&state).value,
+            Balance::new(dec!(989.595), dec!(989.595))
+        );
+        assert!(
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(0))
+                .position
+                .current
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_engine_state_update_from_market_debits_borrow_cost_from_base_balance() {
+        let mut state = state(
+            &[("btc", Balance::new(dec!(10), dec!(10)))],
+            None,
+            &[("btc", dec!(0.365))],
+        );
+
+        // SHORT 1 @ 100
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .update_from_trade(&entry(Side::Sell));
+
+        let market = |days: u64| MarketEvent {
+            time_exchange: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+            time_received: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: "trade".to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Sell,
+            }),
+        };
+        let btc = |state: &TestState| state.assets.asset_index(&AssetIndex(0)).balance.unwrap();
+
+        // 1 day of borrow cost: 1 * 100 * 0.365 / 365 = 0.1 usdt = 0.001 btc
+        assert_eq!(state.update_from_market(&market(1)), None);
+        assert_eq!(btc(&state).value, Balance::new(dec!(9.999), dec!(9.999)));
+        let position = state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .position
+            .current
+            .as_ref()
+            .unwrap();
+        assert_eq!(position.pnl_realised, dec!(-0.1));
+        assert_eq!(
+            position.time_borrow_accrued,
+            time_plus_days(DateTime::<Utc>::MIN_UTC, 1)
+        );
+
+        // Another day of borrow cost
+        state.update_from_market(&market(2));
+        assert_eq!(btc(&state).value, Balance::new(dec!(9.998), dec!(9.998)));
+    }
+
+    #[test]
+    fn test_engine_state_update_from_market_books_funding_against_settlement_balance() {
+        let mut state = state(
+            &[("usdt", Balance::new(dec!(1_000), dec!(1_000)))],
+            None,
+            &[],
+        );
+
+        // LONG 1 @ 100 of a usdt settled perpetual
+        let instrument = state.instruments.instrument_index_mut(&InstrumentIndex(0));
+        instrument.instrument.kind = InstrumentKind::Perpetual(PerpetualContract {
+            contract_size: dec!(1),
+            settlement_asset: AssetIndex(1),
+        });
+        instrument.update_from_trade(&entry(Side::Buy));
+
+        let market = |days: u64, kind: DataKind| MarketEvent {
+            time_exchange: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+            time_received: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind,
+        };
+        let funding = |days: u64| {
+            market(
+                days,
+                DataKind::FundingRate(FundingRate {
+                    rate: 0.001,
+                    time: time_plus_days(DateTime::<Utc>::MIN_UTC, days),
+                }),
+            )
+        };
+        let usdt = |state: &TestState| state.assets.asset_index(&AssetIndex(1)).balance.unwrap();
+        let pnl_realised = |state: &TestState| {
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(0))
+                .position
+                .current
+                .as_ref()
+                .unwrap()
+                .pnl_realised
+        };
+
+        state.update_from_market(&market(
+            1,
+            DataKind::Trade(PublicTrade {
+                id: "trade".to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Sell,
+            }),
+        ));
+
+        // LONG pays positive funding rate: 1 * 100 * 0.001 = 0.1 usdt
+        state.update_from_market(&funding(2));
+        assert_eq!(usdt(&state).value, Balance::new(dec!(999.9), dec!(999.9)));
+        assert_eq!(pnl_realised(&state), dec!(-0.1));
+
+        // Duplicate funding rate is not charged twice
+        state.update_from_market(&funding(2));
+        assert_eq!(usdt(&state).value, Balance::new(dec!(999.9), dec!(999.9)));
+        assert_eq!(pnl_realised(&state), dec!(-0.1));
+
+        // Next funding rate is charged
+        state.update_from_market(&funding(3));
+        assert_eq!(usdt(&state).value, Balance::new(dec!(999.8), dec!(999.8)));
+        assert_eq!(pnl_realised(&state), dec!(-0.2));
+    }
+
+    #[test]
+    fn test_instrument_state_signal_meta_persisted_onto_position() {
+        let mut state = state(&[], None, &[]);
+        let instrument = state.instruments.instrument_index_mut(&InstrumentIndex(0));
+        let signal = SignalMeta::new(Some(dec!(95)), Some(dec!(110)), Some("breakout".into()));
+
+        let trade = |cid: &str, side: Side, quantity: Decimal| Trade {
+            id: TradeId::new(cid),
+            order_id: OrderId::new(cid),
+            cid: Some(ClientOrderId::new(cid)),
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            side,
+            price: dec!(100),
+            quantity,
+            liquidity: None,
+            fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+        };
+
+        // Entry fill attaches the SignalMeta of its order to the opened Position
+        instrument
+            .signals
+            .insert(ClientOrderId::new("entry"), signal.clone());
+        assert_eq!(
+            instrument.update_from_trade(&trade("entry", Side::Buy, dec!(1))),
+            None
+        );
+        let position = instrument.position.current.as_ref().unwrap();
+        assert_eq!(position.signal, Some(signal.clone()));
+        assert!(instrument.signals.is_empty());
+
+        // Flip exits the Position with its SignalMeta, and opens a Position without one
+        let exited = instrument
+            .update_from_trade(&trade("flip", Side::Sell, dec!(2)))
+            .unwrap();
+        assert_eq!(exited.signal, Some(signal));
+        let position = instrument.position.current.as_ref().unwrap();
+        assert_eq!(position.signal, None);
+    }
+
+    #[test]
+    fn test_engine_state_apply_degraded_modes() {
+        struct TestCase {
+            degraded: Option<DegradedMode>,
+            side: Side,
+            quantity: Decimal,
+            expected_approved: bool,
+        }
+
+        let mut state = state(&[], None, &[]);
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .update_from_trade(&Trade {
+                id: TradeId::new("entry"),
+                order_id: OrderId::new("entry"),
+                cid: None,
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                liquidity: None,
+                fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+            });
+
+        let cases = vec![
+            // TC0: operational exchange
+            TestCase {
+                degraded: None,
+                side: Side::Buy,
+                quantity: dec!(1),
+                expected_approved: true,
+            },
+            // TC1: halted exchange refuses position exits
+            TestCase {
+                degraded: Some(DegradedMode::Halt),
+                side: Side::Sell,
+                quantity: dec!(1),
+                expected_approved: false,
+            },
+            // TC2: exit-only exchange approves position exits
+            TestCase {
+                degraded: Some(DegradedMode::ExitOnly),
+                side: Side::Sell,
+                quantity: dec!(1),
+                expected_approved: true,
+            },
+            // TC3: exit-only exchange refuses position increases
+            TestCase {
+                degraded: Some(DegradedMode::ExitOnly),
+                side: Side::Buy,
+                quantity: dec!(1),
+                expected_approved: false,
+            },
+            // TC4: exit-only exchange refuses position flips
+            TestCase {
+                degraded: Some(DegradedMode::ExitOnly),
+                side: Side::Sell,
+                quantity: dec!(2),
+                expected_approved: false,
+            },
+            // TC5: reroute without a SmartOrderRouter refuses
+            TestCase {
+                degraded: Some(DegradedMode::Reroute),
+                side: Side::Buy,
+                quantity: dec!(1),
+                expected_approved: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            state
+                .connectivity
+                .connectivity_index_mut(&ExchangeIndex(0))
+                .degraded = test.degraded;
+
+            let open = OrderRequestOpen {
+                key: OrderKey {
+                    exchange: ExchangeIndex(0),
+                    instrument: InstrumentIndex(0),
+                    strategy: StrategyId::new("strategy"),
+                    cid: ClientOrderId::new("cid"),
+                },
+                state: RequestOpen {
+                    side: test.side,
+                    price: dec!(100),
+                    quantity: test.quantity,
+                    kind: OrderKind::Market,
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    reduce_only: false,
+                },
+            };
+
+            let (approved, refused) = state.apply_degraded_modes(vec![open], None);
+            assert_eq!(
+                approved.len(),
+                usize::from(test.expected_approved),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                refused.len(),
+                usize::from(!test.expected_approved),
+                "TC{index} failed"
+            );
+        }
+    }
+}
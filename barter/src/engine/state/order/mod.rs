@@ -5,7 +5,7 @@ use barter_execution::order::{
     Order,
     id::ClientOrderId,
     request::{OrderRequestCancel, OrderRequestOpen, OrderResponseCancel},
-    state::{ActiveOrderState, CancelInFlight, OrderState},
+    state::{ActiveOrderState, CancelInFlight, OrderLifecycle, OrderState},
 };
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::snapshot::Snapshot;
@@ -32,9 +32,11 @@ pub mod manager;
 /// # State Transitions
 /// Orders tend to progress through the following states:
 /// 1. OpenInFlight - Initial order request sent to exchange
-/// 2. Open - Order confirmed as open on exchange
+/// 2. Open - Order confirmed as open on exchange (possibly partially filled)
 /// 3. CancelInFlight - Cancellation request sent to exchange
 /// 4. Cancelled/Expired/FullyFilled - Terminal states, once achieved order is no longer tracked.
+///
+/// See [`Orders::lifecycle`] for the flattened [`OrderLifecycle`] stage of a tracked order.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Constructor)]
 pub struct Orders<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex>(
     pub FnvHashMap<ClientOrderId, Order<ExchangeKey, InstrumentKey, ActiveOrderState>>,
@@ -46,6 +48,28 @@ impl<ExchangeKey, InstrumentKey> Default for Orders<ExchangeKey, InstrumentKey>
     }
 }
 
+impl<ExchangeKey, InstrumentKey> Orders<ExchangeKey, InstrumentKey> {
+    /// Determine the [`OrderLifecycle`] stage of the tracked order with the provided
+    /// [`ClientOrderId`].
+    ///
+    /// Returns `None` if the order is not tracked (eg/ it has reached a terminal stage).
+    pub fn lifecycle(&self, cid: &ClientOrderId) -> Option<OrderLifecycle> {
+        self.0
+            .get(cid)
+            .map(|order| order.state.lifecycle(order.quantity))
+    }
+
+    /// Returns an `Iterator` of tracked orders at the provided [`OrderLifecycle`] stage.
+    pub fn orders_by_lifecycle(
+        &self,
+        lifecycle: OrderLifecycle,
+    ) -> impl Iterator<Item = &Order<ExchangeKey, InstrumentKey, ActiveOrderState>> {
+        self.0
+            .values()
+            .filter(move |order| order.state.lifecycle(order.quantity) == lifecycle)
+    }
+}
+
 impl<ExchangeKey, InstrumentKey> OrderManager<ExchangeKey, InstrumentKey>
     for Orders<ExchangeKey, InstrumentKey>
 where
@@ -434,6 +458,7 @@ mod tests {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
             state,
         }
     }
@@ -460,6 +485,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::inactive(Cancelled {
                 id: OrderId(SmolStr::default()),
                 time_exchange: Default::default(),
@@ -482,6 +508,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::fully_filled(),
         })
     }
@@ -501,6 +528,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::inactive(OrderError::Connectivity(ConnectivityError::Timeout)),
         })
     }
@@ -520,6 +548,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::expired(),
         })
     }
@@ -540,6 +569,7 @@ mod tests {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
             state: OrderState::active(open(time_exchange)),
         })
     }
@@ -587,6 +617,7 @@ mod tests {
                 quantity: dec!(1),
                 kind: OrderKind::Limit,
                 time_in_force: TimeInForce::GoodUntilEndOfDay,
+                reduce_only: false,
             },
         }
     }
@@ -1132,4 +1163,66 @@ mod tests {
             assert_eq!(test.state, test.expected, "TC{index} failed")
         }
     }
+
+    #[test]
+    fn test_lifecycle() {
+        struct TestCase {
+            state: ActiveOrderState,
+            expected: OrderLifecycle,
+        }
+
+        let time = DateTime::<Utc>::MIN_UTC;
+        let open_filled = |filled_quantity| Open {
+            filled_quantity,
+            ..open(time)
+        };
+
+        let cases = vec![
+            // TC0: OpenInFlight
+            TestCase {
+                state: ActiveOrderState::OpenInFlight(OpenInFlight),
+                expected: OrderLifecycle::OpenInFlight,
+            },
+            // TC1: Open without fills
+            TestCase {
+                state: ActiveOrderState::Open(open_filled(dec!(0))),
+                expected: OrderLifecycle::Open,
+            },
+            // TC2: Open with partial fills
+            TestCase {
+                state: ActiveOrderState::Open(open_filled(dec!(0.5))),
+                expected: OrderLifecycle::PartiallyFilled,
+            },
+            // TC3: Open with full fills
+            TestCase {
+                state: ActiveOrderState::Open(open_filled(dec!(1))),
+                expected: OrderLifecycle::FullyFilled,
+            },
+            // TC4: CancelInFlight
+            TestCase {
+                state: ActiveOrderState::CancelInFlight(CancelInFlight { order: None }),
+                expected: OrderLifecycle::CancelInFlight,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let cid = ClientOrderId::default();
+            let state = orders([order(cid.clone(), test.state)]);
+
+            assert_eq!(
+                state.lifecycle(&cid),
+                Some(test.expected),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                state.orders_by_lifecycle(test.expected).count(),
+                1,
+                "TC{index} failed"
+            );
+        }
+
+        // Untracked order
+        let state = Orders::<ExchangeId, u64>::default();
+        assert_eq!(state.lifecycle(&ClientOrderId::default()), None);
+    }
 }
@@ -4,21 +4,32 @@ use barter_instrument::{
     asset::{AssetIndex, QuoteAsset},
     instrument::InstrumentIndex,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_more::Constructor;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
 use std::fmt::Debug;
 use tracing::error;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize, Constructor)]
 pub struct PositionManager<InstrumentKey = InstrumentIndex> {
     pub current: Option<Position<QuoteAsset, InstrumentKey>>,
+
+    /// Optional [`TrailingStop`] configuration attached to every newly opened [`Position`].
+    pub trailing_stop: Option<TrailingStop>,
+
+    /// Optional [`BreakEvenStop`] configuration attached to every newly opened [`Position`].
+    pub break_even_stop: Option<BreakEvenStop>,
 }
 
 impl<InstrumentKey> Default for PositionManager<InstrumentKey> {
     fn default() -> Self {
-        Self { current: None }
+        Self {
+            current: None,
+            trailing_stop: None,
+            break_even_stop: None,
+        }
     }
 }
 
@@ -29,6 +40,8 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
     /// - Opening a new position if none exists
     /// - Updating an existing position (increase/decrease/close)
     /// - Handling position flips (close existing & open new with any remaining trade quantity)
+    /// - Attaching the configured [`TrailingStop`] & [`BreakEvenStop`] to any newly opened
+    ///   position
     pub fn update_from_trade(
         &mut self,
         trade: &Trade<QuoteAsset, InstrumentKey>,
@@ -36,7 +49,9 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
     where
         InstrumentKey: Debug + Clone + PartialEq,
     {
-        let (current, closed) = match self.current.take() {
+        let had_position = self.current.is_some();
+
+        let (mut current, closed) = match self.current.take() {
             Some(position) => {
                 // Update current Position, maybe closing it, and maybe opening a new Position
                 // with leftover trade.quantity
@@ -48,6 +63,13 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
             }
         };
 
+        // Attach configured stops to any newly opened Position (including flips)
+        let opened = !had_position || closed.is_some();
+        if let Some(position) = current.as_mut().filter(|_| opened) {
+            position.trailing_stop = self.trailing_stop.clone();
+            position.break_even_stop = self.break_even_stop.clone();
+        }
+
         self.current = current;
 
         closed
@@ -77,12 +99,14 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 /// let position = Position::from(&Trade {
 ///     id: TradeId::new("trade_1"),
 ///     order_id: OrderId::new("order_1"),
+///     cid: None,
 ///     instrument: InstrumentNameInternal::new("BTC-USD"),
 ///     strategy: StrategyId::new("strategy_1"),
 ///     time_exchange: DateTime::from_str("2024-01-01T00:00:00Z").unwrap(),
 ///     side: Side::Buy,
 ///     price: dec!(50_000.0),
 ///     quantity: dec!(0.1),
+///     liquidity: None,
 ///     fees: AssetFees::quote_fees(dec!(5.0))
 /// });
 /// assert_eq!(position.side, Side::Buy);
@@ -92,12 +116,14 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 /// let (updated_position, closed_position) = position.update_from_trade(&Trade {
 ///     id: TradeId::new("trade_2"),
 ///     order_id: OrderId::new("order_2"),
+///     cid: None,
 ///     instrument: InstrumentNameInternal::new("BTC-USD"),
 ///     strategy: StrategyId::new("strategy_1"),
 ///     time_exchange: DateTime::from_str("2024-01-01T01:00:00Z").unwrap(),
 ///     side: Side::Sell,
 ///     price: dec!(60_000.0),
 ///     quantity: dec!(0.05),
+///     liquidity: None,
 ///     fees: AssetFees::quote_fees(dec!(2.5))
 /// });
 ///
@@ -125,12 +151,14 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 /// let position = Position::from(&Trade {
 ///     id: TradeId::new("trade_1"),
 ///     order_id: OrderId::new("order_1"),
+///     cid: None,
 ///     instrument: InstrumentNameInternal::new("BTC-USD"),
 ///     strategy: StrategyId::new("strategy_1"),
 ///     time_exchange: DateTime::from_str("2024-01-01T00:00:00Z").unwrap(),
 ///     side: Side::Sell,
 ///     price: dec!(50_000.0),
 ///     quantity: dec!(0.1),
+///     liquidity: None,
 ///     fees: AssetFees::quote_fees(dec!(5.0))
 /// });
 /// assert_eq!(position.side, Side::Sell);
@@ -140,12 +168,14 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 /// let (new_position, closed_position) = position.update_from_trade(&Trade {
 ///     id: TradeId::new("trade_2"),
 ///     order_id: OrderId::new("order_2"),
+///     cid: None,
 ///     instrument: InstrumentNameInternal::new("BTC-USD"),
 ///     strategy: StrategyId::new("strategy_1"),
 ///     time_exchange: DateTime::from_str("2024-01-01T01:00:00Z").unwrap(),
 ///     side: Side::Buy,
 ///     price: dec!(40_000.0),
 ///     quantity: dec!(0.2),
+///     liquidity: None,
 ///     fees: AssetFees::quote_fees(dec!(10.0))
 /// });
 ///
@@ -189,6 +219,28 @@ pub struct Position<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     /// Note this includes fees.
     pub pnl_realised: Decimal,
 
+    /// Maximum adverse excursion (MAE) - the largest observed price move against the
+    /// [`Position`], measured from the `price_entry_average`.
+    ///
+    /// Note this is an absolute price distance (always positive), and is updated alongside
+    /// `pnl_unrealised`.
+    pub excursion_adverse_max: Decimal,
+
+    /// Maximum favourable excursion (MFE) - the largest observed price move in favour of the
+    /// [`Position`], measured from the `price_entry_average`.
+    ///
+    /// Note this is an absolute price distance (always positive), and is updated alongside
+    /// `pnl_unrealised`.
+    pub excursion_favourable_max: Decimal,
+
+    /// Optional [`TrailingStop`] that is updated on every market price update, and triggered
+    /// when the price retraces by the trail distance.
+    pub trailing_stop: Option<TrailingStop>,
+
+    /// Optional [`BreakEvenStop`] that moves the protective stop to the `price_entry_average`
+    /// once the `pnl_unrealised` reaches a configured threshold.
+    pub break_even_stop: Option<BreakEvenStop>,
+
     /// Cumulative fees paid when entering/increasing [`Position`] quantity.
     pub fees_enter: AssetFees<AssetKey>,
 
@@ -204,8 +256,17 @@ pub struct Position<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     /// new market price.
     pub time_exchange_update: DateTime<Utc>,
 
+    /// Timestamp up to which the borrow cost of a SHORT [`Position`] has been accrued (see
+    /// [`Position::accrue_borrow_cost`]).
+    pub time_borrow_accrued: DateTime<Utc>,
+
     /// [`TradeId`]s of all the [`Trade`]s associated with this [`Position`].
     pub trades: Vec<TradeId>,
+
+    /// Optional [`SignalMeta`] attached by the strategy to the order that opened this
+    /// [`Position`].
+    #[serde(default)]
+    pub signal: Option<SignalMeta>,
 }
 
 impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
@@ -298,12 +359,14 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
                 let next_position_trade = Trade {
                     id: trade.id.clone(),
                     order_id: trade.order_id.clone(),
+                    cid: trade.cid.clone(),
                     instrument: trade.instrument.clone(),
                     strategy: trade.strategy.clone(),
                     time_exchange: trade.time_exchange,
                     side: trade.side,
                     price: trade.price,
                     quantity: next_position_quantity,
+                    liquidity: trade.liquidity,
                     fees: AssetFees {
                         asset: trade.fees.asset.clone(),
                         fees: next_position_fee_enter,
@@ -353,6 +416,126 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
             self.fees_enter.fees,
             price,
         );
+        self.update_excursions(price);
+    }
+
+    /// Update the [`Position`] maximum adverse & favourable excursions (MAE & MFE) from the
+    /// provided price.
+    pub fn update_excursions(&mut self, price: Decimal) {
+        let excursion = calculate_price_excursion(self.side, self.price_entry_average, price);
+
+        if excursion.is_sign_negative() {
+            self.excursion_adverse_max = self.excursion_adverse_max.max(excursion.abs());
+        } else {
+            self.excursion_favourable_max = self.excursion_favourable_max.max(excursion);
+        }
+    }
+
+    /// Accrue the borrow cost of a SHORT [`Position`] since the `time_borrow_accrued`, deducting
+    /// it from the `pnl_realised`.
+    ///
+    /// Borrow cost is calculated from the provided annual borrow rate (eg/ 0.05 for 5%) and the
+    /// notional value of the borrowed quantity at the provided price. LONG positions accrue no
+    /// borrow cost.
+    ///
+    /// Returns the accrued borrow cost.
+    pub fn accrue_borrow_cost(
+        &mut self,
+        rate_annual: Decimal,
+        price: Decimal,
+        time: DateTime<Utc>,
+    ) -> Decimal {
+        if self.side == Side::Buy || time <= self.time_borrow_accrued {
+            return Decimal::ZERO;
+        }
+
+        let cost = calculate_borrow_cost(
+            self.quantity_abs,
+            price,
+            rate_annual,
+            time.signed_duration_since(self.time_borrow_accrued),
+        );
+
+        self.pnl_realised -= cost;
+        self.time_borrow_accrued = time;
+
+        cost
+    }
+
+    /// Book a perpetual funding payment against the `pnl_realised`.
+    ///
+    /// The `amount` is signed, positive if received and negative if paid.
+    pub fn apply_funding_payment(&mut self, amount: Decimal) {
+        self.pnl_realised += amount;
+    }
+
+    /// Book the funding payment of a perpetual [`Position`] for the provided funding rate and
+    /// mark price against the `pnl_realised`.
+    ///
+    /// Returns the signed funding payment, positive if received and negative if paid.
+    pub fn apply_funding_rate(&mut self, rate: Decimal, price: Decimal) -> Decimal {
+        let amount = calculate_funding_payment(self.side, self.quantity_abs, price, rate);
+        self.apply_funding_payment(amount);
+        amount
+    }
+
+    /// Update the [`Position`] [`TrailingStop`] (if configured) from a new market price.
+    ///
+    /// Returns true if the [`TrailingStop`] is triggered.
+    pub fn update_trailing_stop(&mut self, price: Decimal) -> bool {
+        let side = self.side;
+        self.trailing_stop
+            .as_mut()
+            .is_some_and(|trailing_stop| trailing_stop.update(side, price))
+    }
+
+    /// Update the [`Position`] [`BreakEvenStop`] (if configured) from a new market price.
+    ///
+    /// Note that this should be called after [`Self::update_pnl_unrealised`], since the
+    /// `BreakEvenStop` is armed using the latest `pnl_unrealised`.
+    ///
+    /// Returns true if the stop was moved to the `price_entry_average` by this update.
+    pub fn update_break_even_stop(&mut self, price: Decimal) -> bool {
+        let (side, price_entry_average, pnl_unrealised) =
+            (self.side, self.price_entry_average, self.pnl_unrealised);
+
+        self.break_even_stop
+            .as_mut()
+            .is_some_and(|break_even_stop| {
+                break_even_stop.update(side, price_entry_average, pnl_unrealised, price)
+            })
+    }
+
+    /// Returns true if either the [`TrailingStop`] or [`BreakEvenStop`] has been triggered.
+    pub fn is_stop_triggered(&self) -> bool {
+        self.trailing_stop
+            .as_ref()
+            .is_some_and(|trailing_stop| trailing_stop.triggered)
+            || self
+                .break_even_stop
+                .as_ref()
+                .is_some_and(|break_even_stop| break_even_stop.triggered)
+    }
+
+    /// Forcibly close the entire [`Position`] at the provided liquidation price, charging a
+    /// liquidation penalty fee of `fee_rate` (eg/ 0.01 for 1%) on the closed notional value.
+    ///
+    /// Returns the liquidated [`PositionExited`].
+    pub fn liquidate(
+        mut self,
+        price: Decimal,
+        fee_rate: Decimal,
+        time: DateTime<Utc>,
+    ) -> PositionExited<QuoteAsset, InstrumentKey> {
+        let fee = self.quantity_abs * price * fee_rate;
+
+        self.fees_exit.fees += fee;
+        self.time_exchange_update = time;
+        self.update_pnl_realised(self.quantity_abs, price, fee);
+        self.quantity_abs = Decimal::ZERO;
+        self.update_pnl_unrealised(price);
+
+        PositionExited::from(self)
     }
 
     /// Updates the [`Position`] `pnl_realised` from a closed portion of the [`Position`] quantity.
@@ -388,11 +571,17 @@ where
             quantity_abs_max: trade.quantity.abs(),
             pnl_unrealised: Decimal::ZERO,
             pnl_realised: -trade.fees.fees,
+            excursion_adverse_max: Decimal::ZERO,
+            excursion_favourable_max: Decimal::ZERO,
+            trailing_stop: None,
+            break_even_stop: None,
             fees_enter: trade.fees.clone(),
             fees_exit: AssetFees::default(),
             time_enter: trade.time_exchange,
             time_exchange_update: trade.time_exchange,
+            time_borrow_accrued: trade.time_exchange,
             trades,
+            signal: None,
         }
     }
 }
@@ -425,6 +614,13 @@ pub struct PositionExited<AssetKey, InstrumentKey = InstrumentIndex> {
     /// Note this includes fees.
     pub pnl_realised: Decimal,
 
+    /// Maximum adverse excursion (MAE) price distance observed during the [`Position`] lifetime.
+    pub excursion_adverse_max: Decimal,
+
+    /// Maximum favourable excursion (MFE) price distance observed during the [`Position`]
+    /// lifetime.
+    pub excursion_favourable_max: Decimal,
+
     /// Cumulative fees paid when entering the [`Position`].
     pub fees_enter: AssetFees<AssetKey>,
 
@@ -439,6 +635,11 @@ pub struct PositionExited<AssetKey, InstrumentKey = InstrumentIndex> {
 
     /// [`TradeId`]s of all the [`Trade`]s associated with the closed [`Position`].
     pub trades: Vec<TradeId>,
+
+    /// Optional [`SignalMeta`] attached by the strategy to the order that opened the closed
+    /// [`Position`].
+    #[serde(default)]
+    pub signal: Option<SignalMeta>,
 }
 
 impl<AssetKey, InstrumentKey> From<Position<AssetKey, InstrumentKey>>
@@ -451,12 +652,319 @@ impl<AssetKey, InstrumentKey> From<Position<AssetKey, InstrumentKey>>
             price_entry_average: value.price_entry_average,
             quantity_abs_max: value.quantity_abs_max,
             pnl_realised: value.pnl_realised,
+            excursion_adverse_max: value.excursion_adverse_max,
+            excursion_favourable_max: value.excursion_favourable_max,
             fees_enter: value.fees_enter,
             fees_exit: value.fees_exit,
             time_enter: value.time_enter,
             time_exit: value.time_exchange_update,
             trades: value.trades,
+            signal: value.signal,
+        }
+    }
+}
+
+/// Distance a [`TrailingStop`] trails behind the most favourable price.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum TrailDistance {
+    /// Fixed price distance.
+    Absolute(Decimal),
+
+    /// Basis points of the most favourable price (eg/ 50 for 0.5%).
+    Bps(Decimal),
+
+    /// Multiple of the Average True Range (ATR).
+    ///
+    /// The `atr` can be refreshed via [`TrailingStop::update_atr`].
+    Atr { atr: Decimal, multiple: Decimal },
+}
+
+impl TrailDistance {
+    /// Calculate the absolute trail price distance from the provided most favourable price.
+    pub fn distance(&self, price_extreme: Decimal) -> Decimal {
+        match self {
+            Self::Absolute(distance) => *distance,
+            Self::Bps(bps) => price_extreme * *bps / Decimal::from(10_000),
+            Self::Atr { atr, multiple } => *atr * *multiple,
+        }
+    }
+}
+
+/// Structured metadata attached by a strategy to the signal behind an open order request,
+/// persisted onto the resulting [`Position`] so post-trade review can group results by setup.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize, Constructor,
+)]
+pub struct SignalMeta {
+    /// Intended protective stop price.
+    pub stop: Option<Decimal>,
+
+    /// Intended target (ie/ take-profit) price.
+    pub target: Option<Decimal>,
+
+    /// Rationale tag identifying the setup type (eg/ "breakout", "mean_reversion").
+    pub tag: Option<SmolStr>,
+}
+
+impl SignalMeta {
+    /// Planned reward to risk ratio of a [`Position`] entered at the provided price, ie/ the
+    /// distance to the `target` divided by the distance to the `stop`.
+    ///
+    /// Returns `None` if the `stop` or `target` is not set, or the `stop` is at the entry price.
+    pub fn reward_risk(&self, price_entry: Decimal) -> Option<Decimal> {
+        let risk = (price_entry - self.stop?).abs();
+        let reward = (self.target? - price_entry).abs();
+        (!risk.is_zero()).then(|| reward / risk)
+    }
+}
+
+/// Trailing stop tracked inside a [`Position`], updated on every market price update.
+///
+/// Once the (optional) activation price is reached, the stop trails the most favourable price
+/// by the configured [`TrailDistance`]. The `TrailingStop` is triggered when the price retraces
+/// to the stop price.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TrailingStop {
+    /// Optional price the [`Position`] must reach (favourably) before the stop starts trailing.
+    ///
+    /// If `None`, the stop starts trailing immediately.
+    pub price_activation: Option<Decimal>,
+
+    /// Distance the stop trails behind the most favourable price.
+    pub distance: TrailDistance,
+
+    /// Most favourable price observed since activation.
+    pub price_extreme: Option<Decimal>,
+
+    /// Current stop price, if activated.
+    pub price_stop: Option<Decimal>,
+
+    /// True if the price has retraced to the `price_stop`.
+    pub triggered: bool,
+}
+
+impl TrailingStop {
+    /// Construct a new inactive `TrailingStop`.
+    pub fn new(price_activation: Option<Decimal>, distance: TrailDistance) -> Self {
+        Self {
+            price_activation,
+            distance,
+            price_extreme: None,
+            price_stop: None,
+            triggered: false,
+        }
+    }
+
+    /// Refresh the `atr` used by a [`TrailDistance::Atr`] distance. No-op for other distances.
+    pub fn update_atr(&mut self, value: Decimal) {
+        if let TrailDistance::Atr { atr, .. } = &mut self.distance {
+            *atr = value;
+        }
+    }
+
+    /// Update the `TrailingStop` from a new market price for a [`Position`] with the provided
+    /// [`Side`].
+    ///
+    /// Returns true if the `TrailingStop` is triggered.
+    pub fn update(&mut self, side: Side, price: Decimal) -> bool {
+        if self.triggered {
+            return true;
+        }
+
+        let is_more_favourable = |current: Decimal| match side {
+            Side::Buy => price >= current,
+            Side::Sell => price <= current,
+        };
+
+        let price_extreme = match self.price_extreme {
+            Some(extreme) if is_more_favourable(extreme) => price,
+            Some(extreme) => extreme,
+            None if self.price_activation.is_none_or(is_more_favourable) => price,
+            None => return false,
+        };
+
+        let distance = self.distance.distance(price_extreme);
+        let price_stop = match side {
+            Side::Buy => price_extreme - distance,
+            Side::Sell => price_extreme + distance,
+        };
+
+        self.price_extreme = Some(price_extreme);
+        self.price_stop = Some(price_stop);
+        self.triggered = match side {
+            Side::Buy => price <= price_stop,
+            Side::Sell => price >= price_stop,
+        };
+
+        self.triggered
+    }
+}
+
+/// Break-even stop tracked inside a [`Position`], updated on every market price update.
+///
+/// Once the [`Position`] `pnl_unrealised` reaches the configured `pnl_threshold`, the protective
+/// stop is moved to the `price_entry_average`. The `BreakEvenStop` is then triggered if the price
+/// retraces back to the entry price, ensuring a winning [`Position`] does not become a loser.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct BreakEvenStop {
+    /// Unrealised PnL the [`Position`] must reach before the stop is moved to break-even.
+    pub pnl_threshold: Decimal,
+
+    /// Current stop price, if moved to break-even.
+    pub price_stop: Option<Decimal>,
+
+    /// True if the price has retraced to the `price_stop`.
+    pub triggered: bool,
+}
+
+impl BreakEvenStop {
+    /// Construct a new inactive `BreakEvenStop`.
+    pub fn new(pnl_threshold: Decimal) -> Self {
+        Self {
+            pnl_threshold,
+            price_stop: None,
+            triggered: false,
+        }
+    }
+
+    /// Update the `BreakEvenStop` from the latest state of a [`Position`] with the provided
+    /// [`Side`].
+    ///
+    /// Returns true if the stop was moved to the `price_entry_average` by this update.
+    pub fn update(
+        &mut self,
+        side: Side,
+        price_entry_average: Decimal,
+        pnl_unrealised: Decimal,
+        price: Decimal,
+    ) -> bool {
+        if self.triggered {
+            return false;
+        }
+
+        let moved = match self.price_stop {
+            Some(_) => false,
+            None if pnl_unrealised >= self.pnl_threshold => {
+                self.price_stop = Some(price_entry_average);
+                true
+            }
+            None => return false,
+        };
+
+        self.triggered = match side {
+            Side::Buy => price <= price_entry_average,
+            Side::Sell => price >= price_entry_average,
+        };
+
+        moved
+    }
+}
+
+/// Protective stop update of an open [`Position`], produced when a market price update moves the
+/// [`BreakEvenStop`] to the `price_entry_average`.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct PositionUpdate<InstrumentKey = InstrumentIndex> {
+    pub instrument: InstrumentKey,
+
+    /// [`Side`] of the [`Position`] (ie/ the protective stop is on the opposite `Side`).
+    pub side: Side,
+
+    /// Absolute [`Position`] quantity protected by the stop.
+    pub quantity_abs: Decimal,
+
+    /// New protective stop price.
+    pub price_stop: Decimal,
+
+    pub time_exchange: DateTime<Utc>,
+}
+
+/// [`Position`] change produced by a market price update.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum PositionMarketUpdate<InstrumentKey = InstrumentIndex> {
+    /// Protective stop of the open [`Position`] was moved.
+    Update(PositionUpdate<InstrumentKey>),
+
+    /// [`Position`] was liquidated after breaching its maintenance margin.
+    Liquidated {
+        exited: PositionExited<QuoteAsset, InstrumentKey>,
+
+        /// Quote asset balance change of the liquidation (ie/ the PnL of closing the
+        /// [`Position`] at the liquidation price, net of the liquidation fee).
+        balance_change: Decimal,
+    },
+}
+
+/// Simulated isolated margin requirement of a leveraged [`Position`], used to model exchange
+/// margin calls and forced liquidation (eg/ in back-tests).
+///
+/// The [`Position`] margin is the entry notional divided by the `leverage`. Once losses erode
+/// the margin down to the maintenance margin (`maintenance_rate` of the entry notional), the
+/// [`Position`] is liquidated at the liquidation price and charged the `liquidation_fee_rate`.
+///
+/// A non-positive `leverage` is invalid, so no margin or liquidation price can be calculated.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct MarginRequirement {
+    /// Leverage used to open the [`Position`] (eg/ 10 for 10x).
+    pub leverage: Decimal,
+
+    /// Maintenance margin rate of the [`Position`] entry notional (eg/ 0.005 for 0.5%).
+    pub maintenance_rate: Decimal,
+
+    /// Penalty fee rate charged on the liquidated notional value (eg/ 0.01 for 1%).
+    pub liquidation_fee_rate: Decimal,
+}
+
+impl MarginRequirement {
+    /// Calculate the margin required to hold the provided notional value.
+    ///
+    /// This is the greater of the initial margin (notional / leverage) and the maintenance
+    /// margin (notional * maintenance_rate).
+    ///
+    /// Returns `None` if the `leverage` is not positive.
+    pub fn margin(&self, notional: Decimal) -> Option<Decimal> {
+        let notional = notional.abs();
+        self.has_valid_leverage()
+            .then(|| (notional / self.leverage).max(notional * self.maintenance_rate))
+    }
+
+    /// Calculate the price at which a [`Position`] with the provided [`Side`] and
+    /// `price_entry_average` breaches the maintenance margin.
+    ///
+    /// LONG: entry * (1 - 1/leverage + maintenance_rate) <br>
+    /// SHORT: entry * (1 + 1/leverage - maintenance_rate)
+    ///
+    /// Returns `None` if the `leverage` is not positive.
+    pub fn price_liquidation(&self, side: Side, price_entry_average: Decimal) -> Option<Decimal> {
+        if !self.has_valid_leverage() {
+            return None;
         }
+
+        let margin_buffer = Decimal::ONE / self.leverage - self.maintenance_rate;
+
+        Some(match side {
+            Side::Buy => price_entry_average * (Decimal::ONE - margin_buffer),
+            Side::Sell => price_entry_average * (Decimal::ONE + margin_buffer),
+        })
+    }
+
+    /// Returns true if the provided price breaches the maintenance margin of a [`Position`] with
+    /// the provided [`Side`] and `price_entry_average`.
+    ///
+    /// Always false if the `leverage` is not positive, since no liquidation price exists.
+    pub fn is_breached(&self, side: Side, price_entry_average: Decimal, price: Decimal) -> bool {
+        self.price_liquidation(side, price_entry_average)
+            .is_some_and(|price_liquidation| match side {
+                Side::Buy => price <= price_liquidation,
+                Side::Sell => price >= price_liquidation,
+            })
+    }
+
+    fn has_valid_leverage(&self) -> bool {
+        self.leverage > Decimal::ZERO
     }
 }
 
@@ -509,6 +1017,20 @@ pub fn calculate_pnl_unrealised(
     }
 }
 
+/// Calculate the signed price excursion of a [`Position`] at the provided price.
+///
+/// A positive value represents a favourable move, and a negative value an adverse move.
+pub fn calculate_price_excursion(
+    position_side: Side,
+    price_entry_average: Decimal,
+    price: Decimal,
+) -> Decimal {
+    match position_side {
+        Side::Buy => price - price_entry_average,
+        Side::Sell => price_entry_average - price,
+    }
+}
+
 /// Approximate the exit fees from closing a [`Position`] with `quantity_abs`.
 ///
 /// The `fees_enter` value was the fee cost to enter a [`Position`] of `quantity_abs_max`,
@@ -541,6 +1063,40 @@ pub fn calculate_pnl_realised(
     }
 }
 
+/// Calculate the cost of borrowing `quantity_abs` at the provided price for the `elapsed`
+/// duration, given an annual borrow rate (eg/ 0.05 for 5%).
+///
+/// Assumes a 365 day year.
+pub fn calculate_borrow_cost(
+    quantity_abs: Decimal,
+    price: Decimal,
+    rate_annual: Decimal,
+    elapsed: TimeDelta,
+) -> Decimal {
+    let elapsed_millis = Decimal::from(elapsed.num_milliseconds());
+    let year_millis = Decimal::from(TimeDelta::days(365).num_milliseconds());
+
+    quantity_abs * price * rate_annual * elapsed_millis / year_millis
+}
+
+/// Calculate the signed funding payment of a perpetual position with the provided funding rate,
+/// positive if received and negative if paid.
+///
+/// A positive funding rate means LONG positions pay SHORT positions.
+pub fn calculate_funding_payment(
+    side: Side,
+    quantity_abs: Decimal,
+    price: Decimal,
+    rate: Decimal,
+) -> Decimal {
+    let payment = quantity_abs * price * rate;
+
+    match side {
+        Side::Buy => -payment,
+        Side::Sell => payment,
+    }
+}
+
 /// Calculate the PnL returns.
 ///
 /// Returns = pnl_realised / cost_of_investment
@@ -585,6 +1141,10 @@ mod tests {
                     quantity_abs_max: dec!(2.0),
                     pnl_unrealised: dec!(0.0),
                     pnl_realised: dec!(-20.0), // Sum of fees
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(10.0),
+                    trailing_stop: None,
+                    break_even_stop: None,
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(20.0),
@@ -595,7 +1155,9 @@ mod tests {
                     },
                     time_enter: base_time,
                     time_exchange_update: time_plus_days(base_time, 1),
+                    time_borrow_accrued: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
                 expected_position_exited: None,
             },
@@ -611,6 +1173,10 @@ mod tests {
                     quantity_abs_max: dec!(2.0),
                     pnl_unrealised: dec!(67.5), // (150-100)*(2.0-0.5) - approx_exit_fees (1.5/2 * 10)
                     pnl_realised: dec!(10.0),   // (150-100)*0.5 - 15_fees
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(50.0),
+                    trailing_stop: None,
+                    break_even_stop: None,
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -621,7 +1187,9 @@ mod tests {
                     },
                     time_enter: base_time,
                     time_exchange_update: time_plus_days(base_time, 1),
+                    time_borrow_accrued: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
                 expected_position_exited: None,
             },
@@ -636,6 +1204,8 @@ mod tests {
                     price_entry_average: dec!(100.0),
                     quantity_abs_max: dec!(1.0),
                     pnl_realised: dec!(30.0), // (150-100)*1 - 20 (total fees)
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(50.0),
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -647,6 +1217,7 @@ mod tests {
                     time_enter: base_time,
                     time_exit: time_plus_days(base_time, 1),
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
             },
             // TC3: Position flip (close and open new)
@@ -661,6 +1232,10 @@ mod tests {
                     quantity_abs_max: dec!(1.0),
                     pnl_unrealised: dec!(0.0),
                     pnl_realised: dec!(-10.0), // Entry fees for new position (2-1)*(1/2)*20
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(0.0),
+                    trailing_stop: None,
+                    break_even_stop: None,
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -671,7 +1246,9 @@ mod tests {
                     },
                     time_enter: time_plus_days(base_time, 1),
                     time_exchange_update: time_plus_days(base_time, 1),
+                    time_borrow_accrued: time_plus_days(base_time, 1),
                     trades: vec![TradeId::new("trade_id")],
+                    signal: None,
                 }),
                 expected_position_exited: Some(PositionExited {
                     instrument: InstrumentNameInternal::new("instrument"),
@@ -679,6 +1256,8 @@ mod tests {
                     price_entry_average: dec!(100.0),
                     quantity_abs_max: dec!(1.0),
                     pnl_realised: dec!(30.0), // (150-100)*1 - 20 (total fees)
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(50.0),
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -690,6 +1269,7 @@ mod tests {
                     time_enter: base_time,
                     time_exit: time_plus_days(base_time, 1),
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
             },
             // TC4: Increase short position
@@ -704,6 +1284,10 @@ mod tests {
                     quantity_abs_max: dec!(2.0),
                     pnl_unrealised: dec!(0.0), // (90-80)*2 - approx_exit_fees(2/2 * 20)
                     pnl_realised: dec!(-20.0), // Sum of entry fees
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(10.0),
+                    trailing_stop: None,
+                    break_even_stop: None,
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(20.0),
@@ -714,7 +1298,9 @@ mod tests {
                     },
                     time_enter: base_time,
                     time_exchange_update: base_time,
+                    time_borrow_accrued: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
                 expected_position_exited: None,
             },
@@ -730,6 +1316,10 @@ mod tests {
                     quantity_abs_max: dec!(2.0),
                     pnl_unrealised: dec!(22.5), // (100-80)*1.5 - approx_exit_fees(1.5/2 * 10)
                     pnl_realised: dec!(-5.0),   // 10_fee_entry - (100-80)*0.5 - 5_fee_exit
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(20.0),
+                    trailing_stop: None,
+                    break_even_stop: None,
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -740,7 +1330,9 @@ mod tests {
                     },
                     time_enter: base_time,
                     time_exchange_update: base_time,
+                    time_borrow_accrued: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
                 expected_position_exited: None,
             },
@@ -755,6 +1347,8 @@ mod tests {
                     price_entry_average: dec!(100.0),
                     quantity_abs_max: dec!(1.0),
                     pnl_realised: dec!(0.0), // (100-80)*1 - 20 (total fees)
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(20.0),
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -766,6 +1360,7 @@ mod tests {
                     time_enter: base_time,
                     time_exit: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
             },
             // TC7: Short position flip (close and open long)
@@ -780,6 +1375,10 @@ mod tests {
                     quantity_abs_max: dec!(1.0),
                     pnl_unrealised: dec!(0.0),
                     pnl_realised: dec!(-10.0), // Entry fees for new position
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(0.0),
+                    trailing_stop: None,
+                    break_even_stop: None,
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -790,7 +1389,9 @@ mod tests {
                     },
                     time_enter: base_time,
                     time_exchange_update: base_time,
+                    time_borrow_accrued: base_time,
                     trades: vec![TradeId::new("trade_id")],
+                    signal: None,
                 }),
                 expected_position_exited: Some(PositionExited {
                     instrument: InstrumentNameInternal::new("instrument"),
@@ -798,6 +1399,8 @@ mod tests {
                     price_entry_average: dec!(100.0),
                     quantity_abs_max: dec!(1.0),
                     pnl_realised: dec!(0.0), // (100-80)*1 - 20 (total fees)
+                    excursion_adverse_max: dec!(0.0),
+                    excursion_favourable_max: dec!(20.0),
                     fees_enter: AssetFees {
                         asset: QuoteAsset,
                         fees: dec!(10.0),
@@ -809,6 +1412,7 @@ mod tests {
                     time_enter: base_time,
                     time_exit: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    signal: None,
                 }),
             },
         ];
@@ -1039,6 +1643,450 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_calculate_price_excursion() {
+        struct TestCase {
+            side: Side,
+            price_entry_average: Decimal,
+            price: Decimal,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: LONG price increase is favourable
+            TestCase {
+                side: Side::Buy,
+                price_entry_average: dec!(100.0),
+                price: dec!(110.0),
+                expected: dec!(10.0),
+            },
+            // TC1: LONG price decrease is adverse
+            TestCase {
+                side: Side::Buy,
+                price_entry_average: dec!(100.0),
+                price: dec!(90.0),
+                expected: dec!(-10.0),
+            },
+            // TC2: SHORT price decrease is favourable
+            TestCase {
+                side: Side::Sell,
+                price_entry_average: dec!(100.0),
+                price: dec!(90.0),
+                expected: dec!(10.0),
+            },
+            // TC3: SHORT price increase is adverse
+            TestCase {
+                side: Side::Sell,
+                price_entry_average: dec!(100.0),
+                price: dec!(110.0),
+                expected: dec!(-10.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = calculate_price_excursion(test.side, test.price_entry_average, test.price);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_position_update_excursions() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+        let mut position = Position::from(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0));
+
+        for price in [dec!(95.0), dec!(110.0), dec!(90.0), dec!(105.0)] {
+            position.update_pnl_unrealised(price);
+        }
+
+        assert_eq!(position.excursion_adverse_max, dec!(10.0));
+        assert_eq!(position.excursion_favourable_max, dec!(10.0));
+
+        let (_, exited) = position.update_from_trade(&trade(
+            time_plus_days(base_time, 1),
+            Side::Sell,
+            120.0,
+            1.0,
+            0.0,
+        ));
+        let exited = exited.unwrap();
+
+        assert_eq!(exited.excursion_adverse_max, dec!(10.0));
+        assert_eq!(exited.excursion_favourable_max, dec!(20.0));
+    }
+
+    #[test]
+    fn test_calculate_borrow_cost() {
+        struct TestCase {
+            quantity_abs: Decimal,
+            price: Decimal,
+            rate_annual: Decimal,
+            elapsed: TimeDelta,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: full year
+            TestCase {
+                quantity_abs: dec!(2.0),
+                price: dec!(100.0),
+                rate_annual: dec!(0.05),
+                elapsed: TimeDelta::days(365),
+                expected: dec!(10.0),
+            },
+            // TC1: single day
+            TestCase {
+                quantity_abs: dec!(1.0),
+                price: dec!(365.0),
+                rate_annual: dec!(0.1),
+                elapsed: TimeDelta::days(1),
+                expected: dec!(0.1),
+            },
+            // TC2: no elapsed time
+            TestCase {
+                quantity_abs: dec!(1.0),
+                price: dec!(100.0),
+                rate_annual: dec!(0.1),
+                elapsed: TimeDelta::zero(),
+                expected: dec!(0.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = calculate_borrow_cost(
+                test.quantity_abs,
+                test.price,
+                test.rate_annual,
+                test.elapsed,
+            );
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_position_accrue_borrow_cost() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // LONG Positions accrue no borrow cost
+        let mut long = Position::from(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0));
+        let cost = long.accrue_borrow_cost(dec!(0.1), dec!(365.0), time_plus_days(base_time, 1));
+        assert_eq!(cost, dec!(0.0));
+        assert_eq!(long.pnl_realised, dec!(0.0));
+
+        // SHORT Positions accrue borrow cost since the last accrual
+        let mut short = Position::from(&trade(base_time, Side::Sell, 100.0, 1.0, 0.0));
+        let cost = short.accrue_borrow_cost(dec!(0.1), dec!(365.0), time_plus_days(base_time, 1));
+        assert_eq!(cost, dec!(0.1));
+        assert_eq!(short.pnl_realised, dec!(-0.1));
+        assert_eq!(short.time_borrow_accrued, time_plus_days(base_time, 1));
+        assert_eq!(short.time_exchange_update, base_time);
+
+        // No additional borrow cost accrued for the same time
+        let cost = short.accrue_borrow_cost(dec!(0.1), dec!(365.0), time_plus_days(base_time, 1));
+        assert_eq!(cost, dec!(0.0));
+        assert_eq!(short.pnl_realised, dec!(-0.1));
+
+        // Other Position updates do not affect the borrow cost accrual period
+        short.time_exchange_update = time_plus_days(base_time, 2);
+        let cost = short.accrue_borrow_cost(dec!(0.1), dec!(365.0), time_plus_days(base_time, 2));
+        assert_eq!(cost, dec!(0.1));
+        assert_eq!(short.pnl_realised, dec!(-0.2));
+    }
+
+    #[test]
+    fn test_position_apply_funding_rate() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // LONG Positions pay positive funding rates
+        let mut long = Position::from(&trade(base_time, Side::Buy, 100.0, 2.0, 0.0));
+        let payment = long.apply_funding_rate(dec!(0.0001), dec!(110.0));
+        assert_eq!(payment, dec!(-0.022));
+        assert_eq!(long.pnl_realised, dec!(-0.022));
+
+        // SHORT Positions receive positive funding rates, and pay negative funding rates
+        let mut short = Position::from(&trade(base_time, Side::Sell, 100.0, 1.0, 0.0));
+        assert_eq!(
+            short.apply_funding_rate(dec!(0.001), dec!(100.0)),
+            dec!(0.1)
+        );
+        assert_eq!(
+            short.apply_funding_rate(dec!(-0.002), dec!(100.0)),
+            dec!(-0.2)
+        );
+        assert_eq!(short.pnl_realised, dec!(-0.1));
+    }
+
+    #[test]
+    fn test_trailing_stop_update() {
+        struct TestCase {
+            side: Side,
+            trailing_stop: TrailingStop,
+            prices: Vec<Decimal>,
+            expected: Vec<bool>,
+            expected_price_stop: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: LONG absolute trail triggered after retrace from high
+            TestCase {
+                side: Side::Buy,
+                trailing_stop: TrailingStop::new(None, TrailDistance::Absolute(dec!(10.0))),
+                prices: vec![dec!(100.0), dec!(120.0), dec!(115.0), dec!(110.0)],
+                expected: vec![false, false, false, true],
+                expected_price_stop: Some(dec!(110.0)),
+            },
+            // TC1: SHORT bps trail triggered after retrace from low
+            TestCase {
+                side: Side::Sell,
+                trailing_stop: TrailingStop::new(None, TrailDistance::Bps(dec!(100.0))),
+                prices: vec![dec!(100.0), dec!(90.0), dec!(90.5), dec!(90.9)],
+                expected: vec![false, false, false, true],
+                expected_price_stop: Some(dec!(90.9)),
+            },
+            // TC2: LONG trail not activated until activation price reached
+            TestCase {
+                side: Side::Buy,
+                trailing_stop: TrailingStop::new(
+                    Some(dec!(110.0)),
+                    TrailDistance::Absolute(dec!(5.0)),
+                ),
+                prices: vec![dec!(100.0), dec!(90.0), dec!(111.0), dec!(107.0)],
+                expected: vec![false, false, false, false],
+                expected_price_stop: Some(dec!(106.0)),
+            },
+            // TC3: SHORT ATR trail remains triggered once hit
+            TestCase {
+                side: Side::Sell,
+                trailing_stop: TrailingStop::new(
+                    None,
+                    TrailDistance::Atr {
+                        atr: dec!(2.0),
+                        multiple: dec!(2.0),
+                    },
+                ),
+                prices: vec![dec!(100.0), dec!(104.0), dec!(95.0)],
+                expected: vec![false, true, true],
+                expected_price_stop: Some(dec!(104.0)),
+            },
+        ];
+
+        for (index, mut test) in cases.into_iter().enumerate() {
+            let actual = test
+                .prices
+                .iter()
+                .map(|price| test.trailing_stop.update(test.side, *price))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(
+                test.trailing_stop.price_stop, test.expected_price_stop,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_position_manager_attaches_trailing_stop_to_new_positions() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+        let trailing_stop = TrailingStop::new(None, TrailDistance::Absolute(dec!(10.0)));
+        let mut manager = PositionManager::new(None, Some(trailing_stop.clone()), None);
+
+        // Open LONG Position
+        manager.update_from_trade(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0));
+        let position = manager.current.as_mut().unwrap();
+        assert_eq!(position.trailing_stop, Some(trailing_stop.clone()));
+
+        // TrailingStop updated from market prices
+        assert!(!position.update_trailing_stop(dec!(120.0)));
+
+        // Increasing the Position retains the current TrailingStop state
+        manager.update_from_trade(&trade(base_time, Side::Buy, 120.0, 1.0, 0.0));
+        let position = manager.current.as_ref().unwrap();
+        assert_eq!(
+            position.trailing_stop.as_ref().unwrap().price_stop,
+            Some(dec!(110.0))
+        );
+
+        // Flipping the Position attaches a fresh TrailingStop
+        manager.update_from_trade(&trade(base_time, Side::Sell, 110.0, 3.0, 0.0));
+        let position = manager.current.as_ref().unwrap();
+        assert_eq!(position.side, Side::Sell);
+        assert_eq!(position.trailing_stop, Some(trailing_stop));
+    }
+
+    #[test]
+    fn test_break_even_stop_update() {
+        struct TestCase {
+            side: Side,
+            updates: Vec<(Decimal, Decimal)>,
+            expected: Vec<bool>,
+            expected_price_stop: Option<Decimal>,
+            expected_triggered: bool,
+        }
+
+        let cases = vec![
+            // TC0: LONG stop not moved before threshold reached
+            TestCase {
+                side: Side::Buy,
+                updates: vec![(dec!(5.0), dec!(105.0)), (dec!(-5.0), dec!(95.0))],
+                expected: vec![false, false],
+                expected_price_stop: None,
+                expected_triggered: false,
+            },
+            // TC1: LONG stop moved to entry once, then triggered on retrace
+            TestCase {
+                side: Side::Buy,
+                updates: vec![
+                    (dec!(10.0), dec!(110.0)),
+                    (dec!(20.0), dec!(120.0)),
+                    (dec!(0.0), dec!(100.0)),
+                ],
+                expected: vec![true, false, false],
+                expected_price_stop: Some(dec!(100.0)),
+                expected_triggered: true,
+            },
+            // TC2: SHORT stop moved to entry and not triggered
+            TestCase {
+                side: Side::Sell,
+                updates: vec![(dec!(15.0), dec!(85.0)), (dec!(5.0), dec!(95.0))],
+                expected: vec![true, false],
+                expected_price_stop: Some(dec!(100.0)),
+                expected_triggered: false,
+            },
+            // TC3: SHORT stop moved to entry, then triggered on retrace
+            TestCase {
+                side: Side::Sell,
+                updates: vec![(dec!(10.0), dec!(90.0)), (dec!(-1.0), dec!(101.0))],
+                expected: vec![true, false],
+                expected_price_stop: Some(dec!(100.0)),
+                expected_triggered: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut stop = BreakEvenStop::new(dec!(10.0));
+
+            let actual = test
+                .updates
+                .iter()
+                .map(|(pnl_unrealised, price)| {
+                    stop.update(test.side, dec!(100.0), *pnl_unrealised, *price)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(
+                stop.price_stop, test.expected_price_stop,
+                "TC{index} failed"
+            );
+            assert_eq!(stop.triggered, test.expected_triggered, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_margin_requirement_margin() {
+        let margin = MarginRequirement::new(dec!(10), dec!(0.005), dec!(0.01));
+        assert_eq!(margin.margin(dec!(1000)), Some(dec!(100)));
+        assert_eq!(margin.margin(dec!(-1000)), Some(dec!(100)));
+
+        let margin = MarginRequirement::new(dec!(500), dec!(0.005), dec!(0.01));
+        assert_eq!(margin.margin(dec!(1000)), Some(dec!(5)));
+    }
+
+    #[test]
+    fn test_margin_requirement_non_positive_leverage() {
+        for (index, leverage) in [dec!(0), dec!(-10)].into_iter().enumerate() {
+            let margin = MarginRequirement::new(leverage, dec!(0.005), dec!(0.01));
+            assert_eq!(margin.margin(dec!(1000)), None, "TC{index} failed");
+            assert_eq!(
+                margin.price_liquidation(Side::Buy, dec!(100)),
+                None,
+                "TC{index} failed"
+            );
+            assert!(
+                !margin.is_breached(Side::Buy, dec!(100), dec!(1)),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_margin_requirement_is_breached() {
+        struct TestCase {
+            side: Side,
+            price: Decimal,
+            expected_price_liquidation: Decimal,
+            expected: bool,
+        }
+
+        // 10x leverage with 0.5% maintenance margin
+        let margin = MarginRequirement::new(dec!(10), dec!(0.005), dec!(0.01));
+
+        let cases = vec![
+            // TC0: LONG above liquidation price
+            TestCase {
+                side: Side::Buy,
+                price: dec!(95.0),
+                expected_price_liquidation: dec!(90.5),
+                expected: false,
+            },
+            // TC1: LONG at liquidation price
+            TestCase {
+                side: Side::Buy,
+                price: dec!(90.5),
+                expected_price_liquidation: dec!(90.5),
+                expected: true,
+            },
+            // TC2: SHORT below liquidation price
+            TestCase {
+                side: Side::Sell,
+                price: dec!(109.0),
+                expected_price_liquidation: dec!(109.5),
+                expected: false,
+            },
+            // TC3: SHORT gapped through liquidation price
+            TestCase {
+                side: Side::Sell,
+                price: dec!(120.0),
+                expected_price_liquidation: dec!(109.5),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(
+                margin.price_liquidation(test.side, dec!(100.0)),
+                Some(test.expected_price_liquidation),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                margin.is_breached(test.side, dec!(100.0), test.price),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_position_liquidate() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // LONG 2 @ 100 with 1.0 entry fees
+        let position = Position::<QuoteAsset, InstrumentNameInternal>::from(&trade(
+            base_time,
+            Side::Buy,
+            100.0,
+            2.0,
+            1.0,
+        ));
+
+        let exited = position.liquidate(dec!(90.5), dec!(0.01), time_plus_days(base_time, 1));
+
+        // pnl_realised = -1.0 entry fee + (90.5 - 100) * 2 - 1.81 liquidation fee
+        assert_eq!(exited.pnl_realised, dec!(-21.81));
+        assert_eq!(exited.fees_exit.fees, dec!(1.81));
+        assert_eq!(exited.time_exit, time_plus_days(base_time, 1));
+    }
+
     #[test]
     fn test_calculate_pnl_realised() {
         struct TestCase {
@@ -1224,4 +2272,40 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_signal_meta_reward_risk() {
+        struct TestCase {
+            input: SignalMeta,
+            expected: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: LONG with stop below & target above the entry
+            TestCase {
+                input: SignalMeta::new(Some(dec!(95)), Some(dec!(110)), None),
+                expected: Some(dec!(2)),
+            },
+            // TC1: SHORT with stop above & target below the entry
+            TestCase {
+                input: SignalMeta::new(Some(dec!(104)), Some(dec!(90)), None),
+                expected: Some(dec!(2.5)),
+            },
+            // TC2: no target
+            TestCase {
+                input: SignalMeta::new(Some(dec!(95)), None, None),
+                expected: None,
+            },
+            // TC3: stop at the entry price
+            TestCase {
+                input: SignalMeta::new(Some(dec!(100)), Some(dec!(110)), None),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.reward_risk(dec!(100));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
 }
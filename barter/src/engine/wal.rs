@@ -0,0 +1,276 @@
+use crate::engine::{
+    Processor,
+    schema::{SchemaError, SchemaMigrations, Versioned},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    fs::File,
+    io::{BufRead, BufWriter, Write},
+};
+use thiserror::Error;
+use tracing::error;
+
+/// All errors generated by a [`WriteAheadLog`], or when replaying one.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum WalError {
+    #[error("WAL I/O: {0}")]
+    Io(String),
+
+    #[error("WAL SerDe: {0}")]
+    SerDe(String),
+
+    #[error("WAL {0}")]
+    Schema(#[from] SchemaError),
+}
+
+impl From<std::io::Error> for WalError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value.to_string())
+    }
+}
+
+impl From<serde_json::Error> for WalError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::SerDe(value.to_string())
+    }
+}
+
+/// Write-ahead log that durably records every `Engine` input event before it is processed.
+///
+/// Since every `EngineState` mutation (positions, balances, orders, etc.) is driven by an input
+/// event, replaying the recorded events into a freshly initialised `Engine` (see [`replay_into`])
+/// reconstructs the exact `EngineState` after a crash, without requiring a full database.
+///
+/// Events are written as newline delimited JSON [`Versioned`] envelopes, and the writer is flushed
+/// & synchronised to durable storage after every event (see [`SyncWrite`]).
+#[derive(Debug)]
+pub struct WriteAheadLog<Writer> {
+    writer: Writer,
+    sequence: u64,
+}
+
+/// [`Write`] destination of a [`WriteAheadLog`] that can synchronise written data to durable
+/// storage, so appended events survive a crash of the host.
+pub trait SyncWrite: Write {
+    /// Synchronise all written data to durable storage (eg/ [`File::sync_data`]).
+    fn sync(&mut self) -> std::io::Result<()>;
+}
+
+impl SyncWrite for File {
+    fn sync(&mut self) -> std::io::Result<()> {
+        self.sync_data()
+    }
+}
+
+impl<Writer> SyncWrite for BufWriter<Writer>
+where
+    Writer: SyncWrite,
+{
+    fn sync(&mut self) -> std::io::Result<()> {
+        self.flush()?;
+        self.get_mut().sync()
+    }
+}
+
+/// In-memory log, which is never durable (eg/ for testing).
+impl SyncWrite for Vec<u8> {
+    fn sync(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<Writer> WriteAheadLog<Writer>
+where
+    Writer: SyncWrite,
+{
+    /// Construct a new `WriteAheadLog` that appends events to the provided `Writer`
+    /// (eg/ a `File` opened in append mode).
+    pub fn new(writer: Writer) -> Self {
+        Self {
+            writer,
+            sequence: 0,
+        }
+    }
+
+    /// Number of events appended to the `WriteAheadLog` by this instance.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Durably append an event to the `WriteAheadLog`, flushing and synchronising the writer to
+    /// durable storage before returning.
+    pub fn append<Event>(&mut self, event: &Event) -> Result<(), WalError>
+    where
+        Event: Serialize,
+    {
+        serde_json::to_writer(&mut self.writer, &Versioned::new(event))?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        self.writer.sync()?;
+        self.sequence += 1;
+        Ok(())
+    }
+
+    /// Wrap an `Engine` event feed, appending each event to the `WriteAheadLog` before it is
+    /// yielded for processing.
+    ///
+    /// If an event cannot be appended the feed ends, ensuring the `Engine` never applies an
+    /// event that has not been recorded.
+    pub fn record<'a, Events>(&'a mut self, feed: Events) -> impl Iterator<Item = Events::Item> + 'a
+    where
+        Events: IntoIterator + 'a,
+        Events::Item: Serialize,
+    {
+        feed.into_iter()
+            .map_while(move |event| match self.append(&event) {
+                Ok(()) => Some(event),
+                Err(error) => {
+                    error!(
+                        ?error,
+                        sequence = self.sequence,
+                        "WriteAheadLog failed to append event - ending feed"
+                    );
+                    None
+                }
+            })
+    }
+}
+
+/// Read the events recorded in a [`WriteAheadLog`], in the order they were appended.
+///
+/// Empty lines (eg/ a trailing newline) are skipped.
+///
+/// See [`replay_with_migrations`] to replay a log written by a previous schema version.
+pub fn replay<Event, Reader>(reader: Reader) -> impl Iterator<Item = Result<Event, WalError>>
+where
+    Event: DeserializeOwned,
+    Reader: BufRead,
+{
+    replay_with_migrations(reader, SchemaMigrations::default())
+}
+
+/// Read the events recorded in a [`WriteAheadLog`], upgrading each event written by a previous
+/// schema version using the provided [`SchemaMigrations`].
+pub fn replay_with_migrations<Event, Reader>(
+    reader: Reader,
+    migrations: SchemaMigrations,
+) -> impl Iterator<Item = Result<Event, WalError>>
+where
+    Event: DeserializeOwned,
+    Reader: BufRead,
+{
+    reader.lines().filter_map(move |line| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some(deserialise_event(&line, &migrations)),
+        Err(error) => Some(Err(WalError::from(error))),
+    })
+}
+
+fn deserialise_event<Event>(line: &str, migrations: &SchemaMigrations) -> Result<Event, WalError>
+where
+    Event: DeserializeOwned,
+{
+    let value = serde_json::from_str(line)?;
+    let payload = migrations.upgrade(value)?;
+    serde_json::from_value(payload).map_err(WalError::from)
+}
+
+/// Replay the events recorded in a [`WriteAheadLog`] into the provided `Engine`, restoring the
+/// state it had before the log was interrupted.
+///
+/// Returns the number of events replayed.
+///
+/// # Warning
+/// Recorded events are processed exactly as a live `Engine` would process them, so if the
+/// `Engine` has `TradingState::Enabled` the replay regenerates algo orders and sends them via
+/// its `ExecutionTxs`. The provided `Engine` must therefore be initialised with
+/// `TradingState::Disabled` and/or a disconnected `ExecutionTxs` implementation, only enabling
+/// trading & connecting execution once the replay is complete.
+pub fn replay_into<Engine, Event, Reader>(
+    engine: &mut Engine,
+    reader: Reader,
+) -> Result<u64, WalError>
+where
+    Engine: Processor<Event>,
+    Event: DeserializeOwned,
+    Reader: BufRead,
+{
+    replay(reader).try_fold(0, |replayed, event| {
+        let _audit = engine.process(event?);
+        Ok(replayed + 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Counter {
+        total: u64,
+    }
+
+    impl Processor<u64> for Counter {
+        type Audit = ();
+
+        fn process(&mut self, event: u64) -> Self::Audit {
+            self.total += event;
+        }
+    }
+
+    #[test]
+    fn test_write_ahead_log_record_and_replay_into() {
+        let mut wal = WriteAheadLog::new(Vec::new());
+
+        let mut engine = Counter::default();
+        for event in wal.record(vec![1_u64, 2, 3]) {
+            engine.process(event);
+        }
+        assert_eq!(wal.sequence(), 3);
+
+        let mut replayed_engine = Counter::default();
+        let replayed = replay_into(&mut replayed_engine, wal.writer.as_slice()).unwrap();
+
+        assert_eq!(replayed, 3);
+        assert_eq!(replayed_engine.total, engine.total);
+    }
+
+    #[test]
+    fn test_replay_with_migrations() {
+        fn double(value: serde_json::Value) -> Result<serde_json::Value, String> {
+            value
+                .as_u64()
+                .map(|value| serde_json::Value::from(value * 2))
+                .ok_or_else(|| "expected u64".to_string())
+        }
+
+        let log = concat!(
+            "1\n",
+            "{\"schema_version\":1,\"payload\":5}\n",
+            "{\"schema_version\":99,\"payload\":1}\n",
+        );
+        let migrations = SchemaMigrations::default().register(0, double);
+
+        let actual =
+            replay_with_migrations::<u64, _>(log.as_bytes(), migrations).collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[0], Ok(2));
+        assert_eq!(actual[1], Ok(5));
+        assert_eq!(
+            actual[2],
+            Err(WalError::Schema(SchemaError::Unsupported(99)))
+        );
+    }
+
+    #[test]
+    fn test_replay_invalid_event() {
+        let log = b"1\n\nnot_json\n";
+
+        let actual = replay::<u64, _>(log.as_slice()).collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0], Ok(1));
+        assert!(matches!(actual[1], Err(WalError::SerDe(_))));
+    }
+}
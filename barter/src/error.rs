@@ -1,4 +1,4 @@
-use crate::execution::error::ExecutionError;
+use crate::{backtest::checkpoint::CheckpointError, execution::error::ExecutionError};
 use barter_data::error::DataError;
 use barter_instrument::index::error::IndexError;
 use serde::{Deserialize, Serialize};
@@ -23,6 +23,9 @@ pub enum BarterError {
 
     #[error("JoinError: {0}")]
     JoinError(String),
+
+    #[error("backtest: {0}")]
+    Checkpoint(#[from] CheckpointError),
 }
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
 #[error("RxDropped")]
@@ -2,7 +2,9 @@ use crate::{
     engine::{clock::EngineClock, execution_tx::MultiExchangeTxMap},
     error::BarterError,
     execution::{
-        AccountStreamEvent, Execution, error::ExecutionError, manager::ExecutionManager,
+        AccountStreamEvent, Execution,
+        error::ExecutionError,
+        manager::{ExecutionManager, ExecutionManagerConfig},
         request::ExecutionRequest,
     },
     shutdown::AsyncShutdown,
@@ -16,9 +18,10 @@ use barter_execution::{
         ExecutionClient,
         mock::{MockExecution, MockExecutionClientConfig, MockExecutionConfig},
     },
-    exchange::mock::{MockExchange, request::MockExchangeRequest},
+    exchange::mock::{MockExchange, price::MockPriceFeed, request::MockExchangeRequest},
     indexer::AccountEventIndexer,
     map::generate_execution_instrument_map,
+    margin::MarginMode,
 };
 use barter_instrument::{
     Keyed, Underlying,
@@ -35,6 +38,7 @@ use barter_instrument::{
 use barter_integration::channel::{Channel, UnboundedTx, mpsc_unbounded};
 use fnv::FnvHashMap;
 use futures::{FutureExt, future::try_join_all};
+use rust_decimal::Decimal;
 use std::{pin::Pin, sync::Arc, time::Duration};
 use tokio::{
     sync::{broadcast, mpsc},
@@ -66,6 +70,10 @@ pub struct ExecutionBuilder<'a> {
     merged_channel: Channel<AccountStreamEvent<ExchangeIndex, AssetIndex, InstrumentIndex>>,
     mock_exchange_futures: Vec<RunFuture>,
     execution_init_futures: Vec<ExecutionInitFuture>,
+    margin_modes: FnvHashMap<InstrumentIndex, MarginMode>,
+    leverage: FnvHashMap<InstrumentIndex, Decimal>,
+    price_feed: Option<MockPriceFeed>,
+    balance_check_interval: Option<Duration>,
 }
 
 impl<'a> ExecutionBuilder<'a> {
@@ -77,9 +85,52 @@ impl<'a> ExecutionBuilder<'a> {
             merged_channel: Channel::default(),
             mock_exchange_futures: Vec::default(),
             execution_init_futures: Vec::default(),
+            margin_modes: FnvHashMap::default(),
+            leverage: FnvHashMap::default(),
+            price_feed: None,
+            balance_check_interval: None,
         }
     }
 
+    /// Configure the [`MarginMode`] applied to the provided (eg/ futures) instrument on startup,
+    /// before trading begins.
+    ///
+    /// Must be configured before the [`ExecutionManager`] of the instrument exchange is added.
+    /// Initialisation fails if the exchange account is not in the configured `MarginMode`, rather
+    /// than trading with the wrong risk assumptions.
+    pub fn margin_mode(mut self, instrument: InstrumentIndex, mode: MarginMode) -> Self {
+        self.margin_modes.insert(instrument, mode);
+        self
+    }
+
+    /// Configure the leverage applied to the provided (eg/ futures) instrument on startup,
+    /// before trading begins.
+    ///
+    /// Must be configured before the [`ExecutionManager`] of the instrument exchange is added.
+    /// Initialisation fails if the exchange does not confirm the configured leverage.
+    pub fn leverage(mut self, instrument: InstrumentIndex, leverage: Decimal) -> Self {
+        self.leverage.insert(instrument, leverage);
+        self
+    }
+
+    /// Configure the [`MockPriceFeed`] used by each [`MockExchange`] to fill market orders at the
+    /// latest live quote prices (eg/ when paper trading).
+    ///
+    /// Must be configured before the mock [`ExecutionManager`]s are added.
+    pub fn price_feed(mut self, price_feed: MockPriceFeed) -> Self {
+        self.price_feed = Some(price_feed);
+        self
+    }
+
+    /// Configure the interval between periodic exchange balance fetches, which are forwarded to
+    /// the `Engine` to detect drift from the internally tracked balances.
+    ///
+    /// Must be configured before the [`ExecutionManager`]s are added.
+    pub fn balance_check_interval(mut self, interval: Duration) -> Self {
+        self.balance_check_interval = Some(interval);
+        self
+    }
+
     /// Adds an [`ExecutionManager`] for a mocked exchange, setting up a [`MockExchange`]
     /// internally.
     ///
@@ -104,6 +155,7 @@ impl<'a> ExecutionBuilder<'a> {
             clock: move || clock.time(),
             request_tx,
             event_rx,
+            failures: config.failures,
         };
 
         // Register MockExchange init Future
@@ -125,7 +177,12 @@ impl<'a> ExecutionBuilder<'a> {
     ) -> RunFuture {
         let instruments =
             generate_mock_exchange_instruments(self.instruments, config.mocked_exchange);
-        Box::pin(MockExchange::new(config, request_rx, event_tx, instruments).run())
+        let exchange = MockExchange::new(config, request_rx, event_tx, instruments);
+
+        match &self.price_feed {
+            Some(price_feed) => Box::pin(exchange.with_price_feed(price_feed.clone()).run()),
+            None => Box::pin(exchange.run()),
+        }
     }
 
     /// Adds an [`ExecutionManager`] for a live exchange.
@@ -169,6 +226,30 @@ impl<'a> ExecutionBuilder<'a> {
 
         let merged_tx = self.merged_channel.tx.clone();
 
+        // Determine configured margin modes & leverage of instruments traded on this exchange
+        let exchange_instruments = self
+            .instruments
+            .instruments()
+            .iter()
+            .filter(|instrument| instrument.value.exchange.value == exchange);
+
+        let margin_modes = exchange_instruments
+            .clone()
+            .filter_map(|instrument| {
+                self.margin_modes
+                    .get(&instrument.key)
+                    .map(|mode| (instrument.value.name_exchange.clone(), *mode))
+            })
+            .collect();
+
+        let leverage = exchange_instruments
+            .filter_map(|instrument| {
+                self.leverage
+                    .get(&instrument.key)
+                    .map(|leverage| (instrument.value.name_exchange.clone(), *leverage))
+            })
+            .collect();
+
         // Init ExecutionManager Future
         let future_result = ExecutionManager::init(
             execution_rx.into_stream(),
@@ -176,6 +257,7 @@ impl<'a> ExecutionBuilder<'a> {
             Arc::new(Client::new(config)),
             AccountEventIndexer::new(Arc::new(instrument_map)),
             STREAM_RECONNECTION_POLICY,
+            ExecutionManagerConfig::new(margin_modes, leverage, self.balance_check_interval),
         );
 
         let future_result = future_result.map(|result| {
@@ -428,6 +510,7 @@ fn generate_mock_exchange_instruments(
                                     unit,
                                     min,
                                     increment,
+                                    max,
                                 },
                             notional,
                         } = spec;
@@ -452,6 +535,7 @@ fn generate_mock_exchange_instruments(
                                 unit,
                                 min: *min,
                                 increment: *increment,
+                                max: *max,
                             },
                             notional: *notional,
                         })
@@ -1,5 +1,6 @@
-use barter_execution::error::ClientError;
+use barter_execution::error::{ClientError, ErrorAction};
 use barter_instrument::index::error::IndexError;
+use barter_integration::Unrecoverable;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -20,3 +21,19 @@ pub enum ExecutionError {
     #[error("{0}")]
     Client(#[from] ClientError),
 }
+
+impl ExecutionError {
+    /// Determine the [`ErrorAction`] a caller should take in response to this error.
+    pub fn action(&self) -> ErrorAction {
+        match self {
+            Self::Config(_) | Self::Index(_) => ErrorAction::Halt,
+            Self::Client(error) => error.action(),
+        }
+    }
+}
+
+impl Unrecoverable for ExecutionError {
+    fn is_unrecoverable(&self) -> bool {
+        self.action() == ErrorAction::Halt
+    }
+}
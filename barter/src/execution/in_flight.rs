@@ -0,0 +1,66 @@
+use barter_execution::order::id::ClientOrderId;
+use fnv::FnvHashSet;
+
+/// Registry of in-flight order requests, keyed by [`ClientOrderId`].
+///
+/// Used by the [`ExecutionManager`](super::manager::ExecutionManager) to suppress duplicate
+/// submissions of a request that is already in-flight (eg/ a retry whose original request may
+/// have actually succeeded), preventing accidental double-sized positions.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct InFlightOrderRegistry {
+    pub opens: FnvHashSet<ClientOrderId>,
+    pub cancels: FnvHashSet<ClientOrderId>,
+}
+
+impl InFlightOrderRegistry {
+    /// Register an in-flight open order request.
+    ///
+    /// Returns false if an open request with the same [`ClientOrderId`] is already in-flight,
+    /// in which case the request is a duplicate and should not be submitted.
+    pub fn register_open(&mut self, cid: &ClientOrderId) -> bool {
+        self.opens.insert(cid.clone())
+    }
+
+    /// Register an in-flight cancel order request.
+    ///
+    /// Returns false if a cancel request with the same [`ClientOrderId`] is already in-flight,
+    /// in which case the request is a duplicate and should not be submitted.
+    pub fn register_cancel(&mut self, cid: &ClientOrderId) -> bool {
+        self.cancels.insert(cid.clone())
+    }
+
+    /// Remove a resolved open order request from the registry.
+    pub fn resolve_open(&mut self, cid: &ClientOrderId) {
+        self.opens.remove(cid);
+    }
+
+    /// Remove a resolved cancel order request from the registry.
+    pub fn resolve_cancel(&mut self, cid: &ClientOrderId) {
+        self.cancels.remove(cid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_flight_order_registry() {
+        let mut registry = InFlightOrderRegistry::default();
+        let cid = ClientOrderId::new("cid");
+
+        // Duplicate open submissions are rejected until resolved
+        assert!(registry.register_open(&cid));
+        assert!(!registry.register_open(&cid));
+
+        // Open & cancel requests are tracked independently
+        assert!(registry.register_cancel(&cid));
+        assert!(!registry.register_cancel(&cid));
+
+        registry.resolve_open(&cid);
+        assert!(registry.register_open(&cid));
+
+        registry.resolve_cancel(&cid);
+        assert!(registry.register_cancel(&cid));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::execution::{
     AccountStreamEvent,
     error::ExecutionError,
+    in_flight::InFlightOrderRegistry,
     request::{ExecutionRequest, RequestFuture},
 };
 use barter_data::streams::{
@@ -9,23 +10,26 @@ use barter_data::streams::{
 };
 use barter_execution::{
     AccountEvent, AccountEventKind,
+    balance::AssetBalance,
     client::ExecutionClient,
-    error::{ConnectivityError, OrderError, UnindexedOrderError},
+    error::{ApiError, ConnectivityError, OrderError, UnindexedOrderError},
     indexer::{AccountEventIndexer, IndexedAccountStream},
     map::ExecutionInstrumentMap,
+    margin::MarginMode,
     order::{
         Order,
         request::{
             OrderRequestCancel, OrderRequestOpen, OrderResponseCancel, UnindexedOrderResponseCancel,
         },
         state::{Open, OrderState},
+        validation::{OrderValidationError, normalise_open},
     },
 };
 use barter_instrument::{
     asset::{AssetIndex, name::AssetNameExchange},
     exchange::{ExchangeId, ExchangeIndex},
     index::error::IndexError,
-    instrument::{InstrumentIndex, name::InstrumentNameExchange},
+    instrument::{InstrumentIndex, name::InstrumentNameExchange, spec::InstrumentSpec},
 };
 use barter_integration::{
     channel::{Tx, UnboundedTx, mpsc_unbounded},
@@ -33,10 +37,28 @@ use barter_integration::{
     stream::merge::merge,
 };
 use derive_more::Constructor;
+use fnv::FnvHashMap;
 use futures::{Stream, StreamExt, future::Either, stream::FuturesUnordered};
+use rust_decimal::Decimal;
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
+/// Instrument account configuration applied by the [`ExecutionManager`] on initialisation, and
+/// the periodic balance check interval used whilst running.
+#[derive(Debug, Clone, Default, PartialEq, Constructor)]
+pub struct ExecutionManagerConfig {
+    /// [`MarginMode`]s applied to (eg/ futures) instruments before trading begins.
+    pub margin_modes: Vec<(InstrumentNameExchange, MarginMode)>,
+
+    /// Leverage applied to (eg/ futures) instruments before trading begins.
+    pub leverage: Vec<(InstrumentNameExchange, Decimal)>,
+
+    /// Interval between periodic exchange balance fetches.
+    ///
+    /// `None` disables periodic balance fetches.
+    pub balance_check_interval: Option<std::time::Duration>,
+}
+
 /// Per-exchange execution manager that actions order requests from the Engine and forwards back
 /// responses.
 ///
@@ -44,6 +66,13 @@ use tracing::{error, info, warn};
 /// - Transforming the requests to use the associated exchange's asset and instrument names.
 /// - Issues the request via it's associated exchange [`ExecutionClient`],
 /// - Tracks requests and returns timeouts to the Engine where necessary.
+/// - Normalises open requests against the exchange [`InstrumentSpec`]s (eg/ tick size), rejecting
+///   requests that cannot be made valid without sending them to the exchange.
+/// - Suppresses duplicate submissions of requests that are already in-flight.
+/// - Resolves ambiguous open request timeouts by fetching the exchange open orders, since a
+///   timed out request may have actually succeeded.
+/// - Optionally fetches the exchange balances periodically, forwarding them to the Engine as an
+///   [`AccountEventKind::BalanceCheck`] so balance drift can be detected.
 #[derive(Debug, Constructor)]
 pub struct ExecutionManager<RequestStream, Client> {
     /// `Stream` of incoming Engine [`ExecutionRequest`]s.
@@ -62,6 +91,16 @@ pub struct ExecutionManager<RequestStream, Client> {
     ///
     /// For example, `InstrumentNameExchange` -> `InstrumentIndex`.
     pub indexer: AccountEventIndexer,
+
+    /// Exchange [`InstrumentSpec`]s used to normalise open requests.
+    ///
+    /// Open requests for instruments without an `InstrumentSpec` are sent unmodified.
+    pub specs: FnvHashMap<InstrumentIndex, InstrumentSpec<AssetNameExchange>>,
+
+    /// Interval between periodic exchange balance fetches.
+    ///
+    /// `None` disables periodic balance fetches.
+    pub balance_check_interval: Option<std::time::Duration>,
 }
 
 impl<RequestStream, Client> ExecutionManager<RequestStream, Client>
@@ -72,6 +111,11 @@ where
 {
     /// Initialises a new `ExecutionManager` and it's associated AccountStream.
     ///
+    /// Before the AccountStream is initialised, the [`ExecutionManagerConfig`] instrument
+    /// [`MarginMode`]s and leverage are applied via the [`ExecutionClient`], and verified against
+    /// the values confirmed by the exchange. The exchange [`InstrumentSpec`]s are then fetched for use in
+    /// normalising open requests.
+    ///
     /// The first item of the AccountStream will be a full account snapshot.
     pub async fn init(
         request_stream: RequestStream,
@@ -79,10 +123,21 @@ where
         client: Arc<Client>,
         indexer: AccountEventIndexer,
         reconnect_policy: ReconnectionBackoffPolicy,
+        config: ExecutionManagerConfig,
     ) -> Result<(Self, impl Stream<Item = AccountStreamEvent> + Send), ExecutionError> {
         // Determine StreamKey & ExchangeId for use in logging
         let stream_key = Self::determine_account_stream_key(&indexer.map)?;
 
+        // Apply configured instrument margin modes & leverage before trading begins
+        let ExecutionManagerConfig {
+            margin_modes,
+            leverage,
+            balance_check_interval,
+        } = config;
+        Self::apply_margin_modes(&client, &indexer, margin_modes).await?;
+        Self::apply_leverage(&client, &indexer, leverage).await?;
+        let specs = Self::fetch_instrument_specs(&client, &indexer).await?;
+
         info!(
             exchange_index = %indexer.map.exchange.key,
             exchange_id = %indexer.map.exchange.value,
@@ -144,6 +199,8 @@ where
                 response_tx,
                 client,
                 indexer,
+                specs,
+                balance_check_interval,
             ),
             merged_account_stream,
         ))
@@ -171,6 +228,110 @@ where
         }
     }
 
+    async fn apply_margin_modes(
+        client: &Arc<Client>,
+        indexer: &AccountEventIndexer,
+        margin_modes: Vec<(InstrumentNameExchange, MarginMode)>,
+    ) -> Result<(), ExecutionError> {
+        for (instrument, requested) in margin_modes {
+            let confirmed = match client.set_margin_mode(&instrument, requested).await {
+                Ok(confirmed) => confirmed,
+                Err(error) => {
+                    error!(
+                        exchange = %indexer.map.exchange.value,
+                        %instrument,
+                        margin_mode = %requested,
+                        ?error,
+                        "ExecutionManager failed to apply instrument margin mode"
+                    );
+                    return Err(ExecutionError::Client(indexer.client_error(error)?));
+                }
+            };
+
+            if confirmed != requested {
+                error!(
+                    exchange = %indexer.map.exchange.value,
+                    %instrument,
+                    configured = %requested,
+                    %confirmed,
+                    "ExecutionManager found unexpected account margin mode - refusing to trade"
+                );
+                return Err(ExecutionError::Config(format!(
+                    "{instrument} margin mode confirmed by exchange: {confirmed} does not match \
+                    configured margin mode: {requested}"
+                )));
+            }
+
+            info!(
+                exchange = %indexer.map.exchange.value,
+                %instrument,
+                margin_mode = %confirmed,
+                "ExecutionManager applied instrument margin mode"
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn apply_leverage(
+        client: &Arc<Client>,
+        indexer: &AccountEventIndexer,
+        leverage: Vec<(InstrumentNameExchange, Decimal)>,
+    ) -> Result<(), ExecutionError> {
+        for (instrument, requested) in leverage {
+            let confirmed = match client.set_leverage(&instrument, requested).await {
+                Ok(confirmed) => confirmed,
+                Err(error) => return Err(ExecutionError::Client(indexer.client_error(error)?)),
+            };
+
+            if confirmed != requested {
+                return Err(ExecutionError::Config(format!(
+                    "{instrument} leverage confirmed by exchange: {confirmed} does not match \
+                    configured leverage: {requested}"
+                )));
+            }
+
+            info!(
+                exchange = %indexer.map.exchange.value,
+                %instrument,
+                leverage = %confirmed,
+                "ExecutionManager applied instrument leverage"
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_instrument_specs(
+        client: &Arc<Client>,
+        indexer: &AccountEventIndexer,
+    ) -> Result<FnvHashMap<InstrumentIndex, InstrumentSpec<AssetNameExchange>>, ExecutionError>
+    {
+        let instruments = indexer
+            .map
+            .exchange_instruments()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let specs = match client.fetch_instrument_specs(&instruments).await {
+            Ok(specs) => specs,
+            Err(error) => return Err(ExecutionError::Client(indexer.client_error(error)?)),
+        };
+
+        let specs = specs
+            .into_iter()
+            .map(|spec| Ok((indexer.map.find_instrument_index(&spec.key)?, spec.value)))
+            .collect::<Result<FnvHashMap<_, _>, IndexError>>()?;
+
+        info!(
+            exchange = %indexer.map.exchange.value,
+            num_specs = specs.len(),
+            "ExecutionManager fetched instrument specs"
+        );
+
+        Ok(specs)
+    }
+
     async fn fetch_indexed_account_snapshot(
         client: &Arc<Client>,
         indexer: &AccountEventIndexer,
@@ -182,6 +343,7 @@ where
                 let indexed_snapshot = indexer.snapshot(snapshot)?;
                 Ok(AccountEvent {
                     exchange: indexer.map.exchange.key,
+                    sub_account: None,
                     kind: AccountEventKind::Snapshot(indexed_snapshot),
                 })
             }
@@ -219,8 +381,19 @@ where
     /// Run the `ExecutionManager`, processing execution requests and forwarding back responses via
     /// the AccountStream.
     pub async fn run(mut self) {
+        let mut in_flight = InFlightOrderRegistry::default();
         let mut in_flight_cancels = FuturesUnordered::new();
         let mut in_flight_opens = FuturesUnordered::new();
+        let mut in_flight_open_resolutions = FuturesUnordered::new();
+        let mut in_flight_balance_checks = FuturesUnordered::new();
+
+        // Skip the immediate first tick, since the initial account snapshot contains balances
+        let mut balance_check_interval = self.balance_check_interval.map(|period| {
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
 
         loop {
             let next_cancel_response = if in_flight_cancels.is_empty() {
@@ -235,6 +408,23 @@ where
                 Either::Right(in_flight_opens.select_next_some())
             };
 
+            let next_open_resolution = if in_flight_open_resolutions.is_empty() {
+                Either::Left(std::future::pending())
+            } else {
+                Either::Right(in_flight_open_resolutions.select_next_some())
+            };
+
+            let next_balance_check = match balance_check_interval.as_mut() {
+                Some(interval) => Either::Right(interval.tick()),
+                None => Either::Left(std::future::pending()),
+            };
+
+            let next_balance_check_response = if in_flight_balance_checks.is_empty() {
+                Either::Left(std::future::pending())
+            } else {
+                Either::Right(in_flight_balance_checks.select_next_some())
+            };
+
             tokio::select! {
                 // Process Engine ExecutionRequests
                 request = self.request_stream.next() => match request {
@@ -242,6 +432,15 @@ where
                         break;
                     }
                     Some(ExecutionRequest::Cancel(request)) => {
+                        if !in_flight.register_cancel(&request.key.cid) {
+                            warn!(
+                                exchange = %self.indexer.map.exchange.value,
+                                cid = %request.key.cid,
+                                "ExecutionManager suppressing duplicate in-flight cancel request"
+                            );
+                            continue
+                        }
+
                         // Panic since the system is set up incorrectly, so it's foolish to continue
                         let client_request = self
                             .indexer
@@ -257,6 +456,31 @@ where
                         ))
                     },
                     Some(ExecutionRequest::Open(request)) => {
+                        let request = match self.normalise_open(&request) {
+                            Ok(request) => request,
+                            Err(error) => {
+                                warn!(
+                                    exchange = %self.indexer.map.exchange.value,
+                                    cid = %request.key.cid,
+                                    %error,
+                                    "ExecutionManager rejecting open request that cannot be made valid"
+                                );
+                                if self.response_tx.send(Self::process_open_invalid(request, error)).is_err() {
+                                    break;
+                                }
+                                continue
+                            }
+                        };
+
+                        if !in_flight.register_open(&request.key.cid) {
+                            warn!(
+                                exchange = %self.indexer.map.exchange.value,
+                                cid = %request.key.cid,
+                                "ExecutionManager suppressing duplicate in-flight open request"
+                            );
+                            continue
+                        }
+
                         // Panic since the system is set up incorrectly, so it's foolish to continue
                         let client_request = self
                             .indexer
@@ -277,6 +501,7 @@ where
                 response_cancel = next_cancel_response => {
                     let event = match response_cancel {
                         Ok(response) => {
+                            in_flight.resolve_cancel(&response.key.cid);
                             match self.process_cancel_response(response) {
                                 Ok(indexed_event) => indexed_event,
                                 Err(error) => {
@@ -290,6 +515,7 @@ where
                             }
                         }
                         Err(request) => {
+                            in_flight.resolve_cancel(&request.key.cid);
                             Self::process_cancel_timeout(request)
                         }
                     };
@@ -303,6 +529,7 @@ where
                 response_open = next_open_response => {
                     let event = match response_open {
                         Ok(response) => {
+                            in_flight.resolve_open(&response.key.cid);
                             match self.process_open_response(response) {
                                 Ok(indexed_event) => indexed_event,
                                 Err(error) => {
@@ -316,6 +543,40 @@ where
                             }
                         }
                         Err(request) => {
+                            // Timed out request may have succeeded, so resolve via open orders
+                            in_flight_open_resolutions.push(RequestFuture::new(
+                                Self::find_open_order(Arc::clone(&self.client), request.clone()),
+                                self.request_timeout,
+                                request,
+                            ));
+                            continue
+                        }
+                    };
+
+                    if self.response_tx.send(event).is_err() {
+                        break;
+                    }
+                }
+
+                // Process next timed out ExecutionRequest::Open resolution
+                resolution = next_open_resolution => {
+                    let event = match resolution.and_then(|found| found) {
+                        Ok(order) => {
+                            in_flight.resolve_open(&order.key.cid);
+                            match self.process_open_response(Self::into_open_response(order)) {
+                                Ok(indexed_event) => indexed_event,
+                                Err(error) => {
+                                    warn!(
+                                        exchange = %self.indexer.map.exchange.value,
+                                        ?error,
+                                        "ExecutionManager filtering resolved open order due to unrecognised index"
+                                    );
+                                    continue
+                                }
+                            }
+                        }
+                        Err(request) => {
+                            in_flight.resolve_open(&request.key.cid);
                             Self::process_open_timeout(request)
                         }
                     };
@@ -325,6 +586,42 @@ where
                     }
                 }
 
+                // Fetch exchange balances to check for drift from the Engine tracked balances
+                _ = next_balance_check => {
+                    if in_flight_balance_checks.is_empty() {
+                        in_flight_balance_checks.push(RequestFuture::new(
+                            self.client.fetch_balances(),
+                            self.request_timeout,
+                            (),
+                        ));
+                    }
+                }
+
+                // Process next balance check response
+                response_balances = next_balance_check_response => {
+                    let balances = match response_balances {
+                        Ok(Ok(balances)) => balances,
+                        Ok(Err(error)) => {
+                            warn!(
+                                exchange = %self.indexer.map.exchange.value,
+                                ?error,
+                                "ExecutionManager failed to fetch balances for balance check"
+                            );
+                            continue
+                        }
+                        Err(()) => {
+                            warn!(
+                                exchange = %self.indexer.map.exchange.value,
+                                "ExecutionManager timed out fetching balances for balance check"
+                            );
+                            continue
+                        }
+                    };
+
+                    if self.response_tx.send(self.process_balance_check(balances)).is_err() {
+                        break;
+                    }
+                }
             }
         }
 
@@ -334,6 +631,43 @@ where
         )
     }
 
+    /// Normalise an open request against the associated instrument [`InstrumentSpec`], if any.
+    ///
+    /// Requests for instruments without an `InstrumentSpec` are returned unmodified.
+    fn normalise_open(
+        &self,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> Result<OrderRequestOpen<ExchangeIndex, InstrumentIndex>, OrderValidationError> {
+        let state = match self.specs.get(&request.key.instrument) {
+            Some(spec) => normalise_open(request.state.clone(), spec)?,
+            None => request.state.clone(),
+        };
+
+        Ok(OrderRequestOpen {
+            key: request.key.clone(),
+            state,
+        })
+    }
+
+    /// Index the exchange fetched balances into an [`AccountEventKind::BalanceCheck`].
+    ///
+    /// Balances of assets that are not configured for this exchange are filtered.
+    fn process_balance_check(
+        &self,
+        balances: Vec<AssetBalance<AssetNameExchange>>,
+    ) -> AccountStreamEvent {
+        let balances = balances
+            .into_iter()
+            .filter_map(|balance| self.indexer.asset_balance(balance).ok())
+            .collect();
+
+        AccountStreamEvent::Item(AccountEvent {
+            exchange: self.indexer.map.exchange.key,
+            sub_account: None,
+            kind: AccountEventKind::BalanceCheck(balances),
+        })
+    }
+
     fn process_cancel_response(
         &self,
         order: UnindexedOrderResponseCancel,
@@ -342,6 +676,7 @@ where
 
         Ok(AccountStreamEvent::Item(AccountEvent {
             exchange: order.key.exchange,
+            sub_account: None,
             kind: AccountEventKind::OrderCancelled(order),
         }))
     }
@@ -353,6 +688,7 @@ where
 
         AccountStreamEvent::Item(AccountEvent {
             exchange: key.exchange,
+            sub_account: None,
             kind: AccountEventKind::OrderCancelled(OrderResponseCancel {
                 key,
                 state: Err(OrderError::Connectivity(ConnectivityError::Timeout)),
@@ -371,6 +707,7 @@ where
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = order;
 
@@ -384,6 +721,7 @@ where
 
         Ok(AccountStreamEvent::Item(AccountEvent {
             exchange: key.exchange,
+            sub_account: None,
             kind: AccountEventKind::OrderSnapshot(Snapshot(Order {
                 key,
                 side,
@@ -391,11 +729,87 @@ where
                 quantity,
                 kind,
                 time_in_force,
+                reduce_only,
                 state,
             })),
         }))
     }
 
+    /// Find the exchange open order associated with a timed out open request, if it actually
+    /// succeeded.
+    ///
+    /// Returns the original request if no matching open order was found.
+    async fn find_open_order(
+        client: Arc<Client>,
+        request: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> Result<
+        Order<ExchangeId, InstrumentNameExchange, Open>,
+        OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    > {
+        match client.fetch_open_orders().await {
+            Ok(orders) => orders
+                .into_iter()
+                .find(|order| order.key.cid == request.key.cid)
+                .ok_or(request),
+            Err(error) => {
+                warn!(
+                    ?error,
+                    cid = %request.key.cid,
+                    "ExecutionManager failed to fetch open orders to resolve timed out open request"
+                );
+                Err(request)
+            }
+        }
+    }
+
+    fn into_open_response(
+        order: Order<ExchangeId, InstrumentNameExchange, Open>,
+    ) -> Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>> {
+        let Order {
+            key,
+            side,
+            price,
+            quantity,
+            kind,
+            time_in_force,
+            reduce_only,
+            state,
+        } = order;
+
+        Order {
+            key,
+            side,
+            price,
+            quantity,
+            kind,
+            time_in_force,
+            reduce_only,
+            state: Ok(state),
+        }
+    }
+
+    fn process_open_invalid(
+        order: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+        error: OrderValidationError,
+    ) -> AccountStreamEvent {
+        let OrderRequestOpen { key, state } = order;
+
+        AccountStreamEvent::Item(AccountEvent {
+            exchange: key.exchange,
+            sub_account: None,
+            kind: AccountEventKind::OrderSnapshot(Snapshot(Order {
+                key,
+                side: state.side,
+                price: state.price,
+                quantity: state.quantity,
+                kind: state.kind,
+                time_in_force: state.time_in_force,
+                reduce_only: state.reduce_only,
+                state: OrderState::inactive(OrderError::Rejected(ApiError::OrderInvalid(error))),
+            })),
+        })
+    }
+
     fn process_open_timeout(
         order: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
     ) -> AccountStreamEvent {
@@ -403,6 +817,7 @@ where
 
         AccountStreamEvent::Item(AccountEvent {
             exchange: key.exchange,
+            sub_account: None,
             kind: AccountEventKind::OrderSnapshot(Snapshot(Order {
                 key,
                 side: state.side,
@@ -410,8 +825,217 @@ where
                 quantity: state.quantity,
                 kind: state.kind,
                 time_in_force: state.time_in_force,
+                reduce_only: state.reduce_only,
                 state: OrderState::inactive(OrderError::Connectivity(ConnectivityError::Timeout)),
             })),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::{
+        UnindexedAccountSnapshot,
+        client::mock::{
+            MockExecution, MockExecutionClientConfig, MockExecutionConfig,
+            failure::MockFailureConfig,
+        },
+        error::ClientError,
+        exchange::mock::{MockExchange, slippage::SlippageConfig},
+        map::generate_execution_instrument_map,
+    };
+    use barter_instrument::{
+        Underlying,
+        index::IndexedInstruments,
+        instrument::{
+            Instrument,
+            kind::{InstrumentKind, perpetual::PerpetualContract},
+            quote::InstrumentQuoteAsset,
+        },
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+    use tokio::sync::{broadcast, mpsc};
+
+    type TestClient = MockExecution<fn() -> DateTime<Utc>>;
+    type TestManager = ExecutionManager<futures::stream::Empty<ExecutionRequest>, TestClient>;
+
+    const EXCHANGE: ExchangeId = ExchangeId::BinanceFuturesUsd;
+
+    fn instrument<AssetKey>(
+        name: &str,
+        kind: InstrumentKind<AssetKey>,
+    ) -> Instrument<ExchangeId, AssetKey>
+    where
+        AssetKey: From<&'static str>,
+    {
+        Instrument::new(
+            EXCHANGE,
+            name,
+            name,
+            Underlying::new("btc", "usdt"),
+            InstrumentQuoteAsset::UnderlyingQuote,
+            kind,
+            None,
+        )
+    }
+
+    fn perpetual<AssetKey>() -> InstrumentKind<AssetKey>
+    where
+        AssetKey: From<&'static str>,
+    {
+        InstrumentKind::Perpetual(PerpetualContract {
+            contract_size: dec!(1),
+            settlement_asset: AssetKey::from("usdt"),
+        })
+    }
+
+    // Initialise a running MockExchange that manages a "spot" & "perpetual" instrument, returning
+    // a MockExecution client connected to it and the associated AccountEventIndexer
+    fn client_and_indexer() -> (Arc<TestClient>, AccountEventIndexer) {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(instrument("spot", InstrumentKind::Spot))
+            .add_instrument(instrument("perpetual", perpetual()))
+            .build();
+        let indexer = AccountEventIndexer::new(Arc::new(
+            generate_execution_instrument_map(&instruments, EXCHANGE).unwrap(),
+        ));
+
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = broadcast::channel(16);
+
+        let exchange = MockExchange::new(
+            MockExecutionConfig::new(
+                EXCHANGE,
+                UnindexedAccountSnapshot {
+                    exchange: EXCHANGE,
+                    balances: vec![],
+                    instruments: vec![],
+                },
+                0,
+                dec!(0),
+                SlippageConfig::default(),
+                MockFailureConfig::default(),
+            ),
+            request_rx,
+            event_tx,
+            [
+                instrument("spot", InstrumentKind::Spot),
+                instrument("perpetual", perpetual()),
+            ]
+            .into_iter()
+            .map(|instrument| (instrument.name_exchange.clone(), instrument))
+            .collect(),
+        );
+        tokio::spawn(exchange.run());
+
+        let client = <TestClient as ExecutionClient>::new(MockExecutionClientConfig::new(
+            EXCHANGE,
+            Utc::now,
+            request_tx,
+            event_rx,
+            MockFailureConfig::default(),
+        ));
+
+        (Arc::new(client), indexer)
+    }
+
+    #[tokio::test]
+    async fn test_apply_leverage() {
+        struct TestCase {
+            leverage: Vec<(InstrumentNameExchange, Decimal)>,
+            expected: Result<(), ExecutionError>,
+        }
+
+        let (client, indexer) = client_and_indexer();
+
+        let cases = vec![
+            // TC0: no leverage configured
+            TestCase {
+                leverage: vec![],
+                expected: Ok(()),
+            },
+            // TC1: leverage confirmed by exchange
+            TestCase {
+                leverage: vec![
+                    (InstrumentNameExchange::new("perpetual"), dec!(10)),
+                    (InstrumentNameExchange::new("spot"), dec!(1)),
+                ],
+                expected: Ok(()),
+            },
+            // TC2: leverage confirmed by exchange does not match configured leverage
+            TestCase {
+                leverage: vec![(InstrumentNameExchange::new("spot"), dec!(10))],
+                expected: Err(ExecutionError::Config(
+                    "spot leverage confirmed by exchange: 1 does not match configured \
+                    leverage: 10"
+                        .to_string(),
+                )),
+            },
+            // TC3: exchange fails to set leverage
+            TestCase {
+                leverage: vec![(InstrumentNameExchange::new("unknown"), dec!(10))],
+                expected: Err(ExecutionError::Client(ClientError::Leverage(
+                    "MockExchange is not set-up for managing: unknown".to_string(),
+                ))),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = TestManager::apply_leverage(&client, &indexer, test.leverage).await;
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_margin_modes() {
+        struct TestCase {
+            margin_modes: Vec<(InstrumentNameExchange, MarginMode)>,
+            expected: Result<(), ExecutionError>,
+        }
+
+        let (client, indexer) = client_and_indexer();
+
+        let cases = vec![
+            // TC0: no margin modes configured
+            TestCase {
+                margin_modes: vec![],
+                expected: Ok(()),
+            },
+            // TC1: margin modes confirmed by exchange
+            TestCase {
+                margin_modes: vec![
+                    (
+                        InstrumentNameExchange::new("perpetual"),
+                        MarginMode::Isolated,
+                    ),
+                    (InstrumentNameExchange::new("spot"), MarginMode::Cross),
+                ],
+                expected: Ok(()),
+            },
+            // TC2: margin mode confirmed by exchange does not match configured margin mode
+            TestCase {
+                margin_modes: vec![(InstrumentNameExchange::new("spot"), MarginMode::Isolated)],
+                expected: Err(ExecutionError::Config(
+                    "spot margin mode confirmed by exchange: cross does not match configured \
+                    margin mode: isolated"
+                        .to_string(),
+                )),
+            },
+            // TC3: exchange fails to set margin mode
+            TestCase {
+                margin_modes: vec![(InstrumentNameExchange::new("unknown"), MarginMode::Cross)],
+                expected: Err(ExecutionError::Client(ClientError::MarginMode(
+                    "MockExchange is not set-up for managing: unknown".to_string(),
+                ))),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                TestManager::apply_margin_modes(&client, &indexer, test.margin_modes).await;
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -15,10 +15,18 @@ pub mod builder;
 /// Provides an error type that represents all errors that are generated by an execution link.
 pub mod error;
 
+/// Defines an [`InFlightOrderRegistry`](in_flight::InFlightOrderRegistry) used to suppress
+/// duplicate order request submissions.
+pub mod in_flight;
+
 /// Per-exchange execution manager that actions order requests from the Engine and forwards back
 /// responses.
 pub mod manager;
 
+/// [`SmartOrderRouter`](router::SmartOrderRouter) that routes order requests for instruments
+/// traded on multiple exchanges to the venues with the best fee-adjusted prices.
+pub mod router;
+
 /// Defines an `ExecutionRequest` used by the `Engine` to communicate with an `ExecutionManager`.
 pub mod request;
 
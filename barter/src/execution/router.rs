@@ -0,0 +1,400 @@
+use crate::engine::state::{EngineState, instrument::data::InstrumentDataState};
+use barter_execution::order::{id::ClientOrderId, request::OrderRequestOpen};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Policy used by the [`SmartOrderRouter`] to allocate an order across venues.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum RoutingPolicy {
+    /// Route the entire quantity to the venue with the best fee-adjusted price that can fill it
+    /// (default).
+    #[default]
+    BestVenue,
+
+    /// Split the quantity across venues, filling the best fee-adjusted prices first.
+    Split,
+}
+
+/// Venue an order can be routed to (ie/ an exchange instrument), with its current executable
+/// price, liquidity, fees, and available balance.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct RouteVenue {
+    pub exchange: ExchangeIndex,
+    pub instrument: InstrumentIndex,
+
+    /// Executable price (ie/ best ask to buy, best bid to sell).
+    pub price: Decimal,
+
+    /// Quantity available at the executable price, or `None` if unknown.
+    pub quantity_available: Option<Decimal>,
+
+    /// Fee rate charged on the traded notional (eg/ 0.001 for 10 bps).
+    pub fee_rate: Decimal,
+
+    /// Free balance available to fund the order (ie/ quote asset to buy, base asset to sell).
+    pub balance_free: Decimal,
+}
+
+impl RouteVenue {
+    /// Price adjusted for fees, such that a lower buy (or higher sell) price is always better.
+    pub fn effective_price(&self, side: Side) -> Decimal {
+        match side {
+            Side::Buy => self.price * (Decimal::ONE + self.fee_rate),
+            Side::Sell => self.price * (Decimal::ONE - self.fee_rate),
+        }
+    }
+
+    /// Maximum quantity that can be routed to this venue, limited by the available liquidity and
+    /// balance.
+    pub fn capacity(&self, side: Side) -> Decimal {
+        let balance_capacity = match side {
+            Side::Buy => {
+                let cost = self.effective_price(side);
+                if cost <= Decimal::ZERO {
+                    Decimal::ZERO
+                } else {
+                    self.balance_free / cost
+                }
+            }
+            Side::Sell => self.balance_free,
+        };
+
+        self.quantity_available
+            .map_or(balance_capacity, |available| {
+                available.min(balance_capacity)
+            })
+            .max(Decimal::ZERO)
+    }
+}
+
+/// Output of the [`SmartOrderRouter`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RoutedOrder {
+    /// Order requests for each selected venue.
+    pub requests: Vec<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+
+    /// Quantity that could not be routed due to insufficient venue liquidity or balance.
+    pub quantity_unrouted: Decimal,
+}
+
+/// Routes order requests for instruments traded on multiple connected exchanges, choosing the
+/// venue (or splitting the quantity across venues) based on current prices, fees, and available
+/// balances.
+///
+/// Split child order [`ClientOrderId`]s are derived from the original request
+/// (eg/ "cid-1", "cid-2").
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct SmartOrderRouter {
+    pub policy: RoutingPolicy,
+
+    /// Fee rate of each exchange. Exchanges without a configured fee rate are assumed to be free.
+    pub fee_rates: FnvHashMap<ExchangeIndex, Decimal>,
+}
+
+impl SmartOrderRouter {
+    /// Construct a new `SmartOrderRouter` using the provided [`RoutingPolicy`] and exchange fee
+    /// rates.
+    pub fn new(
+        policy: RoutingPolicy,
+        fee_rates: impl IntoIterator<Item = (ExchangeIndex, Decimal)>,
+    ) -> Self {
+        Self {
+            policy,
+            fee_rates: fee_rates.into_iter().collect(),
+        }
+    }
+
+    /// Generate the [`RouteVenue`]s of the provided instruments from the current
+    /// [`EngineState`].
+    ///
+    /// Instruments without an executable price for the provided `Side` (see
+    /// [`InstrumentDataState::l1`]), or whose exchange connectivity is unhealthy or degraded, are
+    /// excluded.
+    pub fn venues<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        instruments: &[InstrumentIndex],
+        side: Side,
+    ) -> Vec<RouteVenue>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        instruments
+            .iter()
+            .filter_map(|key| {
+                let instrument = state.instruments.instrument_index(key);
+                let exchange = instrument.instrument.exchange;
+
+                let connectivity = state.connectivity.connectivity_index(&exchange);
+                if !connectivity.all_healthy() || connectivity.degraded.is_some() {
+                    return None;
+                }
+
+                let l1 = instrument.data.l1()?;
+                let level = match side {
+                    Side::Buy => l1.best_ask?,
+                    Side::Sell => l1.best_bid?,
+                };
+
+                let funding_asset = match side {
+                    Side::Buy => instrument.instrument.underlying.quote,
+                    Side::Sell => instrument.instrument.underlying.base,
+                };
+                let balance_free = state
+                    .assets
+                    .asset_index(&funding_asset)
+                    .balance
+                    .as_ref()
+                    .map_or(Decimal::ZERO, |balance| balance.value.free);
+
+                Some(RouteVenue {
+                    exchange,
+                    instrument: *key,
+                    price: level.price,
+                    quantity_available: Some(level.amount),
+                    fee_rate: self.fee_rate(&exchange),
+                    balance_free,
+                })
+            })
+            .collect()
+    }
+
+    /// Fee rate of the provided exchange.
+    pub fn fee_rate(&self, exchange: &ExchangeIndex) -> Decimal {
+        self.fee_rates
+            .get(exchange)
+            .copied()
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// Route the provided order request across the provided [`RouteVenue`]s.
+    ///
+    /// The request `exchange` & `instrument` are replaced with those of the selected venues, and
+    /// the price with the venue executable price.
+    pub fn route(
+        &self,
+        request: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+        venues: &[RouteVenue],
+    ) -> RoutedOrder {
+        let side = request.state.side;
+        let quantity = request.state.quantity;
+
+        let mut ranked = venues
+            .iter()
+            .filter(|venue| venue.capacity(side) > Decimal::ZERO)
+            .collect::<Vec<_>>();
+        ranked.sort_by(|a, b| match side {
+            Side::Buy => a.effective_price(side).cmp(&b.effective_price(side)),
+            Side::Sell => b.effective_price(side).cmp(&a.effective_price(side)),
+        });
+
+        let allocations = match self.policy {
+            RoutingPolicy::BestVenue => ranked
+                .into_iter()
+                .find(|venue| venue.capacity(side) >= quantity)
+                .map(|venue| vec![(venue, quantity)])
+                .unwrap_or_default(),
+            RoutingPolicy::Split => {
+                let mut remaining = quantity;
+                ranked
+                    .into_iter()
+                    .map_while(|venue| {
+                        if remaining <= Decimal::ZERO {
+                            return None;
+                        }
+                        let allocation = venue.capacity(side).min(remaining);
+                        remaining -= allocation;
+                        Some((venue, allocation))
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let quantity_routed = allocations
+            .iter()
+            .map(|(_, allocation)| *allocation)
+            .sum::<Decimal>();
+
+        let split = allocations.len() > 1;
+        let requests = allocations
+            .into_iter()
+            .enumerate()
+            .map(|(index, (venue, allocation))| {
+                let mut child = request.clone();
+                child.key.exchange = venue.exchange;
+                child.key.instrument = venue.instrument;
+                if split {
+                    child.key.cid =
+                        ClientOrderId::new(format!("{}-{}", request.key.cid, index + 1));
+                }
+                child.state.price = venue.price;
+                child.state.quantity = allocation;
+                child
+            })
+            .collect();
+
+        RoutedOrder {
+            requests,
+            quantity_unrouted: quantity - quantity_routed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce, id::StrategyId, request::RequestOpen,
+    };
+    use rust_decimal_macros::dec;
+
+    fn request(side: Side, quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(0),
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn venue(index: usize, price: Decimal, available: Decimal, fee_rate: Decimal) -> RouteVenue {
+        RouteVenue {
+            exchange: ExchangeIndex(index),
+            instrument: InstrumentIndex(index),
+            price,
+            quantity_available: Some(available),
+            fee_rate,
+            balance_free: dec!(1_000_000),
+        }
+    }
+
+    #[test]
+    fn test_smart_order_router_route() {
+        struct TestCase {
+            policy: RoutingPolicy,
+            request: OrderRequestOpen,
+            venues: Vec<RouteVenue>,
+            expected: Vec<(&'static str, usize, Decimal)>,
+            expected_unrouted: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: BestVenue buy routes to lowest ask
+            TestCase {
+                policy: RoutingPolicy::BestVenue,
+                request: request(Side::Buy, dec!(1)),
+                venues: vec![
+                    venue(0, dec!(101), dec!(10), dec!(0)),
+                    venue(1, dec!(100), dec!(10), dec!(0)),
+                ],
+                expected: vec![("cid", 1, dec!(1))],
+                expected_unrouted: dec!(0),
+            },
+            // TC1: BestVenue buy accounts for fees
+            TestCase {
+                policy: RoutingPolicy::BestVenue,
+                request: request(Side::Buy, dec!(1)),
+                venues: vec![
+                    venue(0, dec!(100.5), dec!(10), dec!(0)),
+                    venue(1, dec!(100), dec!(10), dec!(0.01)),
+                ],
+                expected: vec![("cid", 0, dec!(1))],
+                expected_unrouted: dec!(0),
+            },
+            // TC2: BestVenue sell skips best bid venue with insufficient liquidity
+            TestCase {
+                policy: RoutingPolicy::BestVenue,
+                request: request(Side::Sell, dec!(5)),
+                venues: vec![
+                    venue(0, dec!(101), dec!(1), dec!(0)),
+                    venue(1, dec!(100), dec!(10), dec!(0)),
+                ],
+                expected: vec![("cid", 1, dec!(5))],
+                expected_unrouted: dec!(0),
+            },
+            // TC3: Split buy fills best venues first
+            TestCase {
+                policy: RoutingPolicy::Split,
+                request: request(Side::Buy, dec!(5)),
+                venues: vec![
+                    venue(0, dec!(102), dec!(10), dec!(0)),
+                    venue(1, dec!(100), dec!(2), dec!(0)),
+                    venue(2, dec!(101), dec!(2), dec!(0)),
+                ],
+                expected: vec![
+                    ("cid-1", 1, dec!(2)),
+                    ("cid-2", 2, dec!(2)),
+                    ("cid-3", 0, dec!(1)),
+                ],
+                expected_unrouted: dec!(0),
+            },
+            // TC4: Split with insufficient total liquidity
+            TestCase {
+                policy: RoutingPolicy::Split,
+                request: request(Side::Sell, dec!(5)),
+                venues: vec![
+                    venue(0, dec!(100), dec!(1), dec!(0)),
+                    venue(1, dec!(101), dec!(2), dec!(0)),
+                ],
+                expected: vec![("cid-1", 1, dec!(2)), ("cid-2", 0, dec!(1))],
+                expected_unrouted: dec!(2),
+            },
+            // TC5: BestVenue with no venue able to fill the quantity
+            TestCase {
+                policy: RoutingPolicy::BestVenue,
+                request: request(Side::Buy, dec!(5)),
+                venues: vec![venue(0, dec!(100), dec!(1), dec!(0))],
+                expected: vec![],
+                expected_unrouted: dec!(5),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let router = SmartOrderRouter::new(test.policy, []);
+            let actual = router.route(test.request, &test.venues);
+
+            let requests = actual
+                .requests
+                .iter()
+                .map(|request| {
+                    (
+                        request.key.cid.0.as_str(),
+                        request.key.instrument.index(),
+                        request.state.quantity,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(requests, test.expected, "TC{index} failed");
+            assert_eq!(
+                actual.quantity_unrouted, test.expected_unrouted,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_route_venue_capacity_limited_by_balance() {
+        let venue = RouteVenue {
+            balance_free: dec!(250),
+            ..venue(0, dec!(100), dec!(10), dec!(0))
+        };
+
+        assert_eq!(venue.capacity(Side::Buy), dec!(2.5));
+        assert_eq!(venue.capacity(Side::Sell), dec!(10));
+    }
+}
@@ -47,6 +47,7 @@ use barter_data::{
 };
 use barter_execution::AccountEvent;
 use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_integration::channel::Droppable;
 use chrono::{DateTime, Utc};
 use derive_more::{Constructor, From};
 use serde::{Deserialize, Serialize};
@@ -86,6 +87,12 @@ pub mod system;
 /// Backtesting utilities.
 pub mod backtest;
 
+/// Scenario-based test harness that drives a real `Engine` through scripted market events,
+/// account events, exchange responses and disconnects.
+///
+/// eg/ `Scenario`, `SimulatedExchange`, `ScenarioStep`, etc.
+pub mod scenario;
+
 /// Traits and types related to component shutdowns.
 pub mod shutdown;
 
@@ -152,6 +159,18 @@ impl<MarketKind, ExchangeKey, AssetKey, InstrumentKey> From<MarketEvent<Instrume
     }
 }
 
+/// Only market data items may be discarded by a full `Engine` feed channel configured with
+/// [`OverflowPolicy::DropOldest`](barter_integration::channel::OverflowPolicy::DropOldest).
+///
+/// Account events, market stream reconnections, and `Engine` commands are never discarded.
+impl<MarketKind, ExchangeKey, AssetKey, InstrumentKey> Droppable
+    for EngineEvent<MarketKind, ExchangeKey, AssetKey, InstrumentKey>
+{
+    fn is_droppable(&self) -> bool {
+        matches!(self, Self::Market(MarketStreamEvent::Item(_)))
+    }
+}
+
 /// Monotonically increasing event sequence. Used to track `Engine` event processing sequence.
 #[derive(
     Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
@@ -230,12 +249,14 @@ pub mod test_utils {
         Trade {
             id: TradeId::new("trade_id"),
             order_id: OrderId::new("order_id"),
+            cid: None,
             instrument: InstrumentNameInternal::new("instrument"),
             strategy: StrategyId::new("strategy"),
             time_exchange,
             side,
             price: price.try_into().unwrap(),
             quantity: quantity.try_into().unwrap(),
+            liquidity: None,
             fees: AssetFees {
                 asset: QuoteAsset,
                 fees: fees.try_into().unwrap(),
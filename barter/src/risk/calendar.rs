@@ -0,0 +1,307 @@
+use crate::{
+    engine::state::{EngineState, position::PositionExited},
+    risk::{
+        RiskApproved, RiskManager, RiskRefused, check::util::is_position_reducing, halt::RiskHalt,
+    },
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, Datelike, NaiveTime, Utc, Weekday};
+use derive_more::Constructor;
+use itertools::Either;
+use serde::{Deserialize, Serialize};
+
+/// Time-of-day window (UTC) during which trading is permitted.
+///
+/// If `end` is earlier than `start`, the window wraps past midnight (eg/ 22:00 -> 02:00).
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct SessionWindow {
+    /// Inclusive start time of the window.
+    pub start: NaiveTime,
+
+    /// Exclusive end time of the window.
+    pub end: NaiveTime,
+}
+
+impl SessionWindow {
+    /// Returns true if the provided time of day falls within the `SessionWindow`.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        }
+    }
+}
+
+/// Period during which trading is not permitted, even if it falls within a [`SessionWindow`].
+///
+/// eg/ scheduled exchange maintenance.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct Blackout {
+    /// Inclusive start time of the blackout.
+    pub start: DateTime<Utc>,
+
+    /// Exclusive end time of the blackout.
+    pub end: DateTime<Utc>,
+}
+
+impl Blackout {
+    /// Returns true if the provided time falls within the `Blackout`.
+    pub fn contains(&self, time: DateTime<Utc>) -> bool {
+        self.start <= time && time < self.end
+    }
+}
+
+/// Trading calendar that determines if trading is permitted at a given time.
+///
+/// Trading is permitted if the time falls on an allowed weekday, within any configured
+/// [`SessionWindow`] (all times of day permitted if none are configured), and outside every
+/// [`Blackout`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize, Constructor)]
+pub struct TradingCalendar {
+    /// Weekdays that trading is permitted.
+    pub weekdays: Vec<Weekday>,
+
+    /// Time-of-day windows that trading is permitted.
+    pub sessions: Vec<SessionWindow>,
+
+    /// Periods that trading is not permitted.
+    pub blackouts: Vec<Blackout>,
+}
+
+impl Default for TradingCalendar {
+    fn default() -> Self {
+        Self {
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            sessions: vec![],
+            blackouts: vec![],
+        }
+    }
+}
+
+impl TradingCalendar {
+    /// Returns true if trading is permitted at the provided `time`.
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        self.weekdays.contains(&time.weekday())
+            && (self.sessions.is_empty()
+                || self
+                    .sessions
+                    .iter()
+                    .any(|session| session.contains(time.time())))
+            && !self
+                .blackouts
+                .iter()
+                .any(|blackout| blackout.contains(time))
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, refusing entry orders outside of the
+/// [`TradingCalendar`] sessions.
+///
+/// Cancel requests, and open requests that only reduce an existing `Position`, are always
+/// forwarded to the inner `RiskManager` so exits are still permitted outside of sessions.
+#[derive(Debug, Clone, Constructor)]
+pub struct TradingCalendarRiskManager<Risk> {
+    pub inner: Risk,
+    pub calendar: TradingCalendar,
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for TradingCalendarRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let opens = opens.into_iter();
+
+        let (opens, refused_closed) = if self.calendar.is_open(state.time_engine_now) {
+            (Either::Left(opens), Vec::new())
+        } else {
+            let (exits, entries): (Vec<_>, Vec<_>) = opens.partition(|open| {
+                is_position_reducing(
+                    state
+                        .instruments
+                        .instrument_index(&open.key.instrument)
+                        .position
+                        .current
+                        .as_ref(),
+                    open.state.side,
+                    open.state.quantity,
+                )
+            });
+
+            let refused = entries
+                .into_iter()
+                .map(|open| RiskRefused::new(open, "TradingCalendar closed: entry not permitted"))
+                .collect::<Vec<_>>();
+
+            (Either::Right(exits.into_iter()), refused)
+        };
+
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(refused_closed),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(input: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(input).unwrap().to_utc()
+    }
+
+    fn time_of_day(hour: u32, min: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_session_window_contains() {
+        struct TestCase {
+            window: SessionWindow,
+            input: NaiveTime,
+            expected: bool,
+        }
+
+        let day = SessionWindow::new(time_of_day(9, 0), time_of_day(17, 0));
+        let overnight = SessionWindow::new(time_of_day(22, 0), time_of_day(2, 0));
+
+        let cases = vec![
+            // TC0: within day window
+            TestCase {
+                window: day,
+                input: time_of_day(12, 0),
+                expected: true,
+            },
+            // TC1: start of day window is inclusive
+            TestCase {
+                window: day,
+                input: time_of_day(9, 0),
+                expected: true,
+            },
+            // TC2: end of day window is exclusive
+            TestCase {
+                window: day,
+                input: time_of_day(17, 0),
+                expected: false,
+            },
+            // TC3: within overnight window before midnight
+            TestCase {
+                window: overnight,
+                input: time_of_day(23, 0),
+                expected: true,
+            },
+            // TC4: within overnight window after midnight
+            TestCase {
+                window: overnight,
+                input: time_of_day(1, 0),
+                expected: true,
+            },
+            // TC5: outside overnight window
+            TestCase {
+                window: overnight,
+                input: time_of_day(12, 0),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.window.contains(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_trading_calendar_is_open() {
+        struct TestCase {
+            input: DateTime<Utc>,
+            expected: bool,
+        }
+
+        let calendar = TradingCalendar {
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            sessions: vec![SessionWindow::new(time_of_day(9, 0), time_of_day(17, 0))],
+            blackouts: vec![Blackout::new(
+                time("2024-01-03T12:00:00Z"),
+                time("2024-01-03T13:00:00Z"),
+            )],
+        };
+
+        let cases = vec![
+            // TC0: allowed weekday within session
+            TestCase {
+                input: time("2024-01-02T10:00:00Z"),
+                expected: true,
+            },
+            // TC1: allowed weekday outside session
+            TestCase {
+                input: time("2024-01-02T18:00:00Z"),
+                expected: false,
+            },
+            // TC2: disallowed weekday within session
+            TestCase {
+                input: time("2024-01-06T10:00:00Z"),
+                expected: false,
+            },
+            // TC3: within blackout
+            TestCase {
+                input: time("2024-01-03T12:30:00Z"),
+                expected: false,
+            },
+            // TC4: blackout end is exclusive
+            TestCase {
+                input: time("2024-01-03T13:00:00Z"),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = calendar.is_open(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::engine::state::position::Position;
 use barter_instrument::Side;
 use rust_decimal::Decimal;
 
@@ -60,3 +61,15 @@ pub fn calculate_delta(
         Side::Sell => -delta,
     }
 }
+
+/// Determines if an order with the provided `side` and `quantity` only reduces the current
+/// [`Position`] (ie/ it is on the opposite `Side` and does not flip the `Position`).
+///
+/// Returns false if there is no current `Position`.
+pub fn is_position_reducing<AssetKey, InstrumentKey>(
+    position: Option<&Position<AssetKey, InstrumentKey>>,
+    side: Side,
+    quantity: Decimal,
+) -> bool {
+    position.is_some_and(|position| position.side != side && quantity <= position.quantity_abs)
+}
@@ -0,0 +1,416 @@
+use crate::{
+    engine::state::{EngineState, instrument::data::InstrumentDataState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, check::util::calculate_delta, halt::RiskHalt},
+    statistic::metric::value_at_risk::{InstrumentExposure, PortfolioExposures, price_returns},
+    strategy::library::PriceHistory,
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{
+    asset::QuoteAsset,
+    exchange::ExchangeIndex,
+    instrument::{InstrumentIndex, name::InstrumentNameInternal},
+};
+use derive_more::Constructor;
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the [`CorrelationRiskManager`].
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, Constructor)]
+pub struct CorrelationConfig {
+    /// Number of most recent price returns used to estimate rolling correlations.
+    pub period: usize,
+
+    /// Minimum return correlation (eg/ 0.7) for two instruments to be clustered together.
+    pub threshold: Decimal,
+
+    /// Maximum absolute net notional exposure of a cluster, in quote asset units.
+    pub max_cluster_notional: Decimal,
+
+    /// Static buckets of instruments that are always clustered together, regardless of their
+    /// estimated correlation.
+    ///
+    /// eg/ `[["sol_usdt", "avax_usdt", "ada_usdt"]]` to treat L1 tokens as one bucket.
+    #[serde(default)]
+    pub buckets: Vec<Vec<InstrumentNameInternal>>,
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, limiting the aggregate exposure to
+/// clusters of highly correlated positions, rather than only limiting per-instrument exposure.
+///
+/// The cluster of an open order request's instrument contains every instrument linked to it,
+/// directly or transitively, by a rolling return [`correlation`] above the
+/// [`CorrelationConfig::threshold`], or by a static [`CorrelationConfig::buckets`] bucket.
+/// Orders that would push the absolute net notional of the cluster above the
+/// [`CorrelationConfig::max_cluster_notional`] are refused. Orders that reduce it are always
+/// approved.
+#[derive(Debug, Clone, Constructor)]
+pub struct CorrelationRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: CorrelationConfig,
+}
+
+impl<Risk> CorrelationRiskManager<Risk> {
+    /// Check if an order with the provided signed notional can be approved given the current
+    /// [`PortfolioExposures`], returning the refusal reason if not.
+    ///
+    /// The provided closure determines if two instruments share a static bucket.
+    ///
+    /// If approved, the order notional is added to the `PortfolioExposures`, so subsequent
+    /// orders are checked against the projected cluster exposure.
+    pub fn check_open<FnBucket>(
+        &self,
+        exposures: &mut PortfolioExposures,
+        instrument: InstrumentIndex,
+        order_notional: Decimal,
+        same_bucket: FnBucket,
+    ) -> Result<(), String>
+    where
+        FnBucket: Fn(InstrumentIndex, InstrumentIndex) -> bool,
+    {
+        let cluster = correlated_cluster(exposures, instrument, self.config.threshold, same_bucket);
+
+        let current = cluster
+            .iter()
+            .filter_map(|key| exposures.0.get(key))
+            .map(|exposure| exposure.notional)
+            .sum::<Decimal>();
+        let projected = current + order_notional;
+
+        if projected.abs() > self.config.max_cluster_notional && projected.abs() > current.abs() {
+            return Err(format!(
+                "CorrelationRiskManager projected cluster notional {projected} of {} correlated \
+                 instruments exceeds maximum {}",
+                cluster.len(),
+                self.config.max_cluster_notional
+            ));
+        }
+
+        exposures.0.entry(instrument).or_default().notional += order_notional;
+        Ok(())
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for CorrelationRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: InstrumentDataState + PriceHistory,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let same_bucket = |a: InstrumentIndex, b: InstrumentIndex| {
+            let name_a = &state
+                .instruments
+                .instrument_index(&a)
+                .instrument
+                .name_internal;
+            let name_b = &state
+                .instruments
+                .instrument_index(&b)
+                .instrument
+                .name_internal;
+            self.config
+                .buckets
+                .iter()
+                .any(|bucket| bucket.contains(name_a) && bucket.contains(name_b))
+        };
+
+        let mut exposures = None;
+        let mut concentrated_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                let key = open.0.key.instrument;
+                let instrument = state.instruments.instrument_index(&key);
+                let price = instrument.data.price().unwrap_or(open.0.state.price);
+
+                let order_notional = price
+                    * calculate_delta(
+                        Decimal::ONE,
+                        instrument.instrument.kind.contract_size(),
+                        open.0.state.side,
+                        open.0.state.quantity,
+                    );
+
+                let exposures = exposures.get_or_insert_with(|| {
+                    PortfolioExposures::calculate(state, self.config.period)
+                });
+
+                exposures.0.entry(key).or_insert_with(|| {
+                    InstrumentExposure::new(
+                        Decimal::ZERO,
+                        price_returns(instrument.data.prices(), self.config.period)
+                            .unwrap_or_default(),
+                    )
+                });
+
+                match self.check_open(exposures, key, order_notional, same_bucket) {
+                    Ok(()) => Some(open),
+                    Err(reason) => {
+                        concentrated_opens.push(RiskRefused::new(open.into_item(), reason));
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(concentrated_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+/// Determine the cluster of instruments linked to the provided instrument, directly or
+/// transitively, by a return [`correlation`] of at least the threshold, or by sharing a static
+/// bucket.
+///
+/// The cluster always contains the provided instrument.
+pub fn correlated_cluster<FnBucket>(
+    exposures: &PortfolioExposures,
+    instrument: InstrumentIndex,
+    threshold: Decimal,
+    same_bucket: FnBucket,
+) -> Vec<InstrumentIndex>
+where
+    FnBucket: Fn(InstrumentIndex, InstrumentIndex) -> bool,
+{
+    let is_linked = |a: InstrumentIndex, b: InstrumentIndex| {
+        same_bucket(a, b)
+            || match (exposures.0.get(&a), exposures.0.get(&b)) {
+                (Some(a), Some(b)) => correlation(&a.returns, &b.returns)
+                    .is_some_and(|correlation| correlation >= threshold),
+                _ => false,
+            }
+    };
+
+    let mut cluster = vec![instrument];
+    let mut next = 0;
+
+    while let Some(&member) = cluster.get(next) {
+        let linked = exposures
+            .0
+            .keys()
+            .copied()
+            .filter(|candidate| !cluster.contains(candidate) && is_linked(member, *candidate))
+            .collect::<Vec<_>>();
+
+        cluster.extend(linked);
+        next += 1;
+    }
+
+    cluster
+}
+
+/// Pearson correlation of the most recent overlapping values of two return series.
+///
+/// Returns `None` if there are fewer than two overlapping values, or either series has zero
+/// variance.
+pub fn correlation(a: &[Decimal], b: &[Decimal]) -> Option<Decimal> {
+    let len = a.len().min(b.len());
+    if len < 2 {
+        return None;
+    }
+
+    let (a, b) = (&a[a.len() - len..], &b[b.len() - len..]);
+    let count = Decimal::from(len);
+    let mean_a = a.iter().sum::<Decimal>() / count;
+    let mean_b = b.iter().sum::<Decimal>() / count;
+
+    let (covariance, variance_a, variance_b) = a.iter().zip(b).fold(
+        (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO),
+        |(covariance, variance_a, variance_b), (a, b)| {
+            let (delta_a, delta_b) = (a - mean_a, b - mean_b);
+            (
+                covariance + delta_a * delta_b,
+                variance_a + delta_a * delta_a,
+                variance_b + delta_b * delta_b,
+            )
+        },
+    );
+
+    let denominator = (variance_a * variance_b).sqrt()?;
+    (!denominator.is_zero()).then(|| covariance / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::DefaultRiskManager;
+    use barter_integration::collection::FnvIndexMap;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_correlation() {
+        struct TestCase {
+            a: Vec<Decimal>,
+            b: Vec<Decimal>,
+            expected: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: perfectly correlated
+            TestCase {
+                a: vec![dec!(0.01), dec!(0.02), dec!(-0.01)],
+                b: vec![dec!(0.02), dec!(0.04), dec!(-0.02)],
+                expected: Some(dec!(1)),
+            },
+            // TC1: perfectly anti-correlated
+            TestCase {
+                a: vec![dec!(0.01), dec!(0.02), dec!(-0.01)],
+                b: vec![dec!(-0.01), dec!(-0.02), dec!(0.01)],
+                expected: Some(dec!(-1)),
+            },
+            // TC2: aligned to the most recent overlapping returns
+            TestCase {
+                a: vec![dec!(0.5), dec!(0.01), dec!(0.03)],
+                b: vec![dec!(0.02), dec!(0.06)],
+                expected: Some(dec!(1)),
+            },
+            // TC3: zero variance
+            TestCase {
+                a: vec![dec!(0.01), dec!(0.01)],
+                b: vec![dec!(0.01), dec!(0.02)],
+                expected: None,
+            },
+            // TC4: insufficient returns
+            TestCase {
+                a: vec![dec!(0.01)],
+                b: vec![dec!(0.01), dec!(0.02)],
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = correlation(&test.a, &test.b).map(|value| value.round_dp(10));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_correlation_risk_manager_check_open() {
+        struct TestCase {
+            instrument: InstrumentIndex,
+            order_notional: Decimal,
+            bucketed: bool,
+            expected: bool,
+        }
+
+        let (sol, avax, btc, eth) = (
+            InstrumentIndex(0),
+            InstrumentIndex(1),
+            InstrumentIndex(2),
+            InstrumentIndex(3),
+        );
+
+        let returns = vec![dec!(0.01), dec!(0.02), dec!(-0.01), dec!(0.03)];
+        let exposures = PortfolioExposures(FnvIndexMap::from_iter([
+            (sol, InstrumentExposure::new(dec!(600), returns.clone())),
+            (
+                avax,
+                InstrumentExposure::new(
+                    dec!(300),
+                    returns.iter().map(|ret| ret * dec!(2)).collect(),
+                ),
+            ),
+            (
+                btc,
+                InstrumentExposure::new(
+                    dec!(800),
+                    vec![dec!(-0.01), dec!(0.01), dec!(0.01), dec!(-0.01)],
+                ),
+            ),
+            (eth, InstrumentExposure::new(dec!(0), vec![])),
+        ]));
+
+        let cases = vec![
+            // TC0: order within the maximum cluster notional is approved
+            TestCase {
+                instrument: sol,
+                order_notional: dec!(50),
+                bucketed: false,
+                expected: true,
+            },
+            // TC1: order pushing the correlated sol & avax cluster above the maximum is refused
+            TestCase {
+                instrument: avax,
+                order_notional: dec!(200),
+                bucketed: false,
+                expected: false,
+            },
+            // TC2: uncorrelated instrument is limited independently
+            TestCase {
+                instrument: btc,
+                order_notional: dec!(150),
+                bucketed: false,
+                expected: true,
+            },
+            // TC3: order reducing the cluster notional is approved
+            TestCase {
+                instrument: sol,
+                order_notional: dec!(-100),
+                bucketed: false,
+                expected: true,
+            },
+            // TC4: instrument without return history joins the cluster via a static bucket
+            TestCase {
+                instrument: eth,
+                order_notional: dec!(200),
+                bucketed: true,
+                expected: false,
+            },
+            // TC5: instrument without return history or bucket forms its own cluster
+            TestCase {
+                instrument: eth,
+                order_notional: dec!(200),
+                bucketed: false,
+                expected: true,
+            },
+        ];
+
+        let risk = CorrelationRiskManager::new(
+            DefaultRiskManager::<()>::default(),
+            CorrelationConfig::new(4, dec!(0.7), dec!(1000), vec![]),
+        );
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut exposures = exposures.clone();
+            let same_bucket = |a: InstrumentIndex, b: InstrumentIndex| {
+                test.bucketed && [a, b].contains(&sol) && [a, b].contains(&eth)
+            };
+
+            let actual = risk
+                .check_open(
+                    &mut exposures,
+                    test.instrument,
+                    test.order_notional,
+                    same_bucket,
+                )
+                .is_ok();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        position::PositionExited,
+    },
+    risk::{
+        RiskApproved, RiskManager, RiskRefused,
+        check::util::is_position_reducing,
+        halt::{RiskHalt, RiskHaltReason},
+    },
+    strategy::close_positions::build_ioc_market_order_to_close_position,
+};
+use barter_execution::order::{
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+use itertools::Either;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Tracks the daily realised and unrealised PnL of the trading system, and determines if a
+/// configurable daily loss limit has been breached.
+///
+/// Realised PnL is accumulated from [`PositionExited`]s, and reset at every session boundary
+/// (a UTC time of day). Unrealised PnL is the sum of all open `Position` `pnl_unrealised`.
+///
+/// Note that PnL across instruments is summed naively, so all instruments are assumed to share
+/// the same quote asset.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct DailyLossLimit {
+    /// Maximum daily loss (positive value) before the limit is breached.
+    pub limit: Decimal,
+
+    /// UTC time of day that the daily session resets.
+    pub session_start: NaiveTime,
+
+    /// Start of the session the `pnl_realised` is associated with.
+    pub session_open: Option<DateTime<Utc>>,
+
+    /// Realised PnL accumulated during the current session.
+    pub pnl_realised: Decimal,
+}
+
+impl DailyLossLimit {
+    /// Construct a new `DailyLossLimit` with the provided maximum daily loss, and UTC time of day
+    /// the daily session resets.
+    pub fn new(limit: Decimal, session_start: NaiveTime) -> Self {
+        Self {
+            limit: limit.abs(),
+            session_start,
+            session_open: None,
+            pnl_realised: Decimal::ZERO,
+        }
+    }
+
+    /// Calculate the start of the session that the provided `time` belongs to.
+    pub fn session_open_at(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let open = time.date_naive().and_time(self.session_start).and_utc();
+        if open > time {
+            open - TimeDelta::days(1)
+        } else {
+            open
+        }
+    }
+
+    /// Realised PnL of the session that the provided `time_now` belongs to.
+    pub fn pnl_realised_at(&self, time_now: DateTime<Utc>) -> Decimal {
+        if self.session_open == Some(self.session_open_at(time_now)) {
+            self.pnl_realised
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// Calculate the current daily PnL (session realised PnL + open position unrealised PnL).
+    pub fn pnl_daily<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+    ) -> Decimal {
+        let pnl_unrealised = state
+            .instruments
+            .positions(&InstrumentFilter::None)
+            .filter_map(|manager| manager.current.as_ref())
+            .map(|position| position.pnl_unrealised)
+            .sum::<Decimal>();
+
+        self.pnl_realised_at(state.time_engine_now) + pnl_unrealised
+    }
+
+    /// Returns true if the provided daily PnL represents a loss exceeding the limit.
+    pub fn is_breached(&self, pnl_daily: Decimal) -> bool {
+        pnl_daily < -self.limit
+    }
+
+    /// Update the session realised PnL from a [`PositionExited`].
+    ///
+    /// If this realised PnL breaches the daily loss limit, a [`RiskHalt`] is returned that lasts
+    /// until the next session boundary.
+    pub fn update_from_position_exit<AssetKey, InstrumentKey>(
+        &mut self,
+        position: &PositionExited<AssetKey, InstrumentKey>,
+    ) -> Option<RiskHalt> {
+        let session_open = self.session_open_at(position.time_exit);
+        if self.session_open != Some(session_open) {
+            self.session_open = Some(session_open);
+            self.pnl_realised = Decimal::ZERO;
+        }
+
+        let breached_prev = self.is_breached(self.pnl_realised);
+        self.pnl_realised += position.pnl_realised;
+
+        if breached_prev || !self.is_breached(self.pnl_realised) {
+            return None;
+        }
+
+        let halt = RiskHalt {
+            reason: RiskHaltReason::DailyLossLimit {
+                pnl: self.pnl_realised,
+            },
+            time_halted: position.time_exit,
+            halted_until: session_open + TimeDelta::days(1),
+        };
+
+        warn!(
+            ?halt,
+            "RiskManager halting new entries after breaching daily loss limit"
+        );
+
+        Some(halt)
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, blocking new entry orders once the
+/// [`DailyLossLimit`] is breached.
+///
+/// Whilst breached, open order requests that only reduce an existing `Position` are still
+/// forwarded to the inner `RiskManager`.
+///
+/// If configured with a `force_exit` [`StrategyId`], all algorithmic open requests are refused
+/// and `ImmediateOrCancel` `Market` orders are generated to close every open `Position` (that
+/// has no active orders and has market data to price the exit order).
+#[derive(Debug, Clone)]
+pub struct DailyLossRiskManager<Risk> {
+    pub inner: Risk,
+    pub limit: DailyLossLimit,
+    pub force_exit: Option<StrategyId>,
+}
+
+impl<Risk> DailyLossRiskManager<Risk> {
+    /// Construct a new `DailyLossRiskManager` wrapping the provided inner `RiskManager`.
+    pub fn new(inner: Risk, limit: DailyLossLimit, force_exit: Option<StrategyId>) -> Self {
+        Self {
+            inner,
+            limit,
+            force_exit,
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for DailyLossRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let opens = opens.into_iter();
+        let pnl_daily = self.limit.pnl_daily(state);
+
+        let (opens, refused_limit, force_exits) = if self.limit.is_breached(pnl_daily) {
+            let reason = format!(
+                "DailyLossLimit breached: daily PnL {pnl_daily} exceeds limit {}",
+                self.limit.limit
+            );
+
+            match &self.force_exit {
+                Some(strategy_id) => {
+                    let refused = opens
+                        .map(|open| RiskRefused::new(open, reason.clone()))
+                        .collect::<Vec<_>>();
+
+                    let exits = state
+                        .instruments
+                        .instruments(&InstrumentFilter::None)
+                        .filter(|instrument_state| instrument_state.orders.0.is_empty())
+                        .filter_map(|instrument_state| {
+                            let position = instrument_state.position.current.as_ref()?;
+                            let price = instrument_state.data.price()?;
+
+                            Some(build_ioc_market_order_to_close_position(
+                                instrument_state.instrument.exchange,
+                                position,
+                                strategy_id.clone(),
+                                price,
+                                ClientOrderId::random,
+                            ))
+                        })
+                        .collect::<Vec<_>>();
+
+                    (Either::Left(Vec::new().into_iter()), refused, exits)
+                }
+                None => {
+                    let (reducing, refused): (Vec<_>, Vec<_>) = opens.partition(|open| {
+                        is_position_reducing(
+                            state
+                                .instruments
+                                .instrument_index(&open.key.instrument)
+                                .position
+                                .current
+                                .as_ref(),
+                            open.state.side,
+                            open.state.quantity,
+                        )
+                    });
+
+                    let refused = refused
+                        .into_iter()
+                        .map(|open| RiskRefused::new(open, reason.clone()))
+                        .collect::<Vec<_>>();
+
+                    (Either::Left(reducing.into_iter()), refused, Vec::new())
+                }
+            }
+        } else {
+            (Either::Right(opens), Vec::new(), Vec::new())
+        };
+
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        (
+            approved_cancels,
+            approved_opens
+                .into_iter()
+                .chain(force_exits.into_iter().map(RiskApproved::new)),
+            refused_cancels,
+            refused_opens.into_iter().chain(refused_limit),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        let mut halts = self.inner.update_from_position_exit(position);
+        halts.extend(self.limit.update_from_position_exit(position));
+        halts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{time_plus_days, time_plus_secs};
+    use barter_execution::trade::AssetFees;
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    fn position_exited(
+        pnl_realised: Decimal,
+        time_exit: DateTime<Utc>,
+    ) -> PositionExited<QuoteAsset> {
+        PositionExited {
+            instrument: InstrumentIndex(0),
+            side: Side::Buy,
+            price_entry_average: dec!(100.0),
+            quantity_abs_max: dec!(1.0),
+            pnl_realised,
+            excursion_adverse_max: dec!(0.0),
+            excursion_favourable_max: dec!(0.0),
+            fees_enter: AssetFees::quote_fees(dec!(0.0)),
+            fees_exit: AssetFees::quote_fees(dec!(0.0)),
+            time_enter: time_exit,
+            time_exit,
+            trades: vec![],
+            signal: None,
+        }
+    }
+
+    fn time_base() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_daily_loss_limit_session_open_at() {
+        struct TestCase {
+            session_start: NaiveTime,
+            input: DateTime<Utc>,
+            expected: DateTime<Utc>,
+        }
+
+        // 2023-11-14T22:13:20Z
+        let base = time_base();
+        let midnight = base.date_naive().and_time(NaiveTime::MIN).and_utc();
+
+        let cases = vec![
+            // TC0: session starts at midnight
+            TestCase {
+                session_start: NaiveTime::MIN,
+                input: base,
+                expected: midnight,
+            },
+            // TC1: session starts earlier in the same day
+            TestCase {
+                session_start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                input: base,
+                expected: time_plus_secs(midnight, 22 * 60 * 60),
+            },
+            // TC2: session starts later in the day, so belongs to the previous day session
+            TestCase {
+                session_start: NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+                input: base,
+                expected: time_plus_secs(midnight, -(60 * 60)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let limit = DailyLossLimit::new(dec!(100.0), test.session_start);
+            let actual = limit.session_open_at(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_daily_loss_limit_update_from_position_exit() {
+        struct TestCase {
+            exits: Vec<(Decimal, DateTime<Utc>)>,
+            expected_halts: Vec<bool>,
+            expected_pnl_realised: Decimal,
+        }
+
+        let base = time_base();
+
+        let cases = vec![
+            // TC0: losses within limit
+            TestCase {
+                exits: vec![(dec!(-50.0), base), (dec!(-50.0), base)],
+                expected_halts: vec![false, false],
+                expected_pnl_realised: dec!(-100.0),
+            },
+            // TC1: losses exceed limit
+            TestCase {
+                exits: vec![(dec!(-50.0), base), (dec!(-60.0), base)],
+                expected_halts: vec![false, true],
+                expected_pnl_realised: dec!(-110.0),
+            },
+            // TC2: halt is only emitted when the limit is first breached
+            TestCase {
+                exits: vec![(dec!(-110.0), base), (dec!(-10.0), base)],
+                expected_halts: vec![true, false],
+                expected_pnl_realised: dec!(-120.0),
+            },
+            // TC3: realised PnL resets at the next session
+            TestCase {
+                exits: vec![(dec!(-90.0), base), (dec!(-90.0), time_plus_days(base, 1))],
+                expected_halts: vec![false, false],
+                expected_pnl_realised: dec!(-90.0),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut limit = DailyLossLimit::new(dec!(100.0), NaiveTime::MIN);
+
+            let actual = test
+                .exits
+                .into_iter()
+                .map(|(pnl, time)| {
+                    limit
+                        .update_from_position_exit(&position_exited(pnl, time))
+                        .is_some()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected_halts, "TC{index} failed");
+            assert_eq!(
+                limit.pnl_realised, test.expected_pnl_realised,
+                "TC{index} failed"
+            );
+        }
+    }
+}
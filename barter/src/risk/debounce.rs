@@ -0,0 +1,261 @@
+use crate::{
+    engine::state::{EngineState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, halt::RiskHalt},
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{
+    Side, asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+
+/// Configuration of the [`SignalDebounceRiskManager`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct SignalDebounce {
+    /// Minimum duration between two same-direction open orders for an instrument.
+    pub cooldown: TimeDelta,
+
+    /// Minimum number of algo order generations (ie/ `RiskManager` checks) between two
+    /// opposite-direction open orders for an instrument.
+    ///
+    /// Zero disables the flip-flop check.
+    pub flip_events: u64,
+}
+
+/// Most recently approved open order direction of an instrument.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LastSignal {
+    pub side: Side,
+    pub time: DateTime<Utc>,
+    pub event: u64,
+}
+
+/// Per-instrument [`LastSignal`]s used by the [`SignalDebounceRiskManager`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignalDebounceState {
+    pub config: SignalDebounce,
+
+    /// Number of algo order generations (ie/ `RiskManager` checks) seen.
+    pub events: u64,
+
+    pub instruments: FnvHashMap<InstrumentIndex, LastSignal>,
+}
+
+impl SignalDebounceState {
+    /// Construct a new `SignalDebounceState` with the provided [`SignalDebounce`] config.
+    pub fn new(config: SignalDebounce) -> Self {
+        Self {
+            config,
+            events: 0,
+            instruments: FnvHashMap::default(),
+        }
+    }
+
+    /// Record the start of a new algo order generation.
+    pub fn record_event(&mut self) {
+        self.events += 1;
+    }
+
+    /// Attempt to accept an open order of the provided [`Side`] for the instrument at
+    /// `time_now`.
+    ///
+    /// Returns the reason the order is suppressed if it repeats the previous direction within
+    /// the cooldown, or flips the previous direction within the `flip_events`. Accepted orders
+    /// are recorded as the instrument [`LastSignal`].
+    pub fn try_accept(
+        &mut self,
+        time_now: DateTime<Utc>,
+        instrument: InstrumentIndex,
+        side: Side,
+    ) -> Result<(), &'static str> {
+        if let Some(last) = self.instruments.get(&instrument) {
+            if last.side == side && time_now - last.time < self.config.cooldown {
+                return Err("SignalDebounce suppressed repeated signal within cooldown");
+            }
+
+            if last.side != side && self.events - last.event < self.config.flip_events {
+                return Err("SignalDebounce suppressed flip-flopping signal");
+            }
+        }
+
+        self.instruments.insert(
+            instrument,
+            LastSignal {
+                side,
+                time: time_now,
+                event: self.events,
+            },
+        );
+
+        Ok(())
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, debouncing the open order requests it
+/// approves to reduce churn from noisy strategies.
+///
+/// Open orders that repeat the previous direction of an instrument within the
+/// [`SignalDebounce`] `cooldown`, or flip the previous direction within `flip_events` algo order
+/// generations, are refused. Cancel requests are never debounced.
+///
+/// Note that `ClosePositions` commands bypass the `RiskManager`, so are never debounced.
+#[derive(Debug)]
+pub struct SignalDebounceRiskManager<Risk> {
+    pub inner: Risk,
+    pub state: parking_lot::Mutex<SignalDebounceState>,
+}
+
+impl<Risk> SignalDebounceRiskManager<Risk> {
+    /// Construct a new `SignalDebounceRiskManager` wrapping the provided inner `RiskManager`.
+    pub fn new(inner: Risk, config: SignalDebounce) -> Self {
+        Self {
+            inner,
+            state: parking_lot::Mutex::new(SignalDebounceState::new(config)),
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for SignalDebounceRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let time_now = state.time_engine_now;
+        let mut debounce = self.state.lock();
+        debounce.record_event();
+
+        let mut debounced_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                match debounce.try_accept(time_now, open.0.key.instrument, open.0.state.side) {
+                    Ok(()) => Some(open),
+                    Err(reason) => {
+                        debounced_opens.push(RiskRefused::new(open.into_item(), reason));
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        drop(debounce);
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(debounced_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+
+    #[test]
+    fn test_signal_debounce_state_try_accept() {
+        struct TestCase {
+            config: SignalDebounce,
+            // (event, secs, instrument, side)
+            signals: Vec<(u64, i64, usize, Side)>,
+            expected: Vec<bool>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let config = |cooldown_secs, flip_events| {
+            SignalDebounce::new(TimeDelta::seconds(cooldown_secs), flip_events)
+        };
+
+        let cases = vec![
+            // TC0: repeated same-direction signals suppressed within cooldown
+            TestCase {
+                config: config(10, 0),
+                signals: vec![
+                    (1, 0, 0, Side::Buy),
+                    (2, 5, 0, Side::Buy),
+                    (3, 10, 0, Side::Buy),
+                ],
+                expected: vec![true, false, true],
+            },
+            // TC1: flip-flopping signals suppressed within flip_events
+            TestCase {
+                config: config(0, 3),
+                signals: vec![
+                    (1, 0, 0, Side::Buy),
+                    (2, 1, 0, Side::Sell),
+                    (4, 2, 0, Side::Sell),
+                ],
+                expected: vec![true, false, true],
+            },
+            // TC2: instruments are debounced independently
+            TestCase {
+                config: config(10, 3),
+                signals: vec![
+                    (1, 0, 0, Side::Buy),
+                    (1, 0, 1, Side::Buy),
+                    (2, 1, 1, Side::Sell),
+                ],
+                expected: vec![true, true, false],
+            },
+            // TC3: suppressed signals do not reset the cooldown
+            TestCase {
+                config: config(10, 0),
+                signals: vec![
+                    (1, 0, 0, Side::Buy),
+                    (2, 9, 0, Side::Buy),
+                    (3, 10, 0, Side::Buy),
+                    (4, 15, 0, Side::Buy),
+                ],
+                expected: vec![true, false, true, false],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut state = SignalDebounceState::new(test.config);
+
+            let actual = test
+                .signals
+                .into_iter()
+                .map(|(event, secs, instrument, side)| {
+                    while state.events < event {
+                        state.record_event();
+                    }
+                    state
+                        .try_accept(
+                            time_plus_secs(base, secs),
+                            InstrumentIndex(instrument),
+                            side,
+                        )
+                        .is_ok()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
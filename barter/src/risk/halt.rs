@@ -0,0 +1,486 @@
+use crate::{
+    engine::state::{EngineState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, check::util::is_position_reducing},
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use itertools::Either;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Event emitted by a [`RiskManager`] when it halts new entries, informing operators that
+/// algorithmic trading has paused, and why.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct RiskHalt {
+    /// Reason the [`RiskManager`] halted new entries.
+    pub reason: RiskHaltReason,
+
+    /// Time the halt was triggered.
+    pub time_halted: DateTime<Utc>,
+
+    /// Time new entries will be permitted again.
+    pub halted_until: DateTime<Utc>,
+}
+
+/// Reason a [`RiskHalt`] was triggered.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum RiskHaltReason {
+    /// Configured number of consecutive losing positions was reached.
+    ConsecutiveLosses { losses: usize },
+
+    /// Daily PnL loss exceeded the configured daily loss limit.
+    DailyLossLimit { pnl: Decimal },
+}
+
+/// Kill switch that halts new entries for a cooldown period after a configured number of
+/// consecutive losing [`PositionExited`]s.
+///
+/// A losing position is one with a negative `pnl_realised` (ie/ including fees). Any
+/// non-losing exit resets the consecutive loss count.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ConsecutiveLossHalt {
+    /// Number of consecutive losing positions that triggers a [`RiskHalt`].
+    pub max_losses: usize,
+
+    /// Duration new entries are halted for once triggered.
+    pub cooldown: TimeDelta,
+
+    /// Current number of consecutive losing positions.
+    pub losses: usize,
+
+    /// Time new entries will be permitted again, if currently (or previously) halted.
+    pub halted_until: Option<DateTime<Utc>>,
+}
+
+impl ConsecutiveLossHalt {
+    /// Construct a new `ConsecutiveLossHalt` that halts new entries for the `cooldown` after
+    /// `max_losses` consecutive losing positions.
+    pub fn new(max_losses: usize, cooldown: TimeDelta) -> Self {
+        Self {
+            max_losses,
+            cooldown,
+            losses: 0,
+            halted_until: None,
+        }
+    }
+
+    /// Returns true if new entries are halted at `time_now`.
+    pub fn is_halted(&self, time_now: DateTime<Utc>) -> bool {
+        self.halted_until
+            .is_some_and(|halted_until| time_now < halted_until)
+    }
+
+    /// Update the consecutive loss count from a [`PositionExited`].
+    ///
+    /// If the configured number of consecutive losses is reached, the count is reset and a
+    /// [`RiskHalt`] is returned.
+    pub fn update_from_position_exit<AssetKey, InstrumentKey>(
+        &mut self,
+        position: &PositionExited<AssetKey, InstrumentKey>,
+    ) -> Option<RiskHalt> {
+        if position.pnl_realised >= Decimal::ZERO {
+            self.losses = 0;
+            return None;
+        }
+
+        self.losses += 1;
+        if self.losses < self.max_losses {
+            return None;
+        }
+
+        let halt = RiskHalt {
+            reason: RiskHaltReason::ConsecutiveLosses {
+                losses: self.losses,
+            },
+            time_halted: position.time_exit,
+            halted_until: position.time_exit + self.cooldown,
+        };
+
+        warn!(
+            ?halt,
+            "RiskManager halting new entries after consecutive losses"
+        );
+
+        self.losses = 0;
+        self.halted_until = Some(halt.halted_until);
+
+        Some(halt)
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, refusing entry order requests while a
+/// [`ConsecutiveLossHalt`] is active.
+///
+/// Cancel requests, and open requests that only reduce an existing `Position`, are always
+/// forwarded to the inner `RiskManager`, so the `Engine` can still de-risk while halted.
+#[derive(Debug, Clone)]
+pub struct ConsecutiveLossRiskManager<Risk> {
+    pub inner: Risk,
+    pub halt: ConsecutiveLossHalt,
+}
+
+impl<Risk> ConsecutiveLossRiskManager<Risk> {
+    /// Construct a new `ConsecutiveLossRiskManager` wrapping the provided inner `RiskManager`.
+    pub fn new(inner: Risk, max_losses: usize, cooldown: TimeDelta) -> Self {
+        Self {
+            inner,
+            halt: ConsecutiveLossHalt::new(max_losses, cooldown),
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for ConsecutiveLossRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let opens = opens.into_iter();
+
+        let (opens, refused_halted) = if self.halt.is_halted(state.time_engine_now) {
+            let (exits, entries): (Vec<_>, Vec<_>) = opens.partition(|open| {
+                is_position_reducing(
+                    state
+                        .instruments
+                        .instrument_index(&open.key.instrument)
+                        .position
+                        .current
+                        .as_ref(),
+                    open.state.side,
+                    open.state.quantity,
+                )
+            });
+
+            let refused = entries
+                .into_iter()
+                .map(|open| RiskRefused::new(open, "RiskHalt active: consecutive losses"))
+                .collect::<Vec<_>>();
+
+            (Either::Left(exits.into_iter()), refused)
+        } else {
+            (Either::Right(opens), Vec::new())
+        };
+
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(refused_halted),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        let mut halts = self.inner.update_from_position_exit(position);
+        halts.extend(self.halt.update_from_position_exit(position));
+        halts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            position::Position,
+        },
+        risk::DefaultRiskManager,
+        test_utils::time_plus_secs,
+    };
+    use barter_execution::{
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, OrderId, StrategyId},
+            request::RequestOpen,
+        },
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, Underlying, exchange::ExchangeId, index::IndexedInstruments, instrument::Instrument,
+    };
+    use rust_decimal_macros::dec;
+
+    fn position_exited(
+        pnl_realised: Decimal,
+        time_exit: DateTime<Utc>,
+    ) -> PositionExited<QuoteAsset> {
+        PositionExited {
+            instrument: InstrumentIndex(0),
+            side: Side::Buy,
+            price_entry_average: dec!(100.0),
+            quantity_abs_max: dec!(1.0),
+            pnl_realised,
+            excursion_adverse_max: dec!(0.0),
+            excursion_favourable_max: dec!(0.0),
+            fees_enter: AssetFees::quote_fees(dec!(0.0)),
+            fees_exit: AssetFees::quote_fees(dec!(0.0)),
+            time_enter: DateTime::<Utc>::MIN_UTC,
+            time_exit,
+            trades: vec![],
+            signal: None,
+        }
+    }
+
+    #[test]
+    fn test_consecutive_loss_halt_update_from_position_exit() {
+        struct TestCase {
+            pnls: Vec<Decimal>,
+            expected_halts: Vec<bool>,
+        }
+
+        let cases = vec![
+            // TC0: max losses not reached
+            TestCase {
+                pnls: vec![dec!(-1.0), dec!(-1.0)],
+                expected_halts: vec![false, false],
+            },
+            // TC1: max losses reached
+            TestCase {
+                pnls: vec![dec!(-1.0), dec!(-1.0), dec!(-1.0)],
+                expected_halts: vec![false, false, true],
+            },
+            // TC2: winning position resets consecutive losses
+            TestCase {
+                pnls: vec![dec!(-1.0), dec!(-1.0), dec!(1.0), dec!(-1.0), dec!(-1.0)],
+                expected_halts: vec![false, false, false, false, false],
+            },
+            // TC3: consecutive losses reset after a halt
+            TestCase {
+                pnls: vec![
+                    dec!(-1.0),
+                    dec!(-1.0),
+                    dec!(-1.0),
+                    dec!(-1.0),
+                    dec!(-1.0),
+                    dec!(-1.0),
+                ],
+                expected_halts: vec![false, false, true, false, false, true],
+            },
+            // TC4: breakeven position is not a loss
+            TestCase {
+                pnls: vec![dec!(-1.0), dec!(-1.0), dec!(0.0)],
+                expected_halts: vec![false, false, false],
+            },
+        ];
+
+        let base = DateTime::<Utc>::MIN_UTC;
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut halt = ConsecutiveLossHalt::new(3, TimeDelta::seconds(60));
+
+            let actual = test
+                .pnls
+                .into_iter()
+                .map(|pnl| {
+                    halt.update_from_position_exit(&position_exited(pnl, base))
+                        .is_some()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected_halts, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_consecutive_loss_halt_is_halted() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut halt = ConsecutiveLossHalt::new(1, TimeDelta::seconds(60));
+
+        assert!(!halt.is_halted(base));
+
+        let output = halt
+            .update_from_position_exit(&position_exited(dec!(-1.0), base))
+            .unwrap();
+
+        assert_eq!(
+            output,
+            RiskHalt {
+                reason: RiskHaltReason::ConsecutiveLosses { losses: 1 },
+                time_halted: base,
+                halted_until: time_plus_secs(base, 60),
+            }
+        );
+        assert!(halt.is_halted(base));
+        assert!(halt.is_halted(time_plus_secs(base, 59)));
+        assert!(!halt.is_halted(time_plus_secs(base, 60)));
+    }
+
+    fn state(
+        position: Option<Side>,
+    ) -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let mut state = EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .position
+            .current = position.map(|side| {
+            Position::from(&Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                cid: None,
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side,
+                price: dec!(100),
+                quantity: dec!(1),
+                liquidity: None,
+                fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+            })
+        });
+
+        state
+    }
+
+    fn open(side: Side) -> OrderRequestOpen<ExchangeIndex, InstrumentIndex> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_consecutive_loss_risk_manager_check() {
+        struct TestCase {
+            halted: bool,
+            position: Option<Side>,
+            open: Side,
+            expected_approved: bool,
+        }
+
+        let cases = vec![
+            // TC0: entry approved while not halted
+            TestCase {
+                halted: false,
+                position: None,
+                open: Side::Buy,
+                expected_approved: true,
+            },
+            // TC1: entry refused while halted
+            TestCase {
+                halted: true,
+                position: None,
+                open: Side::Buy,
+                expected_approved: false,
+            },
+            // TC2: position reducing exit approved while halted
+            TestCase {
+                halted: true,
+                position: Some(Side::Buy),
+                open: Side::Sell,
+                expected_approved: true,
+            },
+            // TC3: increasing an existing Position refused while halted
+            TestCase {
+                halted: true,
+                position: Some(Side::Buy),
+                open: Side::Buy,
+                expected_approved: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut risk = ConsecutiveLossRiskManager::new(
+                DefaultRiskManager::default(),
+                1,
+                TimeDelta::seconds(60),
+            );
+            if test.halted {
+                risk.halt.halted_until = Some(time_plus_secs(DateTime::<Utc>::MIN_UTC, 60));
+            }
+
+            let state = state(test.position);
+            let (_, approved, _, refused) = risk.check(&state, [], [open(test.open)]);
+
+            let approved = approved.into_iter().count();
+            let refused = refused.into_iter().count();
+            assert_eq!(
+                (approved == 1, refused == 1),
+                (test.expected_approved, !test.expected_approved),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_consecutive_loss_risk_manager_update_from_position_exit() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut risk = ConsecutiveLossRiskManager::new(
+            ConsecutiveLossRiskManager::new(
+                DefaultRiskManager::<EngineState<DefaultGlobalData, DefaultInstrumentMarketData>>::default(),
+                1,
+                TimeDelta::seconds(30),
+            ),
+            2,
+            TimeDelta::seconds(60),
+        );
+
+        // Inner halt is returned when only the inner RiskManager halts
+        let halts = risk.update_from_position_exit(&position_exited(dec!(-1.0), base));
+        assert_eq!(
+            halts
+                .iter()
+                .map(|halt| halt.halted_until)
+                .collect::<Vec<_>>(),
+            vec![time_plus_secs(base, 30)]
+        );
+
+        // Both halts are returned when the inner & outer RiskManagers halt together
+        let halts = risk.update_from_position_exit(&position_exited(dec!(-1.0), base));
+        assert_eq!(
+            halts
+                .iter()
+                .map(|halt| halt.halted_until)
+                .collect::<Vec<_>>(),
+            vec![time_plus_secs(base, 30), time_plus_secs(base, 60)]
+        );
+    }
+}
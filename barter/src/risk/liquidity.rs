@@ -0,0 +1,379 @@
+use crate::{
+    engine::state::{EngineState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, halt::RiskHalt},
+};
+use barter_data::books::{Level, OrderBook};
+use barter_execution::order::{
+    id::ClientOrderId,
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{
+    Side, asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex,
+};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Instrument data that provides a level 2 [`OrderBook`], as required by the
+/// [`LiquidityRiskManager`].
+pub trait OrderBookDepth {
+    /// Latest L2 [`OrderBook`], if available.
+    fn order_book(&self) -> Option<&OrderBook>;
+}
+
+/// Action taken by the [`LiquidityRiskManager`] when an order exceeds the available liquidity.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum LiquidityAction {
+    /// Refuse the entire order.
+    #[default]
+    Refuse,
+
+    /// Approve a child order for the maximum permitted quantity, and refuse the remainder.
+    Split,
+}
+
+/// Configuration of the [`LiquidityRiskManager`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct LiquidityConfig {
+    /// Number of [`OrderBook`] levels considered top-of-book liquidity.
+    pub depth: usize,
+
+    /// Maximum fraction of the top-of-book liquidity an order may consume (eg/ 0.1 for 10%).
+    pub max_fraction: Decimal,
+
+    /// Action taken when an order exceeds the `max_fraction`.
+    pub action: LiquidityAction,
+}
+
+/// Estimated market impact of filling an order against [`OrderBook`] depth.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct MarketImpact {
+    /// Volume-weighted average fill price.
+    pub price_average: Decimal,
+
+    /// Adverse difference between the average fill price and the best price, as a fraction of
+    /// the best price (eg/ 0.002 for 20 bps).
+    pub slippage: Decimal,
+
+    /// Quantity that could not be filled by the available depth.
+    pub quantity_unfilled: Decimal,
+}
+
+/// [`OrderBook`] levels an order of the provided [`Side`] would consume (ie/ asks for a buy), best
+/// price first.
+pub fn consumed_levels(book: &OrderBook, side: Side) -> &[Level] {
+    match side {
+        Side::Buy => book.asks().levels(),
+        Side::Sell => book.bids().levels(),
+    }
+}
+
+/// Total quantity available within the best `depth` levels an order of the provided [`Side`]
+/// would consume.
+pub fn available_liquidity(book: &OrderBook, side: Side, depth: usize) -> Decimal {
+    consumed_levels(book, side)
+        .iter()
+        .take(depth)
+        .map(|level| level.amount)
+        .sum()
+}
+
+/// Estimate the [`MarketImpact`] of an order of the provided [`Side`] and quantity filling
+/// against the [`OrderBook`] depth.
+///
+/// Returns `None` if the consumed side of the [`OrderBook`] is empty.
+pub fn estimate_market_impact(
+    book: &OrderBook,
+    side: Side,
+    quantity: Decimal,
+) -> Option<MarketImpact> {
+    let levels = consumed_levels(book, side);
+    let price_best = levels.first()?.price;
+
+    let mut notional = Decimal::ZERO;
+    let mut quantity_unfilled = quantity;
+    for level in levels {
+        let filled = quantity_unfilled.min(level.amount);
+        notional += filled * level.price;
+        quantity_unfilled -= filled;
+    }
+
+    let quantity_filled = quantity - quantity_unfilled;
+    if quantity_filled.is_zero() || price_best.is_zero() {
+        return None;
+    }
+
+    let price_average = notional / quantity_filled;
+    let slippage = match side {
+        Side::Buy => (price_average - price_best) / price_best,
+        Side::Sell => (price_best - price_average) / price_best,
+    };
+
+    Some(MarketImpact {
+        price_average,
+        slippage,
+        quantity_unfilled,
+    })
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, checking the open order requests it
+/// approves against the current L2 [`OrderBook`] depth.
+///
+/// Orders that would consume more than the configured fraction of top-of-book liquidity are
+/// refused, or split into an approved child order and a refused remainder (see
+/// [`LiquidityAction`]). Orders for instruments without an L2 [`OrderBook`] are not checked.
+#[derive(Debug, Clone, Constructor)]
+pub struct LiquidityRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: LiquidityConfig,
+}
+
+impl<Risk> LiquidityRiskManager<Risk> {
+    /// Check an approved open order request against the provided [`OrderBook`], returning the
+    /// approved order (if any) and the refused order (if any).
+    pub fn check_open(
+        &self,
+        book: &OrderBook,
+        open: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> (
+        Option<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Option<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let liquidity = available_liquidity(book, open.state.side, self.config.depth);
+        let quantity_max = liquidity * self.config.max_fraction;
+
+        if open.state.quantity <= quantity_max {
+            return (Some(RiskApproved::new(open)), None);
+        }
+
+        let reason = format!(
+            "LiquidityRiskManager order quantity {} exceeds {} of top-of-book liquidity {}{}",
+            open.state.quantity,
+            self.config.max_fraction,
+            liquidity,
+            estimate_market_impact(book, open.state.side, open.state.quantity)
+                .map(|impact| format!(" (estimated slippage {})", impact.slippage.round_dp(6)))
+                .unwrap_or_default(),
+        );
+
+        match self.config.action {
+            LiquidityAction::Split if quantity_max > Decimal::ZERO => {
+                let mut child = open.clone();
+                child.state.quantity = quantity_max;
+
+                let mut remainder = open;
+                remainder.key.cid = ClientOrderId::random();
+                remainder.state.quantity -= quantity_max;
+
+                (
+                    Some(RiskApproved::new(child)),
+                    Some(RiskRefused::new(remainder, reason)),
+                )
+            }
+            _ => (None, Some(RiskRefused::new(open, reason))),
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for LiquidityRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: OrderBookDepth,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let mut illiquid_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                let book = state
+                    .instruments
+                    .instrument_index(&open.0.key.instrument)
+                    .data
+                    .order_book();
+
+                let Some(book) = book else {
+                    return Some(open);
+                };
+
+                let (approved, refused) = self.check_open(book, open.into_item());
+                illiquid_opens.extend(refused);
+                approved
+            })
+            .collect::<Vec<_>>();
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(illiquid_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::DefaultRiskManager;
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce, id::StrategyId, request::RequestOpen,
+    };
+    use rust_decimal_macros::dec;
+
+    fn book() -> OrderBook {
+        OrderBook::new(
+            0,
+            None,
+            [Level::new(dec!(99), dec!(1)), Level::new(dec!(98), dec!(2))],
+            [
+                Level::new(dec!(101), dec!(1)),
+                Level::new(dec!(102), dec!(3)),
+            ],
+        )
+    }
+
+    fn open(side: Side, quantity: Decimal) -> OrderRequestOpen<ExchangeIndex, InstrumentIndex> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(100),
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_estimate_market_impact() {
+        struct TestCase {
+            side: Side,
+            quantity: Decimal,
+            expected: Option<MarketImpact>,
+        }
+
+        let cases = vec![
+            // TC0: buy filled entirely at the best ask
+            TestCase {
+                side: Side::Buy,
+                quantity: dec!(1),
+                expected: Some(MarketImpact {
+                    price_average: dec!(101),
+                    slippage: dec!(0),
+                    quantity_unfilled: dec!(0),
+                }),
+            },
+            // TC1: buy walks the book
+            TestCase {
+                side: Side::Buy,
+                quantity: dec!(2),
+                expected: Some(MarketImpact {
+                    price_average: dec!(101.5),
+                    slippage: dec!(0.5) / dec!(101),
+                    quantity_unfilled: dec!(0),
+                }),
+            },
+            // TC2: sell exceeds the available depth
+            TestCase {
+                side: Side::Sell,
+                quantity: dec!(4),
+                expected: Some(MarketImpact {
+                    price_average: dec!(295) / dec!(3),
+                    slippage: (dec!(99) - dec!(295) / dec!(3)) / dec!(99),
+                    quantity_unfilled: dec!(1),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = estimate_market_impact(&book(), test.side, test.quantity);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_liquidity_risk_manager_check_open() {
+        struct TestCase {
+            action: LiquidityAction,
+            open: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+            expected_approved: Option<Decimal>,
+            expected_refused: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: order within the permitted fraction of liquidity is approved
+            TestCase {
+                action: LiquidityAction::Refuse,
+                open: open(Side::Buy, dec!(2)),
+                expected_approved: Some(dec!(2)),
+                expected_refused: None,
+            },
+            // TC1: order exceeding the permitted fraction of liquidity is refused
+            TestCase {
+                action: LiquidityAction::Refuse,
+                open: open(Side::Buy, dec!(3)),
+                expected_approved: None,
+                expected_refused: Some(dec!(3)),
+            },
+            // TC2: order exceeding the permitted fraction of liquidity is split
+            TestCase {
+                action: LiquidityAction::Split,
+                open: open(Side::Sell, dec!(2)),
+                expected_approved: Some(dec!(1.5)),
+                expected_refused: Some(dec!(0.5)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let risk = LiquidityRiskManager::new(
+                DefaultRiskManager::<()>::default(),
+                LiquidityConfig::new(2, dec!(0.5), test.action),
+            );
+
+            let (approved, refused) = risk.check_open(&book(), test.open);
+
+            assert_eq!(
+                approved.map(|open| open.0.state.quantity),
+                test.expected_approved,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                refused.map(|open| open.item.state.quantity),
+                test.expected_refused,
+                "TC{index} failed"
+            );
+        }
+    }
+}
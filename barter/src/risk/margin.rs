@@ -0,0 +1,322 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{InstrumentState, filter::InstrumentFilter},
+        position::PositionExited,
+    },
+    risk::{
+        RiskApproved, RiskManager, RiskRefused, check::util::is_position_reducing, halt::RiskHalt,
+    },
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{
+    asset::{AssetIndex, QuoteAsset},
+    exchange::ExchangeIndex,
+    instrument::InstrumentIndex,
+};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the [`MarginRiskManager`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct MarginConfig {
+    /// Maximum projected margin utilisation (ie/ margin used / equity) an order may result in
+    /// (eg/ 0.5 for 50%).
+    pub max_utilisation: Decimal,
+}
+
+/// Margin used and equity of a collateral asset, across all leveraged instruments quoted in it.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct MarginUsage {
+    /// Margin required by all open `Position`s and active orders.
+    pub used: Decimal,
+
+    /// Collateral balance plus the unrealised PnL of all open `Position`s.
+    pub equity: Decimal,
+}
+
+impl MarginUsage {
+    /// Calculate the [`MarginUsage`] of the provided collateral asset from the [`EngineState`].
+    ///
+    /// Only instruments quoted in the collateral asset with a
+    /// [`MarginRequirement`](crate::engine::state::position::MarginRequirement) are considered.
+    /// Active orders that only reduce the current `Position` do not require margin.
+    pub fn calculate<GlobalData, InstrumentData>(
+        state: &EngineState<GlobalData, InstrumentData>,
+        collateral: AssetIndex,
+    ) -> Self {
+        let balance = state
+            .assets
+            .asset_index(&collateral)
+            .balance
+            .as_ref()
+            .map(|balance| balance.value.total)
+            .unwrap_or_default();
+
+        state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .filter(|state| state.instrument.underlying.quote == collateral)
+            .fold(
+                Self {
+                    used: Decimal::ZERO,
+                    equity: balance,
+                },
+                |usage, state| {
+                    let Some(margin) = state.margin else {
+                        return usage;
+                    };
+
+                    let contract_size = state.instrument.kind.contract_size();
+                    let position = state.position.current.as_ref();
+
+                    let margin_position = position
+                        .and_then(|position| {
+                            margin.margin(
+                                position.quantity_abs
+                                    * position.price_entry_average
+                                    * contract_size,
+                            )
+                        })
+                        .unwrap_or_default();
+
+                    let margin_orders = state
+                        .orders
+                        .0
+                        .values()
+                        .filter(|order| !is_position_reducing(position, order.side, order.quantity))
+                        .filter_map(|order| {
+                            margin.margin(order.quantity * order.price * contract_size)
+                        })
+                        .sum::<Decimal>();
+
+                    Self {
+                        used: usage.used + margin_position + margin_orders,
+                        equity: usage.equity
+                            + position
+                                .map(|position| position.pnl_unrealised)
+                                .unwrap_or_default(),
+                    }
+                },
+            )
+    }
+
+    /// Calculate the projected margin utilisation after using the additional margin.
+    ///
+    /// Returns `None` if the equity is not positive.
+    pub fn utilisation(&self, margin_additional: Decimal) -> Option<Decimal> {
+        (self.equity > Decimal::ZERO).then(|| (self.used + margin_additional) / self.equity)
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, checking the open order requests it
+/// approves against the projected margin utilisation of the instrument collateral (quote) asset.
+///
+/// Orders that would push the margin utilisation above the configured
+/// [`MarginConfig::max_utilisation`] are refused, preventing over-levered entries. Orders for
+/// instruments without a [`MarginRequirement`](crate::engine::state::position::MarginRequirement),
+/// and orders that only reduce the current `Position`, are not checked. Orders for instruments
+/// with an invalid (ie/ non-positive leverage) `MarginRequirement` are always refused.
+#[derive(Debug, Clone, Constructor)]
+pub struct MarginRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: MarginConfig,
+}
+
+impl<Risk> MarginRiskManager<Risk> {
+    /// Check if an order requiring the provided margin can be approved given the current
+    /// [`MarginUsage`], returning the refusal reason if not.
+    ///
+    /// If approved, the order margin is added to the `MarginUsage`, so subsequent orders are
+    /// checked against the projected utilisation.
+    pub fn check_open(&self, usage: &mut MarginUsage, margin_order: Decimal) -> Result<(), String> {
+        match usage.utilisation(margin_order) {
+            Some(utilisation) if utilisation <= self.config.max_utilisation => {
+                usage.used += margin_order;
+                Ok(())
+            }
+            Some(utilisation) => Err(format!(
+                "MarginRiskManager projected margin utilisation {} exceeds maximum {}",
+                utilisation.round_dp(6),
+                self.config.max_utilisation
+            )),
+            None => Err(format!(
+                "MarginRiskManager refused order since equity {} is not positive",
+                usage.equity
+            )),
+        }
+    }
+
+    fn requires_check<InstrumentData>(
+        state: &InstrumentState<InstrumentData>,
+        open: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> bool {
+        !open.state.reduce_only
+            && !is_position_reducing(
+                state.position.current.as_ref(),
+                open.state.side,
+                open.state.quantity,
+            )
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for MarginRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let mut usages = FnvHashMap::<AssetIndex, MarginUsage>::default();
+        let mut over_levered_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                let instrument = state.instruments.instrument_index(&open.0.key.instrument);
+
+                let Some(margin) = instrument
+                    .margin
+                    .filter(|_| Self::requires_check(instrument, &open.0))
+                else {
+                    return Some(open);
+                };
+
+                let collateral = instrument.instrument.underlying.quote;
+                let usage = usages
+                    .entry(collateral)
+                    .or_insert_with(|| MarginUsage::calculate(state, collateral));
+
+                let margin_order = margin
+                    .margin(
+                        open.0.state.quantity
+                            * open.0.state.price
+                            * instrument.instrument.kind.contract_size(),
+                    )
+                    .ok_or_else(|| {
+                        format!(
+                            "MarginRiskManager refused order since MarginRequirement leverage {} \
+                             is not positive",
+                            margin.leverage
+                        )
+                    });
+
+                match margin_order.and_then(|margin_order| self.check_open(usage, margin_order)) {
+                    Ok(()) => Some(open),
+                    Err(reason) => {
+                        over_levered_opens.push(RiskRefused::new(open.into_item(), reason));
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(over_levered_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::DefaultRiskManager;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_margin_risk_manager_check_open() {
+        struct TestCase {
+            usage: MarginUsage,
+            margin_orders: Vec<Decimal>,
+            expected: Vec<bool>,
+            expected_used: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: order within the maximum utilisation is approved
+            TestCase {
+                usage: MarginUsage {
+                    used: dec!(100),
+                    equity: dec!(1000),
+                },
+                margin_orders: vec![dec!(400)],
+                expected: vec![true],
+                expected_used: dec!(500),
+            },
+            // TC1: order exceeding the maximum utilisation is refused
+            TestCase {
+                usage: MarginUsage {
+                    used: dec!(100),
+                    equity: dec!(1000),
+                },
+                margin_orders: vec![dec!(401)],
+                expected: vec![false],
+                expected_used: dec!(100),
+            },
+            // TC2: approved orders count towards the projected utilisation of later orders
+            TestCase {
+                usage: MarginUsage {
+                    used: dec!(0),
+                    equity: dec!(1000),
+                },
+                margin_orders: vec![dec!(300), dec!(300), dec!(200)],
+                expected: vec![true, false, true],
+                expected_used: dec!(500),
+            },
+            // TC3: non-positive equity refuses all orders
+            TestCase {
+                usage: MarginUsage {
+                    used: dec!(0),
+                    equity: dec!(-10),
+                },
+                margin_orders: vec![dec!(1)],
+                expected: vec![false],
+                expected_used: dec!(0),
+            },
+        ];
+
+        let risk = MarginRiskManager::new(
+            DefaultRiskManager::<()>::default(),
+            MarginConfig::new(dec!(0.5)),
+        );
+
+        for (index, mut test) in cases.into_iter().enumerate() {
+            let actual = test
+                .margin_orders
+                .into_iter()
+                .map(|margin_order| risk.check_open(&mut test.usage, margin_order).is_ok())
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(test.usage.used, test.expected_used, "TC{index} failed");
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use crate::{engine::state::position::PositionExited, risk::halt::RiskHalt};
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
-use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::Unrecoverable;
 use derive_more::{Constructor, Display, From};
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,54 @@ use std::{fmt::Debug, hash::Hash, marker::PhantomData};
 /// RiskManager checks and utilities.
 pub mod check;
 
+/// Kill switch `RiskManager` utilities that halt new entries and emit a [`RiskHalt`] event.
+///
+/// eg/ `ConsecutiveLossRiskManager`.
+pub mod halt;
+
+/// Daily loss limit `RiskManager` that blocks new entries (and optionally force-exits all
+/// positions) once daily PnL losses exceed a configurable threshold.
+pub mod daily_loss;
+
+/// Order throttling `RiskManager` that rate limits generated orders globally and per-instrument.
+pub mod throttle;
+
+/// Signal debounce `RiskManager` that suppresses repeated same-direction orders within a cooldown,
+/// and flip-flopping orders within a number of algo order generations.
+pub mod debounce;
+
+/// Liquidity-aware `RiskManager` that refuses or splits orders that would consume too much of the
+/// L2 top-of-book liquidity.
+pub mod liquidity;
+
+/// Spread guard `RiskManager` that defers market orders, or converts them to limit orders, when
+/// the bid-ask spread exceeds a configurable threshold.
+pub mod spread;
+
+/// Margin usage `RiskManager` that refuses orders that would push the projected margin
+/// utilisation of leveraged instruments above a configurable ceiling.
+pub mod margin;
+
+/// Trading calendar `RiskManager` that suppresses entry orders outside of configured sessions
+/// (eg/ weekdays, time-of-day windows, maintenance blackouts).
+pub mod calendar;
+
+/// Cross-exchange net delta `RiskManager` that nets positions of the same underlying asset across
+/// venues, and refuses orders that would push the net delta above a configurable limit.
+pub mod net_delta;
+
+/// Portfolio Value at Risk `RiskManager` that refuses orders that would push the projected
+/// historical or parametric VaR above a configurable limit.
+pub mod value_at_risk;
+
+/// Correlation-aware concentration `RiskManager` that limits the aggregate exposure to clusters
+/// of highly correlated instruments (eg/ all L1 tokens), rather than only per-instrument exposure.
+pub mod correlation;
+
+/// Volatility halt `RiskManager` that halts entries (and optionally tightens stops) when the
+/// short-horizon realised volatility or price range exceeds a multiple of its rolling baseline.
+pub mod volatility;
+
 /// RiskManager interface that reviews and optionally filters cancel and open order requests
 /// generated by an [`AlgoStrategy`](super::strategy::algo::AlgoStrategy).
 ///
@@ -35,6 +84,19 @@ pub trait RiskManager<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentInd
         impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeKey, InstrumentKey>>>,
         impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
     );
+
+    /// Update any internal `RiskManager` state from a [`PositionExited`].
+    ///
+    /// Returns every [`RiskHalt`] triggered as a result, including those of any wrapped inner
+    /// `RiskManager`s.
+    ///
+    /// Default implementation is a no-op for stateless `RiskManager`s.
+    fn update_from_position_exit(
+        &mut self,
+        _: &PositionExited<QuoteAsset, InstrumentKey>,
+    ) -> Vec<RiskHalt> {
+        Vec::new()
+    }
 }
 
 /// New type that wraps [`Order`] requests that have passed [`RiskManager`] checks.
@@ -96,7 +158,8 @@ where
 /// risk checks*.
 ///
 /// *THIS IS FOR DEMONSTRATION PURPOSES ONLY, NEVER USE FOR REAL TRADING OR IN PRODUCTION*.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound = "")]
 pub struct DefaultRiskManager<State> {
     phantom: PhantomData<State>,
 }
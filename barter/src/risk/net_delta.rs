@@ -0,0 +1,298 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{InstrumentState, filter::InstrumentFilter},
+        position::PositionExited,
+    },
+    risk::{RiskApproved, RiskManager, RiskRefused, check::util::calculate_delta, halt::RiskHalt},
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{
+    asset::{QuoteAsset, name::AssetNameInternal},
+    exchange::ExchangeIndex,
+    instrument::{InstrumentIndex, kind::InstrumentKind},
+};
+use barter_integration::collection::FnvIndexMap;
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Net delta of each underlying base asset, netted across every instrument and exchange.
+///
+/// eg/ LONG 1 "btc" on Binance spot and SHORT 0.6 "btc_usdt" perpetual on Bybit nets to a
+/// "btc" delta of 0.4.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct NetDeltas(pub FnvIndexMap<AssetNameInternal, Decimal>);
+
+impl NetDeltas {
+    /// Calculate the [`NetDeltas`] of all open positions in the [`EngineState`].
+    ///
+    /// Option positions are excluded, since the `Engine` does not track option greeks.
+    pub fn calculate<GlobalData, InstrumentData>(
+        state: &EngineState<GlobalData, InstrumentData>,
+    ) -> Self {
+        Self::from_deltas(
+            state
+                .instruments
+                .instruments(&InstrumentFilter::None)
+                .filter_map(|instrument_state| {
+                    let position = instrument_state.position.current.as_ref()?;
+                    let instrument_delta = instrument_delta(&instrument_state.instrument.kind)?;
+
+                    let delta = calculate_delta(
+                        instrument_delta,
+                        instrument_state.instrument.kind.contract_size(),
+                        position.side,
+                        position.quantity_abs,
+                    );
+
+                    Some((base_asset(state, instrument_state).clone(), delta))
+                }),
+        )
+    }
+
+    /// Net the provided per-asset deltas.
+    pub fn from_deltas<Iter>(deltas: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (AssetNameInternal, Decimal)>,
+    {
+        deltas
+            .into_iter()
+            .fold(Self::default(), |mut net, (asset, delta)| {
+                *net.0.entry(asset).or_default() += delta;
+                net
+            })
+    }
+
+    /// Return the net delta of the provided asset (zero if there is no exposure).
+    pub fn delta(&self, asset: &AssetNameInternal) -> Decimal {
+        self.0.get(asset).copied().unwrap_or_default()
+    }
+}
+
+fn instrument_delta<AssetKey>(kind: &InstrumentKind<AssetKey>) -> Option<Decimal> {
+    match kind {
+        InstrumentKind::Spot | InstrumentKind::Perpetual(_) | InstrumentKind::Future(_) => {
+            Some(Decimal::ONE)
+        }
+        InstrumentKind::Option(_) => None,
+    }
+}
+
+fn base_asset<'a, GlobalData, InstrumentData>(
+    state: &'a EngineState<GlobalData, InstrumentData>,
+    instrument_state: &InstrumentState<InstrumentData>,
+) -> &'a AssetNameInternal {
+    &state
+        .assets
+        .asset_index(&instrument_state.instrument.underlying.base)
+        .asset
+        .name_internal
+}
+
+/// Configuration of the [`NetDeltaRiskManager`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize, Constructor)]
+pub struct NetDeltaConfig {
+    /// Maximum absolute net delta of each underlying base asset (eg/ "btc" => 2.0).
+    ///
+    /// Assets without a limit are not checked.
+    pub max_abs_delta: FnvHashMap<AssetNameInternal, Decimal>,
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, checking the open order requests it
+/// approves against the projected cross-exchange [`NetDeltas`] of the instrument base asset.
+///
+/// Orders that would push the absolute net delta of an asset above the configured
+/// [`NetDeltaConfig::max_abs_delta`] are refused. Orders that reduce the absolute net delta
+/// (eg/ hedging a spot position with a perpetual on another exchange) are always approved.
+#[derive(Debug, Clone, Constructor)]
+pub struct NetDeltaRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: NetDeltaConfig,
+}
+
+impl<Risk> NetDeltaRiskManager<Risk> {
+    /// Check if an order with the provided delta can be approved given the current
+    /// [`NetDeltas`], returning the refusal reason if not.
+    ///
+    /// If approved, the order delta is added to the `NetDeltas`, so subsequent orders are
+    /// checked against the projected net delta.
+    pub fn check_open(
+        &self,
+        deltas: &mut NetDeltas,
+        asset: &AssetNameInternal,
+        order_delta: Decimal,
+    ) -> Result<(), String> {
+        let current = deltas.delta(asset);
+        let projected = current + order_delta;
+
+        match self.config.max_abs_delta.get(asset) {
+            Some(max) if projected.abs() > *max && projected.abs() > current.abs() => Err(format!(
+                "NetDeltaRiskManager projected {asset} net delta {projected} exceeds maximum {max}"
+            )),
+            _ => {
+                *deltas.0.entry(asset.clone()).or_default() = projected;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for NetDeltaRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let mut deltas = None;
+        let mut over_exposed_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                let instrument = state.instruments.instrument_index(&open.0.key.instrument);
+
+                let Some(instrument_delta) = instrument_delta(&instrument.instrument.kind) else {
+                    return Some(open);
+                };
+
+                let order_delta = calculate_delta(
+                    instrument_delta,
+                    instrument.instrument.kind.contract_size(),
+                    open.0.state.side,
+                    open.0.state.quantity,
+                );
+
+                let deltas = deltas.get_or_insert_with(|| NetDeltas::calculate(state));
+
+                match self.check_open(deltas, base_asset(state, instrument), order_delta) {
+                    Ok(()) => Some(open),
+                    Err(reason) => {
+                        over_exposed_opens.push(RiskRefused::new(open.into_item(), reason));
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(over_exposed_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::DefaultRiskManager;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_net_deltas_from_deltas() {
+        let btc = AssetNameInternal::new("btc");
+        let eth = AssetNameInternal::new("eth");
+
+        // LONG btc spot on one exchange, SHORT btc perpetual on another, LONG eth
+        let deltas = NetDeltas::from_deltas([
+            (btc.clone(), dec!(1.0)),
+            (btc.clone(), dec!(-0.6)),
+            (eth.clone(), dec!(2.0)),
+        ]);
+
+        assert_eq!(deltas.delta(&btc), dec!(0.4));
+        assert_eq!(deltas.delta(&eth), dec!(2.0));
+        assert_eq!(deltas.delta(&AssetNameInternal::new("sol")), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_net_delta_risk_manager_check_open() {
+        struct TestCase {
+            current: Decimal,
+            order_deltas: Vec<Decimal>,
+            expected: Vec<bool>,
+            expected_delta: Decimal,
+        }
+
+        let btc = AssetNameInternal::new("btc");
+
+        let cases = vec![
+            // TC0: order within the maximum net delta is approved
+            TestCase {
+                current: dec!(1.0),
+                order_deltas: vec![dec!(1.0)],
+                expected: vec![true],
+                expected_delta: dec!(2.0),
+            },
+            // TC1: order exceeding the maximum net delta is refused
+            TestCase {
+                current: dec!(1.0),
+                order_deltas: vec![dec!(1.5)],
+                expected: vec![false],
+                expected_delta: dec!(1.0),
+            },
+            // TC2: SHORT order exceeding the maximum absolute net delta is refused
+            TestCase {
+                current: dec!(-1.5),
+                order_deltas: vec![dec!(-1.0)],
+                expected: vec![false],
+                expected_delta: dec!(-1.5),
+            },
+            // TC3: hedging order reducing an over-limit net delta is approved
+            TestCase {
+                current: dec!(3.0),
+                order_deltas: vec![dec!(-0.5)],
+                expected: vec![true],
+                expected_delta: dec!(2.5),
+            },
+            // TC4: approved orders count towards the projected net delta of later orders
+            TestCase {
+                current: dec!(0.0),
+                order_deltas: vec![dec!(1.5), dec!(1.0), dec!(-1.0), dec!(1.0)],
+                expected: vec![true, false, true, true],
+                expected_delta: dec!(1.5),
+            },
+        ];
+
+        let risk = NetDeltaRiskManager::new(
+            DefaultRiskManager::<()>::default(),
+            NetDeltaConfig::new(FnvHashMap::from_iter([(btc.clone(), dec!(2.0))])),
+        );
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut deltas = NetDeltas::from_deltas([(btc.clone(), test.current)]);
+
+            let actual = test
+                .order_deltas
+                .into_iter()
+                .map(|order_delta| risk.check_open(&mut deltas, &btc, order_delta).is_ok())
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(deltas.delta(&btc), test.expected_delta, "TC{index} failed");
+        }
+    }
+}
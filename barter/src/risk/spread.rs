@@ -0,0 +1,300 @@
+use crate::{
+    engine::state::{EngineState, instrument::data::InstrumentDataState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, halt::RiskHalt},
+};
+use barter_data::subscription::book::OrderBookL1;
+use barter_execution::order::{
+    OrderKind, TimeInForce,
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Maximum bid-ask spread tolerated before a market order is guarded.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum SpreadThreshold {
+    /// Absolute spread in quote asset units (eg/ 5.0 USDT).
+    Absolute(Decimal),
+
+    /// Spread relative to the mid price, in basis points (eg/ 20 for 0.2%).
+    Bps(Decimal),
+}
+
+impl SpreadThreshold {
+    /// Returns true if the spread between the provided best bid & ask prices exceeds the
+    /// `SpreadThreshold`.
+    pub fn is_exceeded(&self, best_bid: Decimal, best_ask: Decimal) -> bool {
+        let spread = best_ask - best_bid;
+
+        match self {
+            Self::Absolute(threshold) => spread > *threshold,
+            Self::Bps(threshold) => {
+                let mid = (best_bid + best_ask) / Decimal::TWO;
+                mid <= Decimal::ZERO || spread / mid * Decimal::from(10_000) > *threshold
+            }
+        }
+    }
+}
+
+/// Action taken by the [`SpreadGuardRiskManager`] when a market order is guarded.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum SpreadAction {
+    /// Refuse the market order, deferring it to be regenerated once the spread normalises.
+    #[default]
+    Defer,
+
+    /// Convert the market order to a `GoodUntilCancelled` limit order at the mid price.
+    ConvertToLimit,
+}
+
+/// Configuration of the [`SpreadGuardRiskManager`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct SpreadGuardConfig {
+    pub threshold: SpreadThreshold,
+    pub action: SpreadAction,
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, guarding the market order requests it
+/// approves against paying excessive bid-ask spreads (eg/ during volatility spikes).
+///
+/// Market orders for instruments whose current [`OrderBookL1`] spread exceeds the
+/// [`SpreadThreshold`] (or that have a one-sided book) are deferred or converted to limit orders
+/// (see [`SpreadAction`]). Limit orders, and orders for instruments whose data does not provide
+/// an [`OrderBookL1`], are not checked.
+#[derive(Debug, Clone, Constructor)]
+pub struct SpreadGuardRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: SpreadGuardConfig,
+}
+
+impl<Risk> SpreadGuardRiskManager<Risk> {
+    /// Check an approved open order request against the provided [`OrderBookL1`].
+    ///
+    /// The [`RiskRefused`] is boxed to keep the `Result` small.
+    pub fn check_open(
+        &self,
+        l1: &OrderBookL1,
+        open: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> Result<
+        RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+        Box<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    > {
+        if open.state.kind != OrderKind::Market {
+            return Ok(RiskApproved::new(open));
+        }
+
+        let (Some(best_bid), Some(best_ask)) = (l1.best_bid, l1.best_ask) else {
+            return Err(Box::new(RiskRefused::new(
+                open,
+                "SpreadGuardRiskManager OrderBookL1 is one-sided",
+            )));
+        };
+
+        if !self
+            .config
+            .threshold
+            .is_exceeded(best_bid.price, best_ask.price)
+        {
+            return Ok(RiskApproved::new(open));
+        }
+
+        match self.config.action {
+            SpreadAction::Defer => Err(Box::new(RiskRefused::new(
+                open,
+                format!(
+                    "SpreadGuardRiskManager spread {} exceeds threshold {:?}",
+                    best_ask.price - best_bid.price,
+                    self.config.threshold
+                ),
+            ))),
+            SpreadAction::ConvertToLimit => {
+                let mut limit = open;
+                limit.state.kind = OrderKind::Limit;
+                limit.state.price = (best_bid.price + best_ask.price) / Decimal::TWO;
+                limit.state.time_in_force = TimeInForce::GoodUntilCancelled { post_only: false };
+                Ok(RiskApproved::new(limit))
+            }
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for SpreadGuardRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let mut guarded_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                let l1 = state
+                    .instruments
+                    .instrument_index(&open.0.key.instrument)
+                    .data
+                    .l1();
+
+                let Some(l1) = l1 else {
+                    return Some(open);
+                };
+
+                match self.check_open(l1, open.into_item()) {
+                    Ok(approved) => Some(approved),
+                    Err(refused) => {
+                        guarded_opens.push(*refused);
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(guarded_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::DefaultRiskManager;
+    use barter_data::books::Level;
+    use barter_execution::order::{
+        OrderKey,
+        id::{ClientOrderId, StrategyId},
+        request::RequestOpen,
+    };
+    use barter_instrument::Side;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_spread_threshold_is_exceeded() {
+        struct TestCase {
+            threshold: SpreadThreshold,
+            best_bid: Decimal,
+            best_ask: Decimal,
+            expected: bool,
+        }
+
+        let cases = vec![
+            // TC0: absolute spread within threshold
+            TestCase {
+                threshold: SpreadThreshold::Absolute(dec!(1)),
+                best_bid: dec!(100),
+                best_ask: dec!(101),
+                expected: false,
+            },
+            // TC1: absolute spread exceeds threshold
+            TestCase {
+                threshold: SpreadThreshold::Absolute(dec!(1)),
+                best_bid: dec!(100),
+                best_ask: dec!(102),
+                expected: true,
+            },
+            // TC2: bps spread within threshold
+            TestCase {
+                threshold: SpreadThreshold::Bps(dec!(20)),
+                best_bid: dec!(99.9),
+                best_ask: dec!(100.1),
+                expected: false,
+            },
+            // TC3: bps spread exceeds threshold
+            TestCase {
+                threshold: SpreadThreshold::Bps(dec!(20)),
+                best_bid: dec!(99),
+                best_ask: dec!(101),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.threshold.is_exceeded(test.best_bid, test.best_ask);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_spread_guard_risk_manager_check_open() {
+        let l1 = OrderBookL1 {
+            last_update_time: DateTime::<Utc>::MIN_UTC,
+            best_bid: Some(Level::new(dec!(99), dec!(1))),
+            best_ask: Some(Level::new(dec!(101), dec!(1))),
+        };
+
+        let open = |kind| OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(101),
+                quantity: dec!(1),
+                kind,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        };
+
+        let guard = |action| {
+            SpreadGuardRiskManager::new(
+                DefaultRiskManager::<()>::default(),
+                SpreadGuardConfig::new(SpreadThreshold::Absolute(dec!(1)), action),
+            )
+        };
+
+        // Market order deferred
+        let actual = guard(SpreadAction::Defer).check_open(&l1, open(OrderKind::Market));
+        assert!(actual.is_err());
+
+        // Limit order not checked
+        let actual = guard(SpreadAction::Defer).check_open(&l1, open(OrderKind::Limit));
+        assert_eq!(actual, Ok(RiskApproved::new(open(OrderKind::Limit))));
+
+        // Market order converted to a limit order at the mid price
+        let actual = guard(SpreadAction::ConvertToLimit)
+            .check_open(&l1, open(OrderKind::Market))
+            .unwrap()
+            .into_item();
+        assert_eq!(actual.state.kind, OrderKind::Limit);
+        assert_eq!(actual.state.price, dec!(100));
+        assert_eq!(
+            actual.state.time_in_force,
+            TimeInForce::GoodUntilCancelled { post_only: false }
+        );
+    }
+}
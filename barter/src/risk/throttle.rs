@@ -0,0 +1,357 @@
+use crate::{
+    engine::state::{EngineState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, halt::RiskHalt},
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use std::collections::VecDeque;
+
+/// Rate limit configuration that permits at most `max_orders` within any rolling `interval`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct OrderThrottle {
+    /// Maximum number of orders permitted within the `interval`.
+    pub max_orders: usize,
+
+    /// Rolling interval the `max_orders` applies to.
+    pub interval: TimeDelta,
+}
+
+/// Rolling window of order request times that enforces an [`OrderThrottle`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ThrottleWindow {
+    pub throttle: OrderThrottle,
+    pub times: VecDeque<DateTime<Utc>>,
+}
+
+impl ThrottleWindow {
+    /// Construct a new empty `ThrottleWindow` for the provided [`OrderThrottle`].
+    pub fn new(throttle: OrderThrottle) -> Self {
+        Self {
+            throttle,
+            times: VecDeque::with_capacity(throttle.max_orders),
+        }
+    }
+
+    /// Returns true if another order can be sent at `time_now` without breaching the
+    /// [`OrderThrottle`].
+    ///
+    /// Order times that have fallen outside the rolling interval are removed.
+    pub fn has_capacity(&mut self, time_now: DateTime<Utc>) -> bool {
+        while self
+            .times
+            .front()
+            .is_some_and(|time| time_now - *time >= self.throttle.interval)
+        {
+            self.times.pop_front();
+        }
+
+        self.times.len() < self.throttle.max_orders
+    }
+
+    /// Record an order sent at `time_now`.
+    pub fn record(&mut self, time_now: DateTime<Utc>) {
+        self.times.push_back(time_now);
+    }
+}
+
+/// Global and per-instrument [`ThrottleWindow`]s used by the [`OrderThrottleRiskManager`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OrderThrottleState {
+    /// Optional global [`ThrottleWindow`] shared by all instruments.
+    pub global: Option<ThrottleWindow>,
+
+    /// Optional [`OrderThrottle`] applied to each instrument independently.
+    pub instrument: Option<OrderThrottle>,
+
+    /// Per-instrument [`ThrottleWindow`]s, lazily initialised.
+    pub instruments: FnvHashMap<InstrumentIndex, ThrottleWindow>,
+}
+
+impl OrderThrottleState {
+    /// Construct a new `OrderThrottleState` with optional global and per-instrument
+    /// [`OrderThrottle`]s.
+    pub fn new(global: Option<OrderThrottle>, instrument: Option<OrderThrottle>) -> Self {
+        Self {
+            global: global.map(ThrottleWindow::new),
+            instrument,
+            instruments: FnvHashMap::default(),
+        }
+    }
+
+    /// Attempt to acquire capacity to send an order for the provided instrument at `time_now`.
+    ///
+    /// Returns true (and records the order) if neither the global or instrument
+    /// [`OrderThrottle`] would be breached.
+    pub fn try_acquire(&mut self, time_now: DateTime<Utc>, instrument: InstrumentIndex) -> bool {
+        let mut instrument_window = self.instrument.map(|throttle| {
+            self.instruments
+                .entry(instrument)
+                .or_insert_with(|| ThrottleWindow::new(throttle))
+        });
+
+        let has_capacity_global = self
+            .global
+            .as_mut()
+            .is_none_or(|window| window.has_capacity(time_now));
+
+        let has_capacity_instrument = instrument_window
+            .as_mut()
+            .is_none_or(|window| window.has_capacity(time_now));
+
+        if !(has_capacity_global && has_capacity_instrument) {
+            return false;
+        }
+
+        if let Some(window) = self.global.as_mut() {
+            window.record(time_now);
+        }
+        if let Some(window) = instrument_window {
+            window.record(time_now);
+        }
+
+        true
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, rate limiting the open order requests it
+/// approves.
+///
+/// Protects against runaway strategies spamming the exchange (eg/ during pathological market
+/// data). Approved open requests that would breach the global or per-instrument
+/// [`OrderThrottle`] are refused. Throttle windows are measured against the [`EngineState`]
+/// `time_engine_now`.
+///
+/// Cancel requests are never throttled and do not consume throttle capacity, since they only
+/// reduce risk (eg/ pulling resting orders during a burst of volatility).
+#[derive(Debug)]
+pub struct OrderThrottleRiskManager<Risk> {
+    pub inner: Risk,
+    pub state: parking_lot::Mutex<OrderThrottleState>,
+}
+
+impl<Risk> OrderThrottleRiskManager<Risk> {
+    /// Construct a new `OrderThrottleRiskManager` wrapping the provided inner `RiskManager`.
+    pub fn new(
+        inner: Risk,
+        global: Option<OrderThrottle>,
+        instrument: Option<OrderThrottle>,
+    ) -> Self {
+        Self {
+            inner,
+            state: parking_lot::Mutex::new(OrderThrottleState::new(global, instrument)),
+        }
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for OrderThrottleRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let time_now = state.time_engine_now;
+        let mut throttle = self.state.lock();
+
+        // Only opens are throttled, since cancels reduce exchange exposure
+        let mut throttled_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                if throttle.try_acquire(time_now, open.0.key.instrument) {
+                    Some(open)
+                } else {
+                    throttled_opens.push(RiskRefused::new(
+                        open.into_item(),
+                        "OrderThrottle rate limit exceeded",
+                    ));
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        drop(throttle);
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(throttled_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        risk::DefaultRiskManager,
+        test_utils::time_plus_secs,
+    };
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{RequestCancel, RequestOpen},
+    };
+    use barter_instrument::{
+        Side, Underlying, exchange::ExchangeId, index::IndexedInstruments, instrument::Instrument,
+    };
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_order_throttle_state_try_acquire() {
+        struct TestCase {
+            global: Option<OrderThrottle>,
+            instrument: Option<OrderThrottle>,
+            requests: Vec<(i64, usize)>,
+            expected: Vec<bool>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let throttle =
+            |max_orders, secs| Some(OrderThrottle::new(max_orders, TimeDelta::seconds(secs)));
+
+        let cases = vec![
+            // TC0: no throttles configured
+            TestCase {
+                global: None,
+                instrument: None,
+                requests: vec![(0, 0), (0, 0), (0, 0)],
+                expected: vec![true, true, true],
+            },
+            // TC1: global throttle breached across instruments
+            TestCase {
+                global: throttle(2, 10),
+                instrument: None,
+                requests: vec![(0, 0), (1, 1), (2, 2)],
+                expected: vec![true, true, false],
+            },
+            // TC2: global throttle capacity is freed after the interval
+            TestCase {
+                global: throttle(2, 10),
+                instrument: None,
+                requests: vec![(0, 0), (1, 0), (2, 0), (10, 0), (11, 0)],
+                expected: vec![true, true, false, true, true],
+            },
+            // TC3: instrument throttle applies to each instrument independently
+            TestCase {
+                global: None,
+                instrument: throttle(1, 10),
+                requests: vec![(0, 0), (1, 1), (2, 0), (3, 1)],
+                expected: vec![true, true, false, false],
+            },
+            // TC4: refused requests do not consume global capacity
+            TestCase {
+                global: throttle(2, 10),
+                instrument: throttle(1, 10),
+                requests: vec![(0, 0), (1, 0), (2, 1), (3, 2)],
+                expected: vec![true, false, true, false],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut state = OrderThrottleState::new(test.global, test.instrument);
+
+            let actual = test
+                .requests
+                .into_iter()
+                .map(|(secs, instrument)| {
+                    state.try_acquire(time_plus_secs(base, secs), InstrumentIndex(instrument))
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_order_throttle_risk_manager_check_never_throttles_cancels() {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+        let state = EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        let key = |cid: &str| OrderKey {
+            exchange: ExchangeIndex(0),
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        };
+        let cancel = |cid| OrderRequestCancel {
+            key: key(cid),
+            state: RequestCancel::new(None),
+        };
+        let open = |cid| OrderRequestOpen {
+            key: key(cid),
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        };
+
+        let risk = OrderThrottleRiskManager::new(
+            DefaultRiskManager::default(),
+            Some(OrderThrottle::new(1, TimeDelta::seconds(10))),
+            None,
+        );
+
+        // Burst of cancels exceeding the throttle are all approved, without consuming capacity
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) = risk.check(
+            &state,
+            [cancel("a"), cancel("b"), cancel("c")],
+            [open("d"), open("e")],
+        );
+        assert_eq!(approved_cancels.into_iter().count(), 3);
+        assert_eq!(refused_cancels.into_iter().count(), 0);
+        assert_eq!(approved_opens.into_iter().count(), 1);
+        assert_eq!(refused_opens.into_iter().count(), 1);
+
+        // Cancels are still approved once the open throttle is exhausted
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            risk.check(&state, [cancel("f")], [open("g")]);
+        assert_eq!(approved_cancels.into_iter().count(), 1);
+        assert_eq!(refused_cancels.into_iter().count(), 0);
+        assert_eq!(approved_opens.into_iter().count(), 0);
+        assert_eq!(refused_opens.into_iter().count(), 1);
+    }
+}
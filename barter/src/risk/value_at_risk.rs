@@ -0,0 +1,229 @@
+use crate::{
+    engine::state::{EngineState, instrument::data::InstrumentDataState, position::PositionExited},
+    risk::{RiskApproved, RiskManager, RiskRefused, check::util::calculate_delta, halt::RiskHalt},
+    statistic::metric::value_at_risk::{
+        InstrumentExposure, PortfolioExposures, VarConfig, price_returns,
+    },
+    strategy::library::PriceHistory,
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the [`VarRiskManager`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct VarRiskConfig {
+    pub var: VarConfig,
+
+    /// Maximum portfolio Value at Risk, in quote asset units (eg/ 1000 USDT).
+    pub max_var: Decimal,
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, checking the open order requests it
+/// approves against the projected portfolio
+/// [`ValueAtRisk`](crate::statistic::metric::value_at_risk::ValueAtRisk).
+///
+/// Orders that would push the portfolio VaR above the configured [`VarRiskConfig::max_var`]
+/// are refused. Orders that reduce the portfolio VaR are always approved, as are orders
+/// whose VaR cannot be projected due to insufficient [`PriceHistory`].
+#[derive(Debug, Clone, Constructor)]
+pub struct VarRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: VarRiskConfig,
+}
+
+impl<Risk> VarRiskManager<Risk> {
+    /// Check if an order with the provided signed notional can be approved given the current
+    /// [`PortfolioExposures`], returning the refusal reason if not.
+    ///
+    /// If approved, the order notional is added to the `PortfolioExposures`, so subsequent
+    /// orders are checked against the projected portfolio VaR.
+    pub fn check_open(
+        &self,
+        exposures: &mut PortfolioExposures,
+        instrument: InstrumentIndex,
+        order_notional: Decimal,
+    ) -> Result<(), String> {
+        let current = exposures.value_at_risk(&self.config.var);
+
+        let exposure = exposures.0.entry(instrument).or_default();
+        exposure.notional += order_notional;
+
+        let Some(projected) = exposures.value_at_risk(&self.config.var) else {
+            return Ok(());
+        };
+
+        let is_increasing = current.is_none_or(|current| projected.var > current.var);
+        if projected.var > self.config.max_var && is_increasing {
+            exposures.0.entry(instrument).or_default().notional -= order_notional;
+            return Err(format!(
+                "VarRiskManager projected portfolio VaR {} exceeds maximum {}",
+                projected.var.round_dp(2),
+                self.config.max_var
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for VarRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: InstrumentDataState + PriceHistory,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let mut exposures = None;
+        let mut over_risk_opens = Vec::new();
+        let approved_opens = approved_opens
+            .into_iter()
+            .filter_map(|open| {
+                let instrument = state.instruments.instrument_index(&open.0.key.instrument);
+                let price = instrument.data.price().unwrap_or(open.0.state.price);
+
+                let order_notional = price
+                    * calculate_delta(
+                        Decimal::ONE,
+                        instrument.instrument.kind.contract_size(),
+                        open.0.state.side,
+                        open.0.state.quantity,
+                    );
+
+                let exposures = exposures.get_or_insert_with(|| {
+                    PortfolioExposures::calculate(state, self.config.var.period)
+                });
+
+                exposures.0.entry(open.0.key.instrument).or_insert_with(|| {
+                    InstrumentExposure::new(
+                        Decimal::ZERO,
+                        price_returns(instrument.data.prices(), self.config.var.period)
+                            .unwrap_or_default(),
+                    )
+                });
+
+                match self.check_open(exposures, open.0.key.instrument, order_notional) {
+                    Ok(()) => Some(open),
+                    Err(reason) => {
+                        over_risk_opens.push(RiskRefused::new(open.into_item(), reason));
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(over_risk_opens),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{risk::DefaultRiskManager, statistic::metric::value_at_risk::VarMethod};
+    use barter_integration::collection::FnvIndexMap;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_var_risk_manager_check_open() {
+        struct TestCase {
+            notional: Decimal,
+            order_notionals: Vec<Decimal>,
+            expected: Vec<bool>,
+            expected_notional: Decimal,
+        }
+
+        let btc = InstrumentIndex(0);
+
+        let cases = vec![
+            // TC0: order within the maximum VaR is approved
+            TestCase {
+                notional: dec!(0),
+                order_notionals: vec![dec!(500)],
+                expected: vec![true],
+                expected_notional: dec!(500),
+            },
+            // TC1: order exceeding the maximum VaR is refused
+            TestCase {
+                notional: dec!(500),
+                order_notionals: vec![dec!(600)],
+                expected: vec![false],
+                expected_notional: dec!(500),
+            },
+            // TC2: order reducing an over-limit VaR is approved
+            TestCase {
+                notional: dec!(2000),
+                order_notionals: vec![dec!(-500)],
+                expected: vec![true],
+                expected_notional: dec!(1500),
+            },
+            // TC3: approved orders count towards the projected VaR of later orders
+            TestCase {
+                notional: dec!(0),
+                order_notionals: vec![dec!(600), dec!(600), dec!(-600), dec!(300)],
+                expected: vec![true, false, true, true],
+                expected_notional: dec!(300),
+            },
+        ];
+
+        // Worst historical return of -10%, so max VaR 100 allows up to 1000 notional
+        let risk = VarRiskManager::new(
+            DefaultRiskManager::<()>::default(),
+            VarRiskConfig::new(
+                VarConfig::new(dec!(0.95), 4, VarMethod::Historical),
+                dec!(100),
+            ),
+        );
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut exposures = PortfolioExposures(FnvIndexMap::from_iter([(
+                btc,
+                InstrumentExposure::new(
+                    test.notional,
+                    vec![dec!(0.05), dec!(-0.1), dec!(0.02), dec!(0.01)],
+                ),
+            )]));
+
+            let actual = test
+                .order_notionals
+                .into_iter()
+                .map(|order_notional| risk.check_open(&mut exposures, btc, order_notional).is_ok())
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(
+                exposures.0[&btc].notional, test.expected_notional,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        position::{PositionExited, TrailDistance},
+    },
+    risk::{
+        RiskApproved, RiskManager, RiskRefused, check::util::is_position_reducing, halt::RiskHalt,
+    },
+    strategy::library::PriceHistory,
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use fnv::FnvHashSet;
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tracing::warn;
+
+/// Short-horizon volatility measure compared against its rolling baseline by the
+/// [`VolatilityHaltRiskManager`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum VolatilityMeasure {
+    /// Realised volatility, ie/ the root mean square of the simple price returns.
+    #[default]
+    RealisedVolatility,
+
+    /// High-low price range relative to the opening price (eg/ the range of a candle when the
+    /// price history contains candle closes).
+    Range,
+}
+
+impl VolatilityMeasure {
+    /// Calculate the volatility measure of the provided window of prices (oldest first).
+    ///
+    /// Returns `None` if the window contains less than two prices, or a non-positive price.
+    pub fn calculate(&self, prices: &[Decimal]) -> Option<Decimal> {
+        if prices.len() < 2 || prices.iter().any(|price| *price <= Decimal::ZERO) {
+            return None;
+        }
+
+        match self {
+            Self::RealisedVolatility => {
+                let squared_returns = prices
+                    .windows(2)
+                    .map(|pair| {
+                        let change = pair[1] / pair[0] - Decimal::ONE;
+                        change * change
+                    })
+                    .sum::<Decimal>();
+
+                (squared_returns / Decimal::from(prices.len() - 1)).sqrt()
+            }
+            Self::Range => {
+                let high = prices.iter().max()?;
+                let low = prices.iter().min()?;
+                Some((high - low) / prices[0])
+            }
+        }
+    }
+}
+
+/// Configuration of the [`VolatilityHaltRiskManager`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct VolatilityHaltConfig {
+    /// Volatility measure compared against its rolling baseline.
+    pub measure: VolatilityMeasure,
+
+    /// Number of price updates (eg/ candles) in the short horizon the measure is calculated over.
+    pub horizon: usize,
+
+    /// Number of preceding horizons averaged to form the rolling baseline.
+    pub baseline: usize,
+
+    /// Multiple of the baseline that the short-horizon measure must exceed to halt entries
+    /// (eg/ 3 for 3x the baseline).
+    pub max_multiple: Decimal,
+
+    /// Optional factor that open `Position` [`TrailingStop`] distances are scaled by while halted
+    /// (eg/ 0.5 halves the distance).
+    ///
+    /// See [`VolatilityHaltRiskManager::tighten_stops`].
+    ///
+    /// [`TrailingStop`]: crate::engine::state::position::TrailingStop
+    pub stop_tightening: Option<Decimal>,
+}
+
+impl VolatilityHaltConfig {
+    /// Number of prices required to calculate the [`Self::volatility_multiple`].
+    pub fn prices_required(&self) -> usize {
+        self.horizon * (self.baseline + 1) + 1
+    }
+
+    /// Calculate the multiple of the rolling baseline the most recent short-horizon
+    /// [`VolatilityMeasure`] represents, from the provided price history (oldest first).
+    ///
+    /// Returns `None` if there is insufficient price history, or the baseline is zero.
+    pub fn volatility_multiple(&self, prices: &VecDeque<Decimal>) -> Option<Decimal> {
+        if self.horizon == 0 || self.baseline == 0 || prices.len() < self.prices_required() {
+            return None;
+        }
+
+        let prices = prices
+            .iter()
+            .skip(prices.len() - self.prices_required())
+            .copied()
+            .collect::<Vec<_>>();
+
+        let measures = (0..=self.baseline)
+            .map(|window| {
+                let start = window * self.horizon;
+                self.measure
+                    .calculate(&prices[start..=start + self.horizon])
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let (current, baseline) = measures.split_last()?;
+        let baseline = baseline.iter().sum::<Decimal>() / Decimal::from(baseline.len());
+
+        (baseline > Decimal::ZERO).then(|| current / baseline)
+    }
+
+    /// Returns true if the most recent short-horizon volatility exceeds the configured multiple of
+    /// its rolling baseline.
+    pub fn is_exceeded(&self, prices: &VecDeque<Decimal>) -> bool {
+        self.volatility_multiple(prices)
+            .is_some_and(|multiple| multiple > self.max_multiple)
+    }
+}
+
+/// [`RiskManager`] that wraps an inner `RiskManager`, halting entries for instruments whose
+/// short-horizon volatility (or price range) exceeds a configured multiple of its rolling
+/// baseline, protecting strategies not designed for flash-crash regimes.
+///
+/// Cancel requests, and open requests that only reduce an existing `Position`, are always
+/// forwarded to the inner `RiskManager` so exits are still permitted while halted. Instruments
+/// with insufficient [`PriceHistory`] are not halted.
+#[derive(Debug, Clone)]
+pub struct VolatilityHaltRiskManager<Risk> {
+    pub inner: Risk,
+    pub config: VolatilityHaltConfig,
+
+    /// Instruments whose open `Position` stops have been tightened during the current halt.
+    pub tightened: FnvHashSet<InstrumentIndex>,
+}
+
+impl<Risk> VolatilityHaltRiskManager<Risk> {
+    /// Construct a new `VolatilityHaltRiskManager` wrapping the provided inner `RiskManager`.
+    pub fn new(inner: Risk, config: VolatilityHaltConfig) -> Self {
+        Self {
+            inner,
+            config,
+            tightened: FnvHashSet::default(),
+        }
+    }
+
+    /// Tighten the [`TrailingStop`] of every open `Position` of a halted instrument by the
+    /// configured [`VolatilityHaltConfig::stop_tightening`] factor, returning the instruments
+    /// tightened by this call.
+    ///
+    /// Stops are tightened at most once per halt, and the tightened distance takes effect on the
+    /// next market price update. No-op if `stop_tightening` is not configured.
+    ///
+    /// This should be called after market data updates the [`EngineState`]
+    /// (eg/ `engine.risk.tighten_stops(&mut engine.state)`).
+    ///
+    /// [`TrailingStop`]: crate::engine::state::position::TrailingStop
+    pub fn tighten_stops<GlobalData, InstrumentData>(
+        &mut self,
+        state: &mut EngineState<GlobalData, InstrumentData>,
+    ) -> Vec<InstrumentIndex>
+    where
+        InstrumentData: PriceHistory,
+    {
+        let Some(factor) = self.config.stop_tightening else {
+            return Vec::new();
+        };
+
+        state
+            .instruments
+            .0
+            .values_mut()
+            .filter_map(|instrument| {
+                if !self.config.is_exceeded(instrument.data.prices()) {
+                    self.tightened.remove(&instrument.key);
+                    return None;
+                }
+
+                if self.tightened.contains(&instrument.key) {
+                    return None;
+                }
+
+                let stop = instrument
+                    .position
+                    .current
+                    .as_mut()?
+                    .trailing_stop
+                    .as_mut()?;
+
+                stop.distance = scale_trail_distance(stop.distance, factor);
+                self.tightened.insert(instrument.key);
+
+                warn!(
+                    instrument = %instrument.instrument.name_internal,
+                    %factor,
+                    "VolatilityHaltRiskManager tightened Position TrailingStop"
+                );
+
+                Some(instrument.key)
+            })
+            .collect()
+    }
+}
+
+fn scale_trail_distance(distance: TrailDistance, factor: Decimal) -> TrailDistance {
+    match distance {
+        TrailDistance::Absolute(distance) => TrailDistance::Absolute(distance * factor),
+        TrailDistance::Bps(bps) => TrailDistance::Bps(bps * factor),
+        TrailDistance::Atr { atr, multiple } => TrailDistance::Atr {
+            atr,
+            multiple: multiple * factor,
+        },
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for VolatilityHaltRiskManager<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: PriceHistory,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeIndex, InstrumentIndex>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    ) {
+        let (opens, entries): (Vec<_>, Vec<_>) = opens.into_iter().partition(|open| {
+            let instrument = state.instruments.instrument_index(&open.key.instrument);
+
+            !self.config.is_exceeded(instrument.data.prices())
+                || is_position_reducing(
+                    instrument.position.current.as_ref(),
+                    open.state.side,
+                    open.state.quantity,
+                )
+        });
+
+        let refused_halted = entries
+            .into_iter()
+            .map(|open| {
+                RiskRefused::new(
+                    open,
+                    "VolatilityHaltRiskManager volatility exceeds baseline: entry not permitted",
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(refused_halted),
+        )
+    }
+
+    fn update_from_position_exit(
+        &mut self,
+        position: &PositionExited<QuoteAsset, InstrumentIndex>,
+    ) -> Vec<RiskHalt> {
+        self.inner.update_from_position_exit(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            global::DefaultGlobalData,
+            position::{Position, TrailingStop},
+        },
+        risk::DefaultRiskManager,
+        strategy::library::PriceHistoryData,
+    };
+    use barter_execution::{
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, OrderId, StrategyId},
+            request::RequestOpen,
+        },
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, Underlying, exchange::ExchangeId, index::IndexedInstruments, instrument::Instrument,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn config(measure: VolatilityMeasure) -> VolatilityHaltConfig {
+        VolatilityHaltConfig {
+            measure,
+            horizon: 2,
+            baseline: 2,
+            max_multiple: dec!(3),
+            stop_tightening: Some(dec!(0.5)),
+        }
+    }
+
+    #[test]
+    fn test_volatility_halt_config_volatility_multiple() {
+        struct TestCase {
+            measure: VolatilityMeasure,
+            prices: Vec<Decimal>,
+            expected: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: insufficient price history
+            TestCase {
+                measure: VolatilityMeasure::RealisedVolatility,
+                prices: vec![
+                    dec!(100),
+                    dec!(101),
+                    dec!(100),
+                    dec!(101),
+                    dec!(100),
+                    dec!(101),
+                ],
+                expected: None,
+            },
+            // TC1: realised volatility in line with the baseline
+            TestCase {
+                measure: VolatilityMeasure::RealisedVolatility,
+                prices: vec![
+                    dec!(100),
+                    dec!(110),
+                    dec!(121),
+                    dec!(133.1),
+                    dec!(146.41),
+                    dec!(161.051),
+                    dec!(177.1561),
+                ],
+                expected: Some(dec!(1)),
+            },
+            // TC2: price range spike relative to the baseline
+            TestCase {
+                measure: VolatilityMeasure::Range,
+                prices: vec![
+                    dec!(100),
+                    dec!(101),
+                    dec!(100),
+                    dec!(101),
+                    dec!(100),
+                    dec!(95),
+                    dec!(104),
+                ],
+                expected: Some(dec!(9)),
+            },
+            // TC3: zero baseline
+            TestCase {
+                measure: VolatilityMeasure::Range,
+                prices: vec![
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(95),
+                    dec!(104),
+                ],
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = config(test.measure).volatility_multiple(&test.prices.into());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    fn state(
+        prices: &[Decimal],
+        position: Option<Side>,
+    ) -> EngineState<DefaultGlobalData, PriceHistoryData> {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let mut state = EngineState::builder(&instruments, DefaultGlobalData, || {
+            PriceHistoryData::new(16)
+        })
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        let instrument = state.instruments.instrument_index_mut(&InstrumentIndex(0));
+        instrument.data.prices.extend(prices);
+        instrument.position.current = position.map(|side| {
+            let mut position = Position::from(&Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                cid: None,
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side,
+                price: dec!(100),
+                quantity: dec!(1),
+                liquidity: None,
+                fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+            });
+            position.trailing_stop =
+                Some(TrailingStop::new(None, TrailDistance::Absolute(dec!(10))));
+            position
+        });
+
+        state
+    }
+
+    fn open(side: Side) -> OrderRequestOpen<ExchangeIndex, InstrumentIndex> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_volatility_halt_risk_manager_check() {
+        struct TestCase {
+            prices: Vec<Decimal>,
+            position: Option<Side>,
+            open: Side,
+            expected_approved: bool,
+        }
+
+        let calm = vec![
+            dec!(100),
+            dec!(101),
+            dec!(100),
+            dec!(101),
+            dec!(100),
+            dec!(101),
+            dec!(100),
+        ];
+        let spike = vec![
+            dec!(100),
+            dec!(101),
+            dec!(100),
+            dec!(101),
+            dec!(100),
+            dec!(95),
+            dec!(104),
+        ];
+
+        let cases = vec![
+            // TC0: entry approved while volatility is in line with the baseline
+            TestCase {
+                prices: calm,
+                position: None,
+                open: Side::Buy,
+                expected_approved: true,
+            },
+            // TC1: entry refused while volatility exceeds the baseline multiple
+            TestCase {
+                prices: spike.clone(),
+                position: None,
+                open: Side::Buy,
+                expected_approved: false,
+            },
+            // TC2: exit approved while volatility exceeds the baseline multiple
+            TestCase {
+                prices: spike.clone(),
+                position: Some(Side::Buy),
+                open: Side::Sell,
+                expected_approved: true,
+            },
+            // TC3: increasing an existing Position refused
+            TestCase {
+                prices: spike,
+                position: Some(Side::Buy),
+                open: Side::Buy,
+                expected_approved: false,
+            },
+        ];
+
+        let risk = VolatilityHaltRiskManager::new(
+            DefaultRiskManager::default(),
+            config(VolatilityMeasure::Range),
+        );
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let state = state(&test.prices, test.position);
+            let (_, approved, _, refused) = risk.check(&state, [], [open(test.open)]);
+
+            let approved = approved.into_iter().count();
+            let refused = refused.into_iter().count();
+            assert_eq!(
+                (approved == 1, refused == 1),
+                (test.expected_approved, !test.expected_approved),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_volatility_halt_risk_manager_tighten_stops() {
+        let spike = [
+            dec!(100),
+            dec!(101),
+            dec!(100),
+            dec!(101),
+            dec!(100),
+            dec!(95),
+            dec!(104),
+        ];
+
+        let mut risk = VolatilityHaltRiskManager::new(
+            DefaultRiskManager::<()>::default(),
+            config(VolatilityMeasure::Range),
+        );
+        let mut state = state(&spike, Some(Side::Buy));
+
+        let distance = |state: &EngineState<DefaultGlobalData, PriceHistoryData>| {
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(0))
+                .position
+                .current
+                .as_ref()
+                .and_then(|position| position.trailing_stop.as_ref())
+                .map(|stop| stop.distance)
+        };
+
+        // Stop tightened once on halt
+        assert_eq!(risk.tighten_stops(&mut state), vec![InstrumentIndex(0)]);
+        assert_eq!(distance(&state), Some(TrailDistance::Absolute(dec!(5))));
+
+        // Stop not tightened again during the same halt
+        assert_eq!(risk.tighten_stops(&mut state), vec![]);
+        assert_eq!(distance(&state), Some(TrailDistance::Absolute(dec!(5))));
+
+        // Halt ends once volatility normalises
+        let prices = &mut state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .data
+            .prices;
+        prices.extend([dec!(104), dec!(104), dec!(104), dec!(104)]);
+        assert_eq!(risk.tighten_stops(&mut state), vec![]);
+        assert!(risk.tightened.is_empty());
+    }
+}
@@ -0,0 +1,530 @@
+use crate::{
+    EngineEvent,
+    engine::{
+        Engine, Processor,
+        execution_tx::MultiExchangeTxMap,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    execution::{AccountStreamEvent, request::ExecutionRequest},
+};
+use barter_data::streams::consumer::MarketStreamEvent;
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    error::{ApiError, OrderError},
+    order::{
+        Order, OrderEvent,
+        id::OrderId,
+        request::{OrderRequestCancel, OrderRequestOpen},
+        state::{Cancelled, Open, OrderState},
+    },
+    trade::{AssetFees, Trade, TradeId},
+};
+use barter_instrument::{
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use barter_integration::{
+    channel::{UnboundedRx, UnboundedTx, mpsc_unbounded},
+    snapshot::Snapshot,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+
+/// Maximum number of [`SimulatedExchange`] response rounds processed after a single
+/// [`ScenarioStep`], guarding against strategies that re-send orders indefinitely.
+const MAX_RESPONSE_ROUNDS: usize = 1_000;
+
+/// Scripted response of the [`SimulatedExchange`] to an open order request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpenResponse {
+    /// Fully fill the order at the requested price.
+    Fill,
+
+    /// Fill the provided quantity at the requested price, leaving the remainder resting open.
+    PartialFill(Decimal),
+
+    /// Accept the order, leaving it resting open without any fills.
+    Rest,
+
+    /// Reject the order with the provided reason.
+    Reject(String),
+
+    /// Never respond (eg/ to simulate a lost request).
+    Ignore,
+}
+
+/// Single step of a [`Scenario`] script.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScenarioStep<MarketKind> {
+    /// Process the scripted [`EngineEvent`] (eg/ `MarketEvent`, `AccountEvent`, `Command`).
+    Event(EngineEvent<MarketKind>),
+
+    /// Script the [`SimulatedExchange`] response to the next open order request.
+    Respond(OpenResponse),
+
+    /// Simulate a disconnection of the exchange market data and account streams.
+    Disconnect(ExchangeId),
+}
+
+/// Scripted simulated exchange that responds to the [`ExecutionRequest`]s sent by an
+/// [`Engine`].
+///
+/// Open requests are answered by the next scripted [`OpenResponse`], or the `default_response`
+/// once the script is exhausted. Cancel requests are always accepted. Balances are not
+/// simulated, so should be scripted as `AccountEvent`s if required.
+#[derive(Debug)]
+pub struct SimulatedExchange {
+    /// Index of the simulated exchange, used to key generated `AccountEvent`s.
+    pub exchange: ExchangeIndex,
+
+    /// Fees charged on every fill, as a percentage of the filled notional (eg/ 0.001 for 0.1%).
+    pub fees_percent: Decimal,
+
+    /// [`OpenResponse`] used once the `scripted` responses are exhausted.
+    pub default_response: OpenResponse,
+
+    /// Scripted [`OpenResponse`]s, used in order.
+    pub scripted: VecDeque<OpenResponse>,
+
+    /// Every [`ExecutionRequest`] received from the [`Engine`], used for assertions.
+    pub requests: Vec<ExecutionRequest>,
+
+    sequence: u64,
+    execution_tx: UnboundedTx<ExecutionRequest>,
+    execution_rx: UnboundedRx<ExecutionRequest>,
+}
+
+impl SimulatedExchange {
+    /// Construct a new `SimulatedExchange` that responds to open requests with the provided
+    /// `default_response`.
+    pub fn new(exchange: ExchangeIndex, default_response: OpenResponse) -> Self {
+        let (execution_tx, execution_rx) = mpsc_unbounded();
+
+        Self {
+            exchange,
+            fees_percent: Decimal::ZERO,
+            default_response,
+            scripted: VecDeque::new(),
+            requests: Vec::new(),
+            sequence: 0,
+            execution_tx,
+            execution_rx,
+        }
+    }
+
+    /// Charge the provided `fees_percent` on every fill.
+    pub fn with_fees_percent(self, fees_percent: Decimal) -> Self {
+        Self {
+            fees_percent,
+            ..self
+        }
+    }
+
+    /// Construct the [`MultiExchangeTxMap`] the [`Engine`] uses to send [`ExecutionRequest`]s
+    /// to this `SimulatedExchange`.
+    pub fn execution_txs(&self, exchange: ExchangeId) -> MultiExchangeTxMap {
+        MultiExchangeTxMap::from_iter([(exchange, Some(self.execution_tx.clone()))])
+    }
+
+    /// Iterator of every [`OrderRequestOpen`] received from the [`Engine`].
+    pub fn opens(&self) -> impl Iterator<Item = &OrderRequestOpen> {
+        self.requests.iter().filter_map(|request| match request {
+            ExecutionRequest::Open(open) => Some(open),
+            _ => None,
+        })
+    }
+
+    /// Iterator of every [`OrderRequestCancel`] received from the [`Engine`].
+    pub fn cancels(&self) -> impl Iterator<Item = &OrderRequestCancel> {
+        self.requests.iter().filter_map(|request| match request {
+            ExecutionRequest::Cancel(cancel) => Some(cancel),
+            _ => None,
+        })
+    }
+
+    /// Respond to all pending [`ExecutionRequest`]s, returning the generated [`AccountEvent`]s.
+    pub fn process_requests(&mut self, time_exchange: DateTime<Utc>) -> Vec<AccountEvent> {
+        let mut events = Vec::new();
+
+        while let Ok(request) = self.execution_rx.rx.try_recv() {
+            self.requests.push(request.clone());
+
+            match request {
+                ExecutionRequest::Shutdown => {}
+                ExecutionRequest::Cancel(cancel) => {
+                    events.push(self.cancel(cancel, time_exchange));
+                }
+                ExecutionRequest::Open(open) => {
+                    events.extend(self.open(open, time_exchange));
+                }
+            }
+        }
+
+        events
+    }
+
+    fn cancel(
+        &mut self,
+        request: OrderRequestCancel,
+        time_exchange: DateTime<Utc>,
+    ) -> AccountEvent {
+        let id = request.state.id.unwrap_or_else(|| self.order_id());
+
+        self.account_event(AccountEventKind::OrderCancelled(OrderEvent {
+            key: request.key,
+            state: Ok(Cancelled { id, time_exchange }),
+        }))
+    }
+
+    fn open(
+        &mut self,
+        request: OrderRequestOpen,
+        time_exchange: DateTime<Utc>,
+    ) -> Vec<AccountEvent> {
+        let response = self
+            .scripted
+            .pop_front()
+            .unwrap_or_else(|| self.default_response.clone());
+
+        let (state, filled) = match response {
+            OpenResponse::Ignore => return vec![],
+            OpenResponse::Reject(reason) => (
+                OrderState::inactive(OrderError::Rejected(ApiError::OrderRejected(reason))),
+                None,
+            ),
+            OpenResponse::Rest => (
+                OrderState::active(Open {
+                    id: self.order_id(),
+                    time_exchange,
+                    filled_quantity: Decimal::ZERO,
+                }),
+                None,
+            ),
+            OpenResponse::Fill => (OrderState::fully_filled(), Some(request.state.quantity)),
+            OpenResponse::PartialFill(quantity) => (
+                OrderState::active(Open {
+                    id: self.order_id(),
+                    time_exchange,
+                    filled_quantity: quantity,
+                }),
+                Some(quantity),
+            ),
+        };
+
+        let snapshot = self.account_event(AccountEventKind::OrderSnapshot(Snapshot(Order {
+            key: request.key.clone(),
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state,
+        })));
+
+        let Some(quantity) = filled else {
+            return vec![snapshot];
+        };
+
+        let order_id = self.order_id();
+        let trade = self.account_event(AccountEventKind::Trade(Trade {
+            id: TradeId(order_id.0.clone()),
+            order_id,
+            cid: Some(request.key.cid),
+            instrument: request.key.instrument,
+            strategy: request.key.strategy,
+            time_exchange,
+            side: request.state.side,
+            price: request.state.price,
+            quantity,
+            liquidity: None,
+            fees: AssetFees::quote_fees(request.state.price * quantity * self.fees_percent),
+        }));
+
+        vec![snapshot, trade]
+    }
+
+    fn order_id(&mut self) -> OrderId {
+        self.sequence += 1;
+        OrderId::new(self.sequence.to_string())
+    }
+
+    fn account_event(
+        &self,
+        kind: AccountEventKind<ExchangeIndex, AssetIndex, InstrumentIndex>,
+    ) -> AccountEvent {
+        AccountEvent {
+            exchange: self.exchange,
+            sub_account: None,
+            kind,
+        }
+    }
+}
+
+/// End-to-end test harness that drives a real [`Engine`] through a scripted [`ScenarioStep`]
+/// sequence, routing the [`SimulatedExchange`] responses back into the [`Engine`].
+///
+/// Assertions can be made over the resulting `EngineState`, the `Engine` audits, and the
+/// [`ExecutionRequest`]s received by the [`SimulatedExchange`].
+#[derive(Debug)]
+pub struct Scenario<Clock, State, Strategy, Risk, Audit> {
+    pub engine: Engine<Clock, State, MultiExchangeTxMap, Strategy, Risk>,
+    pub exchange: SimulatedExchange,
+    pub audits: Vec<Audit>,
+}
+
+impl<Clock, GlobalData, InstrumentData, Strategy, Risk, Audit>
+    Scenario<Clock, EngineState<GlobalData, InstrumentData>, Strategy, Risk, Audit>
+where
+    InstrumentData: InstrumentDataState,
+    Engine<Clock, EngineState<GlobalData, InstrumentData>, MultiExchangeTxMap, Strategy, Risk>:
+        Processor<EngineEvent<InstrumentData::MarketEventKind>, Audit = Audit>,
+{
+    /// Construct a new `Scenario` from an [`Engine`] that sends its [`ExecutionRequest`]s to the
+    /// provided [`SimulatedExchange`] (see [`SimulatedExchange::execution_txs`]).
+    pub fn new(
+        engine: Engine<
+            Clock,
+            EngineState<GlobalData, InstrumentData>,
+            MultiExchangeTxMap,
+            Strategy,
+            Risk,
+        >,
+        exchange: SimulatedExchange,
+    ) -> Self {
+        Self {
+            engine,
+            exchange,
+            audits: Vec::new(),
+        }
+    }
+
+    /// Run every [`ScenarioStep`] in order.
+    pub fn run<Steps>(&mut self, steps: Steps)
+    where
+        Steps: IntoIterator<Item = ScenarioStep<InstrumentData::MarketEventKind>>,
+    {
+        for step in steps {
+            self.step(step);
+        }
+    }
+
+    /// Run the next [`ScenarioStep`], processing any resulting [`SimulatedExchange`] responses
+    /// until the [`Engine`] sends no further [`ExecutionRequest`]s.
+    ///
+    /// Panics if the [`Engine`] keeps sending requests for more than 1000 response rounds.
+    pub fn step(&mut self, step: ScenarioStep<InstrumentData::MarketEventKind>) {
+        match step {
+            ScenarioStep::Event(event) => self.process(event),
+            ScenarioStep::Respond(response) => self.exchange.scripted.push_back(response),
+            ScenarioStep::Disconnect(exchange) => {
+                self.process(EngineEvent::Market(MarketStreamEvent::Reconnecting(
+                    exchange,
+                )));
+                self.process(EngineEvent::Account(AccountStreamEvent::Reconnecting(
+                    exchange,
+                )));
+            }
+        }
+    }
+
+    fn process(&mut self, event: EngineEvent<InstrumentData::MarketEventKind>) {
+        self.audits.push(self.engine.process(event));
+
+        for _ in 0..MAX_RESPONSE_ROUNDS {
+            let responses = self
+                .exchange
+                .process_requests(self.engine.state.time_engine_now);
+
+            if responses.is_empty() {
+                return;
+            }
+
+            for response in responses {
+                let event = EngineEvent::Account(AccountStreamEvent::Item(response));
+                self.audits.push(self.engine.process(event));
+            }
+        }
+
+        panic!("Scenario exceeded {MAX_RESPONSE_ROUNDS} SimulatedExchange response rounds");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::{
+            clock::HistoricalClock,
+            command::Command,
+            state::{
+                global::DefaultGlobalData,
+                instrument::{data::DefaultInstrumentMarketData, filter::InstrumentFilter},
+            },
+        },
+        risk::DefaultRiskManager,
+        strategy::DefaultStrategy,
+        test_utils::time_plus_secs,
+    };
+    use barter_data::{
+        event::{DataKind, MarketEvent},
+        subscription::trade::PublicTrade,
+    };
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::RequestOpen,
+    };
+    use barter_instrument::{Side, Underlying, index::IndexedInstruments, instrument::Instrument};
+    use barter_integration::collection::one_or_many::OneOrMany;
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+    type TestStrategy = DefaultStrategy<TestState>;
+    type TestRisk = DefaultRiskManager<TestState>;
+    type TestEngine =
+        Engine<HistoricalClock, TestState, MultiExchangeTxMap, TestStrategy, TestRisk>;
+    type TestAudit = <TestEngine as Processor<EngineEvent<DataKind>>>::Audit;
+
+    fn scenario() -> Scenario<HistoricalClock, TestState, TestStrategy, TestRisk, TestAudit> {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let state = EngineState::builder(&instruments, DefaultGlobalData, Default::default)
+            .time_engine_start(DateTime::<Utc>::MIN_UTC)
+            .build();
+
+        let exchange = SimulatedExchange::new(ExchangeIndex(0), OpenResponse::Fill);
+
+        let engine = Engine::new(
+            HistoricalClock::new(DateTime::<Utc>::MIN_UTC),
+            state,
+            exchange.execution_txs(ExchangeId::BinanceSpot),
+            DefaultStrategy::default(),
+            DefaultRiskManager::default(),
+        );
+
+        Scenario::new(engine, exchange)
+    }
+
+    fn market_trade(secs: i64, price: f64) -> ScenarioStep<DataKind> {
+        let time = time_plus_secs(DateTime::<Utc>::MIN_UTC, secs);
+        ScenarioStep::Event(EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: secs.to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        })))
+    }
+
+    fn open_buy(quantity: Decimal) -> ScenarioStep<DataKind> {
+        ScenarioStep::Event(EngineEvent::Command(Command::SendOpenRequests(
+            OneOrMany::One(OrderRequestOpen {
+                key: OrderKey {
+                    exchange: ExchangeIndex(0),
+                    instrument: InstrumentIndex(0),
+                    strategy: StrategyId::new("strategy"),
+                    cid: ClientOrderId::new("buy"),
+                },
+                state: RequestOpen {
+                    side: Side::Buy,
+                    price: dec!(100),
+                    quantity,
+                    kind: OrderKind::Market,
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    reduce_only: false,
+                },
+            }),
+        )))
+    }
+
+    fn close_positions() -> ScenarioStep<DataKind> {
+        ScenarioStep::Event(EngineEvent::Command(Command::ClosePositions(
+            InstrumentFilter::None,
+        )))
+    }
+
+    #[test]
+    fn test_scenario_open_and_close_position() {
+        let mut scenario = scenario();
+
+        scenario.run([
+            market_trade(0, 100.0),
+            open_buy(dec!(2)),
+            market_trade(1, 110.0),
+        ]);
+
+        let position = scenario
+            .engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0));
+        let position = position.position.current.as_ref().unwrap();
+        assert_eq!(position.quantity_abs, dec!(2));
+
+        scenario.run([close_positions()]);
+
+        let state = scenario
+            .engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0));
+        assert!(state.position.current.is_none());
+        assert_eq!(scenario.exchange.opens().count(), 2);
+        assert_eq!(
+            scenario.exchange.opens().last().map(|open| open.state.side),
+            Some(Side::Sell)
+        );
+    }
+
+    #[test]
+    fn test_scenario_scripted_responses() {
+        let mut scenario = scenario();
+
+        scenario.run([
+            market_trade(0, 100.0),
+            ScenarioStep::Respond(OpenResponse::Reject("insufficient margin".to_string())),
+            open_buy(dec!(2)),
+        ]);
+
+        let state = scenario
+            .engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0));
+        assert!(state.position.current.is_none());
+        assert_eq!(state.orders.0.len(), 0);
+
+        scenario.run([
+            ScenarioStep::Respond(OpenResponse::PartialFill(dec!(0.5))),
+            open_buy(dec!(2)),
+        ]);
+
+        let state = scenario
+            .engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0));
+        let position = state.position.current.as_ref().unwrap();
+        assert_eq!(position.quantity_abs, dec!(0.5));
+        assert_eq!(state.orders.0.len(), 1);
+
+        scenario.run([ScenarioStep::Disconnect(ExchangeId::BinanceSpot)]);
+        assert_eq!(scenario.exchange.requests.len(), 2);
+    }
+}
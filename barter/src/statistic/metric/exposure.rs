@@ -0,0 +1,218 @@
+use crate::{
+    Timed,
+    engine::state::instrument::{InstrumentStates, data::InstrumentDataState},
+};
+use barter_instrument::Side;
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Gross and net notional exposure of a portfolio at a point in time.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct Exposure {
+    /// Sum of absolute position notional values.
+    pub gross: Decimal,
+
+    /// Signed sum of position notional values (LONG positive, SHORT negative).
+    pub net: Decimal,
+}
+
+impl Exposure {
+    /// Calculate the `Exposure` from the `Side` and absolute quote notional value of each
+    /// position.
+    pub fn calculate<Iter>(positions: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (Side, Decimal)>,
+    {
+        positions
+            .into_iter()
+            .fold(Self::default(), |exposure, (side, notional)| {
+                let notional = notional.abs();
+                Self {
+                    gross: exposure.gross + notional,
+                    net: match side {
+                        Side::Buy => exposure.net + notional,
+                        Side::Sell => exposure.net - notional,
+                    },
+                }
+            })
+    }
+
+    /// Calculate the `Exposure` of the open positions in the provided [`InstrumentStates`].
+    ///
+    /// Positions are valued at the current instrument price, falling back to the position
+    /// average entry price if no price is available.
+    pub fn from_instruments<InstrumentData>(instruments: &InstrumentStates<InstrumentData>) -> Self
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        Self::calculate(instruments.0.values().filter_map(|state| {
+            let position = state.position.current.as_ref()?;
+            let price = state.data.price().unwrap_or(position.price_entry_average);
+            let contract_size = state.instrument.kind.contract_size();
+
+            Some((position.side, position.quantity_abs * price * contract_size))
+        }))
+    }
+}
+
+/// Summary of the [`Exposure`] time series of a trading session.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct ExposureSummary {
+    /// Time-weighted average gross exposure.
+    pub gross_mean: Decimal,
+
+    /// Peak gross exposure.
+    pub gross_peak: Decimal,
+
+    /// Time-weighted average net exposure.
+    pub net_mean: Decimal,
+
+    /// Net exposure with the largest absolute value.
+    pub net_peak: Decimal,
+}
+
+/// Generator for an [`ExposureSummary`], tracking the [`Exposure`] time series of a trading
+/// session.
+///
+/// Each `Exposure` is assumed to hold until the next update, so averages are time-weighted.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct ExposureGenerator {
+    pub time_first: Option<DateTime<Utc>>,
+    pub current: Option<Timed<Exposure>>,
+    pub gross_peak: Decimal,
+    pub net_peak: Decimal,
+
+    /// Sum of gross exposure multiplied by the milliseconds it was held for.
+    gross_weighted: Decimal,
+
+    /// Sum of net exposure multiplied by the milliseconds it was held for.
+    net_weighted: Decimal,
+}
+
+impl ExposureGenerator {
+    /// Update the `ExposureGenerator` with the [`Exposure`] at the provided time.
+    pub fn update(&mut self, time: DateTime<Utc>, exposure: Exposure) {
+        match &self.current {
+            Some(current) => {
+                let held_ms = Decimal::from((time - current.time).num_milliseconds().max(0));
+                self.gross_weighted += current.value.gross * held_ms;
+                self.net_weighted += current.value.net * held_ms;
+            }
+            None => self.time_first = Some(time),
+        }
+
+        self.gross_peak = self.gross_peak.max(exposure.gross);
+        if exposure.net.abs() > self.net_peak.abs() {
+            self.net_peak = exposure.net;
+        }
+
+        self.current = Some(Timed::new(exposure, time));
+    }
+
+    /// Generate the [`ExposureSummary`] of the session up until the provided time.
+    pub fn generate(&self, time_now: DateTime<Utc>) -> ExposureSummary {
+        let (Some(time_first), Some(current)) = (self.time_first, &self.current) else {
+            return ExposureSummary::default();
+        };
+
+        let held_ms = Decimal::from((time_now - current.time).num_milliseconds().max(0));
+        let duration_ms = Decimal::from((time_now - time_first).num_milliseconds().max(0));
+
+        let (gross_mean, net_mean) = if duration_ms.is_zero() {
+            (current.value.gross, current.value.net)
+        } else {
+            (
+                (self.gross_weighted + current.value.gross * held_ms) / duration_ms,
+                (self.net_weighted + current.value.net * held_ms) / duration_ms,
+            )
+        };
+
+        ExposureSummary {
+            gross_mean,
+            gross_peak: self.gross_peak,
+            net_mean,
+            net_peak: self.net_peak,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_exposure_calculate() {
+        struct TestCase {
+            positions: Vec<(Side, Decimal)>,
+            expected: Exposure,
+        }
+
+        let cases = vec![
+            // TC0: no positions
+            TestCase {
+                positions: vec![],
+                expected: Exposure::new(dec!(0), dec!(0)),
+            },
+            // TC1: long & short positions offset net exposure
+            TestCase {
+                positions: vec![(Side::Buy, dec!(100)), (Side::Sell, dec!(40))],
+                expected: Exposure::new(dec!(140), dec!(60)),
+            },
+            // TC2: net short
+            TestCase {
+                positions: vec![(Side::Buy, dec!(10)), (Side::Sell, dec!(50))],
+                expected: Exposure::new(dec!(60), dec!(-40)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Exposure::calculate(test.positions);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_exposure_generator_generate() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut generator = ExposureGenerator::default();
+        assert_eq!(generator.generate(base), ExposureSummary::default());
+
+        generator.update(base, Exposure::new(dec!(100), dec!(100)));
+        generator.update(
+            time_plus_secs(base, 10),
+            Exposure::new(dec!(300), dec!(-300)),
+        );
+        generator.update(time_plus_secs(base, 20), Exposure::new(dec!(0), dec!(0)));
+
+        let actual = generator.generate(time_plus_secs(base, 40));
+
+        assert_eq!(
+            actual,
+            ExposureSummary {
+                gross_mean: dec!(100),
+                gross_peak: dec!(300),
+                net_mean: dec!(-50),
+                net_peak: dec!(-300),
+            }
+        );
+    }
+}
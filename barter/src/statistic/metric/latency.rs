@@ -0,0 +1,343 @@
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    order::{
+        id::{ClientOrderId, OrderId},
+        request::OrderRequestOpen,
+        state::{ActiveOrderState, InactiveOrderState, OrderState},
+    },
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_integration::collection::FnvIndexMap;
+use chrono::{DateTime, Utc};
+use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Write, time::Duration};
+
+/// Number of linear sub-buckets per power of two, giving a maximum relative error of 1/16.
+const SUB_BUCKETS: u64 = 16;
+const SUB_BUCKET_BITS: u32 = SUB_BUCKETS.trailing_zeros();
+
+/// Streaming log-linear (HDR style) latency histogram with microsecond resolution.
+///
+/// Latencies are recorded into buckets whose width grows with the power of two of the latency,
+/// so memory is bounded regardless of the number of recorded latencies, and quantiles are
+/// accurate to within 1/16 (ie/ 6.25%).
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct LatencyHistogram {
+    pub count: u64,
+    pub max_micros: u64,
+    buckets: Vec<u64>,
+}
+
+impl LatencyHistogram {
+    /// Record the next latency.
+    pub fn record(&mut self, latency: Duration) {
+        let micros = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        let index = bucket_index(micros);
+
+        if self.buckets.len() <= index {
+            self.buckets.resize(index + 1, 0);
+        }
+
+        self.buckets[index] += 1;
+        self.count += 1;
+        self.max_micros = self.max_micros.max(micros);
+    }
+
+    /// Calculate the latency at the provided quantile (eg/ 0.99 for the 99th percentile).
+    ///
+    /// Returns `None` if no latencies have been recorded.
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = ((quantile.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).max(1);
+
+        let mut cumulative = 0;
+        self.buckets
+            .iter()
+            .enumerate()
+            .find_map(|(index, count)| {
+                cumulative += count;
+                (cumulative >= rank).then(|| bucket_upper(index).min(self.max_micros))
+            })
+            .map(Duration::from_micros)
+    }
+
+    /// Generate a [`LatencySummary`] of the recorded latencies.
+    pub fn summary(&self) -> LatencySummary {
+        LatencySummary {
+            count: self.count,
+            p50: self.quantile(0.5),
+            p90: self.quantile(0.9),
+            p99: self.quantile(0.99),
+            max: (self.count > 0).then(|| Duration::from_micros(self.max_micros)),
+        }
+    }
+}
+
+fn bucket_index(micros: u64) -> usize {
+    if micros < SUB_BUCKETS {
+        return micros as usize;
+    }
+
+    let shift = (u64::BITS - 1 - micros.leading_zeros()) - SUB_BUCKET_BITS;
+    let sub_bucket = (micros >> shift) - SUB_BUCKETS;
+
+    (SUB_BUCKETS * (u64::from(shift) + 1) + sub_bucket) as usize
+}
+
+fn bucket_upper(index: usize) -> u64 {
+    let index = index as u64;
+    if index < SUB_BUCKETS {
+        return index;
+    }
+
+    let shift = index / SUB_BUCKETS - 1;
+    let sub_bucket = index % SUB_BUCKETS;
+
+    ((SUB_BUCKETS + sub_bucket) << shift) + ((1 << shift) - 1)
+}
+
+/// Percentile summary of a [`LatencyHistogram`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub p50: Option<Duration>,
+    pub p90: Option<Duration>,
+    pub p99: Option<Duration>,
+    pub max: Option<Duration>,
+}
+
+/// Order round-trip [`LatencyHistogram`]s of an exchange.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct ExecutionLatency {
+    /// Latency between an open order request being sent and the exchange acknowledging it as
+    /// open.
+    pub ack: LatencyHistogram,
+
+    /// Latency between an open order being acknowledged and its first fill.
+    pub fill: LatencyHistogram,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+struct PendingOrder {
+    exchange: ExchangeIndex,
+    time_sent: DateTime<Utc>,
+    time_ack: Option<DateTime<Utc>>,
+}
+
+/// Tracks per-exchange order round-trip [`ExecutionLatency`] (request sent -> ack, and
+/// ack -> first fill), so execution quality degradation is detectable in live trading.
+///
+/// Latencies are measured using the provided (ie/ `Engine` clock) times that the request was
+/// sent and the `AccountEvent`s were received.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct LatencyTracker {
+    pub exchanges: FnvIndexMap<ExchangeIndex, ExecutionLatency>,
+    pending: FnvHashMap<ClientOrderId, PendingOrder>,
+    acked: FnvHashMap<OrderId, ClientOrderId>,
+}
+
+impl LatencyTracker {
+    /// Record the time the provided open order requests were sent.
+    pub fn record_sent<'a, Iter>(&mut self, requests: Iter, time: DateTime<Utc>)
+    where
+        Iter: IntoIterator<Item = &'a OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    {
+        for request in requests {
+            self.pending.insert(
+                request.key.cid.clone(),
+                PendingOrder {
+                    exchange: request.key.exchange,
+                    time_sent: time,
+                    time_ack: None,
+                },
+            );
+        }
+    }
+
+    /// Update the `LatencyTracker` from an [`AccountEvent`] received at the provided time.
+    pub fn update_from_account_event(&mut self, event: &AccountEvent, time: DateTime<Utc>) {
+        match &event.kind {
+            AccountEventKind::OrderSnapshot(snapshot) => {
+                let order = &snapshot.0;
+                match &order.state {
+                    OrderState::Active(ActiveOrderState::Open(open)) => {
+                        self.record_ack(&order.key.cid, &open.id, time)
+                    }
+                    OrderState::Inactive(InactiveOrderState::FullyFilled) => {}
+                    OrderState::Inactive(_) => self.remove(&order.key.cid),
+                    OrderState::Active(_) => {}
+                }
+            }
+            AccountEventKind::Trade(trade) => self.record_fill(&trade.order_id, time),
+            _ => {}
+        }
+    }
+
+    fn record_ack(&mut self, cid: &ClientOrderId, order_id: &OrderId, time: DateTime<Utc>) {
+        let Some(pending) = self.pending.get_mut(cid) else {
+            return;
+        };
+
+        if pending.time_ack.is_some() {
+            return;
+        }
+
+        pending.time_ack = Some(time);
+        self.acked.insert(order_id.clone(), cid.clone());
+        self.exchanges
+            .entry(pending.exchange)
+            .or_default()
+            .ack
+            .record(elapsed(pending.time_sent, time));
+    }
+
+    fn record_fill(&mut self, order_id: &OrderId, time: DateTime<Utc>) {
+        let Some(cid) = self.acked.remove(order_id) else {
+            return;
+        };
+
+        if let Some(PendingOrder {
+            exchange,
+            time_ack: Some(time_ack),
+            ..
+        }) = self.pending.remove(&cid)
+        {
+            self.exchanges
+                .entry(exchange)
+                .or_default()
+                .fill
+                .record(elapsed(time_ack, time));
+        }
+    }
+
+    fn remove(&mut self, cid: &ClientOrderId) {
+        if self.pending.remove(cid).is_some() {
+            self.acked.retain(|_, acked_cid| acked_cid != cid);
+        }
+    }
+
+    /// Encode the per-exchange [`LatencySummary`]s in the Prometheus text exposition format.
+    ///
+    /// The provided closure is used to determine the `exchange` label of each [`ExchangeIndex`].
+    pub fn encode_prometheus<FnLabel>(&self, exchange_label: FnLabel) -> String
+    where
+        FnLabel: Fn(&ExchangeIndex) -> String,
+    {
+        let metrics: [(&str, fn(&ExecutionLatency) -> &LatencyHistogram); 2] = [
+            ("barter_order_ack_latency_seconds", |latency| &latency.ack),
+            ("barter_order_fill_latency_seconds", |latency| &latency.fill),
+        ];
+
+        let mut output = String::new();
+        for (name, histogram) in metrics {
+            let _ = writeln!(output, "# TYPE {name} summary");
+
+            for (exchange, latency) in &self.exchanges {
+                let histogram = histogram(latency);
+                let exchange = exchange_label(exchange);
+
+                for quantile in [0.5, 0.9, 0.99] {
+                    if let Some(value) = histogram.quantile(quantile) {
+                        let _ = writeln!(
+                            output,
+                            "{name}{{exchange=\"{exchange}\",quantile=\"{quantile}\"}} {}",
+                            value.as_secs_f64()
+                        );
+                    }
+                }
+
+                let _ = writeln!(
+                    output,
+                    "{name}_count{{exchange=\"{exchange}\"}} {}",
+                    histogram.count
+                );
+            }
+        }
+
+        output
+    }
+}
+
+fn elapsed(from: DateTime<Utc>, to: DateTime<Utc>) -> Duration {
+    (to - from).to_std().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_histogram_quantile() {
+        struct TestCase {
+            latencies_micros: Vec<u64>,
+            quantile: f64,
+            expected_micros: Option<u64>,
+        }
+
+        let cases = vec![
+            // TC0: no latencies recorded
+            TestCase {
+                latencies_micros: vec![],
+                quantile: 0.5,
+                expected_micros: None,
+            },
+            // TC1: small latencies are recorded exactly
+            TestCase {
+                latencies_micros: vec![1, 2, 3, 4, 5],
+                quantile: 0.5,
+                expected_micros: Some(3),
+            },
+            // TC2: maximum quantile is the exact maximum latency
+            TestCase {
+                latencies_micros: vec![10, 1_000, 123_456],
+                quantile: 1.0,
+                expected_micros: Some(123_456),
+            },
+            // TC3: large latencies are accurate to within the bucket width
+            TestCase {
+                latencies_micros: vec![1_000, 1_000, 1_000, 1_000_000],
+                quantile: 0.5,
+                expected_micros: Some(1_023),
+            },
+            // TC4: 99th percentile of 100 latencies is the 99th smallest
+            TestCase {
+                latencies_micros: (1..=100).collect(),
+                quantile: 0.99,
+                expected_micros: Some(99),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut histogram = LatencyHistogram::default();
+            test.latencies_micros
+                .into_iter()
+                .for_each(|micros| histogram.record(Duration::from_micros(micros)));
+
+            let actual = histogram.quantile(test.quantile);
+            let expected = test.expected_micros.map(Duration::from_micros);
+            assert_eq!(actual, expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_bucket_index_upper_bound() {
+        for micros in (0..100_000).chain([u64::MAX / 2, u64::MAX]) {
+            let index = bucket_index(micros);
+            let upper = bucket_upper(index);
+            assert!(
+                upper >= micros,
+                "micros {micros} exceeds bucket upper {upper}"
+            );
+            assert!(
+                upper - micros <= micros / SUB_BUCKETS,
+                "micros {micros} bucket upper {upper} exceeds relative error"
+            );
+        }
+    }
+}
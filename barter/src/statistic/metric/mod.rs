@@ -4,17 +4,30 @@ pub mod calmar;
 /// Drawdown calculation logic.
 pub mod drawdown;
 
+/// Gross & net notional exposure time series, summarised by average and peak exposure.
+pub mod exposure;
+
+/// Streaming per-exchange order round-trip latency histograms, with percentile summaries.
+pub mod latency;
+
 /// Profit Factor calculation logic.
 pub mod profit_factor;
 
 /// Rate Of Return calculation logic.
 pub mod rate_of_return;
 
+/// Implementation shortfall of executed orders, decomposed into delay & execution costs.
+pub mod shortfall;
+
 /// Sharpe Ratio calculation logic.
 pub mod sharpe;
 
 /// Sortino Ratio calculation logic.
 pub mod sortino;
 
+/// Portfolio Value at Risk & Expected Shortfall calculation logic, using parametric or
+/// historical-simulation methods.
+pub mod value_at_risk;
+
 /// Win Rate calculation logic.
 pub mod win_rate;
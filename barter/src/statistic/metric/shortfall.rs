@@ -0,0 +1,361 @@
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    order::{
+        id::{ClientOrderId, OrderId},
+        request::OrderRequestOpen,
+        state::{ActiveOrderState, InactiveOrderState, OrderState},
+    },
+};
+use barter_instrument::{
+    Side,
+    exchange::ExchangeIndex,
+    instrument::{InstrumentIndex, name::InstrumentNameInternal},
+};
+use barter_integration::collection::FnvIndexMap;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Number of basis points in one (ie/ 100%).
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Implementation shortfall of executed orders, decomposed into delay and execution costs so
+/// strategy alpha can be separated from execution costs.
+///
+/// All costs are in quote asset units, where a positive cost is adverse (eg/ buying above the
+/// decision price).
+///
+/// See docs: <https://en.wikipedia.org/wiki/Implementation_shortfall>
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct ImplementationShortfall {
+    /// Number of recorded fills.
+    pub fills: u64,
+
+    /// Total absolute quantity filled.
+    pub quantity: Decimal,
+
+    /// Total absolute notional of the fills valued at the decision price (ie/ the paper
+    /// portfolio notional).
+    pub value_decision: Decimal,
+
+    /// Cost of the market moving between the decision and order arrival at the exchange.
+    pub delay_cost: Decimal,
+
+    /// Cost of filling away from the arrival price (eg/ spread, slippage & market impact).
+    pub execution_cost: Decimal,
+
+    /// Fees paid on the fills.
+    pub fees: Decimal,
+}
+
+impl ImplementationShortfall {
+    /// Record a fill of the provided [`Side`] and quantity.
+    ///
+    /// The decision price is the market price when the order was generated, and the arrival
+    /// price is the market price when the order was acknowledged by the exchange.
+    pub fn record(
+        &mut self,
+        side: Side,
+        quantity: Decimal,
+        price_decision: Decimal,
+        price_arrival: Decimal,
+        price_fill: Decimal,
+        fees: Decimal,
+    ) {
+        let quantity = quantity.abs();
+        let adverse = |from: Decimal, to: Decimal| match side {
+            Side::Buy => (to - from) * quantity,
+            Side::Sell => (from - to) * quantity,
+        };
+
+        self.fills += 1;
+        self.quantity += quantity;
+        self.value_decision += price_decision.abs() * quantity;
+        self.delay_cost += adverse(price_decision, price_arrival);
+        self.execution_cost += adverse(price_arrival, price_fill);
+        self.fees += fees;
+    }
+
+    /// Total implementation shortfall, ie/ `delay_cost + execution_cost + fees`.
+    pub fn total(&self) -> Decimal {
+        self.delay_cost + self.execution_cost + self.fees
+    }
+
+    /// Express the provided cost in basis points of the [`Self::value_decision`].
+    ///
+    /// Returns `None` if no notional has been filled.
+    pub fn to_bps(&self, cost: Decimal) -> Option<Decimal> {
+        (!self.value_decision.is_zero()).then(|| cost / self.value_decision * BPS_PER_UNIT)
+    }
+
+    /// Combine with another `ImplementationShortfall` (eg/ to aggregate instruments).
+    pub fn combine(self, other: &Self) -> Self {
+        Self {
+            fills: self.fills + other.fills,
+            quantity: self.quantity + other.quantity,
+            value_decision: self.value_decision + other.value_decision,
+            delay_cost: self.delay_cost + other.delay_cost,
+            execution_cost: self.execution_cost + other.execution_cost,
+            fees: self.fees + other.fees,
+        }
+    }
+}
+
+/// Implementation shortfall report, summarising the portfolio and per-instrument
+/// [`ImplementationShortfall`].
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct ShortfallReport {
+    pub total: ImplementationShortfall,
+    pub instruments: FnvIndexMap<InstrumentNameInternal, ImplementationShortfall>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+struct PendingOrder {
+    quantity_remaining: Decimal,
+    price_decision: Decimal,
+    price_arrival: Option<Decimal>,
+}
+
+/// Tracks the decision, arrival and fill prices of each order sent by the `Engine`, generating
+/// a per-instrument [`ImplementationShortfall`].
+///
+/// The decision price is the instrument market price when the open order request was
+/// generated, and the arrival price is the market price when the exchange acknowledged the
+/// order as open. If an order is filled without an acknowledgement (eg/ immediately filled
+/// market orders), the arrival price is the decision price.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct ShortfallTracker {
+    pub instruments: FnvIndexMap<InstrumentIndex, ImplementationShortfall>,
+    pending: FnvHashMap<ClientOrderId, PendingOrder>,
+    acked: FnvHashMap<OrderId, ClientOrderId>,
+}
+
+impl ShortfallTracker {
+    /// Record the decision price of the provided open order requests.
+    ///
+    /// The provided closure is used to determine the current market price of an instrument,
+    /// falling back to the order request price if unavailable.
+    pub fn record_sent<'a, Iter, FnPrice>(&mut self, requests: Iter, price_market: FnPrice)
+    where
+        Iter: IntoIterator<Item = &'a OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+        FnPrice: Fn(&InstrumentIndex) -> Option<Decimal>,
+    {
+        for request in requests {
+            self.pending.insert(
+                request.key.cid.clone(),
+                PendingOrder {
+                    quantity_remaining: request.state.quantity.abs(),
+                    price_decision: price_market(&request.key.instrument)
+                        .unwrap_or(request.state.price),
+                    price_arrival: None,
+                },
+            );
+        }
+    }
+
+    /// Update the `ShortfallTracker` from an [`AccountEvent`].
+    ///
+    /// The provided closure is used to determine the current market price of an instrument,
+    /// which is recorded as the arrival price of acknowledged orders.
+    pub fn update_from_account_event<FnPrice>(
+        &mut self,
+        event: &AccountEvent,
+        price_market: FnPrice,
+    ) where
+        FnPrice: Fn(&InstrumentIndex) -> Option<Decimal>,
+    {
+        match &event.kind {
+            AccountEventKind::OrderSnapshot(snapshot) => {
+                let order = &snapshot.0;
+                match &order.state {
+                    OrderState::Active(ActiveOrderState::Open(open)) => {
+                        let Some(pending) = self.pending.get_mut(&order.key.cid) else {
+                            return;
+                        };
+
+                        if pending.price_arrival.is_none() {
+                            pending.price_arrival = Some(
+                                price_market(&order.key.instrument)
+                                    .unwrap_or(pending.price_decision),
+                            );
+                            self.acked.insert(open.id.clone(), order.key.cid.clone());
+                        }
+                    }
+                    OrderState::Inactive(InactiveOrderState::FullyFilled) => {}
+                    OrderState::Inactive(_) => self.remove(&order.key.cid),
+                    OrderState::Active(_) => {}
+                }
+            }
+            AccountEventKind::Trade(trade) => {
+                let Some(cid) = trade
+                    .cid
+                    .clone()
+                    .or_else(|| self.acked.get(&trade.order_id).cloned())
+                else {
+                    return;
+                };
+
+                let Some(pending) = self.pending.get_mut(&cid) else {
+                    return;
+                };
+
+                self.instruments
+                    .entry(trade.instrument)
+                    .or_default()
+                    .record(
+                        trade.side,
+                        trade.quantity,
+                        pending.price_decision,
+                        pending.price_arrival.unwrap_or(pending.price_decision),
+                        trade.price,
+                        trade.fees.fees,
+                    );
+
+                pending.quantity_remaining -= trade.quantity.abs();
+                if pending.quantity_remaining <= Decimal::ZERO {
+                    self.remove(&cid);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn remove(&mut self, cid: &ClientOrderId) {
+        if self.pending.remove(cid).is_some() {
+            self.acked.retain(|_, acked_cid| acked_cid != cid);
+        }
+    }
+
+    /// Generate a [`ShortfallReport`] of the tracked [`ImplementationShortfall`]s.
+    ///
+    /// The provided closure is used to determine the name of each [`InstrumentIndex`].
+    pub fn report<FnName>(&self, instrument_name: FnName) -> ShortfallReport
+    where
+        FnName: Fn(&InstrumentIndex) -> InstrumentNameInternal,
+    {
+        ShortfallReport {
+            total: self
+                .instruments
+                .values()
+                .fold(ImplementationShortfall::default(), |total, shortfall| {
+                    total.combine(shortfall)
+                }),
+            instruments: self
+                .instruments
+                .iter()
+                .map(|(instrument, shortfall)| (instrument_name(instrument), *shortfall))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_implementation_shortfall_record() {
+        struct TestCase {
+            side: Side,
+            price_arrival: Decimal,
+            price_fill: Decimal,
+            expected_delay: Decimal,
+            expected_execution: Decimal,
+            expected_total_bps: Decimal,
+        }
+
+        // Decision price 100, quantity 2 & fees 0.1
+        let cases = vec![
+            // TC0: Buy filled at the decision price only costs fees
+            TestCase {
+                side: Side::Buy,
+                price_arrival: dec!(100),
+                price_fill: dec!(100),
+                expected_delay: dec!(0),
+                expected_execution: dec!(0),
+                expected_total_bps: dec!(5),
+            },
+            // TC1: Buy after the market moved up, filled above arrival
+            TestCase {
+                side: Side::Buy,
+                price_arrival: dec!(101),
+                price_fill: dec!(101.5),
+                expected_delay: dec!(2),
+                expected_execution: dec!(1),
+                expected_total_bps: dec!(155),
+            },
+            // TC2: Sell after the market moved up has negative delay cost
+            TestCase {
+                side: Side::Sell,
+                price_arrival: dec!(101),
+                price_fill: dec!(100.5),
+                expected_delay: dec!(-2),
+                expected_execution: dec!(1),
+                expected_total_bps: dec!(-45),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut shortfall = ImplementationShortfall::default();
+            shortfall.record(
+                test.side,
+                dec!(2),
+                dec!(100),
+                test.price_arrival,
+                test.price_fill,
+                dec!(0.1),
+            );
+
+            assert_eq!(
+                shortfall.delay_cost, test.expected_delay,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                shortfall.execution_cost, test.expected_execution,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                shortfall.to_bps(shortfall.total()),
+                Some(test.expected_total_bps),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_shortfall_tracker_report() {
+        let mut tracker = ShortfallTracker::default();
+        let btc = InstrumentIndex(0);
+        let eth = InstrumentIndex(1);
+
+        tracker.instruments.entry(btc).or_default().record(
+            Side::Buy,
+            dec!(1),
+            dec!(100),
+            dec!(101),
+            dec!(102),
+            dec!(0),
+        );
+        tracker.instruments.entry(eth).or_default().record(
+            Side::Sell,
+            dec!(2),
+            dec!(50),
+            dec!(50),
+            dec!(49),
+            dec!(1),
+        );
+
+        let report =
+            tracker.report(|instrument| InstrumentNameInternal::from(instrument.to_string()));
+
+        assert_eq!(report.instruments.len(), 2);
+        assert_eq!(report.total.fills, 2);
+        assert_eq!(report.total.value_decision, dec!(200));
+        assert_eq!(report.total.delay_cost, dec!(1));
+        assert_eq!(report.total.execution_cost, dec!(3));
+        assert_eq!(report.total.total(), dec!(5));
+    }
+}
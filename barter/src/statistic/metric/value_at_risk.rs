@@ -0,0 +1,402 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+    },
+    risk::check::util::calculate_delta,
+    strategy::library::PriceHistory,
+};
+use barter_instrument::instrument::InstrumentIndex;
+use barter_integration::collection::FnvIndexMap;
+use derive_more::Constructor;
+use rust_decimal::{
+    Decimal, MathematicalOps,
+    prelude::{FromPrimitive, ToPrimitive},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Method used to estimate [`ValueAtRisk`] from a distribution of PnL scenarios.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum VarMethod {
+    /// Empirical quantile of the PnL scenarios, making no distributional assumptions.
+    #[default]
+    Historical,
+
+    /// Normal distribution fitted to the mean and standard deviation of the PnL scenarios.
+    Parametric,
+}
+
+/// Configuration of a [`ValueAtRisk`] calculation.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct VarConfig {
+    /// Confidence level of the [`ValueAtRisk`] (eg/ 0.99 for 99%).
+    pub confidence: Decimal,
+
+    /// Number of most recent price returns used to generate PnL scenarios.
+    pub period: usize,
+
+    pub method: VarMethod,
+}
+
+/// Value at Risk (VaR) and Expected Shortfall (ES) of a portfolio, expressed as positive losses
+/// in quote asset units over a single return period.
+///
+/// - VaR: loss that is not exceeded with the configured confidence.
+/// - ES: average loss in the tail beyond the VaR (ie/ conditional VaR).
+///
+/// See docs: <https://www.investopedia.com/terms/c/conditional_value_at_risk.asp>
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct ValueAtRisk {
+    pub var: Decimal,
+    pub expected_shortfall: Decimal,
+}
+
+impl ValueAtRisk {
+    /// Calculate the [`ValueAtRisk`] of the provided PnL scenarios using the configured
+    /// [`VarMethod`].
+    pub fn calculate(pnls: &[Decimal], config: &VarConfig) -> Option<Self> {
+        match config.method {
+            VarMethod::Historical => Self::historical(pnls, config.confidence),
+            VarMethod::Parametric => Self::parametric(pnls, config.confidence),
+        }
+    }
+
+    /// Calculate the historical-simulation [`ValueAtRisk`] of the provided PnL scenarios.
+    ///
+    /// Returns `None` if there are no PnL scenarios, or the confidence is not between 0 and 1.
+    pub fn historical(pnls: &[Decimal], confidence: Decimal) -> Option<Self> {
+        if pnls.is_empty() || confidence <= Decimal::ZERO || confidence >= Decimal::ONE {
+            return None;
+        }
+
+        let mut sorted = pnls.to_vec();
+        sorted.sort();
+
+        let tail = ((Decimal::ONE - confidence) * Decimal::from(sorted.len()))
+            .ceil()
+            .to_usize()?
+            .clamp(1, sorted.len());
+
+        let tail_pnls = &sorted[..tail];
+        let tail_mean = tail_pnls.iter().sum::<Decimal>() / Decimal::from(tail);
+
+        Some(Self {
+            var: -tail_pnls[tail - 1],
+            expected_shortfall: -tail_mean,
+        })
+    }
+
+    /// Calculate the parametric (normal) [`ValueAtRisk`] of the provided PnL scenarios.
+    ///
+    /// Returns `None` if there are fewer than two PnL scenarios, or the confidence is not
+    /// between 0 and 1.
+    pub fn parametric(pnls: &[Decimal], confidence: Decimal) -> Option<Self> {
+        if pnls.len() < 2 || confidence <= Decimal::ZERO || confidence >= Decimal::ONE {
+            return None;
+        }
+
+        let count = Decimal::from(pnls.len());
+        let mean = pnls.iter().sum::<Decimal>() / count;
+        let std_dev = (pnls
+            .iter()
+            .map(|pnl| (pnl - mean) * (pnl - mean))
+            .sum::<Decimal>()
+            / (count - Decimal::ONE))
+            .sqrt()?;
+
+        let z = normal_quantile(confidence.to_f64()?);
+        let density = (-z * z / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let tail = (Decimal::ONE - confidence).to_f64()?;
+
+        Some(Self {
+            var: std_dev * Decimal::from_f64(z)? - mean,
+            expected_shortfall: std_dev * Decimal::from_f64(density / tail)? - mean,
+        })
+    }
+}
+
+/// Signed notional exposure of an instrument position, and its historical price returns.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize, Constructor)]
+pub struct InstrumentExposure {
+    /// Signed notional value in quote asset units, positive if LONG and negative if SHORT.
+    pub notional: Decimal,
+
+    /// Historical simple price returns, oldest first.
+    pub returns: Vec<Decimal>,
+}
+
+/// [`InstrumentExposure`]s of every open position, used to generate portfolio PnL scenarios.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct PortfolioExposures(pub FnvIndexMap<InstrumentIndex, InstrumentExposure>);
+
+impl PortfolioExposures {
+    /// Calculate the [`PortfolioExposures`] of all open positions in the [`EngineState`], using
+    /// the most recent `period` price returns of each instrument's [`PriceHistory`].
+    ///
+    /// Instruments with an open position but insufficient price history are included with no
+    /// returns, so the portfolio scenarios are only generated once every history is available.
+    pub fn calculate<GlobalData, InstrumentData>(
+        state: &EngineState<GlobalData, InstrumentData>,
+        period: usize,
+    ) -> Self
+    where
+        InstrumentData: InstrumentDataState + PriceHistory,
+    {
+        Self(
+            state
+                .instruments
+                .instruments(&InstrumentFilter::None)
+                .filter_map(|instrument_state| {
+                    let position = instrument_state.position.current.as_ref()?;
+                    let price = instrument_state.data.price()?;
+
+                    let notional = price
+                        * calculate_delta(
+                            Decimal::ONE,
+                            instrument_state.instrument.kind.contract_size(),
+                            position.side,
+                            position.quantity_abs,
+                        );
+
+                    let returns =
+                        price_returns(instrument_state.data.prices(), period).unwrap_or_default();
+
+                    Some((
+                        instrument_state.key,
+                        InstrumentExposure::new(notional, returns),
+                    ))
+                })
+                .collect(),
+        )
+    }
+
+    /// Generate the portfolio PnL scenario of each historical return period, applying every
+    /// instrument's historical returns to its current notional exposure.
+    ///
+    /// Scenarios are aligned to the most recent returns, and limited to the shortest return
+    /// history.
+    pub fn pnl_scenarios(&self) -> Vec<Decimal> {
+        let Some(periods) = self.0.values().map(|exposure| exposure.returns.len()).min() else {
+            return vec![];
+        };
+
+        (0..periods)
+            .map(|period| {
+                self.0
+                    .values()
+                    .map(|exposure| {
+                        let offset = exposure.returns.len() - periods;
+                        exposure.notional * exposure.returns[offset + period]
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Calculate the portfolio [`ValueAtRisk`] of the current exposures.
+    ///
+    /// Returns zero [`ValueAtRisk`] if there are no exposures.
+    pub fn value_at_risk(&self, config: &VarConfig) -> Option<ValueAtRisk> {
+        if self.0.is_empty() {
+            return Some(ValueAtRisk::default());
+        }
+
+        ValueAtRisk::calculate(&self.pnl_scenarios(), config)
+    }
+}
+
+/// Simple price returns of the most recent `period + 1` prices, oldest first.
+///
+/// Returns `None` if there are fewer than `period + 1` prices, or any price is zero.
+pub fn price_returns(prices: &VecDeque<Decimal>, period: usize) -> Option<Vec<Decimal>> {
+    if period == 0 || prices.len() < period + 1 {
+        return None;
+    }
+
+    prices
+        .iter()
+        .skip(prices.len() - (period + 1))
+        .zip(prices.iter().skip(prices.len() - period))
+        .map(|(previous, current)| (!previous.is_zero()).then(|| (current - previous) / previous))
+        .collect()
+}
+
+/// Inverse of the standard normal cumulative distribution function (ie/ z-score of the provided
+/// probability), accurate to approximately 1e-5.
+///
+/// See docs: <https://web.archive.org/web/20151030215612/http://home.online.no/~pjacklam/notes/invnorm/>
+fn normal_quantile(probability: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_614_481_341_324,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    if probability < P_LOW {
+        tail((-2.0 * probability.ln()).sqrt())
+    } else if probability <= 1.0 - P_LOW {
+        let q = probability - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -tail((-2.0 * (1.0 - probability).ln()).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_normal_quantile() {
+        for (probability, expected) in [
+            (0.5, 0.0),
+            (0.95, 1.644_853_627),
+            (0.99, 2.326_347_874),
+            (0.01, -2.326_347_874),
+            (0.999, 3.090_232_306),
+        ] {
+            let actual = normal_quantile(probability);
+            assert!(
+                (actual - expected).abs() < 1e-5,
+                "probability {probability}: {actual} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_value_at_risk_historical() {
+        struct TestCase {
+            pnls: Vec<Decimal>,
+            confidence: Decimal,
+            expected: Option<ValueAtRisk>,
+        }
+
+        // PnL scenarios -10, -9, ..., 9
+        let pnls = (-10..10).map(Decimal::from).collect::<Vec<_>>();
+
+        let cases = vec![
+            // TC0: no PnL scenarios
+            TestCase {
+                pnls: vec![],
+                confidence: dec!(0.95),
+                expected: None,
+            },
+            // TC1: 95% confidence of 20 scenarios is the worst scenario
+            TestCase {
+                pnls: pnls.clone(),
+                confidence: dec!(0.95),
+                expected: Some(ValueAtRisk {
+                    var: dec!(10),
+                    expected_shortfall: dec!(10),
+                }),
+            },
+            // TC2: 80% confidence of 20 scenarios averages the worst 4 scenarios
+            TestCase {
+                pnls: pnls.clone(),
+                confidence: dec!(0.8),
+                expected: Some(ValueAtRisk {
+                    var: dec!(7),
+                    expected_shortfall: dec!(8.5),
+                }),
+            },
+            // TC3: invalid confidence
+            TestCase {
+                pnls,
+                confidence: dec!(1),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = ValueAtRisk::historical(&test.pnls, test.confidence);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_value_at_risk_parametric() {
+        // Mean 0, sample standard deviation 10
+        let pnls = vec![dec!(-10), dec!(10), dec!(-10), dec!(10), dec!(0)];
+
+        let actual = ValueAtRisk::parametric(&pnls, dec!(0.95)).unwrap();
+
+        assert!((actual.var - dec!(16.448536)).abs() < dec!(0.0001));
+        assert!((actual.expected_shortfall - dec!(20.627128)).abs() < dec!(0.0001));
+        assert_eq!(ValueAtRisk::parametric(&pnls[..1], dec!(0.95)), None);
+    }
+
+    #[test]
+    fn test_portfolio_exposures_pnl_scenarios() {
+        let exposures = PortfolioExposures(FnvIndexMap::from_iter([
+            (
+                InstrumentIndex(0),
+                InstrumentExposure::new(dec!(100), vec![dec!(0.5), dec!(0.1), dec!(-0.2)]),
+            ),
+            (
+                InstrumentIndex(1),
+                InstrumentExposure::new(dec!(-50), vec![dec!(0.2), dec!(0.1)]),
+            ),
+        ]));
+
+        // Scenarios aligned to the most recent returns of each instrument
+        assert_eq!(exposures.pnl_scenarios(), vec![dec!(0), dec!(-25)]);
+
+        // Flat portfolio has no VaR
+        assert_eq!(
+            PortfolioExposures::default().value_at_risk(&VarConfig::new(
+                dec!(0.95),
+                10,
+                VarMethod::Historical
+            )),
+            Some(ValueAtRisk::default())
+        );
+    }
+
+    #[test]
+    fn test_price_returns() {
+        let prices = VecDeque::from([dec!(100), dec!(110), dec!(99), dec!(99)]);
+
+        assert_eq!(price_returns(&prices, 2), Some(vec![dec!(-0.1), dec!(0)]));
+        assert_eq!(price_returns(&prices, 4), None);
+    }
+}
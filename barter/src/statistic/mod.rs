@@ -5,11 +5,23 @@ pub mod algorithm;
 /// [`TimeIntervals`](time::TimeInterval).
 pub mod metric;
 
+/// Monte Carlo bootstrap analysis of closed-trade returns, generating distributions of final
+/// equity, maximum drawdown and risk-of-ruin.
+pub mod monte_carlo;
+
+/// Importers of historical exchange account statements (eg/ Binance trade history, Kraken
+/// ledger), used to bootstrap positions, statistics & tax-lots from prior trading.
+pub mod statement;
+
 /// Statistical summaries for financial datasets.
 ///
 /// For example, `TradingSummary`, `TearSheet`, `TearSheetAsset`, `PnLReturns`, etc.
 pub mod summary;
 
+/// FIFO & LIFO tax-lot accounting of spot fills, generating a realised gains report that can be
+/// exported to CSV.
+pub mod tax_lot;
+
 /// TimeInterval definitions used for financial calculations.
 ///
 /// For example, `Annual365`, `Annual252`, `Daily`, etc.
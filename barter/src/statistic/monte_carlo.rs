@@ -0,0 +1,263 @@
+use crate::engine::state::position::PositionExited;
+use derive_more::Constructor;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a Monte Carlo bootstrap analysis of closed-trade returns.
+///
+/// Each simulation resamples (with replacement) the closed-trade returns of a backtest to
+/// generate an alternative equity path of the same length, compounding each return.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct MonteCarloConfig {
+    /// Number of resampled equity paths to simulate.
+    pub simulations: usize,
+
+    /// Initial equity of each simulated equity path.
+    pub equity_initial: Decimal,
+
+    /// Fractional drawdown from peak equity that constitutes ruin (eg/ 0.5 for 50%).
+    pub ruin_drawdown: Decimal,
+
+    /// Confidence level of the generated [`ConfidenceInterval`]s (eg/ 0.95 for 95%).
+    pub confidence: Decimal,
+
+    /// Optional seed for reproducible simulations.
+    pub seed: Option<u64>,
+}
+
+/// Lower bound, median and upper bound of a simulated distribution at a confidence level.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ConfidenceInterval {
+    pub lower: Decimal,
+    pub median: Decimal,
+    pub upper: Decimal,
+}
+
+/// Summary of the equity path distributions generated by a Monte Carlo bootstrap analysis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct MonteCarloSummary {
+    /// Number of simulated equity paths.
+    pub simulations: usize,
+
+    /// Distribution of final equity.
+    pub equity_final: ConfidenceInterval,
+
+    /// Distribution of the fractional maximum drawdown from peak equity.
+    pub drawdown_max: ConfidenceInterval,
+
+    /// Fraction of simulated equity paths that reached the `ruin_drawdown`.
+    pub risk_of_ruin: Decimal,
+}
+
+/// Statistics of a single simulated equity path.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct EquityPath {
+    pub equity_final: Decimal,
+    pub drawdown_max: Decimal,
+}
+
+impl EquityPath {
+    /// Generate the `EquityPath` statistics from compounding the provided returns sequence onto
+    /// the initial equity.
+    pub fn generate(equity_initial: Decimal, returns: impl IntoIterator<Item = Decimal>) -> Self {
+        let (equity_final, _, drawdown_max) = returns.into_iter().fold(
+            (equity_initial, equity_initial, Decimal::ZERO),
+            |(equity, equity_peak, drawdown_max), ret| {
+                let equity = (equity * (Decimal::ONE + ret)).max(Decimal::ZERO);
+                let equity_peak = equity_peak.max(equity);
+
+                let drawdown = if equity_peak.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    (equity_peak - equity) / equity_peak
+                };
+
+                (equity, equity_peak, drawdown_max.max(drawdown))
+            },
+        );
+
+        Self {
+            equity_final,
+            drawdown_max,
+        }
+    }
+}
+
+impl MonteCarloConfig {
+    /// Run the Monte Carlo bootstrap analysis over the provided closed-trade returns.
+    ///
+    /// Returns `None` if there are no returns to resample, or no simulations are configured.
+    pub fn run(&self, returns: &[Decimal]) -> Option<MonteCarloSummary> {
+        if returns.is_empty() || self.simulations == 0 {
+            return None;
+        }
+
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+
+        let paths = (0..self.simulations)
+            .map(|_| {
+                let resampled =
+                    (0..returns.len()).map(|_| returns[rng.random_range(0..returns.len())]);
+                EquityPath::generate(self.equity_initial, resampled)
+            })
+            .collect::<Vec<_>>();
+
+        let ruined = paths
+            .iter()
+            .filter(|path| path.drawdown_max >= self.ruin_drawdown)
+            .count();
+
+        Some(MonteCarloSummary {
+            simulations: self.simulations,
+            equity_final: self.confidence_interval(paths.iter().map(|path| path.equity_final))?,
+            drawdown_max: self.confidence_interval(paths.iter().map(|path| path.drawdown_max))?,
+            risk_of_ruin: Decimal::from(ruined) / Decimal::from(self.simulations),
+        })
+    }
+
+    fn confidence_interval(
+        &self,
+        values: impl IntoIterator<Item = Decimal>,
+    ) -> Option<ConfidenceInterval> {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort();
+
+        let tail = (Decimal::ONE - self.confidence) / Decimal::TWO;
+
+        Some(ConfidenceInterval {
+            lower: percentile(&values, tail)?,
+            median: percentile(&values, Decimal::new(5, 1))?,
+            upper: percentile(&values, Decimal::ONE - tail)?,
+        })
+    }
+}
+
+/// Calculate the nearest-rank percentile (eg/ 0.95 for the 95th percentile) of the provided
+/// ascending sorted values.
+///
+/// Returns `None` if there are no values.
+pub fn percentile(sorted: &[Decimal], quantile: Decimal) -> Option<Decimal> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = (quantile.clamp(Decimal::ZERO, Decimal::ONE) * Decimal::from(last)).round();
+    let index = rank.to_usize().unwrap_or(last).min(last);
+    sorted.get(index).copied()
+}
+
+/// Calculate the fractional return of each closed-trade [`PositionExited`] (ie/ `pnl_realised`
+/// relative to the maximum entry notional).
+pub fn trade_returns<'a, AssetKey, InstrumentKey>(
+    positions: impl IntoIterator<Item = &'a PositionExited<AssetKey, InstrumentKey>>,
+) -> Vec<Decimal>
+where
+    AssetKey: 'a,
+    InstrumentKey: 'a,
+{
+    positions
+        .into_iter()
+        .filter_map(|position| {
+            let notional = position.price_entry_average * position.quantity_abs_max;
+            (!notional.is_zero()).then(|| position.pnl_realised / notional)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_equity_path_generate() {
+        struct TestCase {
+            returns: Vec<Decimal>,
+            expected: EquityPath,
+        }
+
+        let cases = vec![
+            // TC0: no returns
+            TestCase {
+                returns: vec![],
+                expected: EquityPath {
+                    equity_final: dec!(100),
+                    drawdown_max: dec!(0),
+                },
+            },
+            // TC1: only positive returns
+            TestCase {
+                returns: vec![dec!(0.1), dec!(0.1)],
+                expected: EquityPath {
+                    equity_final: dec!(121),
+                    drawdown_max: dec!(0),
+                },
+            },
+            // TC2: drawdown from peak equity
+            TestCase {
+                returns: vec![dec!(0.25), dec!(-0.2), dec!(-0.5), dec!(1)],
+                expected: EquityPath {
+                    equity_final: dec!(100),
+                    drawdown_max: dec!(0.6),
+                },
+            },
+            // TC3: equity cannot fall below zero
+            TestCase {
+                returns: vec![dec!(-1.5)],
+                expected: EquityPath {
+                    equity_final: dec!(0),
+                    drawdown_max: dec!(1),
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = EquityPath::generate(dec!(100), test.returns);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted = vec![dec!(1), dec!(2), dec!(3), dec!(4), dec!(5)];
+
+        assert_eq!(percentile(&sorted, dec!(0)), Some(dec!(1)));
+        assert_eq!(percentile(&sorted, dec!(0.5)), Some(dec!(3)));
+        assert_eq!(percentile(&sorted, dec!(1)), Some(dec!(5)));
+        assert_eq!(percentile(&sorted, dec!(2)), Some(dec!(5)));
+        assert_eq!(percentile(&[], dec!(0.5)), None);
+    }
+
+    #[test]
+    fn test_monte_carlo_config_run() {
+        let config = MonteCarloConfig::new(100, dec!(100), dec!(0.5), dec!(0.95), Some(42));
+
+        // Resampling a single return always generates the same equity path
+        let actual = config.run(&[dec!(-0.5)]).unwrap();
+        let expected = MonteCarloSummary {
+            simulations: 100,
+            equity_final: ConfidenceInterval {
+                lower: dec!(50),
+                median: dec!(50),
+                upper: dec!(50),
+            },
+            drawdown_max: ConfidenceInterval {
+                lower: dec!(0.5),
+                median: dec!(0.5),
+                upper: dec!(0.5),
+            },
+            risk_of_ruin: dec!(1),
+        };
+        assert_eq!(actual, expected);
+
+        // Seeded simulations are reproducible
+        let returns = [dec!(0.1), dec!(-0.05), dec!(0.02), dec!(-0.2)];
+        assert_eq!(config.run(&returns), config.run(&returns));
+
+        // No returns to resample
+        assert_eq!(config.run(&[]), None);
+    }
+}
@@ -0,0 +1,491 @@
+use crate::engine::{
+    Processor,
+    state::{EngineState, position::PositionExited},
+};
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    order::id::{OrderId, StrategyId},
+    trade::{AssetFees, Trade, TradeId},
+};
+use barter_instrument::{
+    Side,
+    asset::{QuoteAsset, name::AssetNameExchange},
+    exchange::ExchangeIndex,
+    instrument::name::InstrumentNameExchange,
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use thiserror::Error;
+use tracing::warn;
+
+/// Exchange [`Trade`] imported from a historical account statement, keyed on the exchange
+/// instrument name.
+pub type StatementTrade = Trade<QuoteAsset, InstrumentNameExchange>;
+
+/// All errors generated when importing a historical account statement.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum StatementError {
+    #[error("statement is missing column: {0}")]
+    MissingColumn(String),
+
+    #[error("statement line {line} is invalid: {reason}")]
+    InvalidLine { line: usize, reason: String },
+
+    #[error("statement trade {0} has an unmatched ledger entry")]
+    UnmatchedEntry(String),
+
+    #[error("statement instrument {0} is not tracked by the EngineState")]
+    UnknownInstrument(InstrumentNameExchange),
+}
+
+/// Parse a Binance spot trade history CSV export.
+///
+/// Expects the columns `Date(UTC)`, `Pair`, `Side`, `Price`, `Executed`, `Amount` and `Fee`,
+/// where the `Executed`, `Amount` & `Fee` values are suffixed with their asset
+/// (eg/ "0.001BTC"). Fees paid in an asset other than the base or quote asset (eg/ BNB) cannot
+/// be converted to the quote asset, so are ignored.
+///
+/// Returned trades are ordered by time, oldest first.
+pub fn parse_binance_trade_history(csv: &str) -> Result<Vec<StatementTrade>, StatementError> {
+    let rows = CsvRows::new(csv)?;
+    let [time, pair, side, price, executed, amount, fee] = rows.columns([
+        "Date(UTC)",
+        "Pair",
+        "Side",
+        "Price",
+        "Executed",
+        "Amount",
+        "Fee",
+    ])?;
+
+    let mut trades = rows
+        .map(|(line, fields)| {
+            let invalid = |reason: &str| StatementError::InvalidLine {
+                line,
+                reason: reason.to_string(),
+            };
+
+            let side = match fields[side].to_ascii_uppercase().as_str() {
+                "BUY" => Side::Buy,
+                "SELL" => Side::Sell,
+                _ => return Err(invalid("invalid Side")),
+            };
+            let price = parse_decimal(fields[price]).ok_or_else(|| invalid("invalid Price"))?;
+            let (quantity, base) =
+                parse_amount(fields[executed]).ok_or_else(|| invalid("invalid Executed"))?;
+            let (_, quote) =
+                parse_amount(fields[amount]).ok_or_else(|| invalid("invalid Amount"))?;
+            let (fee, fee_asset) =
+                parse_amount(fields[fee]).ok_or_else(|| invalid("invalid Fee"))?;
+
+            let fees = if fee_asset == quote {
+                fee
+            } else if fee_asset == base {
+                fee * price
+            } else {
+                warn!(line, %fee_asset, "Binance statement fee asset is not convertible - ignoring");
+                Decimal::ZERO
+            };
+
+            Ok(statement_trade(
+                format!("binance-{line}"),
+                InstrumentNameExchange::new(fields[pair]),
+                parse_time(fields[time]).ok_or_else(|| invalid("invalid Date(UTC)"))?,
+                side,
+                price,
+                quantity,
+                fees,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    trades.sort_by_key(|trade| trade.time_exchange);
+    Ok(trades)
+}
+
+/// Parse a Kraken ledger CSV export, reconstructing a trade from each pair of `trade` ledger
+/// entries that share a `refid`.
+///
+/// Expects the columns `refid`, `time`, `type`, `asset`, `amount` and `fee`. Non-trade entries
+/// (eg/ deposits & withdrawals) are skipped.
+///
+/// The provided closure is used to determine the instrument traded from the (base, quote)
+/// assets of the entry pair, returning `None` if the assets are the other way around.
+///
+/// Returned trades are ordered by time, oldest first.
+pub fn parse_kraken_ledger<FnInstrument>(
+    csv: &str,
+    instrument: FnInstrument,
+) -> Result<Vec<StatementTrade>, StatementError>
+where
+    FnInstrument: Fn(&AssetNameExchange, &AssetNameExchange) -> Option<InstrumentNameExchange>,
+{
+    struct Entry {
+        time: DateTime<Utc>,
+        asset: AssetNameExchange,
+        amount: Decimal,
+        fee: Decimal,
+    }
+
+    let rows = CsvRows::new(csv)?;
+    let [refid, time, kind, asset, amount, fee] =
+        rows.columns(["refid", "time", "type", "asset", "amount", "fee"])?;
+
+    let mut entries = FnvHashMap::<String, Vec<Entry>>::default();
+    let mut refids = Vec::new();
+    for (line, fields) in rows {
+        if fields[kind] != "trade" {
+            continue;
+        }
+
+        let invalid = |reason: &str| StatementError::InvalidLine {
+            line,
+            reason: reason.to_string(),
+        };
+
+        let entry = Entry {
+            time: parse_time(fields[time]).ok_or_else(|| invalid("invalid time"))?,
+            asset: AssetNameExchange::new(fields[asset]),
+            amount: parse_decimal(fields[amount]).ok_or_else(|| invalid("invalid amount"))?,
+            fee: parse_decimal(fields[fee]).ok_or_else(|| invalid("invalid fee"))?,
+        };
+
+        let refid = fields[refid].to_string();
+        if !entries.contains_key(&refid) {
+            refids.push(refid.clone());
+        }
+        entries.entry(refid).or_default().push(entry);
+    }
+
+    let mut trades = refids
+        .into_iter()
+        .map(|refid| {
+            let [a, b] = <[Entry; 2]>::try_from(entries.remove(&refid).unwrap_or_default())
+                .map_err(|_| StatementError::UnmatchedEntry(refid.clone()))?;
+
+            let (name, base, quote) = match instrument(&a.asset, &b.asset) {
+                Some(name) => (name, a, b),
+                None => match instrument(&b.asset, &a.asset) {
+                    Some(name) => (name, b, a),
+                    None => return Err(StatementError::UnmatchedEntry(refid)),
+                },
+            };
+
+            let quantity = base.amount.abs();
+            if quantity.is_zero() {
+                return Err(StatementError::UnmatchedEntry(refid));
+            }
+            let price = quote.amount.abs() / quantity;
+
+            Ok(statement_trade(
+                refid,
+                name,
+                base.time,
+                if base.amount > Decimal::ZERO {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                },
+                price,
+                quantity,
+                quote.fee + base.fee * price,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    trades.sort_by_key(|trade| trade.time_exchange);
+    Ok(trades)
+}
+
+/// Replay imported [`StatementTrade`]s of the provided exchange through the [`EngineState`],
+/// reconstructing the instrument `Position`s, and returning every [`PositionExited`].
+///
+/// The returned [`PositionExited`]s can be used to bootstrap trading statistics, and the
+/// [`StatementTrade`]s used directly to bootstrap a
+/// [`TaxLotTracker`](super::tax_lot::TaxLotTracker).
+pub fn replay_statement<GlobalData, InstrumentData>(
+    state: &mut EngineState<GlobalData, InstrumentData>,
+    exchange: ExchangeIndex,
+    trades: &[StatementTrade],
+) -> Result<Vec<PositionExited<QuoteAsset>>, StatementError>
+where
+    GlobalData: for<'a> Processor<&'a AccountEvent>,
+    InstrumentData: for<'a> Processor<&'a AccountEvent>,
+{
+    let mut exited = Vec::new();
+
+    for trade in trades {
+        let key = state
+            .instruments
+            .0
+            .values()
+            .find(|instrument_state| {
+                instrument_state.instrument.exchange == exchange
+                    && instrument_state.instrument.name_exchange == trade.instrument
+            })
+            .map(|instrument_state| instrument_state.key)
+            .ok_or_else(|| StatementError::UnknownInstrument(trade.instrument.clone()))?;
+
+        let event = AccountEvent::new(
+            exchange,
+            AccountEventKind::Trade(Trade {
+                id: trade.id.clone(),
+                order_id: trade.order_id.clone(),
+                cid: None,
+                instrument: key,
+                strategy: trade.strategy.clone(),
+                time_exchange: trade.time_exchange,
+                side: trade.side,
+                price: trade.price,
+                quantity: trade.quantity,
+                liquidity: None,
+                fees: trade.fees.clone(),
+            }),
+        );
+
+        exited.extend(state.update_from_account(&event));
+    }
+
+    Ok(exited)
+}
+
+fn statement_trade(
+    id: String,
+    instrument: InstrumentNameExchange,
+    time_exchange: DateTime<Utc>,
+    side: Side,
+    price: Decimal,
+    quantity: Decimal,
+    fees: Decimal,
+) -> StatementTrade {
+    Trade {
+        id: TradeId::new(id.as_str()),
+        order_id: OrderId::new(id.as_str()),
+        cid: None,
+        instrument,
+        strategy: StrategyId::unknown(),
+        time_exchange,
+        side,
+        price,
+        quantity,
+        liquidity: None,
+        fees: AssetFees::new(QuoteAsset, fees),
+    }
+}
+
+/// Lines of a simple CSV document, where fields are optionally wrapped in double quotes but
+/// never contain commas.
+struct CsvRows<'a> {
+    header: Vec<&'a str>,
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+}
+
+impl<'a> CsvRows<'a> {
+    fn new(csv: &'a str) -> Result<Self, StatementError> {
+        let mut lines = csv.lines().enumerate();
+        let header = lines
+            .next()
+            .map(|(_, header)| split_fields(header))
+            .ok_or_else(|| StatementError::MissingColumn("header".to_string()))?;
+
+        Ok(Self { header, lines })
+    }
+
+    fn columns<const N: usize>(&self, names: [&str; N]) -> Result<[usize; N], StatementError> {
+        let mut columns = [0; N];
+        for (column, name) in columns.iter_mut().zip(names) {
+            *column = self
+                .header
+                .iter()
+                .position(|header| header.eq_ignore_ascii_case(name))
+                .ok_or_else(|| StatementError::MissingColumn(name.to_string()))?;
+        }
+        Ok(columns)
+    }
+}
+
+impl<'a> Iterator for CsvRows<'a> {
+    /// Line number (1-based) and fields of the next non-empty row.
+    type Item = (usize, Vec<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.find_map(|(index, line)| {
+            (!line.trim().is_empty()).then(|| {
+                let mut fields = split_fields(line);
+                fields.resize(self.header.len(), "");
+                (index + 1, fields)
+            })
+        })
+    }
+}
+
+fn split_fields(line: &str) -> Vec<&str> {
+    line.split(',')
+        .map(|field| field.trim().trim_matches('"'))
+        .collect()
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .map(|time| time.and_utc())
+}
+
+fn parse_decimal(value: &str) -> Option<Decimal> {
+    Decimal::from_str(value)
+        .or_else(|_| Decimal::from_scientific(value))
+        .ok()
+}
+
+/// Parse an asset suffixed amount (eg/ "0.001BTC").
+fn parse_amount(value: &str) -> Option<(Decimal, AssetNameExchange)> {
+    let split = value
+        .find(|char: char| !(char.is_ascii_digit() || char == '.' || char == '-'))
+        .unwrap_or(value.len());
+    let (amount, asset) = value.split_at(split);
+    Some((parse_decimal(amount)?, AssetNameExchange::new(asset)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::state::{
+        global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+    };
+    use barter_instrument::{
+        Underlying, exchange::ExchangeId, index::IndexedInstruments, instrument::Instrument,
+    };
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parse_binance_trade_history() {
+        let csv = "\
+Date(UTC),Pair,Side,Price,Executed,Amount,Fee
+2024-01-02 00:00:00,BTCUSDT,SELL,110,1BTC,110USDT,0.11USDT
+2024-01-01 00:00:00,BTCUSDT,BUY,100,2BTC,200USDT,0.002BTC
+2024-01-01 12:00:00,BTCUSDT,BUY,105,1BTC,105USDT,0.01BNB
+";
+
+        let actual = parse_binance_trade_history(csv)
+            .unwrap()
+            .into_iter()
+            .map(|trade| (trade.side, trade.price, trade.quantity, trade.fees.fees))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (Side::Buy, dec!(100), dec!(2), dec!(0.2)),
+                (Side::Buy, dec!(105), dec!(1), dec!(0)),
+                (Side::Sell, dec!(110), dec!(1), dec!(0.11)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_kraken_ledger() {
+        struct TestCase {
+            csv: &'static str,
+            expected: Result<Vec<(Side, Decimal, Decimal, Decimal)>, StatementError>,
+        }
+
+        let header =
+            r#""txid","refid","time","type","subtype","aclass","asset","amount","fee","balance""#;
+        let instrument = |base: &AssetNameExchange, quote: &AssetNameExchange| {
+            (base.as_ref() == "XXBT" && quote.as_ref() == "ZUSD")
+                .then(|| InstrumentNameExchange::new("XBTUSD"))
+        };
+
+        let cases = vec![
+            // TC0: buy & sell trades reconstructed from ledger entry pairs, skipping deposits
+            TestCase {
+                csv: r#""L1","D1","2024-01-01 00:00:00","deposit","","currency","ZUSD","1000","0","1000"
+"L2","T1","2024-01-01 01:00:00","trade","","currency","ZUSD","-200","0.4","800"
+"L3","T1","2024-01-01 01:00:00","trade","","currency","XXBT","2","0","2"
+"L4","T2","2024-01-02 00:00:00","trade","","currency","XXBT","-1","0.001","1"
+"L5","T2","2024-01-02 00:00:00","trade","","currency","ZUSD","110","0","910""#,
+                expected: Ok(vec![
+                    (Side::Buy, dec!(100), dec!(2), dec!(0.4)),
+                    (Side::Sell, dec!(110), dec!(1), dec!(0.11)),
+                ]),
+            },
+            // TC1: trade with a single ledger entry is unmatched
+            TestCase {
+                csv: r#""L1","T1","2024-01-01 00:00:00","trade","","currency","XXBT","2","0","2""#,
+                expected: Err(StatementError::UnmatchedEntry("T1".to_string())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                parse_kraken_ledger(&format!("{header}\n{}", test.csv), instrument).map(|trades| {
+                    trades
+                        .into_iter()
+                        .map(|trade| (trade.side, trade.price, trade.quantity, trade.fees.fees))
+                        .collect::<Vec<_>>()
+                });
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_replay_statement() {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        let mut state = EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        let trades = parse_binance_trade_history(
+            "\
+Date(UTC),Pair,Side,Price,Executed,Amount,Fee
+2024-01-01 00:00:00,BTCUSDT,BUY,100,2BTC,200USDT,0USDT
+2024-01-02 00:00:00,BTCUSDT,SELL,110,2BTC,220USDT,0USDT
+2024-01-03 00:00:00,BTCUSDT,BUY,120,1BTC,120USDT,0USDT
+",
+        )
+        .unwrap();
+
+        let exited = replay_statement(&mut state, ExchangeIndex(0), &trades).unwrap();
+        assert_eq!(exited.len(), 1);
+        assert_eq!(exited[0].pnl_realised, dec!(20));
+
+        let position = state
+            .instruments
+            .0
+            .values()
+            .next()
+            .unwrap()
+            .position
+            .current
+            .as_ref()
+            .unwrap();
+        assert_eq!((position.side, position.quantity_abs), (Side::Buy, dec!(1)));
+
+        // Trades of an untracked instrument cannot be replayed
+        let mut unknown = trades[0].clone();
+        unknown.instrument = InstrumentNameExchange::new("ETHUSDT");
+        assert_eq!(
+            replay_statement(&mut state, ExchangeIndex(0), &[unknown]),
+            Err(StatementError::UnknownInstrument(
+                InstrumentNameExchange::new("ETHUSDT")
+            ))
+        );
+    }
+}
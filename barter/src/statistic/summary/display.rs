@@ -1,4 +1,5 @@
 use crate::statistic::{
+    metric::shortfall::ImplementationShortfall,
     summary::{TradingSummary, asset::TearSheetAsset, instrument::TearSheet},
     time::TimeInterval,
 };
@@ -14,6 +15,12 @@ where
         self.title_table().printstd();
         self.instrument_table().printstd();
         self.asset_table().printstd();
+        if let Some(table) = self.exposure_table() {
+            table.printstd();
+        }
+        if let Some(table) = self.shortfall_table() {
+            table.printstd();
+        }
     }
     fn title_table(&self) -> Table {
         let mut title_table = Table::new();
@@ -190,6 +197,12 @@ where
                 "N/A".to_string()
             }
         });
+        self.add_instrument_metric_row(&mut table, "MAE Avg", |ts| {
+            format!("{:.4}", ts.excursion_adverse_mean)
+        });
+        self.add_instrument_metric_row(&mut table, "MFE Avg", |ts| {
+            format!("{:.4}", ts.excursion_favourable_mean)
+        });
 
         table
     }
@@ -279,6 +292,90 @@ where
         table
     }
 
+    pub fn exposure_table(&self) -> Option<Table> {
+        let exposure = self.exposure.as_ref()?;
+        let mut table = Table::new();
+
+        // Styling
+        table.set_format(*prettytable::format::consts::FORMAT_BOX_CHARS);
+
+        // Title row spanning all columns
+        let mut title_row = Row::new(vec![]);
+        let mut title_cell = Cell::new("Exposure").style_spec("bcB");
+        title_cell.set_hspan(3);
+        title_row.add_cell(title_cell);
+        table.add_row(title_row);
+
+        // Header row
+        table.add_row(Row::new(vec![
+            Cell::new("").style_spec("bcB"),
+            Cell::new("Gross").style_spec("bcB"),
+            Cell::new("Net").style_spec("bcB"),
+        ]));
+
+        // Add metric rows
+        for (label, gross, net) in [
+            ("Average", exposure.gross_mean, exposure.net_mean),
+            ("Peak", exposure.gross_peak, exposure.net_peak),
+        ] {
+            table.add_row(Row::new(vec![
+                Cell::new(label).style_spec("bcB"),
+                Cell::new(&format!("{:.2}", gross)),
+                Cell::new(&format!("{:.2}", net)),
+            ]));
+        }
+
+        Some(table)
+    }
+
+    pub fn shortfall_table(&self) -> Option<Table> {
+        let shortfall = self.shortfall.as_ref()?;
+        let mut table = Table::new();
+
+        // Styling
+        table.set_format(*prettytable::format::consts::FORMAT_BOX_CHARS);
+
+        // Title row spanning all columns
+        let mut title_row = Row::new(vec![]);
+        let mut title_cell = Cell::new("Implementation Shortfall").style_spec("bcB");
+        title_cell.set_hspan(shortfall.instruments.len() + 2);
+        title_row.add_cell(title_cell);
+        table.add_row(title_row);
+
+        // Header row
+        let mut header = Row::new(vec![Cell::new("").style_spec("bcB")]);
+        for instrument in shortfall.instruments.keys() {
+            header.add_cell(Cell::new(instrument.as_ref()).style_spec("bcB"));
+        }
+        header.add_cell(Cell::new("Total").style_spec("bcB"));
+        table.add_row(header);
+
+        // Add metric rows, with costs in basis points of the decision notional
+        let metrics: [(&str, fn(&ImplementationShortfall) -> Decimal); 4] = [
+            ("Delay (bps)", |shortfall| shortfall.delay_cost),
+            ("Execution (bps)", |shortfall| shortfall.execution_cost),
+            ("Fees (bps)", |shortfall| shortfall.fees),
+            ("Total (bps)", ImplementationShortfall::total),
+        ];
+
+        for (label, cost) in metrics {
+            let mut row = Row::new(vec![Cell::new(label).style_spec("bcB")]);
+            for instrument in shortfall
+                .instruments
+                .values()
+                .chain(std::iter::once(&shortfall.total))
+            {
+                let bps = instrument
+                    .to_bps(cost(instrument))
+                    .map_or_else(|| "N/A".to_string(), |bps| format!("{:.2}", bps));
+                row.add_cell(Cell::new(&bps));
+            }
+            table.add_row(row);
+        }
+
+        Some(table)
+    }
+
     fn add_asset_metric_row<F>(&self, table: &mut Table, label: &str, format_value: F)
     where
         F: Fn(&TearSheetAsset) -> String,
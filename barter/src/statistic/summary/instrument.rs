@@ -1,6 +1,6 @@
 use crate::{
     Timed,
-    engine::state::position::PositionExited,
+    engine::state::position::{PositionExited, calculate_pnl_return},
     statistic::{
         metric::{
             calmar::CalmarRatio,
@@ -15,7 +15,10 @@ use crate::{
             sortino::SortinoRatio,
             win_rate::WinRate,
         },
-        summary::pnl::PnLReturns,
+        summary::{
+            dataset::DataSetSummary,
+            pnl::{PeriodReturns, PnLReturns, ReturnsInterval},
+        },
         time::TimeInterval,
     },
 };
@@ -34,8 +37,14 @@ pub struct TearSheet<Interval> {
     pub pnl_drawdown: Option<Drawdown>,
     pub pnl_drawdown_mean: Option<MeanDrawdown>,
     pub pnl_drawdown_max: Option<MaxDrawdown>,
+    /// Series of all completed PnL [`Drawdown`] periods, oldest first.
+    pub pnl_drawdowns: Vec<Drawdown>,
     pub win_rate: Option<WinRate>,
     pub profit_factor: Option<ProfitFactor>,
+    /// Mean maximum adverse excursion (MAE) price distance across all exited positions.
+    pub excursion_adverse_mean: Decimal,
+    /// Mean maximum favourable excursion (MFE) price distance across all exited positions.
+    pub excursion_favourable_mean: Decimal,
 }
 
 /// Generator for a [`TearSheet`].
@@ -48,9 +57,23 @@ pub struct TearSheetGenerator {
     pub time_engine_now: DateTime<Utc>,
 
     pub pnl_returns: PnLReturns,
+
+    /// PnL returns summed into hourly periods, used to generate [`ReturnsInterval`] period
+    /// risk-adjusted return statistics.
+    pub pnl_returns_period: PeriodReturns,
+
     pub pnl_drawdown: DrawdownGenerator,
     pub pnl_drawdown_mean: MeanDrawdownGenerator,
     pub pnl_drawdown_max: MaxDrawdownGenerator,
+
+    /// Series of all completed PnL [`Drawdown`] periods, oldest first.
+    pub pnl_drawdowns: Vec<Drawdown>,
+
+    /// Maximum adverse excursion (MAE) statistical summary of all exited positions.
+    pub excursion_adverse: DataSetSummary,
+
+    /// Maximum favourable excursion (MFE) statistical summary of all exited positions.
+    pub excursion_favourable: DataSetSummary,
 }
 
 impl TearSheetGenerator {
@@ -60,9 +83,13 @@ impl TearSheetGenerator {
             time_engine_start,
             time_engine_now: time_engine_start,
             pnl_returns: PnLReturns::default(),
+            pnl_returns_period: PeriodReturns::default(),
             pnl_drawdown: DrawdownGenerator::default(),
             pnl_drawdown_mean: MeanDrawdownGenerator::default(),
             pnl_drawdown_max: MaxDrawdownGenerator::default(),
+            pnl_drawdowns: Vec::new(),
+            excursion_adverse: DataSetSummary::default(),
+            excursion_favourable: DataSetSummary::default(),
         }
     }
 
@@ -73,6 +100,18 @@ impl TearSheetGenerator {
     ) {
         self.time_engine_now = position.time_exit;
         self.pnl_returns.update(position);
+        self.pnl_returns_period.update(
+            self.time_engine_now - self.time_engine_start,
+            calculate_pnl_return(
+                position.pnl_realised,
+                position.price_entry_average,
+                position.quantity_abs_max,
+            ),
+        );
+        self.excursion_adverse
+            .update(position.excursion_adverse_max);
+        self.excursion_favourable
+            .update(position.excursion_favourable_max);
 
         if let Some(next_drawdown) = self
             .pnl_drawdown
@@ -80,16 +119,22 @@ impl TearSheetGenerator {
         {
             self.pnl_drawdown_mean.update(&next_drawdown);
             self.pnl_drawdown_max.update(&next_drawdown);
+            self.pnl_drawdowns.push(next_drawdown);
         }
     }
 
     /// Generate the latest [`TearSheet`] at the specific [`TimeInterval`].
     ///
+    /// Risk-adjusted return statistics are calculated from [`ReturnsInterval`] returns, with the
+    /// `risk_free_return` expressed per returns interval, before being scaled to the provided
+    /// [`TimeInterval`].
+    ///
     /// For example, pass [`Annual365`](super::super::time::Annual365) to generate a crypto-centric
     /// (24/7 trading) annualised [`TearSheet`].
     pub fn generate<Interval>(
         &mut self,
         risk_free_return: Decimal,
+        returns_interval: ReturnsInterval,
         interval: Interval,
     ) -> TearSheet<Interval>
     where
@@ -100,19 +145,32 @@ impl TearSheetGenerator {
             .signed_duration_since(self.time_engine_start)
             .max(TimeDelta::seconds(1));
 
+        let (returns, returns_losses, returns_period) = match returns_interval.period() {
+            None => (
+                self.pnl_returns.total.clone(),
+                self.pnl_returns.losses.clone(),
+                trading_period,
+            ),
+            Some(period) => {
+                let (returns, returns_losses) =
+                    self.pnl_returns_period.summarise(period, trading_period);
+                (returns, returns_losses, period)
+            }
+        };
+
         let sharpe_ratio = SharpeRatio::calculate(
             risk_free_return,
-            self.pnl_returns.total.mean,
-            self.pnl_returns.total.dispersion.std_dev,
-            trading_period,
+            returns.mean,
+            returns.dispersion.std_dev,
+            returns_period,
         )
         .scale(interval);
 
         let sortino_ratio = SortinoRatio::calculate(
             risk_free_return,
-            self.pnl_returns.total.mean,
-            self.pnl_returns.losses.dispersion.std_dev,
-            trading_period,
+            returns.mean,
+            returns_losses.dispersion.std_dev,
+            returns_period,
         )
         .scale(interval);
 
@@ -126,19 +184,18 @@ impl TearSheetGenerator {
 
         let calmar_ratio = CalmarRatio::calculate(
             risk_free_return,
-            self.pnl_returns.total.mean,
+            returns.mean,
             // Zero drawdown risk handled by CalmarRatio::calculate
             pnl_drawdown_max
                 .as_ref()
                 .unwrap_or(&MaxDrawdown(Drawdown::default()))
                 .0
                 .value,
-            trading_period,
+            returns_period,
         )
         .scale(interval);
 
-        let pnl_return =
-            RateOfReturn::calculate(self.pnl_returns.total.mean, trading_period).scale(interval);
+        let pnl_return = RateOfReturn::calculate(returns.mean, returns_period).scale(interval);
 
         let win_rate =
             WinRate::calculate(self.pnl_returns.losses.count, self.pnl_returns.total.count);
@@ -155,8 +212,11 @@ impl TearSheetGenerator {
             pnl_drawdown: current_pnl_drawdown,
             pnl_drawdown_mean,
             pnl_drawdown_max,
+            pnl_drawdowns: self.pnl_drawdowns.clone(),
             win_rate,
             profit_factor,
+            excursion_adverse_mean: self.excursion_adverse.mean,
+            excursion_favourable_mean: self.excursion_favourable.mean,
         }
     }
 
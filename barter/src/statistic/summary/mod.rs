@@ -1,14 +1,19 @@
 use crate::{
     engine::state::{asset::AssetStates, instrument::InstrumentStates, position::PositionExited},
     statistic::{
+        metric::{
+            exposure::{ExposureGenerator, ExposureSummary},
+            shortfall::ShortfallReport,
+        },
         summary::{
             asset::{TearSheetAsset, TearSheetAssetGenerator},
             instrument::{TearSheet, TearSheetGenerator},
+            pnl::ReturnsInterval,
         },
         time::TimeInterval,
     },
 };
-use barter_execution::balance::AssetBalance;
+use barter_execution::{balance::AssetBalance, order::id::StrategyId};
 use barter_instrument::{
     asset::{AssetIndex, ExchangeAsset, name::AssetNameInternal},
     instrument::{InstrumentIndex, name::InstrumentNameInternal},
@@ -41,6 +46,17 @@ pub struct TradingSummary<Interval> {
 
     /// [`ExchangeAsset`] [`TearSheet`]s.
     pub assets: FnvIndexMap<ExchangeAsset<AssetNameInternal>, TearSheetAsset>,
+
+    /// Per-[`StrategyId`] Instrument [`TearSheet`]s, summarising the trading performance of
+    /// each strategy when multiple strategies trade within one `Engine`.
+    pub strategies:
+        FnvIndexMap<StrategyId, FnvIndexMap<InstrumentNameInternal, TearSheet<Interval>>>,
+
+    /// Portfolio gross & net notional [`ExposureSummary`], if exposure was tracked.
+    pub exposure: Option<ExposureSummary>,
+
+    /// Order execution [`ShortfallReport`], if implementation shortfall was tracked.
+    pub shortfall: Option<ShortfallReport>,
 }
 
 impl<Interval> TradingSummary<Interval> {
@@ -49,6 +65,25 @@ impl<Interval> TradingSummary<Interval> {
         self.time_engine_end
             .signed_duration_since(self.time_engine_start)
     }
+
+    /// Serialise the entire `TradingSummary` statistics hierarchy (including per-instrument,
+    /// per-asset & per-strategy [`TearSheet`]s and drawdown series) to a compact JSON `String`,
+    /// so it can be consumed programmatically by external tooling.
+    pub fn to_json(&self) -> Result<String, serde_json::Error>
+    where
+        Interval: Serialize,
+    {
+        serde_json::to_string(self)
+    }
+
+    /// Serialise the entire `TradingSummary` statistics hierarchy to a pretty-printed JSON
+    /// `String` (see [`Self::to_json`]).
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error>
+    where
+        Interval: Serialize,
+    {
+        serde_json::to_string_pretty(self)
+    }
 }
 
 /// Generator for a [`TradingSummary`].
@@ -59,6 +94,12 @@ pub struct TradingSummaryGenerator {
     /// See docs: <https://www.investopedia.com/terms/r/risk-freerate.asp>
     pub risk_free_return: Decimal,
 
+    /// Interval over which PnL returns are measured when calculating risk-adjusted return
+    /// statistics.
+    ///
+    /// Note that the `risk_free_return` is expressed per `ReturnsInterval`.
+    pub returns_interval: ReturnsInterval,
+
     /// Trading session summary start time defined by the [`Engine`](crate::engine::Engine) clock.
     pub time_engine_start: DateTime<Utc>,
 
@@ -74,6 +115,16 @@ pub struct TradingSummaryGenerator {
 
     /// [`ExchangeAsset`] [`TearSheetAssetGenerator`]s.
     pub assets: FnvIndexMap<ExchangeAsset<AssetNameInternal>, TearSheetAssetGenerator>,
+
+    /// Per-[`StrategyId`] Instrument [`TearSheetGenerator`]s.
+    pub strategies:
+        FnvIndexMap<StrategyId, FnvIndexMap<InstrumentNameInternal, TearSheetGenerator>>,
+
+    /// Optional portfolio [`ExposureGenerator`].
+    pub exposure: Option<ExposureGenerator>,
+
+    /// Optional order execution [`ShortfallReport`].
+    pub shortfall: Option<ShortfallReport>,
 }
 
 impl TradingSummaryGenerator {
@@ -88,6 +139,7 @@ impl TradingSummaryGenerator {
     ) -> Self {
         Self {
             risk_free_return,
+            returns_interval: ReturnsInterval::default(),
             time_engine_start,
             time_engine_now,
             instruments: instruments
@@ -105,9 +157,46 @@ impl TradingSummaryGenerator {
                 .iter()
                 .map(|(asset, state)| (asset.clone(), state.statistics.clone()))
                 .collect(),
+            strategies: instruments.0.values().fold(
+                FnvIndexMap::default(),
+                |mut strategies, state| {
+                    for (strategy, book) in &state.strategies.0 {
+                        strategies
+                            .entry(strategy.clone())
+                            .or_insert_with(FnvIndexMap::default)
+                            .insert(
+                                state.instrument.name_internal.clone(),
+                                book.tear_sheet.clone(),
+                            );
+                    }
+                    strategies
+                },
+            ),
+            exposure: None,
+            shortfall: None,
+        }
+    }
+
+    /// Configure the [`ReturnsInterval`] over which PnL returns are measured when calculating
+    /// risk-adjusted return statistics (eg/ [`ReturnsInterval::Daily`] for daily Sharpe ratios).
+    pub fn with_returns_interval(self, returns_interval: ReturnsInterval) -> Self {
+        Self {
+            returns_interval,
+            ..self
         }
     }
 
+    /// Include the provided portfolio [`ExposureGenerator`] in the generated [`TradingSummary`].
+    pub fn with_exposure(self, exposure: Option<ExposureGenerator>) -> Self {
+        Self { exposure, ..self }
+    }
+
+    /// Include the provided order execution [`ShortfallReport`] in the generated
+    /// [`TradingSummary`].
+    pub fn with_shortfall(self, shortfall: Option<ShortfallReport>) -> Self {
+        Self { shortfall, ..self }
+    }
+
     /// Update the [`TradingSummaryGenerator`] `time_now`.
     pub fn update_time_now(&mut self, time_now: DateTime<Utc>) {
         self.time_engine_now = time_now;
@@ -155,7 +244,7 @@ impl TradingSummaryGenerator {
             .map(|(instrument, tear_sheet)| {
                 (
                     instrument.clone(),
-                    tear_sheet.generate(self.risk_free_return, interval),
+                    tear_sheet.generate(self.risk_free_return, self.returns_interval, interval),
                 )
             })
             .collect();
@@ -166,11 +255,39 @@ impl TradingSummaryGenerator {
             .map(|(asset, tear_sheet)| (asset.clone(), tear_sheet.generate()))
             .collect();
 
+        let strategies = self
+            .strategies
+            .iter_mut()
+            .map(|(strategy, instruments)| {
+                let tear_sheets = instruments
+                    .iter_mut()
+                    .map(|(instrument, tear_sheet)| {
+                        (
+                            instrument.clone(),
+                            tear_sheet.generate(
+                                self.risk_free_return,
+                                self.returns_interval,
+                                interval,
+                            ),
+                        )
+                    })
+                    .collect();
+
+                (strategy.clone(), tear_sheets)
+            })
+            .collect();
+
         TradingSummary {
             time_engine_start: self.time_engine_start,
             time_engine_end: self.time_engine_now,
             instruments,
             assets,
+            strategies,
+            exposure: self
+                .exposure
+                .as_ref()
+                .map(|exposure| exposure.generate(self.time_engine_now)),
+            shortfall: self.shortfall.clone(),
         }
     }
 }
@@ -247,3 +364,62 @@ impl AssetTearSheetManager<ExchangeAsset<AssetNameInternal>> for TradingSummaryG
             .unwrap_or_else(|| panic!("TradingSummaryGenerator does not contain: {key:?}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::position::PositionExited, statistic::time::Daily, test_utils::time_plus_days,
+    };
+    use barter_execution::trade::AssetFees;
+    use barter_instrument::{Side, asset::QuoteAsset};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_trading_summary_to_json() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let instrument = InstrumentNameInternal::new("instrument");
+
+        let mut tear_sheet = TearSheetGenerator::init(base);
+        for (day, pnl) in [(1, dec!(10)), (2, dec!(-5)), (3, dec!(20))] {
+            tear_sheet.update_from_position(&PositionExited {
+                instrument: instrument.clone(),
+                side: Side::Buy,
+                price_entry_average: dec!(100),
+                quantity_abs_max: dec!(1),
+                pnl_realised: pnl,
+                excursion_adverse_max: dec!(0),
+                excursion_favourable_max: dec!(0),
+                fees_enter: AssetFees::new(QuoteAsset, dec!(0)),
+                fees_exit: AssetFees::new(QuoteAsset, dec!(0)),
+                time_enter: base,
+                time_exit: time_plus_days(base, day),
+                trades: vec![],
+                signal: None,
+            });
+        }
+
+        let mut generator = TradingSummaryGenerator {
+            risk_free_return: dec!(0),
+            returns_interval: ReturnsInterval::default(),
+            time_engine_start: base,
+            time_engine_now: time_plus_days(base, 3),
+            instruments: FnvIndexMap::from_iter([(instrument.clone(), tear_sheet)]),
+            assets: FnvIndexMap::default(),
+            strategies: FnvIndexMap::default(),
+            exposure: None,
+            shortfall: None,
+        };
+
+        let summary = generator.generate(Daily);
+        assert_eq!(summary.instruments[&instrument].pnl_drawdowns.len(), 1);
+
+        let json = summary.to_json().unwrap();
+        let actual = serde_json::from_str::<TradingSummary<Daily>>(&json).unwrap();
+        assert_eq!(actual, summary);
+
+        let json_pretty = summary.to_json_pretty().unwrap();
+        let actual = serde_json::from_str::<TradingSummary<Daily>>(&json_pretty).unwrap();
+        assert_eq!(actual, summary);
+    }
+}
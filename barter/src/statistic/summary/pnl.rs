@@ -1,9 +1,11 @@
 use crate::{
     engine::state::position::{PositionExited, calculate_pnl_return},
-    statistic::summary::dataset::DataSetSummary,
+    statistic::summary::dataset::{DataSetSummary, dispersion::Dispersion},
 };
-use rust_decimal::Decimal;
+use chrono::TimeDelta;
+use rust_decimal::{Decimal, MathematicalOps};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Records Profit and Loss (PnL) data.
 ///
@@ -59,3 +61,161 @@ impl PnLReturns {
         }
     }
 }
+
+/// Interval over which PnL returns are measured when calculating risk-adjusted return statistics
+/// (eg/ Sharpe, Sortino & Calmar ratios).
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum ReturnsInterval {
+    /// Return of each exited position, measured over the whole trading period.
+    #[default]
+    PerTrade,
+
+    /// Sum of the returns of positions exited within each hour of trading.
+    Hourly,
+
+    /// Sum of the returns of positions exited within each day of trading.
+    Daily,
+}
+
+impl ReturnsInterval {
+    /// Duration of each returns period, or `None` for [`ReturnsInterval::PerTrade`].
+    pub fn period(&self) -> Option<TimeDelta> {
+        match self {
+            ReturnsInterval::PerTrade => None,
+            ReturnsInterval::Hourly => Some(TimeDelta::hours(1)),
+            ReturnsInterval::Daily => Some(TimeDelta::days(1)),
+        }
+    }
+}
+
+/// PnL returns of exited positions, summed into hourly periods since the trading session start.
+///
+/// Hourly periods are merged into the requested [`ReturnsInterval`] period when summarised.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, Deserialize, Serialize)]
+pub struct PeriodReturns {
+    /// Sum of the PnL returns of each hour with at least one exited position, keyed by the number
+    /// of whole hours since the trading session start.
+    pub hourly: BTreeMap<i64, Decimal>,
+}
+
+impl PeriodReturns {
+    /// Record the PnL return of a position exited `elapsed` after the trading session start.
+    pub fn update(&mut self, elapsed: TimeDelta, pnl_return: Decimal) {
+        *self.hourly.entry(elapsed.num_hours()).or_default() += pnl_return;
+    }
+
+    /// Summarise the returns of each `period` within the `trading_period`, returning the
+    /// [`DataSetSummary`] of all period returns, and of negative period returns only.
+    ///
+    /// Periods without any exited positions contribute a zero return.
+    pub fn summarise(
+        &self,
+        period: TimeDelta,
+        trading_period: TimeDelta,
+    ) -> (DataSetSummary, DataSetSummary) {
+        let hours_per_period = period.num_hours().max(1);
+
+        let returns = self.hourly.iter().fold(
+            BTreeMap::<i64, Decimal>::new(),
+            |mut returns, (hour, pnl_return)| {
+                *returns
+                    .entry(hour.div_euclid(hours_per_period))
+                    .or_default() += pnl_return;
+                returns
+            },
+        );
+
+        // Number of periods elapsed, including the current partial period
+        let secs_per_period = hours_per_period * 3600;
+        let periods = (trading_period.num_seconds().max(1) + secs_per_period - 1) / secs_per_period;
+        let periods = returns
+            .last_key_value()
+            .map_or(periods, |(last, _)| periods.max(last + 1));
+
+        let (total, losses) = returns.values().fold(
+            (DataSetSummary::default(), DataSetSummary::default()),
+            |(mut total, mut losses), pnl_return| {
+                total.update(*pnl_return);
+                if pnl_return.is_sign_negative() {
+                    losses.update(*pnl_return);
+                }
+                (total, losses)
+            },
+        );
+
+        let periods_empty = Decimal::from(periods) - total.count;
+        (with_zero_returns(total, periods_empty), losses)
+    }
+}
+
+/// Merge `count` zero value observations into the provided [`DataSetSummary`].
+fn with_zero_returns(summary: DataSetSummary, count: Decimal) -> DataSetSummary {
+    if count <= Decimal::ZERO {
+        return summary;
+    }
+
+    let count_total = summary.count + count;
+    let mean = summary.sum / count_total;
+
+    // Parallel variance merge of the summary with a dataset of zeros (mean 0, M 0)
+    let recurrence_relation_m = summary.dispersion.recurrence_relation_m
+        + summary.mean * summary.mean * summary.count * count / count_total;
+    let variance = recurrence_relation_m / count_total;
+
+    let mut range = summary.dispersion.range;
+    range.update(Decimal::ZERO);
+
+    DataSetSummary {
+        count: count_total,
+        sum: summary.sum,
+        mean,
+        dispersion: Dispersion {
+            range,
+            recurrence_relation_m,
+            variance,
+            std_dev: variance.sqrt().expect("variance cannot be negative"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_period_returns_summarise() {
+        let mut returns = PeriodReturns::default();
+        returns.update(TimeDelta::minutes(30), dec!(0.1));
+        returns.update(TimeDelta::hours(5), dec!(-0.05));
+        returns.update(TimeDelta::hours(25), dec!(0.2));
+        returns.update(TimeDelta::hours(26), dec!(-0.5));
+
+        // Hourly: 72 periods, with 4 non-zero returns summing to -0.25
+        let (total, losses) = returns.summarise(TimeDelta::hours(1), TimeDelta::hours(72));
+        assert_eq!(total.count, dec!(72));
+        assert_eq!(total.sum, dec!(-0.25));
+        assert_eq!(losses.count, dec!(2));
+        assert_eq!(losses.sum, dec!(-0.55));
+
+        // Daily: returns of [0.05, -0.3, 0.0]
+        let (total, losses) = returns.summarise(TimeDelta::days(1), TimeDelta::hours(72));
+        let mut expected = DataSetSummary::default();
+        [dec!(0.05), dec!(-0.3), dec!(0.0)]
+            .into_iter()
+            .for_each(|value| expected.update(value));
+
+        assert_eq!(total.count, expected.count);
+        assert_eq!(total.mean, expected.mean);
+        assert_eq!(total.dispersion.variance, expected.dispersion.variance);
+        assert_eq!(total.dispersion.range, expected.dispersion.range);
+        assert_eq!(losses.count, dec!(1));
+        assert_eq!(losses.mean, dec!(-0.3));
+
+        // Daily with a trading period ending part way through the second day
+        let (total, _) = returns.summarise(TimeDelta::days(1), TimeDelta::hours(30));
+        assert_eq!(total.count, dec!(2));
+    }
+}
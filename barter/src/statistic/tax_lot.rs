@@ -0,0 +1,312 @@
+use barter_execution::trade::Trade;
+use barter_instrument::{Side, asset::QuoteAsset};
+use chrono::{DateTime, Utc};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+    hash::Hash,
+    io::Write,
+    ops::Range,
+};
+use tracing::warn;
+
+/// Method used to match a disposal against the open [`TaxLot`]s of an instrument.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum LotMatching {
+    /// First-in-first-out: dispose of the oldest [`TaxLot`] first.
+    #[default]
+    Fifo,
+    /// Last-in-first-out: dispose of the most recent [`TaxLot`] first.
+    Lifo,
+}
+
+/// Quantity of an asset acquired by a single buy [`Trade`], and its cost basis.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TaxLot {
+    pub time_acquired: DateTime<Utc>,
+
+    /// Remaining quantity of the lot that has not been disposed of.
+    pub quantity: Decimal,
+
+    /// Cost per unit, including the acquisition fees.
+    pub cost_per_unit: Decimal,
+}
+
+/// Realised gain (or loss) from disposing of (part of) a [`TaxLot`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct RealisedGain<InstrumentKey> {
+    pub instrument: InstrumentKey,
+    pub time_acquired: DateTime<Utc>,
+    pub time_disposed: DateTime<Utc>,
+    pub quantity: Decimal,
+
+    /// Disposal proceeds, net of the disposal fees.
+    pub proceeds: Decimal,
+
+    /// Cost basis of the disposed quantity, including the acquisition fees.
+    pub cost_basis: Decimal,
+}
+
+impl<InstrumentKey> RealisedGain<InstrumentKey> {
+    /// Realised gain, where a negative value is a realised loss.
+    pub fn gain(&self) -> Decimal {
+        self.proceeds - self.cost_basis
+    }
+}
+
+/// Tracks the acquisition [`TaxLot`]s of each spot instrument (ie/ the base asset) from fills,
+/// generating a [`RealisedGain`] for each disposal matched using the configured
+/// [`LotMatching`].
+///
+/// Fees are assumed to be denominated in the quote asset, so they are added to the cost basis of
+/// acquisitions and deducted from the proceeds of disposals.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TaxLotTracker<InstrumentKey>
+where
+    InstrumentKey: Eq + Hash,
+{
+    pub matching: LotMatching,
+    pub lots: FnvHashMap<InstrumentKey, VecDeque<TaxLot>>,
+    pub realised: Vec<RealisedGain<InstrumentKey>>,
+}
+
+impl<InstrumentKey> TaxLotTracker<InstrumentKey>
+where
+    InstrumentKey: Debug + Clone + Eq + Hash,
+{
+    /// Construct a new `TaxLotTracker` using the provided [`LotMatching`] method.
+    pub fn new(matching: LotMatching) -> Self {
+        Self {
+            matching,
+            lots: FnvHashMap::default(),
+            realised: Vec::new(),
+        }
+    }
+
+    /// Open [`TaxLot`]s of the provided instrument, ordered by acquisition.
+    pub fn open_lots(&self, instrument: &InstrumentKey) -> impl Iterator<Item = &TaxLot> {
+        self.lots.get(instrument).into_iter().flatten()
+    }
+
+    /// [`RealisedGain`]s with a disposal time within the provided period (eg/ a tax year).
+    pub fn realised_within<'a>(
+        &'a self,
+        period: &'a Range<DateTime<Utc>>,
+    ) -> impl Iterator<Item = &'a RealisedGain<InstrumentKey>> {
+        self.realised
+            .iter()
+            .filter(|gain| period.contains(&gain.time_disposed))
+    }
+
+    /// Update the `TaxLotTracker` from a fill, opening a [`TaxLot`] for a buy, or matching a
+    /// sell against the open [`TaxLot`]s.
+    ///
+    /// Any sell quantity exceeding the open [`TaxLot`]s (ie/ a short sale) is not tracked.
+    pub fn update_from_trade(&mut self, trade: &Trade<QuoteAsset, InstrumentKey>) {
+        if trade.quantity.is_zero() {
+            return;
+        }
+
+        match trade.side {
+            Side::Buy => {
+                let cost = trade.price * trade.quantity + trade.fees.fees;
+                self.lots
+                    .entry(trade.instrument.clone())
+                    .or_default()
+                    .push_back(TaxLot {
+                        time_acquired: trade.time_exchange,
+                        quantity: trade.quantity,
+                        cost_per_unit: cost / trade.quantity,
+                    });
+            }
+            Side::Sell => self.dispose(trade),
+        }
+    }
+
+    fn dispose(&mut self, trade: &Trade<QuoteAsset, InstrumentKey>) {
+        let proceeds_per_unit = trade.price - trade.fees.fees / trade.quantity;
+        let lots = self.lots.entry(trade.instrument.clone()).or_default();
+
+        let mut remaining = trade.quantity;
+        while remaining > Decimal::ZERO {
+            let lot = match self.matching {
+                LotMatching::Fifo => lots.front_mut(),
+                LotMatching::Lifo => lots.back_mut(),
+            };
+
+            let Some(lot) = lot else {
+                warn!(
+                    instrument = ?trade.instrument,
+                    quantity_unmatched = %remaining,
+                    "TaxLotTracker disposal exceeds open lots - ignoring unmatched quantity"
+                );
+                break;
+            };
+
+            let quantity = remaining.min(lot.quantity);
+            self.realised.push(RealisedGain {
+                instrument: trade.instrument.clone(),
+                time_acquired: lot.time_acquired,
+                time_disposed: trade.time_exchange,
+                quantity,
+                proceeds: proceeds_per_unit * quantity,
+                cost_basis: lot.cost_per_unit * quantity,
+            });
+
+            lot.quantity -= quantity;
+            remaining -= quantity;
+
+            if lot.quantity.is_zero() {
+                match self.matching {
+                    LotMatching::Fifo => lots.pop_front(),
+                    LotMatching::Lifo => lots.pop_back(),
+                };
+            }
+        }
+    }
+}
+
+/// Write a realised gains report in CSV format, with a row for each [`RealisedGain`].
+pub fn write_csv<'a, InstrumentKey, Writer>(
+    gains: impl IntoIterator<Item = &'a RealisedGain<InstrumentKey>>,
+    mut writer: Writer,
+) -> std::io::Result<()>
+where
+    InstrumentKey: Display + 'a,
+    Writer: Write,
+{
+    writeln!(
+        writer,
+        "instrument,time_acquired,time_disposed,quantity,proceeds,cost_basis,gain"
+    )?;
+
+    for gain in gains {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{}",
+            gain.instrument,
+            gain.time_acquired.to_rfc3339(),
+            gain.time_disposed.to_rfc3339(),
+            gain.quantity,
+            gain.proceeds,
+            gain.cost_basis,
+            gain.gain(),
+        )?;
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{time_plus_days, trade};
+    use barter_instrument::instrument::name::InstrumentNameInternal;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_tax_lot_tracker_update_from_trade() {
+        struct TestCase {
+            matching: LotMatching,
+            expected_realised: Vec<(Decimal, Decimal, Decimal)>,
+            expected_open: Vec<Decimal>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let trades = [
+            trade(time_plus_days(base, 0), Side::Buy, 100.0, 1.0, 0.0),
+            trade(time_plus_days(base, 1), Side::Buy, 200.0, 1.0, 2.0),
+            trade(time_plus_days(base, 2), Side::Sell, 300.0, 1.5, 3.0),
+        ];
+
+        let cases = vec![
+            // TC0: FIFO disposes of the oldest lot first
+            TestCase {
+                matching: LotMatching::Fifo,
+                // (quantity, proceeds, cost_basis)
+                expected_realised: vec![
+                    (dec!(1), dec!(298), dec!(100)),
+                    (dec!(0.5), dec!(149), dec!(101)),
+                ],
+                expected_open: vec![dec!(0.5)],
+            },
+            // TC1: LIFO disposes of the most recent lot first
+            TestCase {
+                matching: LotMatching::Lifo,
+                expected_realised: vec![
+                    (dec!(1), dec!(298), dec!(202)),
+                    (dec!(0.5), dec!(149), dec!(50)),
+                ],
+                expected_open: vec![dec!(0.5)],
+            },
+        ];
+
+        let instrument = InstrumentNameInternal::new("instrument");
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut tracker = TaxLotTracker::new(test.matching);
+            trades
+                .iter()
+                .for_each(|trade| tracker.update_from_trade(trade));
+
+            let actual_realised = tracker
+                .realised
+                .iter()
+                .map(|gain| (gain.quantity, gain.proceeds, gain.cost_basis))
+                .collect::<Vec<_>>();
+            assert_eq!(actual_realised, test.expected_realised, "TC{index} failed");
+
+            let actual_open = tracker
+                .open_lots(&instrument)
+                .map(|lot| lot.quantity)
+                .collect::<Vec<_>>();
+            assert_eq!(actual_open, test.expected_open, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_tax_lot_tracker_disposal_exceeds_open_lots() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut tracker = TaxLotTracker::new(LotMatching::Fifo);
+
+        tracker.update_from_trade(&trade(base, Side::Buy, 100.0, 1.0, 0.0));
+        tracker.update_from_trade(&trade(base, Side::Sell, 110.0, 2.0, 0.0));
+
+        assert_eq!(tracker.realised.len(), 1);
+        assert_eq!(tracker.realised[0].quantity, dec!(1));
+        assert_eq!(tracker.realised[0].gain(), dec!(10));
+        assert_eq!(
+            tracker
+                .open_lots(&InstrumentNameInternal::new("instrument"))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let time = DateTime::<Utc>::from_timestamp(0, 0).unwrap();
+        let gains = vec![RealisedGain {
+            instrument: InstrumentNameInternal::new("btc_usdt"),
+            time_acquired: time,
+            time_disposed: time,
+            quantity: dec!(1),
+            proceeds: dec!(110),
+            cost_basis: dec!(100),
+        }];
+
+        let mut csv = Vec::new();
+        write_csv(&gains, &mut csv).unwrap();
+
+        let expected = "\
+instrument,time_acquired,time_disposed,quantity,proceeds,cost_basis,gain
+btc_usdt,1970-01-01T00:00:00+00:00,1970-01-01T00:00:00+00:00,1,110,100,10
+";
+        assert_eq!(String::from_utf8(csv).unwrap(), expected);
+    }
+}
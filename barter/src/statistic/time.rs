@@ -58,6 +58,22 @@ impl TimeInterval for Annual252 {
     }
 }
 
+/// Annualised [`TimeInterval`] with a configurable number of trading days per year.
+///
+/// eg/ `Annual(365)` for 24/7 crypto markets, `Annual(252)` for traditional markets.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct Annual(pub u32);
+
+impl TimeInterval for Annual {
+    fn name(&self) -> SmolStr {
+        format_smolstr!("Annual({})", self.0)
+    }
+
+    fn interval(&self) -> TimeDelta {
+        TimeDelta::days(i64::from(self.0))
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default, Deserialize, Serialize)]
 pub struct Daily;
 
@@ -71,6 +87,19 @@ impl TimeInterval for Daily {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default, Deserialize, Serialize)]
+pub struct Hourly;
+
+impl TimeInterval for Hourly {
+    fn name(&self) -> SmolStr {
+        SmolStr::new("Hourly")
+    }
+
+    fn interval(&self) -> TimeDelta {
+        TimeDelta::hours(1)
+    }
+}
+
 impl TimeInterval for TimeDelta {
     fn name(&self) -> SmolStr {
         format_smolstr!("Duration {} (minutes)", self.num_minutes())
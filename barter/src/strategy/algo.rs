@@ -1,5 +1,8 @@
+use crate::engine::state::position::SignalMeta;
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Strategy interface for generating algorithmic open and cancel order requests based on the
 /// current `EngineState`.
@@ -23,4 +26,33 @@ pub trait AlgoStrategy<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIn
         impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>>,
         impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
     );
+
+    /// Update the `AlgoStrategy` parameters at runtime from serialised (JSON) parameters.
+    ///
+    /// By default, runtime parameter updates are not supported.
+    fn update_params(&mut self, _params: &str) -> Result<(), StrategyUpdateError> {
+        Err(StrategyUpdateError::Unsupported)
+    }
+
+    /// Structured [`SignalMeta`] (eg/ intended stop, target & setup tag) of the signal behind a
+    /// generated open order request, persisted onto any `Position` the order opens.
+    ///
+    /// By default, no `SignalMeta` is attached.
+    fn signal_meta(
+        &self,
+        _state: &Self::State,
+        _request: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Option<SignalMeta> {
+        None
+    }
+}
+
+/// Error that can occur when updating the parameters of an [`AlgoStrategy`] at runtime.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum StrategyUpdateError {
+    #[error("AlgoStrategy does not support runtime parameter updates")]
+    Unsupported,
+
+    #[error("invalid AlgoStrategy parameters: {0}")]
+    InvalidParams(String),
 }
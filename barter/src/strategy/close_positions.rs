@@ -95,10 +95,58 @@ where
     (std::iter::empty(), open_requests)
 }
 
+/// `ClosePositionsStrategy` logic for closing the open positions of every strategy
+/// [`StrategyBook`](crate::engine::state::instrument::strategy::StrategyBook) with market
+/// orders.
+///
+/// Each strategy position is closed by an order tagged with the owning [`StrategyId`], so the
+/// per-strategy books are neutralised along with the netted instrument position.
+///
+/// Note that if strategies hold offsetting positions in the same instrument, the `reduce_only`
+/// closing orders may be rejected by the exchange.
+pub fn close_strategy_positions_with_market_orders<'a, GlobalData, InstrumentData>(
+    state: &'a EngineState<GlobalData, InstrumentData>,
+    filter: &'a InstrumentFilter,
+    gen_cid: impl Fn(&InstrumentState<InstrumentData>) -> ClientOrderId + Copy + 'a,
+) -> (
+    impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+    impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+)
+where
+    InstrumentData: InstrumentDataState,
+{
+    let open_requests = state
+        .instruments
+        .instruments(filter)
+        .filter_map(|state| state.data.price().map(|price| (state, price)))
+        .flat_map(move |(state, price)| {
+            state
+                .strategies
+                .0
+                .iter()
+                .filter_map(move |(strategy, book)| {
+                    let position = book.position.current.as_ref()?;
+
+                    Some(build_ioc_market_order_to_close_position(
+                        state.instrument.exchange,
+                        position,
+                        strategy.clone(),
+                        price,
+                        || gen_cid(state),
+                    ))
+                })
+        });
+
+    (std::iter::empty(), open_requests)
+}
+
 /// Build an equal but opposite `Side` `ImmediateOrCancel` `Market` order that neutralises the
 /// provided [`Position`].
 ///
 /// For example, if [`Position`] is LONG by 100, build a market order request to sell 100.
+///
+/// The order is `reduce_only`, so a delayed fill can never flip the [`Position`] into the
+/// opposite direction.
 pub fn build_ioc_market_order_to_close_position<ExchangeKey, AssetKey, InstrumentKey>(
     exchange: ExchangeKey,
     position: &Position<AssetKey, InstrumentKey>,
@@ -126,6 +174,7 @@ where
             quantity: position.quantity_abs,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: true,
         },
     }
 }
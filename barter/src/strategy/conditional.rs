@@ -0,0 +1,426 @@
+use crate::engine::state::{EngineState, instrument::data::InstrumentDataState};
+use barter_execution::order::{
+    OrderKind, StopTrigger,
+    id::ClientOrderId,
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_integration::collection::FnvIndexMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use tracing::info;
+
+/// Market price condition that triggers a [`ConditionalOrder`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum Trigger {
+    /// Triggers once the price moves adversely to the stop `price`.
+    ///
+    /// ie/ Buy stops trigger at or above the `price`, and sell stops trigger at or below.
+    Stop { price: Decimal },
+
+    /// Triggers once the price moves favourably to the take-profit `price`.
+    ///
+    /// ie/ Buy take-profits trigger at or below the `price`, and sell take-profits trigger at or
+    /// above.
+    TakeProfit { price: Decimal },
+
+    /// Stop that trails the best price observed by the `offset` fraction (eg/ 0.02 for 2%).
+    ///
+    /// ie/ Sell trailing stops trigger once the price falls `offset` below the highest price
+    /// observed, and buy trailing stops once the price rises `offset` above the lowest.
+    Trailing {
+        offset: Decimal,
+        price_extreme: Option<Decimal>,
+    },
+}
+
+impl Trigger {
+    /// Construct a new [`Trigger::Trailing`] with the provided `offset` fraction.
+    pub fn trailing(offset: Decimal) -> Self {
+        Self::Trailing {
+            offset,
+            price_extreme: None,
+        }
+    }
+
+    /// Update the `Trigger` with the latest market price, returning true if an order of the
+    /// provided [`Side`] is triggered.
+    pub fn update(&mut self, side: Side, price: Decimal) -> bool {
+        match self {
+            Self::Stop { price: trigger } => StopTrigger::is_triggered(side, *trigger, price),
+            Self::TakeProfit { price: trigger } => match side {
+                Side::Buy => price <= *trigger,
+                Side::Sell => price >= *trigger,
+            },
+            Self::Trailing {
+                offset,
+                price_extreme,
+            } => {
+                let extreme = match (side, *price_extreme) {
+                    (_, None) => price,
+                    (Side::Buy, Some(extreme)) => extreme.min(price),
+                    (Side::Sell, Some(extreme)) => extreme.max(price),
+                };
+                *price_extreme = Some(extreme);
+
+                let trigger = match side {
+                    Side::Buy => extreme * (Decimal::ONE + *offset),
+                    Side::Sell => extreme * (Decimal::ONE - *offset),
+                };
+                StopTrigger::is_triggered(side, trigger, price)
+            }
+        }
+    }
+}
+
+/// Order request held locally by a [`ConditionalOrderManager`] until its [`Trigger`] fires.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ConditionalOrder<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// Order request sent for execution once triggered (eg/ a `Market` or `Limit` order).
+    pub request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+
+    /// Market price condition that triggers the order request.
+    pub trigger: Trigger,
+
+    /// Market price monitored by the `trigger`.
+    pub price_source: StopTrigger,
+}
+
+/// Engine-side fallback for exchanges that do not support stop, take-profit or trailing orders
+/// natively (see
+/// [`ExecutionClient::STOP_NATIVE`](barter_execution::client::ExecutionClient::STOP_NATIVE)).
+///
+/// [`ConditionalOrder`]s are held locally and monitored against the latest market prices, and
+/// their order requests are returned for execution once triggered.
+///
+/// The held orders can be persisted across restarts (see [`ConditionalOrderManager::persist`] and
+/// [`ConditionalOrderManager::restore`]), since they are unknown to the exchange.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ConditionalOrderManager<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// Held [`ConditionalOrder`]s, in the order they were registered.
+    pub orders: FnvIndexMap<ClientOrderId, ConditionalOrder<ExchangeKey, InstrumentKey>>,
+}
+
+impl<ExchangeKey, InstrumentKey> Default for ConditionalOrderManager<ExchangeKey, InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            orders: FnvIndexMap::default(),
+        }
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> ConditionalOrderManager<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Clone,
+    InstrumentKey: Clone,
+{
+    /// Register an open order request, returning the order request that should be sent to the
+    /// exchange, if any.
+    ///
+    /// [`OrderKind::StopMarket`] & [`OrderKind::StopLimit`] requests are held as a
+    /// [`Trigger::Stop`] [`ConditionalOrder`], and sent as a `Market` or `Limit` order once
+    /// triggered. All other requests are returned unchanged.
+    pub fn open(
+        &mut self,
+        request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Option<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        let Some((trigger_price, price_source)) = request.state.kind.stop() else {
+            return Some(request);
+        };
+
+        let mut request = request;
+        request.state.kind = match request.state.kind {
+            OrderKind::StopLimit { .. } => OrderKind::Limit,
+            _ => OrderKind::Market,
+        };
+
+        self.register(ConditionalOrder {
+            request,
+            trigger: Trigger::Stop {
+                price: trigger_price,
+            },
+            price_source,
+        });
+
+        None
+    }
+
+    /// Hold a [`ConditionalOrder`] until it is triggered, replacing any existing
+    /// `ConditionalOrder` with the same [`ClientOrderId`].
+    pub fn register(&mut self, order: ConditionalOrder<ExchangeKey, InstrumentKey>) {
+        self.orders.insert(order.request.key.cid.clone(), order);
+    }
+
+    /// Stop holding the [`ConditionalOrder`] associated with the [`ClientOrderId`], returning it
+    /// if it had not yet been triggered.
+    pub fn cancel(
+        &mut self,
+        cid: &ClientOrderId,
+    ) -> Option<ConditionalOrder<ExchangeKey, InstrumentKey>> {
+        self.orders.shift_remove(cid)
+    }
+
+    /// Stop holding any [`ConditionalOrder`] targeted by the provided cancel request, returning
+    /// the cancel request if it should be sent to the exchange (ie/ the order is not held).
+    pub fn cancel_request(
+        &mut self,
+        request: OrderRequestCancel<ExchangeKey, InstrumentKey>,
+    ) -> Option<OrderRequestCancel<ExchangeKey, InstrumentKey>> {
+        match self.cancel(&request.key.cid) {
+            Some(_) => None,
+            None => Some(request),
+        }
+    }
+
+    /// Update every held [`ConditionalOrder`] with the latest market prices, returning the
+    /// order requests of those that have been triggered.
+    ///
+    /// The provided closure is used to determine the current market price of an instrument for
+    /// a [`StopTrigger`] price source. Orders without an available price are not updated.
+    pub fn update<FnPrice>(
+        &mut self,
+        price: FnPrice,
+    ) -> Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>
+    where
+        FnPrice: Fn(&InstrumentKey, StopTrigger) -> Option<Decimal>,
+    {
+        let mut triggered = Vec::new();
+
+        self.orders.retain(|cid, order| {
+            let Some(price) = price(&order.request.key.instrument, order.price_source) else {
+                return true;
+            };
+
+            if !order.trigger.update(order.request.state.side, price) {
+                return true;
+            }
+
+            info!(
+                %cid,
+                %price,
+                trigger = ?order.trigger,
+                "ConditionalOrderManager triggered ConditionalOrder"
+            );
+            triggered.push(order.request.clone());
+            false
+        });
+
+        triggered
+    }
+
+    /// Persist the held [`ConditionalOrder`]s as JSON to the provided `Writer` (eg/ a `File`),
+    /// so they can be restored after a restart.
+    pub fn persist<W>(&self, writer: W) -> Result<(), serde_json::Error>
+    where
+        W: Write,
+        Self: Serialize,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Restore a `ConditionalOrderManager` previously persisted via
+    /// [`ConditionalOrderManager::persist`].
+    pub fn restore<R>(reader: R) -> Result<Self, serde_json::Error>
+    where
+        R: Read,
+        Self: for<'de> Deserialize<'de>,
+    {
+        serde_json::from_reader(reader)
+    }
+}
+
+impl ConditionalOrderManager {
+    /// Update every held [`ConditionalOrder`] with the latest [`EngineState`] instrument market
+    /// prices, returning the order requests of those that have been triggered.
+    ///
+    /// [`StopTrigger::MarkPrice`] orders fall back to the last price if the instrument mark price
+    /// is unavailable.
+    pub fn update_from_state<GlobalData, InstrumentData>(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+    ) -> Vec<OrderRequestOpen>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        self.update(|instrument, price_source| {
+            let data = &state.instruments.instrument_index(instrument).data;
+            match price_source {
+                StopTrigger::LastPrice => data.price(),
+                StopTrigger::MarkPrice => data.price_mark().or_else(|| data.price()),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::order::{
+        OrderKey, TimeInForce,
+        id::StrategyId,
+        request::{RequestCancel, RequestOpen},
+    };
+    use rust_decimal_macros::dec;
+
+    fn request(cid: &str, side: Side, kind: OrderKind) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(90),
+                quantity: dec!(1),
+                kind,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: true,
+            },
+        }
+    }
+
+    #[test]
+    fn test_trigger_update() {
+        struct TestCase {
+            trigger: Trigger,
+            side: Side,
+            prices: Vec<Decimal>,
+            expected: Vec<bool>,
+        }
+
+        let cases = vec![
+            // TC0: sell stop triggers at or below the stop price
+            TestCase {
+                trigger: Trigger::Stop { price: dec!(95) },
+                side: Side::Sell,
+                prices: vec![dec!(100), dec!(96), dec!(95)],
+                expected: vec![false, false, true],
+            },
+            // TC1: buy stop triggers at or above the stop price
+            TestCase {
+                trigger: Trigger::Stop { price: dec!(105) },
+                side: Side::Buy,
+                prices: vec![dec!(100), dec!(106)],
+                expected: vec![false, true],
+            },
+            // TC2: sell take-profit triggers at or above the take-profit price
+            TestCase {
+                trigger: Trigger::TakeProfit { price: dec!(110) },
+                side: Side::Sell,
+                prices: vec![dec!(100), dec!(110)],
+                expected: vec![false, true],
+            },
+            // TC3: buy take-profit triggers at or below the take-profit price
+            TestCase {
+                trigger: Trigger::TakeProfit { price: dec!(90) },
+                side: Side::Buy,
+                prices: vec![dec!(100), dec!(89)],
+                expected: vec![false, true],
+            },
+            // TC4: sell trailing stop ratchets up with the highest price observed
+            TestCase {
+                trigger: Trigger::trailing(dec!(0.1)),
+                side: Side::Sell,
+                prices: vec![dec!(100), dec!(120), dec!(109), dec!(108)],
+                expected: vec![false, false, false, true],
+            },
+            // TC5: buy trailing stop ratchets down with the lowest price observed
+            TestCase {
+                trigger: Trigger::trailing(dec!(0.1)),
+                side: Side::Buy,
+                prices: vec![dec!(100), dec!(80), dec!(87), dec!(88)],
+                expected: vec![false, false, false, true],
+            },
+        ];
+
+        for (index, mut test) in cases.into_iter().enumerate() {
+            let actual = test
+                .prices
+                .iter()
+                .map(|price| test.trigger.update(test.side, *price))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_conditional_order_manager() {
+        let mut manager = ConditionalOrderManager::default();
+
+        // Non-stop orders are sent unchanged
+        let limit = request("limit", Side::Buy, OrderKind::Limit);
+        assert_eq!(manager.open(limit.clone()), Some(limit));
+
+        // Stop orders are held locally
+        let stop = request(
+            "stop",
+            Side::Sell,
+            OrderKind::StopMarket {
+                trigger_price: dec!(95),
+                trigger: StopTrigger::LastPrice,
+            },
+        );
+        assert_eq!(manager.open(stop), None);
+        manager.register(ConditionalOrder {
+            request: request("take_profit", Side::Sell, OrderKind::Limit),
+            trigger: Trigger::TakeProfit { price: dec!(110) },
+            price_source: StopTrigger::MarkPrice,
+        });
+        assert_eq!(manager.orders.len(), 2);
+
+        // Held orders survive a restart
+        let mut persisted = Vec::new();
+        manager.persist(&mut persisted).unwrap();
+        let mut manager: ConditionalOrderManager =
+            ConditionalOrderManager::restore(persisted.as_slice()).unwrap();
+        assert_eq!(manager.orders.len(), 2);
+
+        // Mark price unavailable, so only the last price stop is monitored
+        let last_price = |_: &InstrumentIndex, source| match source {
+            StopTrigger::LastPrice => Some(dec!(120)),
+            StopTrigger::MarkPrice => None,
+        };
+        assert!(manager.update(last_price).is_empty());
+
+        // Take-profit triggered by the mark price is sent as a Limit order
+        let triggered = manager.update(|_, _| Some(dec!(110)));
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].key.cid, ClientOrderId::new("take_profit"));
+        assert_eq!(triggered[0].state.kind, OrderKind::Limit);
+
+        // Stop triggered by the last price is sent as a Market order
+        let triggered = manager.update(|_, _| Some(dec!(94)));
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].key.cid, ClientOrderId::new("stop"));
+        assert_eq!(triggered[0].state.kind, OrderKind::Market);
+        assert!(manager.orders.is_empty());
+    }
+
+    #[test]
+    fn test_conditional_order_manager_cancel_request() {
+        let mut manager = ConditionalOrderManager::default();
+        let stop = request(
+            "stop",
+            Side::Sell,
+            OrderKind::StopLimit {
+                trigger_price: dec!(95),
+                trigger: StopTrigger::LastPrice,
+            },
+        );
+        assert_eq!(manager.open(stop.clone()), None);
+
+        let cancel = OrderRequestCancel {
+            key: stop.key.clone(),
+            state: RequestCancel { id: None },
+        };
+        assert_eq!(manager.cancel_request(cancel.clone()), None);
+        assert!(manager.orders.is_empty());
+
+        // Orders not held are cancelled on the exchange
+        assert_eq!(manager.cancel_request(cancel.clone()), Some(cancel));
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    Timed,
+    engine::{
+        Engine,
+        state::{
+            EngineState,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        },
+    },
+    strategy::{
+        algo::AlgoStrategy,
+        close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::{
+    Side,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, marker::PhantomData};
+
+/// Optional [`DcaConfig`] logic that scales up a purchase when the price has dipped since the
+/// previous purchase.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct DcaDip {
+    /// Fractional price fall since the previous purchase that constitutes a dip
+    /// (eg/ 0.1 for 10%).
+    pub threshold: Decimal,
+
+    /// Multiplier applied to the purchase notional when the price has dipped (eg/ 2.0).
+    pub multiplier: Decimal,
+}
+
+/// Configuration of a [`DcaStrategy`] accumulation schedule.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct DcaConfig {
+    /// Instrument that is accumulated.
+    pub instrument: InstrumentIndex,
+
+    /// Quote notional value of each scheduled purchase.
+    pub notional: Decimal,
+
+    /// Interval of `Engine` time between scheduled purchases.
+    pub interval: TimeDelta,
+
+    /// Optional [`DcaDip`] logic that scales up purchases after a price dip.
+    pub dip: Option<DcaDip>,
+}
+
+impl DcaConfig {
+    /// Determine the quantity of the next purchase, if one is due at `time_now`.
+    ///
+    /// A purchase is due if there has been no previous purchase, or if the `interval` has elapsed
+    /// since the previous purchase.
+    pub fn purchase_quantity(
+        &self,
+        previous: Option<Timed<Decimal>>,
+        time_now: DateTime<Utc>,
+        price: Decimal,
+    ) -> Option<Decimal> {
+        if price <= Decimal::ZERO {
+            return None;
+        }
+
+        let notional = match previous {
+            None => self.notional,
+            Some(previous) if time_now - previous.time < self.interval => return None,
+            Some(previous) => match self.dip {
+                Some(dip) if price <= previous.value * (Decimal::ONE - dip.threshold) => {
+                    self.notional * dip.multiplier
+                }
+                _ => self.notional,
+            },
+        };
+
+        Some(notional / price)
+    }
+}
+
+/// Dollar-cost averaging (DCA) strategy that accumulates an instrument by buying a fixed
+/// notional value on a schedule driven by the `Engine` clock.
+///
+/// *THIS IS A REFERENCE IMPLEMENTATION, REVIEW CAREFULLY BEFORE USING FOR REAL TRADING*.
+///
+/// Since the schedule is measured against the [`EngineState`] `time_engine_now`, back-tests use
+/// the historical clock. Note a purchase is only generated when the `Engine` processes an event,
+/// so a purchase may be generated later than scheduled if events are sparse.
+///
+/// This strategy:
+/// - Generates an `ImmediateOrCancel` `Market` BUY order each time a purchase is due, scaled up
+///   by the optional [`DcaDip`] multiplier if the price has dipped (AlgoStrategy).
+/// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct DcaStrategy<State> {
+    pub id: StrategyId,
+    pub config: DcaConfig,
+    previous: Cell<Option<Timed<Decimal>>>,
+    sequence: Cell<u64>,
+    phantom: PhantomData<State>,
+}
+
+impl<State> DcaStrategy<State> {
+    /// Construct a new `DcaStrategy` using the provided [`StrategyId`] and [`DcaConfig`].
+    pub fn new(id: StrategyId, config: DcaConfig) -> Self {
+        Self {
+            id,
+            config,
+            previous: Cell::new(None),
+            sequence: Cell::new(0),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Price and time of the previous purchase generated, if any.
+    pub fn previous(&self) -> Option<Timed<Decimal>> {
+        self.previous.get()
+    }
+
+    /// Generate the next deterministic [`ClientOrderId`] for this strategy.
+    ///
+    /// The sequence is persisted alongside the strategy, so a resumed strategy continues the
+    /// sequence rather than re-issuing a [`ClientOrderId`].
+    fn next_cid(&self) -> ClientOrderId {
+        let sequence = self.sequence.get();
+        self.sequence.set(sequence + 1);
+        ClientOrderId::from_sequence(&self.id, sequence)
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy
+    for DcaStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let instrument_state = state.instruments.instrument_index(&self.config.instrument);
+
+        // Skip if a previous purchase is still active, or if there is no market data
+        let has_active_order = instrument_state
+            .orders
+            .0
+            .values()
+            .any(|order| order.key.strategy == self.id);
+
+        let Some(price) = instrument_state.data.price().filter(|_| !has_active_order) else {
+            return (std::iter::empty(), None);
+        };
+
+        let time_now = state.time_engine_now;
+        let Some(quantity) = self
+            .config
+            .purchase_quantity(self.previous.get(), time_now, price)
+        else {
+            return (std::iter::empty(), None);
+        };
+
+        self.previous.set(Some(Timed::new(price, time_now)));
+
+        let request = OrderRequestOpen {
+            key: OrderKey {
+                exchange: instrument_state.instrument.exchange,
+                instrument: self.config.instrument,
+                strategy: self.id.clone(),
+                cid: self.next_cid(),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price,
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        };
+
+        (std::iter::empty(), Some(request))
+    }
+}
+
+impl<GlobalData, InstrumentData> ClosePositionsStrategy
+    for DcaStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.id, state, filter, |_| self.next_cid())
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>
+    for DcaStrategy<State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for DcaStrategy<State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_days;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_dca_config_purchase_quantity() {
+        struct TestCase {
+            previous: Option<Timed<Decimal>>,
+            time_now: DateTime<Utc>,
+            price: Decimal,
+            expected: Option<Decimal>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let config = DcaConfig::new(
+            InstrumentIndex(0),
+            dec!(100),
+            TimeDelta::days(7),
+            Some(DcaDip::new(dec!(0.1), dec!(2))),
+        );
+
+        let cases = vec![
+            // TC0: first purchase is always due
+            TestCase {
+                previous: None,
+                time_now: base,
+                price: dec!(50),
+                expected: Some(dec!(2)),
+            },
+            // TC1: purchase not due before interval has elapsed
+            TestCase {
+                previous: Some(Timed::new(dec!(50), base)),
+                time_now: time_plus_days(base, 6),
+                price: dec!(50),
+                expected: None,
+            },
+            // TC2: purchase due once interval has elapsed
+            TestCase {
+                previous: Some(Timed::new(dec!(50), base)),
+                time_now: time_plus_days(base, 7),
+                price: dec!(50),
+                expected: Some(dec!(2)),
+            },
+            // TC3: purchase notional multiplied after a dip
+            TestCase {
+                previous: Some(Timed::new(dec!(50), base)),
+                time_now: time_plus_days(base, 7),
+                price: dec!(40),
+                expected: Some(dec!(5)),
+            },
+            // TC4: purchase notional not multiplied if price fall is below dip threshold
+            TestCase {
+                previous: Some(Timed::new(dec!(50), base)),
+                time_now: time_plus_days(base, 7),
+                price: dec!(46),
+                expected: Some(dec!(100) / dec!(46)),
+            },
+            // TC5: no purchase without a valid price
+            TestCase {
+                previous: None,
+                time_now: base,
+                price: dec!(0),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = config.purchase_quantity(test.previous, test.time_now, test.price);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_dca_strategy_next_cid() {
+        let config = DcaConfig::new(InstrumentIndex(0), dec!(100), TimeDelta::days(7), None);
+        let strategy = DcaStrategy::<()>::new(StrategyId::new("dca"), config);
+        let replay = strategy.clone();
+
+        // Unique within a strategy
+        let cids = (0..3).map(|_| strategy.next_cid()).collect::<Vec<_>>();
+        assert_eq!(
+            cids,
+            vec![
+                ClientOrderId::new("dca-0"),
+                ClientOrderId::new("dca-1"),
+                ClientOrderId::new("dca-2"),
+            ]
+        );
+
+        // Deterministic when replayed from the same starting state
+        let replayed = (0..3).map(|_| replay.next_cid()).collect::<Vec<_>>();
+        assert_eq!(replayed, cids);
+
+        // Sequence continues after a resume from persisted state
+        let resumed: DcaStrategy<()> =
+            serde_json::from_value(serde_json::to_value(&strategy).unwrap()).unwrap();
+        assert_eq!(resumed.next_cid(), ClientOrderId::new("dca-3"));
+    }
+}
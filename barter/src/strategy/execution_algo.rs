@@ -0,0 +1,431 @@
+use barter_execution::{
+    order::{
+        id::{ClientOrderId, OrderId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::{AssetFees, Trade, TradeId},
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Execution algorithm used by an [`ExecutionAlgoManager`] to schedule the child orders of a
+/// large parent order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum ExecutionAlgo {
+    /// Time-weighted average price: split the parent quantity into equal slices, sent at even
+    /// intervals across the `duration`.
+    Twap { duration: TimeDelta, slices: u32 },
+
+    /// Volume-weighted average price: participate in a fixed proportion of the market volume
+    /// traded since the parent order was opened.
+    ///
+    /// Child orders smaller than the `min_child_quantity` are deferred until enough market volume
+    /// has accumulated, unless they complete the parent order.
+    Vwap {
+        participation: Decimal,
+        min_child_quantity: Decimal,
+    },
+}
+
+/// Parent order being worked by an [`ExecutionAlgoManager`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AlgoParent<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    pub request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    pub algo: ExecutionAlgo,
+    pub time_start: DateTime<Utc>,
+
+    /// Parent quantity sent to the exchange via child orders.
+    pub quantity_sent: Decimal,
+
+    /// Parent quantity filled across all child orders.
+    pub quantity_filled: Decimal,
+
+    /// Sum of fill price * quantity across all child orders.
+    pub notional_filled: Decimal,
+
+    /// Sum of fees paid across all child orders.
+    pub fees_filled: Decimal,
+
+    /// Market volume traded since the parent order was opened (used by [`ExecutionAlgo::Vwap`]).
+    pub volume_observed: Decimal,
+
+    /// Number of child orders generated so far.
+    pub sequence: u64,
+}
+
+impl<ExchangeKey, InstrumentKey> AlgoParent<ExchangeKey, InstrumentKey> {
+    /// Proportion of the parent quantity that has been filled, in the range [0, 1].
+    pub fn fill_progress(&self) -> Decimal {
+        if self.request.state.quantity.is_zero() {
+            return Decimal::ONE;
+        }
+        (self.quantity_filled / self.request.state.quantity).min(Decimal::ONE)
+    }
+
+    /// Average fill price across all child orders, or `None` if nothing has been filled.
+    pub fn average_price(&self) -> Option<Decimal> {
+        (!self.quantity_filled.is_zero()).then(|| self.notional_filled / self.quantity_filled)
+    }
+
+    /// Cumulative parent quantity scheduled to have been sent by the provided time.
+    pub fn quantity_due(&self, time: DateTime<Utc>) -> Decimal {
+        let quantity = self.request.state.quantity;
+
+        match self.algo {
+            ExecutionAlgo::Twap { duration, slices } => {
+                let slices = i64::from(slices.max(1));
+                let duration_ms = duration.num_milliseconds();
+                let slices_due = if duration_ms <= 0 {
+                    slices
+                } else {
+                    let elapsed_ms = (time - self.time_start).num_milliseconds();
+                    (elapsed_ms * slices / duration_ms + 1).clamp(1, slices)
+                };
+
+                quantity * Decimal::from(slices_due) / Decimal::from(slices)
+            }
+            ExecutionAlgo::Vwap { participation, .. } => {
+                (participation * self.volume_observed).min(quantity)
+            }
+        }
+    }
+}
+
+/// Engine-side execution algorithm layer that splits large parent orders into scheduled child
+/// orders (see [`ExecutionAlgo`]), tracking aggregate fill progress.
+///
+/// Once a parent order is fully filled, its child fills are reported as a single consolidated
+/// [`Trade`] at the average fill price, suitable for updating the portfolio.
+///
+/// Child order [`ClientOrderId`]s are derived from the parent (eg/ "parent-1", "parent-2"), and
+/// fills are correlated back to the parent via the [`Trade`] `cid`.
+///
+/// Note that child order quantities are not rounded to the instrument lot size.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExecutionAlgoManager<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    pub parents: FnvHashMap<ClientOrderId, AlgoParent<ExchangeKey, InstrumentKey>>,
+    children: FnvHashMap<ClientOrderId, ClientOrderId>,
+}
+
+impl<ExchangeKey, InstrumentKey> Default for ExecutionAlgoManager<ExchangeKey, InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            parents: FnvHashMap::default(),
+            children: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> ExecutionAlgoManager<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Clone,
+    InstrumentKey: Clone + PartialEq,
+{
+    /// Register a parent order request to be worked by the provided [`ExecutionAlgo`], returning
+    /// any child order requests that are immediately due.
+    pub fn open(
+        &mut self,
+        request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+        algo: ExecutionAlgo,
+        time: DateTime<Utc>,
+    ) -> Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        let parent_cid = request.key.cid.clone();
+        self.parents.insert(
+            parent_cid.clone(),
+            AlgoParent {
+                request,
+                algo,
+                time_start: time,
+                quantity_sent: Decimal::ZERO,
+                quantity_filled: Decimal::ZERO,
+                notional_filled: Decimal::ZERO,
+                fees_filled: Decimal::ZERO,
+                volume_observed: Decimal::ZERO,
+                sequence: 0,
+            },
+        );
+
+        self.generate_parent(&parent_cid, time)
+            .into_iter()
+            .collect()
+    }
+
+    /// Update the market volume observed by [`ExecutionAlgo::Vwap`] parent orders of the
+    /// provided instrument (eg/ from a public trade).
+    pub fn update_from_market_volume(&mut self, instrument: &InstrumentKey, volume: Decimal) {
+        self.parents
+            .values_mut()
+            .filter(|parent| &parent.request.key.instrument == instrument)
+            .for_each(|parent| parent.volume_observed += volume);
+    }
+
+    /// Generate the child order requests of all parent orders that are due at the provided time.
+    pub fn generate(
+        &mut self,
+        time: DateTime<Utc>,
+    ) -> Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        let parents = self.parents.keys().cloned().collect::<Vec<_>>();
+        parents
+            .iter()
+            .filter_map(|parent| self.generate_parent(parent, time))
+            .collect()
+    }
+
+    /// Update the `ExecutionAlgoManager` from a child order fill, returning a single
+    /// consolidated [`Trade`] once the parent order has been fully filled.
+    ///
+    /// Fills of orders that are not child orders are ignored.
+    pub fn update_from_trade<AssetKey, TradeInstrumentKey>(
+        &mut self,
+        trade: &Trade<AssetKey, TradeInstrumentKey>,
+    ) -> Option<Trade<AssetKey, TradeInstrumentKey>>
+    where
+        AssetKey: Clone,
+        TradeInstrumentKey: Clone,
+    {
+        let parent_cid = self.children.get(trade.cid.as_ref()?)?.clone();
+        let parent = self.parents.get_mut(&parent_cid)?;
+
+        parent.quantity_filled += trade.quantity;
+        parent.notional_filled += trade.price * trade.quantity;
+        parent.fees_filled += trade.fees.fees;
+
+        if parent.quantity_filled < parent.request.state.quantity {
+            return None;
+        }
+
+        let parent = self.cancel(&parent_cid)?.0;
+
+        Some(Trade {
+            id: TradeId::new(&parent_cid.0),
+            order_id: OrderId::new(&parent_cid.0),
+            cid: Some(parent_cid),
+            instrument: trade.instrument.clone(),
+            strategy: parent.request.key.strategy.clone(),
+            time_exchange: trade.time_exchange,
+            side: parent.request.state.side,
+            price: parent.average_price().unwrap_or(trade.price),
+            quantity: parent.quantity_filled,
+            liquidity: None,
+            fees: AssetFees {
+                asset: trade.fees.asset.clone(),
+                fees: parent.fees_filled,
+            },
+        })
+    }
+
+    /// Stop working the parent order, returning the [`AlgoParent`] and the [`ClientOrderId`]s of
+    /// its child orders, so any still working child orders can be cancelled.
+    pub fn cancel(
+        &mut self,
+        parent: &ClientOrderId,
+    ) -> Option<(AlgoParent<ExchangeKey, InstrumentKey>, Vec<ClientOrderId>)> {
+        let parent_algo = self.parents.remove(parent)?;
+
+        let children = self
+            .children
+            .iter()
+            .filter(|(_, child_parent)| *child_parent == parent)
+            .map(|(child, _)| child.clone())
+            .collect::<Vec<_>>();
+
+        children.iter().for_each(|child| {
+            self.children.remove(child);
+        });
+
+        Some((parent_algo, children))
+    }
+
+    fn generate_parent(
+        &mut self,
+        parent_cid: &ClientOrderId,
+        time: DateTime<Utc>,
+    ) -> Option<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        let parent = self.parents.get_mut(parent_cid)?;
+
+        let quantity_due = parent.quantity_due(time);
+        let child_quantity = quantity_due - parent.quantity_sent;
+        if child_quantity <= Decimal::ZERO {
+            return None;
+        }
+
+        let below_min_child = match parent.algo {
+            ExecutionAlgo::Twap { .. } => false,
+            ExecutionAlgo::Vwap {
+                min_child_quantity, ..
+            } => child_quantity < min_child_quantity,
+        };
+        if below_min_child && quantity_due < parent.request.state.quantity {
+            return None;
+        }
+
+        parent.sequence += 1;
+        parent.quantity_sent = quantity_due;
+
+        let mut key = parent.request.key.clone();
+        key.cid = ClientOrderId::new(format!("{}-{}", parent_cid, parent.sequence));
+        self.children.insert(key.cid.clone(), parent_cid.clone());
+
+        Some(OrderRequestOpen {
+            key,
+            state: RequestOpen {
+                quantity: child_quantity,
+                ..parent.request.state.clone()
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{time_plus_secs, trade};
+    use barter_execution::order::{OrderKey, OrderKind, TimeInForce, id::StrategyId};
+    use barter_instrument::{Side, asset::QuoteAsset, instrument::name::InstrumentNameInternal};
+    use rust_decimal_macros::dec;
+
+    fn parent(quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("parent"),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn fill(cid: &str, price: f64, quantity: f64) -> Trade<QuoteAsset, InstrumentNameInternal> {
+        Trade {
+            cid: Some(ClientOrderId::new(cid)),
+            ..trade(DateTime::<Utc>::MIN_UTC, Side::Buy, price, quantity, 1.0)
+        }
+    }
+
+    fn quantities(requests: Vec<OrderRequestOpen>) -> Vec<Decimal> {
+        requests
+            .into_iter()
+            .map(|request| request.state.quantity)
+            .collect()
+    }
+
+    #[test]
+    fn test_execution_algo_twap_schedule() {
+        struct TestCase {
+            elapsed_secs: i64,
+            expected: Vec<Decimal>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut manager = ExecutionAlgoManager::default();
+
+        // First slice is sent immediately
+        let algo = ExecutionAlgo::Twap {
+            duration: TimeDelta::seconds(40),
+            slices: 4,
+        };
+        assert_eq!(
+            quantities(manager.open(parent(dec!(8)), algo, base)),
+            vec![dec!(2)]
+        );
+
+        let cases = vec![
+            // TC0: second slice not yet due
+            TestCase {
+                elapsed_secs: 5,
+                expected: vec![],
+            },
+            // TC1: second slice due
+            TestCase {
+                elapsed_secs: 10,
+                expected: vec![dec!(2)],
+            },
+            // TC2: missed slices are caught up
+            TestCase {
+                elapsed_secs: 35,
+                expected: vec![dec!(4)],
+            },
+            // TC3: all slices sent
+            TestCase {
+                elapsed_secs: 60,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = manager.generate(time_plus_secs(base, test.elapsed_secs));
+            assert_eq!(quantities(actual), test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_execution_algo_vwap_participation() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut manager = ExecutionAlgoManager::default();
+
+        let algo = ExecutionAlgo::Vwap {
+            participation: dec!(0.1),
+            min_child_quantity: dec!(1),
+        };
+        assert!(manager.open(parent(dec!(5)), algo, base).is_empty());
+
+        // Participation quantity below the minimum child quantity is deferred
+        manager.update_from_market_volume(&InstrumentIndex(0), dec!(5));
+        assert!(manager.generate(base).is_empty());
+
+        // Other instrument volume is ignored
+        manager.update_from_market_volume(&InstrumentIndex(1), dec!(100));
+        assert!(manager.generate(base).is_empty());
+
+        manager.update_from_market_volume(&InstrumentIndex(0), dec!(15));
+        assert_eq!(quantities(manager.generate(base)), vec![dec!(2)]);
+
+        // Participation capped at the remaining parent quantity
+        manager.update_from_market_volume(&InstrumentIndex(0), dec!(1000));
+        assert_eq!(quantities(manager.generate(base)), vec![dec!(3)]);
+    }
+
+    #[test]
+    fn test_execution_algo_update_from_trade_consolidated_fill() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let mut manager = ExecutionAlgoManager::default();
+
+        let algo = ExecutionAlgo::Twap {
+            duration: TimeDelta::zero(),
+            slices: 2,
+        };
+        let children = manager.open(parent(dec!(4)), algo, base);
+        assert_eq!(quantities(children), vec![dec!(4)]);
+
+        // Partial fill progress is tracked without reporting a fill
+        assert_eq!(
+            manager.update_from_trade(&fill("parent-1", 100.0, 1.0)),
+            None
+        );
+        let progress = manager.parents[&ClientOrderId::new("parent")].fill_progress();
+        assert_eq!(progress, dec!(0.25));
+
+        // Unrelated fills are ignored
+        assert_eq!(manager.update_from_trade(&fill("other", 100.0, 3.0)), None);
+
+        let actual = manager
+            .update_from_trade(&fill("parent-1", 104.0, 3.0))
+            .unwrap();
+        assert_eq!(actual.cid, Some(ClientOrderId::new("parent")));
+        assert_eq!(actual.quantity, dec!(4));
+        assert_eq!(actual.price, dec!(103));
+        assert_eq!(actual.fees.fees, dec!(2));
+        assert!(manager.parents.is_empty());
+    }
+}
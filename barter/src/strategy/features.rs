@@ -0,0 +1,306 @@
+use crate::strategy::risk_parity::realised_volatility;
+use barter_data::{
+    books::{Level, OrderBook},
+    event::DataKind,
+    subscription::{
+        book::{OrderBookEvent, OrderBookL1},
+        trade::PublicTrade,
+    },
+};
+use barter_instrument::Side;
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Configuration of [`MicrostructureFeatures`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct FeaturesConfig {
+    /// Number of most recent observations (ie/ trades or top of book updates) each rolling
+    /// feature is calculated over.
+    pub window: usize,
+
+    /// Number of L2 order book levels on each side used to calculate the book imbalance.
+    pub depth: usize,
+}
+
+/// Best bid and ask [`Level`]s of an order book.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TopOfBook {
+    pub bid: Level,
+    pub ask: Level,
+}
+
+/// Streaming microstructure features of an instrument, updated from public trades and order
+/// book events, usable as inputs to signal generators.
+///
+/// Features:
+/// - Order-flow imbalance (OFI): rolling sum of the net change in best bid and ask liquidity.
+/// - Trade aggressor ratio: proportion of rolling traded volume initiated by buyers.
+/// - Realised volatility: sample standard deviation of rolling trade price returns.
+/// - Book imbalance: bid vs ask liquidity across the top L2 order book levels.
+///
+/// Note that L2 `OrderBookEvent::Update`s are deltas, so should be applied to a maintained
+/// [`OrderBook`] which is then passed to [`Self::update_from_book`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MicrostructureFeatures {
+    pub config: FeaturesConfig,
+    pub book_imbalance: Option<Decimal>,
+    top_of_book: Option<TopOfBook>,
+    order_flow: VecDeque<Decimal>,
+    trades: VecDeque<(Side, Decimal)>,
+    prices: VecDeque<Decimal>,
+}
+
+impl MicrostructureFeatures {
+    /// Construct a new empty `MicrostructureFeatures` with the provided [`FeaturesConfig`].
+    pub fn new(config: FeaturesConfig) -> Self {
+        Self {
+            config,
+            book_imbalance: None,
+            top_of_book: None,
+            order_flow: VecDeque::with_capacity(config.window),
+            trades: VecDeque::with_capacity(config.window),
+            prices: VecDeque::with_capacity(config.window + 1),
+        }
+    }
+
+    /// Update the features from the next [`DataKind`] market event.
+    ///
+    /// Only trades, L1 order books, and L2 order book snapshots are used.
+    pub fn update_from_market(&mut self, kind: &DataKind) {
+        match kind {
+            DataKind::Trade(trade) => self.update_from_trade(trade),
+            DataKind::OrderBookL1(book) => self.update_from_l1(book),
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => self.update_from_book(book),
+            _ => {}
+        }
+    }
+
+    /// Update the trade aggressor ratio and realised volatility from the next [`PublicTrade`].
+    pub fn update_from_trade(&mut self, trade: &PublicTrade) {
+        let (Ok(price), Ok(amount)) = (
+            Decimal::try_from(trade.price),
+            Decimal::try_from(trade.amount),
+        ) else {
+            return;
+        };
+
+        push_bounded(&mut self.trades, (trade.side, amount), self.config.window);
+        push_bounded(&mut self.prices, price, self.config.window + 1);
+    }
+
+    /// Update the order-flow imbalance from the next [`OrderBookL1`].
+    pub fn update_from_l1(&mut self, book: &OrderBookL1) {
+        if let (Some(bid), Some(ask)) = (book.best_bid, book.best_ask) {
+            self.update_top_of_book(TopOfBook { bid, ask });
+        }
+    }
+
+    /// Update the book imbalance and order-flow imbalance from the latest full L2 [`OrderBook`].
+    pub fn update_from_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = (book.bids().levels(), book.asks().levels());
+
+        self.book_imbalance = book_imbalance(
+            &bids[..self.config.depth.min(bids.len())],
+            &asks[..self.config.depth.min(asks.len())],
+        );
+
+        if let (Some(bid), Some(ask)) = (bids.first(), asks.first()) {
+            self.update_top_of_book(TopOfBook {
+                bid: *bid,
+                ask: *ask,
+            });
+        }
+    }
+
+    fn update_top_of_book(&mut self, current: TopOfBook) {
+        if let Some(previous) = self.top_of_book.replace(current) {
+            push_bounded(
+                &mut self.order_flow,
+                order_flow_imbalance(&previous, &current),
+                self.config.window,
+            );
+        }
+    }
+
+    /// Rolling order-flow imbalance, in base asset units.
+    ///
+    /// Positive values indicate net buying pressure at the top of book.
+    pub fn order_flow_imbalance(&self) -> Decimal {
+        self.order_flow.iter().sum()
+    }
+
+    /// Proportion (0 to 1) of the rolling traded volume initiated by buyers (ie/ aggressor
+    /// `Side::Buy`).
+    ///
+    /// Returns `None` if no volume has been traded.
+    pub fn trade_aggressor_ratio(&self) -> Option<Decimal> {
+        let (buys, total) = self.trades.iter().fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(buys, total), (side, amount)| match side {
+                Side::Buy => (buys + amount, total + amount),
+                Side::Sell => (buys, total + amount),
+            },
+        );
+
+        (!total.is_zero()).then(|| buys / total)
+    }
+
+    /// Rolling realised volatility of trade price returns (see [`realised_volatility`]).
+    pub fn realised_volatility(&self) -> Option<Decimal> {
+        realised_volatility(&self.prices, self.config.window)
+    }
+}
+
+fn push_bounded<T>(values: &mut VecDeque<T>, value: T, capacity: usize) {
+    if capacity == 0 {
+        return;
+    }
+    if values.len() == capacity {
+        values.pop_front();
+    }
+    values.push_back(value);
+}
+
+/// Order-flow imbalance (Cont, Kukanov & Stoikov) contribution of a top of book update.
+///
+/// Bid liquidity added at an equal or better price counts as buying pressure, and ask liquidity
+/// added at an equal or better price counts as selling pressure.
+pub fn order_flow_imbalance(previous: &TopOfBook, current: &TopOfBook) -> Decimal {
+    let bid_flow = if current.bid.price > previous.bid.price {
+        current.bid.amount
+    } else if current.bid.price == previous.bid.price {
+        current.bid.amount - previous.bid.amount
+    } else {
+        -previous.bid.amount
+    };
+
+    let ask_flow = if current.ask.price < previous.ask.price {
+        current.ask.amount
+    } else if current.ask.price == previous.ask.price {
+        current.ask.amount - previous.ask.amount
+    } else {
+        -previous.ask.amount
+    };
+
+    bid_flow - ask_flow
+}
+
+/// Book imbalance (-1 to 1) of the provided bid and ask levels, where positive values indicate
+/// more bid liquidity than ask liquidity.
+///
+/// Returns `None` if there is no liquidity.
+pub fn book_imbalance(bids: &[Level], asks: &[Level]) -> Option<Decimal> {
+    let bid_volume = bids.iter().map(|level| level.amount).sum::<Decimal>();
+    let ask_volume = asks.iter().map(|level| level.amount).sum::<Decimal>();
+    let total = bid_volume + ask_volume;
+
+    (!total.is_zero()).then(|| (bid_volume - ask_volume) / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn top(
+        bid_price: Decimal,
+        bid_amount: Decimal,
+        ask_price: Decimal,
+        ask_amount: Decimal,
+    ) -> TopOfBook {
+        TopOfBook {
+            bid: Level::new(bid_price, bid_amount),
+            ask: Level::new(ask_price, ask_amount),
+        }
+    }
+
+    #[test]
+    fn test_order_flow_imbalance() {
+        struct TestCase {
+            previous: TopOfBook,
+            current: TopOfBook,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: unchanged top of book
+            TestCase {
+                previous: top(dec!(100), dec!(1), dec!(101), dec!(1)),
+                current: top(dec!(100), dec!(1), dec!(101), dec!(1)),
+                expected: dec!(0),
+            },
+            // TC1: bid liquidity added at the same price
+            TestCase {
+                previous: top(dec!(100), dec!(1), dec!(101), dec!(1)),
+                current: top(dec!(100), dec!(3), dec!(101), dec!(1)),
+                expected: dec!(2),
+            },
+            // TC2: bid price improves
+            TestCase {
+                previous: top(dec!(100), dec!(1), dec!(101), dec!(1)),
+                current: top(dec!(100.5), dec!(2), dec!(101), dec!(1)),
+                expected: dec!(2),
+            },
+            // TC3: ask price improves (selling pressure)
+            TestCase {
+                previous: top(dec!(100), dec!(1), dec!(101), dec!(1)),
+                current: top(dec!(100), dec!(1), dec!(100.5), dec!(4)),
+                expected: dec!(-4),
+            },
+            // TC4: ask level consumed (buying pressure)
+            TestCase {
+                previous: top(dec!(100), dec!(1), dec!(101), dec!(2)),
+                current: top(dec!(100), dec!(1), dec!(102), dec!(5)),
+                expected: dec!(2),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = order_flow_imbalance(&test.previous, &test.current);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_book_imbalance() {
+        let level = |amount| Level::new(dec!(1), amount);
+
+        assert_eq!(book_imbalance(&[], &[]), None);
+        assert_eq!(
+            book_imbalance(&[level(dec!(3))], &[level(dec!(1))]),
+            Some(dec!(0.5))
+        );
+        assert_eq!(
+            book_imbalance(&[level(dec!(1))], &[level(dec!(1)), level(dec!(2))]),
+            Some(dec!(-0.5))
+        );
+    }
+
+    #[test]
+    fn test_microstructure_features_update_from_trade() {
+        let mut features = MicrostructureFeatures::new(FeaturesConfig::new(3, 5));
+        let trade = |price, amount, side| PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side,
+        };
+
+        assert_eq!(features.trade_aggressor_ratio(), None);
+
+        features.update_from_trade(&trade(100.0, 3.0, Side::Buy));
+        features.update_from_trade(&trade(101.0, 1.0, Side::Sell));
+        assert_eq!(features.trade_aggressor_ratio(), Some(dec!(0.75)));
+        assert_eq!(features.realised_volatility(), None);
+
+        // Oldest trade leaves the rolling window
+        features.update_from_trade(&trade(102.0, 1.0, Side::Sell));
+        features.update_from_trade(&trade(101.0, 2.0, Side::Sell));
+        assert_eq!(features.trade_aggressor_ratio(), Some(dec!(0)));
+        assert!(features.realised_volatility().is_some());
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{
+    engine::{
+        Engine,
+        state::{
+            EngineState,
+            instrument::{InstrumentState, data::InstrumentDataState, filter::InstrumentFilter},
+        },
+    },
+    strategy::{
+        algo::AlgoStrategy,
+        close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::{
+    Order, OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+    state::ActiveOrderState,
+};
+use barter_instrument::{
+    Side,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, marker::PhantomData};
+
+/// Configuration of a [`GridStrategy`] ladder of resting limit orders.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct GridConfig {
+    /// Instrument the grid is traded on.
+    pub instrument: InstrumentIndex,
+
+    /// Price of the lowest grid level.
+    pub price_lower: Decimal,
+
+    /// Price of the highest grid level.
+    pub price_upper: Decimal,
+
+    /// Number of evenly spaced grid levels between `price_lower` and `price_upper` (inclusive).
+    pub levels: usize,
+
+    /// Quantity of each grid level limit order.
+    pub quantity: Decimal,
+}
+
+impl GridConfig {
+    /// Evenly spaced grid level prices, ascending from `price_lower` to `price_upper`.
+    ///
+    /// Returns no levels if the `GridConfig` is invalid (ie/ fewer than two levels, or
+    /// `price_lower` is not below `price_upper`).
+    pub fn level_prices(&self) -> Vec<Decimal> {
+        if self.levels < 2 || self.price_lower >= self.price_upper {
+            return vec![];
+        }
+
+        let step = (self.price_upper - self.price_lower) / Decimal::from(self.levels - 1);
+
+        (0..self.levels)
+            .map(|level| self.price_lower + step * Decimal::from(level))
+            .collect()
+    }
+
+    /// Returns true if the provided price is within the grid price range.
+    pub fn contains(&self, price: Decimal) -> bool {
+        price >= self.price_lower && price <= self.price_upper
+    }
+}
+
+/// Grid trading strategy that maintains a ladder of resting limit orders across a configured
+/// price range.
+///
+/// *THIS IS A REFERENCE IMPLEMENTATION, REVIEW CAREFULLY BEFORE USING FOR REAL TRADING*.
+///
+/// This strategy:
+/// - Rests a post-only `Limit` BUY order at every grid level below the current price, and a
+///   post-only `Limit` SELL order at every grid level above it (AlgoStrategy).
+/// - Replaces grid level orders once they are filled, since the filled level is then re-quoted
+///   on the appropriate side as the price moves away from it (AlgoStrategy).
+/// - Cancels all resting grid orders while the price is outside the grid range (AlgoStrategy).
+/// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+///
+/// Note that grid levels are identified by their order price, so existing orders for a level
+/// (including those in-flight) are never duplicated.
+#[derive(Debug, Clone)]
+pub struct GridStrategy<State> {
+    pub id: StrategyId,
+    pub config: GridConfig,
+    phantom: PhantomData<State>,
+}
+
+impl<State> GridStrategy<State> {
+    /// Construct a new `GridStrategy` using the provided [`StrategyId`] and [`GridConfig`].
+    pub fn new(id: StrategyId, config: GridConfig) -> Self {
+        Self {
+            id,
+            config,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns an `Iterator` of the active orders generated by this `GridStrategy`.
+    fn grid_orders<'a, InstrumentData>(
+        &'a self,
+        instrument_state: &'a InstrumentState<InstrumentData>,
+    ) -> impl Iterator<Item = &'a Order<ExchangeIndex, InstrumentIndex, ActiveOrderState>> + 'a
+    {
+        instrument_state
+            .orders
+            .0
+            .values()
+            .filter(|order| order.key.strategy == self.id)
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy
+    for GridStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let instrument_state = state.instruments.instrument_index(&self.config.instrument);
+
+        let Some(price) = instrument_state.data.price() else {
+            return (vec![], vec![]);
+        };
+
+        // Stop quoting the grid while the price is outside the grid range
+        if !self.config.contains(price) {
+            let cancels = self
+                .grid_orders(instrument_state)
+                .filter_map(Order::to_request_cancel)
+                .collect();
+
+            return (cancels, vec![]);
+        }
+
+        let opens = self
+            .config
+            .level_prices()
+            .into_iter()
+            .filter(|level_price| {
+                self.grid_orders(instrument_state)
+                    .all(|order| order.price != *level_price)
+            })
+            .filter_map(|level_price| {
+                let side = match level_price.cmp(&price) {
+                    Ordering::Less => Side::Buy,
+                    Ordering::Greater => Side::Sell,
+                    Ordering::Equal => return None,
+                };
+
+                Some(OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: instrument_state.instrument.exchange,
+                        instrument: self.config.instrument,
+                        strategy: self.id.clone(),
+                        cid: ClientOrderId::random(),
+                    },
+                    state: RequestOpen {
+                        side,
+                        price: level_price,
+                        quantity: self.config.quantity,
+                        kind: OrderKind::Limit,
+                        time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                        reduce_only: false,
+                    },
+                })
+            })
+            .collect();
+
+        (vec![], opens)
+    }
+}
+
+impl<GlobalData, InstrumentData> ClosePositionsStrategy
+    for GridStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.id, state, filter, |_| {
+            ClientOrderId::random()
+        })
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>
+    for GridStrategy<State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for GridStrategy<State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_grid_config_level_prices() {
+        struct TestCase {
+            config: GridConfig,
+            expected: Vec<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: evenly spaced levels including range bounds
+            TestCase {
+                config: GridConfig::new(InstrumentIndex(0), dec!(90), dec!(110), 5, dec!(1)),
+                expected: vec![dec!(90), dec!(95), dec!(100), dec!(105), dec!(110)],
+            },
+            // TC1: two levels at range bounds
+            TestCase {
+                config: GridConfig::new(InstrumentIndex(0), dec!(90), dec!(110), 2, dec!(1)),
+                expected: vec![dec!(90), dec!(110)],
+            },
+            // TC2: single level is invalid
+            TestCase {
+                config: GridConfig::new(InstrumentIndex(0), dec!(90), dec!(110), 1, dec!(1)),
+                expected: vec![],
+            },
+            // TC3: inverted range is invalid
+            TestCase {
+                config: GridConfig::new(InstrumentIndex(0), dec!(110), dec!(90), 5, dec!(1)),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(
+                test.config.level_prices(),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+}
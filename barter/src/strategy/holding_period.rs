@@ -0,0 +1,203 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{InstrumentState, data::InstrumentDataState, filter::InstrumentFilter},
+        position::Position,
+    },
+    strategy::close_positions::build_ioc_market_order_to_close_position,
+};
+use barter_execution::order::{
+    id::{ClientOrderId, StrategyId},
+    request::OrderRequestOpen,
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+
+/// Time-based exit policy that closes any [`Position`] held for the maximum holding period or
+/// longer.
+///
+/// Useful for intraday strategies that must be flat by session end. Since the holding period is
+/// measured against the [`EngineState`] `time_engine_now`, back-tests use the historical clock.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct MaxHoldingPeriod {
+    /// Maximum duration a [`Position`] can be held before an exit order is generated.
+    pub max: TimeDelta,
+}
+
+impl MaxHoldingPeriod {
+    /// Returns true if the provided [`Position`] has been held for the maximum holding period or
+    /// longer at `time_now`.
+    pub fn is_exceeded<AssetKey, InstrumentKey>(
+        &self,
+        position: &Position<AssetKey, InstrumentKey>,
+        time_now: DateTime<Utc>,
+    ) -> bool {
+        time_now.signed_duration_since(position.time_enter) >= self.max
+    }
+
+    /// Generate `ImmediateOrCancel` `Market` orders that close every open [`Position`] which has
+    /// exceeded the maximum holding period.
+    ///
+    /// Instruments with active orders are skipped, since they are likely already being closed,
+    /// as are instruments without market data to price the exit order.
+    pub fn close_expired_positions_with_market_orders<'a, GlobalData, InstrumentData>(
+        &'a self,
+        strategy_id: &'a StrategyId,
+        state: &'a EngineState<GlobalData, InstrumentData>,
+        filter: &'a InstrumentFilter,
+        gen_cid: impl Fn(&InstrumentState<InstrumentData>) -> ClientOrderId + Copy + 'a,
+    ) -> impl Iterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        state
+            .instruments
+            .instruments(filter)
+            .filter_map(move |instrument_state| {
+                let position = instrument_state.position.current.as_ref()?;
+
+                if !self.is_exceeded(position, state.time_engine_now)
+                    || !instrument_state.orders.0.is_empty()
+                {
+                    return None;
+                }
+
+                let price = instrument_state.data.price()?;
+
+                Some(build_ioc_market_order_to_close_position(
+                    instrument_state.instrument.exchange,
+                    position,
+                    strategy_id.clone(),
+                    price,
+                    || gen_cid(instrument_state),
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Timed,
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        test_utils::time_plus_secs,
+    };
+    use barter_execution::{
+        order::id::OrderId,
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, Underlying, asset::QuoteAsset, exchange::ExchangeId, index::IndexedInstruments,
+        instrument::Instrument,
+    };
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn position(time_enter: DateTime<Utc>) -> Position<QuoteAsset, InstrumentIndex> {
+        Position::from(&Trade {
+            id: TradeId::new("trade"),
+            order_id: OrderId::new("order"),
+            cid: None,
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            time_exchange: time_enter,
+            side: Side::Buy,
+            price: dec!(100),
+            quantity: dec!(1),
+            liquidity: None,
+            fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+        })
+    }
+
+    #[test]
+    fn test_max_holding_period_close_expired_positions_with_market_orders() {
+        struct TestCase {
+            position: Option<DateTime<Utc>>,
+            time_now: DateTime<Utc>,
+            expected: Option<Side>,
+        }
+
+        let base = DateTime::<Utc>::MIN_UTC;
+        let policy = MaxHoldingPeriod::new(TimeDelta::seconds(60));
+
+        let cases = vec![
+            // TC0: no Position to close
+            TestCase {
+                position: None,
+                time_now: time_plus_secs(base, 120),
+                expected: None,
+            },
+            // TC1: Position held for less than the max holding period
+            TestCase {
+                position: Some(base),
+                time_now: time_plus_secs(base, 59),
+                expected: None,
+            },
+            // TC2: Position held for exactly the max holding period is closed
+            TestCase {
+                position: Some(base),
+                time_now: time_plus_secs(base, 60),
+                expected: Some(Side::Sell),
+            },
+            // TC3: Position held for longer than the max holding period is closed
+            TestCase {
+                position: Some(base),
+                time_now: time_plus_secs(base, 61),
+                expected: Some(Side::Sell),
+            },
+        ];
+
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .build();
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut state = EngineState::builder(
+                &instruments,
+                DefaultGlobalData,
+                DefaultInstrumentMarketData::default,
+            )
+            .time_engine_start(base)
+            .build();
+            state.time_engine_now = test.time_now;
+
+            let instrument = state.instruments.instrument_index_mut(&InstrumentIndex(0));
+            instrument.position.current = test.position.map(position);
+            instrument.data.last_traded_price = Some(Timed::new(dec!(110), test.time_now));
+
+            if let Some(position) = instrument.position.current.as_ref() {
+                assert_eq!(
+                    policy.is_exceeded(position, test.time_now),
+                    test.expected.is_some(),
+                    "TC{index} failed"
+                );
+            }
+
+            let strategy = StrategyId::new("holding_period");
+            let actual = policy
+                .close_expired_positions_with_market_orders(
+                    &strategy,
+                    &state,
+                    &InstrumentFilter::None,
+                    |_| ClientOrderId::new("cid"),
+                )
+                .map(|order| (order.state.side, order.state.quantity))
+                .collect::<Vec<_>>();
+
+            let expected = test
+                .expected
+                .map(|side| (side, dec!(1)))
+                .into_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use barter_execution::{
+    order::{
+        OrderKind,
+        id::ClientOrderId,
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::Trade,
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use tracing::warn;
+
+/// Parent [`OrderKind::Iceberg`] order being worked by an [`IcebergSlicer`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IcebergParent<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    pub request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    pub display_quantity: Decimal,
+
+    /// Parent quantity that has not yet been filled.
+    pub quantity_remaining: Decimal,
+
+    /// [`ClientOrderId`] of the currently working child order.
+    pub child: ClientOrderId,
+
+    /// Quantity of the currently working child order that has not yet been filled.
+    pub child_quantity_remaining: Decimal,
+
+    /// Number of child orders generated so far.
+    pub sequence: u64,
+}
+
+/// Engine-side fallback for exchanges that do not support [`OrderKind::Iceberg`] orders natively.
+///
+/// Slices each iceberg parent order into sequential `Limit` child orders of at most the
+/// `display_quantity`, generating the next child order once the previous child has been fully
+/// filled.
+///
+/// Child order [`ClientOrderId`]s are derived from the parent (eg/ "parent-1", "parent-2"), and
+/// fills are correlated back to the parent via the [`Trade`] `cid`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IcebergSlicer<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    pub parents: FnvHashMap<ClientOrderId, IcebergParent<ExchangeKey, InstrumentKey>>,
+    children: FnvHashMap<ClientOrderId, ClientOrderId>,
+}
+
+impl<ExchangeKey, InstrumentKey> Default for IcebergSlicer<ExchangeKey, InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            parents: FnvHashMap::default(),
+            children: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> IcebergSlicer<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Clone,
+    InstrumentKey: Clone,
+{
+    /// Register an open order request, returning the order request that should be sent to the
+    /// exchange.
+    ///
+    /// [`OrderKind::Iceberg`] requests are registered as parents, returning their first `Limit`
+    /// child order. All other requests are returned unchanged.
+    pub fn open(
+        &mut self,
+        request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        let OrderKind::Iceberg { display_quantity } = request.state.kind else {
+            return request;
+        };
+
+        if display_quantity <= Decimal::ZERO || display_quantity >= request.state.quantity {
+            // Slicing not possible or required, so send the entire quantity as a Limit order
+            let mut limit = request;
+            limit.state.kind = OrderKind::Limit;
+            return limit;
+        }
+
+        let parent_cid = request.key.cid.clone();
+        let mut parent = IcebergParent {
+            quantity_remaining: request.state.quantity,
+            request,
+            display_quantity,
+            child: parent_cid.clone(),
+            child_quantity_remaining: Decimal::ZERO,
+            sequence: 0,
+        };
+
+        let child = Self::next_child(&mut parent);
+        self.children
+            .insert(parent.child.clone(), parent_cid.clone());
+        self.parents.insert(parent_cid, parent);
+
+        child
+    }
+
+    /// Update the `IcebergSlicer` from a fill, returning the next child order request if the fill
+    /// completed a child order and the parent has quantity remaining.
+    ///
+    /// Fills of orders that are not iceberg child orders are ignored.
+    pub fn update_from_trade<AssetKey, TradeInstrumentKey>(
+        &mut self,
+        trade: &Trade<AssetKey, TradeInstrumentKey>,
+    ) -> Option<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        let child_cid = trade.cid.as_ref()?;
+        let parent_cid = self.children.get(child_cid)?.clone();
+
+        let Some(parent) = self.parents.get_mut(&parent_cid) else {
+            self.children.remove(child_cid);
+            return None;
+        };
+
+        if &parent.child != child_cid {
+            warn!(
+                parent = %parent_cid,
+                child = %child_cid,
+                "IcebergSlicer received fill for a previous child order - ignoring"
+            );
+            return None;
+        }
+
+        parent.quantity_remaining -= trade.quantity;
+        parent.child_quantity_remaining -= trade.quantity;
+
+        if parent.child_quantity_remaining > Decimal::ZERO {
+            return None;
+        }
+
+        self.children.remove(child_cid);
+
+        if parent.quantity_remaining <= Decimal::ZERO {
+            self.parents.remove(&parent_cid);
+            return None;
+        }
+
+        let child = Self::next_child(parent);
+        self.children.insert(child.key.cid.clone(), parent_cid);
+
+        Some(child)
+    }
+
+    /// Stop working the parent iceberg order, returning the [`IcebergParent`] so the currently
+    /// working child order can be cancelled.
+    pub fn cancel(
+        &mut self,
+        parent: &ClientOrderId,
+    ) -> Option<IcebergParent<ExchangeKey, InstrumentKey>> {
+        let parent = self.parents.remove(parent)?;
+        self.children.remove(&parent.child);
+        Some(parent)
+    }
+
+    fn next_child(
+        parent: &mut IcebergParent<ExchangeKey, InstrumentKey>,
+    ) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        parent.sequence += 1;
+        parent.child =
+            ClientOrderId::new(format!("{}-{}", parent.request.key.cid, parent.sequence));
+        parent.child_quantity_remaining = parent.display_quantity.min(parent.quantity_remaining);
+
+        let mut key = parent.request.key.clone();
+        key.cid = parent.child.clone();
+
+        OrderRequestOpen {
+            key,
+            state: RequestOpen {
+                kind: OrderKind::Limit,
+                quantity: parent.child_quantity_remaining,
+                ..parent.request.state
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::trade;
+    use barter_execution::order::{OrderKey, TimeInForce, id::StrategyId};
+    use barter_instrument::{Side, asset::QuoteAsset, instrument::name::InstrumentNameInternal};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn iceberg(quantity: Decimal, display_quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("parent"),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity,
+                kind: OrderKind::Iceberg { display_quantity },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn fill(cid: &str, quantity: f64) -> Trade<QuoteAsset, InstrumentNameInternal> {
+        Trade {
+            cid: Some(ClientOrderId::new(cid)),
+            ..trade(DateTime::<Utc>::MIN_UTC, Side::Buy, 100.0, quantity, 0.0)
+        }
+    }
+
+    #[test]
+    fn test_iceberg_slicer_open() {
+        struct TestCase {
+            request: OrderRequestOpen,
+            expected_cid: &'static str,
+            expected_quantity: Decimal,
+            expected_parents: usize,
+        }
+
+        let cases = vec![
+            // TC0: iceberg sliced into first child of the display quantity
+            TestCase {
+                request: iceberg(dec!(10), dec!(4)),
+                expected_cid: "parent-1",
+                expected_quantity: dec!(4),
+                expected_parents: 1,
+            },
+            // TC1: display quantity covers entire order, so sent as a single Limit order
+            TestCase {
+                request: iceberg(dec!(10), dec!(10)),
+                expected_cid: "parent",
+                expected_quantity: dec!(10),
+                expected_parents: 0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut slicer = IcebergSlicer::default();
+            let actual = slicer.open(test.request);
+            assert_eq!(actual.state.kind, OrderKind::Limit, "TC{index} failed");
+            assert_eq!(actual.key.cid.0, test.expected_cid, "TC{index} failed");
+            assert_eq!(
+                actual.state.quantity, test.expected_quantity,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                slicer.parents.len(),
+                test.expected_parents,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_iceberg_slicer_update_from_trade() {
+        let mut slicer = IcebergSlicer::default();
+        slicer.open(iceberg(dec!(10), dec!(4)));
+
+        // Partial fill of first child does not generate the next child
+        assert_eq!(slicer.update_from_trade(&fill("parent-1", 1.0)), None);
+
+        // Fill completing first child generates the second child
+        let child = slicer.update_from_trade(&fill("parent-1", 3.0)).unwrap();
+        assert_eq!(child.key.cid.0, "parent-2");
+        assert_eq!(child.state.quantity, dec!(4));
+
+        // Unrelated fills are ignored
+        assert_eq!(slicer.update_from_trade(&fill("other", 4.0)), None);
+
+        // Final child only contains the parent quantity remaining
+        let child = slicer.update_from_trade(&fill("parent-2", 4.0)).unwrap();
+        assert_eq!(child.key.cid.0, "parent-3");
+        assert_eq!(child.state.quantity, dec!(2));
+
+        // Parent removed once fully filled
+        assert_eq!(slicer.update_from_trade(&fill("parent-3", 2.0)), None);
+        assert!(slicer.parents.is_empty());
+    }
+
+    #[test]
+    fn test_iceberg_slicer_cancel() {
+        let mut slicer = IcebergSlicer::default();
+        slicer.open(iceberg(dec!(10), dec!(4)));
+
+        let parent = slicer.cancel(&ClientOrderId::new("parent")).unwrap();
+        assert_eq!(parent.child.0, "parent-1");
+        assert_eq!(slicer.update_from_trade(&fill("parent-1", 4.0)), None);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::strategy::library::{SignalGenerator, Target};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Donchian channel breakout momentum [`SignalGenerator`].
+///
+/// Targets a LONG position when the latest price breaks above the highest price of the previous
+/// `period_entry` prices, and a flat position when it breaks below the lowest price of the
+/// previous `period_exit` prices.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct DonchianBreakout {
+    /// Number of previous prices the entry channel high is calculated over (eg/ 20).
+    pub period_entry: usize,
+
+    /// Number of previous prices the exit channel low is calculated over (eg/ 10).
+    pub period_exit: usize,
+}
+
+impl SignalGenerator for DonchianBreakout {
+    fn target(&self, prices: &VecDeque<Decimal>) -> Option<Target> {
+        if self.period_entry == 0
+            || self.period_exit == 0
+            || prices.len() <= self.period_entry.max(self.period_exit)
+        {
+            return None;
+        }
+
+        let mut previous = prices.iter().rev();
+        let latest = previous.next()?;
+
+        let high = previous.clone().take(self.period_entry).max()?;
+        let low = previous.take(self.period_exit).min()?;
+
+        if latest > high {
+            Some(Target::Long)
+        } else if latest < low {
+            Some(Target::Flat)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_donchian_breakout_target() {
+        struct TestCase {
+            prices: Vec<Decimal>,
+            expected: Option<Target>,
+        }
+
+        let signal = DonchianBreakout::new(3, 2);
+
+        let cases = vec![
+            // TC0: insufficient prices
+            TestCase {
+                prices: vec![dec!(1), dec!(2), dec!(3)],
+                expected: None,
+            },
+            // TC1: breakout above entry channel high
+            TestCase {
+                prices: vec![dec!(2), dec!(3), dec!(1), dec!(4)],
+                expected: Some(Target::Long),
+            },
+            // TC2: breakdown below exit channel low, ignoring older prices
+            TestCase {
+                prices: vec![dec!(1), dec!(3), dec!(2), dec!(1.5)],
+                expected: Some(Target::Flat),
+            },
+            // TC3: within channel
+            TestCase {
+                prices: vec![dec!(1), dec!(3), dec!(2), dec!(2.5)],
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = signal.target(&VecDeque::from(test.prices));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::strategy::library::{SignalGenerator, Target, simple_moving_average};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Simple moving average crossover momentum [`SignalGenerator`].
+///
+/// Targets a LONG position while the fast moving average is above the slow moving average, and
+/// a flat position otherwise.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct MovingAverageCrossover {
+    /// Number of prices the fast moving average is calculated over (eg/ 10).
+    pub period_fast: usize,
+
+    /// Number of prices the slow moving average is calculated over (eg/ 50).
+    pub period_slow: usize,
+}
+
+impl SignalGenerator for MovingAverageCrossover {
+    fn target(&self, prices: &VecDeque<Decimal>) -> Option<Target> {
+        let fast = simple_moving_average(prices, self.period_fast)?;
+        let slow = simple_moving_average(prices, self.period_slow)?;
+
+        if fast > slow {
+            Some(Target::Long)
+        } else {
+            Some(Target::Flat)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_moving_average_crossover_target() {
+        struct TestCase {
+            prices: Vec<Decimal>,
+            expected: Option<Target>,
+        }
+
+        let signal = MovingAverageCrossover::new(2, 4);
+
+        let cases = vec![
+            // TC0: insufficient prices for slow moving average
+            TestCase {
+                prices: vec![dec!(1), dec!(2), dec!(3)],
+                expected: None,
+            },
+            // TC1: fast above slow
+            TestCase {
+                prices: vec![dec!(1), dec!(2), dec!(3), dec!(4)],
+                expected: Some(Target::Long),
+            },
+            // TC2: fast below slow
+            TestCase {
+                prices: vec![dec!(4), dec!(3), dec!(2), dec!(1)],
+                expected: Some(Target::Flat),
+            },
+            // TC3: fast equal to slow
+            TestCase {
+                prices: vec![dec!(2), dec!(2), dec!(2), dec!(2)],
+                expected: Some(Target::Flat),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = signal.target(&VecDeque::from(test.prices));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
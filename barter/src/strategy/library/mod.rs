@@ -0,0 +1,372 @@
+use crate::{
+    engine::{
+        Engine, Processor,
+        state::{
+            EngineState,
+            instrument::{
+                data::{DefaultInstrumentMarketData, InstrumentDataState},
+                filter::InstrumentFilter,
+            },
+            order::in_flight_recorder::InFlightRequestRecorder,
+        },
+    },
+    strategy::{
+        algo::{AlgoStrategy, StrategyUpdateError},
+        close_positions::{
+            ClosePositionsStrategy, build_ioc_market_order_to_close_position,
+            close_open_positions_with_market_orders,
+        },
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookL1,
+};
+use barter_execution::{
+    AccountEvent,
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+    },
+};
+use barter_instrument::{
+    Side,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{collections::VecDeque, marker::PhantomData};
+
+/// Donchian channel breakout [`SignalGenerator`].
+pub mod donchian;
+
+/// Fast & slow simple moving average crossover [`SignalGenerator`].
+pub mod ma_crossover;
+
+/// Relative Strength Index (RSI) mean-reversion [`SignalGenerator`].
+pub mod rsi;
+
+/// Target exposure of an instrument, as determined by a [`SignalGenerator`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum Target {
+    /// Hold a LONG position.
+    Long,
+
+    /// Hold no position.
+    Flat,
+}
+
+/// Generates a [`Target`] exposure from an instrument price history.
+pub trait SignalGenerator {
+    /// Determine the [`Target`] exposure from the provided price history (oldest first).
+    ///
+    /// Returns `None` if there is insufficient price history, or if the current exposure should
+    /// be maintained.
+    fn target(&self, prices: &VecDeque<Decimal>) -> Option<Target>;
+}
+
+/// Instrument data that provides a price history for [`SignalGenerator`]s.
+pub trait PriceHistory {
+    /// Price history, oldest first.
+    fn prices(&self) -> &VecDeque<Decimal>;
+}
+
+/// [`InstrumentDataState`] that extends the [`DefaultInstrumentMarketData`] with a bounded
+/// history of `Candle` close prices, as required by the [`LibraryStrategy`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PriceHistoryData {
+    pub market: DefaultInstrumentMarketData,
+    pub prices: VecDeque<Decimal>,
+    pub capacity: usize,
+}
+
+impl PriceHistoryData {
+    /// Construct a new `PriceHistoryData` that retains up to `capacity` close prices.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            market: DefaultInstrumentMarketData::default(),
+            prices: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+impl PriceHistory for PriceHistoryData {
+    fn prices(&self) -> &VecDeque<Decimal> {
+        &self.prices
+    }
+}
+
+impl InstrumentDataState for PriceHistoryData {
+    type MarketEventKind = DataKind;
+
+    fn price(&self) -> Option<Decimal> {
+        self.market.price().or(self.prices.back().copied())
+    }
+
+    fn price_mark(&self) -> Option<Decimal> {
+        self.market.price_mark()
+    }
+
+    fn funding_rate(&self, event: &MarketEvent<InstrumentIndex, DataKind>) -> Option<Decimal> {
+        self.market.funding_rate(event)
+    }
+
+    fn borrow_rate(&self) -> Option<Decimal> {
+        self.market.borrow_rate()
+    }
+
+    fn l1(&self) -> Option<&OrderBookL1> {
+        self.market.l1()
+    }
+
+    fn is_liquidation_cluster(&self) -> bool {
+        self.market.is_liquidation_cluster()
+    }
+
+    fn reset_indicators(&mut self) {
+        self.market.reset_indicators();
+        self.prices.clear();
+    }
+
+    fn init_from_template(&self) -> Option<Self> {
+        Some(Self {
+            market: self.market.init_from_template()?,
+            prices: VecDeque::with_capacity(self.capacity),
+            capacity: self.capacity,
+        })
+    }
+}
+
+impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>> for PriceHistoryData {
+    type Audit = ();
+
+    fn process(&mut self, event: &MarketEvent<InstrumentKey, DataKind>) -> Self::Audit {
+        self.market.process(event);
+
+        if let DataKind::Candle(candle) = &event.kind {
+            let Some(close) = Decimal::from_f64(candle.close) else {
+                return;
+            };
+
+            if self.prices.len() >= self.capacity {
+                self.prices.pop_front();
+            }
+            self.prices.push_back(close);
+        }
+    }
+}
+
+impl<ExchangeKey, AssetKey, InstrumentKey>
+    Processor<&AccountEvent<ExchangeKey, AssetKey, InstrumentKey>> for PriceHistoryData
+{
+    type Audit = ();
+
+    fn process(&mut self, _: &AccountEvent<ExchangeKey, AssetKey, InstrumentKey>) -> Self::Audit {}
+}
+
+impl<ExchangeKey, InstrumentKey> InFlightRequestRecorder<ExchangeKey, InstrumentKey>
+    for PriceHistoryData
+{
+    fn record_in_flight_cancel(&mut self, _: &OrderRequestCancel<ExchangeKey, InstrumentKey>) {}
+
+    fn record_in_flight_open(&mut self, _: &OrderRequestOpen<ExchangeKey, InstrumentKey>) {}
+}
+
+/// Reference LONG-only strategy that trades every instrument towards the [`Target`] exposure
+/// determined by a [`SignalGenerator`] (eg/ [`RsiMeanReversion`](rsi::RsiMeanReversion)).
+///
+/// *THIS IS A REFERENCE IMPLEMENTATION FOR BENCHMARKING, NEVER USE FOR REAL TRADING*.
+///
+/// This strategy:
+/// - Opens a `quantity` LONG position with an `ImmediateOrCancel` `Market` order when the
+///   [`Target`] is `Long` and there is no position (AlgoStrategy).
+/// - Closes a LONG position with an `ImmediateOrCancel` `Market` order when the [`Target`] is
+///   `Flat` (AlgoStrategy).
+/// - Skips instruments with active orders, since they are likely already being traded
+///   (AlgoStrategy).
+/// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+#[derive(Debug, Clone)]
+pub struct LibraryStrategy<Signal, State> {
+    pub id: StrategyId,
+    pub signal: Signal,
+    pub quantity: Decimal,
+    phantom: PhantomData<State>,
+}
+
+impl<Signal, State> LibraryStrategy<Signal, State> {
+    /// Construct a new `LibraryStrategy` using the provided [`StrategyId`], [`SignalGenerator`],
+    /// and position quantity.
+    pub fn new(id: StrategyId, signal: Signal, quantity: Decimal) -> Self {
+        Self {
+            id,
+            signal,
+            quantity,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Signal, GlobalData, InstrumentData> AlgoStrategy
+    for LibraryStrategy<Signal, EngineState<GlobalData, InstrumentData>>
+where
+    Signal: SignalGenerator + DeserializeOwned,
+    InstrumentData: InstrumentDataState + PriceHistory,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let opens = state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .filter(|instrument_state| instrument_state.orders.0.is_empty())
+            .filter_map(|instrument_state| {
+                let target = self.signal.target(instrument_state.data.prices())?;
+                let price = instrument_state.data.price()?;
+
+                match (target, &instrument_state.position.current) {
+                    (Target::Long, None) => Some(OrderRequestOpen {
+                        key: OrderKey {
+                            exchange: instrument_state.instrument.exchange,
+                            instrument: instrument_state.key,
+                            strategy: self.id.clone(),
+                            cid: ClientOrderId::random(),
+                        },
+                        state: RequestOpen {
+                            side: Side::Buy,
+                            price,
+                            quantity: self.quantity,
+                            kind: OrderKind::Market,
+                            time_in_force: TimeInForce::ImmediateOrCancel,
+                            reduce_only: false,
+                        },
+                    }),
+                    (Target::Flat, Some(position)) if position.side == Side::Buy => {
+                        Some(build_ioc_market_order_to_close_position(
+                            instrument_state.instrument.exchange,
+                            position,
+                            self.id.clone(),
+                            price,
+                            ClientOrderId::random,
+                        ))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (std::iter::empty(), opens)
+    }
+
+    fn update_params(&mut self, params: &str) -> Result<(), StrategyUpdateError> {
+        self.signal = serde_json::from_str(params)
+            .map_err(|error| StrategyUpdateError::InvalidParams(error.to_string()))?;
+        Ok(())
+    }
+}
+
+impl<Signal, GlobalData, InstrumentData> ClosePositionsStrategy
+    for LibraryStrategy<Signal, EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.id, state, filter, |_| {
+            ClientOrderId::random()
+        })
+    }
+}
+
+impl<Signal, Clock, State, ExecutionTxs, Risk>
+    OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk> for LibraryStrategy<Signal, State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Signal, Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for LibraryStrategy<Signal, State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+/// Simple moving average of the most recent `period` prices.
+///
+/// Returns `None` if there are fewer than `period` prices, or if `period` is zero.
+pub fn simple_moving_average(prices: &VecDeque<Decimal>, period: usize) -> Option<Decimal> {
+    if period == 0 || prices.len() < period {
+        return None;
+    }
+
+    let sum = prices.iter().rev().take(period).sum::<Decimal>();
+    Some(sum / Decimal::from(period))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::library::rsi::RsiMeanReversion;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_library_strategy_update_params() {
+        let mut strategy = LibraryStrategy::<_, EngineState<(), PriceHistoryData>>::new(
+            StrategyId::new("rsi"),
+            RsiMeanReversion::new(14, dec!(30), dec!(70)),
+            dec!(1),
+        );
+
+        let result = strategy.update_params(r#"{"period":7,"oversold":"20","overbought":"80"}"#);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            strategy.signal,
+            RsiMeanReversion::new(7, dec!(20), dec!(80))
+        );
+
+        // Invalid parameters leave the SignalGenerator unchanged
+        let result = strategy.update_params(r#"{"period":7}"#);
+        assert!(matches!(result, Err(StrategyUpdateError::InvalidParams(_))));
+        assert_eq!(
+            strategy.signal,
+            RsiMeanReversion::new(7, dec!(20), dec!(80))
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::strategy::library::{SignalGenerator, Target};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Relative Strength Index (RSI) mean-reversion [`SignalGenerator`].
+///
+/// Targets a LONG position when the RSI falls below the `oversold` level, and a flat position
+/// when the RSI rises above the `overbought` level.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct RsiMeanReversion {
+    /// Number of price changes the RSI is calculated over (eg/ 14).
+    pub period: usize,
+
+    /// RSI level below which the instrument is considered oversold (eg/ 30).
+    pub oversold: Decimal,
+
+    /// RSI level above which the instrument is considered overbought (eg/ 70).
+    pub overbought: Decimal,
+}
+
+impl SignalGenerator for RsiMeanReversion {
+    fn target(&self, prices: &VecDeque<Decimal>) -> Option<Target> {
+        let rsi = relative_strength_index(prices, self.period)?;
+
+        if rsi < self.oversold {
+            Some(Target::Long)
+        } else if rsi > self.overbought {
+            Some(Target::Flat)
+        } else {
+            None
+        }
+    }
+}
+
+/// Relative Strength Index (0 to 100) of the most recent `period` price changes, using simple
+/// average gains and losses.
+///
+/// Returns `None` if there are fewer than `period + 1` prices, or if `period` is zero.
+pub fn relative_strength_index(prices: &VecDeque<Decimal>, period: usize) -> Option<Decimal> {
+    if period == 0 || prices.len() < period + 1 {
+        return None;
+    }
+
+    let (gains, losses) = prices
+        .iter()
+        .skip(prices.len() - (period + 1))
+        .collect::<Vec<_>>()
+        .windows(2)
+        .fold((Decimal::ZERO, Decimal::ZERO), |(gains, losses), window| {
+            let change = window[1] - window[0];
+            if change.is_sign_positive() {
+                (gains + change, losses)
+            } else {
+                (gains, losses - change)
+            }
+        });
+
+    if losses.is_zero() {
+        return Some(Decimal::ONE_HUNDRED);
+    }
+
+    Some(Decimal::ONE_HUNDRED - Decimal::ONE_HUNDRED / (Decimal::ONE + gains / losses))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_relative_strength_index() {
+        struct TestCase {
+            prices: Vec<Decimal>,
+            period: usize,
+            expected: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: insufficient prices
+            TestCase {
+                prices: vec![dec!(1), dec!(2)],
+                period: 2,
+                expected: None,
+            },
+            // TC1: only gains
+            TestCase {
+                prices: vec![dec!(1), dec!(2), dec!(3)],
+                period: 2,
+                expected: Some(dec!(100)),
+            },
+            // TC2: only losses
+            TestCase {
+                prices: vec![dec!(3), dec!(2), dec!(1)],
+                period: 2,
+                expected: Some(dec!(0)),
+            },
+            // TC3: equal gains & losses, ignoring prices outside period
+            TestCase {
+                prices: vec![dec!(10), dec!(1), dec!(3), dec!(1)],
+                period: 2,
+                expected: Some(dec!(50)),
+            },
+            // TC4: gains three times losses
+            TestCase {
+                prices: vec![dec!(10), dec!(13), dec!(12)],
+                period: 2,
+                expected: Some(dec!(75)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = relative_strength_index(&VecDeque::from(test.prices), test.period);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_rsi_mean_reversion_target() {
+        let signal = RsiMeanReversion::new(2, dec!(30), dec!(70));
+
+        let oversold = VecDeque::from(vec![dec!(3), dec!(2), dec!(1)]);
+        let overbought = VecDeque::from(vec![dec!(1), dec!(2), dec!(3)]);
+        let neutral = VecDeque::from(vec![dec!(10), dec!(1), dec!(3), dec!(1)]);
+
+        assert_eq!(signal.target(&oversold), Some(Target::Long));
+        assert_eq!(signal.target(&overbought), Some(Target::Flat));
+        assert_eq!(signal.target(&neutral), None);
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    engine::{
+        Engine,
+        state::{
+            EngineState,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        },
+    },
+    strategy::{
+        algo::AlgoStrategy,
+        close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+    state::ActiveOrderState,
+};
+use barter_instrument::{
+    Side,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Configuration of a [`MarketMaker`] two-sided quote.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct MarketMakerConfig {
+    /// Instrument that is quoted.
+    pub instrument: InstrumentIndex,
+
+    /// Fractional distance of each quote from the skewed fair value (eg/ 0.001 for 10 bps).
+    pub half_spread: Decimal,
+
+    /// Quantity of each quote.
+    pub quantity: Decimal,
+
+    /// Fractional fair value skew applied per unit of signed inventory (eg/ 0.0005 for 5 bps).
+    ///
+    /// A LONG inventory skews the quotes lower to encourage selling, and vice versa.
+    pub skew: Decimal,
+
+    /// Maximum absolute inventory, beyond which the quote that would increase it is withdrawn.
+    pub inventory_max: Decimal,
+
+    /// Fractional distance a resting quote can drift from its target price before it is
+    /// cancelled and replaced (eg/ 0.0005 for 5 bps).
+    pub requote_threshold: Decimal,
+}
+
+/// Target bid and ask quote prices, where `None` indicates the side should not be quoted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct Quotes {
+    pub bid: Option<Decimal>,
+    pub ask: Option<Decimal>,
+}
+
+impl Quotes {
+    /// Target price of the provided quote [`Side`].
+    pub fn price(&self, side: Side) -> Option<Decimal> {
+        match side {
+            Side::Buy => self.bid,
+            Side::Sell => self.ask,
+        }
+    }
+}
+
+impl MarketMakerConfig {
+    /// Calculate the target [`Quotes`] around the provided fair value, skewed by the provided
+    /// signed inventory (positive if LONG, negative if SHORT).
+    pub fn quotes(&self, fair_value: Decimal, inventory: Decimal) -> Quotes {
+        let fair_value_skewed = fair_value * (Decimal::ONE - self.skew * inventory);
+
+        Quotes {
+            bid: (inventory < self.inventory_max)
+                .then(|| fair_value_skewed * (Decimal::ONE - self.half_spread)),
+            ask: (inventory > -self.inventory_max)
+                .then(|| fair_value_skewed * (Decimal::ONE + self.half_spread)),
+        }
+    }
+
+    /// Returns true if a resting quote price has drifted from the target price by more than the
+    /// `requote_threshold`.
+    pub fn requote_required(&self, price_resting: Decimal, price_target: Decimal) -> bool {
+        price_target.is_zero()
+            || ((price_resting - price_target) / price_target).abs() > self.requote_threshold
+    }
+}
+
+/// Market-making strategy scaffold that maintains two-sided quotes around a fair value, skewed
+/// by the current [`Position`](crate::engine::state::position::Position) inventory.
+///
+/// *THIS IS A SCAFFOLD FOR DEMONSTRATION PURPOSES, NEVER USE FOR REAL TRADING WITHOUT EXTENDING*.
+///
+/// The fair value is the [`InstrumentDataState::price`] of the quoted instrument.
+///
+/// This strategy:
+/// - Quotes a post-only `Limit` bid and ask on each side where there is no resting quote,
+///   withdrawing the side that would increase the inventory beyond the maximum (AlgoStrategy).
+/// - Cancels resting quotes that have drifted beyond the `requote_threshold`, replacing them
+///   once the cancel is confirmed (ie/ cancel-replace) (AlgoStrategy).
+/// - Leaves in-flight quotes untouched until they are confirmed (AlgoStrategy).
+/// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+#[derive(Debug, Clone)]
+pub struct MarketMaker<State> {
+    pub id: StrategyId,
+    pub config: MarketMakerConfig,
+    phantom: PhantomData<State>,
+}
+
+impl<State> MarketMaker<State> {
+    /// Construct a new `MarketMaker` using the provided [`StrategyId`] and
+    /// [`MarketMakerConfig`].
+    pub fn new(id: StrategyId, config: MarketMakerConfig) -> Self {
+        Self {
+            id,
+            config,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy
+    for MarketMaker<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let instrument_state = state.instruments.instrument_index(&self.config.instrument);
+
+        let Some(fair_value) = instrument_state.data.price() else {
+            return (vec![], vec![]);
+        };
+
+        let inventory = instrument_state
+            .position
+            .current
+            .as_ref()
+            .map(|position| match position.side {
+                Side::Buy => position.quantity_abs,
+                Side::Sell => -position.quantity_abs,
+            })
+            .unwrap_or_default();
+
+        let quotes = self.config.quotes(fair_value, inventory);
+
+        let mut cancels = vec![];
+        let mut opens = vec![];
+
+        for side in [Side::Buy, Side::Sell] {
+            let resting = instrument_state
+                .orders
+                .0
+                .values()
+                .find(|order| order.key.strategy == self.id && order.side == side);
+
+            match (resting, quotes.price(side)) {
+                // Leave in-flight quotes untouched until they are confirmed
+                (Some(order), _) if !matches!(order.state, ActiveOrderState::Open(_)) => {}
+                // Cancel resting quotes that have been withdrawn or drifted from the target
+                (Some(order), None) => cancels.extend(order.to_request_cancel()),
+                (Some(order), Some(price)) => {
+                    if self.config.requote_required(order.price, price) {
+                        cancels.extend(order.to_request_cancel());
+                    }
+                }
+                (None, Some(price)) => opens.push(OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: instrument_state.instrument.exchange,
+                        instrument: self.config.instrument,
+                        strategy: self.id.clone(),
+                        cid: ClientOrderId::random(),
+                    },
+                    state: RequestOpen {
+                        side,
+                        price,
+                        quantity: self.config.quantity,
+                        kind: OrderKind::Limit,
+                        time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                        reduce_only: false,
+                    },
+                }),
+                (None, None) => {}
+            }
+        }
+
+        (cancels, opens)
+    }
+}
+
+impl<GlobalData, InstrumentData> ClosePositionsStrategy
+    for MarketMaker<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.id, state, filter, |_| {
+            ClientOrderId::random()
+        })
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>
+    for MarketMaker<State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for MarketMaker<State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_market_maker_config_quotes() {
+        struct TestCase {
+            inventory: Decimal,
+            expected: Quotes,
+        }
+
+        let config = MarketMakerConfig::new(
+            InstrumentIndex(0),
+            dec!(0.01),
+            dec!(1),
+            dec!(0.01),
+            dec!(2),
+            dec!(0.001),
+        );
+
+        let cases = vec![
+            // TC0: flat inventory quotes symmetrically around fair value
+            TestCase {
+                inventory: dec!(0),
+                expected: Quotes {
+                    bid: Some(dec!(99)),
+                    ask: Some(dec!(101)),
+                },
+            },
+            // TC1: LONG inventory skews quotes lower
+            TestCase {
+                inventory: dec!(1),
+                expected: Quotes {
+                    bid: Some(dec!(98.01)),
+                    ask: Some(dec!(99.99)),
+                },
+            },
+            // TC2: SHORT inventory skews quotes higher
+            TestCase {
+                inventory: dec!(-1),
+                expected: Quotes {
+                    bid: Some(dec!(99.99)),
+                    ask: Some(dec!(102.01)),
+                },
+            },
+            // TC3: maximum LONG inventory withdraws bid
+            TestCase {
+                inventory: dec!(2),
+                expected: Quotes {
+                    bid: None,
+                    ask: Some(dec!(98.98)),
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = config.quotes(dec!(100), test.inventory);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_market_maker_config_requote_required() {
+        let config = MarketMakerConfig::new(
+            InstrumentIndex(0),
+            dec!(0.01),
+            dec!(1),
+            dec!(0.01),
+            dec!(2),
+            dec!(0.001),
+        );
+
+        assert!(!config.requote_required(dec!(100.05), dec!(100)));
+        assert!(config.requote_required(dec!(100.2), dec!(100)));
+        assert!(config.requote_required(dec!(100), dec!(0)));
+    }
+}
@@ -22,6 +22,7 @@ use barter_instrument::{
     exchange::{ExchangeId, ExchangeIndex},
     instrument::InstrumentIndex,
 };
+use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
 /// Defines a strategy interface for generating algorithmic open and cancel order requests based
@@ -32,6 +33,61 @@ pub mod algo;
 /// positions.
 pub mod close_positions;
 
+/// Engine-side [`ConditionalOrderManager`](conditional::ConditionalOrderManager) that holds stop,
+/// take-profit & trailing orders locally, sending them once triggered by market prices.
+pub mod conditional;
+
+/// Reference [`DcaStrategy`](dca::DcaStrategy) that accumulates an instrument by buying a fixed
+/// notional value on a schedule driven by the `Engine` clock.
+pub mod dca;
+
+/// [`ExecutionAlgoManager`](execution_algo::ExecutionAlgoManager) that works large parent orders
+/// via scheduled TWAP or VWAP child orders, reporting a single consolidated fill.
+pub mod execution_algo;
+
+/// Streaming [`MicrostructureFeatures`](features::MicrostructureFeatures) (eg/ order-flow
+/// imbalance, trade aggressor ratio, realised volatility) usable as signal generator inputs.
+pub mod features;
+
+/// Reference [`GridStrategy`](grid::GridStrategy) that maintains a ladder of resting limit
+/// orders across a configured price range.
+pub mod grid;
+
+/// Defines a [`MaxHoldingPeriod`](holding_period::MaxHoldingPeriod) time-based exit policy
+/// that closes positions held for longer than a configurable duration.
+pub mod holding_period;
+
+/// Engine-side [`IcebergSlicer`](iceberg::IcebergSlicer) that emulates iceberg orders by slicing
+/// them into sequential child limit orders, for exchanges that do not support them natively.
+pub mod iceberg;
+
+/// Library of reference momentum & mean-reversion strategies, parameterised via config, that
+/// provide working baselines to benchmark against.
+///
+/// eg/ `LibraryStrategy<RsiMeanReversion, _>`.
+pub mod library;
+
+/// [`MarketMaker`](market_maker::MarketMaker) strategy scaffold that maintains inventory skewed
+/// two-sided quotes around a fair value.
+pub mod market_maker;
+
+/// [`MultiStrategy`](multi::MultiStrategy) that hosts multiple `AlgoStrategy`s within one
+/// `Engine`, each identified by a `StrategyId`.
+pub mod multi;
+
+/// [`ExposureAllocator`](normalise::ExposureAllocator) that converts target exposures expressed
+/// in a reference currency (eg/ "$5k long") into base asset quantities, regardless of the
+/// instrument quote asset.
+pub mod normalise;
+
+/// [`OpposingSignalPolicy`](netting::OpposingSignalPolicy) controlling whether a signal that
+/// opposes an open position is ignored, closes & reverses it, or nets it down by signal strength.
+pub mod netting;
+
+/// Engine-side [`OcoManager`](oco::OcoManager) that emulates one-cancels-other order groups by
+/// cancelling the remaining legs once any leg fills.
+pub mod oco;
+
 /// Defines a strategy interface enables custom [`Engine`] to be performed in the event of an
 /// exchange disconnection.
 pub mod on_disconnect;
@@ -40,6 +96,24 @@ pub mod on_disconnect;
 /// `TradingState` gets set to `TradingState::Disabled`.
 pub mod on_trading_disabled;
 
+/// Target-weight portfolio [`Rebalancer`](rebalance::Rebalancer) that generates the minimal set
+/// of orders required to move a portfolio to its target weights at a configurable cadence.
+pub mod rebalance;
+
+/// Risk parity [`RiskParityStrategy`](risk_parity::RiskParityStrategy) that allocates capital
+/// across instruments inversely proportional to their rolling volatility.
+pub mod risk_parity;
+
+/// Engine-side [`OrderSplitter`](split::OrderSplitter) that works orders exceeding the exchange
+/// maximum order quantity (or a configured cap) via sequential child orders, aggregating fills.
+pub mod split;
+
+/// Utilities for actioning `Position` `TrailingStop`s & `BreakEvenStop`s that have been
+/// triggered.
+///
+/// eg/ `close_triggered_trailing_stops_with_market_orders`.
+pub mod trailing_stop;
+
 /// Naive implementation of all strategy interfaces.
 ///
 /// *THIS IS FOR DEMONSTRATION PURPOSES ONLY, NEVER USE FOR REAL TRADING OR IN PRODUCTION*.
@@ -49,7 +123,8 @@ pub mod on_trading_disabled;
 /// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic (ClosePositionsStrategy).
 /// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
 /// - Does nothing when trading state is set to disabled (OnDisconnectStrategy).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound = "")]
 pub struct DefaultStrategy<State> {
     pub id: StrategyId,
     phantom: PhantomData<State>,
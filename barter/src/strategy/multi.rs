@@ -0,0 +1,304 @@
+use crate::{
+    engine::{
+        Engine,
+        state::{
+            EngineState,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+            position::SignalMeta,
+        },
+    },
+    strategy::{
+        algo::{AlgoStrategy, StrategyUpdateError},
+        close_positions::{ClosePositionsStrategy, close_strategy_positions_with_market_orders},
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::{
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{
+    Keyed,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Runtime parameter update for a single [`MultiStrategy`] constituent, routed by
+/// [`StrategyId`].
+///
+/// eg/ `{"strategy": "rsi_btc", "params": {"period": 14}}`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MultiStrategyParams {
+    pub strategy: StrategyId,
+    pub params: serde_json::Value,
+}
+
+/// Hosts multiple [`AlgoStrategy`]s within one `Engine`, each identified by a [`StrategyId`].
+///
+/// Constituent strategies are expected to tag the orders they generate with their own
+/// [`StrategyId`], so resulting `Trade`s are booked against the per-strategy
+/// [`StrategyBooks`](crate::engine::state::instrument::strategy::StrategyBooks) and
+/// summarised in the `TradingSummary` `strategies`.
+///
+/// This strategy:
+/// - Combines the algorithmic orders of every constituent strategy (AlgoStrategy).
+/// - Routes runtime parameter updates to a constituent strategy via [`MultiStrategyParams`]
+///   (AlgoStrategy).
+/// - Closes each strategy position via the [`close_strategy_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+#[derive(Debug, Clone)]
+pub struct MultiStrategy<Strategy, State> {
+    pub strategies: Vec<Keyed<StrategyId, Strategy>>,
+    phantom: PhantomData<State>,
+}
+
+impl<Strategy, State> MultiStrategy<Strategy, State> {
+    /// Construct a new `MultiStrategy` from the provided [`StrategyId`] keyed strategies.
+    pub fn new<Iter>(strategies: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = Keyed<StrategyId, Strategy>>,
+    {
+        Self {
+            strategies: strategies.into_iter().collect(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Return a mutable reference to the strategy associated with the [`StrategyId`], if any.
+    pub fn strategy_mut(&mut self, id: &StrategyId) -> Option<&mut Strategy> {
+        self.strategies
+            .iter_mut()
+            .find(|strategy| &strategy.key == id)
+            .map(|strategy| &mut strategy.value)
+    }
+}
+
+impl<Strategy, State, ExchangeKey, InstrumentKey> AlgoStrategy<ExchangeKey, InstrumentKey>
+    for MultiStrategy<Strategy, State>
+where
+    Strategy: AlgoStrategy<ExchangeKey, InstrumentKey, State = State>,
+{
+    type State = State;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    ) {
+        self.strategies.iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut cancels, mut opens), strategy| {
+                let (next_cancels, next_opens) = strategy.value.generate_algo_orders(state);
+                cancels.extend(next_cancels);
+                opens.extend(next_opens);
+                (cancels, opens)
+            },
+        )
+    }
+
+    fn update_params(&mut self, params: &str) -> Result<(), StrategyUpdateError> {
+        let update = serde_json::from_str::<MultiStrategyParams>(params)
+            .map_err(|error| StrategyUpdateError::InvalidParams(error.to_string()))?;
+
+        self.strategy_mut(&update.strategy)
+            .ok_or_else(|| {
+                StrategyUpdateError::InvalidParams(format!(
+                    "MultiStrategy does not contain: {}",
+                    update.strategy
+                ))
+            })?
+            .update_params(&update.params.to_string())
+    }
+
+    fn signal_meta(
+        &self,
+        state: &Self::State,
+        request: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Option<SignalMeta> {
+        self.strategies
+            .iter()
+            .find(|strategy| strategy.key == request.key.strategy)?
+            .value
+            .signal_meta(state, request)
+    }
+}
+
+impl<Strategy, GlobalData, InstrumentData> ClosePositionsStrategy
+    for MultiStrategy<Strategy, EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_strategy_positions_with_market_orders(state, filter, |_| ClientOrderId::random())
+    }
+}
+
+impl<Strategy, Clock, State, ExecutionTxs, Risk>
+    OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk> for MultiStrategy<Strategy, State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Strategy, Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for MultiStrategy<Strategy, State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::order::{OrderKey, OrderKind, TimeInForce, request::RequestOpen};
+    use barter_instrument::Side;
+    use rust_decimal::Decimal;
+    use std::cell::Cell;
+
+    #[derive(Debug)]
+    struct FixedStrategy {
+        id: StrategyId,
+        quantity: Cell<Decimal>,
+    }
+
+    impl AlgoStrategy for FixedStrategy {
+        type State = ();
+
+        fn generate_algo_orders(
+            &self,
+            _: &Self::State,
+        ) -> (
+            impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+            impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+        ) {
+            let open = OrderRequestOpen {
+                key: OrderKey {
+                    exchange: ExchangeIndex(0),
+                    instrument: InstrumentIndex(0),
+                    strategy: self.id.clone(),
+                    cid: ClientOrderId::new(self.id.0.as_str()),
+                },
+                state: RequestOpen {
+                    side: Side::Buy,
+                    price: Decimal::ONE,
+                    quantity: self.quantity.get(),
+                    kind: OrderKind::Market,
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    reduce_only: false,
+                },
+            };
+
+            (std::iter::empty(), std::iter::once(open))
+        }
+
+        fn update_params(&mut self, params: &str) -> Result<(), StrategyUpdateError> {
+            let quantity = serde_json::from_str(params)
+                .map_err(|error| StrategyUpdateError::InvalidParams(error.to_string()))?;
+            self.quantity.set(quantity);
+            Ok(())
+        }
+    }
+
+    fn multi_strategy() -> MultiStrategy<FixedStrategy, ()> {
+        MultiStrategy::new(["a", "b"].map(|id| {
+            Keyed::new(
+                StrategyId::new(id),
+                FixedStrategy {
+                    id: StrategyId::new(id),
+                    quantity: Cell::new(Decimal::ONE),
+                },
+            )
+        }))
+    }
+
+    #[test]
+    fn test_multi_strategy_generate_algo_orders() {
+        let strategy = multi_strategy();
+
+        let (cancels, opens) = strategy.generate_algo_orders(&());
+
+        assert_eq!(cancels.into_iter().count(), 0);
+        assert_eq!(
+            opens
+                .into_iter()
+                .map(|open| open.key.strategy)
+                .collect::<Vec<_>>(),
+            vec![StrategyId::new("a"), StrategyId::new("b")]
+        );
+    }
+
+    #[test]
+    fn test_multi_strategy_update_params() {
+        struct TestCase {
+            params: &'static str,
+            expected_ok: bool,
+            expected_quantities: Vec<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: params routed to the constituent strategy
+            TestCase {
+                params: r#"{"strategy": "b", "params": "2"}"#,
+                expected_ok: true,
+                expected_quantities: vec![Decimal::ONE, Decimal::TWO],
+            },
+            // TC1: unknown StrategyId
+            TestCase {
+                params: r#"{"strategy": "c", "params": "2"}"#,
+                expected_ok: false,
+                expected_quantities: vec![Decimal::ONE, Decimal::ONE],
+            },
+            // TC2: malformed params
+            TestCase {
+                params: r#"{"params": "2"}"#,
+                expected_ok: false,
+                expected_quantities: vec![Decimal::ONE, Decimal::ONE],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut strategy = multi_strategy();
+
+            let actual = strategy.update_params(test.params);
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{index} failed");
+
+            let quantities = strategy
+                .strategies
+                .iter()
+                .map(|strategy| strategy.value.quantity.get())
+                .collect::<Vec<_>>();
+            assert_eq!(quantities, test.expected_quantities, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    engine::state::position::Position,
+    strategy::close_positions::build_ioc_market_order_to_close_position,
+};
+use barter_execution::order::{
+    id::{ClientOrderId, StrategyId},
+    request::OrderRequestOpen,
+};
+use barter_instrument::Side;
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Directional trading signal for an instrument.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct DirectionalSignal {
+    pub side: Side,
+
+    /// Signal strength in the range `[0, 1]`, where values outside the range are clamped.
+    pub strength: Decimal,
+}
+
+/// Policy controlling what happens when a [`DirectionalSignal`] opposes an open [`Position`].
+///
+/// Defaults to [`OpposingSignalPolicy::Ignore`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum OpposingSignalPolicy {
+    /// Ignore the opposing signal, maintaining the open `Position`.
+    #[default]
+    Ignore,
+
+    /// Close the open `Position`, then open a `Position` of the same quantity on the signal
+    /// `Side`.
+    CloseThenReverse,
+
+    /// Reduce the open `Position` by the proportion of its quantity given by the signal strength,
+    /// without reversing it.
+    NetByStrength,
+}
+
+impl OpposingSignalPolicy {
+    /// Generate the `ImmediateOrCancel` `Market` open order requests required to action a
+    /// [`DirectionalSignal`] according to the `OpposingSignalPolicy`.
+    ///
+    /// Returns no order requests if the signal does not oppose the [`Position`] (ie/ it is on the
+    /// same `Side`), or if the policy is [`OpposingSignalPolicy::Ignore`].
+    pub fn generate_orders<ExchangeKey, AssetKey, InstrumentKey>(
+        &self,
+        exchange: ExchangeKey,
+        position: &Position<AssetKey, InstrumentKey>,
+        signal: &DirectionalSignal,
+        strategy: StrategyId,
+        price: Decimal,
+        gen_cid: impl Fn() -> ClientOrderId,
+    ) -> Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>
+    where
+        ExchangeKey: Clone,
+        InstrumentKey: Clone,
+    {
+        if signal.side == position.side {
+            return vec![];
+        }
+
+        match self {
+            OpposingSignalPolicy::Ignore => vec![],
+            OpposingSignalPolicy::CloseThenReverse => {
+                let close = build_ioc_market_order_to_close_position(
+                    exchange, position, strategy, price, &gen_cid,
+                );
+
+                let mut reverse = close.clone();
+                reverse.key.cid = gen_cid();
+                reverse.state.reduce_only = false;
+
+                vec![close, reverse]
+            }
+            OpposingSignalPolicy::NetByStrength => {
+                let strength = signal.strength.clamp(Decimal::ZERO, Decimal::ONE);
+                let quantity = position.quantity_abs * strength;
+                if quantity.is_zero() {
+                    return vec![];
+                }
+
+                let mut reduce = build_ioc_market_order_to_close_position(
+                    exchange, position, strategy, price, gen_cid,
+                );
+                reduce.state.quantity = quantity;
+
+                vec![reduce]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::{
+        order::id::OrderId,
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{asset::QuoteAsset, instrument::InstrumentIndex};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn position(side: Side, quantity: Decimal) -> Position<QuoteAsset, InstrumentIndex> {
+        Position::from(&Trade {
+            id: TradeId::new("trade"),
+            order_id: OrderId::new("order"),
+            cid: None,
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            side,
+            price: dec!(100),
+            quantity,
+            liquidity: None,
+            fees: AssetFees::new(QuoteAsset, Decimal::ZERO),
+        })
+    }
+
+    #[test]
+    fn test_opposing_signal_policy_generate_orders() {
+        struct TestCase {
+            policy: OpposingSignalPolicy,
+            position: Position<QuoteAsset, InstrumentIndex>,
+            signal: DirectionalSignal,
+            expected: Vec<(Side, Decimal, bool)>,
+        }
+
+        let cases = vec![
+            // TC0: Ignore maintains the open Position
+            TestCase {
+                policy: OpposingSignalPolicy::Ignore,
+                position: position(Side::Buy, dec!(2)),
+                signal: DirectionalSignal::new(Side::Sell, dec!(1)),
+                expected: vec![],
+            },
+            // TC1: CloseThenReverse closes the LONG, then opens a SHORT of the same quantity
+            TestCase {
+                policy: OpposingSignalPolicy::CloseThenReverse,
+                position: position(Side::Buy, dec!(2)),
+                signal: DirectionalSignal::new(Side::Sell, dec!(0.5)),
+                expected: vec![(Side::Sell, dec!(2), true), (Side::Sell, dec!(2), false)],
+            },
+            // TC2: NetByStrength partially reduces the SHORT by the signal strength
+            TestCase {
+                policy: OpposingSignalPolicy::NetByStrength,
+                position: position(Side::Sell, dec!(2)),
+                signal: DirectionalSignal::new(Side::Buy, dec!(0.25)),
+                expected: vec![(Side::Buy, dec!(0.5), true)],
+            },
+            // TC3: NetByStrength clamps the signal strength, closing without reversing
+            TestCase {
+                policy: OpposingSignalPolicy::NetByStrength,
+                position: position(Side::Buy, dec!(2)),
+                signal: DirectionalSignal::new(Side::Sell, dec!(1.5)),
+                expected: vec![(Side::Sell, dec!(2), true)],
+            },
+            // TC4: NetByStrength with zero strength does nothing
+            TestCase {
+                policy: OpposingSignalPolicy::NetByStrength,
+                position: position(Side::Buy, dec!(2)),
+                signal: DirectionalSignal::new(Side::Sell, dec!(0)),
+                expected: vec![],
+            },
+            // TC5: signal on the same Side as the Position does not oppose it
+            TestCase {
+                policy: OpposingSignalPolicy::CloseThenReverse,
+                position: position(Side::Buy, dec!(2)),
+                signal: DirectionalSignal::new(Side::Buy, dec!(1)),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test
+                .policy
+                .generate_orders(
+                    0,
+                    &test.position,
+                    &test.signal,
+                    StrategyId::new("strategy"),
+                    dec!(100),
+                    ClientOrderId::random,
+                )
+                .into_iter()
+                .map(|order| {
+                    (
+                        order.state.side,
+                        order.state.quantity,
+                        order.state.reduce_only,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
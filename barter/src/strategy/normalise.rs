@@ -0,0 +1,270 @@
+use crate::engine::state::{
+    EngineState,
+    asset::equity::EquityCalculator,
+    instrument::{InstrumentState, data::InstrumentDataState},
+};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::{
+    Side,
+    asset::name::AssetNameInternal,
+    exchange::ExchangeIndex,
+    instrument::{InstrumentIndex, name::InstrumentNameInternal},
+};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+
+/// All errors generated when normalising a [`TargetExposure`] into a base asset quantity.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum NormaliseError {
+    #[error("no market price available for instrument: {0}")]
+    MissingPrice(InstrumentNameInternal),
+
+    #[error("no FX rate available to convert quote asset: {0}")]
+    MissingFxRate(AssetNameInternal),
+}
+
+/// Target exposure of an instrument denominated in a reference currency, regardless of the
+/// instrument quote asset (eg/ "$5k long" of "eth_btc").
+///
+/// A negative `notional` is a SHORT exposure.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct TargetExposure {
+    pub instrument: InstrumentIndex,
+    pub notional: Decimal,
+}
+
+/// Converts a notional value in the reference currency into a signed base asset quantity.
+///
+/// The `fx_rate` is the value of one unit of the instrument quote asset in the reference
+/// currency, and the `price` is the instrument price in the quote asset.
+///
+/// Returns `None` if either the `fx_rate` or `price` is not positive.
+pub fn notional_to_quantity(
+    notional: Decimal,
+    fx_rate: Decimal,
+    price: Decimal,
+) -> Option<Decimal> {
+    (fx_rate > Decimal::ZERO && price > Decimal::ZERO).then(|| notional / fx_rate / price)
+}
+
+/// Allocates [`TargetExposure`]s expressed in a reference currency, converting them into base
+/// asset quantities using the latest instrument market prices and FX rates.
+///
+/// The reference currency and FX rates are provided by the [`EquityCalculator`], such that
+/// exposures are expressed in the same currency the portfolio equity is valued in.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Constructor)]
+pub struct ExposureAllocator {
+    pub fx: EquityCalculator,
+}
+
+impl ExposureAllocator {
+    /// Normalise a [`TargetExposure`] into a signed base asset quantity of the instrument.
+    pub fn quantity<GlobalData, InstrumentData>(
+        &self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        target: &TargetExposure,
+    ) -> Result<Decimal, NormaliseError>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let instrument_state = state.instruments.instrument_index(&target.instrument);
+        let quote = &state
+            .assets
+            .asset_index(&instrument_state.instrument.underlying.quote)
+            .asset
+            .name_internal;
+
+        let price = instrument_state.data.price().ok_or_else(|| {
+            NormaliseError::MissingPrice(instrument_state.instrument.name_internal.clone())
+        })?;
+
+        let fx_rate = self
+            .fx
+            .rate(state, quote)
+            .ok_or_else(|| NormaliseError::MissingFxRate(quote.clone()))?;
+
+        notional_to_quantity(target.notional, fx_rate, price).ok_or_else(|| {
+            NormaliseError::MissingPrice(instrument_state.instrument.name_internal.clone())
+        })
+    }
+
+    /// Generate the market [`OrderRequestOpen`]s required to move each instrument's current
+    /// `Position` to its [`TargetExposure`].
+    ///
+    /// Targets that cannot be normalised (eg/ due to a missing price or FX rate) are skipped.
+    pub fn generate_orders<GlobalData, InstrumentData>(
+        &self,
+        strategy: &StrategyId,
+        state: &EngineState<GlobalData, InstrumentData>,
+        targets: &[TargetExposure],
+    ) -> Vec<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        targets
+            .iter()
+            .filter_map(|target| {
+                let quantity_target = match self.quantity(state, target) {
+                    Ok(quantity) => quantity,
+                    Err(error) => {
+                        warn!(%error, ?target, "ExposureAllocator skipping TargetExposure");
+                        return None;
+                    }
+                };
+
+                let instrument_state = state.instruments.instrument_index(&target.instrument);
+                let delta = quantity_target - position_quantity(instrument_state);
+                if delta.is_zero() {
+                    return None;
+                }
+
+                Some(OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: instrument_state.instrument.exchange,
+                        instrument: target.instrument,
+                        strategy: strategy.clone(),
+                        cid: ClientOrderId::random(),
+                    },
+                    state: RequestOpen {
+                        side: if delta > Decimal::ZERO {
+                            Side::Buy
+                        } else {
+                            Side::Sell
+                        },
+                        price: instrument_state.data.price()?,
+                        quantity: delta.abs(),
+                        kind: OrderKind::Market,
+                        time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+fn position_quantity<InstrumentData>(
+    instrument_state: &InstrumentState<InstrumentData>,
+) -> Decimal {
+    instrument_state
+        .position
+        .current
+        .as_ref()
+        .map_or(Decimal::ZERO, |position| match position.side {
+            Side::Buy => position.quantity_abs,
+            Side::Sell => -position.quantity_abs,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Timed,
+        engine::state::{
+            asset::equity::FxRateSource, global::DefaultGlobalData,
+            instrument::data::DefaultInstrumentMarketData,
+        },
+    };
+    use barter_instrument::{
+        Underlying, exchange::ExchangeId, index::IndexedInstruments, instrument::Instrument,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn state(
+        prices: [Option<Decimal>; 2],
+    ) -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        let instruments = IndexedInstruments::builder()
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_btc_usdt",
+                "BTCUSDT",
+                Underlying::new("btc", "usdt"),
+                None,
+            ))
+            .add_instrument(Instrument::spot(
+                ExchangeId::BinanceSpot,
+                "binance_spot_eth_btc",
+                "ETHBTC",
+                Underlying::new("eth", "btc"),
+                None,
+            ))
+            .build();
+
+        let mut state = EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        for (index, price) in prices.into_iter().enumerate() {
+            state
+                .instruments
+                .instrument_index_mut(&InstrumentIndex(index))
+                .data
+                .last_traded_price = price.map(|price| Timed::new(price, DateTime::<Utc>::MIN_UTC));
+        }
+
+        state
+    }
+
+    #[test]
+    fn test_exposure_allocator_quantity() {
+        struct TestCase {
+            prices: [Option<Decimal>; 2],
+            target: TargetExposure,
+            expected: Result<Decimal, NormaliseError>,
+        }
+
+        let allocator = ExposureAllocator::new(EquityCalculator::new(
+            AssetNameInternal::new("usdt"),
+            FxRateSource::LatestPrices,
+        ));
+
+        let cases = vec![
+            // TC0: SHORT exposure of an instrument quoted in the reference currency
+            TestCase {
+                prices: [Some(dec!(50_000)), Some(dec!(0.05))],
+                target: TargetExposure::new(InstrumentIndex(0), dec!(-5_000)),
+                expected: Ok(dec!(-0.1)),
+            },
+            // TC1: LONG exposure of an instrument quoted in another currency is converted
+            TestCase {
+                prices: [Some(dec!(50_000)), Some(dec!(0.05))],
+                target: TargetExposure::new(InstrumentIndex(1), dec!(5_000)),
+                expected: Ok(dec!(2)),
+            },
+            // TC2: missing FX rate of the quote asset
+            TestCase {
+                prices: [None, Some(dec!(0.05))],
+                target: TargetExposure::new(InstrumentIndex(1), dec!(5_000)),
+                expected: Err(NormaliseError::MissingFxRate(AssetNameInternal::new("btc"))),
+            },
+            // TC3: missing instrument price
+            TestCase {
+                prices: [Some(dec!(50_000)), None],
+                target: TargetExposure::new(InstrumentIndex(1), dec!(5_000)),
+                expected: Err(NormaliseError::MissingPrice(InstrumentNameInternal::new(
+                    "binance_spot_eth_btc",
+                ))),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = allocator.quantity(&state(test.prices), &test.target);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::engine::state::order::Orders;
+use barter_execution::{
+    order::{
+        id::ClientOrderId,
+        request::{OrderRequestCancel, OrderRequestOco, OrderRequestOpen},
+    },
+    trade::Trade,
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use fnv::FnvHashMap;
+use std::marker::PhantomData;
+
+/// Engine-side emulation of one-cancels-other [`OrderRequestOco`] groups, for exchanges that do
+/// not support them natively.
+///
+/// Each leg of a group is sent as an independent order. Once any leg fills (fully or
+/// partially), cancel requests are generated for the remaining legs, preventing a double exit
+/// (eg/ both the take-profit and the stop-loss filling).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OcoManager<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// Sibling legs of each tracked leg [`ClientOrderId`].
+    pub siblings: FnvHashMap<ClientOrderId, Vec<ClientOrderId>>,
+    phantom: PhantomData<(ExchangeKey, InstrumentKey)>,
+}
+
+impl<ExchangeKey, InstrumentKey> Default for OcoManager<ExchangeKey, InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            siblings: FnvHashMap::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> OcoManager<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Clone,
+    InstrumentKey: Clone,
+{
+    /// Register an [`OrderRequestOco`] group, returning the leg order requests that should be
+    /// sent to the exchange.
+    pub fn open(
+        &mut self,
+        group: OrderRequestOco<ExchangeKey, InstrumentKey>,
+    ) -> Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        let cids = group
+            .legs
+            .iter()
+            .map(|leg| leg.key.cid.clone())
+            .collect::<Vec<_>>();
+
+        for cid in &cids {
+            let siblings = cids
+                .iter()
+                .filter(|sibling| *sibling != cid)
+                .cloned()
+                .collect();
+
+            self.siblings.insert(cid.clone(), siblings);
+        }
+
+        group.legs
+    }
+
+    /// Update the `OcoManager` from a fill, returning cancel requests for the remaining legs of
+    /// the filled leg's group.
+    ///
+    /// Remaining legs not tracked by the provided [`Orders`] (eg/ already cancelled), or still
+    /// in-flight, are not cancelled. Fills of orders that are not OCO legs are ignored.
+    pub fn update_from_trade<AssetKey, TradeInstrumentKey>(
+        &mut self,
+        trade: &Trade<AssetKey, TradeInstrumentKey>,
+        orders: &Orders<ExchangeKey, InstrumentKey>,
+    ) -> Vec<OrderRequestCancel<ExchangeKey, InstrumentKey>> {
+        let Some(siblings) = trade.cid.as_ref().and_then(|cid| self.remove(cid)) else {
+            return vec![];
+        };
+
+        siblings
+            .iter()
+            .filter_map(|sibling| orders.0.get(sibling))
+            .filter_map(|order| order.to_request_cancel())
+            .collect()
+    }
+
+    /// Stop tracking the group of the provided leg (eg/ after it has been cancelled), returning
+    /// the remaining legs of the group.
+    pub fn remove(&mut self, leg: &ClientOrderId) -> Option<Vec<ClientOrderId>> {
+        let siblings = self.siblings.remove(leg)?;
+        siblings.iter().for_each(|sibling| {
+            self.siblings.remove(sibling);
+        });
+        Some(siblings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::trade;
+    use barter_execution::order::{
+        Order, OrderKey, OrderKind, TimeInForce,
+        id::{OrderId, StrategyId},
+        request::RequestOpen,
+        state::{ActiveOrderState, Open},
+    };
+    use barter_instrument::{Side, asset::QuoteAsset, instrument::name::InstrumentNameInternal};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn leg(cid: &str) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side: Side::Sell,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: true,
+            },
+        }
+    }
+
+    fn open_order(cid: &str) -> Order<ExchangeIndex, InstrumentIndex, ActiveOrderState> {
+        let request = leg(cid);
+        Order {
+            key: request.key,
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state: ActiveOrderState::Open(Open {
+                id: OrderId::new(cid),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                filled_quantity: dec!(0),
+            }),
+        }
+    }
+
+    fn fill(cid: &str) -> Trade<QuoteAsset, InstrumentNameInternal> {
+        Trade {
+            cid: Some(ClientOrderId::new(cid)),
+            ..trade(DateTime::<Utc>::MIN_UTC, Side::Sell, 100.0, 1.0, 0.0)
+        }
+    }
+
+    #[test]
+    fn test_oco_manager_update_from_trade() {
+        struct TestCase {
+            fill: &'static str,
+            expected_cancels: Vec<&'static str>,
+        }
+
+        let cases = vec![
+            // TC0: take-profit fill cancels stop-loss
+            TestCase {
+                fill: "take_profit",
+                expected_cancels: vec!["stop_loss"],
+            },
+            // TC1: stop-loss fill cancels take-profit
+            TestCase {
+                fill: "stop_loss",
+                expected_cancels: vec!["take_profit"],
+            },
+            // TC2: fill of an order that is not an OCO leg is ignored
+            TestCase {
+                fill: "other",
+                expected_cancels: vec![],
+            },
+        ];
+
+        let orders = Orders::new(
+            ["take_profit", "stop_loss"]
+                .into_iter()
+                .map(|cid| (ClientOrderId::new(cid), open_order(cid)))
+                .collect(),
+        );
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut manager = OcoManager::default();
+            let legs = manager.open(OrderRequestOco::new(vec![
+                leg("take_profit"),
+                leg("stop_loss"),
+            ]));
+            assert_eq!(legs.len(), 2, "TC{index} failed");
+
+            let actual = manager
+                .update_from_trade(&fill(test.fill), &orders)
+                .into_iter()
+                .map(|cancel| cancel.key.cid.0)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected_cancels, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_oco_manager_cancels_only_once() {
+        let orders = Orders::new(
+            ["take_profit", "stop_loss"]
+                .into_iter()
+                .map(|cid| (ClientOrderId::new(cid), open_order(cid)))
+                .collect(),
+        );
+
+        let mut manager = OcoManager::default();
+        manager.open(OrderRequestOco::new(vec![
+            leg("take_profit"),
+            leg("stop_loss"),
+        ]));
+
+        assert_eq!(
+            manager
+                .update_from_trade(&fill("take_profit"), &orders)
+                .len(),
+            1
+        );
+        assert!(
+            manager
+                .update_from_trade(&fill("take_profit"), &orders)
+                .is_empty()
+        );
+        assert!(
+            manager
+                .update_from_trade(&fill("stop_loss"), &orders)
+                .is_empty()
+        );
+        assert!(manager.siblings.is_empty());
+    }
+}
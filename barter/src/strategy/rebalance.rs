@@ -0,0 +1,477 @@
+use crate::{
+    engine::{
+        Engine,
+        state::{
+            EngineState,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        },
+    },
+    strategy::{
+        algo::{AlgoStrategy, StrategyUpdateError},
+        close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::{
+    Side,
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, marker::PhantomData};
+
+/// Target fraction of total portfolio value allocated to an instrument (eg/ 0.25 for 25%).
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct TargetWeight {
+    pub instrument: InstrumentIndex,
+    pub weight: Decimal,
+}
+
+/// Current holding of an instrument, where a negative `quantity` is a SHORT holding.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct Holding {
+    pub instrument: InstrumentIndex,
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+/// Trade required to move a [`Holding`] to its [`TargetWeight`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct RebalanceTrade {
+    pub instrument: InstrumentIndex,
+    pub side: Side,
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+/// Configuration of a [`Rebalancer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct RebalanceConfig {
+    /// Quote asset whose balance is held as cash (ie/ the residual portfolio weight).
+    pub quote: AssetIndex,
+
+    /// Interval of `Engine` time between rebalances.
+    pub interval: TimeDelta,
+
+    /// Drift from a [`TargetWeight`] tolerated before rebalancing an instrument (eg/ 0.02 for 2%).
+    pub tolerance: Decimal,
+
+    /// Minimum notional value of a [`RebalanceTrade`].
+    pub min_notional: Decimal,
+
+    /// Estimated fees as a fraction of notional value, reserved from the cash spent on buys.
+    pub fees_percent: Decimal,
+}
+
+/// Rebalances a portfolio towards a set of [`TargetWeight`]s at a configurable cadence, generating
+/// the minimal set of trades (ie/ only instruments that have drifted outside the `tolerance`, and
+/// with a notional value of at least `min_notional`).
+///
+/// Target weights can be provided by any strategy (eg/ from a signal), or from config via the
+/// [`RebalanceStrategy`].
+#[derive(Debug, Clone)]
+pub struct Rebalancer {
+    pub config: RebalanceConfig,
+    previous: Cell<Option<DateTime<Utc>>>,
+}
+
+impl Rebalancer {
+    /// Construct a new `Rebalancer` using the provided [`RebalanceConfig`].
+    pub fn new(config: RebalanceConfig) -> Self {
+        Self {
+            config,
+            previous: Cell::new(None),
+        }
+    }
+
+    /// Time of the previous rebalance, if any.
+    pub fn previous(&self) -> Option<DateTime<Utc>> {
+        self.previous.get()
+    }
+
+    /// Determine the [`RebalanceTrade`]s required to move the provided [`Holding`]s to the
+    /// [`TargetWeight`]s.
+    ///
+    /// Any held instrument without a [`TargetWeight`] is targeted at zero weight. Sells are
+    /// ordered before buys, so the cash they release is available to fund the buys.
+    pub fn trades(
+        &self,
+        targets: &[TargetWeight],
+        cash: Decimal,
+        holdings: &[Holding],
+    ) -> Vec<RebalanceTrade> {
+        let equity = holdings.iter().fold(cash, |equity, holding| {
+            equity + holding.quantity * holding.price
+        });
+
+        if equity <= Decimal::ZERO {
+            return vec![];
+        }
+
+        let mut trades = holdings
+            .iter()
+            .filter(|holding| holding.price > Decimal::ZERO)
+            .filter_map(|holding| {
+                let weight = targets
+                    .iter()
+                    .find(|target| target.instrument == holding.instrument)
+                    .map_or(Decimal::ZERO, |target| target.weight);
+
+                let delta = weight * equity - holding.quantity * holding.price;
+
+                if delta.abs() / equity <= self.config.tolerance
+                    || delta.abs() < self.config.min_notional
+                {
+                    return None;
+                }
+
+                let (side, notional) = if delta > Decimal::ZERO {
+                    (Side::Buy, delta / (Decimal::ONE + self.config.fees_percent))
+                } else {
+                    (Side::Sell, delta.abs())
+                };
+
+                Some(RebalanceTrade::new(
+                    holding.instrument,
+                    side,
+                    holding.price,
+                    notional / holding.price,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        trades.sort_by_key(|trade| trade.side == Side::Buy);
+        trades
+    }
+
+    /// Generate the market [`OrderRequestOpen`]s required to rebalance the [`EngineState`]
+    /// towards the provided [`TargetWeight`]s, if a rebalance is due.
+    ///
+    /// A rebalance is due if there has been no previous rebalance, or if the `interval` has
+    /// elapsed since the previous rebalance, and none of the provided strategy's orders are
+    /// still active.
+    pub fn generate_orders<GlobalData, InstrumentData>(
+        &self,
+        strategy: &StrategyId,
+        state: &EngineState<GlobalData, InstrumentData>,
+        targets: &[TargetWeight],
+    ) -> Vec<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let time_now = state.time_engine_now;
+        if self
+            .previous
+            .get()
+            .is_some_and(|previous| time_now - previous < self.config.interval)
+        {
+            return vec![];
+        }
+
+        let has_active_order =
+            state
+                .instruments
+                .instruments(&InstrumentFilter::None)
+                .any(|instrument_state| {
+                    instrument_state
+                        .orders
+                        .0
+                        .values()
+                        .any(|order| &order.key.strategy == strategy)
+                });
+
+        if has_active_order {
+            return vec![];
+        }
+
+        let holdings =
+            state
+                .instruments
+                .instruments(&InstrumentFilter::None)
+                .filter_map(|instrument_state| {
+                    let quantity = instrument_state.position.current.as_ref().map_or(
+                        Decimal::ZERO,
+                        |position| match position.side {
+                            Side::Buy => position.quantity_abs,
+                            Side::Sell => -position.quantity_abs,
+                        },
+                    );
+
+                    instrument_state
+                        .data
+                        .price()
+                        .map(|price| Holding::new(instrument_state.key, price, quantity))
+                })
+                .collect::<Vec<_>>();
+
+        let cash = state
+            .assets
+            .asset_index(&self.config.quote)
+            .balance
+            .map_or(Decimal::ZERO, |balance| balance.value.total);
+
+        self.previous.set(Some(time_now));
+
+        self.trades(targets, cash, &holdings)
+            .into_iter()
+            .map(|trade| OrderRequestOpen {
+                key: OrderKey {
+                    exchange: state
+                        .instruments
+                        .instrument_index(&trade.instrument)
+                        .instrument
+                        .exchange,
+                    instrument: trade.instrument,
+                    strategy: strategy.clone(),
+                    cid: ClientOrderId::random(),
+                },
+                state: RequestOpen {
+                    side: trade.side,
+                    price: trade.price,
+                    quantity: trade.quantity,
+                    kind: OrderKind::Market,
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    reduce_only: false,
+                },
+            })
+            .collect()
+    }
+}
+
+/// Strategy that rebalances a portfolio towards a set of configured [`TargetWeight`]s using a
+/// [`Rebalancer`].
+///
+/// *THIS IS A REFERENCE IMPLEMENTATION, REVIEW CAREFULLY BEFORE USING FOR REAL TRADING*.
+///
+/// This strategy:
+/// - Generates `ImmediateOrCancel` `Market` orders that move the portfolio to the
+///   [`TargetWeight`]s each time a rebalance is due (AlgoStrategy).
+/// - Supports replacing the [`TargetWeight`]s at runtime via a JSON array of [`TargetWeight`]s
+///   (AlgoStrategy::update_params).
+/// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+#[derive(Debug, Clone)]
+pub struct RebalanceStrategy<State> {
+    pub id: StrategyId,
+    pub targets: Vec<TargetWeight>,
+    pub rebalancer: Rebalancer,
+    phantom: PhantomData<State>,
+}
+
+impl<State> RebalanceStrategy<State> {
+    /// Construct a new `RebalanceStrategy` using the provided [`StrategyId`], [`TargetWeight`]s
+    /// and [`RebalanceConfig`].
+    pub fn new(id: StrategyId, targets: Vec<TargetWeight>, config: RebalanceConfig) -> Self {
+        Self {
+            id,
+            targets,
+            rebalancer: Rebalancer::new(config),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy
+    for RebalanceStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let opens = self
+            .rebalancer
+            .generate_orders(&self.id, state, &self.targets);
+
+        (std::iter::empty(), opens)
+    }
+
+    fn update_params(&mut self, params: &str) -> Result<(), StrategyUpdateError> {
+        self.targets = serde_json::from_str(params)
+            .map_err(|error| StrategyUpdateError::InvalidParams(error.to_string()))?;
+        Ok(())
+    }
+}
+
+impl<GlobalData, InstrumentData> ClosePositionsStrategy
+    for RebalanceStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.id, state, filter, |_| {
+            ClientOrderId::random()
+        })
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>
+    for RebalanceStrategy<State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for RebalanceStrategy<State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_rebalancer_trades() {
+        struct TestCase {
+            targets: Vec<TargetWeight>,
+            cash: Decimal,
+            holdings: Vec<Holding>,
+            expected: Vec<RebalanceTrade>,
+        }
+
+        let rebalancer = Rebalancer::new(RebalanceConfig::new(
+            AssetIndex(0),
+            TimeDelta::days(1),
+            dec!(0.02),
+            dec!(10),
+            dec!(0),
+        ));
+
+        let btc = InstrumentIndex(0);
+        let eth = InstrumentIndex(1);
+
+        let cases = vec![
+            // TC0: buy from cash to reach target weights
+            TestCase {
+                targets: vec![
+                    TargetWeight::new(btc, dec!(0.5)),
+                    TargetWeight::new(eth, dec!(0.5)),
+                ],
+                cash: dec!(1000),
+                holdings: vec![
+                    Holding::new(btc, dec!(100), dec!(0)),
+                    Holding::new(eth, dec!(10), dec!(0)),
+                ],
+                expected: vec![
+                    RebalanceTrade::new(btc, Side::Buy, dec!(100), dec!(5)),
+                    RebalanceTrade::new(eth, Side::Buy, dec!(10), dec!(50)),
+                ],
+            },
+            // TC1: sells ordered before buys
+            TestCase {
+                targets: vec![
+                    TargetWeight::new(btc, dec!(0.2)),
+                    TargetWeight::new(eth, dec!(0.8)),
+                ],
+                cash: dec!(0),
+                holdings: vec![
+                    Holding::new(btc, dec!(100), dec!(8)),
+                    Holding::new(eth, dec!(10), dec!(20)),
+                ],
+                expected: vec![
+                    RebalanceTrade::new(btc, Side::Sell, dec!(100), dec!(6)),
+                    RebalanceTrade::new(eth, Side::Buy, dec!(10), dec!(60)),
+                ],
+            },
+            // TC2: drift within tolerance is not rebalanced
+            TestCase {
+                targets: vec![TargetWeight::new(btc, dec!(0.5))],
+                cash: dec!(510),
+                holdings: vec![Holding::new(btc, dec!(100), dec!(4.9))],
+                expected: vec![],
+            },
+            // TC3: trade below min notional is not generated
+            TestCase {
+                targets: vec![TargetWeight::new(btc, dec!(0.5))],
+                cash: dec!(55),
+                holdings: vec![Holding::new(btc, dec!(100), dec!(0.45))],
+                expected: vec![],
+            },
+            // TC4: held instrument without a target weight is sold
+            TestCase {
+                targets: vec![],
+                cash: dec!(0),
+                holdings: vec![Holding::new(btc, dec!(100), dec!(1))],
+                expected: vec![RebalanceTrade::new(btc, Side::Sell, dec!(100), dec!(1))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = rebalancer.trades(&test.targets, test.cash, &test.holdings);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_rebalancer_trades_reserves_fees() {
+        let rebalancer = Rebalancer::new(RebalanceConfig::new(
+            AssetIndex(0),
+            TimeDelta::days(1),
+            dec!(0),
+            dec!(0),
+            dec!(0.01),
+        ));
+
+        let actual = rebalancer.trades(
+            &[TargetWeight::new(InstrumentIndex(0), dec!(1))],
+            dec!(1010),
+            &[Holding::new(InstrumentIndex(0), dec!(100), dec!(0))],
+        );
+
+        let expected = vec![RebalanceTrade::new(
+            InstrumentIndex(0),
+            Side::Buy,
+            dec!(100),
+            dec!(10),
+        )];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{
+    engine::{
+        Engine,
+        state::{
+            EngineState,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        },
+    },
+    strategy::{
+        algo::AlgoStrategy,
+        close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
+        library::PriceHistory,
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+        rebalance::{RebalanceConfig, Rebalancer, TargetWeight},
+    },
+};
+use barter_execution::order::{
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{
+    asset::AssetIndex,
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use derive_more::Constructor;
+use rust_decimal::{Decimal, MathematicalOps};
+use std::{collections::VecDeque, marker::PhantomData};
+
+/// Configuration of a [`RiskParityStrategy`] allocation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Constructor)]
+pub struct RiskParityConfig {
+    /// Number of most recent price returns used to estimate each instrument's volatility.
+    pub period: usize,
+
+    /// Total portfolio weight allocated across all instruments (eg/ 1.0 to be fully invested).
+    pub gross_weight: Decimal,
+}
+
+/// Sample standard deviation of the most recent `period` simple price returns.
+///
+/// Returns `None` if there are fewer than `period + 1` prices, or if `period` is less than two.
+pub fn realised_volatility(prices: &VecDeque<Decimal>, period: usize) -> Option<Decimal> {
+    if period < 2 || prices.len() < period + 1 {
+        return None;
+    }
+
+    let returns = prices
+        .iter()
+        .skip(prices.len() - (period + 1))
+        .zip(prices.iter().skip(prices.len() - period))
+        .map(|(previous, current)| (!previous.is_zero()).then(|| (current - previous) / previous))
+        .collect::<Option<Vec<_>>>()?;
+
+    let count = Decimal::from(returns.len());
+    let mean = returns.iter().sum::<Decimal>() / count;
+    let variance = returns
+        .iter()
+        .map(|ret| (ret - mean) * (ret - mean))
+        .sum::<Decimal>()
+        / (count - Decimal::ONE);
+
+    variance.sqrt()
+}
+
+/// Allocate the `gross_weight` across instruments inversely proportional to their volatility,
+/// so each instrument contributes an equal amount of risk.
+///
+/// Instruments with a non-positive volatility are not allocated any weight.
+pub fn risk_parity_weights(
+    volatilities: impl IntoIterator<Item = (InstrumentIndex, Decimal)>,
+    gross_weight: Decimal,
+) -> Vec<TargetWeight> {
+    let inverse = volatilities
+        .into_iter()
+        .filter(|(_, volatility)| *volatility > Decimal::ZERO)
+        .map(|(instrument, volatility)| (instrument, Decimal::ONE / volatility))
+        .collect::<Vec<_>>();
+
+    let total = inverse.iter().map(|(_, inverse)| inverse).sum::<Decimal>();
+    if total.is_zero() {
+        return vec![];
+    }
+
+    inverse
+        .into_iter()
+        .map(|(instrument, inverse)| TargetWeight::new(instrument, gross_weight * inverse / total))
+        .collect()
+}
+
+/// Portfolio level strategy that allocates capital across all traded instruments inversely
+/// proportional to their rolling volatility (risk parity), using a [`Rebalancer`] to move the
+/// portfolio to the allocation as volatilities change.
+///
+/// *THIS IS A REFERENCE IMPLEMENTATION, REVIEW CAREFULLY BEFORE USING FOR REAL TRADING*.
+///
+/// This strategy:
+/// - Recalculates the risk parity [`TargetWeight`]s each time a rebalance is due, and generates
+///   `ImmediateOrCancel` `Market` orders to move the portfolio to them (AlgoStrategy).
+/// - Generates no orders until every instrument has sufficient price history to estimate its
+///   volatility (AlgoStrategy).
+/// - Closes positions via the naive [`close_open_positions_with_market_orders`] logic
+///   (ClosePositionsStrategy).
+/// - Does nothing when an exchange disconnects (OnDisconnectStrategy).
+/// - Does nothing when trading state is set to disabled (OnTradingDisabled).
+#[derive(Debug, Clone)]
+pub struct RiskParityStrategy<State> {
+    pub id: StrategyId,
+    pub config: RiskParityConfig,
+    pub rebalancer: Rebalancer,
+    phantom: PhantomData<State>,
+}
+
+impl<State> RiskParityStrategy<State> {
+    /// Construct a new `RiskParityStrategy` using the provided [`StrategyId`],
+    /// [`RiskParityConfig`] and [`RebalanceConfig`].
+    pub fn new(id: StrategyId, config: RiskParityConfig, rebalance: RebalanceConfig) -> Self {
+        Self {
+            id,
+            config,
+            rebalancer: Rebalancer::new(rebalance),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy
+    for RiskParityStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState + PriceHistory,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let volatilities = state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .map(|instrument_state| {
+                realised_volatility(instrument_state.data.prices(), self.config.period)
+                    .map(|volatility| (instrument_state.key, volatility))
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let opens = match volatilities {
+            Some(volatilities) => {
+                let targets = risk_parity_weights(volatilities, self.config.gross_weight);
+                self.rebalancer.generate_orders(&self.id, state, &targets)
+            }
+            None => vec![],
+        };
+
+        (std::iter::empty(), opens)
+    }
+}
+
+impl<GlobalData, InstrumentData> ClosePositionsStrategy
+    for RiskParityStrategy<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.id, state, filter, |_| {
+            ClientOrderId::random()
+        })
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>
+    for RiskParityStrategy<State>
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        _: ExchangeId,
+    ) -> Self::OnDisconnect {
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for RiskParityStrategy<State>
+{
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(
+        _: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_realised_volatility() {
+        struct TestCase {
+            prices: Vec<Decimal>,
+            period: usize,
+            expected: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: insufficient prices
+            TestCase {
+                prices: vec![dec!(100), dec!(110)],
+                period: 2,
+                expected: None,
+            },
+            // TC1: constant returns have zero volatility
+            TestCase {
+                prices: vec![dec!(100), dec!(110), dec!(121)],
+                period: 2,
+                expected: Some(dec!(0)),
+            },
+            // TC2: only the most recent period returns are used
+            TestCase {
+                prices: vec![dec!(1), dec!(100), dec!(90), dec!(90), dec!(99)],
+                period: 3,
+                expected: Some(dec!(0.1)),
+            },
+            // TC3: period less than two
+            TestCase {
+                prices: vec![dec!(100), dec!(110), dec!(121)],
+                period: 1,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = realised_volatility(&VecDeque::from(test.prices), test.period);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_risk_parity_weights() {
+        let actual = risk_parity_weights(
+            [
+                (InstrumentIndex(0), dec!(0.1)),
+                (InstrumentIndex(1), dec!(0.2)),
+                (InstrumentIndex(2), dec!(0)),
+            ],
+            dec!(0.9),
+        );
+
+        let expected = vec![
+            TargetWeight::new(InstrumentIndex(0), dec!(0.6)),
+            TargetWeight::new(InstrumentIndex(1), dec!(0.3)),
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(risk_parity_weights([], dec!(1)), vec![]);
+    }
+}
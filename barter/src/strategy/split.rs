@@ -0,0 +1,397 @@
+use crate::engine::state::EngineState;
+use barter_execution::{
+    order::{
+        id::ClientOrderId,
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::Trade,
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use tracing::warn;
+
+/// Parent order being worked by an [`OrderSplitter`] via sequential child orders.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SplitParent<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    pub request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+
+    /// Maximum quantity of each child order.
+    pub quantity_max: Decimal,
+
+    /// Parent quantity that has not yet been filled.
+    pub quantity_remaining: Decimal,
+
+    /// Aggregate quantity filled across all child orders.
+    pub quantity_filled: Decimal,
+
+    /// Aggregate value (ie/ price * quantity) filled across all child orders.
+    pub value_filled: Decimal,
+
+    /// Aggregate fees paid across all child orders.
+    pub fees: Decimal,
+
+    /// [`ClientOrderId`] of the currently working child order.
+    pub child: ClientOrderId,
+
+    /// Quantity of the currently working child order that has not yet been filled.
+    pub child_quantity_remaining: Decimal,
+
+    /// Number of child orders generated so far.
+    pub sequence: u64,
+}
+
+impl<ExchangeKey, InstrumentKey> SplitParent<ExchangeKey, InstrumentKey> {
+    /// Volume weighted average fill price across all child orders, if any have been filled.
+    pub fn price_average(&self) -> Option<Decimal> {
+        (!self.quantity_filled.is_zero()).then(|| self.value_filled / self.quantity_filled)
+    }
+}
+
+/// Outcome of an [`OrderSplitter`] child order fill.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SplitUpdate<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// Child order partially filled, and is still working.
+    Working,
+
+    /// Child order fully filled, so the next child order request should be sent.
+    Next(OrderRequestOpen<ExchangeKey, InstrumentKey>),
+
+    /// Parent order fully filled, containing the aggregate fill of all child orders.
+    Completed(SplitParent<ExchangeKey, InstrumentKey>),
+}
+
+/// Engine-side splitter that works open order requests exceeding a maximum order quantity via
+/// sequential child orders.
+///
+/// The maximum order quantity is the lower of the configured per-order `cap`, and the exchange
+/// maximum order quantity (if known). Each child order is generated once the previous child has
+/// been fully filled.
+///
+/// Child order [`ClientOrderId`]s are derived from the parent (eg/ "parent-1", "parent-2"), and
+/// fills are correlated back to the parent via the [`Trade`] `cid`, aggregating the parent fill.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OrderSplitter<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// Optional per-order quantity cap applied in addition to any exchange maximum.
+    pub cap: Option<Decimal>,
+    pub parents: FnvHashMap<ClientOrderId, SplitParent<ExchangeKey, InstrumentKey>>,
+    children: FnvHashMap<ClientOrderId, ClientOrderId>,
+}
+
+impl<ExchangeKey, InstrumentKey> Default for OrderSplitter<ExchangeKey, InstrumentKey> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> OrderSplitter<ExchangeKey, InstrumentKey> {
+    /// Construct a new `OrderSplitter` with an optional per-order quantity cap.
+    pub fn new(cap: Option<Decimal>) -> Self {
+        Self {
+            cap,
+            parents: FnvHashMap::default(),
+            children: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> OrderSplitter<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Clone,
+    InstrumentKey: Clone,
+{
+    /// Register an open order request, returning the order request that should be sent to the
+    /// exchange.
+    ///
+    /// Requests exceeding the lower of the per-order `cap` and provided exchange maximum order
+    /// quantity are registered as parents, returning their first child order. All other requests
+    /// are returned unchanged.
+    pub fn open(
+        &mut self,
+        request: OrderRequestOpen<ExchangeKey, InstrumentKey>,
+        exchange_max: Option<Decimal>,
+    ) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        let quantity_max = match (self.cap, exchange_max) {
+            (Some(cap), Some(max)) => cap.min(max),
+            (Some(max), None) | (None, Some(max)) => max,
+            (None, None) => return request,
+        };
+
+        if quantity_max <= Decimal::ZERO || request.state.quantity <= quantity_max {
+            return request;
+        }
+
+        let parent_cid = request.key.cid.clone();
+        let mut parent = SplitParent {
+            quantity_remaining: request.state.quantity,
+            request,
+            quantity_max,
+            quantity_filled: Decimal::ZERO,
+            value_filled: Decimal::ZERO,
+            fees: Decimal::ZERO,
+            child: parent_cid.clone(),
+            child_quantity_remaining: Decimal::ZERO,
+            sequence: 0,
+        };
+
+        let child = Self::next_child(&mut parent);
+        self.children
+            .insert(parent.child.clone(), parent_cid.clone());
+        self.parents.insert(parent_cid, parent);
+
+        child
+    }
+
+    /// Update the `OrderSplitter` from a fill, aggregating it into the parent fill.
+    ///
+    /// Returns `None` if the fill is not for the currently working child order of a parent.
+    pub fn update_from_trade<AssetKey, TradeInstrumentKey>(
+        &mut self,
+        trade: &Trade<AssetKey, TradeInstrumentKey>,
+    ) -> Option<SplitUpdate<ExchangeKey, InstrumentKey>> {
+        let child_cid = trade.cid.as_ref()?;
+        let parent_cid = self.children.get(child_cid)?.clone();
+
+        let Some(parent) = self.parents.get_mut(&parent_cid) else {
+            self.children.remove(child_cid);
+            return None;
+        };
+
+        if &parent.child != child_cid {
+            warn!(
+                parent = %parent_cid,
+                child = %child_cid,
+                "OrderSplitter received fill for a previous child order - ignoring"
+            );
+            return None;
+        }
+
+        parent.quantity_remaining -= trade.quantity;
+        parent.child_quantity_remaining -= trade.quantity;
+        parent.quantity_filled += trade.quantity;
+        parent.value_filled += trade.price * trade.quantity;
+        parent.fees += trade.fees.fees;
+
+        if parent.child_quantity_remaining > Decimal::ZERO {
+            return Some(SplitUpdate::Working);
+        }
+
+        self.children.remove(child_cid);
+
+        if parent.quantity_remaining <= Decimal::ZERO {
+            return self.parents.remove(&parent_cid).map(SplitUpdate::Completed);
+        }
+
+        let child = Self::next_child(parent);
+        self.children.insert(child.key.cid.clone(), parent_cid);
+
+        Some(SplitUpdate::Next(child))
+    }
+
+    /// Stop working the parent order, returning the [`SplitParent`] so the currently working
+    /// child order can be cancelled.
+    pub fn cancel(
+        &mut self,
+        parent: &ClientOrderId,
+    ) -> Option<SplitParent<ExchangeKey, InstrumentKey>> {
+        let parent = self.parents.remove(parent)?;
+        self.children.remove(&parent.child);
+        Some(parent)
+    }
+
+    fn next_child(
+        parent: &mut SplitParent<ExchangeKey, InstrumentKey>,
+    ) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        parent.sequence += 1;
+        parent.child =
+            ClientOrderId::new(format!("{}-{}", parent.request.key.cid, parent.sequence));
+        parent.child_quantity_remaining = parent.quantity_max.min(parent.quantity_remaining);
+
+        let mut key = parent.request.key.clone();
+        key.cid = parent.child.clone();
+
+        OrderRequestOpen {
+            key,
+            state: RequestOpen {
+                quantity: parent.child_quantity_remaining,
+                ..parent.request.state
+            },
+        }
+    }
+}
+
+impl OrderSplitter<ExchangeIndex, InstrumentIndex> {
+    /// Register an open order request using the exchange maximum order quantity of the
+    /// instrument `InstrumentSpec` in the [`EngineState`] (if known).
+    ///
+    /// See [`OrderSplitter::open`].
+    pub fn open_with_state<GlobalData, InstrumentData>(
+        &mut self,
+        state: &EngineState<GlobalData, InstrumentData>,
+        request: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> OrderRequestOpen<ExchangeIndex, InstrumentIndex> {
+        let exchange_max = state
+            .instruments
+            .instrument_index(&request.key.instrument)
+            .instrument
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.quantity.max);
+
+        self.open(request, exchange_max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::trade;
+    use barter_execution::order::{OrderKey, OrderKind, TimeInForce, id::StrategyId};
+    use barter_instrument::{Side, asset::QuoteAsset, instrument::name::InstrumentNameInternal};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn request(quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("parent"),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn fill(cid: &str, price: f64, quantity: f64) -> Trade<QuoteAsset, InstrumentNameInternal> {
+        Trade {
+            cid: Some(ClientOrderId::new(cid)),
+            ..trade(DateTime::<Utc>::MIN_UTC, Side::Buy, price, quantity, 1.0)
+        }
+    }
+
+    #[test]
+    fn test_order_splitter_open() {
+        struct TestCase {
+            cap: Option<Decimal>,
+            exchange_max: Option<Decimal>,
+            expected_cid: &'static str,
+            expected_quantity: Decimal,
+            expected_parents: usize,
+        }
+
+        let cases = vec![
+            // TC0: no maximum order quantity, so sent unchanged
+            TestCase {
+                cap: None,
+                exchange_max: None,
+                expected_cid: "parent",
+                expected_quantity: dec!(10),
+                expected_parents: 0,
+            },
+            // TC1: split by the configured cap
+            TestCase {
+                cap: Some(dec!(4)),
+                exchange_max: None,
+                expected_cid: "parent-1",
+                expected_quantity: dec!(4),
+                expected_parents: 1,
+            },
+            // TC2: split by the lower exchange maximum
+            TestCase {
+                cap: Some(dec!(4)),
+                exchange_max: Some(dec!(3)),
+                expected_cid: "parent-1",
+                expected_quantity: dec!(3),
+                expected_parents: 1,
+            },
+            // TC3: quantity within the maximum, so sent unchanged
+            TestCase {
+                cap: None,
+                exchange_max: Some(dec!(10)),
+                expected_cid: "parent",
+                expected_quantity: dec!(10),
+                expected_parents: 0,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut splitter = OrderSplitter::new(test.cap);
+            let actual = splitter.open(request(dec!(10)), test.exchange_max);
+            assert_eq!(actual.key.cid.0, test.expected_cid, "TC{index} failed");
+            assert_eq!(
+                actual.state.quantity, test.expected_quantity,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                splitter.parents.len(),
+                test.expected_parents,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_splitter_update_from_trade() {
+        let mut splitter = OrderSplitter::new(Some(dec!(4)));
+        splitter.open(request(dec!(10)), None);
+
+        // Partial fill of first child is still working
+        assert_eq!(
+            splitter.update_from_trade(&fill("parent-1", 100.0, 1.0)),
+            Some(SplitUpdate::Working)
+        );
+
+        // Fill completing first child generates the second child
+        let Some(SplitUpdate::Next(child)) =
+            splitter.update_from_trade(&fill("parent-1", 100.0, 3.0))
+        else {
+            panic!("expected next child order");
+        };
+        assert_eq!(child.key.cid.0, "parent-2");
+        assert_eq!(child.state.quantity, dec!(4));
+
+        // Unrelated fills are ignored
+        assert_eq!(splitter.update_from_trade(&fill("other", 100.0, 4.0)), None);
+
+        // Final child only contains the parent quantity remaining
+        let Some(SplitUpdate::Next(child)) =
+            splitter.update_from_trade(&fill("parent-2", 110.0, 4.0))
+        else {
+            panic!("expected next child order");
+        };
+        assert_eq!(child.key.cid.0, "parent-3");
+        assert_eq!(child.state.quantity, dec!(2));
+
+        // Parent completed with the aggregate fill once fully filled
+        let Some(SplitUpdate::Completed(parent)) =
+            splitter.update_from_trade(&fill("parent-3", 105.0, 2.0))
+        else {
+            panic!("expected completed parent order");
+        };
+        assert_eq!(parent.quantity_filled, dec!(10));
+        assert_eq!(parent.price_average(), Some(dec!(105)));
+        assert_eq!(parent.fees, dec!(4));
+        assert!(splitter.parents.is_empty());
+    }
+
+    #[test]
+    fn test_order_splitter_cancel() {
+        let mut splitter = OrderSplitter::new(Some(dec!(4)));
+        splitter.open(request(dec!(10)), None);
+
+        let parent = splitter.cancel(&ClientOrderId::new("parent")).unwrap();
+        assert_eq!(parent.child.0, "parent-1");
+        assert_eq!(
+            splitter.update_from_trade(&fill("parent-1", 100.0, 4.0)),
+            None
+        );
+    }
+}
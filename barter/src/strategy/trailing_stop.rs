@@ -0,0 +1,52 @@
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{InstrumentState, data::InstrumentDataState, filter::InstrumentFilter},
+    },
+    strategy::close_positions::build_ioc_market_order_to_close_position,
+};
+use barter_execution::order::{
+    id::{ClientOrderId, StrategyId},
+    request::OrderRequestOpen,
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+
+/// Generate `ImmediateOrCancel` `Market` orders that close every open `Position` with a
+/// triggered [`TrailingStop`](crate::engine::state::position::TrailingStop) or
+/// [`BreakEvenStop`](crate::engine::state::position::BreakEvenStop).
+///
+/// Both stops are updated by the `EngineState` on every market price update, so this can
+/// be called from an `AlgoStrategy` to action them without re-implementing the stop logic.
+///
+/// Instruments with active orders are skipped, since they are likely already being closed,
+/// as are instruments without market data to price the exit order.
+pub fn close_triggered_trailing_stops_with_market_orders<'a, GlobalData, InstrumentData>(
+    strategy_id: &'a StrategyId,
+    state: &'a EngineState<GlobalData, InstrumentData>,
+    filter: &'a InstrumentFilter,
+    gen_cid: impl Fn(&InstrumentState<InstrumentData>) -> ClientOrderId + Copy + 'a,
+) -> impl Iterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a
+where
+    InstrumentData: InstrumentDataState,
+{
+    state
+        .instruments
+        .instruments(filter)
+        .filter_map(move |instrument_state| {
+            let position = instrument_state.position.current.as_ref()?;
+
+            if !position.is_stop_triggered() || !instrument_state.orders.0.is_empty() {
+                return None;
+            }
+
+            let price = instrument_state.data.price()?;
+
+            Some(build_ioc_market_order_to_close_position(
+                instrument_state.instrument.exchange,
+                position,
+                strategy_id.clone(),
+                price,
+                || gen_cid(instrument_state),
+            ))
+        })
+}
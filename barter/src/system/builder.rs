@@ -2,6 +2,7 @@ use crate::{
     engine::{
         Engine, Processor,
         audit::{Auditor, context::EngineContext, shutdown::ShutdownAudit},
+        batch::MarketBatchPolicy,
         clock::EngineClock,
         execution_tx::MultiExchangeTxMap,
         run::{async_run, async_run_with_audit, sync_run, sync_run_with_audit},
@@ -13,19 +14,20 @@ use crate::{
         builder::{ExecutionBuildFutures, ExecutionBuilder},
     },
     shutdown::SyncShutdown,
-    system::{System, SystemAuxillaryHandles, config::ExecutionConfig},
+    system::{System, SystemAuxillaryHandles, config::ExecutionConfig, paper::PaperTrading},
 };
-use barter_data::streams::reconnect::stream::ReconnectingStream;
 use barter_execution::balance::Balance;
 use barter_instrument::{
     Keyed,
     asset::{ExchangeAsset, name::AssetNameInternal},
     index::IndexedInstruments,
 };
-use barter_integration::channel::{Channel, ChannelTxDroppable, mpsc_unbounded};
+use barter_integration::channel::{
+    BoundedConfig, BoundedTx, Channel, ChannelTxDroppable, Droppable, mpsc_bounded, mpsc_unbounded,
+};
 use derive_more::Constructor;
 use fnv::FnvHashMap;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -96,8 +98,11 @@ pub struct SystemBuilder<'a, Clock, Strategy, Risk, MarketStream, GlobalData, Fn
     args: SystemArgs<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>,
     engine_feed_mode: Option<EngineFeedMode>,
     audit_mode: Option<AuditMode>,
+    market_batch_policy: Option<MarketBatchPolicy>,
+    feed_channel: Option<BoundedConfig>,
     trading_state: Option<TradingState>,
     balances: FnvHashMap<ExchangeAsset<AssetNameInternal>, Balance>,
+    paper_trading: Option<PaperTrading>,
 }
 
 impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
@@ -113,8 +118,11 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             args: config,
             engine_feed_mode: None,
             audit_mode: None,
+            market_batch_policy: None,
+            feed_channel: None,
             trading_state: None,
             balances: FnvHashMap::default(),
+            paper_trading: None,
         }
     }
 
@@ -138,6 +146,28 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         }
     }
 
+    /// Optionally configure the [`MarketBatchPolicy`] (disabled or max events).
+    ///
+    /// Controls how many market events the engine processes before generating algo orders.
+    pub fn market_batch_policy(self, value: MarketBatchPolicy) -> Self {
+        Self {
+            market_batch_policy: Some(value),
+            ..self
+        }
+    }
+
+    /// Optionally configure the bounded `Engine` feed channel [`BoundedConfig`] (capacity and
+    /// `OverflowPolicy`).
+    ///
+    /// Bounds the memory used to buffer market and account events when the `Engine` falls behind
+    /// (eg/ a slow `Strategy` during a market data burst).
+    pub fn feed_channel(self, value: BoundedConfig) -> Self {
+        Self {
+            feed_channel: Some(value),
+            ..self
+        }
+    }
+
     /// Optionally configure the initial [`TradingState`] (enabled or disabled).
     ///
     /// Sets whether algorithmic trading is initially enabled when the system starts.
@@ -168,6 +198,17 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         self
     }
 
+    /// Optionally configure [`PaperTrading`], filling mock execution market orders at the latest
+    /// live quote prices.
+    ///
+    /// Note the live market `Stream` must be tapped via [`PaperTrading::market_stream`].
+    pub fn paper_trading(self, value: PaperTrading) -> Self {
+        Self {
+            paper_trading: Some(value),
+            ..self
+        }
+    }
+
     /// Build the [`SystemBuild`] with the configured builder settings.
     ///
     /// This constructs all the system components but does not start any tasks or streams.
@@ -207,26 +248,33 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
                 },
             engine_feed_mode,
             audit_mode,
+            market_batch_policy,
+            feed_channel,
             trading_state,
             balances,
+            paper_trading,
         } = self;
 
         // Default if not provided
         let engine_feed_mode = engine_feed_mode.unwrap_or_default();
         let audit_mode = audit_mode.unwrap_or_default();
+        let market_batch_policy = market_batch_policy.unwrap_or_default();
+        let feed_channel = feed_channel.unwrap_or_default();
         let trading_state = trading_state.unwrap_or_default();
 
         // Build Execution infrastructure
+        let execution_builder = match paper_trading {
+            Some(paper_trading) => {
+                ExecutionBuilder::new(instruments).price_feed(paper_trading.price_feed)
+            }
+            None => ExecutionBuilder::new(instruments),
+        };
+
         let execution = executions
             .into_iter()
-            .try_fold(
-                ExecutionBuilder::new(instruments),
-                |builder, config| match config {
-                    ExecutionConfig::Mock(mock_config) => {
-                        builder.add_mock(mock_config, clock.clone())
-                    }
-                },
-            )?
+            .try_fold(execution_builder, |builder, config| match config {
+                ExecutionConfig::Mock(mock_config) => builder.add_mock(mock_config, clock.clone()),
+            })?
             .build();
 
         // Build EngineState
@@ -241,12 +289,14 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             .build();
 
         // Construct Engine
-        let engine = Engine::new(clock, state, execution.execution_tx_map, strategy, risk);
+        let engine = Engine::new(clock, state, execution.execution_tx_map, strategy, risk)
+            .with_market_batch_policy(market_batch_policy);
 
         Ok(SystemBuild {
             engine,
             engine_feed_mode,
             audit_mode,
+            feed_channel,
             market_stream,
             account_channel: execution.account_channel,
             execution_build_futures: execution.futures,
@@ -269,6 +319,9 @@ pub struct SystemBuild<Engine, Event, MarketStream> {
     /// Selected [`AuditMode`].
     pub audit_mode: AuditMode,
 
+    /// Selected `Engine` feed channel [`BoundedConfig`].
+    pub feed_channel: BoundedConfig,
+
     /// `Stream` of `MarketStreamEvent`s.
     pub market_stream: MarketStream,
 
@@ -295,7 +348,13 @@ where
         + Send
         + 'static,
     Engine::Output: Debug + Clone + Send + 'static,
-    Event: From<MarketStream::Item> + From<AccountStreamEvent> + Debug + Clone + Send + 'static,
+    Event: From<MarketStream::Item>
+        + From<AccountStreamEvent>
+        + Droppable
+        + Debug
+        + Clone
+        + Send
+        + 'static,
     MarketStream: Stream + Send + 'static,
     Option<ShutdownAudit<Event, Engine::Output>>: for<'a> From<&'a Engine::Audit>,
 {
@@ -304,6 +363,7 @@ where
         engine: Engine,
         engine_feed_mode: EngineFeedMode,
         audit_mode: AuditMode,
+        feed_channel: BoundedConfig,
         market_stream: MarketStream,
         account_channel: Channel<AccountStreamEvent>,
         execution_build_futures: ExecutionBuildFutures,
@@ -312,6 +372,7 @@ where
             engine,
             engine_feed_mode,
             audit_mode,
+            feed_channel,
             market_stream,
             account_channel,
             execution_build_futures,
@@ -344,6 +405,7 @@ where
             mut engine,
             engine_feed_mode,
             audit_mode,
+            feed_channel,
             market_stream,
             account_channel,
             execution_build_futures,
@@ -355,17 +417,17 @@ where
             .init_with_runtime(runtime.clone())
             .await?;
 
-        // Initialise central Engine channel
-        let (feed_tx, mut feed_rx) = mpsc_unbounded();
+        // Initialise central bounded Engine channel
+        let (feed_tx, mut feed_rx) = mpsc_bounded(feed_channel);
 
         // Forward MarketStreamEvents to Engine feed
         let market_to_engine = runtime
             .clone()
-            .spawn(market_stream.forward_to(feed_tx.clone()));
+            .spawn(forward_to_feed(market_stream, feed_tx.clone()));
 
         // Forward AccountStreamEvents to Engine feed
         let account_stream = account_channel.rx.into_stream();
-        let account_to_engine = runtime.spawn(account_stream.forward_to(feed_tx.clone()));
+        let account_to_engine = runtime.spawn(forward_to_feed(account_stream, feed_tx.clone()));
 
         // Run Engine in configured mode
         let (engine, audit_rx) = match (engine_feed_mode, audit_mode) {
@@ -420,9 +482,25 @@ where
                 execution,
                 market_to_engine,
                 account_to_engine,
+                market_subscriptions: FnvHashMap::default(),
             },
             feed_tx,
             audit_rx,
         })
     }
 }
+
+/// Forward a `Stream` to the bounded `Engine` feed, waiting for capacity when the feed is full
+/// (after applying the configured `OverflowPolicy`).
+///
+/// Ends when the `Stream` ends, or the `Engine` feed receiver is dropped.
+pub(super) async fn forward_to_feed<St, Event>(stream: St, feed_tx: BoundedTx<Event>)
+where
+    St: Stream,
+    Event: From<St::Item> + Droppable,
+{
+    let _ = stream
+        .map(|item| Ok(Event::from(item)))
+        .forward(feed_tx)
+        .await;
+}
@@ -115,6 +115,7 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
                     },
                     min: spec.quantity.min,
                     increment: spec.quantity.increment,
+                    max: spec.quantity.max,
                 },
                 notional: spec.notional,
             }),
@@ -8,17 +8,29 @@ use crate::{
     engine::{
         Processor,
         audit::{AuditTick, Auditor, context::EngineContext, shutdown::ShutdownAudit},
-        command::Command,
+        command::{Command, StrategyUpdate},
         state::{instrument::filter::InstrumentFilter, trading::TradingState},
     },
     execution::builder::ExecutionHandles,
     shutdown::{AsyncShutdown, Shutdown},
+    system::builder::forward_to_feed,
+};
+use barter_execution::{
+    credentials::CredentialsUpdate,
+    order::request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::{
+    Keyed,
+    asset::AssetIndex,
+    exchange::ExchangeIndex,
+    instrument::{Instrument, InstrumentIndex},
 };
-use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
 use barter_integration::{
-    channel::{Tx, UnboundedRx, UnboundedTx},
+    channel::{BoundedTx, Droppable, Tx, UnboundedRx},
     collection::one_or_many::OneOrMany,
 };
+use fnv::FnvHashMap;
+use futures::Stream;
 use std::fmt::Debug;
 use tokio::task::{JoinError, JoinHandle};
 
@@ -28,6 +40,13 @@ pub mod builder;
 /// Provides a convenient `SystemConfig` used for defining a Barter trading system.
 pub mod config;
 
+/// Paper trading support, running a system with live market data and simulated execution.
+pub mod paper;
+
+/// Provides a `Supervisor` that launches, monitors, restarts and stops multiple independent
+/// `Engine` systems (bots) within one process.
+pub mod supervisor;
+
 /// Initialised and running Barter trading system.
 ///
 /// Contains handles for the `Engine` and all auxillary system tasks.
@@ -46,8 +65,8 @@ where
     /// Handles to auxiliary system components (execution components, event forwarding, etc.).
     pub handles: SystemAuxillaryHandles,
 
-    /// Transmitter for sending events to the `Engine`.
-    pub feed_tx: UnboundedTx<Event>,
+    /// Bounded transmitter for sending events to the `Engine`.
+    pub feed_tx: BoundedTx<Event>,
 
     /// Optional receiver for engine audit events (present when audit sending is enabled).
     pub audit_rx: Option<UnboundedRx<AuditTick<Engine::Audit, EngineContext>>>,
@@ -57,7 +76,7 @@ impl<Engine, Event> System<Engine, Event>
 where
     Engine: Processor<Event> + Auditor<Engine::Audit, Context = EngineContext>,
     Engine::Audit: From<Engine::Snapshot>,
-    Event: Debug + Clone + Send,
+    Event: Droppable + Debug + Clone + Send,
 {
     /// Shutdown the `System` gracefully.
     pub async fn shutdown(
@@ -107,6 +126,7 @@ where
                     mut execution,
                     market_to_engine,
                     account_to_engine,
+                    market_subscriptions,
                 },
             feed_tx,
             audit_rx: _,
@@ -123,6 +143,9 @@ where
         let (engine, shutdown_audit) = engine.await?;
 
         account_to_engine.abort();
+        market_subscriptions
+            .into_values()
+            .for_each(|handle| handle.abort());
         execution.shutdown().await?;
 
         Ok((engine, shutdown_audit))
@@ -164,6 +187,59 @@ where
         self.send(Command::CancelOrders(filter))
     }
 
+    /// Instruct the `Engine` to update its strategy parameters at runtime.
+    pub fn update_strategy(&self, update: StrategyUpdate)
+    where
+        Event: From<Command>,
+    {
+        self.send(Command::UpdateStrategy(update))
+    }
+
+    /// Instruct the `Engine` to update its exchange credentials at runtime (eg/ key rotation).
+    pub fn update_credentials(&self, update: CredentialsUpdate)
+    where
+        Event: From<Command>,
+    {
+        self.send(Command::UpdateCredentials(update))
+    }
+
+    /// Instruct the `Engine` to add a new instrument at runtime, and forward the provided
+    /// `Stream` of instrument market events to the `Engine`.
+    ///
+    /// The instrument must be keyed with the next `InstrumentIndex` (ie/ the number of
+    /// instruments the `Engine` is tracking), and traded on an exchange the `System` was built
+    /// with.
+    pub fn add_instrument<MarketStream>(
+        &mut self,
+        instrument: Keyed<InstrumentIndex, Instrument<ExchangeIndex, AssetIndex>>,
+        market_stream: MarketStream,
+    ) where
+        MarketStream: Stream + Send + 'static,
+        Event: From<Command> + From<MarketStream::Item> + Send + 'static,
+    {
+        let key = instrument.key;
+        self.send(Command::AddInstrument(Box::new(instrument)));
+
+        let subscription = tokio::spawn(forward_to_feed(market_stream, self.feed_tx.clone()));
+        if let Some(previous) = self.handles.market_subscriptions.insert(key, subscription) {
+            previous.abort();
+        }
+    }
+
+    /// Instruct the `Engine` to gracefully wind down an instrument (ie/ cancel open orders &
+    /// close any open position), and unsubscribe any instrument market data forwarded since it
+    /// was added via [`System::add_instrument`].
+    pub fn remove_instrument(&mut self, instrument: InstrumentIndex)
+    where
+        Event: From<Command>,
+    {
+        self.send(Command::RemoveInstrument(instrument));
+
+        if let Some(subscription) = self.handles.market_subscriptions.remove(&instrument) {
+            subscription.abort();
+        }
+    }
+
     /// Update the algorithmic `TradingState` of the `Engine`.
     pub fn trading_state(&self, trading_state: TradingState)
     where
@@ -206,6 +282,9 @@ pub struct SystemAuxillaryHandles {
 
     /// Task that forwards account events to the engine.
     pub account_to_engine: JoinHandle<()>,
+
+    /// Tasks that forward the market events of instruments added at runtime to the engine.
+    pub market_subscriptions: FnvHashMap<InstrumentIndex, JoinHandle<()>>,
 }
 
 impl AsyncShutdown for SystemAuxillaryHandles {
@@ -215,6 +294,9 @@ impl AsyncShutdown for SystemAuxillaryHandles {
         // Event -> Engine tasks do not need graceful shutdown, so abort
         self.market_to_engine.abort();
         self.account_to_engine.abort();
+        self.market_subscriptions
+            .drain()
+            .for_each(|(_, handle)| handle.abort());
 
         // Await execution components shutdowns concurrently
         self.execution.shutdown().await
@@ -227,6 +309,7 @@ impl SystemAuxillaryHandles {
             .into_iter()
             .chain(std::iter::once(self.market_to_engine))
             .chain(std::iter::once(self.account_to_engine))
+            .chain(self.market_subscriptions.into_values())
             .for_each(|handle| handle.abort());
     }
 }
@@ -0,0 +1,159 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    streams::consumer::MarketStreamEvent,
+};
+use barter_execution::exchange::mock::price::{MockPriceFeed, MockQuote};
+use barter_instrument::{
+    exchange::ExchangeId,
+    index::IndexedInstruments,
+    instrument::{InstrumentIndex, name::InstrumentNameExchange},
+};
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt};
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+use std::sync::Arc;
+
+/// Paper trading support, validating strategies against live market data with simulated
+/// execution before capital is at risk.
+///
+/// Paper trading runs a system with live market data and mock
+/// [`ExecutionConfig`](super::config::ExecutionConfig)s, where each
+/// [`MockExchange`](barter_execution::exchange::mock::MockExchange) fills market orders at the
+/// latest live quote (ie/ crossing the spread), with the configured latency, fees and slippage.
+///
+/// To paper trade:
+/// 1. Construct `PaperTrading` from the system [`IndexedInstruments`].
+/// 2. Tap the live market `Stream` with [`PaperTrading::market_stream`].
+/// 3. Configure the [`SystemBuilder`](super::builder::SystemBuilder) with
+///    [`SystemBuilder::paper_trading`](super::builder::SystemBuilder::paper_trading).
+#[derive(Debug, Clone)]
+pub struct PaperTrading {
+    pub price_feed: MockPriceFeed,
+    instruments: Arc<FnvHashMap<InstrumentIndex, (ExchangeId, InstrumentNameExchange)>>,
+}
+
+impl PaperTrading {
+    /// Construct a new `PaperTrading` for the provided [`IndexedInstruments`].
+    pub fn new(instruments: &IndexedInstruments) -> Self {
+        let instruments = instruments
+            .instruments()
+            .iter()
+            .map(|keyed| {
+                (
+                    keyed.key,
+                    (
+                        keyed.value.exchange.value,
+                        keyed.value.name_exchange.clone(),
+                    ),
+                )
+            })
+            .collect();
+
+        Self {
+            price_feed: MockPriceFeed::default(),
+            instruments: Arc::new(instruments),
+        }
+    }
+
+    /// Tap the provided live market `Stream`, updating the [`MockPriceFeed`] from each market
+    /// event before it is forwarded to the `Engine`.
+    pub fn market_stream<St>(&self, stream: St) -> impl Stream<Item = St::Item> + use<St>
+    where
+        St: Stream<Item = MarketStreamEvent<InstrumentIndex, DataKind>>,
+    {
+        let paper = self.clone();
+        stream.inspect(move |event| {
+            if let MarketStreamEvent::Item(event) = event {
+                paper.update_from_market(event);
+            }
+        })
+    }
+
+    /// Update the [`MockPriceFeed`] from the provided [`MarketEvent`].
+    pub fn update_from_market(&self, event: &MarketEvent<InstrumentIndex, DataKind>) {
+        let Some(quote) = quote_from_market(&event.kind) else {
+            return;
+        };
+
+        if let Some((exchange, name_exchange)) = self.instruments.get(&event.instrument) {
+            self.price_feed
+                .update(*exchange, name_exchange.clone(), quote);
+        }
+    }
+}
+
+/// Derive the latest [`MockQuote`] from a market event, if it contains price information.
+///
+/// An `OrderBookL1` provides the best bid & ask, whereas a trade or candle close price is used as
+/// both the bid & ask.
+pub fn quote_from_market(kind: &DataKind) -> Option<MockQuote> {
+    let price = match kind {
+        DataKind::OrderBookL1(l1) => {
+            return match (&l1.best_bid, &l1.best_ask) {
+                (Some(bid), Some(ask)) => Some(MockQuote::new(bid.price, ask.price)),
+                _ => None,
+            };
+        }
+        DataKind::Trade(trade) => Decimal::from_f64(trade.price)?,
+        DataKind::Candle(candle) => Decimal::from_f64(candle.close)?,
+        _ => return None,
+    };
+
+    Some(MockQuote::new(price, price))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::{
+        books::Level,
+        subscription::{book::OrderBookL1, trade::PublicTrade},
+    };
+    use barter_instrument::Side;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_quote_from_market() {
+        struct TestCase {
+            kind: DataKind,
+            expected: Option<MockQuote>,
+        }
+
+        let cases = vec![
+            // TC0: OrderBookL1 with best bid & ask
+            TestCase {
+                kind: DataKind::OrderBookL1(OrderBookL1 {
+                    last_update_time: DateTime::<Utc>::MIN_UTC,
+                    best_bid: Some(Level::new(dec!(99), dec!(1))),
+                    best_ask: Some(Level::new(dec!(101), dec!(1))),
+                }),
+                expected: Some(MockQuote::new(dec!(99), dec!(101))),
+            },
+            // TC1: OrderBookL1 missing best ask
+            TestCase {
+                kind: DataKind::OrderBookL1(OrderBookL1 {
+                    last_update_time: DateTime::<Utc>::MIN_UTC,
+                    best_bid: Some(Level::new(dec!(99), dec!(1))),
+                    best_ask: None,
+                }),
+                expected: None,
+            },
+            // TC2: trade price used as both bid & ask
+            TestCase {
+                kind: DataKind::Trade(PublicTrade {
+                    id: "1".to_string(),
+                    price: 100.0,
+                    amount: 1.0,
+                    side: Side::Buy,
+                }),
+                expected: Some(MockQuote::new(dec!(100), dec!(100))),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = quote_from_market(&test.kind);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,605 @@
+use crate::{
+    engine::{
+        EngineOutput, Processor,
+        audit::{
+            AuditTick, Auditor, EngineAudit, ProcessAudit, context::EngineContext,
+            shutdown::ShutdownAudit,
+        },
+        health::{EngineHealth, HealthConfig},
+    },
+    error::BarterError,
+    shutdown::{AsyncShutdown, Shutdown},
+    system::System,
+};
+use barter_integration::{
+    channel::{Droppable, UnboundedRx},
+    collection::FnvIndexMap,
+};
+use derive_more::{Display, From};
+use futures::future::BoxFuture;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::{fmt::Debug, future::Future};
+use thiserror::Error;
+use tracing::{error, info, warn};
+
+/// Unique identifier of a bot (ie/ an independent `Engine` [`System`]) run by a [`Supervisor`].
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display, From,
+)]
+pub struct BotId(pub SmolStr);
+
+impl BotId {
+    pub fn new<S: AsRef<str>>(id: S) -> Self {
+        Self(SmolStr::new(id))
+    }
+}
+
+/// All errors generated by a [`Supervisor`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum SupervisorError {
+    #[error("Supervisor bot already running: {0}")]
+    BotRunning(BotId),
+
+    #[error("Supervisor bot not found: {0}")]
+    BotNotFound(BotId),
+
+    #[error("Supervisor failed to launch bot {0}: {1}")]
+    Launch(BotId, BarterError),
+
+    #[error("Supervisor failed to stop bot {0}: {1}")]
+    Stop(BotId, String),
+}
+
+/// Policy that determines if a [`Supervisor`] restarts a bot whose `Engine` failed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum RestartPolicy {
+    /// Never restart a failed bot.
+    Never,
+
+    /// Restart a failed bot, up to a maximum number of restarts.
+    OnFailure { max_restarts: u32 },
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self::OnFailure { max_restarts: 3 }
+    }
+}
+
+impl RestartPolicy {
+    /// Returns true if a failed bot that has already been restarted the provided number of
+    /// times should be restarted.
+    pub fn should_restart(&self, restarts: u32) -> bool {
+        match self {
+            Self::Never => false,
+            Self::OnFailure { max_restarts } => restarts < *max_restarts,
+        }
+    }
+}
+
+/// Lifecycle state of a bot run by a [`Supervisor`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum BotState {
+    Running,
+
+    /// Bot was stopped, or its `Engine` shutdown without error (eg/ the feed ended).
+    Stopped,
+
+    /// Bot `Engine` failed, and the [`RestartPolicy`] does not allow it to be restarted.
+    Failed(String),
+}
+
+/// Status of a bot run by a [`Supervisor`], including the latest health and trading statistics
+/// extracted from its `Engine` audit stream.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BotStatus {
+    pub id: BotId,
+    pub state: BotState,
+
+    /// Number of times the bot has been restarted after failing.
+    pub restarts: u32,
+
+    /// Latest [`EngineHealth`] heartbeat, if the bot `Engine` has a
+    /// [`HealthMonitor`](crate::engine::health::HealthMonitor) configured.
+    pub health: Option<EngineHealth>,
+
+    /// Number of positions exited since the bot was first launched.
+    pub positions_exited: u64,
+
+    /// Realised PnL of all exited positions since the bot was first launched.
+    pub pnl_realised: Decimal,
+}
+
+impl BotStatus {
+    fn new(id: BotId) -> Self {
+        Self {
+            id,
+            state: BotState::Running,
+            restarts: 0,
+            health: None,
+            positions_exited: 0,
+            pnl_realised: Decimal::ZERO,
+        }
+    }
+}
+
+/// Aggregate status of all bots run by a [`Supervisor`].
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct SupervisorStatus {
+    pub bots: Vec<BotStatus>,
+}
+
+impl SupervisorStatus {
+    /// Number of bots currently running.
+    pub fn running(&self) -> usize {
+        self.bots
+            .iter()
+            .filter(|bot| bot.state == BotState::Running)
+            .count()
+    }
+
+    /// Total realised PnL of all bots.
+    pub fn pnl_realised(&self) -> Decimal {
+        self.bots.iter().map(|bot| bot.pnl_realised).sum()
+    }
+
+    /// Returns true if no bot has failed, and the latest [`EngineHealth`] of every running bot
+    /// (if available) is healthy according to the provided [`HealthConfig`].
+    pub fn is_healthy(&self, config: &HealthConfig) -> bool {
+        self.bots.iter().all(|bot| match &bot.state {
+            BotState::Running => bot
+                .health
+                .as_ref()
+                .is_none_or(|health| health.is_healthy(config)),
+            BotState::Stopped => true,
+            BotState::Failed(_) => false,
+        })
+    }
+}
+
+/// `Engine` audit that a [`Supervisor`] extracts bot health and trading statistics from.
+pub trait SupervisedAudit {
+    fn update_status(&self, status: &mut BotStatus);
+}
+
+impl<State, Event, OnDisable, OnDisconnect> SupervisedAudit
+    for EngineAudit<State, Event, EngineOutput<OnDisable, OnDisconnect>>
+{
+    fn update_status(&self, status: &mut BotStatus) {
+        let outputs = match self {
+            EngineAudit::Process(ProcessAudit::ProcessWithOutput(_, outputs))
+            | EngineAudit::Shutdown(ShutdownAudit::ErrorWithProcess(
+                ProcessAudit::ProcessWithOutput(_, outputs),
+                _,
+            )) => outputs,
+            _ => return,
+        };
+
+        for output in outputs.iter() {
+            match output {
+                EngineOutput::Health(health) => status.health = Some(health.clone()),
+                EngineOutput::PositionExit(position) => {
+                    status.positions_exited += 1;
+                    status.pnl_realised += position.pnl_realised;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+type BotLauncher<Engine, Event> =
+    Box<dyn FnMut() -> BoxFuture<'static, Result<System<Engine, Event>, BarterError>> + Send>;
+
+#[allow(missing_debug_implementations)]
+struct SupervisedBot<Engine, Event>
+where
+    Engine: Processor<Event> + Auditor<Engine::Audit, Context = EngineContext>,
+    Engine::Audit: From<Engine::Snapshot>,
+{
+    launcher: BotLauncher<Engine, Event>,
+    system: Option<System<Engine, Event>>,
+    audit_rx: Option<UnboundedRx<AuditTick<Engine::Audit, EngineContext>>>,
+    status: BotStatus,
+}
+
+/// Launches, monitors, restarts and stops multiple independent `Engine` [`System`]s (eg/ bots
+/// running different strategies or markets) within one process.
+///
+/// Each bot is launched from a closure that initialises its `System`, so a failed bot can be
+/// relaunched according to the [`RestartPolicy`]. If a bot `System` is built with audit
+/// sending enabled, the `Supervisor` takes its audit channel to extract the latest bot health
+/// and trading statistics (see [`SupervisorStatus`]).
+///
+/// Note that all bots share the same `Engine` and `Event` types, so bots running different
+/// strategies must use a common strategy type (eg/ an enum of strategies).
+///
+/// [`Supervisor::monitor`] must be called periodically (eg/ on a timer) to detect and restart
+/// failed bots.
+#[allow(missing_debug_implementations)]
+pub struct Supervisor<Engine, Event>
+where
+    Engine: Processor<Event> + Auditor<Engine::Audit, Context = EngineContext>,
+    Engine::Audit: From<Engine::Snapshot>,
+{
+    pub policy: RestartPolicy,
+    bots: FnvIndexMap<BotId, SupervisedBot<Engine, Event>>,
+}
+
+impl<Engine, Event> Supervisor<Engine, Event>
+where
+    Engine: Processor<Event> + Auditor<Engine::Audit, Context = EngineContext>,
+    Engine::Audit: From<Engine::Snapshot> + SupervisedAudit,
+    Event: From<Shutdown> + Droppable + Debug + Clone + Send,
+{
+    /// Construct a new `Supervisor` with the provided [`RestartPolicy`].
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self {
+            policy,
+            bots: FnvIndexMap::default(),
+        }
+    }
+
+    /// Launch a new bot, initialising its [`System`] with the provided closure.
+    ///
+    /// A bot that has previously stopped or failed with the same [`BotId`] is replaced.
+    pub async fn launch<Launch, Fut>(
+        &mut self,
+        id: BotId,
+        mut launch: Launch,
+    ) -> Result<(), SupervisorError>
+    where
+        Launch: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<System<Engine, Event>, BarterError>> + Send + 'static,
+    {
+        if self
+            .bots
+            .get(&id)
+            .is_some_and(|bot| bot.status.state == BotState::Running)
+        {
+            return Err(SupervisorError::BotRunning(id));
+        }
+
+        let mut launcher: BotLauncher<Engine, Event> = Box::new(move || Box::pin(launch()));
+        let mut system = launcher()
+            .await
+            .map_err(|error| SupervisorError::Launch(id.clone(), error))?;
+
+        info!(bot = %id, "Supervisor launched bot");
+        self.bots.insert(
+            id.clone(),
+            SupervisedBot {
+                launcher,
+                audit_rx: system.take_audit_rx(),
+                system: Some(system),
+                status: BotStatus::new(id),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Monitor all bots, extracting the latest health and statistics from their audit streams,
+    /// and restarting any failed bots according to the [`RestartPolicy`].
+    ///
+    /// Returns the latest [`SupervisorStatus`].
+    pub async fn monitor(&mut self) -> SupervisorStatus {
+        for bot in self.bots.values_mut() {
+            drain_audits(bot);
+
+            if !bot
+                .system
+                .as_ref()
+                .is_some_and(|system| system.engine.is_finished())
+            {
+                continue;
+            }
+
+            let Some(system) = bot.system.take() else {
+                continue;
+            };
+
+            let failure = match shutdown_finished(system).await {
+                Ok(()) => {
+                    info!(bot = %bot.status.id, "Supervisor bot Engine shutdown");
+                    bot.status.state = BotState::Stopped;
+                    bot.audit_rx = None;
+                    continue;
+                }
+                Err(failure) => failure,
+            };
+
+            if !self.policy.should_restart(bot.status.restarts) {
+                error!(bot = %bot.status.id, %failure, "Supervisor bot failed");
+                bot.status.state = BotState::Failed(failure);
+                bot.audit_rx = None;
+                continue;
+            }
+
+            warn!(bot = %bot.status.id, %failure, "Supervisor restarting failed bot");
+            bot.status.restarts += 1;
+
+            match (bot.launcher)().await {
+                Ok(mut system) => {
+                    bot.audit_rx = system.take_audit_rx();
+                    bot.system = Some(system);
+                    bot.status.health = None;
+                }
+                Err(error) => {
+                    error!(bot = %bot.status.id, ?error, "Supervisor failed to restart bot");
+                    bot.status.state = BotState::Failed(error.to_string());
+                    bot.audit_rx = None;
+                }
+            }
+        }
+
+        self.status()
+    }
+
+    /// Gracefully stop the bot with the provided [`BotId`].
+    pub async fn stop(&mut self, id: &BotId) -> Result<(), SupervisorError> {
+        let bot = self
+            .bots
+            .get_mut(id)
+            .ok_or_else(|| SupervisorError::BotNotFound(id.clone()))?;
+
+        drain_audits(bot);
+        bot.status.state = BotState::Stopped;
+        bot.audit_rx = None;
+
+        let Some(system) = bot.system.take() else {
+            return Ok(());
+        };
+
+        info!(bot = %id, "Supervisor stopping bot");
+        system
+            .shutdown()
+            .await
+            .map(|_| ())
+            .map_err(|error| SupervisorError::Stop(id.clone(), error.to_string()))
+    }
+
+    /// Gracefully stop all bots, returning the errors of any that failed to stop.
+    pub async fn stop_all(&mut self) -> Vec<SupervisorError> {
+        let ids = self.bots.keys().cloned().collect::<Vec<_>>();
+
+        let mut errors = Vec::new();
+        for id in ids {
+            if let Err(error) = self.stop(&id).await {
+                errors.push(error);
+            }
+        }
+        errors
+    }
+
+    /// Returns the running [`System`] of the bot with the provided [`BotId`], which can be used
+    /// to send it `Engine` commands.
+    pub fn system(&self, id: &BotId) -> Option<&System<Engine, Event>> {
+        self.bots.get(id).and_then(|bot| bot.system.as_ref())
+    }
+
+    /// Returns the current [`SupervisorStatus`] of all bots.
+    pub fn status(&self) -> SupervisorStatus {
+        SupervisorStatus {
+            bots: self.bots.values().map(|bot| bot.status.clone()).collect(),
+        }
+    }
+}
+
+fn drain_audits<Engine, Event>(bot: &mut SupervisedBot<Engine, Event>)
+where
+    Engine: Processor<Event> + Auditor<Engine::Audit, Context = EngineContext>,
+    Engine::Audit: From<Engine::Snapshot> + SupervisedAudit,
+{
+    let Some(audit_rx) = bot.audit_rx.as_mut() else {
+        return;
+    };
+
+    while let Ok(audit) = audit_rx.rx.try_recv() {
+        audit.event.update_status(&mut bot.status);
+    }
+}
+
+/// Await the finished `Engine` of a [`System`] and shutdown its auxiliary tasks, returning the
+/// failure reason if the `Engine` did not shutdown cleanly.
+async fn shutdown_finished<Engine, Event>(system: System<Engine, Event>) -> Result<(), String>
+where
+    Engine: Processor<Event> + Auditor<Engine::Audit, Context = EngineContext>,
+    Engine::Audit: From<Engine::Snapshot>,
+{
+    let System {
+        engine,
+        mut handles,
+        ..
+    } = system;
+
+    let result = match engine.await {
+        Ok((_, ShutdownAudit::Error(_, errors) | ShutdownAudit::ErrorWithProcess(_, errors))) => {
+            Err(format!("Engine unrecoverable error: {errors:?}"))
+        }
+        Ok(_) => Ok(()),
+        Err(error) => Err(format!("Engine task failed: {error}")),
+    };
+
+    if let Err(error) = handles.shutdown().await {
+        warn!(?error, "Supervisor failed to shutdown bot auxiliary tasks");
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        EngineEvent,
+        engine::{
+            command::Command,
+            health::MarketEventTimes,
+            state::{instrument::filter::InstrumentFilter, position::PositionExited},
+        },
+    };
+    use barter_data::event::DataKind;
+    use barter_execution::trade::AssetFees;
+    use barter_instrument::{Side, asset::QuoteAsset, instrument::InstrumentIndex};
+    use barter_integration::collection::one_or_many::OneOrMany;
+    use chrono::{DateTime, TimeDelta, Utc};
+    use rust_decimal_macros::dec;
+
+    type TestAudit = EngineAudit<(), EngineEvent<DataKind>, EngineOutput<(), ()>>;
+
+    fn position_exit(pnl_realised: Decimal) -> EngineOutput<(), ()> {
+        let time = DateTime::<Utc>::MIN_UTC;
+        EngineOutput::PositionExit(PositionExited {
+            instrument: InstrumentIndex(0),
+            side: Side::Buy,
+            price_entry_average: dec!(100),
+            quantity_abs_max: dec!(1),
+            pnl_realised,
+            excursion_adverse_max: dec!(0),
+            excursion_favourable_max: dec!(0),
+            fees_enter: AssetFees::new(QuoteAsset, dec!(0)),
+            fees_exit: AssetFees::new(QuoteAsset, dec!(0)),
+            time_enter: time,
+            time_exit: time,
+            trades: vec![],
+            signal: None,
+        })
+    }
+
+    fn health(feed_lag_secs: i64) -> EngineHealth {
+        let time = DateTime::<Utc>::MIN_UTC + TimeDelta::seconds(60);
+        EngineHealth {
+            time,
+            market_last: Some(MarketEventTimes {
+                time_exchange: time - TimeDelta::seconds(feed_lag_secs),
+                time_processed: time,
+            }),
+            orders_open: 0,
+            exchanges: vec![],
+        }
+    }
+
+    #[test]
+    fn test_restart_policy_should_restart() {
+        struct TestCase {
+            policy: RestartPolicy,
+            restarts: u32,
+            expected: bool,
+        }
+
+        let cases = vec![
+            // TC0: never restart
+            TestCase {
+                policy: RestartPolicy::Never,
+                restarts: 0,
+                expected: false,
+            },
+            // TC1: restart below the maximum restarts
+            TestCase {
+                policy: RestartPolicy::OnFailure { max_restarts: 2 },
+                restarts: 1,
+                expected: true,
+            },
+            // TC2: do not restart once the maximum restarts is reached
+            TestCase {
+                policy: RestartPolicy::OnFailure { max_restarts: 2 },
+                restarts: 2,
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.policy.should_restart(test.restarts);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_supervised_audit_update_status() {
+        let event = EngineEvent::<DataKind>::Command(Command::CancelOrders(InstrumentFilter::None));
+
+        let audits: Vec<TestAudit> = vec![
+            EngineAudit::Snapshot(()),
+            EngineAudit::Process(ProcessAudit::ProcessWithOutput(
+                event.clone(),
+                OneOrMany::Many(vec![
+                    position_exit(dec!(10)),
+                    EngineOutput::Health(health(1)),
+                ]),
+            )),
+            EngineAudit::Process(ProcessAudit::Process(event.clone())),
+            EngineAudit::Shutdown(ShutdownAudit::ErrorWithProcess(
+                ProcessAudit::ProcessWithOutput(event, OneOrMany::One(position_exit(dec!(-4)))),
+                OneOrMany::Many(vec![]),
+            )),
+        ];
+
+        let mut status = BotStatus::new(BotId::new("bot"));
+        audits
+            .iter()
+            .for_each(|audit| audit.update_status(&mut status));
+
+        assert_eq!(status.positions_exited, 2);
+        assert_eq!(status.pnl_realised, dec!(6));
+        assert_eq!(status.health, Some(health(1)));
+    }
+
+    #[test]
+    fn test_supervisor_status_is_healthy() {
+        struct TestCase {
+            bots: Vec<(BotState, Option<EngineHealth>)>,
+            expected: bool,
+        }
+
+        let cases = vec![
+            // TC0: no bots
+            TestCase {
+                bots: vec![],
+                expected: true,
+            },
+            // TC1: running bots with healthy or unknown health, and a stopped bot
+            TestCase {
+                bots: vec![
+                    (BotState::Running, Some(health(1))),
+                    (BotState::Running, None),
+                    (BotState::Stopped, Some(health(60))),
+                ],
+                expected: true,
+            },
+            // TC2: running bot with lagging market data feed
+            TestCase {
+                bots: vec![
+                    (BotState::Running, Some(health(1))),
+                    (BotState::Running, Some(health(60))),
+                ],
+                expected: false,
+            },
+            // TC3: failed bot
+            TestCase {
+                bots: vec![(BotState::Failed("error".to_string()), None)],
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let status = SupervisorStatus {
+                bots: test
+                    .bots
+                    .into_iter()
+                    .enumerate()
+                    .map(|(bot, (state, health))| BotStatus {
+                        state,
+                        health,
+                        ..BotStatus::new(BotId::new(bot.to_string()))
+                    })
+                    .collect(),
+            };
+
+            let actual = status.is_healthy(&HealthConfig::default());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
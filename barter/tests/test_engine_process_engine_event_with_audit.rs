@@ -7,7 +7,7 @@ use barter::{
             generate_algo_orders::GenerateAlgoOrdersOutput,
             send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
         },
-        audit::EngineAudit,
+        audit::{EngineAudit, ProcessAudit},
         clock::HistoricalClock,
         command::Command,
         execution_tx::MultiExchangeTxMap,
@@ -21,7 +21,7 @@ use barter::{
                 data::{DefaultInstrumentMarketData, InstrumentDataState},
                 filter::InstrumentFilter,
             },
-            position::PositionExited,
+            position::{BreakEvenStop, PositionExited, PositionUpdate},
             trading::TradingState,
         },
     },
@@ -44,7 +44,7 @@ use barter_execution::{
     AccountEvent, AccountEventKind, AccountSnapshot,
     balance::{AssetBalance, Balance},
     order::{
-        Order, OrderKey, OrderKind, TimeInForce,
+        Order, OrderKey, OrderKind, StopTrigger, TimeInForce,
         id::{ClientOrderId, OrderId, StrategyId},
         request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
         state::{ActiveOrderState, Open, OrderState},
@@ -90,6 +90,139 @@ const STARTING_BALANCE_ETH: Balance = Balance {
 };
 const QUOTE_FEES_PERCENT: f64 = 0.1; // 10%
 
+#[test]
+fn test_engine_break_even_stop_sends_protective_stop_order() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+
+    let mut engine = build_engine(TradingState::Disabled, execution_tx)
+        .with_protective_stop_orders(StrategyId::new("protective_stop"));
+    engine
+        .state
+        .instruments
+        .instrument_index_mut(&InstrumentIndex(0))
+        .position
+        .break_even_stop = Some(BreakEvenStop::new(dec!(1_000)));
+
+    let event = account_event_snapshot(&engine.state.assets);
+    process_with_audit(&mut engine, event);
+
+    // Open btc_usdt Position @ 10_000
+    let event = market_event_trade(1, 0, 10_000.0);
+    process_with_audit(&mut engine, event);
+    let event = account_event_trade(0, 1, Side::Buy, 10_000.0, 1.0);
+    process_with_audit(&mut engine, event);
+
+    // Price moves in favour, but not enough to move the stop to break-even
+    let event = market_event_trade(2, 0, 10_500.0);
+    let audit = process_with_audit(&mut engine, event.clone());
+    assert_eq!(audit.event, EngineAudit::process(event));
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // Price moves in favour beyond threshold, so the stop is moved to break-even
+    let event = market_event_trade(3, 0, 20_000.0);
+    let audit = process_with_audit(&mut engine, event);
+
+    let EngineAudit::Process(ProcessAudit::ProcessWithOutput(_, outputs)) = audit.event else {
+        panic!("expected ProcessAudit::ProcessWithOutput");
+    };
+    let outputs = outputs.into_vec();
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(
+        outputs[0],
+        EngineOutput::PositionUpdate(PositionUpdate {
+            instrument: InstrumentIndex(0),
+            side: Side::Buy,
+            quantity_abs: dec!(1),
+            price_stop: dec!(10_000),
+            time_exchange: time_plus_days(STARTING_TIMESTAMP, 3),
+        })
+    );
+
+    let ExecutionRequest::Open(mut stop) = execution_rx.next().unwrap() else {
+        panic!("expected ExecutionRequest::Open");
+    };
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    let EngineOutput::ProtectiveStop(SendCancelsAndOpensOutput { cancels, opens }) = &outputs[1]
+    else {
+        panic!("expected EngineOutput::ProtectiveStop");
+    };
+    assert!(cancels.is_empty());
+    assert_eq!(opens.sent, NoneOneOrMany::One(stop.clone()));
+
+    // Sell reduce-only StopMarket order triggered at the Position entry price
+    stop.key.cid = ClientOrderId::new("cid");
+    assert_eq!(
+        stop,
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("protective_stop"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side: Side::Sell,
+                price: dec!(10_000),
+                quantity: dec!(1),
+                kind: OrderKind::StopMarket {
+                    trigger_price: dec!(10_000),
+                    trigger: StopTrigger::LastPrice,
+                },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: true,
+            },
+        }
+    );
+
+    // Stop only moves to break-even once
+    let event = market_event_trade(4, 0, 21_000.0);
+    let audit = process_with_audit(&mut engine, event.clone());
+    assert_eq!(audit.event, EngineAudit::process(event));
+    assert!(execution_rx.rx.try_recv().is_err());
+}
+
+#[test]
+fn test_engine_only_generates_algo_orders_once_all_accounts_synced() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+
+    let mut engine = build_engine(TradingState::Enabled, execution_tx);
+    assert!(!engine.state.connectivity.all_accounts_synced());
+
+    // Market data for every instrument, but AccountSnapshot not yet received -> no AlgoOrders
+    for event in [
+        market_event_trade(1, 0, 10_000.0),
+        market_event_trade(1, 1, 0.1),
+    ] {
+        let audit = process_with_audit(&mut engine, event.clone());
+        assert_eq!(audit.event, EngineAudit::process(event));
+    }
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // AccountSnapshot synchronises the only exchange account -> AlgoOrders generated
+    let event = account_event_snapshot(&engine.state.assets);
+    let audit = process_with_audit(&mut engine, event);
+    assert!(engine.state.connectivity.all_accounts_synced());
+
+    let EngineAudit::Process(ProcessAudit::ProcessWithOutput(_, outputs)) = audit.event else {
+        panic!("expected ProcessAudit::ProcessWithOutput");
+    };
+    assert!(
+        outputs
+            .into_vec()
+            .iter()
+            .any(|output| matches!(output, EngineOutput::AlgoOrders(_)))
+    );
+
+    let sent = std::iter::from_fn(|| execution_rx.rx.try_recv().ok())
+        .map(|request| match request {
+            ExecutionRequest::Open(open) => open.key.cid,
+            request => panic!("expected ExecutionRequest::Open, found: {request:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sent, vec![gen_cid(0), gen_cid(1)]);
+}
+
 #[test]
 fn test_engine_process_engine_event_with_audit() {
     let (execution_tx, mut execution_rx) = mpsc_unbounded();
@@ -133,6 +266,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Buy,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
             price: dec!(10_000),
             quantity: dec!(1),
         },
@@ -148,6 +282,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Buy,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
             price: dec!(0.1),
             quantity: dec!(1),
         },
@@ -334,6 +469,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Sell,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: true,
             price: dec!(20_000),
             quantity: dec!(1),
         },
@@ -423,11 +559,14 @@ fn test_engine_process_engine_event_with_audit() {
                 price_entry_average: dec!(10_000.0),
                 quantity_abs_max: dec!(1.0),
                 pnl_realised: dec!(7000.0), // (-10k entry - 1k fees)+(20k exit - 2k fees) = 7k
+                excursion_adverse_max: dec!(0.0),
+                excursion_favourable_max: dec!(10_000.0),
                 fees_enter: AssetFees::quote_fees(dec!(1_000.0)),
                 fees_exit: AssetFees::quote_fees(dec!(2_000.0)),
                 time_enter: time_plus_days(STARTING_TIMESTAMP, 2),
                 time_exit: time_plus_days(STARTING_TIMESTAMP, 3),
                 trades: vec![gen_trade_id(0), gen_trade_id(0)],
+                signal: None,
             }
         )
     );
@@ -470,6 +609,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Sell,
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             price: dec!(0.05),
             quantity: dec!(1),
         },
@@ -532,6 +672,7 @@ fn test_engine_process_engine_event_with_audit() {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             state: ActiveOrderState::Open(Open {
                 id: gen_order_id(1),
                 time_exchange: time_plus_days(STARTING_TIMESTAMP, 4),
@@ -561,6 +702,7 @@ fn test_engine_process_engine_event_with_audit() {
     // Simulate Order FullyFilled update for Sequence(21) LIMIT eth_btc_sell_order
     let event = EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
         exchange: ExchangeIndex(0),
+        sub_account: None,
         kind: AccountEventKind::OrderSnapshot(Snapshot(Order {
             key: OrderKey {
                 exchange: ExchangeIndex(0),
@@ -573,6 +715,7 @@ fn test_engine_process_engine_event_with_audit() {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             state: OrderState::fully_filled(),
         })),
     }));
@@ -603,11 +746,14 @@ fn test_engine_process_engine_event_with_audit() {
                 price_entry_average: dec!(0.1),
                 quantity_abs_max: dec!(1.0),
                 pnl_realised: dec!(-0.065), // 0.05 - 0.01 - 0.01 entry fees - 0.005 exit fees
+                excursion_adverse_max: dec!(0.05),
+                excursion_favourable_max: dec!(0.0),
                 fees_enter: AssetFees::quote_fees(dec!(0.01)), // 0.01 btc
                 fees_exit: AssetFees::quote_fees(dec!(0.005)), // 0.005 btc
                 time_enter: time_plus_days(STARTING_TIMESTAMP, 2),
                 time_exit: time_plus_days(STARTING_TIMESTAMP, 5),
                 trades: vec![gen_trade_id(1), gen_trade_id(1)],
+                signal: None,
             }
         )
     );
@@ -692,6 +838,7 @@ impl AlgoStrategy for TestBuyAndHoldStrategy {
                         side: Side::Buy,
                         kind: OrderKind::Market,
                         time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
                         price,
                         quantity: dec!(1),
                     },
@@ -864,6 +1011,7 @@ fn build_engine(
 fn account_event_snapshot(assets: &AssetStates) -> EngineEvent<DataKind> {
     EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
         exchange: ExchangeIndex(0),
+        sub_account: None,
         kind: AccountEventKind::Snapshot(AccountSnapshot {
             exchange: ExchangeIndex(0),
             balances: assets
@@ -906,6 +1054,7 @@ fn account_event_order_response(
 ) -> EngineEvent<DataKind> {
     EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
         exchange: ExchangeIndex(0),
+        sub_account: None,
         kind: AccountEventKind::OrderSnapshot(Snapshot(Order {
             key: OrderKey {
                 exchange: ExchangeIndex(0),
@@ -918,6 +1067,7 @@ fn account_event_order_response(
             quantity: Decimal::try_from(quantity).unwrap(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             state: OrderState::active(Open {
                 id: gen_order_id(instrument),
                 time_exchange: time_plus_days(STARTING_TIMESTAMP, time_plus),
@@ -935,6 +1085,7 @@ fn account_event_balance(
 ) -> EngineEvent<DataKind> {
     EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
         exchange: ExchangeIndex(0),
+        sub_account: None,
         kind: AccountEventKind::BalanceSnapshot(Snapshot(AssetBalance {
             asset: AssetIndex(asset),
             balance: Balance::new(
@@ -955,15 +1106,18 @@ fn account_event_trade(
 ) -> EngineEvent<DataKind> {
     EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
         exchange: ExchangeIndex(0),
+        sub_account: None,
         kind: AccountEventKind::Trade(Trade {
             id: gen_trade_id(instrument),
             order_id: gen_order_id(instrument),
+            cid: None,
             instrument: InstrumentIndex(instrument),
             strategy: strategy_id(),
             time_exchange: time_plus_days(STARTING_TIMESTAMP, time_plus),
             side,
             price: Decimal::try_from(price).unwrap(),
             quantity: Decimal::try_from(quantity).unwrap(),
+            liquidity: None,
             fees: AssetFees::quote_fees(
                 Decimal::try_from(price * quantity * QUOTE_FEES_PERCENT).unwrap(),
             ),